@@ -205,6 +205,18 @@ fn configure_validation(builder: Builder) -> Builder {
             ("SearchBatchPoints.collection_name", "length(min = 1, max = 255)"),
             ("SearchBatchPoints.search_points", ""),
             ("SearchBatchPoints.timeout", "custom = \"crate::grpc::validate::validate_u64_range_min_1\""),
+            ("SearchTextPoints.collection_name", "length(min = 1, max = 255)"),
+            ("SearchTextPoints.text", "length(min = 1)"),
+            ("SearchTextPoints.filter", ""),
+            ("SearchTextPoints.limit", "range(min = 1)"),
+            ("SearchTextPoints.params", ""),
+            ("SearchTextPoints.timeout", "custom = \"crate::grpc::validate::validate_u64_range_min_1\""),
+            ("SearchArithmeticPoints.collection_name", "length(min = 1, max = 255)"),
+            ("SearchArithmeticPoints.terms", "length(min = 1)"),
+            ("SearchArithmeticPoints.filter", ""),
+            ("SearchArithmeticPoints.limit", "range(min = 1)"),
+            ("SearchArithmeticPoints.params", ""),
+            ("SearchArithmeticPoints.timeout", "custom = \"crate::grpc::validate::validate_u64_range_min_1\""),
             ("SearchPointGroups.collection_name", "length(min = 1, max = 255)"),
             ("SearchPointGroups.group_by", "length(min = 1)"),
             ("SearchPointGroups.filter", ""),
@@ -245,6 +257,9 @@ fn configure_validation(builder: Builder) -> Builder {
             ("DiscoverBatchPoints.timeout", "custom = \"crate::grpc::validate::validate_u64_range_min_1\""),
             ("CountPoints.collection_name", "length(min = 1, max = 255)"),
             ("CountPoints.filter", ""),
+            ("PayloadStatsPoints.collection_name", "length(min = 1, max = 255)"),
+            ("PayloadStatsPoints.limit", "custom = \"crate::grpc::validate::validate_u64_range_min_1_max_100000\""),
+            ("PayloadStatsPoints.filter", ""),
             ("GeoPolygon.exterior", "custom = \"crate::grpc::validate::validate_geo_polygon_exterior\""),
             ("GeoPolygon.interiors", "custom = \"crate::grpc::validate::validate_geo_polygon_interiors\""),
             ("Filter.should", ""),
@@ -292,6 +307,9 @@ fn configure_validation(builder: Builder) -> Builder {
             ("ScrollPointsInternal.scroll_points", ""),
             ("GetPointsInternal.get_points", ""),
             ("CountPointsInternal.count_points", ""),
+            ("IdfStatisticsInternal.collection_name", "length(min = 1, max = 255)"),
+            ("PayloadStatsInternal.payload_stats_points", ""),
+            ("SparseStatsInternal.collection_name", "length(min = 1, max = 255)"),
             ("SyncPointsInternal.sync_points", ""),
             ("SyncPoints.collection_name", "length(min = 1, max = 255)"),
         ], &[])
@@ -3,16 +3,20 @@ use std::time::Instant;
 
 use chrono::{NaiveDateTime, Timelike};
 use segment::data_types::integer_index::IntegerIndexType;
+use segment::data_types::keyword_index::KeywordIndexType;
 use segment::data_types::text_index::TextIndexType;
 use segment::data_types::vectors::{DenseVector, MultiDenseVector};
+use segment::index::sparse_index::sparse_search_telemetry as segment_sparse_stats;
 use segment::json_path::JsonPath;
 use segment::types::{default_quantization_ignore_value, DateTimePayloadType, FloatPayloadType};
 use tonic::Status;
 use uuid::Uuid;
 
 use super::qdrant::{
-    start_from, BinaryQuantization, CompressionRatio, DatetimeRange, Direction, GeoLineString,
-    GroupId, MultiVectorComparator, MultiVectorConfig, OrderBy, Range, SparseIndices, StartFrom,
+    start_from, BinaryQuantization, BinaryQuantizationEncoding, BinaryQuantizationQueryEncoding,
+    ComparisonOperator, CompressionRatio, DatetimeRange, Direction, FdeConfig,
+    FieldsComparisonCondition, GeoLineString, GroupId, MultiVectorComparator, MultiVectorConfig,
+    OrderBy, Range, SparseIndexStatistics, SparseIndices, StartFrom,
 };
 use crate::grpc::models::{CollectionsResponse, VersionInfo};
 use crate::grpc::qdrant::condition::ConditionOneOf;
@@ -26,9 +30,9 @@ use crate::grpc::qdrant::{
     shard_key, with_vectors_selector, CollectionDescription, CollectionOperationResponse,
     Condition, Distance, FieldCondition, Filter, GeoBoundingBox, GeoPoint, GeoPolygon, GeoRadius,
     HasIdCondition, HealthCheckReply, HnswConfigDiff, IntegerIndexParams, IsEmptyCondition,
-    IsNullCondition, ListCollectionsResponse, ListValue, Match, MinShould, NamedVectors,
-    NestedCondition, PayloadExcludeSelector, PayloadIncludeSelector, PayloadIndexParams,
-    PayloadSchemaInfo, PayloadSchemaType, PointId, PointsOperationResponse,
+    IsNullCondition, KeywordIndexParams, ListCollectionsResponse, ListValue, Match, MinShould,
+    NamedVectors, NestedCondition, PayloadExcludeSelector, PayloadIncludeSelector,
+    PayloadIndexParams, PayloadSchemaInfo, PayloadSchemaType, PointId, PointsOperationResponse,
     PointsOperationResponseInternal, ProductQuantization, QuantizationConfig,
     QuantizationSearchParams, QuantizationType, RepeatedIntegers, RepeatedStrings,
     ScalarQuantization, ScoredPoint, SearchParams, ShardKey, Struct, TextIndexParams,
@@ -222,6 +226,16 @@ impl From<segment::data_types::integer_index::IntegerIndexParams> for PayloadInd
     }
 }
 
+impl From<segment::data_types::keyword_index::KeywordIndexParams> for PayloadIndexParams {
+    fn from(params: segment::data_types::keyword_index::KeywordIndexParams) -> Self {
+        PayloadIndexParams {
+            index_params: Some(IndexParams::KeywordIndexParams(KeywordIndexParams {
+                is_tenant: params.is_tenant,
+            })),
+        }
+    }
+}
+
 impl From<segment::types::PayloadIndexInfo> for PayloadSchemaInfo {
     fn from(schema: segment::types::PayloadIndexInfo) -> Self {
         PayloadSchemaInfo {
@@ -242,6 +256,9 @@ impl From<segment::types::PayloadIndexInfo> for PayloadSchemaInfo {
                 segment::types::PayloadSchemaParams::Integer(integer_params) => {
                     integer_params.into()
                 }
+                segment::types::PayloadSchemaParams::Keyword(keyword_params) => {
+                    keyword_params.into()
+                }
             }),
             points: Some(schema.points as u64),
         }
@@ -291,6 +308,16 @@ impl TryFrom<IntegerIndexParams> for segment::data_types::integer_index::Integer
     }
 }
 
+impl TryFrom<KeywordIndexParams> for segment::data_types::keyword_index::KeywordIndexParams {
+    type Error = Status;
+    fn try_from(params: KeywordIndexParams) -> Result<Self, Self::Error> {
+        Ok(segment::data_types::keyword_index::KeywordIndexParams {
+            r#type: KeywordIndexType::Keyword,
+            is_tenant: params.is_tenant,
+        })
+    }
+}
+
 impl TryFrom<IndexParams> for segment::types::PayloadSchemaParams {
     type Error = Status;
 
@@ -302,6 +329,9 @@ impl TryFrom<IndexParams> for segment::types::PayloadSchemaParams {
             IndexParams::IntegerIndexParams(integer_params) => Ok(
                 segment::types::PayloadSchemaParams::Integer(integer_params.try_into()?),
             ),
+            IndexParams::KeywordIndexParams(keyword_params) => Ok(
+                segment::types::PayloadSchemaParams::Keyword(keyword_params.try_into()?),
+            ),
         }
     }
 }
@@ -690,6 +720,9 @@ impl From<segment::types::ScalarQuantization> for ScalarQuantization {
                 segment::types::ScalarType::Int8 => {
                     crate::grpc::qdrant::QuantizationType::Int8 as i32
                 }
+                segment::types::ScalarType::Int4 => {
+                    crate::grpc::qdrant::QuantizationType::Int4 as i32
+                }
             },
             quantile: config.quantile,
             always_ram: config.always_ram,
@@ -705,6 +738,7 @@ impl TryFrom<ScalarQuantization> for segment::types::ScalarQuantization {
             scalar: segment::types::ScalarQuantizationConfig {
                 r#type: match QuantizationType::from_i32(value.r#type) {
                     Some(QuantizationType::Int8) => segment::types::ScalarType::Int8,
+                    Some(QuantizationType::Int4) => segment::types::ScalarType::Int4,
                     Some(QuantizationType::UnknownQuantization) | None => {
                         return Err(Status::invalid_argument("Unknown quantization type"));
                     }
@@ -761,6 +795,52 @@ impl From<segment::types::BinaryQuantization> for BinaryQuantization {
         let config = value.binary;
         BinaryQuantization {
             always_ram: config.always_ram,
+            encoding: config
+                .encoding
+                .map(|encoding| BinaryQuantizationEncoding::from(encoding) as i32),
+            query_encoding: config
+                .query_encoding
+                .map(|query_encoding| BinaryQuantizationQueryEncoding::from(query_encoding) as i32),
+        }
+    }
+}
+
+impl From<segment::types::BinaryQuantizationEncoding> for BinaryQuantizationEncoding {
+    fn from(value: segment::types::BinaryQuantizationEncoding) -> Self {
+        match value {
+            segment::types::BinaryQuantizationEncoding::OneBit => Self::OneBit,
+            segment::types::BinaryQuantizationEncoding::OneAndHalfBits => Self::OneAndHalfBits,
+            segment::types::BinaryQuantizationEncoding::TwoBits => Self::TwoBits,
+        }
+    }
+}
+
+impl From<BinaryQuantizationEncoding> for segment::types::BinaryQuantizationEncoding {
+    fn from(value: BinaryQuantizationEncoding) -> Self {
+        match value {
+            BinaryQuantizationEncoding::OneBit => Self::OneBit,
+            BinaryQuantizationEncoding::OneAndHalfBits => Self::OneAndHalfBits,
+            BinaryQuantizationEncoding::TwoBits => Self::TwoBits,
+        }
+    }
+}
+
+impl From<segment::types::BinaryQuantizationQueryEncoding> for BinaryQuantizationQueryEncoding {
+    fn from(value: segment::types::BinaryQuantizationQueryEncoding) -> Self {
+        match value {
+            segment::types::BinaryQuantizationQueryEncoding::Binary => Self::Binary,
+            segment::types::BinaryQuantizationQueryEncoding::Scalar4Bits => Self::Scalar4Bits,
+            segment::types::BinaryQuantizationQueryEncoding::Scalar8Bits => Self::Scalar8Bits,
+        }
+    }
+}
+
+impl From<BinaryQuantizationQueryEncoding> for segment::types::BinaryQuantizationQueryEncoding {
+    fn from(value: BinaryQuantizationQueryEncoding) -> Self {
+        match value {
+            BinaryQuantizationQueryEncoding::Binary => Self::Binary,
+            BinaryQuantizationQueryEncoding::Scalar4Bits => Self::Scalar4Bits,
+            BinaryQuantizationQueryEncoding::Scalar8Bits => Self::Scalar8Bits,
         }
     }
 }
@@ -772,6 +852,28 @@ impl TryFrom<BinaryQuantization> for segment::types::BinaryQuantization {
         Ok(segment::types::BinaryQuantization {
             binary: segment::types::BinaryQuantizationConfig {
                 always_ram: value.always_ram,
+                encoding: value
+                    .encoding
+                    .map(|encoding| {
+                        BinaryQuantizationEncoding::from_i32(encoding)
+                            .map(segment::types::BinaryQuantizationEncoding::from)
+                            .ok_or_else(|| {
+                                Status::invalid_argument("Unknown binary quantization encoding")
+                            })
+                    })
+                    .transpose()?,
+                query_encoding: value
+                    .query_encoding
+                    .map(|query_encoding| {
+                        BinaryQuantizationQueryEncoding::from_i32(query_encoding)
+                            .map(segment::types::BinaryQuantizationQueryEncoding::from)
+                            .ok_or_else(|| {
+                                Status::invalid_argument(
+                                    "Unknown binary quantization query encoding",
+                                )
+                            })
+                    })
+                    .transpose()?,
             },
         })
     }
@@ -822,8 +924,10 @@ impl TryFrom<QuantizationConfig> for segment::types::QuantizationConfig {
 
 impl From<segment::types::MultiVectorConfig> for MultiVectorConfig {
     fn from(value: segment::types::MultiVectorConfig) -> Self {
+        let segment::types::MultiVectorConfig { comparator, fde } = value;
         Self {
-            comparator: MultiVectorComparator::from(value.comparator) as i32,
+            comparator: MultiVectorComparator::from(comparator) as i32,
+            fde: fde.map(FdeConfig::from),
         }
     }
 }
@@ -832,6 +936,54 @@ impl From<segment::types::MultiVectorComparator> for MultiVectorComparator {
     fn from(value: segment::types::MultiVectorComparator) -> Self {
         match value {
             segment::types::MultiVectorComparator::MaxSim => MultiVectorComparator::MaxSim,
+            segment::types::MultiVectorComparator::MeanPooledCosine => {
+                MultiVectorComparator::MeanPooledCosine
+            }
+            segment::types::MultiVectorComparator::MaxSimNormalized => {
+                MultiVectorComparator::MaxSimNormalized
+            }
+            segment::types::MultiVectorComparator::SymmetricMaxSim => {
+                MultiVectorComparator::SymmetricMaxSim
+            }
+        }
+    }
+}
+
+impl From<segment::types::FdeConfig> for FdeConfig {
+    fn from(value: segment::types::FdeConfig) -> Self {
+        let segment::types::FdeConfig {
+            repetitions,
+            simhash_bits,
+            projection_dim,
+            seed,
+            prefetch_factor,
+        } = value;
+        Self {
+            repetitions: Some(repetitions),
+            simhash_bits: Some(simhash_bits),
+            projection_dim: Some(projection_dim),
+            seed: Some(seed),
+            prefetch_factor: Some(prefetch_factor),
+        }
+    }
+}
+
+impl From<FdeConfig> for segment::types::FdeConfig {
+    fn from(value: FdeConfig) -> Self {
+        let FdeConfig {
+            repetitions,
+            simhash_bits,
+            projection_dim,
+            seed,
+            prefetch_factor,
+        } = value;
+        let default = segment::types::FdeConfig::default();
+        Self {
+            repetitions: repetitions.unwrap_or(default.repetitions),
+            simhash_bits: simhash_bits.unwrap_or(default.simhash_bits),
+            projection_dim: projection_dim.unwrap_or(default.projection_dim),
+            seed: seed.unwrap_or(default.seed),
+            prefetch_factor: prefetch_factor.unwrap_or(default.prefetch_factor),
         }
     }
 }
@@ -840,10 +992,12 @@ impl TryFrom<MultiVectorConfig> for segment::types::MultiVectorConfig {
     type Error = Status;
 
     fn try_from(value: MultiVectorConfig) -> Result<Self, Self::Error> {
-        let comparator = MultiVectorComparator::from_i32(value.comparator)
+        let MultiVectorConfig { comparator, fde } = value;
+        let comparator = MultiVectorComparator::from_i32(comparator)
             .ok_or_else(|| Status::invalid_argument("Unknown multi vector comparator"))?;
         Ok(segment::types::MultiVectorConfig {
             comparator: segment::types::MultiVectorComparator::from(comparator),
+            fde: fde.map(segment::types::FdeConfig::from),
         })
     }
 }
@@ -852,6 +1006,15 @@ impl From<MultiVectorComparator> for segment::types::MultiVectorComparator {
     fn from(value: MultiVectorComparator) -> Self {
         match value {
             MultiVectorComparator::MaxSim => segment::types::MultiVectorComparator::MaxSim,
+            MultiVectorComparator::MeanPooledCosine => {
+                segment::types::MultiVectorComparator::MeanPooledCosine
+            }
+            MultiVectorComparator::MaxSimNormalized => {
+                segment::types::MultiVectorComparator::MaxSimNormalized
+            }
+            MultiVectorComparator::SymmetricMaxSim => {
+                segment::types::MultiVectorComparator::SymmetricMaxSim
+            }
         }
     }
 }
@@ -956,6 +1119,9 @@ impl TryFrom<Condition> for segment::types::Condition {
                 ConditionOneOf::Nested(nested) => Ok(segment::types::Condition::Nested(
                     segment::types::NestedCondition::new(nested.try_into()?),
                 )),
+                ConditionOneOf::Compare(compare) => {
+                    Ok(segment::types::Condition::Compare(compare.try_into()?))
+                }
             };
         }
         Err(Status::invalid_argument("Malformed Condition type"))
@@ -975,6 +1141,7 @@ impl From<segment::types::Condition> for Condition {
             segment::types::Condition::Nested(nested) => {
                 ConditionOneOf::Nested(nested.nested.into())
             }
+            segment::types::Condition::Compare(compare) => ConditionOneOf::Compare(compare.into()),
         };
 
         Self {
@@ -1008,6 +1175,57 @@ impl From<segment::types::Nested> for NestedCondition {
     }
 }
 
+impl From<ComparisonOperator> for segment::types::ComparisonOperator {
+    fn from(value: ComparisonOperator) -> Self {
+        match value {
+            ComparisonOperator::Lt => segment::types::ComparisonOperator::Lt,
+            ComparisonOperator::Lte => segment::types::ComparisonOperator::Lte,
+            ComparisonOperator::Gt => segment::types::ComparisonOperator::Gt,
+            ComparisonOperator::Gte => segment::types::ComparisonOperator::Gte,
+            ComparisonOperator::Eq => segment::types::ComparisonOperator::Eq,
+            ComparisonOperator::Ne => segment::types::ComparisonOperator::Ne,
+        }
+    }
+}
+
+impl From<segment::types::ComparisonOperator> for ComparisonOperator {
+    fn from(value: segment::types::ComparisonOperator) -> Self {
+        match value {
+            segment::types::ComparisonOperator::Lt => ComparisonOperator::Lt,
+            segment::types::ComparisonOperator::Lte => ComparisonOperator::Lte,
+            segment::types::ComparisonOperator::Gt => ComparisonOperator::Gt,
+            segment::types::ComparisonOperator::Gte => ComparisonOperator::Gte,
+            segment::types::ComparisonOperator::Eq => ComparisonOperator::Eq,
+            segment::types::ComparisonOperator::Ne => ComparisonOperator::Ne,
+        }
+    }
+}
+
+impl TryFrom<FieldsComparisonCondition> for segment::types::FieldsComparisonCondition {
+    type Error = Status;
+
+    fn try_from(value: FieldsComparisonCondition) -> Result<Self, Self::Error> {
+        let op = ComparisonOperator::from_i32(value.op)
+            .ok_or_else(|| Status::invalid_argument("Unknown comparison operator"))?;
+        Ok(segment::types::FieldsComparisonCondition::new(
+            json_path_from_proto(&value.left)?,
+            op.into(),
+            json_path_from_proto(&value.right)?,
+        ))
+    }
+}
+
+impl From<segment::types::FieldsComparisonCondition> for FieldsComparisonCondition {
+    fn from(value: segment::types::FieldsComparisonCondition) -> Self {
+        let segment::types::FieldsComparison { left, op, right } = value.compare;
+        Self {
+            left: left.to_string(),
+            op: ComparisonOperator::from(op) as i32,
+            right: right.to_string(),
+        }
+    }
+}
+
 impl TryFrom<IsEmptyCondition> for segment::types::IsEmptyCondition {
     type Error = Status;
 
@@ -1472,16 +1690,30 @@ impl TryFrom<Distance> for segment::types::Distance {
             Distance::Euclid => segment::types::Distance::Euclid,
             Distance::Dot => segment::types::Distance::Dot,
             Distance::Manhattan => segment::types::Distance::Manhattan,
+            Distance::Hamming => segment::types::Distance::Hamming,
+            Distance::Jaccard => segment::types::Distance::Jaccard,
+            Distance::Minkowski => {
+                return Err(Status::invalid_argument(
+                    "Minkowski distance requires minkowski_p parameter",
+                ));
+            }
         })
     }
 }
 
-pub fn from_grpc_dist(dist: i32) -> Result<segment::types::Distance, Status> {
-    match Distance::from_i32(dist) {
-        None => Err(Status::invalid_argument(format!(
+pub fn from_grpc_dist(
+    dist: i32,
+    minkowski_p: Option<u32>,
+) -> Result<segment::types::Distance, Status> {
+    match (Distance::from_i32(dist), minkowski_p) {
+        (None, _) => Err(Status::invalid_argument(format!(
             "Malformed distance parameter, unexpected value: {dist}"
         ))),
-        Some(grpc_distance) => Ok(grpc_distance.try_into()?),
+        (Some(Distance::Minkowski), Some(p)) => Ok(segment::types::Distance::Minkowski(p)),
+        (Some(_), Some(_)) => Err(Status::invalid_argument(
+            "minkowski_p parameter is only allowed for Minkowski distance",
+        )),
+        (Some(grpc_distance), None) => Ok(grpc_distance.try_into()?),
     }
 }
 
@@ -1551,3 +1783,48 @@ impl From<UpdateResult> for UpdateResultInternal {
         }
     }
 }
+
+impl From<(String, segment_sparse_stats::SparseIndexStatistics)> for SparseIndexStatistics {
+    fn from(
+        (vector_name, statistics): (String, segment_sparse_stats::SparseIndexStatistics),
+    ) -> Self {
+        let segment_sparse_stats::SparseIndexStatistics {
+            indexed_vectors,
+            ram_usage_bytes,
+            disk_usage_bytes,
+            document_frequencies,
+        } = statistics;
+        Self {
+            vector_name,
+            indexed_vectors: indexed_vectors as u64,
+            ram_usage_bytes: ram_usage_bytes as u64,
+            disk_usage_bytes: disk_usage_bytes as u64,
+            document_frequencies: document_frequencies
+                .into_iter()
+                .map(|(dim_id, frequency)| (dim_id, frequency as u64))
+                .collect(),
+        }
+    }
+}
+
+impl From<SparseIndexStatistics> for (String, segment_sparse_stats::SparseIndexStatistics) {
+    fn from(value: SparseIndexStatistics) -> Self {
+        let SparseIndexStatistics {
+            vector_name,
+            indexed_vectors,
+            ram_usage_bytes,
+            disk_usage_bytes,
+            document_frequencies,
+        } = value;
+        let statistics = segment_sparse_stats::SparseIndexStatistics {
+            indexed_vectors: indexed_vectors as usize,
+            ram_usage_bytes: ram_usage_bytes as usize,
+            disk_usage_bytes: disk_usage_bytes as usize,
+            document_frequencies: document_frequencies
+                .into_iter()
+                .map(|(dim_id, frequency)| (dim_id, frequency as usize))
+                .collect(),
+        };
+        (vector_name, statistics)
+    }
+}
@@ -27,6 +27,12 @@ pub struct VectorParams {
     /// Configuration for multi-vector search
     #[prost(message, optional, tag = "7")]
     pub multivector_config: ::core::option::Option<MultiVectorConfig>,
+    /// If set, index and quantization are built over only this many first dimensions of the vectors
+    #[prost(uint64, optional, tag = "8")]
+    pub prefix_size: ::core::option::Option<u64>,
+    /// Order of Minkowski distance, from 1 to 16, required for `Minkowski` distance
+    #[prost(uint32, optional, tag = "9")]
+    pub minkowski_p: ::core::option::Option<u32>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -118,6 +124,38 @@ pub struct SparseVectorParams {
     /// If set - apply modifier to the vector values
     #[prost(enumeration = "Modifier", optional, tag = "2")]
     pub modifier: ::core::option::Option<i32>,
+    /// If set - derive vectors from the text of a payload field
+    #[prost(message, optional, tag = "3")]
+    pub bm25: ::core::option::Option<Bm25Config>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Bm25Config {
+    /// Payload field with the text to vectorize
+    #[prost(string, tag = "1")]
+    pub field: ::prost::alloc::string::String,
+    /// Tokenizer type, default - word
+    #[prost(enumeration = "TokenizerType", optional, tag = "2")]
+    pub tokenizer: ::core::option::Option<i32>,
+    /// Minimal token length
+    #[prost(uint64, optional, tag = "3")]
+    pub min_token_len: ::core::option::Option<u64>,
+    /// Maximal token length
+    #[prost(uint64, optional, tag = "4")]
+    pub max_token_len: ::core::option::Option<u64>,
+    /// If true - all tokens will be lowercase, default - true
+    #[prost(bool, optional, tag = "5")]
+    pub lowercase: ::core::option::Option<bool>,
+    /// Term frequency saturation, default - 1.2
+    #[prost(float, optional, tag = "6")]
+    pub k: ::core::option::Option<f32>,
+    /// Document length normalization, default - 0.75
+    #[prost(float, optional, tag = "7")]
+    pub b: ::core::option::Option<f32>,
+    /// Average number of terms in a document of the collection, must be at least 1
+    #[prost(float, tag = "8")]
+    pub avg_len: f32,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -132,10 +170,33 @@ pub struct SparseVectorConfig {
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FdeConfig {
+    /// Number of independent partitionings, concatenated in the encoding. Default: 10
+    #[prost(uint32, optional, tag = "1")]
+    pub repetitions: ::core::option::Option<u32>,
+    /// Number of random hyperplanes of each partitioning. Default: 4
+    #[prost(uint32, optional, tag = "2")]
+    pub simhash_bits: ::core::option::Option<u32>,
+    /// Vectors of each bucket are reduced to this many dimensions with a random projection. Default: 16
+    #[prost(uint32, optional, tag = "3")]
+    pub projection_dim: ::core::option::Option<u32>,
+    /// Seed of the random hyperplanes and projections. Default: 42
+    #[prost(uint64, optional, tag = "4")]
+    pub seed: ::core::option::Option<u64>,
+    /// Number of candidates selected by the encodings for each requested result. Default: 10
+    #[prost(uint32, optional, tag = "5")]
+    pub prefetch_factor: ::core::option::Option<u32>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MultiVectorConfig {
     /// Comparator for multi-vector search
     #[prost(enumeration = "MultiVectorComparator", tag = "1")]
     pub comparator: i32,
+    /// If set, MUVERA fixed dimensional encodings are maintained to select candidates before exact scoring
+    #[prost(message, optional, tag = "2")]
+    pub fde: ::core::option::Option<FdeConfig>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -265,6 +326,22 @@ pub struct SparseIndexConfig {
     /// Store inverted index on disk. If set to false, the index will be stored in RAM.
     #[prost(bool, optional, tag = "2")]
     pub on_disk: ::core::option::Option<bool>,
+    ///
+    /// Datatype used to store weights in the index.
+    #[prost(enumeration = "Datatype", optional, tag = "3")]
+    pub datatype: ::core::option::Option<i32>,
+    ///
+    /// Index only this number of dimensions with the largest absolute weights of each vector.
+    #[prost(uint64, optional, tag = "4")]
+    pub prune_top_k: ::core::option::Option<u64>,
+    ///
+    /// Do not index dimensions with absolute weight below this threshold.
+    #[prost(float, optional, tag = "5")]
+    pub prune_threshold: ::core::option::Option<f32>,
+    ///
+    /// Retrieve this many times more candidates from a pruned index before rescoring them with the original vectors.
+    #[prost(double, optional, tag = "6")]
+    pub prune_oversampling: ::core::option::Option<f64>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -385,6 +462,12 @@ pub struct BinaryQuantization {
     /// If true - quantized vectors always will be stored in RAM, ignoring the config of main storage
     #[prost(bool, optional, tag = "1")]
     pub always_ram: ::core::option::Option<bool>,
+    /// Number of bits used to encode each dimension of stored vectors
+    #[prost(enumeration = "BinaryQuantizationEncoding", optional, tag = "2")]
+    pub encoding: ::core::option::Option<i32>,
+    /// Encoding of the query vector, default is the same as stored vectors
+    #[prost(enumeration = "BinaryQuantizationQueryEncoding", optional, tag = "3")]
+    pub query_encoding: ::core::option::Option<i32>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -495,6 +578,9 @@ pub struct CreateCollection {
     /// Configuration for sparse vectors
     #[prost(message, optional, tag = "16")]
     pub sparse_vectors_config: ::core::option::Option<SparseVectorConfig>,
+    /// How to check payload values of indexed fields on write
+    #[prost(enumeration = "PayloadSchemaEnforcement", optional, tag = "17")]
+    pub payload_schema_enforcement: ::core::option::Option<i32>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -588,6 +674,9 @@ pub struct CollectionParams {
     /// Configuration for sparse vectors
     #[prost(message, optional, tag = "10")]
     pub sparse_vectors_config: ::core::option::Option<SparseVectorConfig>,
+    /// How to check payload values of indexed fields on write
+    #[prost(enumeration = "PayloadSchemaEnforcement", optional, tag = "11")]
+    pub payload_schema_enforcement: ::core::option::Option<i32>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -606,6 +695,9 @@ pub struct CollectionParamsDiff {
     /// Fan-out every read request to these many additional remote nodes (and return first available response)
     #[prost(uint32, optional, tag = "4")]
     pub read_fan_out_factor: ::core::option::Option<u32>,
+    /// How to check payload values of indexed fields on write
+    #[prost(enumeration = "PayloadSchemaEnforcement", optional, tag = "5")]
+    pub payload_schema_enforcement: ::core::option::Option<i32>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -662,8 +754,16 @@ pub struct IntegerIndexParams {
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct KeywordIndexParams {
+    /// If true - used for tenant optimization.
+    #[prost(bool, optional, tag = "1")]
+    pub is_tenant: ::core::option::Option<bool>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PayloadIndexParams {
-    #[prost(oneof = "payload_index_params::IndexParams", tags = "1, 2")]
+    #[prost(oneof = "payload_index_params::IndexParams", tags = "1, 2, 3")]
     pub index_params: ::core::option::Option<payload_index_params::IndexParams>,
 }
 /// Nested message and enum types in `PayloadIndexParams`.
@@ -678,6 +778,9 @@ pub mod payload_index_params {
         /// Parameters for integer index
         #[prost(message, tag = "2")]
         IntegerIndexParams(super::IntegerIndexParams),
+        /// Parameters for keyword index
+        #[prost(message, tag = "3")]
+        KeywordIndexParams(super::KeywordIndexParams),
     }
 }
 #[derive(serde::Serialize)]
@@ -1113,6 +1216,7 @@ pub enum Datatype {
     Default = 0,
     Float32 = 1,
     Uint8 = 2,
+    Float16 = 3,
 }
 impl Datatype {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -1124,6 +1228,7 @@ impl Datatype {
             Datatype::Default => "Default",
             Datatype::Float32 => "Float32",
             Datatype::Uint8 => "Uint8",
+            Datatype::Float16 => "Float16",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -1132,6 +1237,7 @@ impl Datatype {
             "Default" => Some(Self::Default),
             "Float32" => Some(Self::Float32),
             "Uint8" => Some(Self::Uint8),
+            "Float16" => Some(Self::Float16),
             _ => None,
         }
     }
@@ -1169,6 +1275,9 @@ impl Modifier {
 #[repr(i32)]
 pub enum MultiVectorComparator {
     MaxSim = 0,
+    MeanPooledCosine = 1,
+    MaxSimNormalized = 2,
+    SymmetricMaxSim = 3,
 }
 impl MultiVectorComparator {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -1178,12 +1287,18 @@ impl MultiVectorComparator {
     pub fn as_str_name(&self) -> &'static str {
         match self {
             MultiVectorComparator::MaxSim => "MaxSim",
+            MultiVectorComparator::MeanPooledCosine => "MeanPooledCosine",
+            MultiVectorComparator::MaxSimNormalized => "MaxSimNormalized",
+            MultiVectorComparator::SymmetricMaxSim => "SymmetricMaxSim",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
     pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
         match value {
             "MaxSim" => Some(Self::MaxSim),
+            "MeanPooledCosine" => Some(Self::MeanPooledCosine),
+            "MaxSimNormalized" => Some(Self::MaxSimNormalized),
+            "SymmetricMaxSim" => Some(Self::SymmetricMaxSim),
             _ => None,
         }
     }
@@ -1197,6 +1312,9 @@ pub enum Distance {
     Euclid = 2,
     Dot = 3,
     Manhattan = 4,
+    Hamming = 5,
+    Jaccard = 6,
+    Minkowski = 7,
 }
 impl Distance {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -1210,6 +1328,9 @@ impl Distance {
             Distance::Euclid => "Euclid",
             Distance::Dot => "Dot",
             Distance::Manhattan => "Manhattan",
+            Distance::Hamming => "Hamming",
+            Distance::Jaccard => "Jaccard",
+            Distance::Minkowski => "Minkowski",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -1220,6 +1341,9 @@ impl Distance {
             "Euclid" => Some(Self::Euclid),
             "Dot" => Some(Self::Dot),
             "Manhattan" => Some(Self::Manhattan),
+            "Hamming" => Some(Self::Hamming),
+            "Jaccard" => Some(Self::Jaccard),
+            "Minkowski" => Some(Self::Minkowski),
             _ => None,
         }
     }
@@ -1315,6 +1439,7 @@ impl PayloadSchemaType {
 pub enum QuantizationType {
     UnknownQuantization = 0,
     Int8 = 1,
+    Int4 = 2,
 }
 impl QuantizationType {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -1325,6 +1450,7 @@ impl QuantizationType {
         match self {
             QuantizationType::UnknownQuantization => "UnknownQuantization",
             QuantizationType::Int8 => "Int8",
+            QuantizationType::Int4 => "Int4",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -1332,6 +1458,7 @@ impl QuantizationType {
         match value {
             "UnknownQuantization" => Some(Self::UnknownQuantization),
             "Int8" => Some(Self::Int8),
+            "Int4" => Some(Self::Int4),
             _ => None,
         }
     }
@@ -1375,6 +1502,72 @@ impl CompressionRatio {
 #[derive(serde::Serialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
+pub enum BinaryQuantizationEncoding {
+    /// Sign of each dimension, 1 bit per dimension
+    OneBit = 0,
+    /// Sign of each dimension plus a magnitude bit shared by each pair of dimensions
+    OneAndHalfBits = 1,
+    /// Sign and magnitude of each dimension, 2 bits per dimension
+    TwoBits = 2,
+}
+impl BinaryQuantizationEncoding {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            BinaryQuantizationEncoding::OneBit => "OneBit",
+            BinaryQuantizationEncoding::OneAndHalfBits => "OneAndHalfBits",
+            BinaryQuantizationEncoding::TwoBits => "TwoBits",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "OneBit" => Some(Self::OneBit),
+            "OneAndHalfBits" => Some(Self::OneAndHalfBits),
+            "TwoBits" => Some(Self::TwoBits),
+            _ => None,
+        }
+    }
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum BinaryQuantizationQueryEncoding {
+    /// Encode the query in the same way as stored vectors
+    Binary = 0,
+    /// Quantize the query into 4-bit integers
+    Scalar4Bits = 1,
+    /// Quantize the query into 8-bit integers
+    Scalar8Bits = 2,
+}
+impl BinaryQuantizationQueryEncoding {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            BinaryQuantizationQueryEncoding::Binary => "Binary",
+            BinaryQuantizationQueryEncoding::Scalar4Bits => "Scalar4Bits",
+            BinaryQuantizationQueryEncoding::Scalar8Bits => "Scalar8Bits",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "Binary" => Some(Self::Binary),
+            "Scalar4Bits" => Some(Self::Scalar4Bits),
+            "Scalar8Bits" => Some(Self::Scalar8Bits),
+            _ => None,
+        }
+    }
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
 pub enum ShardingMethod {
     /// Auto-sharding based on record ids
     Auto = 0,
@@ -1404,6 +1597,39 @@ impl ShardingMethod {
 #[derive(serde::Serialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
+pub enum PayloadSchemaEnforcement {
+    /// Values of unexpected type are stored, but not indexed
+    Disabled = 0,
+    /// Reject the operation if a value doesn't match the type of the indexed field
+    Reject = 1,
+    /// Convert the value into the type of the indexed field, reject the operation if not possible
+    Coerce = 2,
+}
+impl PayloadSchemaEnforcement {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            PayloadSchemaEnforcement::Disabled => "Disabled",
+            PayloadSchemaEnforcement::Reject => "Reject",
+            PayloadSchemaEnforcement::Coerce => "Coerce",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "Disabled" => Some(Self::Disabled),
+            "Reject" => Some(Self::Reject),
+            "Coerce" => Some(Self::Coerce),
+            _ => None,
+        }
+    }
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
 pub enum TokenizerType {
     Unknown = 0,
     Prefix = 1,
@@ -4208,6 +4434,57 @@ pub struct SearchBatchPoints {
     #[validate(custom = "crate::grpc::validate::validate_u64_range_min_1")]
     pub timeout: ::core::option::Option<u64>,
 }
+#[derive(validator::Validate)]
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SearchTextPoints {
+    /// Name of the collection
+    #[prost(string, tag = "1")]
+    #[validate(length(min = 1, max = 255))]
+    pub collection_name: ::prost::alloc::string::String,
+    /// Name of the sparse vector with configured BM25 vectorizer
+    #[prost(string, tag = "2")]
+    pub using: ::prost::alloc::string::String,
+    /// Look for points with text relevant to this query
+    #[prost(string, tag = "3")]
+    #[validate(length(min = 1))]
+    pub text: ::prost::alloc::string::String,
+    /// Filter conditions - return only those points that satisfy the specified conditions
+    #[prost(message, optional, tag = "4")]
+    #[validate]
+    pub filter: ::core::option::Option<Filter>,
+    /// Max number of result
+    #[prost(uint64, tag = "5")]
+    #[validate(range(min = 1))]
+    pub limit: u64,
+    /// Options for specifying which payload to include or not
+    #[prost(message, optional, tag = "6")]
+    pub with_payload: ::core::option::Option<WithPayloadSelector>,
+    /// Search config
+    #[prost(message, optional, tag = "7")]
+    #[validate]
+    pub params: ::core::option::Option<SearchParams>,
+    /// If provided - cut off results with worse scores
+    #[prost(float, optional, tag = "8")]
+    pub score_threshold: ::core::option::Option<f32>,
+    /// Offset of the result
+    #[prost(uint64, optional, tag = "9")]
+    pub offset: ::core::option::Option<u64>,
+    /// Options for specifying which vectors to include into response
+    #[prost(message, optional, tag = "10")]
+    pub with_vectors: ::core::option::Option<WithVectorsSelector>,
+    /// Options for specifying read consistency guarantees
+    #[prost(message, optional, tag = "11")]
+    pub read_consistency: ::core::option::Option<ReadConsistency>,
+    /// If set, overrides global timeout setting for this request. Unit is seconds.
+    #[prost(uint64, optional, tag = "12")]
+    #[validate(custom = "crate::grpc::validate::validate_u64_range_min_1")]
+    pub timeout: ::core::option::Option<u64>,
+    /// Specify in which shards to look for the points, if not specified - look in all shards
+    #[prost(message, optional, tag = "13")]
+    pub shard_key_selector: ::core::option::Option<ShardKeySelector>,
+}
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -4646,6 +4923,74 @@ pub struct DiscoverBatchPoints {
     #[validate(custom = "crate::grpc::validate::validate_u64_range_min_1")]
     pub timeout: ::core::option::Option<u64>,
 }
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WeightedVectorExample {
+    /// Id of a stored point or a raw vector
+    #[prost(message, optional, tag = "1")]
+    pub example: ::core::option::Option<VectorExample>,
+    /// Multiplier of the vector, negative weights subtract the vector from the query
+    #[prost(float, tag = "2")]
+    pub weight: f32,
+}
+#[derive(validator::Validate)]
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SearchArithmeticPoints {
+    /// Name of the collection
+    #[prost(string, tag = "1")]
+    #[validate(length(min = 1, max = 255))]
+    pub collection_name: ::prost::alloc::string::String,
+    /// Vectors to combine into the query, each multiplied by its weight
+    #[prost(message, repeated, tag = "2")]
+    #[validate(length(min = 1))]
+    pub terms: ::prost::alloc::vec::Vec<WeightedVectorExample>,
+    /// If true - divide the combination by the sum of weights, default - false
+    #[prost(bool, optional, tag = "3")]
+    pub average: ::core::option::Option<bool>,
+    /// Filter conditions - return only those points that satisfy the specified conditions
+    #[prost(message, optional, tag = "4")]
+    #[validate]
+    pub filter: ::core::option::Option<Filter>,
+    /// Max number of result
+    #[prost(uint64, tag = "5")]
+    #[validate(range(min = 1))]
+    pub limit: u64,
+    /// Options for specifying which payload to include or not
+    #[prost(message, optional, tag = "6")]
+    pub with_payload: ::core::option::Option<WithPayloadSelector>,
+    /// Search config
+    #[prost(message, optional, tag = "7")]
+    #[validate]
+    pub params: ::core::option::Option<SearchParams>,
+    /// If provided - cut off results with worse scores
+    #[prost(float, optional, tag = "8")]
+    pub score_threshold: ::core::option::Option<f32>,
+    /// Offset of the result
+    #[prost(uint64, optional, tag = "9")]
+    pub offset: ::core::option::Option<u64>,
+    /// Define which vector to search in, if not specified - default vector
+    #[prost(string, optional, tag = "10")]
+    pub using: ::core::option::Option<::prost::alloc::string::String>,
+    /// Options for specifying which vectors to include into response
+    #[prost(message, optional, tag = "11")]
+    pub with_vectors: ::core::option::Option<WithVectorsSelector>,
+    /// Name of the collection to use for points lookup, if not specified - use current collection
+    #[prost(message, optional, tag = "12")]
+    pub lookup_from: ::core::option::Option<LookupLocation>,
+    /// Options for specifying read consistency guarantees
+    #[prost(message, optional, tag = "13")]
+    pub read_consistency: ::core::option::Option<ReadConsistency>,
+    /// If set, overrides global timeout setting for this request. Unit is seconds.
+    #[prost(uint64, optional, tag = "14")]
+    #[validate(custom = "crate::grpc::validate::validate_u64_range_min_1")]
+    pub timeout: ::core::option::Option<u64>,
+    /// Specify in which shards to look for the points, if not specified - look in all shards
+    #[prost(message, optional, tag = "15")]
+    pub shard_key_selector: ::core::option::Option<ShardKeySelector>,
+}
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -4669,6 +5014,24 @@ pub struct CountPoints {
     #[prost(message, optional, tag = "5")]
     pub shard_key_selector: ::core::option::Option<ShardKeySelector>,
 }
+#[derive(validator::Validate)]
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PayloadStatsPoints {
+    /// Name of the collection
+    #[prost(string, tag = "1")]
+    #[validate(length(min = 1, max = 255))]
+    pub collection_name: ::prost::alloc::string::String,
+    /// Maximum number of points to sample from each shard. Default: 1000
+    #[prost(uint64, optional, tag = "2")]
+    #[validate(custom = "crate::grpc::validate::validate_u64_range_min_1_max_100000")]
+    pub limit: ::core::option::Option<u64>,
+    /// Only sample points which satisfy this conditions
+    #[prost(message, optional, tag = "3")]
+    #[validate]
+    pub filter: ::core::option::Option<Filter>,
+}
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -4975,6 +5338,16 @@ pub struct CountResponse {
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PayloadStatsResponse {
+    #[prost(message, optional, tag = "1")]
+    pub result: ::core::option::Option<PayloadStatsResult>,
+    /// Time spent to process
+    #[prost(double, tag = "2")]
+    pub time: f64,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ScrollResponse {
     /// Use this offset for the next query
     #[prost(message, optional, tag = "1")]
@@ -4995,6 +5368,50 @@ pub struct CountResult {
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PayloadValueTypeCount {
+    #[prost(enumeration = "PayloadValueType", tag = "1")]
+    pub value_type: i32,
+    /// Number of observed values of this type
+    #[prost(uint64, tag = "2")]
+    pub count: u64,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PayloadFieldStats {
+    /// Number of observed values of each type
+    #[prost(message, repeated, tag = "1")]
+    pub types: ::prost::alloc::vec::Vec<PayloadValueTypeCount>,
+    /// Fraction of sampled points which have this field
+    #[prost(double, tag = "2")]
+    pub fill_rate: f64,
+    /// Estimated number of distinct values
+    #[prost(uint64, tag = "3")]
+    pub distinct_values: u64,
+    /// Whether there is a payload index for this field
+    #[prost(bool, tag = "4")]
+    pub indexed: bool,
+    /// Type of payload index which could be created for this field, if it is not indexed yet
+    #[prost(enumeration = "FieldType", optional, tag = "5")]
+    pub suggested_index: ::core::option::Option<i32>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PayloadStatsResult {
+    /// Number of points which payloads were sampled
+    #[prost(uint64, tag = "1")]
+    pub points_sampled: u64,
+    /// Statistics for each JSON path found in the sampled payloads
+    #[prost(map = "string, message", tag = "2")]
+    pub fields: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        PayloadFieldStats,
+    >,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RetrievedPoint {
     #[prost(message, optional, tag = "1")]
     pub id: ::core::option::Option<PointId>,
@@ -5111,7 +5528,7 @@ pub struct MinShould {
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Condition {
-    #[prost(oneof = "condition::ConditionOneOf", tags = "1, 2, 3, 4, 5, 6")]
+    #[prost(oneof = "condition::ConditionOneOf", tags = "1, 2, 3, 4, 5, 6, 7")]
     #[validate]
     pub condition_one_of: ::core::option::Option<condition::ConditionOneOf>,
 }
@@ -5133,6 +5550,8 @@ pub mod condition {
         IsNull(super::IsNullCondition),
         #[prost(message, tag = "6")]
         Nested(super::NestedCondition),
+        #[prost(message, tag = "7")]
+        Compare(super::FieldsComparisonCondition),
     }
 }
 #[derive(serde::Serialize)]
@@ -5172,6 +5591,20 @@ pub struct NestedCondition {
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FieldsComparisonCondition {
+    /// Payload key of the left-hand side value
+    #[prost(string, tag = "1")]
+    pub left: ::prost::alloc::string::String,
+    /// How to compare the left value with the right one
+    #[prost(enumeration = "ComparisonOperator", tag = "2")]
+    pub op: i32,
+    /// Payload key of the right-hand side value
+    #[prost(string, tag = "3")]
+    pub right: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct FieldCondition {
     #[prost(string, tag = "1")]
     pub key: ::prost::alloc::string::String,
@@ -5525,28 +5958,78 @@ impl Direction {
         }
     }
 }
-/// How to use positive and negative vectors to find the results, default is `AverageVector`:
 #[derive(serde::Serialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
-pub enum RecommendStrategy {
-    /// Average positive and negative vectors and create a single query with the formula
-    /// `query = avg_pos + avg_pos - avg_neg`. Then performs normal search.
-    AverageVector = 0,
-    /// Uses custom search objective. Each candidate is compared against all
-    /// examples, its score is then chosen from the `max(max_pos_score, max_neg_score)`.
-    /// If the `max_neg_score` is chosen then it is squared and negated.
-    BestScore = 1,
-}
-impl RecommendStrategy {
+pub enum ComparisonOperator {
+    /// left < right
+    Lt = 0,
+    /// left <= right
+    Lte = 1,
+    /// left > right
+    Gt = 2,
+    /// left >= right
+    Gte = 3,
+    /// left == right
+    Eq = 4,
+    /// left != right
+    Ne = 5,
+}
+impl ComparisonOperator {
     /// String value of the enum field names used in the ProtoBuf definition.
     ///
     /// The values are not transformed in any way and thus are considered stable
     /// (if the ProtoBuf definition does not change) and safe for programmatic use.
     pub fn as_str_name(&self) -> &'static str {
         match self {
-            RecommendStrategy::AverageVector => "AverageVector",
-            RecommendStrategy::BestScore => "BestScore",
+            ComparisonOperator::Lt => "Lt",
+            ComparisonOperator::Lte => "Lte",
+            ComparisonOperator::Gt => "Gt",
+            ComparisonOperator::Gte => "Gte",
+            ComparisonOperator::Eq => "Eq",
+            ComparisonOperator::Ne => "Ne",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "Lt" => Some(Self::Lt),
+            "Lte" => Some(Self::Lte),
+            "Gt" => Some(Self::Gt),
+            "Gte" => Some(Self::Gte),
+            "Eq" => Some(Self::Eq),
+            "Ne" => Some(Self::Ne),
+            _ => None,
+        }
+    }
+}
+/// How to use positive and negative vectors to find the results, default is `AverageVector`:
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum RecommendStrategy {
+    /// Average positive and negative vectors and create a single query with the formula
+    /// `query = avg_pos + avg_pos - avg_neg`. Then performs normal search.
+    AverageVector = 0,
+    /// Uses custom search objective. Each candidate is compared against all
+    /// examples, its score is then chosen from the `max(max_pos_score, max_neg_score)`.
+    /// If the `max_neg_score` is chosen then it is squared and negated.
+    BestScore = 1,
+    /// Uses custom search objective. Each candidate is compared against all
+    /// examples, its score is the sum of all its positive scores minus the sum
+    /// of all its negative scores.
+    SumScores = 2,
+}
+impl RecommendStrategy {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            RecommendStrategy::AverageVector => "AverageVector",
+            RecommendStrategy::BestScore => "BestScore",
+            RecommendStrategy::SumScores => "SumScores",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -5554,6 +6037,7 @@ impl RecommendStrategy {
         match value {
             "AverageVector" => Some(Self::AverageVector),
             "BestScore" => Some(Self::BestScore),
+            "SumScores" => Some(Self::SumScores),
             _ => None,
         }
     }
@@ -5594,6 +6078,48 @@ impl UpdateStatus {
         }
     }
 }
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum PayloadValueType {
+    Keyword = 0,
+    Integer = 1,
+    Float = 2,
+    Bool = 3,
+    Geo = 4,
+    Datetime = 5,
+    Null = 6,
+}
+impl PayloadValueType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            PayloadValueType::Keyword => "PayloadValueTypeKeyword",
+            PayloadValueType::Integer => "PayloadValueTypeInteger",
+            PayloadValueType::Float => "PayloadValueTypeFloat",
+            PayloadValueType::Bool => "PayloadValueTypeBool",
+            PayloadValueType::Geo => "PayloadValueTypeGeo",
+            PayloadValueType::Datetime => "PayloadValueTypeDatetime",
+            PayloadValueType::Null => "PayloadValueTypeNull",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "PayloadValueTypeKeyword" => Some(Self::Keyword),
+            "PayloadValueTypeInteger" => Some(Self::Integer),
+            "PayloadValueTypeFloat" => Some(Self::Float),
+            "PayloadValueTypeBool" => Some(Self::Bool),
+            "PayloadValueTypeGeo" => Some(Self::Geo),
+            "PayloadValueTypeDatetime" => Some(Self::Datetime),
+            "PayloadValueTypeNull" => Some(Self::Null),
+            _ => None,
+        }
+    }
+}
 /// Generated client implementations.
 pub mod points_client {
     #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
@@ -6036,6 +6562,52 @@ pub mod points_client {
             self.inner.unary(req, path, codec).await
         }
         ///
+        /// Retrieve points with text most relevant to the query, using BM25 vectorizer of the sparse vector
+        pub async fn search_text(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SearchTextPoints>,
+        ) -> std::result::Result<tonic::Response<super::SearchResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/qdrant.Points/SearchText",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("qdrant.Points", "SearchText"));
+            self.inner.unary(req, path, codec).await
+        }
+        ///
+        /// Retrieve points closest to a weighted combination of vectors, given by ids of stored points or raw vectors
+        pub async fn search_arithmetic(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SearchArithmeticPoints>,
+        ) -> std::result::Result<tonic::Response<super::SearchResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/qdrant.Points/SearchArithmetic",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("qdrant.Points", "SearchArithmetic"));
+            self.inner.unary(req, path, codec).await
+        }
+        ///
         /// Iterate over all or filtered points
         pub async fn scroll(
             &mut self,
@@ -6221,6 +6793,33 @@ pub mod points_client {
             self.inner.unary(req, path, codec).await
         }
         ///
+        /// Sample payloads of points and collect statistics of the present fields
+        pub async fn payload_stats(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PayloadStatsPoints>,
+        ) -> std::result::Result<
+            tonic::Response<super::PayloadStatsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/qdrant.Points/PayloadStats",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.Points", "PayloadStats"));
+            self.inner.unary(req, path, codec).await
+        }
+        ///
         /// Perform multiple update operations in one request
         pub async fn update_batch(
             &mut self,
@@ -6376,6 +6975,18 @@ pub mod points_server {
             tonic::Status,
         >;
         ///
+        /// Retrieve points with text most relevant to the query, using BM25 vectorizer of the sparse vector
+        async fn search_text(
+            &self,
+            request: tonic::Request<super::SearchTextPoints>,
+        ) -> std::result::Result<tonic::Response<super::SearchResponse>, tonic::Status>;
+        ///
+        /// Retrieve points closest to a weighted combination of vectors, given by ids of stored points or raw vectors
+        async fn search_arithmetic(
+            &self,
+            request: tonic::Request<super::SearchArithmeticPoints>,
+        ) -> std::result::Result<tonic::Response<super::SearchResponse>, tonic::Status>;
+        ///
         /// Iterate over all or filtered points
         async fn scroll(
             &self,
@@ -6447,6 +7058,15 @@ pub mod points_server {
             request: tonic::Request<super::CountPoints>,
         ) -> std::result::Result<tonic::Response<super::CountResponse>, tonic::Status>;
         ///
+        /// Sample payloads of points and collect statistics of the present fields
+        async fn payload_stats(
+            &self,
+            request: tonic::Request<super::PayloadStatsPoints>,
+        ) -> std::result::Result<
+            tonic::Response<super::PayloadStatsResponse>,
+            tonic::Status,
+        >;
+        ///
         /// Perform multiple update operations in one request
         async fn update_batch(
             &self,
@@ -7163,6 +7783,94 @@ pub mod points_server {
                     };
                     Box::pin(fut)
                 }
+                "/qdrant.Points/SearchText" => {
+                    #[allow(non_camel_case_types)]
+                    struct SearchTextSvc<T: Points>(pub Arc<T>);
+                    impl<T: Points> tonic::server::UnaryService<super::SearchTextPoints>
+                    for SearchTextSvc<T> {
+                        type Response = super::SearchResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SearchTextPoints>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Points>::search_text(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = SearchTextSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/qdrant.Points/SearchArithmetic" => {
+                    #[allow(non_camel_case_types)]
+                    struct SearchArithmeticSvc<T: Points>(pub Arc<T>);
+                    impl<T: Points> tonic::server::UnaryService<super::SearchArithmeticPoints>
+                    for SearchArithmeticSvc<T> {
+                        type Response = super::SearchResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SearchArithmeticPoints>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Points>::search_arithmetic(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = SearchArithmeticSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/qdrant.Points/Scroll" => {
                     #[allow(non_camel_case_types)]
                     struct ScrollSvc<T: Points>(pub Arc<T>);
@@ -7477,6 +8185,52 @@ pub mod points_server {
                     };
                     Box::pin(fut)
                 }
+                "/qdrant.Points/PayloadStats" => {
+                    #[allow(non_camel_case_types)]
+                    struct PayloadStatsSvc<T: Points>(pub Arc<T>);
+                    impl<
+                        T: Points,
+                    > tonic::server::UnaryService<super::PayloadStatsPoints>
+                    for PayloadStatsSvc<T> {
+                        type Response = super::PayloadStatsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PayloadStatsPoints>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Points>::payload_stats(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = PayloadStatsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/qdrant.Points/UpdateBatch" => {
                     #[allow(non_camel_case_types)]
                     struct UpdateBatchSvc<T: Points>(pub Arc<T>);
@@ -7835,7 +8589,7 @@ pub struct ContextQuery {
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryEnum {
-    #[prost(oneof = "query_enum::Query", tags = "1, 2, 3, 4")]
+    #[prost(oneof = "query_enum::Query", tags = "1, 2, 3, 4, 5")]
     pub query: ::core::option::Option<query_enum::Query>,
 }
 /// Nested message and enum types in `QueryEnum`.
@@ -7856,6 +8610,9 @@ pub mod query_enum {
         /// Use only the context to find points that minimize loss against negative examples
         #[prost(message, tag = "4")]
         Context(super::ContextQuery),
+        /// Recommend points by the sum of similarities to positive examples minus the sum of similarities to negative examples
+        #[prost(message, tag = "5")]
+        RecommendSumScores(super::RecoQuery),
     }
 }
 /// This is only used internally, so it makes more sense to add it here rather than in points.proto
@@ -7906,6 +8663,9 @@ pub struct CoreSearchBatchPointsInternal {
     pub shard_id: ::core::option::Option<u32>,
     #[prost(uint64, optional, tag = "4")]
     pub timeout: ::core::option::Option<u64>,
+    /// Statistics collected over all shards, local ones are used if not set
+    #[prost(message, optional, tag = "5")]
+    pub idf_statistics: ::core::option::Option<IdfStatistics>,
 }
 #[derive(serde::Serialize)]
 #[derive(validator::Validate)]
@@ -7951,25 +8711,172 @@ pub struct CountPointsInternal {
     #[prost(uint32, optional, tag = "2")]
     pub shard_id: ::core::option::Option<u32>,
 }
-/// Generated client implementations.
-pub mod points_internal_client {
-    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
-    use tonic::codegen::*;
-    use tonic::codegen::http::Uri;
-    #[derive(Debug, Clone)]
-    pub struct PointsInternalClient<T> {
-        inner: tonic::client::Grpc<T>,
-    }
-    impl PointsInternalClient<tonic::transport::Channel> {
-        /// Attempt to create a new client by connecting to a given endpoint.
-        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
-        where
-            D: TryInto<tonic::transport::Endpoint>,
-            D::Error: Into<StdError>,
-        {
-            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
-            Ok(Self::new(conn))
-        }
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IdfVectorIndices {
+    #[prost(string, tag = "1")]
+    pub vector_name: ::prost::alloc::string::String,
+    #[prost(uint32, repeated, tag = "2")]
+    pub indices: ::prost::alloc::vec::Vec<u32>,
+}
+#[derive(serde::Serialize)]
+#[derive(validator::Validate)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IdfStatisticsInternal {
+    #[prost(string, tag = "1")]
+    #[validate(length(min = 1, max = 255))]
+    pub collection_name: ::prost::alloc::string::String,
+    /// Sparse dimensions to collect document frequencies of
+    #[prost(message, repeated, tag = "2")]
+    pub vectors: ::prost::alloc::vec::Vec<IdfVectorIndices>,
+    #[prost(uint32, optional, tag = "3")]
+    pub shard_id: ::core::option::Option<u32>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IdfDocumentFrequencies {
+    #[prost(string, tag = "1")]
+    pub vector_name: ::prost::alloc::string::String,
+    #[prost(uint32, repeated, tag = "2")]
+    pub indices: ::prost::alloc::vec::Vec<u32>,
+    /// Number of points containing the dimension at the same position in `indices`
+    #[prost(uint64, repeated, tag = "3")]
+    pub frequencies: ::prost::alloc::vec::Vec<u64>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IdfStatistics {
+    /// Number of points, over which document frequencies were collected
+    #[prost(uint64, tag = "1")]
+    pub point_count: u64,
+    #[prost(message, repeated, tag = "2")]
+    pub vectors: ::prost::alloc::vec::Vec<IdfDocumentFrequencies>,
+    /// Version of every shard, over which statistics were collected
+    #[prost(map = "uint32, uint64", tag = "3")]
+    pub shard_versions: ::std::collections::HashMap<u32, u64>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IdfStatisticsResponse {
+    #[prost(message, optional, tag = "1")]
+    pub result: ::core::option::Option<IdfStatistics>,
+    /// Time spent to process
+    #[prost(double, tag = "2")]
+    pub time: f64,
+}
+#[derive(serde::Serialize)]
+#[derive(validator::Validate)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PayloadStatsInternal {
+    #[prost(message, optional, tag = "1")]
+    #[validate]
+    pub payload_stats_points: ::core::option::Option<PayloadStatsPoints>,
+    #[prost(uint32, optional, tag = "2")]
+    pub shard_id: ::core::option::Option<u32>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PayloadFieldSketch {
+    /// JSON path of the field
+    #[prost(string, tag = "1")]
+    pub path: ::prost::alloc::string::String,
+    /// Number of sampled points which have this field
+    #[prost(uint64, tag = "2")]
+    pub points: u64,
+    /// Number of observed values of each type
+    #[prost(message, repeated, tag = "3")]
+    pub types: ::prost::alloc::vec::Vec<PayloadValueTypeCount>,
+    /// HyperLogLog registers of the field values
+    #[prost(bytes = "vec", tag = "4")]
+    pub distinct_registers: ::prost::alloc::vec::Vec<u8>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PayloadStatsPartial {
+    /// Number of points which payloads were sampled
+    #[prost(uint64, tag = "1")]
+    pub points_sampled: u64,
+    #[prost(message, repeated, tag = "2")]
+    pub fields: ::prost::alloc::vec::Vec<PayloadFieldSketch>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PayloadStatsInternalResponse {
+    #[prost(message, optional, tag = "1")]
+    pub result: ::core::option::Option<PayloadStatsPartial>,
+    /// Time spent to process
+    #[prost(double, tag = "2")]
+    pub time: f64,
+}
+#[derive(serde::Serialize)]
+#[derive(validator::Validate)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SparseStatsInternal {
+    #[prost(string, tag = "1")]
+    #[validate(length(min = 1, max = 255))]
+    pub collection_name: ::prost::alloc::string::String,
+    /// Name of the sparse vector to report, all sparse vectors if not set
+    #[prost(string, optional, tag = "2")]
+    pub using: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint32, optional, tag = "3")]
+    pub shard_id: ::core::option::Option<u32>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SparseIndexStatistics {
+    #[prost(string, tag = "1")]
+    pub vector_name: ::prost::alloc::string::String,
+    /// Number of vectors in the index
+    #[prost(uint64, tag = "2")]
+    pub indexed_vectors: u64,
+    #[prost(uint64, tag = "3")]
+    pub ram_usage_bytes: u64,
+    #[prost(uint64, tag = "4")]
+    pub disk_usage_bytes: u64,
+    /// Number of indexed vectors containing each dimension
+    #[prost(map = "uint32, uint64", tag = "5")]
+    pub document_frequencies: ::std::collections::HashMap<u32, u64>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SparseStatsInternalResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub result: ::prost::alloc::vec::Vec<SparseIndexStatistics>,
+    /// Time spent to process
+    #[prost(double, tag = "2")]
+    pub time: f64,
+}
+/// Generated client implementations.
+pub mod points_internal_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    #[derive(Debug, Clone)]
+    pub struct PointsInternalClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl PointsInternalClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
     }
     impl<T> PointsInternalClient<T>
     where
@@ -8426,6 +9333,81 @@ pub mod points_internal_client {
             req.extensions_mut().insert(GrpcMethod::new("qdrant.PointsInternal", "Get"));
             self.inner.unary(req, path, codec).await
         }
+        pub async fn idf_statistics(
+            &mut self,
+            request: impl tonic::IntoRequest<super::IdfStatisticsInternal>,
+        ) -> std::result::Result<
+            tonic::Response<super::IdfStatisticsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/qdrant.PointsInternal/IdfStatistics",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.PointsInternal", "IdfStatistics"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn payload_stats(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PayloadStatsInternal>,
+        ) -> std::result::Result<
+            tonic::Response<super::PayloadStatsInternalResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/qdrant.PointsInternal/PayloadStats",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.PointsInternal", "PayloadStats"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn sparse_stats(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SparseStatsInternal>,
+        ) -> std::result::Result<
+            tonic::Response<super::SparseStatsInternalResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/qdrant.PointsInternal/SparseStats",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.PointsInternal", "SparseStats"));
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -8538,6 +9520,27 @@ pub mod points_internal_server {
             &self,
             request: tonic::Request<super::GetPointsInternal>,
         ) -> std::result::Result<tonic::Response<super::GetResponse>, tonic::Status>;
+        async fn idf_statistics(
+            &self,
+            request: tonic::Request<super::IdfStatisticsInternal>,
+        ) -> std::result::Result<
+            tonic::Response<super::IdfStatisticsResponse>,
+            tonic::Status,
+        >;
+        async fn payload_stats(
+            &self,
+            request: tonic::Request<super::PayloadStatsInternal>,
+        ) -> std::result::Result<
+            tonic::Response<super::PayloadStatsInternalResponse>,
+            tonic::Status,
+        >;
+        async fn sparse_stats(
+            &self,
+            request: tonic::Request<super::SparseStatsInternal>,
+        ) -> std::result::Result<
+            tonic::Response<super::SparseStatsInternalResponse>,
+            tonic::Status,
+        >;
     }
     #[derive(Debug)]
     pub struct PointsInternalServer<T: PointsInternal> {
@@ -9364,6 +10367,144 @@ pub mod points_internal_server {
                     };
                     Box::pin(fut)
                 }
+                "/qdrant.PointsInternal/IdfStatistics" => {
+                    #[allow(non_camel_case_types)]
+                    struct IdfStatisticsSvc<T: PointsInternal>(pub Arc<T>);
+                    impl<
+                        T: PointsInternal,
+                    > tonic::server::UnaryService<super::IdfStatisticsInternal>
+                    for IdfStatisticsSvc<T> {
+                        type Response = super::IdfStatisticsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::IdfStatisticsInternal>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PointsInternal>::idf_statistics(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = IdfStatisticsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/qdrant.PointsInternal/PayloadStats" => {
+                    #[allow(non_camel_case_types)]
+                    struct PayloadStatsSvc<T: PointsInternal>(pub Arc<T>);
+                    impl<
+                        T: PointsInternal,
+                    > tonic::server::UnaryService<super::PayloadStatsInternal>
+                    for PayloadStatsSvc<T> {
+                        type Response = super::PayloadStatsInternalResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PayloadStatsInternal>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PointsInternal>::payload_stats(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = PayloadStatsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/qdrant.PointsInternal/SparseStats" => {
+                    #[allow(non_camel_case_types)]
+                    struct SparseStatsSvc<T: PointsInternal>(pub Arc<T>);
+                    impl<
+                        T: PointsInternal,
+                    > tonic::server::UnaryService<super::SparseStatsInternal>
+                    for SparseStatsSvc<T> {
+                        type Response = super::SparseStatsInternalResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SparseStatsInternal>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PointsInternal>::sparse_stats(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = SparseStatsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
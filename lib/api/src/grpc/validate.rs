@@ -188,6 +188,7 @@ impl Validate for grpc::condition::ConditionOneOf {
             ConditionOneOf::IsEmpty(_) => Ok(()),
             ConditionOneOf::HasId(_) => Ok(()),
             ConditionOneOf::IsNull(_) => Ok(()),
+            ConditionOneOf::Compare(_) => Ok(()),
         }
     }
 }
@@ -265,6 +266,7 @@ impl Validate for super::qdrant::query_enum::Query {
             super::qdrant::query_enum::Query::RecommendBestScore(q) => q.validate(),
             super::qdrant::query_enum::Query::Discover(q) => q.validate(),
             super::qdrant::query_enum::Query::Context(q) => q.validate(),
+            super::qdrant::query_enum::Query::RecommendSumScores(q) => q.validate(),
         }
     }
 }
@@ -299,6 +301,11 @@ pub fn validate_u64_range_min_4_max_10000(value: &Option<u64>) -> Result<(), Val
     value.map_or(Ok(()), |v| validate_range_generic(v, Some(4), Some(10_000)))
 }
 
+/// Validate the value is in `[1, 100000]` or `None`.
+pub fn validate_u64_range_min_1_max_100000(value: &Option<u64>) -> Result<(), ValidationError> {
+    value.map_or(Ok(()), |v| validate_range_generic(v, Some(1), Some(100_000)))
+}
+
 /// Validate the value is in `[0.5, 1.0]` or `None`.
 pub fn validate_f32_range_min_0_5_max_1(value: &Option<f32>) -> Result<(), ValidationError> {
     value.map_or(Ok(()), |v| validate_range_generic(v, Some(0.5), Some(1.0)))
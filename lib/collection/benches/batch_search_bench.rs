@@ -144,6 +144,7 @@ fn batch_search_bench(c: &mut Criterion) {
                             .core_search(
                                 Arc::new(CoreSearchRequestBatch {
                                     searches: vec![search_query.into()],
+                                    idf_statistics: None,
                                 }),
                                 search_runtime_handle,
                                 None,
@@ -176,7 +177,10 @@ fn batch_search_bench(c: &mut Criterion) {
                         searches.push(search_query.into());
                     }
 
-                    let search_query = CoreSearchRequestBatch { searches };
+                    let search_query = CoreSearchRequestBatch {
+                        searches,
+                        idf_statistics: None,
+                    };
                     let result = shard
                         .core_search(Arc::new(search_query), search_runtime_handle, None)
                         .await
@@ -0,0 +1,298 @@
+use std::future::Future;
+use std::iter;
+use std::time::Duration;
+
+use segment::data_types::vectors::{
+    DenseVector, NamedVectorStruct, Vector, VectorElementType, VectorRef, DEFAULT_VECTOR_NAME,
+};
+use segment::types::{Condition, Filter, HasIdCondition, ScoredPoint};
+use sparse::common::sparse_vector::SparseVector;
+use tokio::sync::RwLockReadGuard;
+
+use crate::collection::Collection;
+use crate::common::fetch_vectors::{
+    convert_to_vectors, resolve_referenced_vectors_batch, ReferencedVectors,
+};
+use crate::common::retrieve_request_trait::RetrieveRequest;
+use crate::operations::consistency_params::ReadConsistency;
+use crate::operations::query_enum::QueryEnum;
+use crate::operations::shard_selector_internal::ShardSelectorInternal;
+use crate::operations::types::{
+    ArithmeticRequestInternal, CollectionError, CollectionResult, CoreSearchRequest,
+    CoreSearchRequestBatch, UsingVector,
+};
+
+/// Sum of vectors multiplied by their weights.
+/// If `average` is set, the sum is divided by the sum of weights.
+/// All dense vectors are required to have the same dimension.
+fn combine_vectors<'a>(
+    terms: impl Iterator<Item = (VectorRef<'a>, f32)>,
+    average: bool,
+) -> CollectionResult<Vector> {
+    let mut dense = DenseVector::default();
+    let mut sparse = SparseVector::default();
+    let mut dense_count = 0;
+    let mut sparse_count = 0;
+    let mut weights_sum = 0.0;
+    for (vector, weight) in terms {
+        weights_sum += weight;
+        match vector {
+            VectorRef::Dense(vector) => {
+                dense_count += 1;
+                if dense_count == 1 {
+                    dense.resize(vector.len(), 0.0);
+                } else if dense.len() != vector.len() {
+                    return Err(CollectionError::bad_input(format!(
+                        "Vectors of different dimensions can't be combined: {} and {}",
+                        dense.len(),
+                        vector.len(),
+                    )));
+                }
+                for (acc, value) in dense.iter_mut().zip(vector) {
+                    *acc += value * weight;
+                }
+            }
+            VectorRef::Sparse(vector) => {
+                sparse_count += 1;
+                sparse = sparse.combine_aggregate(vector, |acc, value| acc + value * weight);
+            }
+            VectorRef::MultiDense(_) => {
+                // TODO(colbert)
+                return Err(CollectionError::bad_input(
+                    "MultiDenseVector is not supported".to_owned(),
+                ));
+            }
+        }
+    }
+
+    let divider = if average {
+        if weights_sum == 0.0 {
+            return Err(CollectionError::bad_input(
+                "Sum of weights should not be zero to compute an average".to_owned(),
+            ));
+        }
+        weights_sum
+    } else {
+        1.0
+    };
+
+    match (dense_count, sparse_count) {
+        (0, 0) => Err(CollectionError::bad_input(
+            "At least one term is required".to_owned(),
+        )),
+        (_, 0) => {
+            for item in &mut dense {
+                *item /= divider as VectorElementType;
+            }
+            Ok(dense.into())
+        }
+        (0, _) => {
+            for item in &mut sparse.values {
+                *item /= divider;
+            }
+            Ok(sparse.into())
+        }
+        (_, _) => Err(CollectionError::bad_input(
+            "Can't combine dense and sparse vectors together".to_owned(),
+        )),
+    }
+}
+
+fn arithmetic_into_core_search(
+    request: ArithmeticRequestInternal,
+    all_vectors_records_map: &ReferencedVectors,
+) -> CollectionResult<CoreSearchRequest> {
+    let lookup_collection_name = request.lookup_from.as_ref().map(|x| &x.collection);
+
+    let lookup_vector_name = request.get_search_vector_name();
+
+    // Check we actually fetched all referenced vectors in this request
+    let referenced_ids = request.get_referenced_point_ids();
+
+    for &point_id in &referenced_ids {
+        if all_vectors_records_map
+            .get(&lookup_collection_name, point_id)
+            .is_none()
+        {
+            return Err(CollectionError::PointNotFound {
+                missed_point_id: point_id,
+            });
+        }
+    }
+
+    let terms = request
+        .terms
+        .iter()
+        .map(|term| {
+            let vector = convert_to_vectors(
+                iter::once(&term.example),
+                all_vectors_records_map,
+                &lookup_vector_name,
+                lookup_collection_name,
+            )
+            .next()
+            .ok_or_else(|| {
+                CollectionError::bad_input(format!(
+                    "Vector {lookup_vector_name} is not found for one of the terms"
+                ))
+            })?;
+            Ok((vector, term.weight))
+        })
+        .collect::<CollectionResult<Vec<_>>>()?;
+
+    let search_vector = combine_vectors(terms.into_iter(), request.average.unwrap_or_default())?;
+
+    let vector_name = match request.using {
+        None => DEFAULT_VECTOR_NAME.to_string(),
+        Some(UsingVector::Name(name)) => name,
+    };
+
+    let filter = {
+        let not_ids = Filter::new_must_not(Condition::HasId(HasIdCondition {
+            has_id: referenced_ids.into_iter().collect(),
+        }));
+
+        match &request.filter {
+            None => not_ids,
+            Some(filter) => not_ids.merge(filter),
+        }
+    };
+
+    Ok(CoreSearchRequest {
+        query: QueryEnum::Nearest(NamedVectorStruct::new_from_vector(
+            search_vector,
+            vector_name,
+        )),
+        filter: Some(filter),
+        params: request.params,
+        limit: request.limit,
+        offset: request.offset.unwrap_or_default(),
+        with_payload: request.with_payload,
+        with_vector: request.with_vector,
+        score_threshold: request.score_threshold,
+    })
+}
+
+/// Search points closest to a weighted combination of vectors.
+///
+/// Ids of the terms are resolved into vectors of this or another collection,
+/// the same way as examples of recommendation requests.
+pub async fn search_arithmetic<'a, F, Fut>(
+    request: ArithmeticRequestInternal,
+    collection: &Collection,
+    collection_by_name: F,
+    read_consistency: Option<ReadConsistency>,
+    shard_selector: ShardSelectorInternal,
+    timeout: Option<Duration>,
+) -> CollectionResult<Vec<ScoredPoint>>
+where
+    F: Fn(String) -> Fut,
+    Fut: Future<Output = Option<RwLockReadGuard<'a, Collection>>>,
+{
+    if request.limit == 0 {
+        return Ok(vec![]);
+    }
+
+    if request.terms.is_empty() {
+        return Err(CollectionError::bad_request(
+            "At least one term is required".to_string(),
+        ));
+    }
+
+    let request_batch = vec![(request, shard_selector)];
+
+    let all_vectors_records_map = resolve_referenced_vectors_batch(
+        &request_batch,
+        collection,
+        collection_by_name,
+        read_consistency,
+    )
+    .await?;
+
+    let (request, shard_selector) = request_batch.into_iter().next().unwrap();
+    let core_search = arithmetic_into_core_search(request, &all_vectors_records_map)?;
+
+    let core_search_batch_request = CoreSearchRequestBatch {
+        searches: vec![core_search],
+        idf_statistics: None,
+    };
+
+    let results = collection
+        .core_search_batch(
+            core_search_batch_request,
+            read_consistency,
+            shard_selector,
+            timeout,
+        )
+        .await?;
+    Ok(results.into_iter().next().unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use segment::data_types::vectors::{Vector, VectorRef};
+    use sparse::common::sparse_vector::SparseVector;
+
+    use super::combine_vectors;
+
+    #[test]
+    fn test_combine_vectors() {
+        let vectors: Vec<Vector> = vec![
+            vec![1.0, 2.0, 3.0].into(),
+            vec![0.0, 1.0, 1.0].into(),
+            vec![2.0, 0.0, 0.0].into(),
+        ];
+        let weights = [1.0, -1.0, 0.5];
+        let terms = || {
+            vectors
+                .iter()
+                .map(VectorRef::from)
+                .zip(weights.iter().copied())
+        };
+
+        // A - B + 0.5 * C
+        assert_eq!(
+            combine_vectors(terms(), false).unwrap(),
+            vec![2.0, 1.0, 2.0].into(),
+        );
+        // Same, divided by the sum of weights
+        assert_eq!(
+            combine_vectors(terms(), true).unwrap(),
+            vec![4.0, 2.0, 4.0].into(),
+        );
+
+        let vectors: Vec<Vector> = vec![
+            SparseVector::new(vec![0, 1], vec![1.0, 2.0])
+                .unwrap()
+                .into(),
+            SparseVector::new(vec![1, 2], vec![1.0, 1.0])
+                .unwrap()
+                .into(),
+        ];
+        let terms = vectors.iter().map(VectorRef::from).zip([2.0, -1.0]);
+        assert_eq!(
+            combine_vectors(terms, false).unwrap(),
+            SparseVector::new(vec![0, 1, 2], vec![2.0, 3.0, -1.0])
+                .unwrap()
+                .into(),
+        );
+
+        // Weights cancel each other out, average is undefined
+        let terms = vectors.iter().map(VectorRef::from).zip([1.0, -1.0]);
+        assert!(combine_vectors(terms, true).is_err());
+
+        let vectors: Vec<Vector> = vec![
+            vec![1.0, 2.0, 3.0].into(),
+            SparseVector::new(vec![0, 1, 2], vec![0.0, 0.1, 0.2])
+                .unwrap()
+                .into(),
+        ];
+        let terms = vectors.iter().map(VectorRef::from).zip([1.0, 1.0]);
+        assert!(combine_vectors(terms, false).is_err());
+
+        // Dense vectors of different dimensions
+        let vectors: Vec<Vector> = vec![vec![1.0, 2.0, 3.0].into(), vec![1.0, 2.0].into()];
+        let terms = vectors.iter().map(VectorRef::from).zip([1.0, 1.0]);
+        assert!(combine_vectors(terms, false).is_err());
+    }
+}
@@ -0,0 +1,304 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use futures::stream::FuturesUnordered;
+use futures::TryStreamExt as _;
+use sparse::common::types::DimId;
+
+use super::Collection;
+use crate::operations::shard_selector_internal::ShardSelectorInternal;
+use crate::operations::types::{
+    CollectionError, CollectionResult, CoreSearchRequestBatch, IdfStatistics, IdfStatisticsRequest,
+    Modifier,
+};
+
+/// How long collected statistics are reused.
+/// Updates received by this peer invalidate the cache immediately,
+/// while updates applied on other peers are only picked up after this time.
+const IDF_STATISTICS_CACHE_TTL: Duration = Duration::from_secs(5);
+
+/// Cache of IDF statistics collected over all shards of the collection
+#[derive(Debug, Default)]
+pub(crate) struct IdfStatisticsCache {
+    statistics: IdfStatistics,
+    updated_at: Option<Instant>,
+}
+
+impl IdfStatisticsCache {
+    fn is_fresh(&self) -> bool {
+        self.updated_at.map_or(false, |updated_at| {
+            updated_at.elapsed() < IDF_STATISTICS_CACHE_TTL
+        })
+    }
+
+    /// Get cached statistics of the requested dimensions, if all of them are present
+    pub fn get(&self, request: &IdfStatisticsRequest) -> Option<IdfStatistics> {
+        if !self.is_fresh() || !self.statistics.covers(request) {
+            return None;
+        }
+
+        let document_frequencies = request
+            .vectors
+            .iter()
+            .map(|(vector_name, indices)| {
+                let frequencies = &self.statistics.document_frequencies[vector_name];
+                let requested = indices
+                    .iter()
+                    .map(|index| (*index, frequencies[index]))
+                    .collect();
+                (vector_name.clone(), requested)
+            })
+            .collect();
+
+        Some(IdfStatistics {
+            point_count: self.statistics.point_count,
+            document_frequencies,
+            shard_versions: self.statistics.shard_versions.clone(),
+        })
+    }
+
+    /// Remember freshly collected statistics.
+    /// Previously cached dimensions are kept only if they were collected from the same versions
+    /// of all shards.
+    pub fn insert(&mut self, statistics: IdfStatistics) {
+        if !self.is_fresh() || self.statistics.shard_versions != statistics.shard_versions {
+            self.statistics = statistics;
+            self.updated_at = Some(Instant::now());
+            return;
+        }
+
+        for (vector_name, frequencies) in statistics.document_frequencies {
+            self.statistics
+                .document_frequencies
+                .entry(vector_name)
+                .or_default()
+                .extend(frequencies);
+        }
+    }
+
+    pub fn invalidate(&mut self) {
+        self.statistics = IdfStatistics::default();
+        self.updated_at = None;
+    }
+}
+
+impl Collection {
+    /// Collect IDF statistics of the requested sparse vector dimensions from the selected shards
+    pub async fn idf_statistics(
+        &self,
+        request: IdfStatisticsRequest,
+        shard_selection: &ShardSelectorInternal,
+    ) -> CollectionResult<IdfStatistics> {
+        let shards_holder = self.shards_holder.read().await;
+        let shards = shards_holder.select_shards(shard_selection)?;
+
+        let request = Arc::new(request);
+        let mut requests: FuturesUnordered<_> = shards
+            .into_iter()
+            // `idf_statistics` requests received through internal gRPC *always* have `shard_selection`
+            .map(|(shard, _shard_key)| {
+                shard.idf_statistics(request.clone(), shard_selection.is_shard_id())
+            })
+            .collect();
+
+        let mut statistics = IdfStatistics::default();
+
+        while let Some(response) = requests.try_next().await? {
+            statistics.merge(response);
+        }
+
+        Ok(statistics)
+    }
+
+    /// Attach IDF statistics collected over all selected shards to the search batch,
+    /// so that every shard scores sparse vectors with `idf` modifier the same way.
+    pub(super) async fn fill_idf_statistics(
+        &self,
+        request: &mut CoreSearchRequestBatch,
+        shard_selection: &ShardSelectorInternal,
+    ) -> CollectionResult<()> {
+        // Statistics are collected by the peer, which received the request from the client,
+        // requests to specific shards already carry them
+        if shard_selection.is_shard_id() || request.idf_statistics.is_some() {
+            return Ok(());
+        }
+
+        // A single shard is consistent with itself, it can use local statistics
+        let shards_count = self
+            .shards_holder
+            .read()
+            .await
+            .select_shards(shard_selection)?
+            .len();
+        if shards_count <= 1 {
+            return Ok(());
+        }
+
+        let idf_request = self.idf_statistics_request(request).await;
+        if idf_request.is_empty() {
+            return Ok(());
+        }
+
+        // Only statistics over all shards are cached, as shard keys select different subsets
+        let is_cacheable = matches!(shard_selection, ShardSelectorInternal::All);
+
+        let cached = if is_cacheable {
+            self.idf_statistics_cache.lock().get(&idf_request)
+        } else {
+            None
+        };
+
+        let statistics = match cached {
+            Some(statistics) => statistics,
+            None => {
+                let statistics = match self.idf_statistics(idf_request, shard_selection).await {
+                    Ok(statistics) => statistics,
+                    // Peers running an older version can't collect statistics,
+                    // every shard scores with its local statistics then
+                    Err(err) if is_unimplemented(&err) => {
+                        log::warn!("Cannot collect IDF statistics from all shards, because some peers are running an older version, using local statistics: {err}");
+                        return Ok(());
+                    }
+                    Err(err) => return Err(err),
+                };
+                if is_cacheable {
+                    self.idf_statistics_cache.lock().insert(statistics.clone());
+                }
+                statistics
+            }
+        };
+
+        request.idf_statistics = Some(statistics);
+        Ok(())
+    }
+
+    /// Drop cached IDF statistics, e.g. after points were updated
+    pub(super) fn invalidate_idf_statistics(&self) {
+        self.idf_statistics_cache.lock().invalidate();
+    }
+
+    /// Dimensions of all sparse query vectors, which are scored with `idf` modifier
+    async fn idf_statistics_request(
+        &self,
+        request: &CoreSearchRequestBatch,
+    ) -> IdfStatisticsRequest {
+        let collection_config = self.collection_config.read().await;
+
+        let mut vectors: HashMap<String, Vec<DimId>> = HashMap::new();
+        for search in &request.searches {
+            search.query.iterate_sparse(|vector_name, sparse_vector| {
+                let is_idf = collection_config
+                    .params
+                    .get_sparse_vector_params_opt(vector_name)
+                    .map_or(false, |params| params.modifier == Some(Modifier::Idf));
+                if is_idf {
+                    vectors
+                        .entry(vector_name.to_string())
+                        .or_default()
+                        .extend_from_slice(&sparse_vector.indices);
+                }
+            });
+        }
+
+        for indices in vectors.values_mut() {
+            indices.sort_unstable();
+            indices.dedup();
+        }
+
+        IdfStatisticsRequest { vectors }
+    }
+}
+
+/// Check if the error is caused by a peer, which doesn't implement the requested operation
+fn is_unimplemented(err: &CollectionError) -> bool {
+    // This string match is fragile but there does not seem to be a better way
+    matches!(
+        err,
+        CollectionError::ServiceError { error, .. }
+            if error.starts_with("Tonic status error: status: Unimplemented"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use segment::types::SeqNumberType;
+
+    use super::*;
+    use crate::shards::shard::ShardId;
+
+    fn statistics(
+        point_count: usize,
+        frequencies: &[(DimId, usize)],
+        shard_versions: &[(ShardId, SeqNumberType)],
+    ) -> IdfStatistics {
+        IdfStatistics {
+            point_count,
+            document_frequencies: HashMap::from([(
+                "sparse".to_string(),
+                frequencies.iter().copied().collect(),
+            )]),
+            shard_versions: shard_versions.iter().copied().collect(),
+        }
+    }
+
+    fn request(indices: &[DimId]) -> IdfStatisticsRequest {
+        IdfStatisticsRequest {
+            vectors: HashMap::from([("sparse".to_string(), indices.to_vec())]),
+        }
+    }
+
+    #[test]
+    fn test_merge_idf_statistics() {
+        let mut merged = statistics(10, &[(1, 3), (2, 5)], &[(0, 4)]);
+        merged.merge(statistics(20, &[(2, 1), (3, 7)], &[(1, 9)]));
+        assert_eq!(
+            merged,
+            statistics(30, &[(1, 3), (2, 6), (3, 7)], &[(0, 4), (1, 9)]),
+        );
+    }
+
+    #[test]
+    fn test_idf_statistics_cache() {
+        let versions = [(0, 5), (1, 7)];
+        let mut cache = IdfStatisticsCache::default();
+        assert_eq!(cache.get(&request(&[1])), None);
+
+        cache.insert(statistics(10, &[(1, 3), (2, 5)], &versions));
+        assert_eq!(
+            cache.get(&request(&[2])),
+            Some(statistics(10, &[(2, 5)], &versions)),
+        );
+        // Not all requested dimensions are cached
+        assert_eq!(cache.get(&request(&[2, 3])), None);
+
+        // Statistics from the same shard versions are extended
+        cache.insert(statistics(10, &[(3, 1)], &versions));
+        assert_eq!(
+            cache.get(&request(&[1, 2, 3])),
+            Some(statistics(10, &[(1, 3), (2, 5), (3, 1)], &versions)),
+        );
+
+        // Statistics from other shard versions replace the cached ones,
+        // even if the number of points didn't change
+        let updated_versions = [(0, 5), (1, 8)];
+        cache.insert(statistics(10, &[(3, 2)], &updated_versions));
+        assert_eq!(cache.get(&request(&[1])), None);
+        assert_eq!(
+            cache.get(&request(&[3])),
+            Some(statistics(10, &[(3, 2)], &updated_versions)),
+        );
+
+        cache.invalidate();
+        assert_eq!(cache.get(&request(&[3])), None);
+    }
+
+    #[test]
+    fn test_is_unimplemented() {
+        let err = CollectionError::from(tonic::Status::unimplemented("unknown method"));
+        assert!(is_unimplemented(&err));
+
+        let err = CollectionError::from(tonic::Status::unavailable("peer is down"));
+        assert!(!is_unimplemented(&err));
+    }
+}
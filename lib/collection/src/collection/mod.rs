@@ -1,4 +1,5 @@
 mod collection_ops;
+mod idf_statistics;
 pub mod payload_index_schema;
 mod point_ops;
 mod search;
@@ -21,6 +22,7 @@ use semver::Version;
 use tokio::runtime::Handle;
 use tokio::sync::{Mutex, RwLock, RwLockWriteGuard};
 
+use crate::collection::idf_statistics::IdfStatisticsCache;
 use crate::collection::payload_index_schema::PayloadIndexSchema;
 use crate::collection_state::{ShardInfo, State};
 use crate::common::is_ready::IsReady;
@@ -69,6 +71,8 @@ pub struct Collection {
     // Search runtime handle.
     search_runtime: Handle,
     optimizer_cpu_budget: CpuBudget,
+    // IDF statistics of sparse vectors, collected over all shards.
+    idf_statistics_cache: parking_lot::Mutex<IdfStatisticsCache>,
 }
 
 pub type RequestShardTransfer = Arc<dyn Fn(ShardTransfer) + Send + Sync>;
@@ -152,6 +156,7 @@ impl Collection {
             update_runtime: update_runtime.unwrap_or_else(Handle::current),
             search_runtime: search_runtime.unwrap_or_else(Handle::current),
             optimizer_cpu_budget,
+            idf_statistics_cache: Default::default(),
         })
     }
 
@@ -249,6 +254,7 @@ impl Collection {
             update_runtime: update_runtime.unwrap_or_else(Handle::current),
             search_runtime: search_runtime.unwrap_or_else(Handle::current),
             optimizer_cpu_budget,
+            idf_statistics_cache: Default::default(),
         }
     }
 
@@ -6,6 +6,7 @@ use segment::types::{PayloadFieldSchema, PayloadKeyType};
 use serde::{Deserialize, Serialize};
 
 use crate::collection::Collection;
+use crate::operations::payload_schema_enforcement::enforce_payload_schema;
 use crate::operations::types::{CollectionResult, UpdateResult};
 use crate::operations::{CollectionUpdateOperations, CreateIndex, FieldIndexOperations};
 use crate::save_on_disk::SaveOnDisk;
@@ -83,4 +84,21 @@ impl Collection {
 
         Ok(result)
     }
+
+    /// Check payload values of the operation against the payload index schema,
+    /// according to the payload schema enforcement of the collection.
+    pub(crate) async fn enforce_payload_schema(
+        &self,
+        operation: &mut CollectionUpdateOperations,
+    ) -> CollectionResult<()> {
+        let enforcement = self
+            .collection_config
+            .read()
+            .await
+            .params
+            .payload_schema_enforcement
+            .unwrap_or_default();
+        let payload_index_schema = self.payload_index_schema.read();
+        enforce_payload_schema(operation, &payload_index_schema.schema, enforcement)
+    }
 }
@@ -1,16 +1,24 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use futures::stream::FuturesUnordered;
 use futures::{future, StreamExt as _, TryFutureExt, TryStreamExt as _};
 use itertools::Itertools;
 use segment::data_types::order_by::{Direction, OrderBy};
+use segment::index::sparse_index::sparse_search_telemetry::SparseIndexStatistics;
 use segment::types::{ShardKey, WithPayload, WithPayloadInterface};
 use validator::Validate as _;
 
 use super::Collection;
 use crate::operations::consistency_params::ReadConsistency;
+use crate::operations::payload_stats::{
+    PayloadStatsAccumulator, PayloadStatsRequest, PayloadStatsResult,
+};
 use crate::operations::point_ops::WriteOrdering;
 use crate::operations::shard_selector_internal::ShardSelectorInternal;
+use crate::operations::sparse_stats::{
+    SparseStatsRequest, SparseStatsResult, DEFAULT_SPARSE_STATS_LIMIT,
+};
 use crate::operations::types::*;
 use crate::operations::{CollectionUpdateOperations, OperationWithClockTag};
 use crate::shards::shard::ShardId;
@@ -57,6 +65,8 @@ impl Collection {
         })
         .await?;
 
+        self.invalidate_idf_statistics();
+
         let mut result = None;
 
         for collection_result in results {
@@ -114,6 +124,8 @@ impl Collection {
         })
         .await??;
 
+        self.invalidate_idf_statistics();
+
         if let Some(result) = result {
             Ok(result)
         } else {
@@ -130,12 +142,13 @@ impl Collection {
     /// This method is cancel safe.
     pub async fn update_from_client(
         &self,
-        operation: CollectionUpdateOperations,
+        mut operation: CollectionUpdateOperations,
         wait: bool,
         ordering: WriteOrdering,
         shard_keys_selection: Option<ShardKey>,
     ) -> CollectionResult<UpdateResult> {
         operation.validate()?;
+        self.enforce_payload_schema(&mut operation).await?;
 
         let update_lock = self.updates_lock.clone().read_owned().await;
         let shard_holder = self.shards_holder.clone().read_owned().await;
@@ -157,6 +170,8 @@ impl Collection {
         })
         .await??;
 
+        self.invalidate_idf_statistics();
+
         if results.is_empty() {
             return Err(CollectionError::bad_request(
                 "Empty update request".to_string(),
@@ -375,6 +390,135 @@ impl Collection {
         Ok(CountResult { count })
     }
 
+    /// Sample payloads of the selected shards and collect statistics of the present fields.
+    /// Every shard samples its points uniformly at random, partial statistics are then merged.
+    pub async fn payload_stats(
+        &self,
+        request: PayloadStatsRequest,
+        shard_selection: &ShardSelectorInternal,
+    ) -> CollectionResult<PayloadStatsResult> {
+        let accumulator = self.payload_stats_partial(request, shard_selection).await?;
+
+        let payload_index_schema = self.payload_index_schema.read();
+        Ok(accumulator.finish(|path| {
+            payload_index_schema
+                .schema
+                .keys()
+                .any(|key| key.to_string() == path)
+        }))
+    }
+
+    /// Collect mergeable payload statistics of the selected shards
+    pub async fn payload_stats_partial(
+        &self,
+        request: PayloadStatsRequest,
+        shard_selection: &ShardSelectorInternal,
+    ) -> CollectionResult<PayloadStatsAccumulator> {
+        if request.limit == Some(0) {
+            return Err(CollectionError::bad_request(
+                "Limit cannot be 0".to_string(),
+            ));
+        }
+
+        let shards_holder = self.shards_holder.read().await;
+        let shards = shards_holder.select_shards(shard_selection)?;
+
+        let request = Arc::new(request);
+        let mut requests: FuturesUnordered<_> = shards
+            .into_iter()
+            // `payload_stats` requests received through internal gRPC *always* have `shard_selection`
+            .map(|(shard, _shard_key)| {
+                shard.payload_stats(request.clone(), shard_selection.is_shard_id())
+            })
+            .collect();
+
+        let mut accumulator = PayloadStatsAccumulator::default();
+
+        while let Some(response) = requests.try_next().await? {
+            accumulator.merge(response);
+        }
+
+        Ok(accumulator)
+    }
+
+    /// Collect statistics of sparse vector indexes of the selected shards.
+    /// Statistics of a single replica of every shard are merged, including remote ones.
+    pub async fn sparse_stats(
+        &self,
+        request: SparseStatsRequest,
+        shard_selection: &ShardSelectorInternal,
+    ) -> CollectionResult<SparseStatsResult> {
+        let limit = request.limit.unwrap_or(DEFAULT_SPARSE_STATS_LIMIT);
+
+        // Report every requested vector, even if no points are indexed yet
+        let mut statistics: HashMap<String, SparseIndexStatistics> = {
+            let collection_config = self.collection_config.read().await;
+            let sparse_vectors = collection_config.params.sparse_vectors.as_ref();
+            match &request.using {
+                Some(vector_name) => {
+                    if sparse_vectors.map_or(true, |vectors| !vectors.contains_key(vector_name)) {
+                        return Err(CollectionError::bad_input(format!(
+                            "Sparse vector {vector_name} does not exist"
+                        )));
+                    }
+                    HashMap::from([(vector_name.clone(), SparseIndexStatistics::default())])
+                }
+                None => sparse_vectors
+                    .into_iter()
+                    .flat_map(|vectors| vectors.keys())
+                    .map(|vector_name| (vector_name.clone(), SparseIndexStatistics::default()))
+                    .collect(),
+            }
+        };
+
+        let shards_statistics = self
+            .sparse_stats_partial(request.clone(), shard_selection)
+            .await?;
+        for (vector_name, shard_statistics) in shards_statistics {
+            if let Some(vector_statistics) = statistics.get_mut(&vector_name) {
+                vector_statistics.merge(shard_statistics);
+            }
+        }
+
+        Ok(SparseStatsResult {
+            vectors: statistics
+                .into_iter()
+                .map(|(vector_name, statistics)| (vector_name, statistics.summarize(limit)))
+                .collect(),
+        })
+    }
+
+    /// Collect mergeable statistics of sparse vector indexes of the selected shards
+    pub async fn sparse_stats_partial(
+        &self,
+        request: SparseStatsRequest,
+        shard_selection: &ShardSelectorInternal,
+    ) -> CollectionResult<HashMap<String, SparseIndexStatistics>> {
+        let shards_holder = self.shards_holder.read().await;
+        let shards = shards_holder.select_shards(shard_selection)?;
+
+        let request = Arc::new(request);
+        let mut requests: FuturesUnordered<_> = shards
+            .into_iter()
+            // `sparse_stats` requests received through internal gRPC *always* have `shard_selection`
+            .map(|(shard, _shard_key)| {
+                shard.sparse_stats(request.clone(), shard_selection.is_shard_id())
+            })
+            .collect();
+
+        let mut statistics: HashMap<String, SparseIndexStatistics> = HashMap::new();
+        while let Some(response) = requests.try_next().await? {
+            for (vector_name, shard_statistics) in response {
+                statistics
+                    .entry(vector_name)
+                    .or_default()
+                    .merge(shard_statistics);
+            }
+        }
+
+        Ok(statistics)
+    }
+
     pub async fn retrieve(
         &self,
         request: PointRequestInternal,
@@ -3,7 +3,8 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use futures::{future, TryFutureExt};
-use segment::data_types::vectors::VectorStruct;
+use segment::data_types::vectors::{NamedSparseVector, NamedVectorStruct, VectorStruct};
+use segment::index::sparse_index::bm25::Bm25Vectorizer;
 use segment::spaces::tools;
 use segment::types::{ExtendedPointId, Order, ScoredPoint, WithPayloadInterface, WithVector};
 
@@ -27,6 +28,7 @@ impl Collection {
         // search is a special case of search_batch with a single batch
         let request_batch = CoreSearchRequestBatch {
             searches: vec![request],
+            idf_statistics: None,
         };
         let results = self
             .do_core_search_batch(request_batch, read_consistency, shard_selection, timeout)
@@ -34,6 +36,60 @@ impl Collection {
         Ok(results.into_iter().next().unwrap())
     }
 
+    /// Search by raw text, using the BM25 vectorizer of the requested sparse vector
+    pub async fn search_text(
+        &self,
+        request: TextSearchRequestInternal,
+        read_consistency: Option<ReadConsistency>,
+        shard_selection: &ShardSelectorInternal,
+        timeout: Option<Duration>,
+    ) -> CollectionResult<Vec<ScoredPoint>> {
+        let vectorizer = {
+            let collection_config = self.collection_config.read().await;
+            let bm25 = collection_config
+                .params
+                .get_sparse_vector_params_opt(&request.using)
+                .and_then(|params| params.bm25.as_ref())
+                .ok_or_else(|| {
+                    CollectionError::bad_input(format!(
+                        "Sparse vector {} has no BM25 vectorizer configured",
+                        request.using,
+                    ))
+                })?;
+            Bm25Vectorizer::new(bm25)
+        };
+
+        let TextSearchRequestInternal {
+            using,
+            text,
+            filter,
+            params,
+            limit,
+            offset,
+            with_payload,
+            with_vector,
+            score_threshold,
+        } = request;
+
+        let vector = NamedSparseVector {
+            name: using,
+            vector: vectorizer.vectorize_query(&text),
+        };
+        let core_request = CoreSearchRequest {
+            query: QueryEnum::Nearest(NamedVectorStruct::Sparse(vector)),
+            filter,
+            params,
+            limit,
+            offset: offset.unwrap_or_default(),
+            with_payload,
+            with_vector,
+            score_threshold,
+        };
+
+        self.search(core_request, read_consistency, shard_selection, timeout)
+            .await
+    }
+
     pub async fn core_search_batch(
         &self,
         request: CoreSearchRequestBatch,
@@ -89,6 +145,7 @@ impl Collection {
             }
             let without_payload_batch = CoreSearchRequestBatch {
                 searches: without_payload_requests,
+                idf_statistics: request.idf_statistics.clone(),
             };
             let without_payload_results = self
                 .do_core_search_batch(
@@ -121,11 +178,13 @@ impl Collection {
 
     async fn do_core_search_batch(
         &self,
-        request: CoreSearchRequestBatch,
+        mut request: CoreSearchRequestBatch,
         read_consistency: Option<ReadConsistency>,
         shard_selection: &ShardSelectorInternal,
         timeout: Option<Duration>,
     ) -> CollectionResult<Vec<Vec<ScoredPoint>>> {
+        self.fill_idf_statistics(&mut request, shard_selection)
+            .await?;
         let request = Arc::new(request);
 
         // query all shards concurrently
@@ -239,7 +298,8 @@ impl Collection {
                     // directly represent distance anymore, so the order is always `LargeBetter`
                     QueryEnum::Discover(_)
                     | QueryEnum::Context(_)
-                    | QueryEnum::RecommendBestScore(_) => Order::LargeBetter,
+                    | QueryEnum::RecommendBestScore(_)
+                    | QueryEnum::RecommendSumScores(_) => Order::LargeBetter,
                 };
 
                 let mut top_res = match order {
@@ -13,7 +13,9 @@ use segment::data_types::query_context::{QueryContext, SegmentQueryContext};
 use segment::data_types::vectors::{QueryVector, Vector};
 use segment::entry::entry_point::SegmentEntry;
 use segment::index::field_index::CardinalityEstimation;
+use segment::index::sparse_index::sparse_search_telemetry::SparseIndexStatistics;
 use segment::json_path::JsonPath;
+use segment::spaces::tools::merge_samples;
 use segment::telemetry::SegmentTelemetry;
 use segment::types::{
     Condition, Filter, Payload, PayloadFieldSchema, PayloadKeyType, PayloadKeyTypeRef, PointIdType,
@@ -631,6 +633,38 @@ impl SegmentEntry for ProxySegment {
         read_points
     }
 
+    fn sample_filtered<'a>(
+        &'a self,
+        filter: Option<&'a Filter>,
+        limit: usize,
+    ) -> (Vec<PointIdType>, usize) {
+        let deleted_points = self.deleted_points.read();
+        let wrapped_sample = if deleted_points.is_empty() {
+            self.wrapped_segment
+                .get()
+                .read()
+                .sample_filtered(filter, limit)
+        } else {
+            let wrapped_filter =
+                self.add_deleted_points_condition_to_filter(filter, &deleted_points);
+            self.wrapped_segment
+                .get()
+                .read()
+                .sample_filtered(Some(&wrapped_filter), limit)
+        };
+        let write_segment_sample = self
+            .write_segment
+            .get()
+            .read()
+            .sample_filtered(filter, limit);
+        // Points are moved to the write segment along with deleting them from the wrapped one
+        merge_samples(
+            vec![wrapped_sample, write_segment_sample],
+            limit,
+            &mut rand::thread_rng(),
+        )
+    }
+
     fn read_ordered_filtered<'a>(
         &'a self,
         limit: Option<usize>,
@@ -969,6 +1003,11 @@ impl SegmentEntry for ProxySegment {
             .read()
             .fill_query_context(query_context)
     }
+
+    fn sparse_index_statistics(&self) -> HashMap<String, SparseIndexStatistics> {
+        // Temporary segment only holds points updated during optimization, they are not included
+        self.wrapped_segment.get().read().sparse_index_statistics()
+    }
 }
 
 #[cfg(test)]
@@ -1513,6 +1552,7 @@ mod tests {
                         quantization_config: None,
                         multivec_config: None,
                         datatype: None,
+                        prefix_size: None,
                     },
                 ),
                 (
@@ -1525,6 +1565,7 @@ mod tests {
                         quantization_config: None,
                         multivec_config: None,
                         datatype: None,
+                        prefix_size: None,
                     },
                 ),
             ]),
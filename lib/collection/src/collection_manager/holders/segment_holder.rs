@@ -12,6 +12,7 @@ use rand::{thread_rng, Rng};
 use segment::common::operation_error::{OperationError, OperationResult};
 use segment::common::version::StorageVersion;
 use segment::entry::entry_point::SegmentEntry;
+use segment::index::sparse_index::bm25::Bm25Vectorizer;
 use segment::segment::{Segment, SegmentVersion};
 use segment::segment_constructor::build_segment;
 use segment::types::{PointIdType, SegmentConfig, SeqNumberType};
@@ -148,6 +149,9 @@ pub struct SegmentHolder {
 
     /// Holds the first uncorrected error happened with optimizer
     pub optimizer_errors: Option<CollectionError>,
+
+    /// Sparse vectors, which are derived from the payload of upserted points
+    pub sparse_vectorizers: HashMap<String, Bm25Vectorizer>,
 }
 
 pub type LockedSegmentHolder = Arc<RwLock<SegmentHolder>>;
@@ -6,7 +6,11 @@ use std::sync::Arc;
 use parking_lot::Mutex;
 use segment::common::operation_time_statistics::OperationDurationsAggregator;
 use segment::index::sparse_index::sparse_index_config::SparseIndexType;
-use segment::types::{HnswConfig, Indexes, QuantizationConfig, SegmentType, VECTOR_ELEMENT_SIZE};
+use segment::types::{
+    HnswConfig, Indexes, QuantizationConfig, SegmentType, VectorStorageDatatype,
+    VECTOR_ELEMENT_SIZE,
+};
+use sparse::index::inverted_index::inverted_index_ram_builder::IndexPruning;
 
 use crate::collection_manager::holders::segment_holder::{LockedSegmentHolder, SegmentId};
 use crate::collection_manager::optimizers::segment_optimizer::{
@@ -69,6 +73,32 @@ impl ConfigMismatchOptimizer {
             .and_then(|index| index.on_disk)
     }
 
+    fn get_required_sparse_index_datatype(
+        &self,
+        vector_name: &str,
+    ) -> Option<VectorStorageDatatype> {
+        self.collection_params
+            .sparse_vectors
+            .as_ref()
+            .and_then(|vector_params| vector_params.get(vector_name))
+            .and_then(|params| params.index)
+            .and_then(|index| index.datatype)
+            .map(VectorStorageDatatype::from)
+    }
+
+    fn get_required_sparse_index_pruning(&self, vector_name: &str) -> IndexPruning {
+        self.collection_params
+            .sparse_vectors
+            .as_ref()
+            .and_then(|vector_params| vector_params.get(vector_name))
+            .and_then(|params| params.index)
+            .map(|index| IndexPruning {
+                top_k: index.prune_top_k,
+                threshold: index.prune_threshold,
+            })
+            .unwrap_or_default()
+    }
+
     /// Calculates and HNSW config that should be used for a given vector
     /// with current configuration.
     ///
@@ -169,7 +199,8 @@ impl ConfigMismatchOptimizer {
                                 });
                             let target_quantization = target_quantization_vector
                                 .as_ref()
-                                .or(target_quantization_collection);
+                                .or(target_quantization_collection)
+                                .filter(|_| vector_data.distance.is_quantizable());
                             let quantization_mismatch = vector_data
                                 .quantization_config
                                 .as_ref()
@@ -192,6 +223,24 @@ impl ConfigMismatchOptimizer {
                         .sparse_vector_data
                         .iter()
                         .any(|(vector_name, vector_data)| {
+                            // Rebuild immutable indexes if weights are stored with another datatype
+                            if vector_data.index.index_type != SparseIndexType::MutableRam
+                                && vector_data.index.datatype.unwrap_or_default()
+                                    != self
+                                        .get_required_sparse_index_datatype(vector_name)
+                                        .unwrap_or_default()
+                            {
+                                return true;
+                            }
+
+                            // Rebuild immutable indexes if vectors are pruned differently
+                            if vector_data.index.index_type != SparseIndexType::MutableRam
+                                && vector_data.index.pruning()
+                                    != self.get_required_sparse_index_pruning(vector_name)
+                            {
+                                return true;
+                            }
+
                             let Some(is_required_on_disk) =
                                 self.check_if_sparse_vectors_index_on_disk(vector_name)
                             else {
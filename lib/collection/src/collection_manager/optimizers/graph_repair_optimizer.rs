@@ -0,0 +1,573 @@
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+
+use common::cpu::CpuPermit;
+use ordered_float::OrderedFloat;
+use parking_lot::{Mutex, RwLock, RwLockUpgradableReadGuard};
+use segment::common::operation_error::check_process_stopped;
+use segment::common::operation_time_statistics::{
+    OperationDurationsAggregator, ScopeDurationMeasurer,
+};
+use segment::common::version::StorageVersion;
+use segment::entry::entry_point::SegmentEntry;
+use segment::index::hnsw_index::graph_layers::GraphHealth;
+use segment::index::{RepairedVectorIndex, VectorIndex};
+use segment::segment::{Segment, SegmentVersion, VectorData};
+use segment::types::{
+    HnswConfig, PayloadFieldSchema, PayloadKeyType, PointIdType, QuantizationConfig, SegmentType,
+    SeqNumberType,
+};
+use segment::vector_storage::VectorStorage;
+
+use crate::collection_manager::holders::proxy_segment::ProxySegment;
+use crate::collection_manager::holders::segment_holder::{
+    LockedSegment, LockedSegmentHolder, SegmentId,
+};
+use crate::collection_manager::optimizers::segment_optimizer::{
+    OptimizerThresholds, SegmentOptimizer,
+};
+use crate::config::CollectionParams;
+use crate::operations::types::CollectionResult;
+
+/// Fraction of indexed vectors which must be deleted before the graph health is measured at all
+const DELETED_FROM_INDEX_THRESHOLD: f64 = 0.05;
+
+/// Graph is repaired if less than this fraction of live points is reachable from the entry point
+const MIN_REACHABLE_RATIO: f64 = 0.95;
+
+/// Graph is repaired if more than this fraction of links points to deleted points
+const MAX_DELETED_LINKS_RATIO: f64 = 0.2;
+
+/// Optimizer which repairs HNSW graphs damaged by deletions
+///
+/// Deleted points stay in the HNSW graph until the segment is rebuilt. With many deletions, live
+/// points end up surrounded by deleted neighbours and become hard or impossible to reach, which
+/// hurts the search quality long before the vacuum optimizer kicks in.
+///
+/// This optimizer measures the health of the graph and re-links only the affected points, which
+/// is much cheaper than a full rebuild. The segment itself is not rebuilt, its graph is replaced
+/// in place.
+///
+/// Segments which are littered enough to be rebuilt by the vacuum optimizer are left to it.
+pub struct GraphRepairOptimizer {
+    /// Deleted threshold of the vacuum optimizer
+    deleted_threshold: f64,
+    min_vectors_number: usize,
+    thresholds_config: OptimizerThresholds,
+    segments_path: PathBuf,
+    collection_temp_dir: PathBuf,
+    collection_params: CollectionParams,
+    hnsw_config: HnswConfig,
+    quantization_config: Option<QuantizationConfig>,
+    telemetry_durations_aggregator: Arc<Mutex<OperationDurationsAggregator>>,
+    /// Measured graph health by segment and vector name, along with the segment version it was
+    /// measured at. `None` health means that the graph doesn't need repair.
+    /// Graph health only changes with deletions, which bump the segment version.
+    measured_graphs: Mutex<HashMap<(SegmentId, String), (SeqNumberType, Option<GraphHealth>)>>,
+}
+
+impl GraphRepairOptimizer {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        deleted_threshold: f64,
+        min_vectors_number: usize,
+        thresholds_config: OptimizerThresholds,
+        segments_path: PathBuf,
+        collection_temp_dir: PathBuf,
+        collection_params: CollectionParams,
+        hnsw_config: HnswConfig,
+        quantization_config: Option<QuantizationConfig>,
+    ) -> Self {
+        GraphRepairOptimizer {
+            deleted_threshold,
+            min_vectors_number,
+            thresholds_config,
+            segments_path,
+            collection_temp_dir,
+            collection_params,
+            hnsw_config,
+            quantization_config,
+            telemetry_durations_aggregator: OperationDurationsAggregator::new(),
+            measured_graphs: Default::default(),
+        }
+    }
+
+    fn worst_segment(
+        &self,
+        segments: LockedSegmentHolder,
+        excluded_ids: &HashSet<SegmentId>,
+    ) -> Option<SegmentId> {
+        // Measuring graphs is slow, don't block the segment holder meanwhile
+        let candidates: Vec<_> = {
+            let segments_read_guard = segments.read();
+
+            // Forget segments which are gone
+            self.measured_graphs
+                .lock()
+                .retain(|(idx, _), _| segments_read_guard.get(*idx).is_some());
+
+            segments_read_guard
+                .iter()
+                // Excluded externally, might already be scheduled for optimization
+                .filter(|(idx, _segment)| !excluded_ids.contains(idx))
+                .filter_map(|(idx, segment)| match segment {
+                    // We can only work with original segments
+                    LockedSegment::Original(segment) => Some((*idx, segment.clone())),
+                    LockedSegment::Proxy(_) => None,
+                })
+                .collect()
+        };
+
+        candidates
+            .iter()
+            .filter_map(|(idx, segment)| {
+                let damage = self.graph_damage(*idx, &segment.read())?;
+                Some((*idx, damage))
+            })
+            .max_by_key(|(_, damage)| OrderedFloat(*damage))
+            .map(|(idx, _)| idx)
+    }
+
+    /// Measure how badly HNSW graphs of the segment are damaged
+    ///
+    /// Returns `None` if no graph of the segment requires repair.
+    fn graph_damage(&self, segment_id: SegmentId, segment: &Segment) -> Option<f64> {
+        // Never optimize special segments
+        if segment.segment_type() == SegmentType::Special {
+            return None;
+        }
+
+        // Vacuum optimizer rebuilds the whole segment anyway
+        let littered_ratio =
+            segment.deleted_point_count() as f64 / segment.total_point_count() as f64;
+        if segment.total_point_count() >= self.min_vectors_number
+            && littered_ratio > self.deleted_threshold
+        {
+            return None;
+        }
+
+        segment
+            .vector_data
+            .iter()
+            .filter_map(|(vector_name, vector_data)| {
+                let health =
+                    self.unhealthy_graph(segment_id, segment.version(), vector_name, vector_data)?;
+                Some((1.0 - health.reachable_ratio()).max(health.deleted_links_ratio()))
+            })
+            .max_by_key(|damage| OrderedFloat(*damage))
+    }
+
+    /// Measure health of the vector index graph
+    ///
+    /// Returns `None` if the graph is healthy or is not worth checking.
+    fn unhealthy_graph(
+        &self,
+        segment_id: SegmentId,
+        segment_version: SeqNumberType,
+        vector_name: &str,
+        vector_data: &VectorData,
+    ) -> Option<GraphHealth> {
+        let vector_index = vector_data.vector_index.borrow();
+        if !vector_index.is_index() {
+            return None;
+        }
+
+        // Cheap check first: graph can only degrade if vectors were deleted from it
+        let available_vector_count = vector_data.vector_storage.borrow().available_vector_count();
+        let indexed_vector_count = vector_index.indexed_vector_count();
+        let deleted_from_index = indexed_vector_count.saturating_sub(available_vector_count);
+        if indexed_vector_count < self.min_vectors_number
+            || (deleted_from_index as f64)
+                < indexed_vector_count as f64 * DELETED_FROM_INDEX_THRESHOLD
+        {
+            return None;
+        }
+
+        // Left to the vacuum optimizer, same condition as it uses
+        let deleted_ratio = deleted_from_index as f64 / indexed_vector_count as f64;
+        if deleted_from_index >= self.min_vectors_number && deleted_ratio > self.deleted_threshold {
+            return None;
+        }
+
+        let key = (segment_id, vector_name.to_string());
+        if let Some((measured_version, health)) = self.measured_graphs.lock().get(&key) {
+            if *measured_version == segment_version {
+                return *health;
+            }
+        }
+
+        let health = vector_index.graph_health()?;
+        let is_healthy = health.reachable_ratio() >= MIN_REACHABLE_RATIO
+            && health.deleted_links_ratio() <= MAX_DELETED_LINKS_RATIO;
+        let health = (!is_healthy).then_some(health);
+        self.measured_graphs
+            .lock()
+            .insert(key, (segment_version, health));
+        health
+    }
+
+    /// Build repaired graphs of all unhealthy vector indices of the segment
+    fn repair_graphs(
+        &self,
+        segment_id: SegmentId,
+        segment: &Segment,
+        permit: CpuPermit,
+        stopped: &AtomicBool,
+    ) -> CollectionResult<Vec<(String, RepairedVectorIndex)>> {
+        let permit = Arc::new(permit);
+        let mut repaired_indices = Vec::new();
+        for (vector_name, vector_data) in &segment.vector_data {
+            if self
+                .unhealthy_graph(segment_id, segment.version(), vector_name, vector_data)
+                .is_none()
+            {
+                continue;
+            }
+            let repaired = vector_data
+                .vector_index
+                .borrow()
+                .repair_graph(permit.clone(), stopped)?;
+            if let Some(repaired) = repaired {
+                repaired_indices.push((vector_name.clone(), repaired));
+            }
+        }
+        check_process_stopped(stopped)?;
+        Ok(repaired_indices)
+    }
+}
+
+impl SegmentOptimizer for GraphRepairOptimizer {
+    fn name(&self) -> &str {
+        "graph_repair"
+    }
+
+    fn collection_path(&self) -> &Path {
+        self.segments_path.as_path()
+    }
+
+    fn temp_path(&self) -> &Path {
+        self.collection_temp_dir.as_path()
+    }
+
+    fn collection_params(&self) -> CollectionParams {
+        self.collection_params.clone()
+    }
+
+    fn hnsw_config(&self) -> &HnswConfig {
+        &self.hnsw_config
+    }
+
+    fn quantization_config(&self) -> Option<QuantizationConfig> {
+        self.quantization_config.clone()
+    }
+
+    fn threshold_config(&self) -> &OptimizerThresholds {
+        &self.thresholds_config
+    }
+
+    fn check_condition(
+        &self,
+        segments: LockedSegmentHolder,
+        excluded_ids: &HashSet<SegmentId>,
+    ) -> Vec<SegmentId> {
+        self.worst_segment(segments, excluded_ids)
+            .into_iter()
+            .collect()
+    }
+
+    fn get_telemetry_counter(&self) -> &Mutex<OperationDurationsAggregator> {
+        &self.telemetry_durations_aggregator
+    }
+
+    /// Repairs graphs of the segment in place, instead of building a new segment
+    ///
+    /// While repaired graphs are built, the segment is wrapped into a proxy, so updates go to a
+    /// temporary segment and neither searches nor updates are blocked. Once graphs are built,
+    /// they are swapped in and the segment is unwrapped under the write lock of the collection.
+    fn optimize(
+        &self,
+        segments: LockedSegmentHolder,
+        ids: Vec<SegmentId>,
+        permit: CpuPermit,
+        stopped: &AtomicBool,
+    ) -> CollectionResult<bool> {
+        check_process_stopped(stopped)?;
+
+        let mut timer = ScopeDurationMeasurer::new(self.get_telemetry_counter());
+        timer.set_success(false);
+
+        let [segment_id] = ids[..] else {
+            return Ok(false);
+        };
+
+        let segments_lock = segments.upgradable_read();
+        let segment = match segments_lock.get(segment_id) {
+            Some(LockedSegment::Original(segment)) => segment.clone(),
+            // Segment is gone or is under another optimization
+            Some(LockedSegment::Proxy(_)) | None => return Ok(false),
+        };
+
+        let tmp_segment = self.temp_segment(false)?;
+
+        let proxy_deleted_points = Arc::new(RwLock::new(HashSet::<PointIdType>::new()));
+        let proxy_deleted_indexes = Arc::new(RwLock::new(HashSet::<PayloadKeyType>::new()));
+        let proxy_created_indexes = Arc::new(RwLock::new(HashMap::<
+            PayloadKeyType,
+            PayloadFieldSchema,
+        >::new()));
+
+        let mut proxy = ProxySegment::new(
+            LockedSegment::Original(segment.clone()),
+            tmp_segment.clone(),
+            proxy_deleted_points.clone(),
+            proxy_created_indexes.clone(),
+            proxy_deleted_indexes.clone(),
+        );
+        proxy.replicate_field_indexes(0)?;
+
+        match &tmp_segment {
+            LockedSegment::Original(tmp_segment) => {
+                SegmentVersion::save(&tmp_segment.read().current_path)?;
+            }
+            LockedSegment::Proxy(_) => unreachable!(),
+        }
+
+        let proxy_id = {
+            let mut write_segments = RwLockUpgradableReadGuard::upgrade(segments_lock);
+            proxy.replicate_field_indexes(0)?;
+            write_segments.swap(proxy, &[segment_id]).0
+        };
+
+        // ---- SLOW PART -----
+
+        // Nothing is written into the wrapped segment, so holding its read lock blocks no one
+        let repaired_indices = self.repair_graphs(segment_id, &segment.read(), permit, stopped);
+
+        // ---- SLOW PART ENDS HERE -----
+
+        // This part locks all operations with collection. It should be fast
+        let mut write_segments_guard = segments.write();
+        let mut write_segment = segment.write();
+
+        // Repaired graphs are only applied if all of them were built
+        let is_repaired = repaired_indices.and_then(|repaired_indices| {
+            let is_repaired = !repaired_indices.is_empty();
+            for (vector_name, repaired) in repaired_indices {
+                log::debug!(
+                    "Applying repaired HNSW graph of vector {vector_name} in segment {segment_id}"
+                );
+                write_segment.vector_data[&vector_name]
+                    .vector_index
+                    .borrow_mut()
+                    .apply_repaired_graph(repaired)?;
+            }
+            Ok(is_repaired)
+        });
+
+        // Changes made through the proxy are applied to the segment in any case
+        let version = write_segment.version();
+        for &point_id in proxy_deleted_points.read().iter() {
+            write_segment.delete_point(version, point_id)?;
+        }
+        for deleted_field_name in proxy_deleted_indexes.read().iter() {
+            write_segment.delete_field_index(version, deleted_field_name)?;
+        }
+        for (created_field_name, schema_type) in proxy_created_indexes.read().iter() {
+            write_segment.create_field_index(version, created_field_name, Some(schema_type))?;
+        }
+        drop(write_segment);
+
+        write_segments_guard.swap(LockedSegment::Original(segment), &[proxy_id]);
+
+        // Append a temp segment to collection if it is not empty or there is no other appendable segment
+        let has_appendable_segments = write_segments_guard.random_appendable_segment().is_some();
+        let keep_tmp_segment =
+            tmp_segment.get().read().available_point_count() > 0 || !has_appendable_segments;
+        if keep_tmp_segment {
+            write_segments_guard.add_locked(tmp_segment.clone());
+        }
+
+        // unlock collection for search and updates
+        drop(write_segments_guard);
+
+        if !keep_tmp_segment {
+            tmp_segment.drop_data()?;
+        }
+
+        // Segment got a new id in the holder, its measurements are forgotten with the old one
+        self.measured_graphs
+            .lock()
+            .retain(|(idx, _), _| *idx != segment_id);
+
+        if !is_repaired? {
+            return Ok(false);
+        }
+
+        timer.set_success(true);
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use itertools::Itertools;
+    use parking_lot::RwLock;
+    use segment::data_types::vectors::DEFAULT_VECTOR_NAME;
+    use segment::index::hnsw_index::num_rayon_threads;
+    use segment::types::Distance;
+    use tempfile::Builder;
+
+    use super::*;
+    use crate::collection_manager::fixtures::random_segment;
+    use crate::collection_manager::holders::segment_holder::SegmentHolder;
+    use crate::collection_manager::optimizers::indexing_optimizer::IndexingOptimizer;
+    use crate::operations::types::VectorsConfig;
+    use crate::operations::vector_params_builder::VectorParamsBuilder;
+
+    #[test]
+    fn test_graph_repair_optimizer() {
+        let (point_count, dim) = (2000, 16);
+        let thresholds_config = OptimizerThresholds {
+            max_segment_size: usize::MAX,
+            memmap_threshold: usize::MAX,
+            indexing_threshold: 10,
+        };
+        let collection_params = CollectionParams {
+            vectors: VectorsConfig::Single(VectorParamsBuilder::new(dim, Distance::Dot).build()),
+            ..CollectionParams::empty()
+        };
+        let hnsw_config = HnswConfig {
+            m: 8,
+            ef_construct: 64,
+            full_scan_threshold: 10,
+            max_indexing_threads: 0,
+            on_disk: None,
+            payload_m: None,
+        };
+
+        let temp_dir = Builder::new().prefix("segment_temp_dir").tempdir().unwrap();
+        let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+        let mut holder = SegmentHolder::default();
+        let segment_id = holder.add(random_segment(dir.path(), 100, point_count, dim as usize));
+        let locked_holder: Arc<RwLock<_>> = Arc::new(RwLock::new(holder));
+
+        let permit_cpu_count = num_rayon_threads(hnsw_config.max_indexing_threads);
+
+        let index_optimizer = IndexingOptimizer::new(
+            2,
+            thresholds_config.clone(),
+            dir.path().to_owned(),
+            temp_dir.path().to_owned(),
+            collection_params.clone(),
+            hnsw_config.clone(),
+            Default::default(),
+        );
+        // Vacuum optimizer tolerates the deletions below
+        let repair_optimizer = GraphRepairOptimizer::new(
+            0.9,
+            100,
+            thresholds_config.clone(),
+            dir.path().to_owned(),
+            temp_dir.path().to_owned(),
+            collection_params.clone(),
+            hnsw_config.clone(),
+            Default::default(),
+        );
+        // Vacuum optimizer rebuilds the segment after the deletions below
+        let vacuumed_repair_optimizer = GraphRepairOptimizer::new(
+            0.2,
+            100,
+            thresholds_config,
+            dir.path().to_owned(),
+            temp_dir.path().to_owned(),
+            collection_params,
+            hnsw_config,
+            Default::default(),
+        );
+
+        let changed = index_optimizer
+            .optimize(
+                locked_holder.clone(),
+                vec![segment_id],
+                CpuPermit::dummy(permit_cpu_count as u32),
+                &false.into(),
+            )
+            .unwrap();
+        assert!(changed, "optimizer should have rebuilt this segment");
+
+        let (indexed_segment_id, indexed_segment) = locked_holder
+            .read()
+            .iter()
+            .map(|(idx, segment)| match segment {
+                LockedSegment::Original(s) => (*idx, s.clone()),
+                LockedSegment::Proxy(_) => unreachable!(),
+            })
+            .find(|(_, segment)| segment.read().total_point_count() > 0)
+            .unwrap();
+
+        // Healthy graph is not touched
+        let suggested_to_optimize =
+            repair_optimizer.check_condition(locked_holder.clone(), &Default::default());
+        assert!(suggested_to_optimize.is_empty());
+
+        // Delete most of the points
+        {
+            let mut segment = indexed_segment.write();
+            let points_to_delete = segment
+                .iter_points()
+                .enumerate()
+                .filter_map(|(i, point_id)| (i % 3 != 0).then_some(point_id))
+                .collect_vec();
+            for point_id in points_to_delete {
+                segment.delete_point(201, point_id).unwrap();
+            }
+        }
+
+        let suggested_to_optimize =
+            vacuumed_repair_optimizer.check_condition(locked_holder.clone(), &Default::default());
+        assert!(suggested_to_optimize.is_empty());
+
+        let suggested_to_optimize =
+            repair_optimizer.check_condition(locked_holder.clone(), &Default::default());
+        assert_eq!(suggested_to_optimize, vec![indexed_segment_id]);
+
+        let changed = repair_optimizer
+            .optimize(
+                locked_holder.clone(),
+                suggested_to_optimize,
+                CpuPermit::dummy(permit_cpu_count as u32),
+                &false.into(),
+            )
+            .unwrap();
+        assert!(changed, "optimizer should have repaired the graph");
+
+        // Segment is repaired in place
+        assert!(locked_holder
+            .read()
+            .iter()
+            .any(|(_, segment)| match segment {
+                LockedSegment::Original(segment) => Arc::ptr_eq(segment, &indexed_segment),
+                LockedSegment::Proxy(_) => false,
+            }));
+
+        {
+            let segment = indexed_segment.read();
+            let available_point_count = segment.available_point_count();
+            let vector_index = segment.vector_data[DEFAULT_VECTOR_NAME]
+                .vector_index
+                .borrow();
+            let health = vector_index.graph_health().unwrap();
+            assert_eq!(health.deleted_links, 0);
+            assert_eq!(health.reachable_points, health.live_points);
+            assert_eq!(vector_index.indexed_vector_count(), available_point_count);
+        }
+
+        let suggested_to_optimize =
+            repair_optimizer.check_condition(locked_holder.clone(), &Default::default());
+        assert!(suggested_to_optimize.is_empty());
+    }
+}
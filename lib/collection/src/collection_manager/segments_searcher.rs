@@ -25,7 +25,10 @@ use crate::collection_manager::search_result_aggregator::BatchResultAggregator;
 use crate::common::stopping_guard::StoppingGuard;
 use crate::config::CollectionConfig;
 use crate::operations::query_enum::QueryEnum;
-use crate::operations::types::{CollectionResult, CoreSearchRequestBatch, Modifier, Record};
+use crate::operations::types::{
+    CollectionResult, CoreSearchRequestBatch, IdfStatistics, IdfStatisticsRequest, Modifier, Record,
+    ShardIdfStatistics,
+};
 use crate::optimizers_builder::DEFAULT_INDEXING_THRESHOLD_KB;
 
 type BatchOffset = usize;
@@ -218,6 +221,61 @@ impl SegmentsSearcher {
             })
         };
 
+        let mut query_context = task.await?;
+
+        // Statistics collected over all shards take precedence over the local ones
+        if let (Some(query_context), Some(idf_statistics)) = (
+            query_context.as_mut(),
+            batch_request.idf_statistics.as_ref(),
+        ) {
+            query_context.set_idf_point_count(idf_statistics.point_count);
+            for vector_name in idf_vectors {
+                if let Some(frequencies) = idf_statistics.document_frequencies.get(vector_name) {
+                    query_context.set_idf(vector_name, frequencies.clone());
+                }
+            }
+        }
+
+        Ok(query_context)
+    }
+
+    /// Collect IDF statistics of the requested sparse vector dimensions over all segments
+    pub async fn idf_statistics(
+        segments: LockedSegmentHolder,
+        request: Arc<IdfStatisticsRequest>,
+    ) -> CollectionResult<ShardIdfStatistics> {
+        // Do blocking calls in a blocking task: `segment.get().read()` calls might block async runtime
+        let task = tokio::task::spawn_blocking(move || {
+            let mut query_context = QueryContext::default();
+            for (vector_name, indices) in &request.vectors {
+                query_context.init_idf(vector_name, indices);
+            }
+
+            let mut version = 0;
+            let segments = segments.read();
+            for locked_segment in segments.non_appendable_then_appendable_segments() {
+                let segment = locked_segment.get();
+                let segment_guard = segment.read();
+                segment_guard.fill_query_context(&mut query_context);
+                version = version.max(segment_guard.version());
+            }
+
+            ShardIdfStatistics {
+                version,
+                statistics: IdfStatistics {
+                    point_count: query_context.available_point_count(),
+                    document_frequencies: query_context
+                        .idf()
+                        .iter()
+                        .map(|(vector_name, frequencies)| {
+                            (vector_name.clone(), frequencies.clone())
+                        })
+                        .collect(),
+                    shard_versions: HashMap::new(),
+                },
+            }
+        });
+
         Ok(task.await?)
     }
 
@@ -300,6 +358,7 @@ impl SegmentsSearcher {
                             .iter()
                             .map(|batch_id| batch_request.searches[*batch_id].clone())
                             .collect(),
+                        idf_statistics: None,
                     });
                     res.push(runtime_handle.spawn_blocking(move || {
                         search_in_segment(
@@ -402,6 +461,7 @@ pub enum SearchType {
     #[default]
     Nearest,
     RecommendBestScore,
+    RecommendSumScores,
     Discover,
     Context,
 }
@@ -411,6 +471,7 @@ impl From<&QueryEnum> for SearchType {
         match query {
             QueryEnum::Nearest(_) => Self::Nearest,
             QueryEnum::RecommendBestScore(_) => Self::RecommendBestScore,
+            QueryEnum::RecommendSumScores(_) => Self::RecommendSumScores,
             QueryEnum::Discover(_) => Self::Discover,
             QueryEnum::Context(_) => Self::Context,
         }
@@ -673,6 +734,7 @@ mod tests {
 
         let batch_request = CoreSearchRequestBatch {
             searches: vec![req],
+            idf_statistics: None,
         };
 
         let result = SegmentsSearcher::search(
@@ -736,6 +798,7 @@ mod tests {
 
             let batch_request = CoreSearchRequestBatch {
                 searches: vec![req1.into(), req2.into()],
+                idf_statistics: None,
             };
 
             let batch_request = Arc::new(batch_request);
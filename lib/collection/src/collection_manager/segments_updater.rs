@@ -1,13 +1,17 @@
 //! A collection of functions for updating points and payloads stored in segments
 
 use std::collections::{HashMap, HashSet};
+use std::mem;
 
 use itertools::iproduct;
 use parking_lot::{RwLock, RwLockWriteGuard};
 use segment::common::operation_error::{OperationError, OperationResult};
 use segment::data_types::named_vectors::NamedVectors;
-use segment::data_types::vectors::{BatchVectorStruct, VectorStruct};
+use segment::data_types::vectors::{
+    BatchVectorStruct, VectorRef, VectorStruct, DEFAULT_VECTOR_NAME,
+};
 use segment::entry::entry_point::SegmentEntry;
+use segment::index::sparse_index::bm25::Bm25Vectorizer;
 use segment::json_path::JsonPath;
 use segment::types::{
     Filter, Payload, PayloadFieldSchema, PayloadKeyType, PayloadKeyTypeRef, PointIdType,
@@ -119,11 +123,16 @@ pub(crate) fn overwrite_payload(
     payload: &Payload,
     points: &[PointIdType],
 ) -> CollectionResult<usize> {
+    let vectorizers = affected_vectorizers(segments, |_| true);
     let updated_points = segments.apply_points_with_conditional_move(
         op_num,
         points,
-        |id, write_segment| write_segment.set_full_payload(op_num, id, payload),
-        |segment| segment.get_indexed_fields().is_empty(),
+        |id, write_segment| {
+            let res = write_segment.set_full_payload(op_num, id, payload)?;
+            revectorize_point(write_segment, op_num, id, &vectorizers)?;
+            Ok(res)
+        },
+        |segment| vectorizers.is_empty() && segment.get_indexed_fields().is_empty(),
     )?;
 
     check_unprocessed_points(points, &updated_points)?;
@@ -147,14 +156,22 @@ pub(crate) fn set_payload(
     points: &[PointIdType],
     key: &Option<JsonPath>,
 ) -> CollectionResult<usize> {
+    let vectorizers = affected_vectorizers(segments, |field| {
+        field.is_affected_by_value_set(&payload.0, key.as_ref())
+    });
     let updated_points = segments.apply_points_with_conditional_move(
         op_num,
         points,
-        |id, write_segment| write_segment.set_payload(op_num, id, payload, key),
+        |id, write_segment| {
+            let res = write_segment.set_payload(op_num, id, payload, key)?;
+            revectorize_point(write_segment, op_num, id, &vectorizers)?;
+            Ok(res)
+        },
         |segment| {
-            segment.get_indexed_fields().keys().all(|indexed_path| {
-                !indexed_path.is_affected_by_value_set(&payload.0, key.as_ref())
-            })
+            vectorizers.is_empty()
+                && segment.get_indexed_fields().keys().all(|indexed_path| {
+                    !indexed_path.is_affected_by_value_set(&payload.0, key.as_ref())
+                })
         },
     )?;
 
@@ -192,6 +209,10 @@ pub(crate) fn delete_payload(
     points: &[PointIdType],
     keys: &[PayloadKeyType],
 ) -> CollectionResult<usize> {
+    let vectorizers = affected_vectorizers(segments, |field| {
+        keys.iter()
+            .any(|path_to_delete| field.is_affected_by_value_remove(path_to_delete))
+    });
     let updated_points = segments.apply_points_with_conditional_move(
         op_num,
         points,
@@ -200,14 +221,16 @@ pub(crate) fn delete_payload(
             for key in keys {
                 res &= write_segment.delete_payload(op_num, id, key)?;
             }
+            revectorize_point(write_segment, op_num, id, &vectorizers)?;
             Ok(res)
         },
         |segment| {
-            iproduct!(segment.get_indexed_fields().keys(), keys).all(
-                |(indexed_path, path_to_delete)| {
-                    !indexed_path.is_affected_by_value_remove(path_to_delete)
-                },
-            )
+            vectorizers.is_empty()
+                && iproduct!(segment.get_indexed_fields().keys(), keys).all(
+                    |(indexed_path, path_to_delete)| {
+                        !indexed_path.is_affected_by_value_remove(path_to_delete)
+                    },
+                )
         },
     )?;
 
@@ -230,11 +253,16 @@ pub(crate) fn clear_payload(
     op_num: SeqNumberType,
     points: &[PointIdType],
 ) -> CollectionResult<usize> {
+    let vectorizers = affected_vectorizers(segments, |_| true);
     let updated_points = segments.apply_points_with_conditional_move(
         op_num,
         points,
-        |id, write_segment| write_segment.clear_payload(op_num, id),
-        |segment| segment.get_indexed_fields().is_empty(),
+        |id, write_segment| {
+            let res = write_segment.clear_payload(op_num, id)?;
+            revectorize_point(write_segment, op_num, id, &vectorizers)?;
+            Ok(res)
+        },
+        |segment| vectorizers.is_empty() && segment.get_indexed_fields().is_empty(),
     )?;
 
     check_unprocessed_points(points, &updated_points)?;
@@ -248,12 +276,17 @@ pub(crate) fn clear_payload_by_filter(
     filter: &Filter,
 ) -> CollectionResult<usize> {
     let points_to_clear = points_by_filter(segments, filter)?;
+    let vectorizers = affected_vectorizers(segments, |_| true);
 
     let updated_points = segments.apply_points_with_conditional_move(
         op_num,
         points_to_clear.as_slice(),
-        |id, write_segment| write_segment.clear_payload(op_num, id),
-        |segment| segment.get_indexed_fields().is_empty(),
+        |id, write_segment| {
+            let res = write_segment.clear_payload(op_num, id)?;
+            revectorize_point(write_segment, op_num, id, &vectorizers)?;
+            Ok(res)
+        },
+        |segment| vectorizers.is_empty() && segment.get_indexed_fields().is_empty(),
     )?;
 
     Ok(updated_points.len())
@@ -440,6 +473,82 @@ where
     Ok(res)
 }
 
+/// Derive sparse vectors of the points from their payload, using BM25 vectorizers of the collection.
+///
+/// Vectors explicitly provided by the user are kept as is.
+pub(crate) fn vectorize_points(segments: &SegmentHolder, points: &mut [PointStruct]) {
+    if segments.sparse_vectorizers.is_empty() {
+        return;
+    }
+
+    for point in points {
+        let Some(payload) = &point.payload else {
+            continue;
+        };
+        for (vector_name, vectorizer) in &segments.sparse_vectorizers {
+            if let api::rest::VectorStruct::Multi(vectors) = &point.vector {
+                if vectors.contains_key(vector_name) {
+                    continue;
+                }
+            }
+            let Some(vector) = vectorizer.vectorize_payload(payload) else {
+                continue;
+            };
+            if let api::rest::VectorStruct::Single(dense) = &mut point.vector {
+                let dense = mem::take(dense);
+                point.vector = api::rest::VectorStruct::Multi(HashMap::from([(
+                    DEFAULT_VECTOR_NAME.to_string(),
+                    api::rest::Vector::Dense(dense),
+                )]));
+            }
+            if let api::rest::VectorStruct::Multi(vectors) = &mut point.vector {
+                vectors.insert(vector_name.clone(), api::rest::Vector::Sparse(vector));
+            }
+        }
+    }
+}
+
+/// BM25 vectorizers of the collection, whose payload field is affected by a payload change
+fn affected_vectorizers(
+    segments: &SegmentHolder,
+    is_affected: impl Fn(&JsonPath) -> bool,
+) -> Vec<(&str, &Bm25Vectorizer)> {
+    segments
+        .sparse_vectorizers
+        .iter()
+        .filter(|(_, vectorizer)| is_affected(vectorizer.field()))
+        .map(|(vector_name, vectorizer)| (vector_name.as_str(), vectorizer))
+        .collect()
+}
+
+/// Derive sparse vectors of the point again, after its payload has been changed.
+///
+/// Vectors are removed, if the payload has no text to vectorize anymore.
+fn revectorize_point(
+    write_segment: &mut RwLockWriteGuard<dyn SegmentEntry>,
+    op_num: SeqNumberType,
+    point_id: PointIdType,
+    vectorizers: &[(&str, &Bm25Vectorizer)],
+) -> OperationResult<()> {
+    if vectorizers.is_empty() {
+        return Ok(());
+    }
+
+    let payload = write_segment.payload(point_id)?;
+    for (vector_name, vectorizer) in vectorizers {
+        match vectorizer.vectorize_payload(&payload) {
+            Some(vector) => {
+                let vectors = NamedVectors::from_ref(vector_name, VectorRef::from(&vector));
+                write_segment.update_vectors(op_num, point_id, vectors)?;
+            }
+            None => {
+                write_segment.delete_vector(op_num, point_id, vector_name)?;
+            }
+        }
+    }
+    Ok(())
+}
+
 pub(crate) fn process_point_operation(
     segments: &RwLock<SegmentHolder>,
     op_num: SeqNumberType,
@@ -448,7 +557,7 @@ pub(crate) fn process_point_operation(
     match point_operation {
         PointOperations::DeletePoints { ids, .. } => delete_points(&segments.read(), op_num, &ids),
         PointOperations::UpsertPoints(operation) => {
-            let points: Vec<_> = match operation {
+            let mut points: Vec<_> = match operation {
                 PointInsertOperationsInternal::PointsBatch(batch) => {
                     let batch_vectors: BatchVectorStruct = batch.vectors.into();
                     let all_vectors = batch_vectors.into_all_vectors(batch.ids.len());
@@ -473,15 +582,19 @@ pub(crate) fn process_point_operation(
                 }
                 PointInsertOperationsInternal::PointsList(points) => points,
             };
-            let res = upsert_points(&segments.read(), op_num, points.iter())?;
+            let segments = segments.read();
+            vectorize_points(&segments, &mut points);
+            let res = upsert_points(&segments, op_num, points.iter())?;
             Ok(res)
         }
         PointOperations::DeletePointsByFilter(filter) => {
             delete_points_by_filter(&segments.read(), op_num, &filter)
         }
-        PointOperations::SyncPoints(operation) => {
+        PointOperations::SyncPoints(mut operation) => {
+            let segments = segments.read();
+            vectorize_points(&segments, &mut operation.points);
             let (deleted, new, updated) = sync_points(
-                &segments.read(),
+                &segments,
                 op_num,
                 operation.from_id,
                 operation.to_id,
@@ -2,7 +2,9 @@ use segment::data_types::vectors::DEFAULT_VECTOR_NAME;
 use segment::types::PointIdType;
 
 use crate::operations::shard_key_selector::ShardKeySelector;
-use crate::operations::types::{DiscoverRequestInternal, RecommendRequestInternal, UsingVector};
+use crate::operations::types::{
+    ArithmeticRequestInternal, DiscoverRequestInternal, RecommendRequestInternal, UsingVector,
+};
 
 const EMPTY_SHARD_KEY_SELECTOR: Option<ShardKeySelector> = None;
 
@@ -101,3 +103,36 @@ impl RetrieveRequest for DiscoverRequestInternal {
             .unwrap_or(&EMPTY_SHARD_KEY_SELECTOR)
     }
 }
+
+impl RetrieveRequest for ArithmeticRequestInternal {
+    fn get_lookup_collection(&self) -> Option<&String> {
+        self.lookup_from.as_ref().map(|x| &x.collection)
+    }
+
+    fn get_referenced_point_ids(&self) -> Vec<PointIdType> {
+        self.terms
+            .iter()
+            .filter_map(|term| term.example.as_point_id())
+            .collect()
+    }
+
+    fn get_search_vector_name(&self) -> String {
+        match &self.lookup_from {
+            None => match &self.using {
+                None => DEFAULT_VECTOR_NAME.to_owned(),
+                Some(UsingVector::Name(vector_name)) => vector_name.clone(),
+            },
+            Some(lookup_from) => match &lookup_from.vector {
+                None => DEFAULT_VECTOR_NAME.to_owned(),
+                Some(vector_name) => vector_name.clone(),
+            },
+        }
+    }
+
+    fn get_lookup_shard_key(&self) -> &Option<ShardKeySelector> {
+        self.lookup_from
+            .as_ref()
+            .map(|x| &x.shard_key)
+            .unwrap_or(&EMPTY_SHARD_KEY_SELECTOR)
+    }
+}
@@ -9,6 +9,7 @@ use atomicwrites::OverwriteBehavior::AllowOverwrite;
 use schemars::JsonSchema;
 use segment::common::anonymize::Anonymize;
 use segment::data_types::vectors::DEFAULT_VECTOR_NAME;
+use segment::index::sparse_index::bm25::Bm25Vectorizer;
 use segment::index::sparse_index::sparse_index_config::{SparseIndexConfig, SparseIndexType};
 use segment::types::{
     Distance, HnswConfig, Indexes, PayloadStorageType, QuantizationConfig, SparseVectorDataConfig,
@@ -63,6 +64,19 @@ pub enum ShardingMethod {
     Custom,
 }
 
+/// How payload values of indexed fields are checked against the payload index schema on write
+#[derive(Debug, Deserialize, Serialize, JsonSchema, PartialEq, Eq, Hash, Clone, Copy, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum PayloadSchemaEnforcement {
+    /// Values of unexpected type are stored, but not indexed
+    #[default]
+    Disabled,
+    /// Reject the operation if a value doesn't match the type of the indexed field
+    Reject,
+    /// Convert the value into the type of the indexed field, reject the operation if not possible
+    Coerce,
+}
+
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub struct CollectionParams {
@@ -100,6 +114,10 @@ pub struct CollectionParams {
     /// Note: those payload values that are involved in filtering and are indexed - remain in RAM.
     #[serde(default = "default_on_disk_payload")]
     pub on_disk_payload: bool,
+    /// How to check payload values of indexed fields on upsert and set payload.
+    /// Default is Disabled - values of unexpected type are stored, but not indexed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payload_schema_enforcement: Option<PayloadSchemaEnforcement>,
     /// Configuration of the sparse vector storage
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[validate]
@@ -126,6 +144,7 @@ impl Anonymize for CollectionParams {
             write_consistency_factor: self.write_consistency_factor,
             read_fan_out_factor: self.read_fan_out_factor,
             on_disk_payload: self.on_disk_payload,
+            payload_schema_enforcement: self.payload_schema_enforcement,
             sparse_vectors: self.sparse_vectors.anonymize(),
         }
     }
@@ -203,6 +222,7 @@ impl CollectionParams {
             write_consistency_factor: default_write_consistency_factor(),
             read_fan_out_factor: None,
             on_disk_payload: default_on_disk_payload(),
+            payload_schema_enforcement: None,
             sparse_vectors: None,
         }
     }
@@ -251,6 +271,18 @@ impl CollectionParams {
             .and_then(|sparse_vectors| sparse_vectors.get(vector_name))
     }
 
+    /// BM25 vectorizers of sparse vectors, which are derived from the payload
+    pub fn sparse_vectorizers(&self) -> HashMap<String, Bm25Vectorizer> {
+        self.sparse_vectors
+            .iter()
+            .flatten()
+            .filter_map(|(vector_name, params)| {
+                let vectorizer = Bm25Vectorizer::new(params.bm25.as_ref()?);
+                Some((vector_name.clone(), vectorizer))
+            })
+            .collect()
+    }
+
     pub fn get_sparse_vector_params_mut(
         &mut self,
         vector_name: &str,
@@ -299,6 +331,14 @@ impl CollectionParams {
                         Some(QuantizationConfig::Binary(binary))
                     }
                     QuantizationConfigDiff::Disabled(_) => None,
+                };
+                if vector_params.quantization_config.is_some()
+                    && !vector_params.distance.is_quantizable()
+                {
+                    return Err(CollectionError::bad_input(format!(
+                        "Quantization is not supported for {:?} distance",
+                        vector_params.distance,
+                    )));
                 }
             }
 
@@ -314,9 +354,31 @@ impl CollectionParams {
         &mut self,
         update_vectors: &SparseVectorsConfig,
     ) -> CollectionResult<()> {
+        // Vectors derived with the previous BM25 configuration would not be updated
+        for (vector_name, update_params) in update_vectors.0.iter() {
+            let Some(bm25) = &update_params.bm25 else {
+                continue;
+            };
+            let current_bm25 = self
+                .get_sparse_vector_params_opt(vector_name)
+                .and_then(|params| params.bm25.as_ref());
+            if current_bm25 != Some(bm25) {
+                return Err(CollectionError::BadInput {
+                    description: format!(
+                        "BM25 configuration of sparse vector {vector_name} can't be changed, \
+                         create a new sparse vector instead",
+                    ),
+                });
+            }
+        }
+
         for (vector_name, update_params) in update_vectors.0.iter() {
             let sparse_vector_params = self.get_sparse_vector_params_mut(vector_name)?;
-            let SparseVectorParams { index, modifier } = update_params.clone();
+            let SparseVectorParams {
+                index,
+                modifier,
+                bm25: _,
+            } = update_params.clone();
 
             if let Some(modifier) = modifier {
                 sparse_vector_params.modifier = Some(modifier);
@@ -359,6 +421,7 @@ impl CollectionParams {
                         },
                         multivec_config: params.multivec_config,
                         datatype: params.datatype.map(VectorStorageDatatype::from),
+                        prefix_size: params.prefix_size.map(|size| size.get() as usize),
                     },
                 )
             })
@@ -384,6 +447,17 @@ impl CollectionParams {
                                     .index
                                     .and_then(|index| index.full_scan_threshold),
                                 index_type: SparseIndexType::MutableRam,
+                                datatype: params
+                                    .index
+                                    .and_then(|index| index.datatype)
+                                    .map(VectorStorageDatatype::from),
+                                prune_top_k: params.index.and_then(|index| index.prune_top_k),
+                                prune_threshold: params
+                                    .index
+                                    .and_then(|index| index.prune_threshold),
+                                prune_oversampling: params
+                                    .index
+                                    .and_then(|index| index.prune_oversampling),
                             },
                         },
                     )
@@ -205,6 +205,7 @@ where
 
             let core_search_batch_request = CoreSearchRequestBatch {
                 searches: core_searches,
+                idf_statistics: None,
             };
 
             requests.push(collection.core_search_batch(
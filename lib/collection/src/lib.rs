@@ -1,3 +1,4 @@
+pub mod arithmetic;
 pub mod collection;
 pub mod collection_manager;
 pub mod collection_state;
@@ -1,6 +1,5 @@
 use std::collections::{BTreeMap, HashMap};
 use std::num::{NonZeroU32, NonZeroU64};
-use std::str::FromStr;
 use std::time::Duration;
 
 use api::grpc::conversions::{
@@ -16,16 +15,18 @@ use api::grpc::qdrant::CreateShardKey;
 use common::types::ScoreType;
 use itertools::Itertools;
 use segment::data_types::order_by::{OrderBy, StartFrom};
+use segment::data_types::text_index::TokenizerType;
 use segment::data_types::vectors::{
     BatchVectorStruct, Named, NamedQuery, NamedVectorStruct, Vector, VectorStruct,
     DEFAULT_VECTOR_NAME,
 };
+use segment::index::sparse_index::bm25::Bm25Config;
 use segment::types::{
     DateTimeWrapper, Distance, MultiVectorConfig, QuantizationConfig, ScoredPoint,
 };
 use segment::vector_storage::query::context_query::{ContextPair, ContextQuery};
 use segment::vector_storage::query::discovery_query::DiscoveryQuery;
-use segment::vector_storage::query::reco_query::RecoQuery;
+use segment::vector_storage::query::reco_query::{RecoQuery, RecoSumScoresQuery};
 use sparse::common::sparse_vector::{validate_sparse_vector_impl, SparseVector};
 use tonic::Status;
 
@@ -34,11 +35,11 @@ use super::types::{
     BaseGroupRequest, ContextExamplePair, CoreSearchRequest, Datatype, DiscoverRequestInternal,
     GroupsResult, Modifier, OrderByInterface, PointGroup, RecommendExample,
     RecommendGroupsRequestInternal, RecommendStrategy, SearchGroupsRequestInternal,
-    SparseIndexParams, SparseVectorParams, VectorParamsDiff, VectorsConfigDiff,
+    SparseIndexParams, SparseVectorParams, VectorParamsDiff, VectorsConfigDiff, WeightedExample,
 };
 use crate::config::{
     default_replication_factor, default_write_consistency_factor, CollectionConfig,
-    CollectionParams, ShardingMethod, WalConfig,
+    CollectionParams, PayloadSchemaEnforcement, ShardingMethod, WalConfig,
 };
 use crate::lookup::types::WithLookupInterface;
 use crate::lookup::WithLookup;
@@ -52,6 +53,10 @@ use crate::operations::config_diff::{
     CollectionParamsDiff, HnswConfigDiff, OptimizersConfigDiff, QuantizationConfigDiff,
     WalConfigDiff,
 };
+use crate::operations::payload_stats::{
+    FieldAccumulator, HyperLogLog, PayloadFieldStats, PayloadStatsAccumulator, PayloadStatsRequest,
+    PayloadStatsResult, PayloadValueType, HLL_REGISTERS,
+};
 use crate::operations::point_ops::PointsSelector::PointIdsSelector;
 use crate::operations::point_ops::{
     Batch, FilterSelector, PointIdsList, PointStruct, PointsSelector, WriteOrdering,
@@ -60,10 +65,10 @@ use crate::operations::query_enum::QueryEnum;
 use crate::operations::shard_key_selector::ShardKeySelector;
 use crate::operations::shard_selector_internal::ShardSelectorInternal;
 use crate::operations::types::{
-    AliasDescription, CollectionClusterInfo, CollectionInfo, CollectionStatus, CountResult,
-    LocalShardInfo, LookupLocation, OptimizersStatus, RecommendRequestInternal, Record,
-    RemoteShardInfo, SearchRequestInternal, ShardTransferInfo, UpdateResult, UpdateStatus,
-    VectorParams, VectorsConfig,
+    validate_vector_params, AliasDescription, CollectionClusterInfo, CollectionInfo,
+    CollectionStatus, CountResult, IdfStatistics, LocalShardInfo, LookupLocation, OptimizersStatus,
+    RecommendRequestInternal, Record, RemoteShardInfo, SearchRequestInternal, ShardTransferInfo,
+    UpdateResult, UpdateStatus, VectorParams, VectorsConfig,
 };
 use crate::optimizers_builder::OptimizersConfig;
 use crate::shards::remote_shard::{CollectionCoreSearchRequest, CollectionSearchRequest};
@@ -88,6 +93,39 @@ pub fn sharding_method_from_proto(sharding_method: i32) -> Result<ShardingMethod
     }
 }
 
+pub fn payload_schema_enforcement_to_proto(enforcement: PayloadSchemaEnforcement) -> i32 {
+    match enforcement {
+        PayloadSchemaEnforcement::Disabled => {
+            api::grpc::qdrant::PayloadSchemaEnforcement::Disabled as i32
+        }
+        PayloadSchemaEnforcement::Reject => {
+            api::grpc::qdrant::PayloadSchemaEnforcement::Reject as i32
+        }
+        PayloadSchemaEnforcement::Coerce => {
+            api::grpc::qdrant::PayloadSchemaEnforcement::Coerce as i32
+        }
+    }
+}
+
+pub fn payload_schema_enforcement_from_proto(
+    enforcement: i32,
+) -> Result<PayloadSchemaEnforcement, Status> {
+    match api::grpc::qdrant::PayloadSchemaEnforcement::from_i32(enforcement) {
+        Some(api::grpc::qdrant::PayloadSchemaEnforcement::Disabled) => {
+            Ok(PayloadSchemaEnforcement::Disabled)
+        }
+        Some(api::grpc::qdrant::PayloadSchemaEnforcement::Reject) => {
+            Ok(PayloadSchemaEnforcement::Reject)
+        }
+        Some(api::grpc::qdrant::PayloadSchemaEnforcement::Coerce) => {
+            Ok(PayloadSchemaEnforcement::Coerce)
+        }
+        None => Err(Status::invalid_argument(format!(
+            "Cannot convert payload schema enforcement: {enforcement}"
+        ))),
+    }
+}
+
 pub fn write_ordering_to_proto(ordering: WriteOrdering) -> api::grpc::qdrant::WriteOrdering {
     api::grpc::qdrant::WriteOrdering {
         r#type: match ordering {
@@ -289,6 +327,10 @@ impl TryFrom<api::grpc::qdrant::CollectionParamsDiff> for CollectionParamsDiff {
                 .transpose()?,
             read_fan_out_factor: value.read_fan_out_factor,
             on_disk_payload: value.on_disk_payload,
+            payload_schema_enforcement: value
+                .payload_schema_enforcement
+                .map(payload_schema_enforcement_from_proto)
+                .transpose()?,
         })
     }
 }
@@ -390,6 +432,10 @@ impl From<CollectionInfo> for api::grpc::qdrant::CollectionInfo {
                     write_consistency_factor: Some(config.params.write_consistency_factor.get()),
                     read_fan_out_factor: config.params.read_fan_out_factor,
                     sharding_method: config.params.sharding_method.map(sharding_method_to_proto),
+                    payload_schema_enforcement: config
+                        .params
+                        .payload_schema_enforcement
+                        .map(payload_schema_enforcement_to_proto),
                     sparse_vectors_config: config.params.sparse_vectors.map(|sparse_vectors| {
                         api::grpc::qdrant::SparseVectorConfig {
                             map: sparse_vectors
@@ -543,11 +589,11 @@ impl TryFrom<api::grpc::qdrant::VectorParams> for VectorParams {
     type Error = Status;
 
     fn try_from(vector_params: api::grpc::qdrant::VectorParams) -> Result<Self, Self::Error> {
-        Ok(Self {
+        let vector_params = Self {
             size: NonZeroU64::new(vector_params.size).ok_or_else(|| {
                 Status::invalid_argument("VectorParams size must be greater than zero")
             })?,
-            distance: from_grpc_dist(vector_params.distance)?,
+            distance: from_grpc_dist(vector_params.distance, vector_params.minkowski_p)?,
             hnsw_config: vector_params.hnsw_config.map(Into::into),
             quantization_config: vector_params
                 .quantization_config
@@ -559,7 +605,11 @@ impl TryFrom<api::grpc::qdrant::VectorParams> for VectorParams {
                 .multivector_config
                 .map(MultiVectorConfig::try_from)
                 .transpose()?,
-        })
+            prefix_size: vector_params.prefix_size.and_then(NonZeroU64::new),
+        };
+        validate_vector_params(&vector_params)
+            .map_err(|err| Status::invalid_argument(format!("Invalid VectorParams: {err}")))?;
+        Ok(vector_params)
     }
 }
 
@@ -570,6 +620,7 @@ fn convert_datatype_from_proto(datatype: Option<i32>) -> Result<Option<Datatype>
             match grpc_datatype {
                 api::grpc::qdrant::Datatype::Uint8 => Ok(Some(Datatype::Uint8)),
                 api::grpc::qdrant::Datatype::Float32 => Ok(Some(Datatype::Float32)),
+                api::grpc::qdrant::Datatype::Float16 => Ok(Some(Datatype::Float16)),
                 api::grpc::qdrant::Datatype::Default => Ok(None),
             }
         } else {
@@ -607,19 +658,97 @@ impl From<api::grpc::qdrant::Modifier> for Modifier {
     }
 }
 
-impl From<api::grpc::qdrant::SparseVectorParams> for SparseVectorParams {
-    fn from(sparse_vector_params: api::grpc::qdrant::SparseVectorParams) -> Self {
-        Self {
+impl TryFrom<api::grpc::qdrant::SparseVectorParams> for SparseVectorParams {
+    type Error = Status;
+
+    fn try_from(
+        sparse_vector_params: api::grpc::qdrant::SparseVectorParams,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
             index: sparse_vector_params
                 .index
-                .map(|index_config| SparseIndexParams {
-                    full_scan_threshold: index_config.full_scan_threshold.map(|v| v as usize),
-                    on_disk: index_config.on_disk,
-                }),
+                .map(|index_config| -> Result<_, Status> {
+                    Ok(SparseIndexParams {
+                        full_scan_threshold: index_config.full_scan_threshold.map(|v| v as usize),
+                        on_disk: index_config.on_disk,
+                        datatype: convert_datatype_from_proto(index_config.datatype)?,
+                        prune_top_k: index_config.prune_top_k.map(|v| v as usize),
+                        prune_threshold: index_config.prune_threshold,
+                        prune_oversampling: index_config.prune_oversampling,
+                    })
+                })
+                .transpose()?,
             modifier: sparse_vector_params
                 .modifier
                 .and_then(api::grpc::qdrant::Modifier::from_i32)
                 .map(Modifier::from),
+            bm25: sparse_vector_params
+                .bm25
+                .map(Bm25Config::try_from)
+                .transpose()?,
+        })
+    }
+}
+
+impl TryFrom<api::grpc::qdrant::Bm25Config> for Bm25Config {
+    type Error = Status;
+
+    fn try_from(value: api::grpc::qdrant::Bm25Config) -> Result<Self, Self::Error> {
+        let api::grpc::qdrant::Bm25Config {
+            field,
+            tokenizer,
+            min_token_len,
+            max_token_len,
+            lowercase,
+            k,
+            b,
+            avg_len,
+        } = value;
+        let tokenizer = match tokenizer {
+            None => TokenizerType::default(),
+            Some(tokenizer) => api::grpc::qdrant::TokenizerType::from_i32(tokenizer)
+                .ok_or_else(|| Status::invalid_argument("unknown tokenizer type"))?
+                .try_into()?,
+        };
+        if avg_len < 1.0 {
+            return Err(Status::invalid_argument(
+                "BM25 average document length must be at least 1",
+            ));
+        }
+        Ok(Self {
+            field: json_path_from_proto(&field)?,
+            tokenizer,
+            min_token_len: min_token_len.map(|v| v as usize),
+            max_token_len: max_token_len.map(|v| v as usize),
+            lowercase,
+            k,
+            b,
+            avg_len,
+        })
+    }
+}
+
+impl From<Bm25Config> for api::grpc::qdrant::Bm25Config {
+    fn from(value: Bm25Config) -> Self {
+        let Bm25Config {
+            field,
+            tokenizer,
+            min_token_len,
+            max_token_len,
+            lowercase,
+            k,
+            b,
+            avg_len,
+        } = value;
+        Self {
+            field: field.to_string(),
+            tokenizer: Some(api::grpc::qdrant::TokenizerType::from(tokenizer) as i32),
+            min_token_len: min_token_len.map(|v| v as u64),
+            max_token_len: max_token_len.map(|v| v as u64),
+            lowercase,
+            k,
+            b,
+            avg_len,
         }
     }
 }
@@ -640,11 +769,20 @@ impl From<SparseVectorParams> for api::grpc::qdrant::SparseVectorParams {
                 api::grpc::qdrant::SparseIndexConfig {
                     full_scan_threshold: index_config.full_scan_threshold.map(|v| v as u64),
                     on_disk: index_config.on_disk,
+                    datatype: index_config
+                        .datatype
+                        .map(|dt| api::grpc::qdrant::Datatype::from(dt).into()),
+                    prune_top_k: index_config.prune_top_k.map(|v| v as u64),
+                    prune_threshold: index_config.prune_threshold,
+                    prune_oversampling: index_config.prune_oversampling,
                 }
             }),
             modifier: sparse_vector_params
                 .modifier
                 .map(|modifier| api::grpc::qdrant::Modifier::from(modifier) as i32),
+            bm25: sparse_vector_params
+                .bm25
+                .map(api::grpc::qdrant::Bm25Config::from),
         }
     }
 }
@@ -702,13 +840,18 @@ impl TryFrom<api::grpc::qdrant::CollectionConfig> for CollectionConfig {
                             ),
                         },
                     },
-                    sparse_vectors: params.sparse_vectors_config.map(|sparse_vectors| {
-                        sparse_vectors
-                            .map
-                            .into_iter()
-                            .map(|(name, sparse_vector_params)| (name, sparse_vector_params.into()))
-                            .collect()
-                    }),
+                    sparse_vectors: params
+                        .sparse_vectors_config
+                        .map(|sparse_vectors| {
+                            sparse_vectors
+                                .map
+                                .into_iter()
+                                .map(|(name, sparse_vector_params)| {
+                                    Ok((name, sparse_vector_params.try_into()?))
+                                })
+                                .collect::<Result<_, Status>>()
+                        })
+                        .transpose()?,
                     shard_number: NonZeroU32::new(params.shard_number)
                         .ok_or_else(|| Status::invalid_argument("`shard_number` cannot be zero"))?,
                     on_disk_payload: params.on_disk_payload,
@@ -734,6 +877,10 @@ impl TryFrom<api::grpc::qdrant::CollectionConfig> for CollectionConfig {
                         .sharding_method
                         .map(sharding_method_from_proto)
                         .transpose()?,
+                    payload_schema_enforcement: params
+                        .payload_schema_enforcement
+                        .map(payload_schema_enforcement_from_proto)
+                        .transpose()?,
                 },
             },
             hnsw_config: match config.hnsw_config {
@@ -1002,6 +1149,259 @@ impl From<CountResult> for api::grpc::qdrant::CountResult {
     }
 }
 
+impl TryFrom<api::grpc::qdrant::IdfStatistics> for IdfStatistics {
+    type Error = Status;
+    fn try_from(value: api::grpc::qdrant::IdfStatistics) -> Result<Self, Self::Error> {
+        let api::grpc::qdrant::IdfStatistics {
+            point_count,
+            vectors,
+            shard_versions,
+        } = value;
+
+        let mut document_frequencies = HashMap::with_capacity(vectors.len());
+        for vector in vectors {
+            let api::grpc::qdrant::IdfDocumentFrequencies {
+                vector_name,
+                indices,
+                frequencies,
+            } = vector;
+            if indices.len() != frequencies.len() {
+                return Err(Status::invalid_argument(format!(
+                    "Document frequencies of vector {vector_name} don't match its indices"
+                )));
+            }
+            let frequencies = indices
+                .into_iter()
+                .zip(frequencies)
+                .map(|(index, frequency)| (index, frequency as usize))
+                .collect();
+            document_frequencies.insert(vector_name, frequencies);
+        }
+
+        Ok(Self {
+            point_count: point_count as usize,
+            document_frequencies,
+            shard_versions,
+        })
+    }
+}
+
+impl From<IdfStatistics> for api::grpc::qdrant::IdfStatistics {
+    fn from(value: IdfStatistics) -> Self {
+        let IdfStatistics {
+            point_count,
+            document_frequencies,
+            shard_versions,
+        } = value;
+
+        Self {
+            point_count: point_count as u64,
+            vectors: document_frequencies
+                .into_iter()
+                .map(|(vector_name, frequencies)| {
+                    let (indices, frequencies) = frequencies
+                        .into_iter()
+                        .map(|(index, frequency)| (index, frequency as u64))
+                        .unzip();
+                    api::grpc::qdrant::IdfDocumentFrequencies {
+                        vector_name,
+                        indices,
+                        frequencies,
+                    }
+                })
+                .collect(),
+            shard_versions,
+        }
+    }
+}
+
+impl TryFrom<api::grpc::qdrant::PayloadStatsPoints> for PayloadStatsRequest {
+    type Error = Status;
+    fn try_from(value: api::grpc::qdrant::PayloadStatsPoints) -> Result<Self, Self::Error> {
+        let api::grpc::qdrant::PayloadStatsPoints {
+            collection_name: _,
+            limit,
+            filter,
+        } = value;
+
+        Ok(Self {
+            limit: limit.map(|limit| limit as usize),
+            filter: filter.map(|f| f.try_into()).transpose()?,
+        })
+    }
+}
+
+impl From<PayloadValueType> for api::grpc::qdrant::PayloadValueType {
+    fn from(value: PayloadValueType) -> Self {
+        match value {
+            PayloadValueType::Keyword => api::grpc::qdrant::PayloadValueType::Keyword,
+            PayloadValueType::Integer => api::grpc::qdrant::PayloadValueType::Integer,
+            PayloadValueType::Float => api::grpc::qdrant::PayloadValueType::Float,
+            PayloadValueType::Bool => api::grpc::qdrant::PayloadValueType::Bool,
+            PayloadValueType::Geo => api::grpc::qdrant::PayloadValueType::Geo,
+            PayloadValueType::Datetime => api::grpc::qdrant::PayloadValueType::Datetime,
+            PayloadValueType::Null => api::grpc::qdrant::PayloadValueType::Null,
+        }
+    }
+}
+
+impl From<api::grpc::qdrant::PayloadValueType> for PayloadValueType {
+    fn from(value: api::grpc::qdrant::PayloadValueType) -> Self {
+        match value {
+            api::grpc::qdrant::PayloadValueType::Keyword => PayloadValueType::Keyword,
+            api::grpc::qdrant::PayloadValueType::Integer => PayloadValueType::Integer,
+            api::grpc::qdrant::PayloadValueType::Float => PayloadValueType::Float,
+            api::grpc::qdrant::PayloadValueType::Bool => PayloadValueType::Bool,
+            api::grpc::qdrant::PayloadValueType::Geo => PayloadValueType::Geo,
+            api::grpc::qdrant::PayloadValueType::Datetime => PayloadValueType::Datetime,
+            api::grpc::qdrant::PayloadValueType::Null => PayloadValueType::Null,
+        }
+    }
+}
+
+fn payload_value_types_to_grpc(
+    types: BTreeMap<PayloadValueType, usize>,
+) -> Vec<api::grpc::qdrant::PayloadValueTypeCount> {
+    types
+        .into_iter()
+        .map(
+            |(value_type, count)| api::grpc::qdrant::PayloadValueTypeCount {
+                value_type: api::grpc::qdrant::PayloadValueType::from(value_type) as i32,
+                count: count as u64,
+            },
+        )
+        .collect()
+}
+
+impl TryFrom<api::grpc::qdrant::PayloadStatsPartial> for PayloadStatsAccumulator {
+    type Error = Status;
+    fn try_from(value: api::grpc::qdrant::PayloadStatsPartial) -> Result<Self, Self::Error> {
+        let api::grpc::qdrant::PayloadStatsPartial {
+            points_sampled,
+            fields,
+        } = value;
+
+        let mut accumulator_fields = BTreeMap::new();
+        for field in fields {
+            let api::grpc::qdrant::PayloadFieldSketch {
+                path,
+                points,
+                types,
+                distinct_registers,
+            } = field;
+            if distinct_registers.len() != HLL_REGISTERS {
+                return Err(Status::invalid_argument(format!(
+                    "Distinct values sketch of field {path} must have {HLL_REGISTERS} registers, got {}",
+                    distinct_registers.len(),
+                )));
+            }
+            let mut field_types = BTreeMap::new();
+            for type_count in types {
+                let value_type =
+                    api::grpc::qdrant::PayloadValueType::from_i32(type_count.value_type)
+                        .ok_or_else(|| {
+                            Status::invalid_argument(format!(
+                                "Unknown payload value type: {}",
+                                type_count.value_type,
+                            ))
+                        })?;
+                *field_types
+                    .entry(PayloadValueType::from(value_type))
+                    .or_default() += type_count.count as usize;
+            }
+            let field = FieldAccumulator {
+                points: points as usize,
+                types: field_types,
+                distinct: HyperLogLog {
+                    registers: distinct_registers,
+                },
+            };
+            accumulator_fields.insert(path, field);
+        }
+
+        Ok(Self {
+            points_sampled: points_sampled as usize,
+            fields: accumulator_fields,
+        })
+    }
+}
+
+impl From<PayloadStatsAccumulator> for api::grpc::qdrant::PayloadStatsPartial {
+    fn from(value: PayloadStatsAccumulator) -> Self {
+        let PayloadStatsAccumulator {
+            points_sampled,
+            fields,
+        } = value;
+
+        Self {
+            points_sampled: points_sampled as u64,
+            fields: fields
+                .into_iter()
+                .map(|(path, field)| api::grpc::qdrant::PayloadFieldSketch {
+                    path,
+                    points: field.points as u64,
+                    types: payload_value_types_to_grpc(field.types),
+                    distinct_registers: field.distinct.registers,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<PayloadFieldStats> for api::grpc::qdrant::PayloadFieldStats {
+    fn from(value: PayloadFieldStats) -> Self {
+        let PayloadFieldStats {
+            types,
+            fill_rate,
+            distinct_values,
+            indexed,
+            suggested_index,
+        } = value;
+
+        Self {
+            types: payload_value_types_to_grpc(types),
+            fill_rate,
+            distinct_values: distinct_values as u64,
+            indexed,
+            suggested_index: suggested_index.map(|field_type| {
+                let field_type = match field_type {
+                    segment::types::PayloadSchemaType::Keyword => {
+                        api::grpc::qdrant::FieldType::Keyword
+                    }
+                    segment::types::PayloadSchemaType::Integer => {
+                        api::grpc::qdrant::FieldType::Integer
+                    }
+                    segment::types::PayloadSchemaType::Float => api::grpc::qdrant::FieldType::Float,
+                    segment::types::PayloadSchemaType::Geo => api::grpc::qdrant::FieldType::Geo,
+                    segment::types::PayloadSchemaType::Text => api::grpc::qdrant::FieldType::Text,
+                    segment::types::PayloadSchemaType::Bool => api::grpc::qdrant::FieldType::Bool,
+                    segment::types::PayloadSchemaType::Datetime => {
+                        api::grpc::qdrant::FieldType::Datetime
+                    }
+                };
+                field_type as i32
+            }),
+        }
+    }
+}
+
+impl From<PayloadStatsResult> for api::grpc::qdrant::PayloadStatsResult {
+    fn from(value: PayloadStatsResult) -> Self {
+        let PayloadStatsResult {
+            points_sampled,
+            fields,
+        } = value;
+
+        Self {
+            points_sampled: points_sampled as u64,
+            fields: fields
+                .into_iter()
+                .map(|(path, stats)| (path, stats.into()))
+                .collect(),
+        }
+    }
+}
+
 impl TryFrom<api::grpc::qdrant::SearchPoints> for CoreSearchRequest {
     type Error = Status;
     fn try_from(value: api::grpc::qdrant::SearchPoints) -> Result<Self, Self::Error> {
@@ -1132,6 +1532,14 @@ impl From<QueryEnum> for api::grpc::qdrant::QueryEnum {
                     },
                 )),
             },
+            QueryEnum::RecommendSumScores(named) => api::grpc::qdrant::QueryEnum {
+                query: Some(api::grpc::qdrant::query_enum::Query::RecommendSumScores(
+                    api::grpc::qdrant::RecoQuery {
+                        positives: named.query.0.positives.into_iter().map_into().collect(),
+                        negatives: named.query.0.negatives.into_iter().map_into().collect(),
+                    },
+                )),
+            },
         }
     }
 }
@@ -1275,6 +1683,23 @@ impl TryFrom<api::grpc::qdrant::CoreSearchPoints> for CoreSearchRequest {
                             using: value.vector_name,
                         })
                     }
+                    api::grpc::qdrant::query_enum::Query::RecommendSumScores(query) => {
+                        QueryEnum::RecommendSumScores(NamedQuery {
+                            query: RecoSumScoresQuery::new(
+                                query
+                                    .positives
+                                    .into_iter()
+                                    .map(TryInto::try_into)
+                                    .collect::<Result<_, _>>()?,
+                                query
+                                    .negatives
+                                    .into_iter()
+                                    .map(TryInto::try_into)
+                                    .collect::<Result<_, _>>()?,
+                            ),
+                            using: value.vector_name,
+                        })
+                    }
                 })
             })
             .transpose()?
@@ -1416,6 +1841,7 @@ impl From<api::grpc::qdrant::RecommendStrategy> for RecommendStrategy {
         match value {
             api::grpc::qdrant::RecommendStrategy::AverageVector => RecommendStrategy::AverageVector,
             api::grpc::qdrant::RecommendStrategy::BestScore => RecommendStrategy::BestScore,
+            api::grpc::qdrant::RecommendStrategy::SumScores => RecommendStrategy::SumScores,
         }
     }
 }
@@ -1528,6 +1954,18 @@ impl TryFrom<api::grpc::qdrant::VectorExample> for RecommendExample {
     }
 }
 
+impl TryFrom<api::grpc::qdrant::WeightedVectorExample> for WeightedExample {
+    type Error = Status;
+
+    fn try_from(value: api::grpc::qdrant::WeightedVectorExample) -> Result<Self, Self::Error> {
+        let api::grpc::qdrant::WeightedVectorExample { example, weight } = value;
+        let example = example
+            .ok_or_else(|| Status::invalid_argument("Example is required in a weighted term"))?
+            .try_into()?;
+        Ok(Self { example, weight })
+    }
+}
+
 impl TryFrom<api::grpc::qdrant::RecommendPoints> for RecommendRequestInternal {
     type Error = Status;
 
@@ -1658,6 +2096,9 @@ impl From<VectorParams> for api::grpc::qdrant::VectorParams {
                 Distance::Euclid => api::grpc::qdrant::Distance::Euclid,
                 Distance::Dot => api::grpc::qdrant::Distance::Dot,
                 Distance::Manhattan => api::grpc::qdrant::Distance::Manhattan,
+                Distance::Hamming => api::grpc::qdrant::Distance::Hamming,
+                Distance::Jaccard => api::grpc::qdrant::Distance::Jaccard,
+                Distance::Minkowski(_) => api::grpc::qdrant::Distance::Minkowski,
             }
             .into(),
             hnsw_config: value.hnsw_config.map(Into::into),
@@ -1669,6 +2110,11 @@ impl From<VectorParams> for api::grpc::qdrant::VectorParams {
             multivector_config: value
                 .multivec_config
                 .map(api::grpc::qdrant::MultiVectorConfig::from),
+            prefix_size: value.prefix_size.map(NonZeroU64::get),
+            minkowski_p: match value.distance {
+                Distance::Minkowski(p) => Some(p),
+                _ => None,
+            },
         }
     }
 }
@@ -1678,6 +2124,7 @@ impl From<Datatype> for api::grpc::qdrant::Datatype {
         match value {
             Datatype::Float32 => api::grpc::qdrant::Datatype::Float32,
             Datatype::Uint8 => api::grpc::qdrant::Datatype::Uint8,
+            Datatype::Float16 => api::grpc::qdrant::Datatype::Float16,
         }
     }
 }
@@ -1965,11 +2412,13 @@ impl TryFrom<api::grpc::qdrant::OrderBy> for OrderByInterface {
                     api::grpc::qdrant::start_from::Value::Timestamp(timestamp) => {
                         Ok(StartFrom::Datetime(try_date_time_from_proto(timestamp)?))
                     }
-                    api::grpc::qdrant::start_from::Value::Datetime(datetime_str) => Ok(
-                        StartFrom::Datetime(DateTimeWrapper::from_str(&datetime_str).map_err(
-                            |e| Status::invalid_argument(format!("Malformed datetime: {e}")),
-                        )?),
-                    ),
+                    api::grpc::qdrant::start_from::Value::Datetime(datetime_str) => {
+                        Ok(StartFrom::Datetime(
+                            DateTimeWrapper::from_expression(&datetime_str).map_err(|e| {
+                                Status::invalid_argument(format!("Malformed datetime: {e}"))
+                            })?,
+                        ))
+                    }
                 }
             })
             .transpose()?;
@@ -5,12 +5,15 @@ pub mod conversions;
 pub mod conversions_rest;
 pub mod operation_effect;
 pub mod payload_ops;
+pub mod payload_schema_enforcement;
+pub mod payload_stats;
 pub mod point_ops;
 pub mod query_enum;
 pub mod shard_key_selector;
 pub mod shard_selector_internal;
 pub mod shared_storage_config;
 pub mod snapshot_ops;
+pub mod sparse_stats;
 pub mod types;
 pub mod validation;
 pub mod vector_ops;
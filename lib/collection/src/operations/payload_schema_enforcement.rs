@@ -0,0 +1,270 @@
+//! Checking payload values of update operations against the payload index schema
+
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use segment::json_path::JsonPath;
+use segment::types::{
+    DateTimePayloadType, GeoPoint, Payload, PayloadFieldSchema, PayloadKeyType, PayloadSchemaType,
+};
+use serde::Deserialize;
+use serde_json::{Number, Value};
+
+use crate::config::PayloadSchemaEnforcement;
+use crate::operations::payload_ops::PayloadOps;
+use crate::operations::point_ops::{PointInsertOperationsInternal, PointOperations};
+use crate::operations::types::{CollectionError, CollectionResult};
+use crate::operations::CollectionUpdateOperations;
+
+/// Check payload values of the indexed fields in the operation against the payload index schema.
+///
+/// Depending on the collection configuration, values of unexpected type are either rejected
+/// or converted into the type of the indexed field.
+///
+/// Must be applied before the operation is written into the WAL, so that all replicas
+/// receive the same, already checked operation.
+pub fn enforce_payload_schema(
+    operation: &mut CollectionUpdateOperations,
+    schema: &HashMap<PayloadKeyType, PayloadFieldSchema>,
+    enforcement: PayloadSchemaEnforcement,
+) -> CollectionResult<()> {
+    if enforcement == PayloadSchemaEnforcement::Disabled || schema.is_empty() {
+        return Ok(());
+    }
+
+    match operation {
+        CollectionUpdateOperations::PointOperation(point_operation) => match point_operation {
+            PointOperations::UpsertPoints(PointInsertOperationsInternal::PointsBatch(batch)) => {
+                for payload in batch.payloads.iter_mut().flatten().flatten() {
+                    enforce_payload(payload, None, schema, enforcement)?;
+                }
+            }
+            PointOperations::UpsertPoints(PointInsertOperationsInternal::PointsList(points)) => {
+                for payload in points.iter_mut().filter_map(|point| point.payload.as_mut()) {
+                    enforce_payload(payload, None, schema, enforcement)?;
+                }
+            }
+            PointOperations::SyncPoints(operation) => {
+                for payload in operation
+                    .points
+                    .iter_mut()
+                    .filter_map(|point| point.payload.as_mut())
+                {
+                    enforce_payload(payload, None, schema, enforcement)?;
+                }
+            }
+            PointOperations::DeletePoints { .. } | PointOperations::DeletePointsByFilter(_) => {}
+        },
+        CollectionUpdateOperations::PayloadOperation(payload_operation) => {
+            match payload_operation {
+                PayloadOps::SetPayload(operation) => {
+                    enforce_payload(
+                        &mut operation.payload,
+                        operation.key.as_ref(),
+                        schema,
+                        enforcement,
+                    )?;
+                }
+                PayloadOps::OverwritePayload(operation) => {
+                    enforce_payload(&mut operation.payload, None, schema, enforcement)?;
+                }
+                PayloadOps::DeletePayload(_)
+                | PayloadOps::ClearPayload { .. }
+                | PayloadOps::ClearPayloadByFilter(_) => {}
+            }
+        }
+        CollectionUpdateOperations::VectorOperation(_)
+        | CollectionUpdateOperations::FieldIndexOperation(_) => {}
+    }
+
+    Ok(())
+}
+
+/// Check payload values against the schema.
+/// If `key` is specified, the payload is going to be set under this path.
+fn enforce_payload(
+    payload: &mut Payload,
+    key: Option<&JsonPath>,
+    schema: &HashMap<PayloadKeyType, PayloadFieldSchema>,
+    enforcement: PayloadSchemaEnforcement,
+) -> CollectionResult<()> {
+    for (indexed_path, field_schema) in schema {
+        let data_type = field_schema.data_type();
+
+        let values = match key {
+            None => indexed_path.value_get_mut(&mut payload.0),
+            Some(key) => match indexed_path.value_get_mut_under_key(key, &mut payload.0) {
+                Some(values) => values,
+                None => {
+                    // The whole payload becomes the value of the indexed field
+                    let value = Value::Object(payload.0.clone());
+                    if !value_matches_type(&value, data_type) {
+                        return Err(payload_schema_mismatch(indexed_path, data_type, &value));
+                    }
+                    continue;
+                }
+            },
+        };
+
+        for value in values {
+            let values = match value {
+                Value::Array(values) => values.iter_mut().collect(),
+                value => vec![value],
+            };
+            for value in values {
+                if value.is_null() || value_matches_type(value, data_type) {
+                    continue;
+                }
+                match coerce_value(value, data_type) {
+                    Some(coerced) if enforcement == PayloadSchemaEnforcement::Coerce => {
+                        *value = coerced;
+                    }
+                    _ => return Err(payload_schema_mismatch(indexed_path, data_type, value)),
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn payload_schema_mismatch(
+    path: &JsonPath,
+    data_type: PayloadSchemaType,
+    value: &Value,
+) -> CollectionError {
+    CollectionError::bad_input(format!(
+        "Payload value {value} at path `{path}` does not match the payload index schema, expected {data_type:?}",
+    ))
+}
+
+fn value_matches_type(value: &Value, data_type: PayloadSchemaType) -> bool {
+    match data_type {
+        PayloadSchemaType::Keyword | PayloadSchemaType::Text => value.is_string(),
+        PayloadSchemaType::Integer => value.is_i64(),
+        PayloadSchemaType::Float => value.is_number(),
+        PayloadSchemaType::Bool => value.is_boolean(),
+        PayloadSchemaType::Geo => GeoPoint::deserialize(value).is_ok(),
+        PayloadSchemaType::Datetime => value
+            .as_str()
+            .is_some_and(|s| DateTimePayloadType::from_str(s).is_ok()),
+    }
+}
+
+/// Convert value into the given type, if it can be done without losing information
+fn coerce_value(value: &Value, data_type: PayloadSchemaType) -> Option<Value> {
+    match (data_type, value) {
+        (PayloadSchemaType::Keyword | PayloadSchemaType::Text, Value::Number(number)) => {
+            Some(Value::String(number.to_string()))
+        }
+        (PayloadSchemaType::Keyword | PayloadSchemaType::Text, Value::Bool(boolean)) => {
+            Some(Value::String(boolean.to_string()))
+        }
+        (PayloadSchemaType::Integer, Value::String(s)) => {
+            s.trim().parse::<i64>().ok().map(Value::from)
+        }
+        (PayloadSchemaType::Integer, Value::Number(number)) => {
+            let float = number.as_f64()?;
+            let is_integer =
+                float.fract() == 0.0 && (i64::MIN as f64..i64::MAX as f64).contains(&float);
+            is_integer.then(|| Value::from(float as i64))
+        }
+        (PayloadSchemaType::Float, Value::String(s)) => {
+            let float = s.trim().parse::<f64>().ok()?;
+            Number::from_f64(float).map(Value::Number)
+        }
+        (PayloadSchemaType::Bool, Value::String(s)) => {
+            s.trim().parse::<bool>().ok().map(Value::from)
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::operations::payload_ops::SetPayloadOp;
+
+    fn set_payload(payload: serde_json::Value, key: Option<&str>) -> CollectionUpdateOperations {
+        CollectionUpdateOperations::PayloadOperation(PayloadOps::SetPayload(SetPayloadOp {
+            payload: serde_json::from_value(payload).unwrap(),
+            points: Some(vec![1.into()]),
+            filter: None,
+            key: key.map(|key| key.parse().unwrap()),
+        }))
+    }
+
+    fn enforced_payload(
+        payload: serde_json::Value,
+        key: Option<&str>,
+        enforcement: PayloadSchemaEnforcement,
+    ) -> CollectionResult<serde_json::Value> {
+        let schema = HashMap::from([
+            ("price".parse().unwrap(), PayloadSchemaType::Integer.into()),
+            ("city".parse().unwrap(), PayloadSchemaType::Keyword.into()),
+            (
+                "meta.tags[].name".parse().unwrap(),
+                PayloadSchemaType::Keyword.into(),
+            ),
+        ]);
+        let mut operation = set_payload(payload, key);
+        enforce_payload_schema(&mut operation, &schema, enforcement)?;
+        let CollectionUpdateOperations::PayloadOperation(PayloadOps::SetPayload(operation)) =
+            operation
+        else {
+            unreachable!();
+        };
+        Ok(serde_json::to_value(operation.payload).unwrap())
+    }
+
+    #[test]
+    fn test_payload_schema_enforcement() {
+        use PayloadSchemaEnforcement::{Coerce, Disabled, Reject};
+
+        // Not enforced by default
+        let payload = enforced_payload(json!({"price": "42"}), None, Disabled).unwrap();
+        assert_eq!(payload["price"], json!("42"));
+
+        let error = enforced_payload(json!({"price": "42"}), None, Reject).unwrap_err();
+        assert!(matches!(error, CollectionError::BadInput { .. }));
+        assert!(error.to_string().contains("`price`"), "{error}");
+        enforced_payload(json!({"price": 42, "city": ["Berlin", null]}), None, Reject).unwrap();
+
+        let payload = enforced_payload(
+            json!({"price": "43", "city": [10115, "Berlin"]}),
+            None,
+            Coerce,
+        )
+        .unwrap();
+        assert_eq!(payload["price"], json!(43));
+        assert_eq!(payload["city"], json!(["10115", "Berlin"]));
+
+        let error = enforced_payload(json!({"price": 43.5}), None, Coerce).unwrap_err();
+        assert!(error.to_string().contains("`price`"), "{error}");
+    }
+
+    #[test]
+    fn test_payload_schema_enforcement_with_key() {
+        use PayloadSchemaEnforcement::{Coerce, Reject};
+
+        // Nested values are resolved relative to the key
+        let payload = json!({"tags": [{"name": 1}, {"name": "b"}]});
+        let error = enforced_payload(payload.clone(), Some("meta"), Reject).unwrap_err();
+        assert!(error.to_string().contains("`meta.tags[].name`"), "{error}");
+        let payload = enforced_payload(payload, Some("meta"), Coerce).unwrap();
+        assert_eq!(payload, json!({"tags": [{"name": "1"}, {"name": "b"}]}));
+
+        // Key inside of an array of the indexed path
+        let payload = enforced_payload(json!({"name": 2}), Some("meta.tags[0]"), Coerce).unwrap();
+        assert_eq!(payload, json!({"name": "2"}));
+
+        // Unrelated key
+        enforced_payload(json!({"price": "a"}), Some("other"), Reject).unwrap();
+
+        // Payload becomes the value of the indexed field
+        let error = enforced_payload(json!({"a": 1}), Some("city"), Reject).unwrap_err();
+        assert!(error.to_string().contains("`city`"), "{error}");
+    }
+}
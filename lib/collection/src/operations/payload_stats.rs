@@ -0,0 +1,385 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::hash::Hasher;
+use std::str::FromStr;
+
+use fnv::FnvHasher;
+use schemars::JsonSchema;
+use segment::types::{DateTimePayloadType, Filter, Payload, PayloadSchemaType};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use validator::Validate;
+
+/// Default number of points sampled from each shard
+pub const DEFAULT_PAYLOAD_STATS_LIMIT: usize = 1000;
+
+/// Number of bits of the hash used to select a HyperLogLog register
+const HLL_PRECISION: u32 = 10;
+pub(crate) const HLL_REGISTERS: usize = 1 << HLL_PRECISION;
+
+/// Payload statistics request
+/// Samples payloads of the collection and reports which fields are present, their types and cardinality.
+#[derive(Deserialize, Serialize, JsonSchema, Validate, Clone, Debug, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub struct PayloadStatsRequest {
+    /// Maximum number of points to sample from each shard. Default: 1000
+    #[validate(range(min = 1, max = 100000))]
+    pub limit: Option<usize>,
+    /// Only sample points which satisfy this conditions
+    #[validate]
+    pub filter: Option<Filter>,
+}
+
+/// Type of a value observed in the payload
+#[derive(
+    Debug, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum PayloadValueType {
+    Keyword,
+    Integer,
+    Float,
+    Bool,
+    Geo,
+    Datetime,
+    Null,
+}
+
+impl PayloadValueType {
+    fn of(value: &Value) -> Option<Self> {
+        match value {
+            Value::Null => Some(Self::Null),
+            Value::Bool(_) => Some(Self::Bool),
+            Value::Number(num) if num.is_i64() || num.is_u64() => Some(Self::Integer),
+            Value::Number(_) => Some(Self::Float),
+            Value::String(str) if DateTimePayloadType::from_str(str).is_ok() => {
+                Some(Self::Datetime)
+            }
+            Value::String(_) => Some(Self::Keyword),
+            Value::Object(_) if is_geo_point(value) => Some(Self::Geo),
+            Value::Array(_) | Value::Object(_) => None,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, JsonSchema, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub struct PayloadFieldStats {
+    /// Number of observed values of each type
+    pub types: BTreeMap<PayloadValueType, usize>,
+    /// Fraction of sampled points which have this field
+    pub fill_rate: f64,
+    /// Estimated number of distinct values
+    pub distinct_values: usize,
+    /// Whether there is a payload index for this field
+    pub indexed: bool,
+    /// Type of payload index which could be created for this field, if it is not indexed yet
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suggested_index: Option<PayloadSchemaType>,
+}
+
+#[derive(Debug, Serialize, JsonSchema, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub struct PayloadStatsResult {
+    /// Number of points which payloads were sampled
+    pub points_sampled: usize,
+    /// Statistics for each JSON path found in the sampled payloads
+    pub fields: BTreeMap<String, PayloadFieldStats>,
+}
+
+/// Collects payload statistics of a single shard, results of several shards are combined with [`Self::merge`]
+#[derive(Debug, Default, Clone)]
+pub struct PayloadStatsAccumulator {
+    pub(crate) points_sampled: usize,
+    pub(crate) fields: BTreeMap<String, FieldAccumulator>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub(crate) struct FieldAccumulator {
+    pub(crate) points: usize,
+    pub(crate) types: BTreeMap<PayloadValueType, usize>,
+    pub(crate) distinct: HyperLogLog,
+}
+
+impl PayloadStatsAccumulator {
+    pub fn add_payload(&mut self, payload: &Payload) {
+        self.points_sampled += 1;
+
+        let mut values = Vec::new();
+        for (key, value) in payload.0.iter() {
+            collect_values(key.clone(), value, &mut values);
+        }
+
+        let mut seen_paths = BTreeSet::new();
+        for (path, value) in values {
+            let Some(value_type) = PayloadValueType::of(value) else {
+                continue;
+            };
+            let field = self.fields.entry(path.clone()).or_default();
+            *field.types.entry(value_type).or_default() += 1;
+            if value_type != PayloadValueType::Null {
+                field.distinct.add(hash_value(value));
+            }
+            if seen_paths.insert(path) {
+                field.points += 1;
+            }
+        }
+    }
+
+    pub fn merge(&mut self, other: Self) {
+        self.points_sampled += other.points_sampled;
+        for (path, other_field) in other.fields {
+            let field = self.fields.entry(path).or_default();
+            field.points += other_field.points;
+            for (value_type, count) in other_field.types {
+                *field.types.entry(value_type).or_default() += count;
+            }
+            field.distinct.merge(&other_field.distinct);
+        }
+    }
+
+    /// Build final statistics, `is_indexed` tells whether the given path has a payload index
+    pub fn finish(self, is_indexed: impl Fn(&str) -> bool) -> PayloadStatsResult {
+        let points_sampled = self.points_sampled;
+        let fields = self
+            .fields
+            .into_iter()
+            .map(|(path, field)| {
+                let indexed = is_indexed(&path);
+                let suggested_index = if indexed {
+                    None
+                } else {
+                    suggest_index(&field.types)
+                };
+                let fill_rate = if points_sampled == 0 {
+                    0.0
+                } else {
+                    field.points as f64 / points_sampled as f64
+                };
+                let stats = PayloadFieldStats {
+                    fill_rate,
+                    distinct_values: field.distinct.estimate(),
+                    indexed,
+                    suggested_index,
+                    types: field.types,
+                };
+                (path, stats)
+            })
+            .collect();
+
+        PayloadStatsResult {
+            points_sampled,
+            fields,
+        }
+    }
+}
+
+fn is_geo_point(value: &Value) -> bool {
+    let Value::Object(obj) = value else {
+        return false;
+    };
+    let lon = obj.get("lon").and_then(Value::as_f64);
+    let lat = obj.get("lat").and_then(Value::as_f64);
+    lon.is_some() && lat.is_some()
+}
+
+/// Flatten the value into leaf values, keyed by their JSON path.
+///
+/// Nested objects produce `a.b` paths, objects inside arrays produce `a[].b` paths
+/// and arrays of scalar values are reported under the path of the array itself.
+fn collect_values<'a>(path: String, value: &'a Value, values: &mut Vec<(String, &'a Value)>) {
+    match value {
+        Value::Object(obj) if !is_geo_point(value) => {
+            for (key, nested) in obj {
+                collect_values(format!("{path}.{key}"), nested, values);
+            }
+        }
+        Value::Array(array) => {
+            for item in array {
+                match item {
+                    Value::Object(_) if !is_geo_point(item) => {
+                        collect_values(format!("{path}[]"), item, values)
+                    }
+                    // Nested arrays are flattened
+                    Value::Array(_) => collect_values(path.clone(), item, values),
+                    _ => values.push((path.clone(), item)),
+                }
+            }
+        }
+        _ => values.push((path, value)),
+    }
+}
+
+/// Suggest a payload index type for the field, if all its values are of a compatible type
+fn suggest_index(types: &BTreeMap<PayloadValueType, usize>) -> Option<PayloadSchemaType> {
+    let mut non_null = types
+        .keys()
+        .copied()
+        .filter(|value_type| *value_type != PayloadValueType::Null);
+
+    let first = non_null.next()?;
+    let rest: Vec<_> = non_null.collect();
+
+    match (first, rest.as_slice()) {
+        (PayloadValueType::Keyword, []) => Some(PayloadSchemaType::Keyword),
+        (PayloadValueType::Integer, []) => Some(PayloadSchemaType::Integer),
+        (PayloadValueType::Integer, [PayloadValueType::Float]) => Some(PayloadSchemaType::Float),
+        (PayloadValueType::Float, []) => Some(PayloadSchemaType::Float),
+        (PayloadValueType::Bool, []) => Some(PayloadSchemaType::Bool),
+        (PayloadValueType::Geo, []) => Some(PayloadSchemaType::Geo),
+        (PayloadValueType::Datetime, []) => Some(PayloadSchemaType::Datetime),
+        _ => None,
+    }
+}
+
+fn hash_value(value: &Value) -> u64 {
+    let mut hasher = FnvHasher::default();
+    hasher.write(value.to_string().as_bytes());
+    // FNV has weak high bits, apply a finalizer to spread them over the whole hash
+    let mut hash = hasher.finish();
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(0xff51afd7ed558ccd);
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(0xc4ceb9fe1a85ec53);
+    hash ^ (hash >> 33)
+}
+
+/// Minimal HyperLogLog sketch for estimating number of distinct values.
+/// Sketches of different shards are merged by taking the maximum of each register.
+#[derive(Debug, Clone)]
+pub(crate) struct HyperLogLog {
+    pub(crate) registers: Vec<u8>,
+}
+
+impl Default for HyperLogLog {
+    fn default() -> Self {
+        Self {
+            registers: vec![0; HLL_REGISTERS],
+        }
+    }
+}
+
+impl HyperLogLog {
+    fn add(&mut self, hash: u64) {
+        let index = (hash >> (64 - HLL_PRECISION)) as usize;
+        let rest = hash << HLL_PRECISION;
+        let rank = (rest.leading_zeros() + 1).min(64 - HLL_PRECISION + 1) as u8;
+        self.registers[index] = self.registers[index].max(rank);
+    }
+
+    fn merge(&mut self, other: &Self) {
+        for (register, other) in self.registers.iter_mut().zip(&other.registers) {
+            *register = (*register).max(*other);
+        }
+    }
+
+    fn estimate(&self) -> usize {
+        let m = HLL_REGISTERS as f64;
+        let alpha = 0.7213 / (1.0 + 1.079 / m);
+        let sum: f64 = self
+            .registers
+            .iter()
+            .map(|&register| 2f64.powi(-i32::from(register)))
+            .sum();
+        let estimate = alpha * m * m / sum;
+
+        let zeros = self
+            .registers
+            .iter()
+            .filter(|&&register| register == 0)
+            .count();
+        let estimate = if estimate <= 2.5 * m && zeros > 0 {
+            // Linear counting for small cardinalities
+            m * (m / zeros as f64).ln()
+        } else {
+            estimate
+        };
+
+        estimate.round() as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn payload(value: Value) -> Payload {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_payload_stats_merge() {
+        let mut shard_a = PayloadStatsAccumulator::default();
+        let mut shard_b = PayloadStatsAccumulator::default();
+
+        for i in 0..100 {
+            let point = payload(json!({
+                "city": format!("city_{}", i % 10),
+                "price": i,
+                "meta": {"created": "2024-01-01T00:00:00Z", "tags": ["a", "b"]},
+                "items": [{"id": i}],
+            }));
+            if i % 2 == 0 {
+                shard_a.add_payload(&point);
+            } else {
+                shard_b.add_payload(&point);
+            }
+        }
+        shard_b.add_payload(&payload(
+            json!({"price": 1.5, "location": {"lon": 1.0, "lat": 2.0}}),
+        ));
+
+        shard_a.merge(shard_b);
+        let stats = shard_a.finish(|path| path == "city");
+
+        assert_eq!(stats.points_sampled, 101);
+
+        let city = &stats.fields["city"];
+        assert!(city.indexed);
+        assert_eq!(city.suggested_index, None);
+        assert_eq!(city.distinct_values, 10);
+        assert!((city.fill_rate - 100.0 / 101.0).abs() < 1e-9);
+
+        let price = &stats.fields["price"];
+        assert_eq!(price.types[&PayloadValueType::Integer], 100);
+        assert_eq!(price.types[&PayloadValueType::Float], 1);
+        assert_eq!(price.suggested_index, Some(PayloadSchemaType::Float));
+        assert!((95..=105).contains(&price.distinct_values));
+
+        let tags = &stats.fields["meta.tags"];
+        assert_eq!(tags.types[&PayloadValueType::Keyword], 200);
+        assert_eq!(tags.distinct_values, 2);
+
+        let created = &stats.fields["meta.created"];
+        assert_eq!(created.suggested_index, Some(PayloadSchemaType::Datetime));
+
+        assert_eq!(
+            stats.fields["items[].id"].suggested_index,
+            Some(PayloadSchemaType::Integer),
+        );
+        assert_eq!(
+            stats.fields["location"].suggested_index,
+            Some(PayloadSchemaType::Geo),
+        );
+    }
+
+    #[test]
+    fn test_payload_stats_grpc_roundtrip() {
+        let mut shard = PayloadStatsAccumulator::default();
+        for i in 0..50 {
+            shard.add_payload(&payload(
+                json!({"city": format!("city_{}", i % 5), "rating": i}),
+            ));
+        }
+        shard.add_payload(&payload(json!({"city": null})));
+
+        let grpc_partial = api::grpc::qdrant::PayloadStatsPartial::from(shard.clone());
+        let restored = PayloadStatsAccumulator::try_from(grpc_partial.clone()).unwrap();
+        assert_eq!(restored.finish(|_| false), shard.finish(|_| false));
+
+        let mut broken = grpc_partial;
+        broken.fields[0].distinct_registers.pop();
+        assert!(PayloadStatsAccumulator::try_from(broken).is_err());
+    }
+}
@@ -1,7 +1,7 @@
 use segment::data_types::vectors::{DenseVector, Named, NamedQuery, NamedVectorStruct, Vector};
 use segment::vector_storage::query::context_query::ContextQuery;
 use segment::vector_storage::query::discovery_query::DiscoveryQuery;
-use segment::vector_storage::query::reco_query::RecoQuery;
+use segment::vector_storage::query::reco_query::{RecoQuery, RecoSumScoresQuery};
 use sparse::common::sparse_vector::SparseVector;
 
 impl QueryEnum {
@@ -9,6 +9,7 @@ impl QueryEnum {
         match self {
             QueryEnum::Nearest(vector) => vector.get_name(),
             QueryEnum::RecommendBestScore(reco_query) => reco_query.get_name(),
+            QueryEnum::RecommendSumScores(reco_query) => reco_query.get_name(),
             QueryEnum::Discover(discovery_query) => discovery_query.get_name(),
             QueryEnum::Context(context_query) => context_query.get_name(),
         }
@@ -33,6 +34,15 @@ impl QueryEnum {
                     }
                 }
             }
+            QueryEnum::RecommendSumScores(reco_query) => {
+                let name = reco_query.get_name();
+                for vector in reco_query.query.flat_iter() {
+                    match vector {
+                        Vector::Sparse(sparse_vector) => f(name, sparse_vector),
+                        Vector::Dense(_) | Vector::MultiDense(_) => {}
+                    }
+                }
+            }
             QueryEnum::Discover(discovery_query) => {
                 let name = discovery_query.get_name();
                 for pair in discovery_query.query.flat_iter() {
@@ -59,6 +69,7 @@ impl QueryEnum {
 pub enum QueryEnum {
     Nearest(NamedVectorStruct),
     RecommendBestScore(NamedQuery<RecoQuery<Vector>>),
+    RecommendSumScores(NamedQuery<RecoSumScoresQuery<Vector>>),
     Discover(NamedQuery<DiscoveryQuery<Vector>>),
     Context(NamedQuery<ContextQuery<Vector>>),
 }
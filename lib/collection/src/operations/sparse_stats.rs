@@ -0,0 +1,28 @@
+use std::collections::BTreeMap;
+
+use schemars::JsonSchema;
+use segment::index::sparse_index::sparse_search_telemetry::SparseIndexStats;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+/// Default number of dimensions reported for each sparse vector
+pub const DEFAULT_SPARSE_STATS_LIMIT: usize = 10;
+
+/// Sparse vector statistics request
+/// Reports the shape of sparse inverted indexes of all shards of the collection.
+#[derive(Deserialize, Serialize, JsonSchema, Validate, Clone, Debug, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub struct SparseStatsRequest {
+    /// Name of the sparse vector to report. If not set, all sparse vectors are reported
+    pub using: Option<String>,
+    /// Number of dimensions with the highest document frequency to report. Default: 10
+    #[validate(range(min = 1, max = 10000))]
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Serialize, JsonSchema, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub struct SparseStatsResult {
+    /// Statistics of each sparse vector, merged over all shards of the collection
+    pub vectors: BTreeMap<String, SparseIndexStats>,
+}
@@ -21,6 +21,7 @@ use segment::data_types::order_by::OrderBy;
 use segment::data_types::vectors::{
     DenseVector, QueryVector, VectorRef, VectorStruct, DEFAULT_VECTOR_NAME,
 };
+use segment::index::sparse_index::bm25::Bm25Config;
 use segment::json_path::{JsonPath, JsonPathInterface};
 use segment::types::{
     Distance, Filter, MultiVectorConfig, Payload, PayloadIndexInfo, PayloadKeyType, PointIdType,
@@ -32,6 +33,7 @@ use serde;
 use serde::{Deserialize, Serialize};
 use serde_json::Error as JsonError;
 use sparse::common::sparse_vector::SparseVector;
+use sparse::common::types::DimId;
 use thiserror::Error;
 use tokio::sync::mpsc::error::SendError;
 use tokio::sync::oneshot::error::RecvError as OneshotRecvError;
@@ -413,6 +415,50 @@ pub struct SearchRequestBatch {
     pub searches: Vec<SearchRequest>,
 }
 
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub struct TextSearchRequest {
+    #[serde(flatten)]
+    #[validate]
+    pub text_search_request: TextSearchRequestInternal,
+    /// Specify in which shards to look for the points, if not specified - look in all shards
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shard_key: Option<ShardKeySelector>,
+}
+
+/// Text search request.
+/// The text is converted into a sparse query vector by the BM25 vectorizer of the sparse vector.
+#[derive(Deserialize, Serialize, JsonSchema, Validate, Clone, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub struct TextSearchRequestInternal {
+    /// Name of the sparse vector with configured BM25 vectorizer
+    pub using: String,
+    /// Look for points with text relevant to this query
+    #[validate(length(min = 1))]
+    pub text: String,
+    /// Look only for points which satisfies this conditions
+    #[validate]
+    pub filter: Option<Filter>,
+    /// Additional search params
+    #[validate]
+    pub params: Option<SearchParams>,
+    /// Max number of result to return
+    #[validate(range(min = 1))]
+    pub limit: usize,
+    /// Offset of the first result to return.
+    /// May be used to paginate results.
+    /// Note: large offset values may cause performance issues.
+    pub offset: Option<usize>,
+    /// Select which payload to return with the response. Default: None
+    pub with_payload: Option<WithPayloadInterface>,
+    /// Whether to return the point vector with the result?
+    #[serde(default, alias = "with_vectors")]
+    pub with_vector: Option<WithVector>,
+    /// Define a minimal score threshold for the result.
+    /// If defined, less relevant results will not be returned.
+    pub score_threshold: Option<ScoreType>,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct CoreSearchRequest {
     /// Every kind of query that can be performed on segment level
@@ -437,6 +483,65 @@ pub struct CoreSearchRequest {
 #[derive(Debug, Clone)]
 pub struct CoreSearchRequestBatch {
     pub searches: Vec<CoreSearchRequest>,
+    /// IDF statistics collected over all shards of the collection.
+    /// If not set, statistics of the local shard are used.
+    pub idf_statistics: Option<IdfStatistics>,
+}
+
+/// Dimensions of sparse vectors with `idf` modifier, which are required to score a search request
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IdfStatisticsRequest {
+    pub vectors: HashMap<String, Vec<DimId>>,
+}
+
+impl IdfStatisticsRequest {
+    pub fn is_empty(&self) -> bool {
+        self.vectors.is_empty()
+    }
+}
+
+/// Statistics required to compute Inverse Document Frequency of sparse vector dimensions
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IdfStatistics {
+    /// Number of points, over which document frequencies were collected
+    pub point_count: usize,
+    /// Number of points containing each dimension, per vector name
+    pub document_frequencies: HashMap<String, HashMap<DimId, usize>>,
+    /// Version of every shard, over which statistics were collected
+    pub shard_versions: HashMap<ShardId, SeqNumberType>,
+}
+
+/// IDF statistics collected from a single shard
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ShardIdfStatistics {
+    /// Latest operation applied to the shard, statistics change only along with it
+    pub version: SeqNumberType,
+    pub statistics: IdfStatistics,
+}
+
+impl IdfStatistics {
+    /// Add up statistics collected from a disjoint set of points, e.g. another shard
+    pub fn merge(&mut self, other: IdfStatistics) {
+        self.point_count += other.point_count;
+        self.shard_versions.extend(other.shard_versions);
+        for (vector_name, frequencies) in other.document_frequencies {
+            let merged = self.document_frequencies.entry(vector_name).or_default();
+            for (dim_id, frequency) in frequencies {
+                *merged.entry(dim_id).or_default() += frequency;
+            }
+        }
+    }
+
+    /// Check if statistics are available for all requested dimensions
+    pub fn covers(&self, request: &IdfStatisticsRequest) -> bool {
+        request.vectors.iter().all(|(vector_name, indices)| {
+            self.document_frequencies
+                .get(vector_name)
+                .map_or(false, |frequencies| {
+                    indices.iter().all(|index| frequencies.contains_key(index))
+                })
+        })
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone)]
@@ -545,12 +650,17 @@ impl From<u64> for RecommendExample {
 ///   examples, its score is then chosen from the `max(max_pos_score, max_neg_score)`.
 ///   If the `max_neg_score` is chosen then it is squared and negated, otherwise it is just
 ///   the `max_pos_score`.
+///
+/// * `sum_scores` - Uses custom search objective. Each candidate is compared against all
+///   examples, its score is the sum of all its positive scores minus the sum of all its
+///   negative scores.
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Default, PartialEq, Clone, Copy)]
 #[serde(rename_all = "snake_case")]
 pub enum RecommendStrategy {
     #[default]
     AverageVector,
     BestScore,
+    SumScores,
 }
 
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq)]
@@ -814,6 +924,79 @@ pub struct DiscoverRequestBatch {
     pub searches: Vec<DiscoverRequest>,
 }
 
+/// Example multiplied by a weight, a single term of the vector arithmetic query
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone, PartialEq)]
+pub struct WeightedExample {
+    /// Id of a stored point or a raw vector
+    #[validate]
+    pub example: RecommendExample,
+    /// Multiplier of the vector, negative weights subtract the vector from the query
+    pub weight: f32,
+}
+
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone)]
+pub struct ArithmeticRequest {
+    #[serde(flatten)]
+    #[validate]
+    pub arithmetic_request: ArithmeticRequestInternal,
+    /// Specify in which shards to look for the points, if not specified - look in all shards
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shard_key: Option<ShardKeySelector>,
+}
+
+/// Search with a weighted combination of vectors, e.g. `vector(A) - vector(B) + vector(C)`.
+/// Each term is either an id of a point, which vector is looked up the same way as in
+/// recommendations, or a raw vector. Referenced points are excluded from the result.
+#[derive(Deserialize, Serialize, JsonSchema, Validate, Clone, Debug, PartialEq)]
+pub struct ArithmeticRequestInternal {
+    /// Vectors to combine into the query, each multiplied by its weight
+    #[validate]
+    pub terms: Vec<WeightedExample>,
+
+    /// If true - divide the combination by the sum of weights, producing a weighted average.
+    /// Default: false
+    #[serde(default)]
+    pub average: Option<bool>,
+
+    /// Look only for points which satisfies this conditions
+    #[validate]
+    pub filter: Option<Filter>,
+
+    /// Additional search params
+    #[validate]
+    pub params: Option<SearchParams>,
+
+    /// Max number of result to return
+    #[serde(alias = "top")]
+    #[validate(range(min = 1))]
+    pub limit: usize,
+
+    /// Offset of the first result to return.
+    /// May be used to paginate results.
+    /// Note: large offset values may cause performance issues.
+    pub offset: Option<usize>,
+
+    /// Select which payload to return with the response. Default: None
+    pub with_payload: Option<WithPayloadInterface>,
+
+    /// Whether to return the point vector with the result?
+    #[serde(default, alias = "with_vectors")]
+    pub with_vector: Option<WithVector>,
+
+    /// Define a minimal score threshold for the result.
+    /// If defined, less similar results will not be returned.
+    pub score_threshold: Option<ScoreType>,
+
+    /// Define which vector to search in, if not specified - try to use default vector
+    #[serde(default)]
+    pub using: Option<UsingVector>,
+
+    /// The location used to lookup vectors. If not specified - use current collection.
+    /// Note: the other collection should have the same vector size as the current collection
+    #[serde(default)]
+    pub lookup_from: Option<LookupLocation>,
+}
+
 #[derive(Debug, Serialize, JsonSchema, Clone)]
 pub struct PointGroup {
     /// Scored points that have the same value of the group_by key
@@ -1262,10 +1445,12 @@ impl Record {
 /// Choosing different datatypes allows to optimize memory usage and performance vs accuracy.
 /// - For `float32` datatype - vectors are stored as single-precision floating point numbers, 4bytes.
 /// - For `uint8` datatype - vectors are stored as unsigned 8-bit integers, 1byte. It expects vector elements to be in range `[0, 255]`.
+/// - For `float16` datatype - vectors are stored as half-precision floating point numbers, 2bytes.
 pub enum Datatype {
     #[default]
     Float32,
     Uint8,
+    Float16,
 }
 
 impl From<Datatype> for VectorStorageDatatype {
@@ -1273,12 +1458,14 @@ impl From<Datatype> for VectorStorageDatatype {
         match value {
             Datatype::Float32 => VectorStorageDatatype::Float32,
             Datatype::Uint8 => VectorStorageDatatype::Uint8,
+            Datatype::Float16 => VectorStorageDatatype::Float16,
         }
     }
 }
 
 /// Params of single vector data storage
 #[derive(Debug, Hash, Deserialize, Serialize, JsonSchema, Validate, Clone, PartialEq, Eq)]
+#[validate(schema(function = "validate_vector_params"))]
 #[serde(rename_all = "snake_case")]
 pub struct VectorParams {
     /// Size of a vectors used
@@ -1291,6 +1478,7 @@ pub struct VectorParams {
     #[validate]
     pub hnsw_config: Option<HnswConfigDiff>,
     /// Custom params for quantization. If none - values from collection configuration are used.
+    /// Vectors with `Jaccard` or `Minkowski` distance can't be quantized.
     #[serde(
         default,
         alias = "quantization",
@@ -1308,6 +1496,61 @@ pub struct VectorParams {
 
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub multivec_config: Option<MultiVectorConfig>,
+
+    /// If set, HNSW index and quantization are built over only this many first dimensions of the
+    /// vectors, as with Matryoshka embeddings.
+    /// Found candidates are always rescored with full vectors, use `oversampling` search param to
+    /// rescore more of them.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prefix_size: Option<NonZeroU64>,
+}
+
+/// Distance must have a metric implementation.
+/// Multivector config must be compatible with the distance.
+/// Prefix search is only supported for single float vectors, and the prefix must be shorter than
+/// the vector itself.
+pub fn validate_vector_params(params: &VectorParams) -> Result<(), ValidationError> {
+    if let Err(message) = params.distance.validate() {
+        let mut error = ValidationError::new("distance");
+        error.message = Some(message.into());
+        return Err(error);
+    }
+    if params.quantization_config.is_some() && !params.distance.is_quantizable() {
+        let mut error = ValidationError::new("quantization_config");
+        error.message = Some(
+            format!(
+                "quantization is not supported for {:?} distance",
+                params.distance,
+            )
+            .into(),
+        );
+        return Err(error);
+    }
+    if let Some(multivec_config) = &params.multivec_config {
+        if let Err(message) = multivec_config.validate(params.distance) {
+            let mut error = ValidationError::new("multivec_config");
+            error.message = Some(message.into());
+            return Err(error);
+        }
+    }
+    let Some(prefix_size) = params.prefix_size else {
+        return Ok(());
+    };
+    let error = |message: &'static str| {
+        let mut error = ValidationError::new("prefix_size");
+        error.message = Some(message.into());
+        error
+    };
+    if prefix_size >= params.size {
+        return Err(error("prefix_size must be less than size"));
+    }
+    if params.multivec_config.is_some() {
+        return Err(error("prefix_size is not supported for multivectors"));
+    }
+    if !matches!(params.datatype, None | Some(Datatype::Float32)) {
+        return Err(error("prefix_size is only supported for float32 vectors"));
+    }
+    Ok(())
 }
 
 /// Validate the value is in `[1, 65536]` or `None`.
@@ -1348,12 +1591,20 @@ pub enum Modifier {
 pub struct SparseVectorParams {
     /// Custom params for index. If none - values from collection configuration are used.
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub index: Option<SparseIndexParams>,
 
     /// Configures addition value modifications for sparse vectors.
     /// Default: none
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub modifier: Option<Modifier>,
+
+    /// If set, vectors are derived from the text of the payload field on upsert,
+    /// and can be searched with a raw text query.
+    /// Use together with `idf` modifier for BM25 scoring.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub bm25: Option<Bm25Config>,
 }
 
 impl Anonymize for SparseVectorParams {
@@ -1361,12 +1612,13 @@ impl Anonymize for SparseVectorParams {
         Self {
             index: self.index.anonymize(),
             modifier: self.modifier.clone(),
+            bm25: self.bm25.clone(),
         }
     }
 }
 
 /// Configuration for sparse inverted index.
-#[derive(Debug, Hash, Deserialize, Serialize, JsonSchema, Copy, Clone, PartialEq, Eq, Default)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Copy, Clone, PartialEq, Default)]
 #[serde(rename_all = "snake_case")]
 pub struct SparseIndexParams {
     /// We prefer a full scan search upto (excluding) this number of vectors.
@@ -1377,22 +1629,77 @@ pub struct SparseIndexParams {
     /// Store index on disk. If set to false, the index will be stored in RAM. Default: false
     #[serde(skip_serializing_if = "Option::is_none")]
     pub on_disk: Option<bool>,
+    /// Defines which datatype should be used for the index.
+    /// Choosing different datatypes allows to optimize memory usage and performance vs accuracy.
+    /// - For `float32` datatype - weights are stored as single-precision floating point numbers, 4bytes.
+    /// - For `float16` datatype - weights are stored as half-precision floating point numbers, 2bytes.
+    /// - For `uint8` datatype - weights are quantized to unsigned 8-bit integers, 1byte.
+    ///
+    /// Only applies to immutable indexes. Default: float32
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub datatype: Option<Datatype>,
+    /// If set, only this number of dimensions with the largest absolute weights of each vector is indexed.
+    /// Search results are rescored with the original vectors, so scores stay exact,
+    /// but points may be missed if all their matching dimensions are pruned.
+    /// `idf` statistics are counted before pruning.
+    ///
+    /// Only applies to immutable indexes. Default: no pruning
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 1))]
+    pub prune_top_k: Option<usize>,
+    /// If set, dimensions with absolute weight below this threshold are not indexed.
+    /// Search results are rescored with the original vectors, so scores stay exact.
+    ///
+    /// Only applies to immutable indexes. Default: no pruning
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 0.0))]
+    pub prune_threshold: Option<f32>,
+    /// Retrieve this many times more candidates from a pruned index before rescoring them
+    /// with the original vectors. Larger values reduce the number of missed points at the cost of speed.
+    ///
+    /// Only applies to pruned immutable indexes. Default: 2.0
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 1.0))]
+    pub prune_oversampling: Option<f64>,
 }
 
+impl std::hash::Hash for SparseIndexParams {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.full_scan_threshold.hash(state);
+        self.on_disk.hash(state);
+        self.datatype.hash(state);
+        self.prune_top_k.hash(state);
+    }
+}
+
+impl Eq for SparseIndexParams {}
+
 impl Anonymize for SparseIndexParams {
     fn anonymize(&self) -> Self {
         SparseIndexParams {
             full_scan_threshold: self.full_scan_threshold,
             on_disk: self.on_disk,
+            datatype: self.datatype,
+            prune_top_k: self.prune_top_k,
+            prune_threshold: self.prune_threshold,
+            prune_oversampling: self.prune_oversampling,
         }
     }
 }
 
 impl SparseIndexParams {
-    pub fn new(full_scan_threshold: Option<usize>, on_disk: Option<bool>) -> Self {
+    pub fn new(
+        full_scan_threshold: Option<usize>,
+        on_disk: Option<bool>,
+        datatype: Option<Datatype>,
+    ) -> Self {
         SparseIndexParams {
             full_scan_threshold,
             on_disk,
+            datatype,
+            prune_top_k: None,
+            prune_threshold: None,
+            prune_oversampling: None,
         }
     }
 
@@ -1403,6 +1710,18 @@ impl SparseIndexParams {
         if let Some(on_disk) = other.on_disk {
             self.on_disk = Some(on_disk);
         }
+        if let Some(datatype) = other.datatype {
+            self.datatype = Some(datatype);
+        }
+        if let Some(prune_top_k) = other.prune_top_k {
+            self.prune_top_k = Some(prune_top_k);
+        }
+        if let Some(prune_threshold) = other.prune_threshold {
+            self.prune_threshold = Some(prune_threshold);
+        }
+        if let Some(prune_oversampling) = other.prune_oversampling {
+            self.prune_oversampling = Some(prune_oversampling);
+        }
     }
 }
 
@@ -1808,6 +2127,7 @@ impl From<QueryEnum> for QueryVector {
         match query {
             QueryEnum::Nearest(named) => QueryVector::Nearest(named.into()),
             QueryEnum::RecommendBestScore(named) => QueryVector::Recommend(named.query),
+            QueryEnum::RecommendSumScores(named) => QueryVector::RecommendSumScores(named.query),
             QueryEnum::Discover(named) => QueryVector::Discovery(named.query),
             QueryEnum::Context(named) => QueryVector::Context(named.query),
         }
@@ -20,6 +20,7 @@ impl VectorParamsBuilder {
                 on_disk: None,
                 datatype: None,
                 multivec_config: None,
+                prefix_size: None,
             },
         }
     }
@@ -49,6 +50,11 @@ impl VectorParamsBuilder {
         self
     }
 
+    pub fn with_prefix_size(mut self, prefix_size: u64) -> Self {
+        self.vector_params.prefix_size = NonZeroU64::new(prefix_size);
+        self
+    }
+
     pub fn build(self) -> VectorParams {
         self.vector_params
     }
@@ -9,7 +9,7 @@ use segment::data_types::vectors::{
 use segment::types::{
     Condition, ExtendedPointId, Filter, HasIdCondition, PointIdType, ScoredPoint,
 };
-use segment::vector_storage::query::reco_query::RecoQuery;
+use segment::vector_storage::query::reco_query::{RecoQuery, RecoSumScoresQuery};
 use sparse::common::sparse_vector::SparseVector;
 use tokio::sync::RwLockReadGuard;
 
@@ -158,10 +158,22 @@ pub fn recommend_into_core_search(
         RecommendStrategy::AverageVector => {
             recommend_by_avg_vector(request, reference_vectors_ids, all_vectors_records_map)
         }
-        RecommendStrategy::BestScore => Ok(recommend_by_best_score(
+        RecommendStrategy::BestScore => Ok(recommend_by_custom_score(
             request,
             reference_vectors_ids,
             all_vectors_records_map,
+            |query, using| QueryEnum::RecommendBestScore(NamedQuery { query, using }),
+        )),
+        RecommendStrategy::SumScores => Ok(recommend_by_custom_score(
+            request,
+            reference_vectors_ids,
+            all_vectors_records_map,
+            |query, using| {
+                QueryEnum::RecommendSumScores(NamedQuery {
+                    query: RecoSumScoresQuery(query),
+                    using,
+                })
+            },
         )),
     }
 }
@@ -209,7 +221,7 @@ where
                     });
                 }
             }
-            RecommendStrategy::BestScore => {
+            RecommendStrategy::BestScore | RecommendStrategy::SumScores => {
                 if request.positive.is_empty() && request.negative.is_empty() {
                     return Err(CollectionError::BadRequest {
                         description: "At least one positive or negative vector ID required with this strategy"
@@ -249,6 +261,7 @@ where
 
             let core_search_batch_request = CoreSearchRequestBatch {
                 searches: core_searches,
+                idf_statistics: None,
             };
 
             requests.push(collection.core_search_batch(
@@ -342,10 +355,13 @@ fn recommend_by_avg_vector(
     })
 }
 
-fn recommend_by_best_score(
+/// Build a search request with a custom scoring query, constructed from the resolved examples
+/// by `build_query`.
+fn recommend_by_custom_score(
     request: RecommendRequestInternal,
     reference_vectors_ids: Vec<PointIdType>,
     all_vectors_records_map: &ReferencedVectors,
+    build_query: impl FnOnce(RecoQuery<Vector>, Option<String>) -> QueryEnum,
 ) -> CoreSearchRequest {
     let lookup_vector_name = request.get_search_vector_name();
 
@@ -380,12 +396,12 @@ fn recommend_by_best_score(
         lookup_collection_name,
     );
 
-    let query = QueryEnum::RecommendBestScore(NamedQuery {
-        query: RecoQuery::new(positive, negative),
-        using: using.map(|x| match x {
+    let query = build_query(
+        RecoQuery::new(positive, negative),
+        using.map(|x| match x {
             UsingVector::Name(name) => name,
         }),
-    });
+    );
 
     CoreSearchRequest {
         query,
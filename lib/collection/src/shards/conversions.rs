@@ -376,6 +376,10 @@ pub fn internal_create_index(
                     api::grpc::qdrant::FieldType::Integer as i32,
                     Some(integer_params.into()),
                 ),
+                PayloadSchemaParams::Keyword(keyword_params) => (
+                    api::grpc::qdrant::FieldType::Keyword as i32,
+                    Some(keyword_params.into()),
+                ),
             },
         })
         .map(|(field_type, field_params)| (Some(field_type), field_params))
@@ -1,17 +1,22 @@
+use std::collections::HashMap;
 use std::path::Path;
 use std::sync::Arc;
 use std::time::Duration;
 
 use async_trait::async_trait;
 use segment::data_types::order_by::OrderBy;
+use segment::index::sparse_index::sparse_search_telemetry::SparseIndexStatistics;
 use segment::types::{
     ExtendedPointId, Filter, ScoredPoint, WithPayload, WithPayloadInterface, WithVector,
 };
 use tokio::runtime::Handle;
 
+use crate::operations::payload_stats::{PayloadStatsAccumulator, PayloadStatsRequest};
+use crate::operations::sparse_stats::SparseStatsRequest;
 use crate::operations::types::{
     CollectionError, CollectionInfo, CollectionResult, CoreSearchRequestBatch,
-    CountRequestInternal, CountResult, PointRequestInternal, Record, UpdateResult,
+    CountRequestInternal, CountResult, IdfStatisticsRequest, PointRequestInternal, Record,
+    ShardIdfStatistics, UpdateResult,
 };
 use crate::operations::OperationWithClockTag;
 use crate::shards::shard_trait::ShardOperation;
@@ -92,6 +97,27 @@ impl ShardOperation for DummyShard {
         self.dummy()
     }
 
+    async fn idf_statistics(
+        &self,
+        _: Arc<IdfStatisticsRequest>,
+    ) -> CollectionResult<ShardIdfStatistics> {
+        self.dummy()
+    }
+
+    async fn payload_stats(
+        &self,
+        _: Arc<PayloadStatsRequest>,
+    ) -> CollectionResult<PayloadStatsAccumulator> {
+        self.dummy()
+    }
+
+    async fn sparse_stats(
+        &self,
+        _: Arc<SparseStatsRequest>,
+    ) -> CollectionResult<HashMap<String, SparseIndexStatistics>> {
+        self.dummy()
+    }
+
     async fn retrieve(
         &self,
         _: Arc<PointRequestInternal>,
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::path::Path;
 use std::sync::Arc;
 use std::time::Duration;
@@ -5,6 +6,7 @@ use std::time::Duration;
 use async_trait::async_trait;
 use common::types::TelemetryDetail;
 use segment::data_types::order_by::OrderBy;
+use segment::index::sparse_index::sparse_search_telemetry::SparseIndexStatistics;
 use segment::types::{
     ExtendedPointId, Filter, PointIdType, ScoredPoint, WithPayload, WithPayloadInterface,
     WithVector,
@@ -13,10 +15,13 @@ use tokio::runtime::Handle;
 use tokio::sync::Mutex;
 
 use super::update_tracker::UpdateTracker;
+use crate::operations::payload_stats::{PayloadStatsAccumulator, PayloadStatsRequest};
 use crate::operations::point_ops::{PointOperations, PointStruct, PointSyncOperation};
+use crate::operations::sparse_stats::SparseStatsRequest;
 use crate::operations::types::{
     CollectionError, CollectionInfo, CollectionResult, CoreSearchRequestBatch,
-    CountRequestInternal, CountResult, PointRequestInternal, Record, UpdateResult, UpdateStatus,
+    CountRequestInternal, CountResult, IdfStatisticsRequest, PointRequestInternal, Record,
+    ShardIdfStatistics, UpdateResult, UpdateStatus,
 };
 use crate::operations::{
     CollectionUpdateOperations, CreateIndex, FieldIndexOperations, OperationWithClockTag,
@@ -260,6 +265,30 @@ impl ShardOperation for ForwardProxyShard {
         local_shard.count(request).await
     }
 
+    async fn idf_statistics(
+        &self,
+        request: Arc<IdfStatisticsRequest>,
+    ) -> CollectionResult<ShardIdfStatistics> {
+        let local_shard = &self.wrapped_shard;
+        local_shard.idf_statistics(request).await
+    }
+
+    async fn payload_stats(
+        &self,
+        request: Arc<PayloadStatsRequest>,
+    ) -> CollectionResult<PayloadStatsAccumulator> {
+        let local_shard = &self.wrapped_shard;
+        local_shard.payload_stats(request).await
+    }
+
+    async fn sparse_stats(
+        &self,
+        request: Arc<SparseStatsRequest>,
+    ) -> CollectionResult<HashMap<String, SparseIndexStatistics>> {
+        let local_shard = &self.wrapped_shard;
+        local_shard.sparse_stats(request).await
+    }
+
     async fn retrieve(
         &self,
         request: Arc<PointRequestInternal>,
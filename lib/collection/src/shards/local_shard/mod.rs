@@ -125,7 +125,7 @@ impl LocalShard {
 
     #[allow(clippy::too_many_arguments)]
     pub async fn new(
-        segment_holder: SegmentHolder,
+        mut segment_holder: SegmentHolder,
         collection_config: Arc<TokioRwLock<CollectionConfig>>,
         shared_storage_config: Arc<SharedStorageConfig>,
         wal: SerdeWal<OperationWithClockTag>,
@@ -135,8 +135,9 @@ impl LocalShard {
         clocks: LocalShardClocks,
         update_runtime: Handle,
     ) -> Self {
-        let segment_holder = Arc::new(RwLock::new(segment_holder));
         let config = collection_config.read().await;
+        segment_holder.sparse_vectorizers = config.params.sparse_vectorizers();
+        let segment_holder = Arc::new(RwLock::new(segment_holder));
         let locked_wal = Arc::new(ParkingMutex::new(wal));
         let optimizers_log = Arc::new(ParkingMutex::new(Default::default()));
 
@@ -611,6 +612,7 @@ impl LocalShard {
             &config.quantization_config,
         );
         update_handler.optimizers = new_optimizers;
+        self.segments.write().sparse_vectorizers = config.params.sparse_vectorizers();
         update_handler.flush_interval_sec = config.optimizer_config.flush_interval_sec;
         update_handler.max_optimization_threads = config.optimizer_config.max_optimization_threads;
         update_handler.run_workers(update_receiver);
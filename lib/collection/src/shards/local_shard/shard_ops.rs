@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -5,6 +6,8 @@ use async_trait::async_trait;
 use futures::future::try_join_all;
 use itertools::Itertools;
 use segment::data_types::order_by::{Direction, OrderBy};
+use segment::index::sparse_index::sparse_search_telemetry::SparseIndexStatistics;
+use segment::spaces::tools::merge_samples;
 use segment::types::{
     ExtendedPointId, Filter, ScoredPoint, WithPayload, WithPayloadInterface, WithVector,
 };
@@ -14,10 +17,15 @@ use tokio::sync::oneshot;
 use crate::collection_manager::holders::segment_holder::LockedSegment;
 use crate::collection_manager::segments_searcher::SegmentsSearcher;
 use crate::common::stopping_guard::StoppingGuard;
+use crate::operations::payload_stats::{
+    PayloadStatsAccumulator, PayloadStatsRequest, DEFAULT_PAYLOAD_STATS_LIMIT,
+};
 use crate::operations::query_enum::QueryEnum;
+use crate::operations::sparse_stats::SparseStatsRequest;
 use crate::operations::types::{
     CollectionError, CollectionInfo, CollectionResult, CoreSearchRequestBatch,
-    CountRequestInternal, CountResult, PointRequestInternal, Record, UpdateResult, UpdateStatus,
+    CountRequestInternal, CountResult, IdfStatisticsRequest, PointRequestInternal, Record,
+    ShardIdfStatistics, UpdateResult, UpdateStatus,
 };
 use crate::operations::OperationWithClockTag;
 use crate::shards::local_shard::LocalShard;
@@ -83,6 +91,7 @@ impl LocalShard {
                         }
                         // Don't post-process if we are dealing with custom scoring
                         QueryEnum::RecommendBestScore(_)
+                        | QueryEnum::RecommendSumScores(_)
                         | QueryEnum::Discover(_)
                         | QueryEnum::Context(_) => {}
                     };
@@ -348,6 +357,87 @@ impl ShardOperation for LocalShard {
         Ok(CountResult { count: total_count })
     }
 
+    async fn idf_statistics(
+        &self,
+        request: Arc<IdfStatisticsRequest>,
+    ) -> CollectionResult<ShardIdfStatistics> {
+        SegmentsSearcher::idf_statistics(self.segments.clone(), request).await
+    }
+
+    async fn payload_stats(
+        &self,
+        request: Arc<PayloadStatsRequest>,
+    ) -> CollectionResult<PayloadStatsAccumulator> {
+        let limit = request.limit.unwrap_or(DEFAULT_PAYLOAD_STATS_LIMIT);
+
+        // Do blocking calls in a blocking task: `segment.get().read()` calls might block async runtime
+        let sample_filtered = |segment: LockedSegment| {
+            let filter = request.filter.clone();
+            tokio::task::spawn_blocking(move || {
+                segment.get().read().sample_filtered(filter.as_ref(), limit)
+            })
+        };
+        let segments: Vec<_> = self
+            .segments()
+            .read()
+            .non_appendable_then_appendable_segments()
+            .collect();
+        let samples = try_join_all(segments.into_iter().map(sample_filtered)).await?;
+
+        // Sample uniformly among all matching points, so the result is not biased towards low ids
+        let (mut sampled_ids, _) = merge_samples(samples, limit, &mut rand::thread_rng());
+        // Same point might be present in multiple segments during optimization
+        sampled_ids.sort_unstable();
+        sampled_ids.dedup();
+
+        let records = SegmentsSearcher::retrieve(
+            self.segments(),
+            &sampled_ids,
+            &WithPayload::from(true),
+            &WithVector::Bool(false),
+        )?;
+
+        let mut accumulator = PayloadStatsAccumulator::default();
+        for payload in records.iter().filter_map(|record| record.payload.as_ref()) {
+            accumulator.add_payload(payload);
+        }
+        Ok(accumulator)
+    }
+
+    async fn sparse_stats(
+        &self,
+        request: Arc<SparseStatsRequest>,
+    ) -> CollectionResult<HashMap<String, SparseIndexStatistics>> {
+        // Do blocking calls in a blocking task: `segment.get().read()` calls might block async runtime
+        let segment_statistics = |segment: LockedSegment| {
+            tokio::task::spawn_blocking(move || segment.get().read().sparse_index_statistics())
+        };
+        let segments: Vec<_> = self
+            .segments()
+            .read()
+            .iter()
+            .map(|(_id, segment)| segment.clone())
+            .collect();
+        let segments_statistics =
+            try_join_all(segments.into_iter().map(segment_statistics)).await?;
+
+        let mut statistics: HashMap<String, SparseIndexStatistics> = HashMap::new();
+        for (vector_name, vector_statistics) in segments_statistics.into_iter().flatten() {
+            if request
+                .using
+                .as_ref()
+                .map_or(false, |using| using != &vector_name)
+            {
+                continue;
+            }
+            statistics
+                .entry(vector_name)
+                .or_default()
+                .merge(vector_statistics);
+        }
+        Ok(statistics)
+    }
+
     async fn retrieve(
         &self,
         request: Arc<PointRequestInternal>,
@@ -1,4 +1,4 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
 use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
@@ -7,6 +7,7 @@ use std::time::Duration;
 use async_trait::async_trait;
 use common::types::TelemetryDetail;
 use segment::data_types::order_by::OrderBy;
+use segment::index::sparse_index::sparse_search_telemetry::SparseIndexStatistics;
 use segment::types::{
     ExtendedPointId, Filter, PointIdType, ScoredPoint, WithPayload, WithPayloadInterface,
     WithVector,
@@ -19,9 +20,12 @@ use super::update_tracker::UpdateTracker;
 use crate::operations::operation_effect::{
     EstimateOperationEffectArea, OperationEffectArea, PointsOperationEffect,
 };
+use crate::operations::payload_stats::{PayloadStatsAccumulator, PayloadStatsRequest};
+use crate::operations::sparse_stats::SparseStatsRequest;
 use crate::operations::types::{
     CollectionError, CollectionInfo, CollectionResult, CoreSearchRequestBatch,
-    CountRequestInternal, CountResult, PointRequestInternal, Record, UpdateResult,
+    CountRequestInternal, CountResult, IdfStatisticsRequest, PointRequestInternal, Record,
+    ShardIdfStatistics, UpdateResult,
 };
 use crate::operations::OperationWithClockTag;
 use crate::shards::local_shard::LocalShard;
@@ -233,6 +237,33 @@ impl ShardOperation for ProxyShard {
         local_shard.count(request).await
     }
 
+    /// Forward read-only `idf_statistics` to `wrapped_shard`
+    async fn idf_statistics(
+        &self,
+        request: Arc<IdfStatisticsRequest>,
+    ) -> CollectionResult<ShardIdfStatistics> {
+        let local_shard = &self.wrapped_shard;
+        local_shard.idf_statistics(request).await
+    }
+
+    /// Forward read-only `payload_stats` to `wrapped_shard`
+    async fn payload_stats(
+        &self,
+        request: Arc<PayloadStatsRequest>,
+    ) -> CollectionResult<PayloadStatsAccumulator> {
+        let local_shard = &self.wrapped_shard;
+        local_shard.payload_stats(request).await
+    }
+
+    /// Forward read-only `sparse_stats` to `wrapped_shard`
+    async fn sparse_stats(
+        &self,
+        request: Arc<SparseStatsRequest>,
+    ) -> CollectionResult<HashMap<String, SparseIndexStatistics>> {
+        let local_shard = &self.wrapped_shard;
+        local_shard.sparse_stats(request).await
+    }
+
     /// Forward read-only `retrieve` to `wrapped_shard`
     async fn retrieve(
         &self,
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::path::Path;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
@@ -7,6 +8,7 @@ use async_trait::async_trait;
 use common::types::TelemetryDetail;
 use parking_lot::Mutex as ParkingMutex;
 use segment::data_types::order_by::OrderBy;
+use segment::index::sparse_index::sparse_search_telemetry::SparseIndexStatistics;
 use segment::types::{
     ExtendedPointId, Filter, ScoredPoint, WithPayload, WithPayloadInterface, WithVector,
 };
@@ -17,10 +19,13 @@ use super::remote_shard::RemoteShard;
 use super::transfer::driver::MAX_RETRY_COUNT;
 use super::transfer::transfer_tasks_pool::TransferTaskProgress;
 use super::update_tracker::UpdateTracker;
+use crate::operations::payload_stats::{PayloadStatsAccumulator, PayloadStatsRequest};
 use crate::operations::point_ops::WriteOrdering;
+use crate::operations::sparse_stats::SparseStatsRequest;
 use crate::operations::types::{
     CollectionError, CollectionInfo, CollectionResult, CoreSearchRequestBatch,
-    CountRequestInternal, CountResult, PointRequestInternal, Record, UpdateResult,
+    CountRequestInternal, CountResult, IdfStatisticsRequest, PointRequestInternal, Record,
+    ShardIdfStatistics, UpdateResult,
 };
 use crate::operations::OperationWithClockTag;
 use crate::shards::local_shard::LocalShard;
@@ -277,6 +282,42 @@ impl ShardOperation for QueueProxyShard {
             .await
     }
 
+    /// Forward read-only `idf_statistics` to `wrapped_shard`
+    async fn idf_statistics(
+        &self,
+        request: Arc<IdfStatisticsRequest>,
+    ) -> CollectionResult<ShardIdfStatistics> {
+        self.inner
+            .as_ref()
+            .expect("Queue proxy has been finalized")
+            .idf_statistics(request)
+            .await
+    }
+
+    /// Forward read-only `payload_stats` to `wrapped_shard`
+    async fn payload_stats(
+        &self,
+        request: Arc<PayloadStatsRequest>,
+    ) -> CollectionResult<PayloadStatsAccumulator> {
+        self.inner
+            .as_ref()
+            .expect("Queue proxy has been finalized")
+            .payload_stats(request)
+            .await
+    }
+
+    /// Forward read-only `sparse_stats` to `wrapped_shard`
+    async fn sparse_stats(
+        &self,
+        request: Arc<SparseStatsRequest>,
+    ) -> CollectionResult<HashMap<String, SparseIndexStatistics>> {
+        self.inner
+            .as_ref()
+            .expect("Queue proxy has been finalized")
+            .sparse_stats(request)
+            .await
+    }
+
     /// Forward read-only `retrieve` to `wrapped_shard`
     async fn retrieve(
         &self,
@@ -550,6 +591,33 @@ impl ShardOperation for Inner {
         local_shard.count(request).await
     }
 
+    /// Forward read-only `idf_statistics` to `wrapped_shard`
+    async fn idf_statistics(
+        &self,
+        request: Arc<IdfStatisticsRequest>,
+    ) -> CollectionResult<ShardIdfStatistics> {
+        let local_shard = &self.wrapped_shard;
+        local_shard.idf_statistics(request).await
+    }
+
+    /// Forward read-only `payload_stats` to `wrapped_shard`
+    async fn payload_stats(
+        &self,
+        request: Arc<PayloadStatsRequest>,
+    ) -> CollectionResult<PayloadStatsAccumulator> {
+        let local_shard = &self.wrapped_shard;
+        local_shard.payload_stats(request).await
+    }
+
+    /// Forward read-only `sparse_stats` to `wrapped_shard`
+    async fn sparse_stats(
+        &self,
+        request: Arc<SparseStatsRequest>,
+    ) -> CollectionResult<HashMap<String, SparseIndexStatistics>> {
+        let local_shard = &self.wrapped_shard;
+        local_shard.sparse_stats(request).await
+    }
+
     /// Forward read-only `retrieve` to `wrapped_shard`
     async fn retrieve(
         &self,
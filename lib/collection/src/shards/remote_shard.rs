@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::future::Future;
 use std::path::Path;
 use std::sync::Arc;
@@ -11,9 +12,11 @@ use api::grpc::qdrant::shard_snapshots_client::ShardSnapshotsClient;
 use api::grpc::qdrant::{
     CollectionOperationResponse, CoreSearchBatchPointsInternal, CountPoints, CountPointsInternal,
     GetCollectionInfoRequest, GetCollectionInfoRequestInternal, GetPoints, GetPointsInternal,
-    GetShardRecoveryPointRequest, HealthCheckRequest, InitiateShardTransferRequest,
+    GetShardRecoveryPointRequest, HealthCheckRequest, IdfStatisticsInternal, IdfVectorIndices,
+    InitiateShardTransferRequest, PayloadStatsInternal, PayloadStatsPoints,
     RecoverShardSnapshotRequest, RecoverSnapshotResponse, ScrollPoints, ScrollPointsInternal,
-    ShardSnapshotLocation, UpdateShardCutoffPointRequest, WaitForShardStateRequest,
+    ShardSnapshotLocation, SparseStatsInternal, UpdateShardCutoffPointRequest,
+    WaitForShardStateRequest,
 };
 use api::grpc::transport_channel_pool::{AddTimeout, MAX_GRPC_CHANNEL_TIMEOUT};
 use async_trait::async_trait;
@@ -23,6 +26,7 @@ use segment::common::operation_time_statistics::{
     OperationDurationsAggregator, ScopeDurationMeasurer,
 };
 use segment::data_types::order_by::OrderBy;
+use segment::index::sparse_index::sparse_search_telemetry::SparseIndexStatistics;
 use segment::types::{
     ExtendedPointId, Filter, ScoredPoint, WithPayload, WithPayloadInterface, WithVector,
 };
@@ -39,12 +43,14 @@ use super::local_shard::clock_map::RecoveryPoint;
 use super::replica_set::ReplicaState;
 use crate::operations::conversions::try_record_from_grpc;
 use crate::operations::payload_ops::PayloadOps;
+use crate::operations::payload_stats::{PayloadStatsAccumulator, PayloadStatsRequest};
 use crate::operations::point_ops::{PointOperations, WriteOrdering};
 use crate::operations::snapshot_ops::SnapshotPriority;
+use crate::operations::sparse_stats::SparseStatsRequest;
 use crate::operations::types::{
     CollectionError, CollectionInfo, CollectionResult, CoreSearchRequest, CoreSearchRequestBatch,
-    CountRequestInternal, CountResult, PointRequestInternal, Record, SearchRequestInternal,
-    UpdateResult,
+    CountRequestInternal, CountResult, IdfStatistics, IdfStatisticsRequest, PointRequestInternal,
+    Record, SearchRequestInternal, ShardIdfStatistics, UpdateResult,
 };
 use crate::operations::vector_ops::VectorOperations;
 use crate::operations::{CollectionUpdateOperations, FieldIndexOperations, OperationWithClockTag};
@@ -733,6 +739,7 @@ impl ShardOperation for RemoteShard {
             search_points,
             shard_id: Some(self.id),
             timeout: timeout.map(|t| t.as_secs()),
+            idf_statistics: batch_request.idf_statistics.clone().map(Into::into),
         };
         let search_batch_response = self
             .with_points_client(|mut client| async move {
@@ -800,6 +807,100 @@ impl ShardOperation for RemoteShard {
         )
     }
 
+    async fn idf_statistics(
+        &self,
+        request: Arc<IdfStatisticsRequest>,
+    ) -> CollectionResult<ShardIdfStatistics> {
+        let vectors = request
+            .vectors
+            .iter()
+            .map(|(vector_name, indices)| IdfVectorIndices {
+                vector_name: vector_name.clone(),
+                indices: indices.clone(),
+            })
+            .collect();
+
+        let request = &IdfStatisticsInternal {
+            collection_name: self.collection_id.clone(),
+            vectors,
+            shard_id: Some(self.id),
+        };
+        let idf_statistics_response = self
+            .with_points_client(|mut client| async move {
+                client
+                    .idf_statistics(tonic::Request::new(request.clone()))
+                    .await
+            })
+            .await?
+            .into_inner();
+        let idf_statistics = idf_statistics_response.result.ok_or_else(|| {
+            CollectionError::service_error("Unexpected empty IdfStatistics".to_string())
+        })?;
+        let mut statistics = IdfStatistics::try_from(idf_statistics)?;
+        let version = statistics.shard_versions.remove(&self.id).ok_or_else(|| {
+            CollectionError::service_error(format!(
+                "IdfStatistics are missing version of shard {}",
+                self.id,
+            ))
+        })?;
+        Ok(ShardIdfStatistics {
+            version,
+            statistics,
+        })
+    }
+
+    async fn payload_stats(
+        &self,
+        request: Arc<PayloadStatsRequest>,
+    ) -> CollectionResult<PayloadStatsAccumulator> {
+        let payload_stats_points = PayloadStatsPoints {
+            collection_name: self.collection_id.clone(),
+            limit: request.limit.map(|limit| limit as u64),
+            filter: request.filter.clone().map(|f| f.into()),
+        };
+
+        let request = &PayloadStatsInternal {
+            payload_stats_points: Some(payload_stats_points),
+            shard_id: Some(self.id),
+        };
+        let payload_stats_response = self
+            .with_points_client(|mut client| async move {
+                client
+                    .payload_stats(tonic::Request::new(request.clone()))
+                    .await
+            })
+            .await?
+            .into_inner();
+        let payload_stats = payload_stats_response.result.ok_or_else(|| {
+            CollectionError::service_error("Unexpected empty PayloadStatsPartial".to_string())
+        })?;
+        Ok(PayloadStatsAccumulator::try_from(payload_stats)?)
+    }
+
+    async fn sparse_stats(
+        &self,
+        request: Arc<SparseStatsRequest>,
+    ) -> CollectionResult<HashMap<String, SparseIndexStatistics>> {
+        let request = &SparseStatsInternal {
+            collection_name: self.collection_id.clone(),
+            using: request.using.clone(),
+            shard_id: Some(self.id),
+        };
+        let sparse_stats_response = self
+            .with_points_client(|mut client| async move {
+                client
+                    .sparse_stats(tonic::Request::new(request.clone()))
+                    .await
+            })
+            .await?
+            .into_inner();
+        Ok(sparse_stats_response
+            .result
+            .into_iter()
+            .map(<(String, SparseIndexStatistics)>::from)
+            .collect())
+    }
+
     async fn retrieve(
         &self,
         request: Arc<PointRequestInternal>,
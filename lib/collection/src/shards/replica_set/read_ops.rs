@@ -1,12 +1,16 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 
 use futures::FutureExt as _;
 use segment::data_types::order_by::OrderBy;
+use segment::index::sparse_index::sparse_search_telemetry::SparseIndexStatistics;
 use segment::types::*;
 
 use super::ShardReplicaSet;
 use crate::operations::consistency_params::ReadConsistency;
+use crate::operations::payload_stats::{PayloadStatsAccumulator, PayloadStatsRequest};
+use crate::operations::sparse_stats::SparseStatsRequest;
 use crate::operations::types::*;
 
 impl ShardReplicaSet {
@@ -93,6 +97,60 @@ impl ShardReplicaSet {
         .await
     }
 
+    pub async fn idf_statistics(
+        &self,
+        request: Arc<IdfStatisticsRequest>,
+        local_only: bool,
+    ) -> CollectionResult<IdfStatistics> {
+        // Replicas hold the same points, so statistics of any of them are sufficient
+        let ShardIdfStatistics {
+            version,
+            mut statistics,
+        } = self
+            .execute_read_operation(
+                |shard| {
+                    let request = request.clone();
+                    async move { shard.idf_statistics(request).await }.boxed()
+                },
+                local_only,
+            )
+            .await?;
+        statistics.shard_versions.insert(self.shard_id, version);
+        Ok(statistics)
+    }
+
+    pub async fn payload_stats(
+        &self,
+        request: Arc<PayloadStatsRequest>,
+        local_only: bool,
+    ) -> CollectionResult<PayloadStatsAccumulator> {
+        // Every replica samples its own points, so a single replica per shard is queried
+        self.execute_read_operation(
+            |shard| {
+                let request = request.clone();
+                async move { shard.payload_stats(request).await }.boxed()
+            },
+            local_only,
+        )
+        .await
+    }
+
+    pub async fn sparse_stats(
+        &self,
+        request: Arc<SparseStatsRequest>,
+        local_only: bool,
+    ) -> CollectionResult<HashMap<String, SparseIndexStatistics>> {
+        // Replicas hold the same points, so statistics of any of them are sufficient
+        self.execute_read_operation(
+            |shard| {
+                let request = request.clone();
+                async move { shard.sparse_stats(request).await }.boxed()
+            },
+            local_only,
+        )
+        .await
+    }
+
     pub async fn retrieve(
         &self,
         request: Arc<PointRequestInternal>,
@@ -1,11 +1,15 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 
 use async_trait::async_trait;
 use segment::data_types::order_by::OrderBy;
+use segment::index::sparse_index::sparse_search_telemetry::SparseIndexStatistics;
 use segment::types::*;
 use tokio::runtime::Handle;
 
+use crate::operations::payload_stats::{PayloadStatsAccumulator, PayloadStatsRequest};
+use crate::operations::sparse_stats::SparseStatsRequest;
 use crate::operations::types::*;
 use crate::operations::OperationWithClockTag;
 
@@ -40,6 +44,21 @@ pub trait ShardOperation {
 
     async fn count(&self, request: Arc<CountRequestInternal>) -> CollectionResult<CountResult>;
 
+    async fn idf_statistics(
+        &self,
+        request: Arc<IdfStatisticsRequest>,
+    ) -> CollectionResult<ShardIdfStatistics>;
+
+    async fn payload_stats(
+        &self,
+        request: Arc<PayloadStatsRequest>,
+    ) -> CollectionResult<PayloadStatsAccumulator>;
+
+    async fn sparse_stats(
+        &self,
+        request: Arc<SparseStatsRequest>,
+    ) -> CollectionResult<HashMap<String, SparseIndexStatistics>>;
+
     async fn retrieve(
         &self,
         request: Arc<PointRequestInternal>,
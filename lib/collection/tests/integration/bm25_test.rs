@@ -0,0 +1,225 @@
+use std::collections::BTreeMap;
+use std::num::NonZeroU32;
+use std::path::Path;
+
+use api::rest::VectorStruct;
+use collection::collection::Collection;
+use collection::config::{CollectionConfig, CollectionParams, WalConfig};
+use collection::operations::payload_ops::{DeletePayloadOp, PayloadOps, SetPayloadOp};
+use collection::operations::point_ops::{
+    PointInsertOperationsInternal, PointOperations, PointStruct, WriteOrdering,
+};
+use collection::operations::shard_selector_internal::ShardSelectorInternal;
+use collection::operations::types::{
+    CollectionError, Modifier, SparseVectorParams, SparseVectorsConfig, TextSearchRequestInternal,
+};
+use collection::operations::vector_params_builder::VectorParamsBuilder;
+use collection::operations::CollectionUpdateOperations;
+use segment::data_types::text_index::TokenizerType;
+use segment::index::sparse_index::bm25::Bm25Config;
+use segment::types::{Distance, ExtendedPointId};
+use serde_json::json;
+use tempfile::Builder;
+
+use crate::common::{new_local_collection, N_SHARDS, TEST_OPTIMIZERS_CONFIG};
+
+const BM25_VECTOR_NAME: &str = "bm25";
+
+fn bm25_config() -> Bm25Config {
+    Bm25Config {
+        field: "text".parse().unwrap(),
+        tokenizer: TokenizerType::Word,
+        min_token_len: None,
+        max_token_len: None,
+        lowercase: None,
+        k: None,
+        b: None,
+        avg_len: 4.0,
+    }
+}
+
+async fn bm25_collection(collection_dir: &Path) -> Collection {
+    let wal_config = WalConfig {
+        wal_capacity_mb: 1,
+        wal_segments_ahead: 0,
+    };
+
+    let sparse_vectors = BTreeMap::from([(
+        BM25_VECTOR_NAME.to_string(),
+        SparseVectorParams {
+            index: None,
+            modifier: Some(Modifier::Idf),
+            bm25: Some(bm25_config()),
+        },
+    )]);
+
+    let collection_params = CollectionParams {
+        vectors: VectorParamsBuilder::new(4, Distance::Dot).build().into(),
+        sparse_vectors: Some(sparse_vectors),
+        shard_number: NonZeroU32::new(N_SHARDS).unwrap(),
+        ..CollectionParams::empty()
+    };
+
+    let collection_config = CollectionConfig {
+        params: collection_params,
+        optimizer_config: TEST_OPTIMIZERS_CONFIG.clone(),
+        wal_config,
+        hnsw_config: Default::default(),
+        quantization_config: Default::default(),
+    };
+
+    let snapshot_path = collection_dir.join("snapshots");
+    let collection = new_local_collection(
+        "test".to_string(),
+        collection_dir,
+        &snapshot_path,
+        &collection_config,
+    )
+    .await
+    .unwrap();
+
+    let texts = [
+        "the quick brown fox",
+        "the lazy dog sleeps",
+        "quick quick fox",
+        "nothing relevant here",
+    ];
+
+    // Points only have dense vectors, sparse ones are derived from the payload
+    let points = texts
+        .iter()
+        .enumerate()
+        .map(|(id, text)| PointStruct {
+            id: (id as u64).into(),
+            vector: VectorStruct::Single(vec![1.0, 0.0, 0.0, 0.0]),
+            payload: Some(json!({ "text": text }).into()),
+        })
+        .collect();
+
+    let insert_points = CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(
+        PointInsertOperationsInternal::PointsList(points),
+    ));
+    collection
+        .update_from_client_simple(insert_points, true, WriteOrdering::default())
+        .await
+        .unwrap();
+
+    collection
+}
+
+async fn search_text(collection: &Collection, text: &str) -> Vec<ExtendedPointId> {
+    let request = TextSearchRequestInternal {
+        using: BM25_VECTOR_NAME.to_string(),
+        text: text.to_string(),
+        filter: None,
+        params: None,
+        limit: 10,
+        offset: None,
+        with_payload: None,
+        with_vector: None,
+        score_threshold: None,
+    };
+
+    let result = collection
+        .search_text(request, None, &ShardSelectorInternal::All, None)
+        .await
+        .unwrap();
+
+    result.iter().map(|point| point.id).collect()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_bm25_text_search() {
+    let collection_dir = Builder::new().prefix("test_bm25").tempdir().unwrap();
+    let collection = bm25_collection(collection_dir.path()).await;
+
+    assert_eq!(
+        search_text(&collection, "Quick FOX").await,
+        vec![ExtendedPointId::from(2u64), ExtendedPointId::from(0u64)],
+        "only points containing query terms are found, frequent terms in short texts score higher",
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_bm25_payload_update() {
+    let collection_dir = Builder::new().prefix("test_bm25").tempdir().unwrap();
+    let collection = bm25_collection(collection_dir.path()).await;
+
+    // Vectors follow the changed text
+    let set_payload =
+        CollectionUpdateOperations::PayloadOperation(PayloadOps::SetPayload(SetPayloadOp {
+            payload: json!({ "text": "a sleeping fox" }).into(),
+            points: Some(vec![1.into()]),
+            filter: None,
+            key: None,
+        }));
+    collection
+        .update_from_client_simple(set_payload, true, WriteOrdering::default())
+        .await
+        .unwrap();
+    assert_eq!(
+        search_text(&collection, "lazy").await,
+        Vec::<ExtendedPointId>::new()
+    );
+    assert_eq!(
+        search_text(&collection, "sleeping").await,
+        vec![ExtendedPointId::from(1u64)]
+    );
+
+    // Vectors are removed together with the text
+    let delete_payload =
+        CollectionUpdateOperations::PayloadOperation(PayloadOps::DeletePayload(DeletePayloadOp {
+            keys: vec!["text".parse().unwrap()],
+            points: Some(vec![2.into()]),
+            filter: None,
+        }));
+    collection
+        .update_from_client_simple(delete_payload, true, WriteOrdering::default())
+        .await
+        .unwrap();
+    let clear_payload = CollectionUpdateOperations::PayloadOperation(PayloadOps::ClearPayload {
+        points: vec![0.into()],
+    });
+    collection
+        .update_from_client_simple(clear_payload, true, WriteOrdering::default())
+        .await
+        .unwrap();
+    assert_eq!(
+        search_text(&collection, "quick fox").await,
+        vec![ExtendedPointId::from(1u64)]
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_bm25_config_update() {
+    let collection_dir = Builder::new().prefix("test_bm25").tempdir().unwrap();
+    let collection = bm25_collection(collection_dir.path()).await;
+
+    let update = |bm25| {
+        SparseVectorsConfig(BTreeMap::from([(
+            BM25_VECTOR_NAME.to_string(),
+            SparseVectorParams {
+                index: None,
+                modifier: None,
+                bm25: Some(bm25),
+            },
+        )]))
+    };
+
+    // Same configuration is accepted
+    collection
+        .update_sparse_vectors_from_other(&update(bm25_config()))
+        .await
+        .unwrap();
+
+    // Derived vectors would not match the new configuration
+    let changed = Bm25Config {
+        field: "other".parse().unwrap(),
+        ..bm25_config()
+    };
+    let error = collection
+        .update_sparse_vectors_from_other(&update(changed))
+        .await
+        .unwrap_err();
+    assert!(matches!(error, CollectionError::BadInput { .. }), "{error}");
+}
@@ -1,12 +1,14 @@
 use std::collections::{HashMap, HashSet};
 use std::fs::File;
 
+use collection::arithmetic::search_arithmetic;
 use collection::operations::payload_ops::{PayloadOps, SetPayloadOp};
 use collection::operations::point_ops::{Batch, PointOperations, PointStruct, WriteOrdering};
 use collection::operations::shard_selector_internal::ShardSelectorInternal;
 use collection::operations::types::{
-    CountRequestInternal, OrderByInterface, PointRequestInternal, RecommendRequestInternal,
-    ScrollRequestInternal, SearchRequestInternal, UpdateStatus,
+    ArithmeticRequestInternal, CountRequestInternal, OrderByInterface, PointRequestInternal,
+    RecommendExample, RecommendRequestInternal, ScrollRequestInternal, SearchRequestInternal,
+    UpdateStatus, WeightedExample,
 };
 use collection::operations::CollectionUpdateOperations;
 use collection::recommendations::recommend_by;
@@ -373,6 +375,90 @@ async fn test_recommendation_api_with_shards(shard_number: u32) {
     assert!(top1.id == 5.into() || top1.id == 6.into());
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_vector_arithmetic_api() {
+    test_vector_arithmetic_api_with_shards(1).await;
+    test_vector_arithmetic_api_with_shards(N_SHARDS).await;
+}
+
+async fn test_vector_arithmetic_api_with_shards(shard_number: u32) {
+    let collection_dir = Builder::new().prefix("collection").tempdir().unwrap();
+    let collection = simple_collection_fixture(collection_dir.path(), shard_number).await;
+
+    let insert_points = CollectionUpdateOperations::PointOperation(
+        Batch {
+            ids: vec![0, 1, 2, 3, 4, 5, 6, 7, 8]
+                .into_iter()
+                .map(|x| x.into())
+                .collect_vec(),
+            vectors: BatchVectorStruct::from(vec![
+                vec![0.0, 0.0, 1.0, 1.0],
+                vec![1.0, 0.0, 0.0, 0.0],
+                vec![1.0, 0.0, 0.0, 0.0],
+                vec![0.0, 1.0, 0.0, 0.0],
+                vec![0.0, 1.0, 0.0, 0.0],
+                vec![0.0, 0.0, 1.0, 0.0],
+                vec![0.0, 0.0, 1.0, 0.0],
+                vec![0.0, 0.0, 0.0, 1.0],
+                vec![0.0, 0.0, 0.0, 1.0],
+            ])
+            .into(),
+            payloads: None,
+        }
+        .into(),
+    );
+
+    collection
+        .update_from_client_simple(insert_points, true, WriteOrdering::default())
+        .await
+        .unwrap();
+
+    // vector(0) - vector(8) + 0.5 * [1.0, 0.0, 0.0, 0.0] = [0.5, 0.0, 1.0, 0.0]
+    let terms = vec![
+        WeightedExample {
+            example: 0.into(),
+            weight: 1.0,
+        },
+        WeightedExample {
+            example: 8.into(),
+            weight: -1.0,
+        },
+        WeightedExample {
+            example: RecommendExample::Dense(vec![1.0, 0.0, 0.0, 0.0]),
+            weight: 0.5,
+        },
+    ];
+
+    let result = search_arithmetic(
+        ArithmeticRequestInternal {
+            terms,
+            average: None,
+            filter: None,
+            params: None,
+            limit: 3,
+            offset: None,
+            with_payload: None,
+            with_vector: None,
+            score_threshold: None,
+            using: None,
+            lookup_from: None,
+        },
+        &collection,
+        |_name| async { unreachable!("Should not be called in this test") },
+        None,
+        ShardSelectorInternal::All,
+        None,
+    )
+    .await
+    .unwrap();
+
+    let ids: HashSet<_> = result[..2].iter().map(|point| point.id).collect();
+    assert_eq!(ids, HashSet::from([5.into(), 6.into()]));
+    // Referenced points are excluded, raw vectors are not.
+    // Points 1 and 2 have the same vector, so either of them may come next
+    assert!([1.into(), 2.into()].contains(&result[2].id));
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_read_api() {
     test_read_api_with_shards(1).await;
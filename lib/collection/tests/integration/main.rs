@@ -1,4 +1,6 @@
 #[cfg(test)]
+pub mod bm25_test;
+#[cfg(test)]
 pub mod collection_restore_test;
 #[cfg(test)]
 pub mod collection_test;
@@ -14,3 +16,5 @@ pub mod multi_vec_test;
 pub mod pagination_test;
 #[cfg(test)]
 pub mod snapshot_recovery_test;
+#[cfg(test)]
+pub mod sparse_idf_test;
@@ -0,0 +1,200 @@
+use std::collections::BTreeMap;
+use std::num::NonZeroU32;
+use std::path::Path;
+
+use collection::collection::Collection;
+use collection::config::{CollectionConfig, CollectionParams, WalConfig};
+use collection::operations::point_ops::{
+    PointInsertOperationsInternal, PointOperations, PointStruct, WriteOrdering,
+};
+use collection::operations::shard_selector_internal::ShardSelectorInternal;
+use collection::operations::sparse_stats::SparseStatsRequest;
+use collection::operations::types::{Modifier, SearchRequestInternal, SparseVectorParams};
+use collection::operations::vector_params_builder::VectorParamsBuilder;
+use collection::operations::CollectionUpdateOperations;
+use segment::data_types::named_vectors::NamedVectors;
+use segment::data_types::vectors::{NamedSparseVector, VectorStruct, DEFAULT_VECTOR_NAME};
+use segment::index::sparse_index::sparse_search_telemetry::DimensionStats;
+use segment::types::Distance;
+use sparse::common::sparse_vector::SparseVector;
+use tempfile::Builder;
+
+use crate::common::{new_local_collection, N_SHARDS, TEST_OPTIMIZERS_CONFIG};
+
+const SPARSE_VECTOR_NAME: &str = "sparse";
+
+async fn idf_collection_fixture(collection_path: &Path, shard_number: u32) -> Collection {
+    let wal_config = WalConfig {
+        wal_capacity_mb: 1,
+        wal_segments_ahead: 0,
+    };
+
+    let sparse_vectors = BTreeMap::from([(
+        SPARSE_VECTOR_NAME.to_string(),
+        SparseVectorParams {
+            index: None,
+            modifier: Some(Modifier::Idf),
+            bm25: None,
+        },
+    )]);
+
+    let collection_params = CollectionParams {
+        vectors: VectorParamsBuilder::new(4, Distance::Dot).build().into(),
+        sparse_vectors: Some(sparse_vectors),
+        shard_number: NonZeroU32::new(shard_number).expect("Shard number can not be zero"),
+        ..CollectionParams::empty()
+    };
+
+    let collection_config = CollectionConfig {
+        params: collection_params,
+        optimizer_config: TEST_OPTIMIZERS_CONFIG.clone(),
+        wal_config,
+        hnsw_config: Default::default(),
+        quantization_config: Default::default(),
+    };
+
+    let snapshot_path = collection_path.join("snapshots");
+
+    new_local_collection(
+        "test".to_string(),
+        collection_path,
+        &snapshot_path,
+        &collection_config,
+    )
+    .await
+    .unwrap()
+}
+
+/// Upsert points with ids in the given range, every second point contains dimension 1
+async fn upsert_points(collection: &Collection, ids: std::ops::Range<u64>) {
+    let points = ids
+        .map(|id| {
+            let (indices, values) = if id % 2 == 0 {
+                (vec![0, 1], vec![1.0, 1.0])
+            } else {
+                (vec![0], vec![1.0])
+            };
+            let mut vectors = NamedVectors::default();
+            vectors.insert(
+                DEFAULT_VECTOR_NAME.to_string(),
+                vec![1.0, 0.0, 0.0, 0.0].into(),
+            );
+            vectors.insert(
+                SPARSE_VECTOR_NAME.to_string(),
+                SparseVector::new(indices, values).unwrap().into(),
+            );
+            PointStruct {
+                id: id.into(),
+                vector: VectorStruct::from(vectors).into(),
+                payload: None,
+            }
+        })
+        .collect();
+
+    let insert_points = CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(
+        PointInsertOperationsInternal::PointsList(points),
+    ));
+    collection
+        .update_from_client_simple(insert_points, true, WriteOrdering::default())
+        .await
+        .unwrap();
+}
+
+/// Search for dimension 1 and check, that all matching points are scored with the same IDF,
+/// computed over points of all shards
+async fn check_idf_scores(collection: &Collection, point_count: usize, document_frequency: usize) {
+    let request = SearchRequestInternal {
+        vector: NamedSparseVector {
+            name: SPARSE_VECTOR_NAME.to_string(),
+            vector: SparseVector::new(vec![1], vec![1.0]).unwrap(),
+        }
+        .into(),
+        filter: None,
+        limit: point_count,
+        offset: None,
+        with_payload: None,
+        with_vector: None,
+        params: None,
+        score_threshold: None,
+    };
+
+    let result = collection
+        .search(request.into(), None, &ShardSelectorInternal::All, None)
+        .await
+        .unwrap();
+
+    let (n, df) = (point_count as f32, document_frequency as f32);
+    let expected_score = ((n - df + 0.5) / (df + 0.5) + 1.0).ln();
+
+    assert_eq!(result.len(), document_frequency);
+    for point in result {
+        assert!(
+            (point.score - expected_score).abs() < 1e-5,
+            "point {} scored {}, expected {expected_score}",
+            point.id,
+            point.score,
+        );
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_sparse_idf_is_consistent_across_shards() {
+    let collection_dir = Builder::new()
+        .prefix("test_sparse_idf_shards")
+        .tempdir()
+        .unwrap();
+
+    let collection = idf_collection_fixture(collection_dir.path(), N_SHARDS).await;
+
+    upsert_points(&collection, 0..100).await;
+    check_idf_scores(&collection, 100, 50).await;
+
+    // Cached statistics are invalidated by updates
+    upsert_points(&collection, 100..150).await;
+    check_idf_scores(&collection, 150, 75).await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_sparse_stats_are_merged_across_shards() {
+    let collection_dir = Builder::new()
+        .prefix("test_sparse_stats_shards")
+        .tempdir()
+        .unwrap();
+
+    let collection = idf_collection_fixture(collection_dir.path(), N_SHARDS).await;
+
+    upsert_points(&collection, 0..100).await;
+
+    let result = collection
+        .sparse_stats(SparseStatsRequest::default(), &ShardSelectorInternal::All)
+        .await
+        .unwrap();
+    let stats = &result.vectors[SPARSE_VECTOR_NAME];
+
+    assert_eq!(stats.indexed_vectors, 100);
+    assert_eq!(stats.dimensions, 2);
+    assert_eq!(stats.postings, 150);
+    assert_eq!(stats.max_posting_list_len, 100);
+    assert_eq!(
+        stats.top_dimensions,
+        vec![
+            DimensionStats {
+                dimension: 0,
+                document_frequency: 100,
+            },
+            DimensionStats {
+                dimension: 1,
+                document_frequency: 50,
+            },
+        ],
+    );
+
+    let unknown_vector = SparseStatsRequest {
+        using: Some("missing".to_string()),
+        limit: None,
+    };
+    assert!(collection
+        .sparse_stats(unknown_vector, &ShardSelectorInternal::All)
+        .await
+        .is_err());
+}
@@ -48,6 +48,7 @@ fn multi_vector_search_benchmark(c: &mut Criterion) {
                 quantization_config: None,
                 multivec_config: Some(MultiVectorConfig::default()), // uses multivec config
                 datatype: None,
+                prefix_size: None,
             },
         )]),
         sparse_vector_data: Default::default(),
@@ -84,6 +85,7 @@ fn multi_vector_search_benchmark(c: &mut Criterion) {
         quantized_vectors.clone(),
         segment.payload_index.clone(),
         hnsw_config,
+        None,
     )
     .unwrap();
 
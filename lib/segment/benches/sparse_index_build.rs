@@ -21,6 +21,7 @@ use segment::payload_storage::in_memory_payload_storage::InMemoryPayloadStorage;
 use segment::vector_storage::simple_sparse_vector_storage::open_simple_sparse_vector_storage;
 use segment::vector_storage::VectorStorage;
 use sparse::common::sparse_vector_fixture::random_sparse_vector;
+use sparse::common::types::WeightType;
 use sparse::index::inverted_index::inverted_index_mmap::InvertedIndexMmap;
 use sparse::index::inverted_index::inverted_index_ram::InvertedIndexRam;
 use sparse::index::inverted_index::InvertedIndex;
@@ -66,7 +67,7 @@ fn sparse_vector_index_build_benchmark(c: &mut Criterion) {
     drop(borrowed_storage);
 
     // save index config to disk
-    let index_config = SparseIndexConfig::new(Some(10_000), SparseIndexType::ImmutableRam);
+    let index_config = SparseIndexConfig::new(Some(10_000), SparseIndexType::ImmutableRam, None);
 
     let permit_cpu_count = num_rayon_threads(0);
     let permit = Arc::new(CpuPermit::dummy(permit_cpu_count as u32));
@@ -111,6 +112,7 @@ fn sparse_vector_index_build_benchmark(c: &mut Criterion) {
             let mmap_inverted_index = InvertedIndexMmap::convert_and_save(
                 &sparse_vector_index.inverted_index,
                 &mmap_index_dir,
+                WeightType::Float32,
             )
             .unwrap();
             assert_eq!(mmap_inverted_index.vector_count(), NUM_VECTORS);
@@ -16,8 +16,12 @@ use std::sync::atomic::AtomicBool;
 
 use crate::common::operation_error::{OperationError, OperationResult};
 use crate::data_types::named_vectors::NamedVectors;
-use crate::data_types::vectors::{QueryVector, VectorRef};
-use crate::types::{SegmentConfig, SparseVectorDataConfig, VectorDataConfig};
+use crate::data_types::vectors::{
+    QueryVector, VectorElementType, VectorElementTypeHalf, VectorRef,
+};
+use crate::types::{
+    Distance, SegmentConfig, SparseVectorDataConfig, VectorDataConfig, VectorStorageDatatype,
+};
 
 pub type Flusher = Box<dyn FnOnce() -> OperationResult<()> + Send>;
 
@@ -61,6 +65,11 @@ fn check_query_vector(
         QueryVector::Recommend(reco_query) => reco_query.flat_iter().try_for_each(|vector| {
             check_vector_against_config(VectorRef::from(vector), vector_config)
         })?,
+        QueryVector::RecommendSumScores(reco_query) => {
+            reco_query.flat_iter().try_for_each(|vector| {
+                check_vector_against_config(VectorRef::from(vector), vector_config)
+            })?
+        }
         QueryVector::Discovery(discovery_query) => {
             discovery_query.flat_iter().try_for_each(|vector| {
                 check_vector_against_config(VectorRef::from(vector), vector_config)
@@ -87,6 +96,11 @@ fn check_query_sparse_vector(
         QueryVector::Recommend(reco_query) => reco_query.flat_iter().try_for_each(|vector| {
             check_sparse_vector_against_config(VectorRef::from(vector), vector_config)
         })?,
+        QueryVector::RecommendSumScores(reco_query) => {
+            reco_query.flat_iter().try_for_each(|vector| {
+                check_sparse_vector_against_config(VectorRef::from(vector), vector_config)
+            })?
+        }
         QueryVector::Discovery(discovery_query) => {
             discovery_query.flat_iter().try_for_each(|vector| {
                 check_sparse_vector_against_config(VectorRef::from(vector), vector_config)
@@ -184,7 +198,7 @@ fn check_vector_against_config(
                     received_dim: vector.len(),
                 });
             }
-            Ok(())
+            check_vector_elements_range(vector, vector_config)
         }
         VectorRef::Sparse(_) => Err(OperationError::WrongSparse),
         VectorRef::MultiDense(multi_vector) => {
@@ -197,12 +211,37 @@ fn check_vector_against_config(
                         received_dim: vector.len(),
                     });
                 }
+                check_vector_elements_range(vector, vector_config)?;
             }
             Ok(())
         }
     }
 }
 
+/// Check that vector elements can be represented by the storage datatype.
+///
+/// Half-precision storage can't represent values above 65504, they would be stored as infinity.
+/// Cosine vectors are exempt, as they are normalized before being stored.
+fn check_vector_elements_range(
+    vector: &[VectorElementType],
+    vector_config: &VectorDataConfig,
+) -> OperationResult<()> {
+    if vector_config.datatype != Some(VectorStorageDatatype::Float16)
+        || vector_config.distance == Distance::Cosine
+    {
+        return Ok(());
+    }
+    let max = VectorElementTypeHalf::MAX.to_f32();
+    match vector.iter().find(|value| value.abs() > max) {
+        Some(value) => Err(OperationError::ValidationError {
+            description: format!(
+                "Vector element {value} is out of range of float16 datatype, absolute values must not exceed {max}",
+            ),
+        }),
+        None => Ok(()),
+    }
+}
+
 fn check_sparse_vector_against_config(
     vector: VectorRef,
     _vector_config: &SparseVectorDataConfig,
@@ -3,7 +3,7 @@ use std::sync::Arc;
 
 use parking_lot::RwLock;
 //use atomic_refcell::{AtomicRef, AtomicRefCell};
-use rocksdb::{ColumnFamily, DBRecoveryMode, LogLevel, Options, WriteOptions, DB};
+use rocksdb::{ColumnFamily, DBRecoveryMode, LogLevel, Options, ReadOptions, WriteOptions, DB};
 
 //use crate::common::arc_rwlock_iterator::ArcRwLockIterator;
 use crate::common::operation_error::{OperationError, OperationResult};
@@ -160,6 +160,37 @@ impl DatabaseColumnWrapper {
         Ok(result)
     }
 
+    /// Read values of multiple keys in a single batch.
+    ///
+    /// With `async_io`, RocksDB reads the blocks of different keys from disk concurrently.
+    pub fn multi_get_pinned<K, T, F>(
+        &self,
+        keys: &[K],
+        async_io: bool,
+        mut f: F,
+    ) -> OperationResult<Vec<Option<T>>>
+    where
+        K: AsRef<[u8]>,
+        F: FnMut(&[u8]) -> T,
+    {
+        let db = self.database.read();
+        let cf_handle = self.get_column_family(&db)?;
+        let mut read_options = ReadOptions::default();
+        read_options.set_async_io(async_io);
+        db.batched_multi_get_cf_opt(cf_handle, keys, false, &read_options)
+            .into_iter()
+            .map(|value| {
+                value
+                    .map(|value| value.map(|value| f(&value)))
+                    .map_err(|err| {
+                        OperationError::service_error(format!(
+                            "RocksDB batched_multi_get_cf error: {err}"
+                        ))
+                    })
+            })
+            .collect()
+    }
+
     pub fn remove<K>(&self, key: K) -> OperationResult<()>
     where
         K: AsRef<[u8]>,
@@ -54,6 +54,7 @@ impl From<SegmentConfigV5> for SegmentConfig {
                         .unwrap_or_else(|| old_segment.storage_type.into()),
                     multivec_config: None,
                     datatype: None,
+                    prefix_size: None,
                 };
 
                 (vector_name, new_data)
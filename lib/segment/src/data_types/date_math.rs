@@ -0,0 +1,277 @@
+//! Relative datetime expressions ("date math"), resolved against the current time.
+//!
+//! Syntax follows the well-known Elasticsearch flavour:
+//!
+//! ```text
+//! now-7d/d
+//! now+1h
+//! 2024-01-01||+1M/M
+//! now/w[Europe/Berlin]
+//! ```
+//!
+//! An expression starts with an anchor, which is either `now` or an absolute datetime
+//! followed by `||`. The anchor is followed by any number of operations:
+//!
+//! * `+<n><unit>` / `-<n><unit>` - add or subtract `n` units
+//! * `/<unit>` - round down to the beginning of the unit
+//!
+//! Supported units: `y` (years), `M` (months), `w` (weeks), `d` (days), `h` or `H` (hours),
+//! `m` (minutes), `s` (seconds).
+//!
+//! An optional IANA time zone in square brackets may be appended. If present, calendar
+//! arithmetic and rounding are performed in the local time of that zone, so `now/d[Europe/Berlin]`
+//! is the midnight in Berlin, taking daylight saving time into account. Otherwise UTC is used.
+
+use std::str::FromStr;
+
+use chrono::{DateTime, Datelike, Days, Duration, Months, NaiveDateTime, TimeZone, Timelike, Utc};
+use chrono_tz::Tz;
+
+use crate::types::DateTimeWrapper;
+
+const NOW: &str = "now";
+const ANCHOR_SEPARATOR: &str = "||";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DateMathUnit {
+    Year,
+    Month,
+    Week,
+    Day,
+    Hour,
+    Minute,
+    Second,
+}
+
+impl DateMathUnit {
+    fn parse(c: char) -> Result<Self, String> {
+        match c {
+            'y' => Ok(Self::Year),
+            'M' => Ok(Self::Month),
+            'w' => Ok(Self::Week),
+            'd' => Ok(Self::Day),
+            'h' | 'H' => Ok(Self::Hour),
+            'm' => Ok(Self::Minute),
+            's' => Ok(Self::Second),
+            _ => Err(format!("unknown date math unit '{c}'")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DateMathOp {
+    Add(i64, DateMathUnit),
+    Round(DateMathUnit),
+}
+
+/// Returns true if the string looks like a relative datetime expression
+pub fn is_date_math(s: &str) -> bool {
+    s.starts_with(NOW) || s.contains(ANCHOR_SEPARATOR)
+}
+
+/// Resolve datetime expression into absolute datetime.
+///
+/// Absolute datetimes are accepted as well, in that case `now` is not used.
+pub fn resolve_datetime_expression(s: &str, now: DateTime<Utc>) -> Result<DateTimeWrapper, String> {
+    if !is_date_math(s) {
+        return DateTimeWrapper::from_str(s).map_err(|err| err.to_string());
+    }
+
+    let (expression, time_zone) = split_time_zone(s)?;
+
+    let (anchor, ops) = if let Some(ops) = expression.strip_prefix(NOW) {
+        (now, ops)
+    } else if let Some((anchor, ops)) = expression.split_once(ANCHOR_SEPARATOR) {
+        let anchor = DateTimeWrapper::from_str(anchor)
+            .map_err(|err| format!("invalid date math anchor '{anchor}': {err}"))?;
+        (anchor.0, ops)
+    } else {
+        return Err(format!("invalid date math expression '{s}'"));
+    };
+
+    let ops = parse_ops(ops)?;
+
+    let resolved = match time_zone {
+        Some(tz) => apply_ops(anchor.with_timezone(&tz), &ops).map(|dt| dt.with_timezone(&Utc)),
+        None => apply_ops(anchor, &ops),
+    };
+
+    resolved
+        .map(DateTimeWrapper)
+        .ok_or_else(|| format!("date math expression '{s}' is out of range"))
+}
+
+fn split_time_zone(s: &str) -> Result<(&str, Option<Tz>), String> {
+    let Some(expression) = s.strip_suffix(']') else {
+        return Ok((s, None));
+    };
+    let Some((expression, time_zone)) = expression.rsplit_once('[') else {
+        return Err(format!("invalid time zone in date math expression '{s}'"));
+    };
+    let time_zone = Tz::from_str(time_zone)
+        .map_err(|_| format!("unknown time zone '{time_zone}', expected an IANA name"))?;
+    Ok((expression, Some(time_zone)))
+}
+
+fn parse_ops(mut s: &str) -> Result<Vec<DateMathOp>, String> {
+    let mut ops = Vec::new();
+    while let Some(op) = s.chars().next() {
+        s = &s[op.len_utf8()..];
+        match op {
+            '+' | '-' => {
+                let digits = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+                // Like Elasticsearch, `now+d` is the same as `now+1d`
+                let amount = if digits == 0 {
+                    1
+                } else {
+                    s[..digits]
+                        .parse::<i64>()
+                        .map_err(|err| format!("invalid date math amount: {err}"))?
+                };
+                s = &s[digits..];
+                let unit = s
+                    .chars()
+                    .next()
+                    .ok_or_else(|| "missing date math unit".to_string())?;
+                s = &s[unit.len_utf8()..];
+                let amount = if op == '-' { -amount } else { amount };
+                ops.push(DateMathOp::Add(amount, DateMathUnit::parse(unit)?));
+            }
+            '/' => {
+                let unit = s
+                    .chars()
+                    .next()
+                    .ok_or_else(|| "missing date math rounding unit".to_string())?;
+                s = &s[unit.len_utf8()..];
+                ops.push(DateMathOp::Round(DateMathUnit::parse(unit)?));
+            }
+            _ => return Err(format!("unexpected date math operator '{op}'")),
+        }
+    }
+    Ok(ops)
+}
+
+fn apply_ops<T: TimeZone>(mut datetime: DateTime<T>, ops: &[DateMathOp]) -> Option<DateTime<T>> {
+    for op in ops {
+        datetime = match *op {
+            DateMathOp::Add(amount, unit) => add(datetime, amount, unit)?,
+            DateMathOp::Round(unit) => round_down(datetime, unit)?,
+        };
+    }
+    Some(datetime)
+}
+
+fn add<T: TimeZone>(datetime: DateTime<T>, amount: i64, unit: DateMathUnit) -> Option<DateTime<T>> {
+    let magnitude = amount.unsigned_abs();
+    match unit {
+        DateMathUnit::Year | DateMathUnit::Month => {
+            let months = if unit == DateMathUnit::Year {
+                magnitude.checked_mul(12)?
+            } else {
+                magnitude
+            };
+            let months = Months::new(u32::try_from(months).ok()?);
+            if amount >= 0 {
+                datetime.checked_add_months(months)
+            } else {
+                datetime.checked_sub_months(months)
+            }
+        }
+        DateMathUnit::Week | DateMathUnit::Day => {
+            let days = if unit == DateMathUnit::Week {
+                magnitude.checked_mul(7)?
+            } else {
+                magnitude
+            };
+            if amount >= 0 {
+                datetime.checked_add_days(Days::new(days))
+            } else {
+                datetime.checked_sub_days(Days::new(days))
+            }
+        }
+        DateMathUnit::Hour => datetime.checked_add_signed(Duration::try_hours(amount)?),
+        DateMathUnit::Minute => datetime.checked_add_signed(Duration::try_minutes(amount)?),
+        DateMathUnit::Second => datetime.checked_add_signed(Duration::try_seconds(amount)?),
+    }
+}
+
+fn round_down<T: TimeZone>(datetime: DateTime<T>, unit: DateMathUnit) -> Option<DateTime<T>> {
+    let local = datetime.naive_local();
+    let date = local.date();
+    let rounded: NaiveDateTime = match unit {
+        DateMathUnit::Year => date.with_month(1)?.with_day(1)?.and_hms_opt(0, 0, 0)?,
+        DateMathUnit::Month => date.with_day(1)?.and_hms_opt(0, 0, 0)?,
+        DateMathUnit::Week => {
+            let days_from_monday = u64::from(date.weekday().num_days_from_monday());
+            date.checked_sub_days(Days::new(days_from_monday))?
+                .and_hms_opt(0, 0, 0)?
+        }
+        DateMathUnit::Day => date.and_hms_opt(0, 0, 0)?,
+        DateMathUnit::Hour => date.and_hms_opt(local.time().hour(), 0, 0)?,
+        DateMathUnit::Minute => date.and_hms_opt(local.time().hour(), local.time().minute(), 0)?,
+        DateMathUnit::Second => date.and_hms_opt(
+            local.time().hour(),
+            local.time().minute(),
+            local.time().second(),
+        )?,
+    };
+
+    let time_zone = datetime.timezone();
+    time_zone
+        .from_local_datetime(&rounded)
+        .earliest()
+        // Local time may not exist because of a DST gap, take the first valid time after it
+        .or_else(|| {
+            time_zone
+                .from_local_datetime(&(rounded + Duration::try_hours(1)?))
+                .earliest()
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn now() -> DateTime<Utc> {
+        // Wednesday
+        DateTimeWrapper::from_str("2024-03-27T15:42:17.123Z")
+            .unwrap()
+            .0
+    }
+
+    #[rstest]
+    #[case::now("now", "2024-03-27T15:42:17.123Z")]
+    #[case::minus_days("now-7d", "2024-03-20T15:42:17.123Z")]
+    #[case::round_day("now-7d/d", "2024-03-20T00:00:00Z")]
+    #[case::plus_hour("now+1h", "2024-03-27T16:42:17.123Z")]
+    #[case::implicit_amount("now-d", "2024-03-26T15:42:17.123Z")]
+    #[case::round_week("now/w", "2024-03-25T00:00:00Z")]
+    #[case::round_month("now/M", "2024-03-01T00:00:00Z")]
+    #[case::round_year("now/y", "2024-01-01T00:00:00Z")]
+    #[case::round_minute("now/m", "2024-03-27T15:42:00Z")]
+    #[case::month_end("2024-01-31||+1M", "2024-02-29T00:00:00Z")]
+    #[case::anchor("2024-01-15T10:00:00Z||+1M/M", "2024-02-01T00:00:00Z")]
+    #[case::chained("now-1y+2w/d", "2023-04-10T00:00:00Z")]
+    #[case::absolute("2020-03-01T00:00:00Z", "2020-03-01T00:00:00Z")]
+    #[case::tz_round_day("now/d[Europe/Berlin]", "2024-03-26T23:00:00Z")]
+    #[case::tz_dst("now+7d/d[Europe/Berlin]", "2024-04-02T22:00:00Z")]
+    #[case::tz_offset("now/d[America/New_York]", "2024-03-27T04:00:00Z")]
+    fn test_resolve_datetime_expression(#[case] expression: &str, #[case] expected: &str) {
+        let resolved = resolve_datetime_expression(expression, now()).unwrap();
+        let expected = DateTimeWrapper::from_str(expected).unwrap();
+        assert_eq!(resolved, expected, "expression: {expression}");
+    }
+
+    #[rstest]
+    #[case::unknown_unit("now-7x")]
+    #[case::missing_unit("now-7")]
+    #[case::garbage("now*7d")]
+    #[case::bad_anchor("yesterday||+1d")]
+    #[case::bad_time_zone("now/d[Mars/Olympus_Mons]")]
+    #[case::unclosed_time_zone("now/dEurope/Berlin]")]
+    fn test_invalid_datetime_expression(#[case] expression: &str) {
+        assert!(resolve_datetime_expression(expression, now()).is_err());
+    }
+}
@@ -1,5 +1,7 @@
+pub mod date_math;
 pub mod groups;
 pub mod integer_index;
+pub mod keyword_index;
 pub mod named_vectors;
 pub mod order_by;
 pub mod primitive;
@@ -5,11 +5,14 @@ use sparse::common::sparse_vector::SparseVector;
 
 use super::tiny_map;
 use super::vectors::{
-    DenseVector, MultiDenseVector, Vector, VectorElementType, VectorElementTypeByte, VectorRef,
+    DenseVector, MultiDenseVector, Vector, VectorElementType, VectorElementTypeByte,
+    VectorElementTypeHalf, VectorRef,
 };
 use crate::common::operation_error::OperationError;
 use crate::spaces::metric::Metric;
-use crate::spaces::simple::{CosineMetric, DotProductMetric, EuclidMetric, ManhattanMetric};
+use crate::spaces::simple::{
+    CosineMetric, DotProductMetric, EuclidMetric, HammingMetric, JaccardMetric, ManhattanMetric,
+};
 use crate::types::{Distance, VectorDataConfig, VectorStorageDatatype};
 
 type CowKey<'a> = Cow<'a, str>;
@@ -280,6 +283,14 @@ impl<'a> NamedVectors<'a> {
                 Distance::Manhattan => {
                     <ManhattanMetric as Metric<VectorElementType>>::preprocess(dense_vector)
                 }
+                Distance::Hamming => {
+                    <HammingMetric as Metric<VectorElementType>>::preprocess(dense_vector)
+                }
+                Distance::Jaccard => {
+                    <JaccardMetric as Metric<VectorElementType>>::preprocess(dense_vector)
+                }
+                // Minkowski distance doesn't preprocess vectors, regardless of the order
+                Distance::Minkowski(_) => dense_vector,
             },
             Some(VectorStorageDatatype::Uint8) => match config.distance {
                 Distance::Cosine => {
@@ -294,6 +305,34 @@ impl<'a> NamedVectors<'a> {
                 Distance::Manhattan => {
                     <ManhattanMetric as Metric<VectorElementTypeByte>>::preprocess(dense_vector)
                 }
+                Distance::Hamming => {
+                    <HammingMetric as Metric<VectorElementTypeByte>>::preprocess(dense_vector)
+                }
+                Distance::Jaccard => {
+                    <JaccardMetric as Metric<VectorElementTypeByte>>::preprocess(dense_vector)
+                }
+                Distance::Minkowski(_) => dense_vector,
+            },
+            Some(VectorStorageDatatype::Float16) => match config.distance {
+                Distance::Cosine => {
+                    <CosineMetric as Metric<VectorElementTypeHalf>>::preprocess(dense_vector)
+                }
+                Distance::Euclid => {
+                    <EuclidMetric as Metric<VectorElementTypeHalf>>::preprocess(dense_vector)
+                }
+                Distance::Dot => {
+                    <DotProductMetric as Metric<VectorElementTypeHalf>>::preprocess(dense_vector)
+                }
+                Distance::Manhattan => {
+                    <ManhattanMetric as Metric<VectorElementTypeHalf>>::preprocess(dense_vector)
+                }
+                Distance::Hamming => {
+                    <HammingMetric as Metric<VectorElementTypeHalf>>::preprocess(dense_vector)
+                }
+                Distance::Jaccard => {
+                    <JaccardMetric as Metric<VectorElementTypeHalf>>::preprocess(dense_vector)
+                }
+                Distance::Minkowski(_) => dense_vector,
             },
         }
     }
@@ -1,12 +1,15 @@
 use std::borrow::Cow;
 
+use half::f16;
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 
 use super::vectors::TypedMultiDenseVector;
-use crate::data_types::vectors::{VectorElementType, VectorElementTypeByte};
+use crate::data_types::vectors::{VectorElementType, VectorElementTypeByte, VectorElementTypeHalf};
 use crate::spaces::metric::Metric;
-use crate::spaces::simple::{CosineMetric, DotProductMetric, EuclidMetric, ManhattanMetric};
+use crate::spaces::simple::{
+    CosineMetric, DotProductMetric, EuclidMetric, HammingMetric, JaccardMetric, ManhattanMetric,
+};
 use crate::types::{Distance, QuantizationConfig, VectorStorageDatatype};
 
 pub trait PrimitiveVectorElement:
@@ -22,6 +25,11 @@ pub trait PrimitiveVectorElement:
         vector: &'a [Self],
     ) -> Cow<'a, [f32]>;
 
+    /// Dimensionality of vectors after `quantization_preprocess`
+    fn quantization_dim(_distance: Distance, dim: usize) -> usize {
+        dim
+    }
+
     fn datatype() -> VectorStorageDatatype;
 
     fn from_float_multivector(
@@ -81,6 +89,18 @@ impl PrimitiveVectorElement for VectorElementTypeByte {
         distance: Distance,
         vector: &'a [Self],
     ) -> Cow<'a, [f32]> {
+        // Bits are quantized one by one as -1 and 1 values,
+        // so L1 distance between quantized vectors is twice the Hamming distance
+        if is_bit_distance(distance) {
+            return Cow::from(
+                vector
+                    .iter()
+                    .flat_map(|&x| {
+                        (0..u8::BITS).map(move |bit| if (x >> bit) & 1 == 1 { 1.0 } else { -1.0 })
+                    })
+                    .collect_vec(),
+            );
+        }
         if let QuantizationConfig::Binary(_) = quantization_config {
             Cow::from(
                 vector
@@ -99,11 +119,27 @@ impl PrimitiveVectorElement for VectorElementTypeByte {
                 Distance::Manhattan => {
                     <ManhattanMetric as Metric<VectorElementType>>::preprocess(vector)
                 }
+                Distance::Hamming => {
+                    <HammingMetric as Metric<VectorElementType>>::preprocess(vector)
+                }
+                Distance::Jaccard => {
+                    <JaccardMetric as Metric<VectorElementType>>::preprocess(vector)
+                }
+                // Minkowski distance doesn't preprocess vectors, regardless of the order
+                Distance::Minkowski(_) => vector,
             };
             Cow::from(preprocessed_vector)
         }
     }
 
+    fn quantization_dim(distance: Distance, dim: usize) -> usize {
+        if is_bit_distance(distance) {
+            dim * u8::BITS as usize
+        } else {
+            dim
+        }
+    }
+
     fn datatype() -> VectorStorageDatatype {
         VectorStorageDatatype::Uint8
     }
@@ -134,3 +170,57 @@ impl PrimitiveVectorElement for VectorElementTypeByte {
         ))
     }
 }
+
+impl PrimitiveVectorElement for VectorElementTypeHalf {
+    fn slice_from_float_cow(vector: Cow<[VectorElementType]>) -> Cow<[Self]> {
+        Cow::Owned(vector.iter().map(|&x| f16::from_f32(x)).collect())
+    }
+
+    fn slice_to_float_cow(vector: Cow<[Self]>) -> Cow<[VectorElementType]> {
+        Cow::Owned(vector.iter().map(|&x| x.to_f32()).collect_vec())
+    }
+
+    fn quantization_preprocess<'a>(
+        _quantization_config: &QuantizationConfig,
+        _distance: Distance,
+        vector: &'a [Self],
+    ) -> Cow<'a, [f32]> {
+        // Vectors are already preprocessed on insertion, only the precision has to be restored
+        Cow::Owned(vector.iter().map(|&x| x.to_f32()).collect_vec())
+    }
+
+    fn datatype() -> VectorStorageDatatype {
+        VectorStorageDatatype::Float16
+    }
+
+    fn from_float_multivector(
+        multivector: Cow<TypedMultiDenseVector<VectorElementType>>,
+    ) -> Cow<TypedMultiDenseVector<Self>> {
+        Cow::Owned(TypedMultiDenseVector::new(
+            multivector
+                .flattened_vectors
+                .iter()
+                .map(|&x| f16::from_f32(x))
+                .collect_vec(),
+            multivector.dim,
+        ))
+    }
+
+    fn into_float_multivector(
+        multivector: Cow<TypedMultiDenseVector<Self>>,
+    ) -> Cow<TypedMultiDenseVector<VectorElementType>> {
+        Cow::Owned(TypedMultiDenseVector::new(
+            multivector
+                .flattened_vectors
+                .iter()
+                .map(|&x| x.to_f32())
+                .collect_vec(),
+            multivector.dim,
+        ))
+    }
+}
+
+/// Distances, which treat byte vectors as packed bits
+fn is_bit_distance(distance: Distance) -> bool {
+    matches!(distance, Distance::Hamming | Distance::Jaccard)
+}
@@ -26,6 +26,11 @@ pub struct QueryContext {
     /// Required for processing sparse vector search with `idf-dot` similarity.
     #[allow(dead_code)]
     idf: tiny_map::TinyMap<String, HashMap<DimId, usize>>,
+
+    /// Number of documents, over which `idf` statistics were collected.
+    /// Only set if statistics come from outside of the local segments, e.g. from all shards.
+    /// Otherwise `available_point_count` is used.
+    idf_point_count: Option<usize>,
 }
 
 impl QueryContext {
@@ -35,6 +40,7 @@ impl QueryContext {
             search_optimized_threshold_kb,
             is_stopped: Arc::new(AtomicBool::new(false)),
             idf: tiny_map::TinyMap::new(),
+            idf_point_count: None,
         }
     }
 
@@ -79,6 +85,22 @@ impl QueryContext {
         &mut self.idf
     }
 
+    pub fn idf(&self) -> &tiny_map::TinyMap<String, HashMap<DimId, usize>> {
+        &self.idf
+    }
+
+    /// Replace statistics of the element frequency of the given vector with externally
+    /// collected ones, e.g. over all shards of a collection.
+    pub fn set_idf(&mut self, vector_name: &str, document_frequencies: HashMap<DimId, usize>) {
+        self.idf
+            .insert(vector_name.to_string(), document_frequencies);
+    }
+
+    /// Set number of documents, over which externally provided `idf` statistics were collected.
+    pub fn set_idf_point_count(&mut self, point_count: usize) {
+        self.idf_point_count = Some(point_count);
+    }
+
     pub fn get_segment_query_context(&self) -> SegmentQueryContext {
         SegmentQueryContext {
             query_context: Some(self),
@@ -108,6 +130,9 @@ impl<'a> SegmentQueryContext<'a> {
                 search_optimized_threshold_kb: query_context.search_optimized_threshold_kb,
                 is_stopped: Some(&query_context.is_stopped),
                 idf: query_context.idf.get(vector_name),
+                idf_point_count: query_context
+                    .idf_point_count
+                    .unwrap_or(query_context.available_point_count),
                 deleted_points: self.deleted_points,
             }
         } else {
@@ -138,6 +163,9 @@ pub struct VectorQueryContext<'a> {
 
     idf: Option<&'a HashMap<DimId, usize>>,
 
+    /// Number of documents, over which `idf` statistics were collected.
+    idf_point_count: usize,
+
     deleted_points: Option<&'a BitSlice>,
 }
 
@@ -185,7 +213,7 @@ impl VectorQueryContext<'_> {
 
     pub fn remap_idf_weights(&self, indices: &[DimId], weights: &mut [DimWeight]) {
         // Number of documents
-        let n = self.available_point_count as DimWeight;
+        let n = self.idf_point_count as DimWeight;
         for (weight, index) in weights.iter_mut().zip(indices) {
             // Document frequency
             let df = self
@@ -210,6 +238,7 @@ impl Default for VectorQueryContext<'_> {
             search_optimized_threshold_kb: usize::MAX,
             is_stopped: None,
             idf: None,
+            idf_point_count: 0,
             deleted_points: None,
         }
     }
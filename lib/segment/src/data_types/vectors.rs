@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 use std::slice::ChunksExactMut;
 
+use half::f16;
 use itertools::Itertools;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -13,7 +14,7 @@ use crate::common::operation_error::{OperationError, OperationResult};
 use crate::common::utils::transpose_map_into_named_vector;
 use crate::vector_storage::query::context_query::ContextQuery;
 use crate::vector_storage::query::discovery_query::DiscoveryQuery;
-use crate::vector_storage::query::reco_query::RecoQuery;
+use crate::vector_storage::query::reco_query::{RecoQuery, RecoSumScoresQuery};
 use crate::vector_storage::query::TransformInto;
 
 #[derive(Clone, Debug, PartialEq)]
@@ -179,6 +180,8 @@ pub type VectorElementType = f32;
 
 pub type VectorElementTypeByte = u8;
 
+pub type VectorElementTypeHalf = f16;
+
 pub const DEFAULT_VECTOR_NAME: &str = "";
 
 pub type TypedDenseVector<T> = Vec<T>;
@@ -618,6 +621,7 @@ impl NamedQuery<RecoQuery<Vector>> {
 pub enum QueryVector {
     Nearest(Vector),
     Recommend(RecoQuery<Vector>),
+    RecommendSumScores(RecoSumScoresQuery<Vector>),
     Discovery(DiscoveryQuery<Vector>),
     Context(ContextQuery<Vector>),
 }
@@ -630,12 +634,28 @@ impl TransformInto<QueryVector, Vector, Vector> for QueryVector {
         match self {
             QueryVector::Nearest(v) => f(v).map(QueryVector::Nearest),
             QueryVector::Recommend(v) => Ok(QueryVector::Recommend(v.transform(&mut f)?)),
+            QueryVector::RecommendSumScores(v) => {
+                Ok(QueryVector::RecommendSumScores(v.transform(&mut f)?))
+            }
             QueryVector::Discovery(v) => Ok(QueryVector::Discovery(v.transform(&mut f)?)),
             QueryVector::Context(v) => Ok(QueryVector::Context(v.transform(&mut f)?)),
         }
     }
 }
 
+impl QueryVector {
+    /// Keep only the first `size` dimensions of all dense vectors of the query
+    pub fn truncate_dense(self, size: usize) -> OperationResult<Self> {
+        self.transform(|vector| match vector {
+            Vector::Dense(mut dense) => {
+                dense.truncate(size);
+                Ok(Vector::Dense(dense))
+            }
+            other => Ok(other),
+        })
+    }
+}
+
 impl From<DenseVector> for QueryVector {
     fn from(vec: DenseVector) -> Self {
         Self::Nearest(Vector::Dense(vec))
@@ -9,6 +9,7 @@ use crate::data_types::order_by::{OrderBy, OrderingValue};
 use crate::data_types::query_context::{QueryContext, SegmentQueryContext};
 use crate::data_types::vectors::{QueryVector, Vector};
 use crate::index::field_index::CardinalityEstimation;
+use crate::index::sparse_index::sparse_search_telemetry::SparseIndexStatistics;
 use crate::json_path::JsonPath;
 use crate::telemetry::SegmentTelemetry;
 use crate::types::{
@@ -126,6 +127,16 @@ pub trait SegmentEntry {
         order_by: &'a OrderBy,
     ) -> OperationResult<Vec<(OrderingValue, PointIdType)>>;
 
+    /// Uniformly sample up to `limit` points which satisfy filtering condition.
+    ///
+    /// Also returns the number of points satisfying the condition, so samples of multiple
+    /// segments can be merged with [`crate::spaces::tools::merge_samples`].
+    fn sample_filtered<'a>(
+        &'a self,
+        filter: Option<&'a Filter>,
+        limit: usize,
+    ) -> (Vec<PointIdType>, usize);
+
     /// Read points in [from; to) range
     fn read_range(&self, from: Option<PointIdType>, to: Option<PointIdType>) -> Vec<PointIdType>;
 
@@ -210,4 +221,7 @@ pub trait SegmentEntry {
     fn get_telemetry_data(&self, detail: TelemetryDetail) -> SegmentTelemetry;
 
     fn fill_query_context(&self, query_context: &mut QueryContext);
+
+    /// Statistics of inverted indexes of all sparse vectors in the segment
+    fn sparse_index_statistics(&self) -> HashMap<String, SparseIndexStatistics>;
 }
@@ -81,6 +81,13 @@ pub fn index_selector(
                 });
                 lookup.into_iter().chain(range).collect()
             }
+            PayloadSchemaParams::Keyword(_) => {
+                vec![FieldIndex::KeywordIndex(MapIndex::new(
+                    db,
+                    field,
+                    is_appendable,
+                ))]
+            }
         },
     }
 }
@@ -29,6 +29,9 @@ pub struct HnswGraphConfig {
     pub payload_m0: Option<usize>,
     #[serde(default)]
     pub indexed_vector_count: Option<usize>,
+    /// Graph is built over only this many first dimensions of the vectors
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prefix_size: Option<usize>,
 }
 
 impl HnswGraphConfig {
@@ -39,6 +42,7 @@ impl HnswGraphConfig {
         max_indexing_threads: usize,
         payload_m: Option<usize>,
         indexed_vector_count: usize,
+        prefix_size: Option<usize>,
     ) -> Self {
         HnswGraphConfig {
             m,
@@ -50,6 +54,7 @@ impl HnswGraphConfig {
             payload_m,
             payload_m0: payload_m.map(|v| v * 2),
             indexed_vector_count: Some(indexed_vector_count),
+            prefix_size,
         }
     }
 
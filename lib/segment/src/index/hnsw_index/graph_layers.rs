@@ -1,6 +1,7 @@
 use std::cmp::max;
 use std::path::{Path, PathBuf};
 
+use bitvec::prelude::{BitSlice, BitVec};
 use common::fixed_length_priority_queue::FixedLengthPriorityQueue;
 use common::types::{PointOffsetType, ScoredPointOffset};
 use io::file_operations::{atomic_save_bin, read_bin, FileStorageError};
@@ -25,6 +26,50 @@ pub type LayersContainer = Vec<LinkContainer>;
 pub const HNSW_GRAPH_FILE: &str = "graph.bin";
 pub const HNSW_LINKS_FILE: &str = "links.bin";
 
+/// Strategy of the graph traversal during search
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SearchAlgorithm {
+    /// Regular HNSW search, only direct neighbours of the visited points are considered
+    #[default]
+    Hnsw,
+    /// Filter-aware search, neighbours which do not pass the filter are expanded with their own
+    /// neighbours. Slower per visited point, but keeps the filtered graph connected.
+    Acorn,
+}
+
+/// Health of HNSW graph, degrading as points of the graph are deleted
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GraphHealth {
+    /// Number of points in the graph, which are not deleted
+    pub live_points: usize,
+    /// Number of live points reachable from the entry point on level 0, without passing deleted points
+    pub reachable_points: usize,
+    /// Number of level 0 links of live points
+    pub total_links: usize,
+    /// Number of level 0 links of live points, which point to deleted points
+    pub deleted_links: usize,
+}
+
+impl GraphHealth {
+    /// Fraction of live points reachable from the entry point
+    pub fn reachable_ratio(&self) -> f64 {
+        if self.live_points == 0 {
+            1.0
+        } else {
+            self.reachable_points as f64 / self.live_points as f64
+        }
+    }
+
+    /// Fraction of links pointing to deleted points
+    pub fn deleted_links_ratio(&self) -> f64 {
+        if self.total_links == 0 {
+            0.0
+        } else {
+            self.deleted_links as f64 / self.total_links as f64
+        }
+    }
+}
+
 #[derive(Deserialize, Serialize, Debug)]
 pub struct GraphLayersBackwardCompatibility {
     pub(super) max_level: usize,
@@ -90,18 +135,107 @@ pub trait GraphLayersBase {
         }
     }
 
+    /// Greedy search within a single graph layer, which also looks at the neighbours of the
+    /// neighbours not passing the filter.
+    ///
+    /// Based on ACORN-1 (<https://arxiv.org/abs/2403.04871>). With restrictive filters most of the
+    /// direct neighbours are filtered out, and regular search gets stuck in a disconnected part of
+    /// the filtered graph. Expanding the filtered out neighbours recovers the connectivity, without
+    /// building additional links for each possible filter.
+    fn _search_on_level_acorn(
+        &self,
+        searcher: &mut SearchContext,
+        level: usize,
+        visited_list: &mut VisitedListHandle,
+        points_scorer: &mut FilteredScorer,
+    ) {
+        let limit = self.get_m(level);
+        let mut points_ids: Vec<PointOffsetType> = Vec::with_capacity(2 * limit);
+        let mut filtered_out_ids: Vec<PointOffsetType> = Vec::with_capacity(limit);
+
+        while let Some(candidate) = searcher.candidates.pop() {
+            if candidate.score < searcher.lower_bound() {
+                break;
+            }
+
+            points_ids.clear();
+            filtered_out_ids.clear();
+            self.links_map(candidate.idx, level, |link| {
+                if visited_list.check(link) {
+                    return;
+                }
+                if points_scorer.check_vector(link) {
+                    visited_list.check_and_update_visited(link);
+                    points_ids.push(link);
+                } else {
+                    filtered_out_ids.push(link);
+                }
+            });
+
+            // Second hop through the neighbours which do not pass the filter.
+            // They are only marked visited once expanded, so the ones skipped here can still be
+            // expanded from other candidates.
+            for &filtered_out_id in &filtered_out_ids {
+                if points_ids.len() >= limit {
+                    break;
+                }
+                if visited_list.check_and_update_visited(filtered_out_id) {
+                    continue;
+                }
+                self.links_map(filtered_out_id, level, |link| {
+                    if !visited_list.check(link) && points_scorer.check_vector(link) {
+                        visited_list.check_and_update_visited(link);
+                        points_ids.push(link);
+                    }
+                });
+            }
+
+            let scores = points_scorer.score_points(&mut points_ids, 0);
+            scores.iter().copied().for_each(|score_point| {
+                searcher.process_candidate(score_point);
+            });
+        }
+    }
+
     fn search_on_level(
         &self,
         level_entry: ScoredPointOffset,
         level: usize,
         ef: usize,
         points_scorer: &mut FilteredScorer,
+    ) -> FixedLengthPriorityQueue<ScoredPointOffset> {
+        self.search_on_level_with_algorithm(
+            level_entry,
+            level,
+            ef,
+            SearchAlgorithm::Hnsw,
+            points_scorer,
+        )
+    }
+
+    fn search_on_level_with_algorithm(
+        &self,
+        level_entry: ScoredPointOffset,
+        level: usize,
+        ef: usize,
+        algorithm: SearchAlgorithm,
+        points_scorer: &mut FilteredScorer,
     ) -> FixedLengthPriorityQueue<ScoredPointOffset> {
         let mut visited_list = self.get_visited_list_from_pool();
         visited_list.check_and_update_visited(level_entry.idx);
         let mut search_context = SearchContext::new(level_entry, ef);
 
-        self._search_on_level(&mut search_context, level, &mut visited_list, points_scorer);
+        match algorithm {
+            SearchAlgorithm::Hnsw => {
+                self._search_on_level(&mut search_context, level, &mut visited_list, points_scorer)
+            }
+            SearchAlgorithm::Acorn => self._search_on_level_acorn(
+                &mut search_context,
+                level,
+                &mut visited_list,
+                points_scorer,
+            ),
+        }
         search_context.nearest
     }
 
@@ -155,7 +289,7 @@ impl<TGraphLinks: GraphLinks> GraphLayersBase for GraphLayers<TGraphLinks> {
         F: FnMut(PointOffsetType),
     {
         for link in self.links.links(point_id, level) {
-            f(*link);
+            f(link);
         }
     }
 
@@ -205,6 +339,23 @@ impl<TGraphLinks: GraphLinks> GraphLayers<TGraphLinks> {
         &self,
         top: usize,
         ef: usize,
+        points_scorer: FilteredScorer,
+        custom_entry_points: Option<&[PointOffsetType]>,
+    ) -> Vec<ScoredPointOffset> {
+        self.search_with_algorithm(
+            top,
+            ef,
+            SearchAlgorithm::Hnsw,
+            points_scorer,
+            custom_entry_points,
+        )
+    }
+
+    pub fn search_with_algorithm(
+        &self,
+        top: usize,
+        ef: usize,
+        algorithm: SearchAlgorithm,
         mut points_scorer: FilteredScorer,
         custom_entry_points: Option<&[PointOffsetType]>,
     ) -> Vec<ScoredPointOffset> {
@@ -218,7 +369,13 @@ impl<TGraphLinks: GraphLinks> GraphLayers<TGraphLinks> {
             0,
             &mut points_scorer,
         );
-        let nearest = self.search_on_level(zero_level_entry, 0, max(top, ef), &mut points_scorer);
+        let nearest = self.search_on_level_with_algorithm(
+            zero_level_entry,
+            0,
+            max(top, ef),
+            algorithm,
+            &mut points_scorer,
+        );
         nearest.into_iter().take(top).collect_vec()
     }
 
@@ -233,6 +390,46 @@ impl<TGraphLinks: GraphLinks> GraphLayers<TGraphLinks> {
     pub fn num_points(&self) -> usize {
         self.links.num_points()
     }
+
+    /// Measure health of the graph, given the mask of points which are not deleted
+    pub fn health(&self, live: &BitSlice) -> GraphHealth {
+        let is_live =
+            |point_id: PointOffsetType| live.get(point_id as usize).is_some_and(|is_live| *is_live);
+
+        let num_points = self.links.num_points() as PointOffsetType;
+        let mut health = GraphHealth {
+            live_points: 0,
+            reachable_points: 0,
+            total_links: 0,
+            deleted_links: 0,
+        };
+        for point_id in (0..num_points).filter(|&point_id| is_live(point_id)) {
+            health.live_points += 1;
+            for link in self.links.links(point_id, 0) {
+                health.total_links += 1;
+                if !is_live(link) {
+                    health.deleted_links += 1;
+                }
+            }
+        }
+
+        // Search never expands deleted points, so only live points are traversed
+        if let Some(entry_point) = self.entry_points.get_entry_point(is_live) {
+            let mut visited = BitVec::repeat(false, num_points as usize);
+            visited.set(entry_point.point_id as usize, true);
+            let mut stack = vec![entry_point.point_id];
+            while let Some(point_id) = stack.pop() {
+                health.reachable_points += 1;
+                for link in self.links.links(point_id, 0) {
+                    if is_live(link) && !visited[link as usize] {
+                        visited.set(link as usize, true);
+                        stack.push(link);
+                    }
+                }
+            }
+        }
+        health
+    }
 }
 
 impl<TGraphLinks> GraphLayers<TGraphLinks>
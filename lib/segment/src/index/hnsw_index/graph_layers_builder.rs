@@ -3,8 +3,7 @@ use std::collections::BinaryHeap;
 use std::path::Path;
 use std::sync::atomic::AtomicUsize;
 
-use bitvec::prelude::BitVec;
-use common::fixed_length_priority_queue::FixedLengthPriorityQueue;
+use bitvec::prelude::{BitSlice, BitVec};
 use common::types::{PointOffsetType, ScoreType, ScoredPointOffset};
 use parking_lot::{Mutex, MutexGuard, RwLock};
 use rand::distributions::Uniform;
@@ -15,6 +14,7 @@ use crate::common::operation_error::OperationResult;
 use crate::index::hnsw_index::entry_points::EntryPoints;
 use crate::index::hnsw_index::graph_layers::{GraphLayers, GraphLayersBase, LinkContainer};
 use crate::index::hnsw_index::graph_links::GraphLinksConverter;
+use crate::index::hnsw_index::old_index::OldIndex;
 use crate::index::hnsw_index::point_scorer::FilteredScorer;
 use crate::index::hnsw_index::search_context::SearchContext;
 use crate::index::visited_pool::{VisitedListHandle, VisitedPool};
@@ -225,6 +225,52 @@ impl GraphLayersBuilder {
             .fetch_max(level, std::sync::atomic::Ordering::Relaxed);
     }
 
+    /// Fill the graph with points and links of a previously built graph.
+    ///
+    /// Points which are not `live` are dropped, together with links pointing to them.
+    /// Returns a mask of reused points and the list of reused points which lost some of
+    /// their links and should be linked again.
+    pub fn fill_from_old_index(
+        &mut self,
+        old_index: OldIndex,
+        live: &BitSlice,
+    ) -> (BitVec, Vec<PointOffsetType>) {
+        let mut reused = BitVec::repeat(false, self.num_points());
+        for point in &old_index.points {
+            if live.get(point.point_id as usize).is_some_and(|live| *live) {
+                reused.set(point.point_id as usize, true);
+            }
+        }
+
+        let mut relink = Vec::new();
+        for point in old_index.points {
+            if !reused[point.point_id as usize] {
+                continue;
+            }
+            let level = point.links.len() - 1;
+            self.set_levels(point.point_id, level);
+
+            let mut lost_links = point.lost_links;
+            for (level, mut links) in point.links.into_iter().enumerate() {
+                let total = links.len();
+                links.retain(|&link| reused.get(link as usize).is_some_and(|reused| *reused));
+                lost_links |= links.len() < total || (level == 0 && links.is_empty());
+                *self.links_layers[point.point_id as usize][level].get_mut() = links;
+            }
+
+            if lost_links {
+                // Registered as an entry point and marked ready once it is linked again
+                relink.push(point.point_id);
+            } else {
+                self.entry_points
+                    .get_mut()
+                    .new_point(point.point_id, level, |_| true);
+                self.ready_list.get_mut().set(point.point_id as usize, true);
+            }
+        }
+        (reused, relink)
+    }
+
     /// Connect new point to links, so that links contains only closest points
     fn connect_new_point<F>(
         links: &mut LinkContainer,
@@ -287,7 +333,7 @@ impl GraphLayersBuilder {
 
     /// <https://github.com/nmslib/hnswlib/issues/99>
     fn select_candidates_with_heuristic<F>(
-        candidates: FixedLengthPriorityQueue<ScoredPointOffset>,
+        candidates: impl IntoIterator<Item = ScoredPointOffset>,
         m: usize,
         score_internal: F,
     ) -> Vec<PointOffsetType>
@@ -353,7 +399,14 @@ impl GraphLayersBuilder {
                         &mut points_scorer,
                     );
 
-                    if let Some(the_nearest) = search_context.nearest.iter().max() {
+                    // A point which is linked again can be reached through the links of its
+                    // own neighbours, it must not be linked to itself
+                    if let Some(the_nearest) = search_context
+                        .nearest
+                        .iter()
+                        .filter(|nearest| nearest.idx != point_id)
+                        .max()
+                    {
                         level_entry = *the_nearest;
                     }
 
@@ -378,7 +431,10 @@ impl GraphLayersBuilder {
                             }
 
                             let selected_nearest = Self::select_candidates_with_heuristic(
-                                search_context.nearest,
+                                search_context
+                                    .nearest
+                                    .into_iter()
+                                    .filter(|nearest| nearest.idx != point_id),
                                 level_m,
                                 scorer,
                             );
@@ -419,7 +475,11 @@ impl GraphLayersBuilder {
                             }
                         }
                     } else {
-                        for nearest_point in &search_context.nearest {
+                        for nearest_point in search_context
+                            .nearest
+                            .iter()
+                            .filter(|nearest| nearest.idx != point_id)
+                        {
                             {
                                 let mut links =
                                     self.links_layers[point_id as usize][curr_level].write();
@@ -478,6 +538,7 @@ impl GraphLayersBuilder {
 
 #[cfg(test)]
 mod tests {
+    use common::fixed_length_priority_queue::FixedLengthPriorityQueue;
     use itertools::Itertools;
     use rand::prelude::StdRng;
     use rand::seq::SliceRandom;
@@ -678,10 +739,15 @@ mod tests {
         assert_eq!(orig_len, builder_len);
 
         for idx in 0..builder_len {
-            let links_orig = &graph_layers_orig.links.links(idx as PointOffsetType, 0);
+            let links_orig = graph_layers_orig
+                .links
+                .links(idx as PointOffsetType, 0)
+                .collect::<Vec<_>>();
             let links_builder = graph_layers_builder.links_layers[idx][0].read();
-            let link_container_from_builder = links_builder.iter().copied().collect::<Vec<_>>();
-            assert_eq!(links_orig, &link_container_from_builder);
+            // stored links are sorted
+            let mut link_container_from_builder = links_builder.iter().copied().collect::<Vec<_>>();
+            link_container_from_builder.sort_unstable();
+            assert_eq!(links_orig, link_container_from_builder);
         }
 
         let main_entry = graph_layers_builder
@@ -776,7 +842,7 @@ mod tests {
 
         let layers910 = graph_layers.links.point_level(910);
         let links910 = (0..layers910 + 1)
-            .map(|i| graph_layers.links.links(910, i).to_vec())
+            .map(|i| graph_layers.links.links(910, i).collect::<Vec<_>>())
             .collect::<Vec<_>>();
         eprintln!("graph_layers.links_layers[910] = {links910:#?}",);
 
@@ -1,8 +1,10 @@
 use std::cmp::max;
 use std::fs::OpenOptions;
+use std::iter::Copied;
 use std::mem::{self, size_of};
 use std::ops::Range;
 use std::path::{Path, PathBuf};
+use std::slice;
 use std::sync::Arc;
 
 use common::types::PointOffsetType;
@@ -44,8 +46,59 @@ reindex:           142350  142350 142350 142350  (same for each level)
 
 for lvl > 0:
 links offset = level_offsets[level] + offsets[reindex[point_id]]
+
+
+In the compressed format `flatten` is a byte array, offsets point to bytes.
+Each list of links is sorted and encoded as:
+
+    varint(count) varint(first) u8(bits) packed(deltas)
+
+where `deltas` are `count - 1` differences between consecutive links,
+bit-packed with `bits` bits each, least significant bit first.
 */
 
+/// Format version of links written before compression was introduced.
+/// Its header has no version field, the reserved space after the header is zeroed.
+const GRAPH_LINKS_VERSION_PLAIN: u64 = 0;
+const GRAPH_LINKS_VERSION_COMPRESSED: u64 = 1;
+
+/// Format of links in the file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GraphLinksFormat {
+    /// Each link is stored as raw `PointOffsetType`, in the order of insertion
+    Plain,
+    /// Links are sorted, delta encoded and bit-packed
+    #[default]
+    Compressed,
+}
+
+impl GraphLinksFormat {
+    fn version(self) -> u64 {
+        match self {
+            GraphLinksFormat::Plain => GRAPH_LINKS_VERSION_PLAIN,
+            GraphLinksFormat::Compressed => GRAPH_LINKS_VERSION_COMPRESSED,
+        }
+    }
+
+    fn from_version(version: u64) -> OperationResult<Self> {
+        match version {
+            GRAPH_LINKS_VERSION_PLAIN => Ok(GraphLinksFormat::Plain),
+            GRAPH_LINKS_VERSION_COMPRESSED => Ok(GraphLinksFormat::Compressed),
+            _ => Err(OperationError::service_error(format!(
+                "Unsupported HNSW links format version: {version}"
+            ))),
+        }
+    }
+
+    /// Size of a single element of the links array
+    fn link_size(self) -> usize {
+        match self {
+            GraphLinksFormat::Plain => size_of::<PointOffsetType>(),
+            GraphLinksFormat::Compressed => size_of::<u8>(),
+        }
+    }
+}
+
 #[derive(Default)]
 struct GraphLinksFileHeader {
     pub point_count: u64,
@@ -53,6 +106,7 @@ struct GraphLinksFileHeader {
     pub total_links_len: u64,
     pub total_offsets_len: u64,
     pub offsets_padding: u64,
+    pub format: GraphLinksFormat,
 }
 
 fn get_reindex_slice<'a>(
@@ -70,6 +124,10 @@ fn get_links_slice<'a>(data: &'a [u8], header: &'a GraphLinksFileHeader) -> &'a
     mmap_ops::transmute_from_u8_to_slice(links_byte_slice)
 }
 
+fn get_compressed_links_slice<'a>(data: &'a [u8], header: &'a GraphLinksFileHeader) -> &'a [u8] {
+    &data[header.get_links_range()]
+}
+
 fn get_offsets_iter<'a>(
     data: &'a [u8],
     header: &'a GraphLinksFileHeader,
@@ -96,23 +154,24 @@ impl GraphLinksFileHeader {
         levels_count: usize,
         total_links_len: usize,
         total_offsets_len: usize,
+        format: GraphLinksFormat,
     ) -> GraphLinksFileHeader {
-        let offsets_padding = if (point_count + total_links_len) % 2 == 0 {
-            0
-        } else {
-            4
-        };
-        GraphLinksFileHeader {
+        let mut header = GraphLinksFileHeader {
             point_count: point_count as u64,
             levels_count: levels_count as u64,
             total_links_len: total_links_len as u64,
             total_offsets_len: total_offsets_len as u64,
-            offsets_padding,
-        }
+            offsets_padding: 0,
+            format,
+        };
+        // align offsets to `u64`
+        let links_end = header.get_links_range().end;
+        header.offsets_padding = (links_end.next_multiple_of(size_of::<u64>()) - links_end) as u64;
+        header
     }
 
     pub fn raw_size() -> usize {
-        size_of::<u64>() * 5
+        size_of::<u64>() * 6
     }
 
     pub fn serialize_bytes_to(&self, raw_data: &mut [u8]) {
@@ -123,18 +182,20 @@ impl GraphLinksFileHeader {
         arr[2] = self.total_links_len;
         arr[3] = self.total_offsets_len;
         arr[4] = self.offsets_padding;
+        arr[5] = self.format.version();
     }
 
-    pub fn deserialize_bytes_from(raw_data: &[u8]) -> GraphLinksFileHeader {
+    pub fn deserialize_bytes_from(raw_data: &[u8]) -> OperationResult<GraphLinksFileHeader> {
         let byte_slice = &raw_data[0..Self::raw_size()];
         let arr: &[u64] = mmap_ops::transmute_from_u8_to_slice(byte_slice);
-        GraphLinksFileHeader {
+        Ok(GraphLinksFileHeader {
             point_count: arr[0],
             levels_count: arr[1],
             total_links_len: arr[2],
             total_offsets_len: arr[3],
             offsets_padding: arr[4],
-        }
+            format: GraphLinksFormat::from_version(arr[5])?,
+        })
     }
 
     pub fn get_data_size(&self) -> u64 {
@@ -155,7 +216,7 @@ impl GraphLinksFileHeader {
 
     pub fn get_links_range(&self) -> Range<usize> {
         let start = self.get_reindex_range().end;
-        start..start + self.total_links_len as usize * size_of::<PointOffsetType>()
+        start..start + self.total_links_len as usize * self.format.link_size()
     }
 
     pub fn get_offsets_range(&self) -> Range<usize> {
@@ -171,10 +232,18 @@ pub struct GraphLinksConverter {
     total_links_len: usize,
     total_offsets_len: usize,
     path: Option<PathBuf>,
+    format: GraphLinksFormat,
 }
 
 impl GraphLinksConverter {
     pub fn new(edges: Vec<Vec<Vec<PointOffsetType>>>) -> Self {
+        Self::new_with_format(edges, GraphLinksFormat::default())
+    }
+
+    pub fn new_with_format(
+        mut edges: Vec<Vec<Vec<PointOffsetType>>>,
+        format: GraphLinksFormat,
+    ) -> Self {
         if edges.is_empty() {
             return Self {
                 edges,
@@ -183,9 +252,16 @@ impl GraphLinksConverter {
                 total_links_len: 0,
                 total_offsets_len: 1,
                 path: None,
+                format,
             };
         }
 
+        if format == GraphLinksFormat::Compressed {
+            for layer in edges.iter_mut().flatten() {
+                layer.sort_unstable();
+            }
+        }
+
         // create map from index in `offsets` to point_id
         let mut back_index: Vec<usize> = (0..edges.len()).collect();
         // sort by max layer and use this map to build `Self.reindex`
@@ -203,7 +279,10 @@ impl GraphLinksConverter {
         let mut total_offsets_len = 1;
         for point in edges.iter() {
             for layer in point.iter() {
-                total_links_len += layer.len();
+                total_links_len += match format {
+                    GraphLinksFormat::Plain => layer.len(),
+                    GraphLinksFormat::Compressed => compressed_links_size(layer),
+                };
                 total_offsets_len += 1;
             }
         }
@@ -215,6 +294,7 @@ impl GraphLinksConverter {
             total_links_len,
             total_offsets_len,
             path: None,
+            format,
         }
     }
 
@@ -228,6 +308,7 @@ impl GraphLinksConverter {
             self.get_levels_count(),
             self.total_links_len,
             self.total_offsets_len,
+            self.format,
         )
     }
 
@@ -259,22 +340,37 @@ impl GraphLinksConverter {
                 .as_mut()
                 .split_at_mut(links_range.len());
             let offsets_mmap = &mut offsets_with_padding_mmap[header.offsets_padding as _..];
-            let links_mmap: &mut [PointOffsetType] =
-                mmap_ops::transmute_from_u8_to_mut_slice(links_mmap);
             let offsets_mmap: &mut [u64] = mmap_ops::transmute_from_u8_to_mut_slice(offsets_mmap);
             offsets_mmap[0] = 0;
 
             let mut links_pos = 0;
             let mut offsets_pos = 1;
-            for level in 0..header_levels_count {
-                level_offsets.push(offsets_pos as u64 - 1);
-                self.iterate_level_points(level, |_, links| {
-                    links_mmap[links_pos..links_pos + links.len()].copy_from_slice(links);
-                    links_pos += links.len();
-
-                    offsets_mmap[offsets_pos] = links_pos as u64;
-                    offsets_pos += 1;
-                });
+            match self.format {
+                GraphLinksFormat::Plain => {
+                    let links_mmap: &mut [PointOffsetType] =
+                        mmap_ops::transmute_from_u8_to_mut_slice(links_mmap);
+                    for level in 0..header_levels_count {
+                        level_offsets.push(offsets_pos as u64 - 1);
+                        self.iterate_level_points(level, |_, links| {
+                            links_mmap[links_pos..links_pos + links.len()].copy_from_slice(links);
+                            links_pos += links.len();
+
+                            offsets_mmap[offsets_pos] = links_pos as u64;
+                            offsets_pos += 1;
+                        });
+                    }
+                }
+                GraphLinksFormat::Compressed => {
+                    for level in 0..header_levels_count {
+                        level_offsets.push(offsets_pos as u64 - 1);
+                        self.iterate_level_points(level, |_, links| {
+                            links_pos += compress_links(links, &mut links_mmap[links_pos..]);
+
+                            offsets_mmap[offsets_pos] = links_pos as u64;
+                            offsets_pos += 1;
+                        });
+                    }
+                }
             }
         }
 
@@ -345,6 +441,213 @@ impl GraphLinksConverter {
     }
 }
 
+fn varint_size(mut value: u64) -> usize {
+    let mut size = 1;
+    while value >= 0x80 {
+        value >>= 7;
+        size += 1;
+    }
+    size
+}
+
+fn write_varint(mut value: u64, out: &mut [u8]) -> usize {
+    let mut pos = 0;
+    while value >= 0x80 {
+        out[pos] = (value as u8) | 0x80;
+        value >>= 7;
+        pos += 1;
+    }
+    out[pos] = value as u8;
+    pos + 1
+}
+
+fn read_varint(data: &[u8], pos: &mut usize) -> u64 {
+    let mut value = 0;
+    let mut shift = 0;
+    loop {
+        let byte = data[*pos];
+        *pos += 1;
+        value |= u64::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return value;
+        }
+        shift += 7;
+    }
+}
+
+/// Number of bits required to store the largest delta between sorted links
+fn deltas_bits(sorted_links: &[PointOffsetType]) -> u8 {
+    let max_delta = sorted_links
+        .windows(2)
+        .map(|pair| pair[1] - pair[0])
+        .max()
+        .unwrap_or(0);
+    (PointOffsetType::BITS - max_delta.leading_zeros()) as u8
+}
+
+/// Size in bytes of the compressed representation of sorted links
+fn compressed_links_size(sorted_links: &[PointOffsetType]) -> usize {
+    let count_size = varint_size(sorted_links.len() as u64);
+    let Some(&first) = sorted_links.first() else {
+        return count_size;
+    };
+    let packed_bits = (sorted_links.len() - 1) * deltas_bits(sorted_links) as usize;
+    count_size + varint_size(u64::from(first)) + size_of::<u8>() + packed_bits.div_ceil(8)
+}
+
+/// Compress sorted links into `out`, returns number of written bytes
+fn compress_links(sorted_links: &[PointOffsetType], out: &mut [u8]) -> usize {
+    let mut pos = write_varint(sorted_links.len() as u64, out);
+    let Some(&first) = sorted_links.first() else {
+        return pos;
+    };
+    pos += write_varint(u64::from(first), &mut out[pos..]);
+    let bits = deltas_bits(sorted_links);
+    out[pos] = bits;
+    pos += 1;
+
+    let packed_bits = (sorted_links.len() - 1) * bits as usize;
+    let packed = &mut out[pos..pos + packed_bits.div_ceil(8)];
+    packed.fill(0);
+    let mut bit_pos = 0;
+    for pair in sorted_links.windows(2) {
+        let delta = u64::from(pair[1] - pair[0]);
+        // delta occupies at most 32 + 7 bits starting from the current byte
+        let shifted = delta << (bit_pos % 8);
+        for (i, byte) in packed[bit_pos / 8..].iter_mut().take(5).enumerate() {
+            *byte |= (shifted >> (i * 8)) as u8;
+        }
+        bit_pos += bits as usize;
+    }
+    pos + packed.len()
+}
+
+/// Iterator over links decoded on the fly from the compressed representation
+pub struct CompressedLinksIterator<'a> {
+    packed: &'a [u8],
+    bits: u8,
+    bit_pos: usize,
+    current: PointOffsetType,
+    remaining: usize,
+    started: bool,
+}
+
+impl<'a> CompressedLinksIterator<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        let mut pos = 0;
+        let remaining = read_varint(data, &mut pos) as usize;
+        if remaining == 0 {
+            return Self {
+                packed: &[],
+                bits: 0,
+                bit_pos: 0,
+                current: 0,
+                remaining,
+                started: false,
+            };
+        }
+        let current = read_varint(data, &mut pos) as PointOffsetType;
+        let bits = data[pos];
+        Self {
+            packed: &data[pos + 1..],
+            bits,
+            bit_pos: 0,
+            current,
+            remaining,
+            started: false,
+        }
+    }
+
+    #[inline]
+    fn read_delta(&mut self) -> PointOffsetType {
+        if self.bits == 0 {
+            return 0;
+        }
+        let start = self.bit_pos / 8;
+        let mut buffer = [0u8; size_of::<u64>()];
+        let available = (self.packed.len() - start).min(buffer.len());
+        buffer[..available].copy_from_slice(&self.packed[start..start + available]);
+        let word = u64::from_le_bytes(buffer) >> (self.bit_pos % 8);
+        self.bit_pos += self.bits as usize;
+        (word & ((1u64 << self.bits) - 1)) as PointOffsetType
+    }
+}
+
+impl Iterator for CompressedLinksIterator<'_> {
+    type Item = PointOffsetType;
+
+    #[inline]
+    fn next(&mut self) -> Option<PointOffsetType> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        if self.started {
+            self.current += self.read_delta();
+        } else {
+            self.started = true;
+        }
+        Some(self.current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl ExactSizeIterator for CompressedLinksIterator<'_> {}
+
+/// Iterator over links of a single point at a single level
+pub enum LinksIterator<'a> {
+    Plain(Copied<slice::Iter<'a, PointOffsetType>>),
+    Compressed(CompressedLinksIterator<'a>),
+}
+
+impl Iterator for LinksIterator<'_> {
+    type Item = PointOffsetType;
+
+    #[inline]
+    fn next(&mut self) -> Option<PointOffsetType> {
+        match self {
+            LinksIterator::Plain(iter) => iter.next(),
+            LinksIterator::Compressed(iter) => iter.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            LinksIterator::Plain(iter) => iter.size_hint(),
+            LinksIterator::Compressed(iter) => iter.size_hint(),
+        }
+    }
+}
+
+impl ExactSizeIterator for LinksIterator<'_> {}
+
+fn links_iterator<'a>(links: LinksData<'a>, range: Range<usize>) -> LinksIterator<'a> {
+    match links {
+        LinksData::Plain(links) => LinksIterator::Plain(links[range].iter().copied()),
+        LinksData::Compressed(links) => {
+            LinksIterator::Compressed(CompressedLinksIterator::new(&links[range]))
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum LinksData<'a> {
+    Plain(&'a [PointOffsetType]),
+    Compressed(&'a [u8]),
+}
+
+fn get_links_data<'a>(data: &'a [u8], header: &'a GraphLinksFileHeader) -> LinksData<'a> {
+    match header.format {
+        GraphLinksFormat::Plain => LinksData::Plain(get_links_slice(data, header)),
+        GraphLinksFormat::Compressed => {
+            LinksData::Compressed(get_compressed_links_slice(data, header))
+        }
+    }
+}
+
 pub trait GraphLinks: Default {
     fn load_from_file(path: &Path) -> OperationResult<Self>;
 
@@ -354,7 +657,7 @@ pub trait GraphLinks: Default {
 
     fn levels_count(&self) -> usize;
 
-    fn get_links(&self, range: Range<usize>) -> &[PointOffsetType];
+    fn get_links(&self, range: Range<usize>) -> LinksIterator<'_>;
 
     fn get_links_range(&self, idx: usize) -> Range<usize>;
 
@@ -364,7 +667,7 @@ pub trait GraphLinks: Default {
 
     fn num_points(&self) -> usize;
 
-    fn links(&self, point_id: PointOffsetType, level: usize) -> &[PointOffsetType] {
+    fn links(&self, point_id: PointOffsetType, level: usize) -> LinksIterator<'_> {
         if level == 0 {
             let links_range = self.get_links_range(point_id as usize);
             self.get_links(links_range)
@@ -410,10 +713,21 @@ pub trait GraphLinks: Default {
     }
 }
 
+enum GraphLinksRamData {
+    Plain(Vec<PointOffsetType>),
+    Compressed(Vec<u8>),
+}
+
+impl Default for GraphLinksRamData {
+    fn default() -> Self {
+        GraphLinksRamData::Plain(Vec::new())
+    }
+}
+
 #[derive(Default)]
 pub struct GraphLinksRam {
-    // all flattened links of all levels
-    links: Vec<PointOffsetType>,
+    // all flattened links of all levels, raw or compressed
+    links: GraphLinksRamData,
     // all ranges in `links`. each range is `links[offsets[i]..offsets[i+1]]`
     // ranges are sorted by level
     offsets: Vec<u64>,
@@ -425,16 +739,26 @@ pub struct GraphLinksRam {
 
 impl GraphLinksRam {
     pub fn load_from_memory(data: &[u8]) -> OperationResult<Self> {
-        let header = GraphLinksFileHeader::deserialize_bytes_from(data);
+        let header = GraphLinksFileHeader::deserialize_bytes_from(data)?;
 
-        let mut links: Vec<PointOffsetType> = Vec::new();
         let mut offsets: Vec<u64> = Vec::new();
         let mut level_offsets: Vec<u64> = Vec::new();
         let mut reindex: Vec<PointOffsetType> = Vec::new();
 
-        let link_slice = get_links_slice(data, &header);
-        links.try_set_capacity_exact(link_slice.len())?;
-        links.extend_from_slice(link_slice);
+        let links = match get_links_data(data, &header) {
+            LinksData::Plain(link_slice) => {
+                let mut links: Vec<PointOffsetType> = Vec::new();
+                links.try_set_capacity_exact(link_slice.len())?;
+                links.extend_from_slice(link_slice);
+                GraphLinksRamData::Plain(links)
+            }
+            LinksData::Compressed(link_slice) => {
+                let mut links: Vec<u8> = Vec::new();
+                links.try_set_capacity_exact(link_slice.len())?;
+                links.extend_from_slice(link_slice);
+                GraphLinksRamData::Compressed(links)
+            }
+        };
 
         offsets.try_set_capacity_exact(header.get_offsets_range().len() / size_of::<u64>())?;
         offsets.extend(get_offsets_iter(data, &header));
@@ -487,8 +811,12 @@ impl GraphLinks for GraphLinksRam {
         self.level_offsets.len()
     }
 
-    fn get_links(&self, range: Range<usize>) -> &[PointOffsetType] {
-        &self.links[range]
+    fn get_links(&self, range: Range<usize>) -> LinksIterator<'_> {
+        let links = match &self.links {
+            GraphLinksRamData::Plain(links) => LinksData::Plain(links),
+            GraphLinksRamData::Compressed(links) => LinksData::Compressed(links),
+        };
+        links_iterator(links, range)
     }
 
     fn get_links_range(&self, idx: usize) -> Range<usize> {
@@ -526,9 +854,9 @@ impl GraphLinksMmap {
         }
     }
 
-    fn get_links_slice(&self) -> &[PointOffsetType] {
+    fn get_links_data(&self) -> LinksData<'_> {
         if let Some(mmap) = &self.mmap {
-            get_links_slice(mmap, &self.header)
+            get_links_data(mmap, &self.header)
         } else {
             panic!("{}", MMAP_PANIC_MESSAGE);
         }
@@ -559,7 +887,7 @@ impl GraphLinks for GraphLinksMmap {
         let mmap = unsafe { Mmap::map(&file)? };
         madvise::madvise(&mmap, madvise::get_global())?;
 
-        let header = GraphLinksFileHeader::deserialize_bytes_from(&mmap);
+        let header = GraphLinksFileHeader::deserialize_bytes_from(&mmap)?;
         let level_offsets = get_level_offsets(&mmap, &header).to_vec();
 
         Ok(Self {
@@ -587,8 +915,8 @@ impl GraphLinks for GraphLinksMmap {
         self.level_offsets.len()
     }
 
-    fn get_links(&self, range: Range<usize>) -> &[PointOffsetType] {
-        &self.get_links_slice()[range]
+    fn get_links(&self, range: Range<usize>) -> LinksIterator<'_> {
+        links_iterator(self.get_links_data(), range)
     }
 
     fn get_links_range(&self, idx: usize) -> Range<usize> {
@@ -629,7 +957,7 @@ mod tests {
             let mut layers = Vec::new();
             let num_levels = links.point_level(i as PointOffsetType) + 1;
             for level in 0..num_levels {
-                let links = links.links(i as PointOffsetType, level).to_vec();
+                let links = links.links(i as PointOffsetType, level).collect();
                 layers.push(links);
             }
             result.push(layers);
@@ -637,6 +965,14 @@ mod tests {
         result
     }
 
+    /// Compressed format stores links sorted
+    fn sorted(mut links: Vec<Vec<Vec<PointOffsetType>>>) -> Vec<Vec<Vec<PointOffsetType>>> {
+        for layer in links.iter_mut().flatten() {
+            layer.sort_unstable();
+        }
+        links
+    }
+
     fn random_links(
         points_count: usize,
         max_levels_count: usize,
@@ -658,7 +994,7 @@ mod tests {
     }
 
     /// Test that random links can be saved by `GraphLinksConverter` and loaded correctly by a GraphLinks impl.
-    fn test_save_load<A>(points_count: usize, max_levels_count: usize)
+    fn test_save_load<A>(points_count: usize, max_levels_count: usize, format: GraphLinksFormat)
     where
         A: GraphLinks,
     {
@@ -666,11 +1002,14 @@ mod tests {
         let links_file = path.path().join("links.bin");
         let links = random_links(points_count, max_levels_count);
         {
-            let mut links_converter = GraphLinksConverter::new(links.clone());
+            let mut links_converter = GraphLinksConverter::new_with_format(links.clone(), format);
             links_converter.save_as(&links_file).unwrap();
         }
         let cmp_links = to_vec(&A::load_from_file(&links_file).unwrap());
-        assert_eq!(links, cmp_links);
+        match format {
+            GraphLinksFormat::Plain => assert_eq!(links, cmp_links),
+            GraphLinksFormat::Compressed => assert_eq!(sorted(links), cmp_links),
+        }
     }
 
     #[test]
@@ -680,21 +1019,21 @@ mod tests {
         let cmp_links = to_vec(
             &GraphLinksRam::from_converter(GraphLinksConverter::new(links.clone())).unwrap(),
         );
-        assert_eq!(links, cmp_links);
+        assert_eq!(sorted(links), cmp_links);
 
         // 2 points without any links
         let links: Vec<Vec<Vec<PointOffsetType>>> = vec![vec![vec![]], vec![vec![]]];
         let cmp_links = to_vec(
             &GraphLinksRam::from_converter(GraphLinksConverter::new(links.clone())).unwrap(),
         );
-        assert_eq!(links, cmp_links);
+        assert_eq!(sorted(links), cmp_links);
 
         // one link at level 0
         let links: Vec<Vec<Vec<PointOffsetType>>> = vec![vec![vec![1]], vec![vec![0]]];
         let cmp_links = to_vec(
             &GraphLinksRam::from_converter(GraphLinksConverter::new(links.clone())).unwrap(),
         );
-        assert_eq!(links, cmp_links);
+        assert_eq!(sorted(links), cmp_links);
 
         // 3 levels with no links at second level
         let links: Vec<Vec<Vec<PointOffsetType>>> = vec![
@@ -705,7 +1044,7 @@ mod tests {
         let cmp_links = to_vec(
             &GraphLinksRam::from_converter(GraphLinksConverter::new(links.clone())).unwrap(),
         );
-        assert_eq!(links, cmp_links);
+        assert_eq!(sorted(links), cmp_links);
 
         // 3 levels with no links at last level
         let links: Vec<Vec<Vec<PointOffsetType>>> = vec![
@@ -716,7 +1055,7 @@ mod tests {
         let cmp_links = to_vec(
             &GraphLinksRam::from_converter(GraphLinksConverter::new(links.clone())).unwrap(),
         );
-        assert_eq!(links, cmp_links);
+        assert_eq!(sorted(links), cmp_links);
 
         // 4 levels with random nonexistent links
         let links: Vec<Vec<Vec<PointOffsetType>>> = vec![
@@ -729,19 +1068,70 @@ mod tests {
         let cmp_links = to_vec(
             &GraphLinksRam::from_converter(GraphLinksConverter::new(links.clone())).unwrap(),
         );
-        assert_eq!(links, cmp_links);
+        assert_eq!(sorted(links), cmp_links);
 
         // fully random links
         let links = random_links(100, 10);
         let cmp_links = to_vec(
             &GraphLinksRam::from_converter(GraphLinksConverter::new(links.clone())).unwrap(),
         );
-        assert_eq!(links, cmp_links);
+        assert_eq!(sorted(links), cmp_links);
     }
 
     #[test]
     fn test_graph_links_mmap_ram_compatibility() {
-        test_save_load::<GraphLinksRam>(1000, 10);
-        test_save_load::<GraphLinksMmap>(1000, 10);
+        test_save_load::<GraphLinksRam>(1000, 10, GraphLinksFormat::Compressed);
+        test_save_load::<GraphLinksMmap>(1000, 10, GraphLinksFormat::Compressed);
+    }
+
+    #[test]
+    fn test_graph_links_plain_format_compatibility() {
+        // files written before compression was introduced must remain readable
+        test_save_load::<GraphLinksRam>(1000, 10, GraphLinksFormat::Plain);
+        test_save_load::<GraphLinksMmap>(1000, 10, GraphLinksFormat::Plain);
+    }
+
+    #[test]
+    fn test_compressed_links_roundtrip() {
+        let cases: Vec<Vec<PointOffsetType>> = vec![
+            vec![],
+            vec![0],
+            vec![PointOffsetType::MAX],
+            vec![7, 7, 7],
+            vec![0, PointOffsetType::MAX],
+            vec![1, 2, 3, 100, 1000, 100_000, 10_000_000],
+            (0..100).map(|i| i * 3 + 1000).collect(),
+        ];
+        for links in cases {
+            let mut data = vec![0xff; compressed_links_size(&links)];
+            assert_eq!(compress_links(&links, &mut data), data.len());
+            let iter = CompressedLinksIterator::new(&data);
+            assert_eq!(iter.len(), links.len());
+            assert_eq!(iter.collect::<Vec<_>>(), links);
+        }
+    }
+
+    #[test]
+    fn test_compressed_links_size() {
+        let mut rng = rand::thread_rng();
+        let points_count = 10_000;
+        let links: Vec<Vec<Vec<PointOffsetType>>> = (0..points_count)
+            .map(|_| {
+                vec![(0..32)
+                    .map(|_| rng.gen_range(0..points_count) as PointOffsetType)
+                    .collect()]
+            })
+            .collect();
+
+        let plain =
+            GraphLinksConverter::new_with_format(links.clone(), GraphLinksFormat::Plain).to_bytes();
+        let compressed =
+            GraphLinksConverter::new_with_format(links, GraphLinksFormat::Compressed).to_bytes();
+        assert!(
+            compressed.len() < plain.len() * 3 / 4,
+            "compressed: {}, plain: {}",
+            compressed.len(),
+            plain.len(),
+        );
     }
 }
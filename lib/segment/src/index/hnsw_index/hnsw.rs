@@ -5,7 +5,7 @@ use std::sync::Arc;
 use std::thread;
 
 use atomic_refcell::AtomicRefCell;
-use bitvec::prelude::BitSlice;
+use bitvec::prelude::{BitSlice, BitVec};
 #[cfg(target_os = "linux")]
 use common::cpu::linux_low_thread_priority;
 use common::cpu::CpuPermit;
@@ -26,12 +26,14 @@ use crate::common::BYTES_IN_KB;
 use crate::data_types::query_context::VectorQueryContext;
 use crate::data_types::vectors::{QueryVector, Vector, VectorRef};
 use crate::id_tracker::IdTrackerSS;
+use crate::index::field_index::CardinalityEstimation;
 use crate::index::hnsw_index::build_condition_checker::BuildConditionChecker;
 use crate::index::hnsw_index::config::HnswGraphConfig;
-use crate::index::hnsw_index::graph_layers::GraphLayers;
+use crate::index::hnsw_index::graph_layers::{GraphHealth, GraphLayers, SearchAlgorithm};
 use crate::index::hnsw_index::graph_layers_builder::GraphLayersBuilder;
+use crate::index::hnsw_index::old_index::OldIndex;
 use crate::index::hnsw_index::point_scorer::FilteredScorer;
-use crate::index::query_estimator::adjust_to_available_vectors;
+use crate::index::query_estimator::{adjust_to_available_vectors, expected_selectivity};
 use crate::index::sample_estimation::sample_check_cardinality;
 use crate::index::struct_payload_index::StructPayloadIndex;
 use crate::index::visited_pool::{VisitedListHandle, VisitedPool};
@@ -40,16 +42,29 @@ use crate::telemetry::VectorIndexSearchesTelemetry;
 use crate::types::Condition::Field;
 use crate::types::{
     default_quantization_ignore_value, default_quantization_oversampling_value, FieldCondition,
-    Filter, HnswConfig, QuantizationSearchParams, SearchParams, VECTOR_ELEMENT_SIZE,
+    Filter, HnswConfig, QuantizationConfig, QuantizationSearchParams, SearchParams,
+    VECTOR_ELEMENT_SIZE,
 };
 use crate::vector_storage::quantized::quantized_vectors::QuantizedVectors;
 use crate::vector_storage::query::discovery_query::DiscoveryQuery;
 use crate::vector_storage::{
-    new_raw_scorer, new_stoppable_raw_scorer, RawScorer, VectorStorage, VectorStorageEnum,
+    new_fde_raw_scorer, new_prefix_raw_scorer, new_raw_scorer, new_stoppable_raw_scorer, RawScorer,
+    VectorStorage, VectorStorageEnum,
 };
 
 const HNSW_USE_HEURISTIC: bool = true;
 
+/// Extension of the links file of a repaired graph, until it replaces the current one
+const REPAIRED_LINKS_EXTENSION: &str = "repaired";
+
+/// Filtered searches expected to select less than this fraction of vectors use filter-aware
+/// graph traversal, see [`SearchAlgorithm::Acorn`]
+const ACORN_MAX_SELECTIVITY: f64 = 0.5;
+
+/// Filter-aware graph traversal stays connected with fewer points passing the filter, so the
+/// full scan threshold is lowered by this factor when it is selected
+const ACORN_FULL_SCAN_THRESHOLD_DIVISOR: usize = 4;
+
 /// Build first N points in HNSW graph using only a single thread, to avoid
 /// disconnected components in the graph.
 #[cfg(debug_assertions)]
@@ -57,6 +72,57 @@ const SINGLE_THREADED_HNSW_BUILD_THRESHOLD: usize = 32;
 #[cfg(not(debug_assertions))]
 const SINGLE_THREADED_HNSW_BUILD_THRESHOLD: usize = 256;
 
+fn build_thread_pool(permit: &CpuPermit) -> OperationResult<ThreadPool> {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .thread_name(|idx| format!("hnsw-build-{idx}"))
+        .num_threads(permit.num_cpus as usize)
+        .spawn_handler(|thread| {
+            let mut b = thread::Builder::new();
+            if let Some(name) = thread.name() {
+                b = b.name(name.to_owned());
+            }
+            if let Some(stack_size) = thread.stack_size() {
+                b = b.stack_size(stack_size);
+            }
+            b.spawn(|| {
+                // On Linux, use lower thread priority so we interfere less with serving traffic
+                #[cfg(target_os = "linux")]
+                if let Err(err) = linux_low_thread_priority() {
+                    log::debug!(
+                        "Failed to set low thread priority for HNSW building, ignoring: {err}"
+                    );
+                }
+
+                thread.run()
+            })?;
+            Ok(())
+        })
+        .build()?;
+    Ok(pool)
+}
+
+/// Mask of vectors which are neither deleted themselves nor belong to a deleted point
+fn live_vectors(
+    id_tracker: &IdTrackerSS,
+    deleted_bitslice: &BitSlice,
+    total_vector_count: usize,
+) -> BitVec {
+    let mut live = BitVec::repeat(false, total_vector_count);
+    for vector_id in id_tracker.iter_ids_excluding(deleted_bitslice) {
+        if let Some(mut is_live) = live.get_mut(vector_id as usize) {
+            *is_live = true;
+        }
+    }
+    live
+}
+
+/// Graph built by [`HNSWIndex::repair_graph`]
+pub struct RepairedGraph<TGraphLinks: GraphLinks> {
+    graph: GraphLayers<TGraphLinks>,
+    links_path: PathBuf,
+    indexed_vector_count: usize,
+}
+
 pub struct HNSWIndex<TGraphLinks: GraphLinks> {
     id_tracker: Arc<AtomicRefCell<IdTrackerSS>>,
     vector_storage: Arc<AtomicRefCell<VectorStorageEnum>>,
@@ -85,6 +151,7 @@ impl<TGraphLinks: GraphLinks> HNSWIndex<TGraphLinks> {
         quantized_vectors: Arc<AtomicRefCell<Option<QuantizedVectors>>>,
         payload_index: Arc<AtomicRefCell<StructPayloadIndex>>,
         hnsw_config: HnswConfig,
+        prefix_size: Option<usize>,
     ) -> OperationResult<Self> {
         create_dir_all(path)?;
 
@@ -104,6 +171,7 @@ impl<TGraphLinks: GraphLinks> HNSWIndex<TGraphLinks> {
                 hnsw_config.max_indexing_threads,
                 hnsw_config.payload_m,
                 available_vectors,
+                prefix_size,
             )
         };
 
@@ -142,6 +210,137 @@ impl<TGraphLinks: GraphLinks> HNSWIndex<TGraphLinks> {
         self.quantized_vectors.clone()
     }
 
+    /// Extract the graph of this index to reuse it in the index of another segment.
+    ///
+    /// `old_to_new` maps internal ids of this segment to internal ids of the new one.
+    pub fn old_index(&self, old_to_new: &[Option<PointOffsetType>]) -> Option<OldIndex> {
+        let graph = self.graph.as_ref()?;
+        let vector_storage = self.vector_storage.borrow();
+        Some(OldIndex::from_graph(
+            graph,
+            &self.config,
+            vector_storage.distance(),
+            vector_storage.try_multi_vector_config().copied(),
+            self.quantization_config(),
+            old_to_new,
+        ))
+    }
+
+    /// Quantization of the vectors, which the graph is built with
+    fn quantization_config(&self) -> Option<QuantizationConfig> {
+        self.quantized_vectors
+            .borrow()
+            .as_ref()
+            .map(|quantized_vectors| quantized_vectors.quantization_config().clone())
+    }
+
+    /// Measure health of the graph with respect to deleted points, see [`GraphHealth`]
+    pub fn graph_health(&self) -> Option<GraphHealth> {
+        let graph = self.graph.as_ref()?;
+        let id_tracker = self.id_tracker.borrow();
+        let vector_storage = self.vector_storage.borrow();
+        let live = live_vectors(
+            &id_tracker,
+            vector_storage.deleted_vector_bitslice(),
+            vector_storage.total_vector_count(),
+        );
+        Some(graph.health(&live))
+    }
+
+    /// Build a repaired copy of the graph, without modifying the index itself.
+    ///
+    /// Deleted points are dropped from the graph, points which lost some of their links are
+    /// linked again, the rest of the graph is kept as is. The result is stored in a temporary
+    /// file and should be applied with [`Self::apply_repaired_graph`].
+    pub fn repair_graph(
+        &self,
+        permit: Arc<CpuPermit>,
+        stopped: &AtomicBool,
+    ) -> OperationResult<Option<RepairedGraph<TGraphLinks>>> {
+        let Some(graph) = &self.graph else {
+            return Ok(None);
+        };
+        if self.config.m == 0 {
+            return Ok(None);
+        }
+
+        let id_tracker = self.id_tracker.borrow();
+        let vector_storage = self.vector_storage.borrow();
+        let total_vector_count = vector_storage.total_vector_count();
+        let deleted_bitslice = vector_storage.deleted_vector_bitslice();
+
+        let mut graph_layers_builder = self.new_graph_layers_builder(total_vector_count);
+        let identity: Vec<_> = (0..graph.num_points() as PointOffsetType)
+            .map(Some)
+            .collect();
+        let old_index = OldIndex::from_graph(
+            graph,
+            &self.config,
+            vector_storage.distance(),
+            vector_storage.try_multi_vector_config().copied(),
+            self.quantization_config(),
+            &identity,
+        );
+        let live = live_vectors(&id_tracker, deleted_bitslice, total_vector_count);
+        let (reused, relink) = graph_layers_builder.fill_from_old_index(old_index, &live);
+
+        // Points which are not in the graph yet are linked as well
+        let mut rng = thread_rng();
+        let missing: Vec<_> = id_tracker
+            .iter_ids_excluding(deleted_bitslice)
+            .filter(|&vector_id| !reused.get(vector_id as usize).is_some_and(|r| *r))
+            .collect();
+        for &vector_id in &missing {
+            let level = graph_layers_builder.get_random_layer(&mut rng);
+            graph_layers_builder.set_levels(vector_id, level);
+        }
+        debug!(
+            "repairing HNSW graph: {} points are linked again, {} points are added",
+            relink.len(),
+            missing.len(),
+        );
+
+        let pool = build_thread_pool(&permit)?;
+        let linked_reused_count = reused.count_ones() - relink.len();
+        let indexed_vector_count = linked_reused_count
+            + self.link_points(
+                &graph_layers_builder,
+                relink.into_iter().chain(missing),
+                &pool,
+                stopped,
+            )?;
+
+        let links_path = GraphLayers::<TGraphLinks>::get_links_path(&self.path)
+            .with_extension(REPAIRED_LINKS_EXTENSION);
+        let graph = graph_layers_builder.into_graph_layers(Some(&links_path))?;
+        Ok(Some(RepairedGraph {
+            graph,
+            links_path,
+            indexed_vector_count,
+        }))
+    }
+
+    /// Replace the graph with the one built by [`Self::repair_graph`]
+    pub fn apply_repaired_graph(
+        &mut self,
+        repaired: RepairedGraph<TGraphLinks>,
+    ) -> OperationResult<()> {
+        let RepairedGraph {
+            graph,
+            links_path,
+            indexed_vector_count,
+        } = repaired;
+        std::fs::rename(
+            links_path,
+            GraphLayers::<TGraphLinks>::get_links_path(&self.path),
+        )?;
+        self.graph = Some(graph);
+        self.config
+            .indexed_vector_count
+            .replace(indexed_vector_count);
+        self.save()
+    }
+
     fn save_config(&self) -> OperationResult<()> {
         let config_path = HnswGraphConfig::get_config_path(&self.path);
         self.config.save(&config_path)
@@ -162,6 +361,91 @@ impl<TGraphLinks: GraphLinks> HNSWIndex<TGraphLinks> {
         Ok(())
     }
 
+    fn new_graph_layers_builder(&self, total_vector_count: usize) -> GraphLayersBuilder {
+        GraphLayersBuilder::new(
+            total_vector_count,
+            self.config.m,
+            self.config.m0,
+            self.config.ef_construct,
+            (total_vector_count
+                .checked_div(self.config.full_scan_threshold)
+                .unwrap_or(0)
+                * 10)
+                .max(1),
+            HNSW_USE_HEURISTIC,
+        )
+    }
+
+    /// Scorer for linking a new point into the graph
+    fn construct_build_scorer<'a>(
+        &self,
+        vector: QueryVector,
+        vector_storage: &'a VectorStorageEnum,
+        quantized_storage: Option<&'a QuantizedVectors>,
+        deleted_points: &'a BitSlice,
+        stopped: &'a AtomicBool,
+    ) -> OperationResult<Box<dyn RawScorer + 'a>> {
+        match (quantized_storage, self.config.prefix_size) {
+            (Some(quantized_storage), _) => quantized_storage.raw_scorer(
+                vector,
+                deleted_points,
+                vector_storage.deleted_vector_bitslice(),
+                stopped,
+            ),
+            (None, Some(prefix_size)) => {
+                new_prefix_raw_scorer(vector, vector_storage, prefix_size, deleted_points, stopped)
+            }
+            (None, None) => new_raw_scorer(vector, vector_storage, deleted_points),
+        }
+    }
+
+    /// Insert points into the main graph, returns number of inserted points
+    fn link_points(
+        &self,
+        graph_layers_builder: &GraphLayersBuilder,
+        mut ids_iterator: impl Iterator<Item = PointOffsetType>,
+        pool: &ThreadPool,
+        stopped: &AtomicBool,
+    ) -> OperationResult<usize> {
+        let id_tracker = self.id_tracker.borrow();
+        let vector_storage = self.vector_storage.borrow();
+        let quantized_vectors = self.quantized_vectors.borrow();
+
+        let first_few_ids: Vec<_> = ids_iterator
+            .by_ref()
+            .take(SINGLE_THREADED_HNSW_BUILD_THRESHOLD)
+            .collect();
+        let ids: Vec<_> = ids_iterator.collect();
+        let linked_count = ids.len() + first_few_ids.len();
+
+        let insert_point = |vector_id| {
+            check_process_stopped(stopped)?;
+            let vector = vector_storage.get_vector(vector_id);
+            let vector = vector.as_vec_ref().into();
+            let raw_scorer = self.construct_build_scorer(
+                vector,
+                &vector_storage,
+                quantized_vectors.as_ref(),
+                id_tracker.deleted_point_bitslice(),
+                stopped,
+            )?;
+            let points_scorer = FilteredScorer::new(raw_scorer.as_ref(), None);
+
+            graph_layers_builder.link_new_point(vector_id, points_scorer);
+            Ok::<_, OperationError>(())
+        };
+
+        for vector_id in first_few_ids {
+            insert_point(vector_id)?;
+        }
+
+        if !ids.is_empty() {
+            pool.install(|| ids.into_par_iter().try_for_each(insert_point))?;
+        }
+
+        Ok(linked_count)
+    }
+
     pub fn build_filtered_graph(
         &self,
         pool: &ThreadPool,
@@ -209,17 +493,13 @@ impl<TGraphLinks: GraphLinks> HNSWIndex<TGraphLinks> {
 
             let vector = vector_storage.get_vector(block_point_id);
             let vector = vector.as_vec_ref().into();
-            let raw_scorer = match quantized_vectors.as_ref() {
-                Some(quantized_storage) => quantized_storage.raw_scorer(
-                    vector,
-                    id_tracker.deleted_point_bitslice(),
-                    deleted_bitslice,
-                    stopped,
-                ),
-                None => {
-                    new_raw_scorer(vector, &vector_storage, id_tracker.deleted_point_bitslice())
-                }
-            }?;
+            let raw_scorer = self.construct_build_scorer(
+                vector,
+                &vector_storage,
+                quantized_vectors.as_ref(),
+                id_tracker.deleted_point_bitslice(),
+                stopped,
+            )?;
             let block_condition_checker = BuildConditionChecker {
                 filter_list: block_filter_list,
                 current_point: block_point_id,
@@ -261,6 +541,7 @@ impl<TGraphLinks: GraphLinks> HNSWIndex<TGraphLinks> {
         top: usize,
         params: Option<&SearchParams>,
         custom_entry_points: Option<&[PointOffsetType]>,
+        algorithm: SearchAlgorithm,
         vector_query_context: &VectorQueryContext,
     ) -> OperationResult<Vec<ScoredPointOffset>> {
         let ef = params
@@ -278,24 +559,66 @@ impl<TGraphLinks: GraphLinks> HNSWIndex<TGraphLinks> {
             .deleted_points()
             .unwrap_or(id_tracker.deleted_point_bitslice());
 
-        let raw_scorer = Self::construct_search_scorer(
-            vector,
-            &vector_storage,
-            quantized_vectors.as_ref(),
-            deleted_points,
-            params,
-            &is_stopped,
-        )?;
-        let oversampled_top = Self::get_oversampled_top(quantized_vectors.as_ref(), params, top);
+        // Multivectors are prefetched by their fixed dimensional encodings and rescored after
+        // the graph search
+        let fde_search =
+            Self::is_fde_search(vector, &vector_storage, quantized_vectors.as_ref(), params);
+        let (raw_scorer, oversampled_top) = match vector_storage.fixed_dimensional_encodings() {
+            Some(fixed_dimensional_encodings) if fde_search => (
+                new_fde_raw_scorer(
+                    vector.to_owned(),
+                    &vector_storage,
+                    deleted_points,
+                    &is_stopped,
+                )?,
+                top.saturating_mul(fixed_dimensional_encodings.prefetch_factor()),
+            ),
+            _ => {
+                let raw_scorer = Self::construct_search_scorer(
+                    vector,
+                    &vector_storage,
+                    quantized_vectors.as_ref(),
+                    self.config.prefix_size,
+                    deleted_points,
+                    params,
+                    &is_stopped,
+                )?;
+                let prefix_search = Self::is_prefix_search(
+                    vector,
+                    quantized_vectors.as_ref(),
+                    self.config.prefix_size,
+                    params,
+                );
+                let oversampled_top = Self::get_oversampled_top(
+                    quantized_vectors.as_ref(),
+                    prefix_search,
+                    params,
+                    top,
+                );
+                (raw_scorer, oversampled_top)
+            }
+        };
 
         let filter_context = filter.map(|f| payload_index.filter_context(f));
         let points_scorer = FilteredScorer::new(raw_scorer.as_ref(), filter_context.as_deref());
 
         match &self.graph {
             Some(graph) => {
-                let search_result =
-                    graph.search(oversampled_top, ef, points_scorer, custom_entry_points);
-                self.postprocess_search_result(search_result, vector, params, top, &is_stopped)
+                let search_result = graph.search_with_algorithm(
+                    oversampled_top,
+                    ef,
+                    algorithm,
+                    points_scorer,
+                    custom_entry_points,
+                );
+                self.postprocess_search_result(
+                    search_result,
+                    vector,
+                    params,
+                    top,
+                    fde_search,
+                    &is_stopped,
+                )
             }
             None => Ok(Default::default()),
         }
@@ -307,6 +630,7 @@ impl<TGraphLinks: GraphLinks> HNSWIndex<TGraphLinks> {
         filter: Option<&Filter>,
         top: usize,
         params: Option<&SearchParams>,
+        algorithm: SearchAlgorithm,
         vector_query_context: &VectorQueryContext,
     ) -> OperationResult<Vec<Vec<ScoredPointOffset>>> {
         vectors
@@ -317,11 +641,18 @@ impl<TGraphLinks: GraphLinks> HNSWIndex<TGraphLinks> {
                     filter,
                     top,
                     params,
+                    algorithm,
+                    vector_query_context,
+                ),
+                other => self.search_with_graph(
+                    other,
+                    filter,
+                    top,
+                    params,
+                    None,
+                    algorithm,
                     vector_query_context,
                 ),
-                other => {
-                    self.search_with_graph(other, filter, top, params, None, vector_query_context)
-                }
             })
             .collect()
     }
@@ -348,16 +679,24 @@ impl<TGraphLinks: GraphLinks> HNSWIndex<TGraphLinks> {
             vector,
             &vector_storage,
             quantized_vectors.as_ref(),
+            self.config.prefix_size,
             deleted_points,
             params,
             &is_stopped,
         )?;
-        let oversampled_top = Self::get_oversampled_top(quantized_vectors.as_ref(), params, top);
+        let prefix_search = Self::is_prefix_search(
+            vector,
+            quantized_vectors.as_ref(),
+            self.config.prefix_size,
+            params,
+        );
+        let oversampled_top =
+            Self::get_oversampled_top(quantized_vectors.as_ref(), prefix_search, params, top);
 
         let search_result =
             raw_scorer.peek_top_iter(&mut filtered_points.iter().copied(), oversampled_top);
 
-        self.postprocess_search_result(search_result, vector, params, top, &is_stopped)
+        self.postprocess_search_result(search_result, vector, params, top, false, &is_stopped)
     }
 
     fn search_vectors_plain(
@@ -385,6 +724,7 @@ impl<TGraphLinks: GraphLinks> HNSWIndex<TGraphLinks> {
         filter: Option<&Filter>,
         top: usize,
         params: Option<&SearchParams>,
+        algorithm: SearchAlgorithm,
         vector_query_context: &VectorQueryContext,
     ) -> OperationResult<Vec<ScoredPointOffset>> {
         // Stage 1: Find best entry points using Context search
@@ -399,6 +739,7 @@ impl<TGraphLinks: GraphLinks> HNSWIndex<TGraphLinks> {
                 DISCOVERY_ENTRY_POINT_COUNT,
                 params,
                 None,
+                algorithm,
                 vector_query_context,
             )
             .map(|search_result| search_result.iter().map(|x| x.idx).collect())?;
@@ -412,10 +753,35 @@ impl<TGraphLinks: GraphLinks> HNSWIndex<TGraphLinks> {
             top,
             params,
             Some(&custom_entry_points),
+            algorithm,
             vector_query_context,
         )
     }
 
+    /// Pick graph traversal for a filtered search, based on the expected share of points
+    /// passing the filter
+    fn filtered_search_algorithm(
+        query_cardinality: &CardinalityEstimation,
+        available_vector_count: usize,
+    ) -> SearchAlgorithm {
+        if expected_selectivity(query_cardinality, available_vector_count) < ACORN_MAX_SELECTIVITY {
+            SearchAlgorithm::Acorn
+        } else {
+            SearchAlgorithm::Hnsw
+        }
+    }
+
+    /// Filtered searches expected to select less than this number of vectors score the filtered
+    /// points directly instead of searching the graph
+    fn filtered_full_scan_threshold(&self, algorithm: SearchAlgorithm) -> usize {
+        match algorithm {
+            SearchAlgorithm::Hnsw => self.config.full_scan_threshold,
+            SearchAlgorithm::Acorn => {
+                self.config.full_scan_threshold / ACORN_FULL_SCAN_THRESHOLD_DIVISOR
+            }
+        }
+    }
+
     fn is_quantized_search(
         quantized_storage: Option<&QuantizedVectors>,
         params: Option<&SearchParams>,
@@ -427,20 +793,62 @@ impl<TGraphLinks: GraphLinks> HNSWIndex<TGraphLinks> {
         quantized_storage.is_some() && !ignore_quantization
     }
 
+    /// Whether search scores only a prefix of the vectors, so found points must be rescored
+    /// with full vectors
+    fn is_prefix_search(
+        vector: &QueryVector,
+        quantized_storage: Option<&QuantizedVectors>,
+        prefix_size: Option<usize>,
+        params: Option<&SearchParams>,
+    ) -> bool {
+        if Self::is_quantized_search(quantized_storage, params) {
+            return quantized_storage.is_some_and(|q| q.prefix_size().is_some());
+        }
+        let exact = params.map(|params| params.exact).unwrap_or(false);
+        prefix_size.is_some() && !exact && matches!(vector, QueryVector::Nearest(_))
+    }
+
+    /// Whether graph search scores multivectors by their fixed dimensional encodings, so found
+    /// points must be rescored with the original multivectors
+    fn is_fde_search(
+        vector: &QueryVector,
+        vector_storage: &VectorStorageEnum,
+        quantized_storage: Option<&QuantizedVectors>,
+        params: Option<&SearchParams>,
+    ) -> bool {
+        if Self::is_quantized_search(quantized_storage, params) {
+            return false;
+        }
+        let exact = params.map(|params| params.exact).unwrap_or(false);
+        !exact
+            && matches!(vector, QueryVector::Nearest(Vector::MultiDense(_)))
+            && vector_storage.fixed_dimensional_encodings().is_some()
+    }
+
     fn construct_search_scorer<'a>(
         vector: &QueryVector,
         vector_storage: &'a VectorStorageEnum,
         quantized_storage: Option<&'a QuantizedVectors>,
+        prefix_size: Option<usize>,
         deleted_points: &'a BitSlice,
         params: Option<&SearchParams>,
         is_stopped: &'a AtomicBool,
     ) -> OperationResult<Box<dyn RawScorer + 'a>> {
         let quantization_enabled = Self::is_quantized_search(quantized_storage, params);
-        match quantized_storage {
-            Some(quantized_storage) if quantization_enabled => quantized_storage.raw_scorer(
+        let prefix_search = Self::is_prefix_search(vector, quantized_storage, prefix_size, params);
+        match (quantized_storage, prefix_size) {
+            (Some(quantized_storage), _) if quantization_enabled => quantized_storage
+                .search_raw_scorer(
+                    vector.to_owned(),
+                    deleted_points,
+                    vector_storage.deleted_vector_bitslice(),
+                    is_stopped,
+                ),
+            (_, Some(prefix_size)) if prefix_search => new_prefix_raw_scorer(
                 vector.to_owned(),
+                vector_storage,
+                prefix_size,
                 deleted_points,
-                vector_storage.deleted_vector_bitslice(),
                 is_stopped,
             ),
             _ => new_stoppable_raw_scorer(
@@ -454,6 +862,7 @@ impl<TGraphLinks: GraphLinks> HNSWIndex<TGraphLinks> {
 
     fn get_oversampled_top(
         quantized_storage: Option<&QuantizedVectors>,
+        prefix_search: bool,
         params: Option<&SearchParams>,
         top: usize,
     ) -> usize {
@@ -465,7 +874,7 @@ impl<TGraphLinks: GraphLinks> HNSWIndex<TGraphLinks> {
             .unwrap_or(default_quantization_oversampling_value());
 
         match oversampling_value {
-            Some(oversampling) if quantization_enabled && oversampling > 1.0 => {
+            Some(oversampling) if (quantization_enabled || prefix_search) && oversampling > 1.0 => {
                 (oversampling * top as f64) as usize
             }
             _ => top,
@@ -478,6 +887,7 @@ impl<TGraphLinks: GraphLinks> HNSWIndex<TGraphLinks> {
         vector: &QueryVector,
         params: Option<&SearchParams>,
         top: usize,
+        fde_search: bool,
         is_stopped: &AtomicBool,
     ) -> OperationResult<Vec<ScoredPointOffset>> {
         let id_tracker = self.id_tracker.borrow();
@@ -490,11 +900,25 @@ impl<TGraphLinks: GraphLinks> HNSWIndex<TGraphLinks> {
             .as_ref()
             .map(|q| q.default_rescoring())
             .unwrap_or(false);
-        let rescore = quantization_enabled
-            && params
-                .and_then(|p| p.quantization)
-                .and_then(|q| q.rescore)
-                .unwrap_or(default_rescoring);
+        // Scores of a vector prefix are only an approximation, so they are always rescored
+        let prefix_search = Self::is_prefix_search(
+            vector,
+            quantized_vectors.as_ref(),
+            self.config.prefix_size,
+            params,
+        );
+        let approximate_distance = quantization_enabled
+            && quantized_vectors
+                .as_ref()
+                .is_some_and(|q| q.is_approximate_distance());
+        let rescore = prefix_search
+            || fde_search
+            || approximate_distance
+            || (quantization_enabled
+                && params
+                    .and_then(|p| p.quantization)
+                    .and_then(|q| q.rescore)
+                    .unwrap_or(default_rescoring));
 
         let mut postprocess_result = if rescore {
             let raw_scorer = new_stoppable_raw_scorer(
@@ -574,7 +998,14 @@ impl<TGraphLinks: GraphLinks> VectorIndex for HNSWIndex<TGraphLinks> {
                 } else {
                     let _timer =
                         ScopeDurationMeasurer::new(&self.searches_telemetry.unfiltered_hnsw);
-                    self.search_vectors_with_graph(vectors, None, top, params, query_context)
+                    self.search_vectors_with_graph(
+                        vectors,
+                        None,
+                        top,
+                        params,
+                        SearchAlgorithm::Hnsw,
+                        query_context,
+                    )
                 }
             }
             Some(query_filter) => {
@@ -614,8 +1045,11 @@ impl<TGraphLinks: GraphLinks> VectorIndex for HNSWIndex<TGraphLinks> {
                     available_vector_count,
                     id_tracker.available_point_count(),
                 );
+                let algorithm =
+                    Self::filtered_search_algorithm(&query_cardinality, available_vector_count);
+                let full_scan_threshold = self.filtered_full_scan_threshold(algorithm);
 
-                if query_cardinality.max < self.config.full_scan_threshold {
+                if query_cardinality.max < full_scan_threshold {
                     // if cardinality is small - use plain index
                     let _timer =
                         ScopeDurationMeasurer::new(&self.searches_telemetry.small_cardinality);
@@ -628,7 +1062,7 @@ impl<TGraphLinks: GraphLinks> VectorIndex for HNSWIndex<TGraphLinks> {
                     );
                 }
 
-                if query_cardinality.min > self.config.full_scan_threshold {
+                if query_cardinality.min > full_scan_threshold {
                     // if cardinality is high enough - use HNSW index
                     let _timer =
                         ScopeDurationMeasurer::new(&self.searches_telemetry.large_cardinality);
@@ -637,6 +1071,7 @@ impl<TGraphLinks: GraphLinks> VectorIndex for HNSWIndex<TGraphLinks> {
                         filter,
                         top,
                         params,
+                        algorithm,
                         query_context,
                     );
                 }
@@ -648,13 +1083,20 @@ impl<TGraphLinks: GraphLinks> VectorIndex for HNSWIndex<TGraphLinks> {
                 if sample_check_cardinality(
                     id_tracker.sample_ids(Some(vector_storage.deleted_vector_bitslice())),
                     |idx| filter_context.check(idx),
-                    self.config.full_scan_threshold,
+                    full_scan_threshold,
                     available_vector_count, // Check cardinality among available vectors
                 ) {
                     // if cardinality is high enough - use HNSW index
                     let _timer =
                         ScopeDurationMeasurer::new(&self.searches_telemetry.large_cardinality);
-                    self.search_vectors_with_graph(vectors, filter, top, params, query_context)
+                    self.search_vectors_with_graph(
+                        vectors,
+                        filter,
+                        top,
+                        params,
+                        algorithm,
+                        query_context,
+                    )
                 } else {
                     // if cardinality is small - use plain index
                     let _timer =
@@ -670,11 +1112,65 @@ impl<TGraphLinks: GraphLinks> VectorIndex for HNSWIndex<TGraphLinks> {
         permit: Arc<CpuPermit>,
         stopped: &AtomicBool,
         _tick_progress: impl FnMut(),
+    ) -> OperationResult<()> {
+        self.build_index_with_old_index(permit, stopped, None)
+    }
+
+    fn get_telemetry_data(&self, detail: TelemetryDetail) -> VectorIndexSearchesTelemetry {
+        let tm = &self.searches_telemetry;
+        VectorIndexSearchesTelemetry {
+            index_name: None,
+            unfiltered_plain: tm.unfiltered_plain.lock().get_statistics(detail),
+            filtered_plain: Default::default(),
+            unfiltered_hnsw: tm.unfiltered_hnsw.lock().get_statistics(detail),
+            filtered_small_cardinality: tm.small_cardinality.lock().get_statistics(detail),
+            filtered_large_cardinality: tm.large_cardinality.lock().get_statistics(detail),
+            filtered_exact: tm.exact_filtered.lock().get_statistics(detail),
+            filtered_sparse: Default::default(),
+            unfiltered_exact: tm.exact_unfiltered.lock().get_statistics(detail),
+            unfiltered_sparse: Default::default(),
+            sparse_index: None,
+        }
+    }
+
+    fn files(&self) -> Vec<PathBuf> {
+        if self.graph.is_some() {
+            vec![
+                GraphLayers::<TGraphLinks>::get_path(&self.path),
+                GraphLayers::<TGraphLinks>::get_links_path(&self.path),
+            ]
+        } else {
+            vec![]
+        }
+    }
+
+    fn indexed_vector_count(&self) -> usize {
+        self.config
+            .indexed_vector_count
+            // If indexed vector count is unknown, fall back to number of points
+            .or_else(|| self.graph.as_ref().map(|graph| graph.num_points()))
+            .unwrap_or(0)
+    }
+
+    fn update_vector(&mut self, _id: PointOffsetType, _vector: VectorRef) -> OperationResult<()> {
+        Err(OperationError::service_error("Cannot update HNSW index"))
+    }
+}
+
+impl<TGraphLinks: GraphLinks> HNSWIndex<TGraphLinks> {
+    /// Build the index, reusing the graph of `old_index` if it is compatible with this index.
+    ///
+    /// Points of the old graph keep their levels and links, only the rest of points is
+    /// inserted into the graph.
+    pub fn build_index_with_old_index(
+        &mut self,
+        permit: Arc<CpuPermit>,
+        stopped: &AtomicBool,
+        old_index: Option<OldIndex>,
     ) -> OperationResult<()> {
         // Build main index graph
         let id_tracker = self.id_tracker.borrow();
         let vector_storage = self.vector_storage.borrow();
-        let quantized_vectors = self.quantized_vectors.borrow();
         let mut rng = thread_rng();
 
         let total_vector_count = vector_storage.total_vector_count();
@@ -685,46 +1181,46 @@ impl<TGraphLinks: GraphLinks> VectorIndex for HNSWIndex<TGraphLinks> {
             permit.num_cpus,
         );
         let indexing_threshold = self.config.full_scan_threshold;
-        let mut graph_layers_builder = GraphLayersBuilder::new(
-            total_vector_count,
-            self.config.m,
-            self.config.m0,
-            self.config.ef_construct,
-            (total_vector_count
-                .checked_div(indexing_threshold)
-                .unwrap_or(0)
-                * 10)
-                .max(1),
-            HNSW_USE_HEURISTIC,
-        );
-
-        let pool = rayon::ThreadPoolBuilder::new()
-            .thread_name(|idx| format!("hnsw-build-{idx}"))
-            .num_threads(permit.num_cpus as usize)
-            .spawn_handler(|thread| {
-                let mut b = thread::Builder::new();
-                if let Some(name) = thread.name() {
-                    b = b.name(name.to_owned());
-                }
-                if let Some(stack_size) = thread.stack_size() {
-                    b = b.stack_size(stack_size);
-                }
-                b.spawn(|| {
-                    // On Linux, use lower thread priority so we interfere less with serving traffic
-                    #[cfg(target_os = "linux")]
-                    if let Err(err) = linux_low_thread_priority() {
-                        log::debug!(
-                            "Failed to set low thread priority for HNSW building, ignoring: {err}"
-                        );
-                    }
-
-                    thread.run()
-                })?;
-                Ok(())
-            })
-            .build()?;
+        let mut graph_layers_builder = self.new_graph_layers_builder(total_vector_count);
+
+        let pool = build_thread_pool(&permit)?;
+
+        // Points of the old graph keep their links, points which lost some of them are linked again
+        let (reused, relink) = match old_index {
+            Some(old_index)
+                if self.config.m > 0
+                    && old_index.is_compatible(
+                        &self.config,
+                        vector_storage.distance(),
+                        vector_storage.try_multi_vector_config(),
+                        self.quantization_config().as_ref(),
+                    ) =>
+            {
+                let live = live_vectors(&id_tracker, deleted_bitslice, total_vector_count);
+                let (reused, relink) = graph_layers_builder.fill_from_old_index(old_index, &live);
+                debug!(
+                    "reusing {} points of old HNSW graph, {} of them are linked again",
+                    reused.count_ones(),
+                    relink.len(),
+                );
+                (reused, relink)
+            }
+            Some(_) => {
+                debug!("old HNSW graph is not compatible with the new index, not reusing it");
+                (BitVec::repeat(false, total_vector_count), Vec::new())
+            }
+            None => (BitVec::repeat(false, total_vector_count), Vec::new()),
+        };
+        let is_reused = |vector_id: PointOffsetType| {
+            reused
+                .get(vector_id as usize)
+                .is_some_and(|is_reused| *is_reused)
+        };
 
-        for vector_id in id_tracker.iter_ids_excluding(deleted_bitslice) {
+        for vector_id in id_tracker
+            .iter_ids_excluding(deleted_bitslice)
+            .filter(|&vector_id| !is_reused(vector_id))
+        {
             check_process_stopped(stopped)?;
             let level = graph_layers_builder.get_random_layer(&mut rng);
             graph_layers_builder.set_levels(vector_id, level);
@@ -733,43 +1229,14 @@ impl<TGraphLinks: GraphLinks> VectorIndex for HNSWIndex<TGraphLinks> {
         let mut indexed_vectors = 0;
 
         if self.config.m > 0 {
-            let mut ids_iterator = id_tracker.iter_ids_excluding(deleted_bitslice);
-
-            let first_few_ids: Vec<_> = ids_iterator
-                .by_ref()
-                .take(SINGLE_THREADED_HNSW_BUILD_THRESHOLD)
-                .collect();
-            let ids: Vec<_> = ids_iterator.collect();
-
-            indexed_vectors = ids.len() + first_few_ids.len();
-
-            let insert_point = |vector_id| {
-                check_process_stopped(stopped)?;
-                let vector = vector_storage.get_vector(vector_id);
-                let vector = vector.as_vec_ref().into();
-                let raw_scorer = if let Some(quantized_storage) = quantized_vectors.as_ref() {
-                    quantized_storage.raw_scorer(
-                        vector,
-                        id_tracker.deleted_point_bitslice(),
-                        vector_storage.deleted_vector_bitslice(),
-                        stopped,
-                    )
-                } else {
-                    new_raw_scorer(vector, &vector_storage, id_tracker.deleted_point_bitslice())
-                }?;
-                let points_scorer = FilteredScorer::new(raw_scorer.as_ref(), None);
+            let linked_reused_count = reused.count_ones() - relink.len();
+            let ids_iterator = id_tracker
+                .iter_ids_excluding(deleted_bitslice)
+                .filter(|&vector_id| !is_reused(vector_id))
+                .chain(relink);
 
-                graph_layers_builder.link_new_point(vector_id, points_scorer);
-                Ok::<_, OperationError>(())
-            };
-
-            for vector_id in first_few_ids {
-                insert_point(vector_id)?;
-            }
-
-            if !ids.is_empty() {
-                pool.install(|| ids.into_par_iter().try_for_each(insert_point))?;
-            }
+            indexed_vectors = linked_reused_count
+                + self.link_points(&graph_layers_builder, ids_iterator, &pool, stopped)?;
 
             debug!("finish main graph");
         } else {
@@ -790,7 +1257,7 @@ impl<TGraphLinks: GraphLinks> VectorIndex for HNSWIndex<TGraphLinks> {
                 graph_layers_builder.get_average_connectivity_on_level(0);
             let average_links_per_0_level_int = (average_links_per_0_level as usize).max(1);
 
-            for (field, _) in payload_index.indexed_fields() {
+            for (field, payload_schema) in payload_index.indexed_fields() {
                 debug!("building additional index for field {}", &field);
 
                 // It is expected, that graph will become disconnected less than
@@ -798,7 +1265,12 @@ impl<TGraphLinks: GraphLinks> VectorIndex for HNSWIndex<TGraphLinks> {
                 // So blocks larger than $1/m$ are not needed.
                 // We add multiplier for the extra safety.
                 let percolation_multiplier = 4;
-                let max_block_size = if self.config.m > 0 {
+                let max_block_size = if payload_schema.is_tenant() {
+                    // Searches are expected to always be scoped to a single tenant,
+                    // so large tenants get their own sub-graph however large they are.
+                    // Tenants below `min_block_size` are served by full scan instead.
+                    usize::MAX
+                } else if self.config.m > 0 {
                     total_vector_count / average_links_per_0_level_int * percolation_multiplier
                 } else {
                     usize::MAX
@@ -850,7 +1322,7 @@ impl<TGraphLinks: GraphLinks> VectorIndex for HNSWIndex<TGraphLinks> {
             let graph = self.graph.as_ref().unwrap();
             for (idx, deleted) in deleted_bitslice.iter().enumerate() {
                 if *deleted {
-                    debug_assert!(graph.links.links(idx as PointOffsetType, 0).is_empty());
+                    debug_assert_eq!(graph.links.links(idx as PointOffsetType, 0).len(), 0);
                 }
             }
         }
@@ -858,43 +1330,4 @@ impl<TGraphLinks: GraphLinks> VectorIndex for HNSWIndex<TGraphLinks> {
         debug!("finish additional payload field indexing");
         self.save()
     }
-
-    fn get_telemetry_data(&self, detail: TelemetryDetail) -> VectorIndexSearchesTelemetry {
-        let tm = &self.searches_telemetry;
-        VectorIndexSearchesTelemetry {
-            index_name: None,
-            unfiltered_plain: tm.unfiltered_plain.lock().get_statistics(detail),
-            filtered_plain: Default::default(),
-            unfiltered_hnsw: tm.unfiltered_hnsw.lock().get_statistics(detail),
-            filtered_small_cardinality: tm.small_cardinality.lock().get_statistics(detail),
-            filtered_large_cardinality: tm.large_cardinality.lock().get_statistics(detail),
-            filtered_exact: tm.exact_filtered.lock().get_statistics(detail),
-            filtered_sparse: Default::default(),
-            unfiltered_exact: tm.exact_unfiltered.lock().get_statistics(detail),
-            unfiltered_sparse: Default::default(),
-        }
-    }
-
-    fn files(&self) -> Vec<PathBuf> {
-        if self.graph.is_some() {
-            vec![
-                GraphLayers::<TGraphLinks>::get_path(&self.path),
-                GraphLayers::<TGraphLinks>::get_links_path(&self.path),
-            ]
-        } else {
-            vec![]
-        }
-    }
-
-    fn indexed_vector_count(&self) -> usize {
-        self.config
-            .indexed_vector_count
-            // If indexed vector count is unknown, fall back to number of points
-            .or_else(|| self.graph.as_ref().map(|graph| graph.num_points()))
-            .unwrap_or(0)
-    }
-
-    fn update_vector(&mut self, _id: PointOffsetType, _vector: VectorRef) -> OperationResult<()> {
-        Err(OperationError::service_error("Cannot update HNSW index"))
-    }
 }
@@ -0,0 +1,104 @@
+use common::types::PointOffsetType;
+
+use crate::index::hnsw_index::config::HnswGraphConfig;
+use crate::index::hnsw_index::graph_layers::GraphLayers;
+use crate::index::hnsw_index::graph_links::GraphLinks;
+use crate::types::{Distance, MultiVectorConfig, QuantizationConfig};
+
+/// HNSW graph of a previously built index, which is reused as a starting point
+/// when building the index of a new segment out of the old one.
+///
+/// Links are stored in internal ids of the new segment.
+pub struct OldIndex {
+    m: usize,
+    m0: usize,
+    ef_construct: usize,
+    /// Graph built over a prefix of dimensions links points by prefix scores
+    prefix_size: Option<usize>,
+    distance: Distance,
+    /// Multivector points are linked by scores of the comparator
+    multivector_config: Option<MultiVectorConfig>,
+    /// Graph built with quantized vectors links points by quantized scores
+    quantization_config: Option<QuantizationConfig>,
+    pub(super) points: Vec<OldIndexPoint>,
+}
+
+pub(super) struct OldIndexPoint {
+    /// Internal id in the new segment
+    pub point_id: PointOffsetType,
+    /// Links of the point on each level
+    pub links: Vec<Vec<PointOffsetType>>,
+    /// Some of the links pointed to points which are not present in the new segment
+    pub lost_links: bool,
+}
+
+impl OldIndex {
+    /// Extract links of the graph, mapping internal ids with `old_to_new`.
+    ///
+    /// Points without mapping are dropped, as well as links pointing to them.
+    pub(super) fn from_graph<TGraphLinks: GraphLinks>(
+        graph: &GraphLayers<TGraphLinks>,
+        config: &HnswGraphConfig,
+        distance: Distance,
+        multivector_config: Option<MultiVectorConfig>,
+        quantization_config: Option<QuantizationConfig>,
+        old_to_new: &[Option<PointOffsetType>],
+    ) -> Self {
+        let links = &graph.links;
+        let map = |old_id: PointOffsetType| old_to_new.get(old_id as usize).copied().flatten();
+
+        let points = (0..links.num_points() as PointOffsetType)
+            .filter_map(|old_id| {
+                let point_id = map(old_id)?;
+                let mut lost_links = false;
+                let point_links = (0..=links.point_level(old_id))
+                    .map(|level| {
+                        let level_links = links.links(old_id, level);
+                        let total = level_links.len();
+                        let mapped: Vec<_> = level_links.filter_map(map).collect();
+                        lost_links |= mapped.len() < total;
+                        mapped
+                    })
+                    .collect();
+                Some(OldIndexPoint {
+                    point_id,
+                    links: point_links,
+                    lost_links,
+                })
+            })
+            .collect();
+
+        Self {
+            m: config.m,
+            m0: config.m0,
+            ef_construct: config.ef_construct,
+            prefix_size: config.prefix_size,
+            distance,
+            multivector_config,
+            quantization_config,
+            points,
+        }
+    }
+
+    /// Number of points which could be reused
+    pub fn num_points(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Old graph can only be reused if it was built with the same parameters and scores
+    pub(super) fn is_compatible(
+        &self,
+        config: &HnswGraphConfig,
+        distance: Distance,
+        multivector_config: Option<&MultiVectorConfig>,
+        quantization_config: Option<&QuantizationConfig>,
+    ) -> bool {
+        self.m == config.m
+            && self.m0 == config.m0
+            && self.ef_construct == config.ef_construct
+            && self.prefix_size == config.prefix_size
+            && self.distance == distance
+            && self.multivector_config.as_ref() == multivector_config
+            && self.quantization_config.as_ref() == quantization_config
+    }
+}
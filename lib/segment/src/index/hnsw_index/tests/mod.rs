@@ -1,5 +1,8 @@
+mod test_acorn_search;
 mod test_compact_graph_layer;
 mod test_graph_connectivity;
+mod test_graph_reuse;
+mod test_prefix_search;
 
 use std::path::Path;
 
@@ -0,0 +1,82 @@
+use common::types::PointOffsetType;
+use itertools::Itertools;
+use rand::prelude::StdRng;
+use rand::SeedableRng;
+
+use crate::fixtures::index_fixtures::random_vector;
+use crate::index::hnsw_index::graph_layers::SearchAlgorithm;
+use crate::index::hnsw_index::point_scorer::FilteredScorer;
+use crate::index::hnsw_index::tests::create_graph_layer_fixture;
+use crate::payload_storage::FilterContext;
+use crate::spaces::simple::DotProductMetric;
+
+/// Selects every `n`-th point
+struct ModuloFilterContext {
+    n: PointOffsetType,
+}
+
+impl FilterContext for ModuloFilterContext {
+    fn check(&self, point_id: PointOffsetType) -> bool {
+        point_id % self.n == 0
+    }
+}
+
+#[test]
+fn test_acorn_search_with_restrictive_filter() {
+    let num_vectors = 5_000;
+    let num_queries = 50;
+    let m = 8;
+    let dim = 16;
+    let top = 10;
+    let ef = 32;
+
+    let mut rng = StdRng::seed_from_u64(42);
+
+    let (vector_holder, graph_layers) = create_graph_layer_fixture::<DotProductMetric, _>(
+        num_vectors,
+        m,
+        dim,
+        true,
+        &mut rng,
+        None,
+    );
+
+    // Only 5% of points pass the filter
+    let filter_context = ModuloFilterContext { n: 20 };
+    let filtered_ids = (0..num_vectors as PointOffsetType)
+        .filter(|&point_id| filter_context.check(point_id))
+        .collect_vec();
+
+    let mut hnsw_hits = 0;
+    let mut acorn_hits = 0;
+    for _ in 0..num_queries {
+        let query = random_vector(&mut rng, dim);
+        let raw_scorer = vector_holder.get_raw_scorer(query).unwrap();
+
+        let exact = raw_scorer.peek_top_iter(&mut filtered_ids.iter().copied(), top);
+        let exact_ids = exact.iter().map(|point| point.idx).collect_vec();
+
+        let search = |algorithm| {
+            let scorer = FilteredScorer::new(raw_scorer.as_ref(), Some(&filter_context));
+            let result = graph_layers.search_with_algorithm(top, ef, algorithm, scorer, None);
+            assert!(result.iter().all(|point| filter_context.check(point.idx)));
+            result
+                .iter()
+                .filter(|point| exact_ids.contains(&point.idx))
+                .count()
+        };
+
+        hnsw_hits += search(SearchAlgorithm::Hnsw);
+        acorn_hits += search(SearchAlgorithm::Acorn);
+    }
+
+    let total = num_queries * top;
+    assert!(
+        acorn_hits >= hnsw_hits,
+        "acorn: {acorn_hits}, hnsw: {hnsw_hits} of {total}",
+    );
+    assert!(
+        acorn_hits >= total * 8 / 10,
+        "acorn: {acorn_hits}, hnsw: {hnsw_hits} of {total}",
+    );
+}
@@ -47,6 +47,7 @@ fn test_graph_connectivity() {
                 quantization_config: None,
                 multivec_config: None,
                 datatype: None,
+                prefix_size: None,
             },
         )]),
         payload_storage_type: Default::default(),
@@ -86,6 +87,7 @@ fn test_graph_connectivity() {
         Default::default(),
         payload_index_ptr.clone(),
         hnsw_config,
+        None,
     )
     .unwrap();
 
@@ -98,7 +100,7 @@ fn test_graph_connectivity() {
     for point_id in 0..num_vectors {
         let links = graph.links.links(point_id as PointOffsetType, 0);
         for link in links {
-            reverse_links[*link as usize].push(point_id);
+            reverse_links[link as usize].push(point_id);
         }
     }
 
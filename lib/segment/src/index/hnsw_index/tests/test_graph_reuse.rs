@@ -0,0 +1,332 @@
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+
+use common::cpu::CpuPermit;
+use common::types::PointOffsetType;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use tempfile::Builder;
+
+use crate::data_types::vectors::{only_default_vector, QueryVector, DEFAULT_VECTOR_NAME};
+use crate::entry::entry_point::SegmentEntry;
+use crate::fixtures::index_fixtures::random_vector;
+use crate::index::hnsw_index::config::HnswGraphConfig;
+use crate::index::hnsw_index::graph_links::{GraphLinks, GraphLinksRam};
+use crate::index::hnsw_index::hnsw::HNSWIndex;
+use crate::index::VectorIndex;
+use crate::segment::Segment;
+use crate::segment_constructor::build_segment;
+use crate::types::{
+    Distance, FdeConfig, HnswConfig, Indexes, MultiVectorComparator, MultiVectorConfig,
+    QuantizationConfig, ScalarQuantizationConfig, ScalarType, SegmentConfig, SeqNumberType,
+    VectorDataConfig, VectorStorageType,
+};
+
+fn open_index(path: &Path, segment: &Segment, hnsw_config: HnswConfig) -> HNSWIndex<GraphLinksRam> {
+    HNSWIndex::<GraphLinksRam>::open(
+        path,
+        segment.id_tracker.clone(),
+        segment.vector_data[DEFAULT_VECTOR_NAME]
+            .vector_storage
+            .clone(),
+        Default::default(),
+        segment.payload_index.clone(),
+        hnsw_config,
+        None,
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_graph_reuse() {
+    let stopped = AtomicBool::new(false);
+
+    let dim = 32;
+    let num_vectors: u64 = 1_000;
+    let num_deleted: u64 = 100;
+    let num_added: u64 = 200;
+    let top = 10;
+
+    let mut rnd = StdRng::seed_from_u64(42);
+
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let old_hnsw_dir = Builder::new().prefix("old_hnsw_dir").tempdir().unwrap();
+    let new_hnsw_dir = Builder::new().prefix("new_hnsw_dir").tempdir().unwrap();
+
+    let config = SegmentConfig {
+        vector_data: HashMap::from([(
+            DEFAULT_VECTOR_NAME.to_owned(),
+            VectorDataConfig {
+                size: dim,
+                distance: Distance::Cosine,
+                storage_type: VectorStorageType::Memory,
+                index: Indexes::Plain {},
+                quantization_config: None,
+                multivec_config: None,
+                datatype: None,
+                prefix_size: None,
+            },
+        )]),
+        payload_storage_type: Default::default(),
+        sparse_vector_data: Default::default(),
+    };
+
+    let mut segment = build_segment(dir.path(), &config, true).unwrap();
+    for n in 0..num_vectors {
+        let vector = random_vector(&mut rnd, dim);
+        segment
+            .upsert_point(n as SeqNumberType, n.into(), only_default_vector(&vector))
+            .unwrap();
+    }
+
+    let hnsw_config = HnswConfig {
+        m: 16,
+        ef_construct: 100,
+        full_scan_threshold: 10,
+        max_indexing_threads: 2,
+        on_disk: Some(false),
+        payload_m: None,
+    };
+
+    let mut old_index = open_index(old_hnsw_dir.path(), &segment, hnsw_config.clone());
+    old_index
+        .build_index(Arc::new(CpuPermit::dummy(2)), &stopped)
+        .unwrap();
+
+    // Some points of the old graph are gone, some new points are added
+    let version = num_vectors + num_added;
+    for n in 0..num_deleted {
+        segment.delete_point(version, n.into()).unwrap();
+    }
+    for n in num_vectors..num_vectors + num_added {
+        let vector = random_vector(&mut rnd, dim);
+        segment
+            .upsert_point(version, n.into(), only_default_vector(&vector))
+            .unwrap();
+    }
+
+    let old_to_new: Vec<_> = (0..num_vectors as PointOffsetType).map(Some).collect();
+    let reused_graph = old_index.old_index(&old_to_new).unwrap();
+    assert_eq!(reused_graph.num_points(), num_vectors as usize);
+
+    // Graph links points by scores, it can't be reused with other scores
+    let graph_config = HnswGraphConfig::new(
+        hnsw_config.m,
+        hnsw_config.ef_construct,
+        hnsw_config.full_scan_threshold,
+        hnsw_config.max_indexing_threads,
+        hnsw_config.payload_m,
+        0,
+        None,
+    );
+    assert!(reused_graph.is_compatible(&graph_config, Distance::Cosine, None, None));
+    assert!(!reused_graph.is_compatible(&graph_config, Distance::Dot, None, None));
+    let prefix_graph_config = HnswGraphConfig {
+        prefix_size: Some(dim / 2),
+        ..graph_config
+    };
+    assert!(!reused_graph.is_compatible(&prefix_graph_config, Distance::Cosine, None, None));
+    let multivector_config = MultiVectorConfig {
+        comparator: MultiVectorComparator::MaxSim,
+        fde: Some(FdeConfig::default()),
+    };
+    assert!(!reused_graph.is_compatible(
+        &graph_config,
+        Distance::Cosine,
+        Some(&multivector_config),
+        None,
+    ));
+    let quantization_config: QuantizationConfig = ScalarQuantizationConfig {
+        r#type: ScalarType::Int8,
+        quantile: None,
+        always_ram: None,
+    }
+    .into();
+    assert!(!reused_graph.is_compatible(
+        &graph_config,
+        Distance::Cosine,
+        None,
+        Some(&quantization_config),
+    ));
+
+    let mut new_index = open_index(new_hnsw_dir.path(), &segment, hnsw_config);
+    new_index
+        .build_index_with_old_index(Arc::new(CpuPermit::dummy(2)), &stopped, Some(reused_graph))
+        .unwrap();
+    assert_eq!(
+        new_index.indexed_vector_count(),
+        (num_vectors - num_deleted + num_added) as usize,
+    );
+
+    // Deleted points are dropped, all the rest is reachable
+    let graph = new_index.graph().unwrap();
+    let mut has_inbound_links = HashSet::new();
+    for point_id in 0..(num_vectors + num_added) as PointOffsetType {
+        let links: Vec<_> = graph.links.links(point_id, 0).collect();
+        if (point_id as u64) < num_deleted {
+            assert!(links.is_empty(), "Deleted point {point_id} has links");
+        }
+        assert!(links.iter().all(|&link| link as u64 >= num_deleted));
+        has_inbound_links.extend(links);
+        for level in 0..=graph.links.point_level(point_id) {
+            assert!(
+                graph
+                    .links
+                    .links(point_id, level)
+                    .all(|link| link != point_id),
+                "Point {point_id} links to itself on level {level}",
+            );
+        }
+    }
+    for point_id in num_deleted..num_vectors + num_added {
+        assert!(
+            has_inbound_links.contains(&(point_id as PointOffsetType)),
+            "Point {point_id} has no inbound links",
+        );
+    }
+
+    let attempts = 20;
+    let mut sames = 0;
+    for _ in 0..attempts {
+        let query: QueryVector = random_vector(&mut rnd, dim).into();
+        let exact = segment.vector_data[DEFAULT_VECTOR_NAME]
+            .vector_index
+            .borrow()
+            .search(&[&query], None, top, None, &Default::default())
+            .unwrap();
+        let approximate = new_index
+            .search(&[&query], None, top, None, &Default::default())
+            .unwrap();
+        let exact_ids: HashSet<_> = exact[0].iter().map(|point| point.idx).collect();
+        sames += approximate[0]
+            .iter()
+            .filter(|point| exact_ids.contains(&point.idx))
+            .count();
+    }
+    assert!(
+        sames >= attempts * top * 9 / 10,
+        "sames: {sames} of {}",
+        attempts * top,
+    );
+}
+
+#[test]
+fn test_graph_repair() {
+    let stopped = AtomicBool::new(false);
+
+    let dim = 16;
+    let num_vectors: u64 = 2_000;
+    let top = 10;
+
+    let mut rnd = StdRng::seed_from_u64(42);
+
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let hnsw_dir = Builder::new().prefix("hnsw_dir").tempdir().unwrap();
+
+    let config = SegmentConfig {
+        vector_data: HashMap::from([(
+            DEFAULT_VECTOR_NAME.to_owned(),
+            VectorDataConfig {
+                size: dim,
+                distance: Distance::Dot,
+                storage_type: VectorStorageType::Memory,
+                index: Indexes::Plain {},
+                quantization_config: None,
+                multivec_config: None,
+                datatype: None,
+                prefix_size: None,
+            },
+        )]),
+        payload_storage_type: Default::default(),
+        sparse_vector_data: Default::default(),
+    };
+
+    let mut segment = build_segment(dir.path(), &config, true).unwrap();
+    for n in 0..num_vectors {
+        let vector = random_vector(&mut rnd, dim);
+        segment
+            .upsert_point(n as SeqNumberType, n.into(), only_default_vector(&vector))
+            .unwrap();
+    }
+
+    let hnsw_config = HnswConfig {
+        m: 8,
+        ef_construct: 64,
+        full_scan_threshold: 10,
+        max_indexing_threads: 2,
+        on_disk: Some(false),
+        payload_m: None,
+    };
+
+    let mut hnsw_index = open_index(hnsw_dir.path(), &segment, hnsw_config.clone());
+    hnsw_index
+        .build_index(Arc::new(CpuPermit::dummy(2)), &stopped)
+        .unwrap();
+
+    let health = hnsw_index.graph_health().unwrap();
+    assert_eq!(health.live_points, num_vectors as usize);
+    assert_eq!(health.deleted_links, 0);
+
+    // Delete every second point
+    for n in (0..num_vectors).step_by(2) {
+        segment.delete_point(num_vectors, n.into()).unwrap();
+    }
+
+    let health = hnsw_index.graph_health().unwrap();
+    assert_eq!(health.live_points, num_vectors as usize / 2);
+    assert!(health.deleted_links_ratio() > 0.3, "{health:?}");
+
+    let repaired = hnsw_index
+        .repair_graph(Arc::new(CpuPermit::dummy(2)), &stopped)
+        .unwrap()
+        .unwrap();
+    hnsw_index.apply_repaired_graph(repaired).unwrap();
+
+    let health = hnsw_index.graph_health().unwrap();
+    assert_eq!(health.deleted_links, 0);
+    assert_eq!(health.reachable_points, health.live_points);
+    assert_eq!(hnsw_index.indexed_vector_count(), num_vectors as usize / 2);
+    let graph = hnsw_index.graph().unwrap();
+    for point_id in 0..num_vectors as PointOffsetType {
+        for level in 0..=graph.links.point_level(point_id) {
+            assert!(
+                graph
+                    .links
+                    .links(point_id, level)
+                    .all(|link| link != point_id),
+                "Point {point_id} links to itself on level {level}",
+            );
+        }
+    }
+
+    // Repaired graph is persisted
+    drop(hnsw_index);
+    let hnsw_index = open_index(hnsw_dir.path(), &segment, hnsw_config);
+    assert_eq!(hnsw_index.graph_health().unwrap(), health);
+
+    let attempts = 20;
+    let mut sames = 0;
+    for _ in 0..attempts {
+        let query: QueryVector = random_vector(&mut rnd, dim).into();
+        let exact = segment.vector_data[DEFAULT_VECTOR_NAME]
+            .vector_index
+            .borrow()
+            .search(&[&query], None, top, None, &Default::default())
+            .unwrap();
+        let approximate = hnsw_index
+            .search(&[&query], None, top, None, &Default::default())
+            .unwrap();
+        let exact_ids: HashSet<_> = exact[0].iter().map(|point| point.idx).collect();
+        sames += approximate[0]
+            .iter()
+            .filter(|point| exact_ids.contains(&point.idx))
+            .count();
+    }
+    assert!(
+        sames >= attempts * top * 9 / 10,
+        "sames: {sames} of {}",
+        attempts * top,
+    );
+}
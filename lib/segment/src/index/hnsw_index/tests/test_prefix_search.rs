@@ -0,0 +1,141 @@
+use std::collections::HashMap;
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+
+use common::cpu::CpuPermit;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use tempfile::Builder;
+
+use crate::data_types::vectors::{
+    only_default_vector, DenseVector, QueryVector, DEFAULT_VECTOR_NAME,
+};
+use crate::entry::entry_point::SegmentEntry;
+use crate::index::hnsw_index::graph_links::GraphLinksRam;
+use crate::index::hnsw_index::hnsw::HNSWIndex;
+use crate::index::VectorIndex;
+use crate::segment_constructor::build_segment;
+use crate::types::{
+    Distance, HnswConfig, Indexes, QuantizationSearchParams, SearchParams, SegmentConfig,
+    SeqNumberType, VectorDataConfig, VectorStorageType,
+};
+
+/// Vector, which is mostly defined by its first `prefix_size` dimensions
+fn matryoshka_vector(rnd: &mut StdRng, dim: usize, prefix_size: usize) -> DenseVector {
+    (0..dim)
+        .map(|i| {
+            if i < prefix_size {
+                rnd.gen_range(-1.0..1.0)
+            } else {
+                rnd.gen_range(-0.1..0.1)
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn test_prefix_search() {
+    let stopped = AtomicBool::new(false);
+
+    let dim = 64;
+    let prefix_size = 16;
+    let num_vectors: u64 = 1_000;
+    let top = 10;
+
+    let mut rnd = StdRng::seed_from_u64(42);
+
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let hnsw_dir = Builder::new().prefix("hnsw_dir").tempdir().unwrap();
+
+    let config = SegmentConfig {
+        vector_data: HashMap::from([(
+            DEFAULT_VECTOR_NAME.to_owned(),
+            VectorDataConfig {
+                size: dim,
+                distance: Distance::Cosine,
+                storage_type: VectorStorageType::Memory,
+                index: Indexes::Plain {},
+                quantization_config: None,
+                multivec_config: None,
+                datatype: None,
+                prefix_size: None,
+            },
+        )]),
+        payload_storage_type: Default::default(),
+        sparse_vector_data: Default::default(),
+    };
+
+    let mut segment = build_segment(dir.path(), &config, true).unwrap();
+    for n in 0..num_vectors {
+        let vector = matryoshka_vector(&mut rnd, dim, prefix_size);
+        segment
+            .upsert_point(n as SeqNumberType, n.into(), only_default_vector(&vector))
+            .unwrap();
+    }
+
+    let hnsw_config = HnswConfig {
+        m: 16,
+        ef_construct: 100,
+        full_scan_threshold: 10,
+        max_indexing_threads: 2,
+        on_disk: Some(false),
+        payload_m: None,
+    };
+
+    let mut hnsw_index = HNSWIndex::<GraphLinksRam>::open(
+        hnsw_dir.path(),
+        segment.id_tracker.clone(),
+        segment.vector_data[DEFAULT_VECTOR_NAME]
+            .vector_storage
+            .clone(),
+        Default::default(),
+        segment.payload_index.clone(),
+        hnsw_config,
+        Some(prefix_size),
+    )
+    .unwrap();
+    hnsw_index
+        .build_index(Arc::new(CpuPermit::dummy(2)), &stopped)
+        .unwrap();
+
+    let params = SearchParams {
+        quantization: Some(QuantizationSearchParams {
+            ignore: false,
+            rescore: None,
+            oversampling: Some(2.0),
+        }),
+        ..Default::default()
+    };
+
+    let attempts = 20;
+    let mut sames = 0;
+    for _ in 0..attempts {
+        let query: QueryVector = matryoshka_vector(&mut rnd, dim, prefix_size).into();
+        let exact = segment.vector_data[DEFAULT_VECTOR_NAME]
+            .vector_index
+            .borrow()
+            .search(&[&query], None, top, None, &Default::default())
+            .unwrap();
+        let approximate = hnsw_index
+            .search(&[&query], None, top, Some(&params), &Default::default())
+            .unwrap();
+        assert_eq!(approximate[0].len(), top);
+
+        // Found points are rescored with full vectors
+        let exact_scores: HashMap<_, _> = exact[0]
+            .iter()
+            .map(|point| (point.idx, point.score))
+            .collect();
+        for point in &approximate[0] {
+            if let Some(exact_score) = exact_scores.get(&point.idx) {
+                assert!((exact_score - point.score).abs() < 1e-5);
+                sames += 1;
+            }
+        }
+    }
+    assert!(
+        sames >= attempts * top * 8 / 10,
+        "sames: {sames} of {}",
+        attempts * top,
+    );
+}
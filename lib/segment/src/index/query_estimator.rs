@@ -74,6 +74,17 @@ pub fn adjust_to_available_vectors(
     }
 }
 
+/// Expected fraction of available vectors selected by the filter
+///
+/// Used to pick the graph traversal strategy: the lower the fraction, the more neighbours of
+/// each visited point are filtered out.
+pub fn expected_selectivity(estimation: &CardinalityEstimation, available_vectors: usize) -> f64 {
+    if available_vectors == 0 {
+        return 0.0;
+    }
+    (estimation.exp as f64 / available_vectors as f64).min(1.0)
+}
+
 pub fn combine_should_estimations(
     estimations: &[CardinalityEstimation],
     total: usize,
@@ -349,6 +360,7 @@ mod tests {
                 exp: TOTAL / 2,
                 max: TOTAL,
             },
+            Condition::Compare(_) => CardinalityEstimation::unknown(TOTAL),
         }
     }
 
@@ -580,4 +592,17 @@ mod tests {
         assert_eq!(new_estimation.exp, 16);
         assert_eq!(new_estimation.max, 50);
     }
+
+    #[test]
+    fn test_expected_selectivity() {
+        let estimation = CardinalityEstimation {
+            primary_clauses: vec![],
+            min: 100,
+            exp: 250,
+            max: 400,
+        };
+        assert_eq!(expected_selectivity(&estimation, TOTAL), 0.25);
+        assert_eq!(expected_selectivity(&estimation, 200), 1.0);
+        assert_eq!(expected_selectivity(&estimation, 0), 0.0);
+    }
 }
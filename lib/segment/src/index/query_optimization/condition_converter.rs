@@ -10,8 +10,8 @@ use crate::index::query_optimization::optimized_filter::ConditionCheckerFn;
 use crate::index::query_optimization::payload_provider::PayloadProvider;
 use crate::payload_storage::condition_checker::INDEXSET_ITER_THRESHOLD;
 use crate::payload_storage::query_checker::{
-    check_field_condition, check_is_empty_condition, check_is_null_condition, check_payload,
-    select_nested_indexes,
+    check_field_condition, check_fields_comparison_condition, check_is_empty_condition,
+    check_is_null_condition, check_payload, select_nested_indexes,
 };
 use crate::types::{
     AnyVariants, Condition, DateTimePayloadType, FieldCondition, FloatPayloadType, GeoBoundingBox,
@@ -64,6 +64,12 @@ pub fn condition_converter<'a>(
                 check_is_null_condition(is_null, &payload)
             })
         }),
+        // Values of two fields are only available together in the payload, so no index is used
+        Condition::Compare(compare) => Box::new(move |point_id| {
+            payload_provider.with_payload(point_id, |payload| {
+                check_fields_comparison_condition(compare, &payload)
+            })
+        }),
         // ToDo: It might be possible to make this condition faster by using `VisitedPool` instead of HashSet
         Condition::HasId(has_id) => {
             let segment_ids: HashSet<_> = has_id
@@ -0,0 +1,222 @@
+use std::collections::HashMap;
+use std::hash::Hasher;
+
+use schemars::JsonSchema;
+use seahash::SeaHasher;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sparse::common::sparse_vector::SparseVector;
+use sparse::common::types::{DimId, DimWeight};
+use validator::Validate;
+
+use crate::data_types::text_index::{TextIndexParams, TextIndexType, TokenizerType};
+use crate::index::field_index::full_text_index::tokenizers::Tokenizer;
+use crate::json_path::JsonPath;
+use crate::types::{Payload, PayloadContainer};
+
+pub const DEFAULT_BM25_K: f32 = 1.2;
+pub const DEFAULT_BM25_B: f32 = 0.75;
+
+/// Configuration of the built-in BM25 vectorizer.
+/// Sparse vectors are derived from the text of a payload field, each term is hashed into a dimension.
+/// Combined with `idf` modifier, scoring of such vectors is equivalent to BM25.
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub struct Bm25Config {
+    /// Payload field with the text to vectorize. Arrays of strings are treated as a single text.
+    pub field: JsonPath,
+    /// Tokenizer used to split the text into terms. Default: word
+    #[serde(default)]
+    pub tokenizer: TokenizerType,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_token_len: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_token_len: Option<usize>,
+    /// If true, lowercase all terms. Default: true
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lowercase: Option<bool>,
+    /// Controls saturation of term frequency. Default: 1.2
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 0.0))]
+    pub k: Option<f32>,
+    /// Controls normalization by document length, 0 disables it. Default: 0.75
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 0.0, max = 1.0))]
+    pub b: Option<f32>,
+    /// Average number of terms in a document of the collection.
+    /// It is a tuning constant, which has to match the data: it is not derived from the stored points,
+    /// and already vectorized documents are not updated if the actual average changes.
+    #[validate(range(min = 1.0))]
+    pub avg_len: f32,
+}
+
+impl std::hash::Hash for Bm25Config {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.field.hash(state);
+        self.tokenizer.hash(state);
+        self.min_token_len.hash(state);
+        self.max_token_len.hash(state);
+        self.lowercase.hash(state);
+    }
+}
+
+impl Eq for Bm25Config {}
+
+impl Bm25Config {
+    fn text_index_params(&self) -> TextIndexParams {
+        TextIndexParams {
+            r#type: TextIndexType::Text,
+            tokenizer: self.tokenizer,
+            min_token_len: self.min_token_len,
+            max_token_len: self.max_token_len,
+            lowercase: self.lowercase,
+        }
+    }
+}
+
+/// Converts texts into sparse vectors according to [`Bm25Config`]
+#[derive(Debug, Clone)]
+pub struct Bm25Vectorizer {
+    field: JsonPath,
+    text_index_params: TextIndexParams,
+    k: f32,
+    b: f32,
+    avg_len: f32,
+}
+
+impl Bm25Vectorizer {
+    pub fn new(config: &Bm25Config) -> Self {
+        Self {
+            field: config.field.clone(),
+            text_index_params: config.text_index_params(),
+            k: config.k.unwrap_or(DEFAULT_BM25_K),
+            b: config.b.unwrap_or(DEFAULT_BM25_B),
+            avg_len: config.avg_len,
+        }
+    }
+
+    /// Payload field with the text to vectorize
+    pub fn field(&self) -> &JsonPath {
+        &self.field
+    }
+
+    /// Stable mapping of a term into a sparse dimension
+    fn term_dim(term: &str) -> DimId {
+        let mut hasher = SeaHasher::new();
+        hasher.write(term.as_bytes());
+        hasher.finish() as DimId
+    }
+
+    /// Vectorize the text of the configured payload field.
+    /// Returns `None` if the payload contains no text in this field.
+    pub fn vectorize_payload(&self, payload: &Payload) -> Option<SparseVector> {
+        let texts: Vec<&str> = payload
+            .get_value(&self.field)
+            .iter()
+            .flat_map(|value| match value {
+                Value::String(text) => vec![text.as_str()],
+                Value::Array(values) => values.iter().filter_map(Value::as_str).collect(),
+                _ => vec![],
+            })
+            .collect();
+
+        if texts.is_empty() {
+            return None;
+        }
+
+        Some(self.vectorize_document(&texts))
+    }
+
+    /// Document vector, weight of each term is its frequency saturated by BM25:
+    /// `tf * (k + 1) / (tf + k * (1 - b + b * doc_len / avg_len))`
+    pub fn vectorize_document(&self, texts: &[&str]) -> SparseVector {
+        let mut frequencies: HashMap<DimId, usize> = HashMap::new();
+        let mut doc_len = 0;
+        for text in texts {
+            Tokenizer::tokenize_doc(text, &self.text_index_params, |term| {
+                *frequencies.entry(Self::term_dim(term)).or_default() += 1;
+                doc_len += 1;
+            });
+        }
+
+        let norm = self.k * (1.0 - self.b + self.b * doc_len as f32 / self.avg_len);
+        Self::to_sparse_vector(frequencies.into_iter().map(|(dim, tf)| {
+            let tf = tf as DimWeight;
+            (dim, tf * (self.k + 1.0) / (tf + norm))
+        }))
+    }
+
+    /// Query vector, each unique term of the query has weight 1
+    pub fn vectorize_query(&self, text: &str) -> SparseVector {
+        let mut dims: Vec<DimId> = Vec::new();
+        Tokenizer::tokenize_query(text, &self.text_index_params, |term| {
+            dims.push(Self::term_dim(term));
+        });
+        dims.sort_unstable();
+        dims.dedup();
+        Self::to_sparse_vector(dims.into_iter().map(|dim| (dim, 1.0)))
+    }
+
+    fn to_sparse_vector(weights: impl Iterator<Item = (DimId, DimWeight)>) -> SparseVector {
+        let (indices, values) = weights.unzip();
+        let mut vector = SparseVector { indices, values };
+        vector.sort_by_indices();
+        vector
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::json_path::path;
+
+    fn vectorizer() -> Bm25Vectorizer {
+        Bm25Vectorizer::new(&Bm25Config {
+            field: path("text"),
+            tokenizer: TokenizerType::Word,
+            min_token_len: None,
+            max_token_len: None,
+            lowercase: None,
+            k: None,
+            b: None,
+            avg_len: 4.0,
+        })
+    }
+
+    #[test]
+    fn test_bm25_document_weights() {
+        let vectorizer = vectorizer();
+        let payload: Payload = json!({ "text": "Hello world, hello BM25" }).into();
+        let vector = vectorizer.vectorize_payload(&payload).unwrap();
+
+        assert_eq!(vector.indices.len(), 3);
+        assert!(vector.is_sorted());
+
+        // Document length equals average, so normalization is `k`
+        let weight = |tf: f32| tf * (DEFAULT_BM25_K + 1.0) / (tf + DEFAULT_BM25_K);
+        let hello = Bm25Vectorizer::term_dim("hello");
+        let world = Bm25Vectorizer::term_dim("world");
+        let position = |dim| vector.indices.iter().position(|i| *i == dim).unwrap();
+        assert!((vector.values[position(hello)] - weight(2.0)).abs() < 1e-6);
+        assert!((vector.values[position(world)] - weight(1.0)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_bm25_query_matches_document() {
+        let vectorizer = vectorizer();
+        let payload: Payload = json!({ "text": ["Hello world", "hello BM25"] }).into();
+        let document = vectorizer.vectorize_payload(&payload).unwrap();
+        let query = vectorizer.vectorize_query("WORLD hello hello");
+
+        assert_eq!(query.values, vec![1.0, 1.0]);
+        assert!(query
+            .indices
+            .iter()
+            .all(|dim| document.indices.contains(dim)));
+
+        let missing: Payload = json!({ "other": "Hello world" }).into();
+        assert!(vectorizer.vectorize_payload(&missing).is_none());
+    }
+}
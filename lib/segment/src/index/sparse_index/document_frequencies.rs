@@ -0,0 +1,50 @@
+use std::path::{Path, PathBuf};
+
+use ahash::AHashMap;
+use io::file_operations::{atomic_save_json, read_json};
+use serde::{Deserialize, Serialize};
+use sparse::common::sparse_vector::RemappedSparseVector;
+use sparse::common::types::DimOffset;
+
+use crate::common::operation_error::OperationResult;
+
+const DOCUMENT_FREQUENCIES_FILE_NAME: &str = "document_frequencies.json";
+
+/// Number of indexed vectors which contain each dimension, counted before vectors are pruned.
+///
+/// Posting lists of a pruned index miss some of the vectors, so their lengths can't be used
+/// as document frequencies for `idf` statistics.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct DocumentFrequencies {
+    pub frequencies: AHashMap<DimOffset, usize>,
+}
+
+impl DocumentFrequencies {
+    /// Load document frequencies, returns `None` if they were not stored with the index
+    pub fn open(path: &Path) -> std::io::Result<Option<Self>> {
+        let path = Self::file_path(path);
+        if !path.exists() {
+            return Ok(None);
+        }
+        Ok(Some(read_json(&path)?))
+    }
+
+    pub fn save(&self, path: &Path) -> OperationResult<()> {
+        let path = Self::file_path(path);
+        Ok(atomic_save_json(&path, self)?)
+    }
+
+    pub fn file_path(path: &Path) -> PathBuf {
+        path.join(DOCUMENT_FREQUENCIES_FILE_NAME)
+    }
+
+    pub fn register_vector(&mut self, vector: &RemappedSparseVector) {
+        for index in &vector.indices {
+            *self.frequencies.entry(*index).or_default() += 1;
+        }
+    }
+
+    pub fn get(&self, index: &DimOffset) -> Option<usize> {
+        self.frequencies.get(index).copied()
+    }
+}
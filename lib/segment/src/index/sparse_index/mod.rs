@@ -1,4 +1,6 @@
 #![allow(dead_code)]
+pub mod bm25;
+pub mod document_frequencies;
 pub mod indices_tracker;
 pub mod sparse_index_config;
 pub mod sparse_search_telemetry;
@@ -3,12 +3,18 @@ use std::path::{Path, PathBuf};
 use io::file_operations::{atomic_save_json, read_json};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use sparse::common::types::WeightType;
+use sparse::index::inverted_index::inverted_index_ram_builder::IndexPruning;
 
 use crate::common::anonymize::Anonymize;
 use crate::common::operation_error::OperationResult;
+use crate::types::VectorStorageDatatype;
 
 pub const SPARSE_INDEX_CONFIG_FILE: &str = "sparse_index_config.json";
 
+/// Default ratio of candidates retrieved from a pruned index to the number of requested results
+pub const DEFAULT_PRUNE_OVERSAMPLING: f64 = 2.0;
+
 /// Sparse index types
 #[derive(Default, Hash, Debug, Deserialize, Serialize, JsonSchema, Eq, PartialEq, Copy, Clone)]
 pub enum SparseIndexType {
@@ -22,7 +28,7 @@ pub enum SparseIndexType {
 }
 
 /// Configuration for sparse inverted index.
-#[derive(Debug, Hash, Deserialize, Serialize, JsonSchema, Copy, Clone, PartialEq, Eq, Default)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Copy, Clone, PartialEq, Default)]
 #[serde(rename_all = "snake_case")]
 pub struct SparseIndexConfig {
     /// We prefer a full scan search upto (excluding) this number of vectors.
@@ -31,22 +37,91 @@ pub struct SparseIndexConfig {
     pub full_scan_threshold: Option<usize>,
     /// Type of sparse index
     pub index_type: SparseIndexType,
+    /// Datatype used to store weights in the index.
+    /// Only used by immutable indexes, mutable indexes always store weights as float32.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub datatype: Option<VectorStorageDatatype>,
+    /// Index only this number of dimensions with the largest absolute weights of each vector.
+    /// Only used by immutable indexes, original vectors are kept in the storage for exact scoring.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prune_top_k: Option<usize>,
+    /// Do not index dimensions with absolute weight below this threshold.
+    /// Only used by immutable indexes, original vectors are kept in the storage for exact scoring.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prune_threshold: Option<f32>,
+    /// Retrieve this many times more candidates from a pruned index before rescoring them
+    /// with the original vectors.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prune_oversampling: Option<f64>,
+}
+
+impl std::hash::Hash for SparseIndexConfig {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.full_scan_threshold.hash(state);
+        self.index_type.hash(state);
+        self.datatype.hash(state);
+        self.prune_top_k.hash(state);
+    }
 }
 
+impl Eq for SparseIndexConfig {}
+
 impl Anonymize for SparseIndexConfig {
     fn anonymize(&self) -> Self {
         SparseIndexConfig {
             full_scan_threshold: self.full_scan_threshold,
             index_type: self.index_type,
+            datatype: self.datatype,
+            prune_top_k: self.prune_top_k,
+            prune_threshold: self.prune_threshold,
+            prune_oversampling: self.prune_oversampling,
         }
     }
 }
 
 impl SparseIndexConfig {
-    pub fn new(full_scan_threshold: Option<usize>, index_type: SparseIndexType) -> Self {
+    pub fn new(
+        full_scan_threshold: Option<usize>,
+        index_type: SparseIndexType,
+        datatype: Option<VectorStorageDatatype>,
+    ) -> Self {
         SparseIndexConfig {
             full_scan_threshold,
             index_type,
+            datatype,
+            prune_top_k: None,
+            prune_threshold: None,
+            prune_oversampling: None,
+        }
+    }
+
+    /// Pruning of vectors applied when building the index.
+    /// Mutable indexes are never pruned.
+    pub fn pruning(&self) -> IndexPruning {
+        match self.index_type {
+            SparseIndexType::MutableRam => IndexPruning::default(),
+            SparseIndexType::ImmutableRam | SparseIndexType::Mmap => IndexPruning {
+                top_k: self.prune_top_k,
+                threshold: self.prune_threshold,
+            },
+        }
+    }
+
+    /// Number of candidates to retrieve from a pruned index to return `top` results after rescoring
+    pub fn prune_candidates(&self, top: usize) -> usize {
+        let oversampling = self
+            .prune_oversampling
+            .unwrap_or(DEFAULT_PRUNE_OVERSAMPLING)
+            .max(1.0);
+        (top as f64 * oversampling).ceil() as usize
+    }
+
+    /// Type used to store weights in immutable posting lists
+    pub fn weight_type(&self) -> WeightType {
+        match self.datatype.unwrap_or_default() {
+            VectorStorageDatatype::Float32 => WeightType::Float32,
+            VectorStorageDatatype::Float16 => WeightType::Float16,
+            VectorStorageDatatype::Uint8 => WeightType::Uint8,
         }
     }
 
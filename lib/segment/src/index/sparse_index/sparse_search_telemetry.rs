@@ -1,11 +1,19 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use common::types::TelemetryDetail;
 use parking_lot::Mutex;
+use schemars::JsonSchema;
+use serde::Serialize;
+use sparse::common::types::DimId;
 
+use crate::common::anonymize::Anonymize;
 use crate::common::operation_time_statistics::OperationDurationsAggregator;
 use crate::telemetry::VectorIndexSearchesTelemetry;
 
+/// Number of dimensions with the longest posting lists reported in telemetry
+pub const TELEMETRY_TOP_DIMENSIONS: usize = 10;
+
 pub struct SparseSearchesTelemetry {
     pub filtered_sparse: Arc<Mutex<OperationDurationsAggregator>>,
     pub unfiltered_sparse: Arc<Mutex<OperationDurationsAggregator>>,
@@ -37,6 +45,7 @@ impl SparseSearchesTelemetry {
             filtered_sparse: self.filtered_sparse.lock().get_statistics(detail),
             unfiltered_sparse: self.unfiltered_sparse.lock().get_statistics(detail),
             unfiltered_exact: Default::default(),
+            sparse_index: None,
         }
     }
 }
@@ -46,3 +55,153 @@ impl Default for SparseSearchesTelemetry {
         Self::new()
     }
 }
+
+/// Statistics of a sparse inverted index.
+/// Collected for each segment separately, statistics of several segments are combined with [`Self::merge`]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SparseIndexStatistics {
+    /// Number of vectors in the index
+    pub indexed_vectors: usize,
+    pub ram_usage_bytes: usize,
+    pub disk_usage_bytes: usize,
+    /// Number of indexed vectors containing each dimension
+    pub document_frequencies: HashMap<DimId, usize>,
+}
+
+impl SparseIndexStatistics {
+    /// Add up statistics of an index over a disjoint set of points, e.g. another segment
+    pub fn merge(&mut self, other: SparseIndexStatistics) {
+        self.indexed_vectors += other.indexed_vectors;
+        self.ram_usage_bytes += other.ram_usage_bytes;
+        self.disk_usage_bytes += other.disk_usage_bytes;
+        for (dim_id, frequency) in other.document_frequencies {
+            *self.document_frequencies.entry(dim_id).or_default() += frequency;
+        }
+    }
+
+    /// Summarize statistics, reporting `top` dimensions with the longest posting lists
+    pub fn summarize(&self, top: usize) -> SparseIndexStats {
+        let postings: usize = self.document_frequencies.values().sum();
+        let dimensions = self
+            .document_frequencies
+            .values()
+            .filter(|&&frequency| frequency > 0)
+            .count();
+
+        let mut top_dimensions: Vec<_> = self
+            .document_frequencies
+            .iter()
+            .filter(|(_, &frequency)| frequency > 0)
+            .map(|(&dimension, &document_frequency)| DimensionStats {
+                dimension,
+                document_frequency,
+            })
+            .collect();
+        // Ties are broken by dimension id to keep results stable
+        top_dimensions.sort_unstable_by(|a, b| {
+            b.document_frequency
+                .cmp(&a.document_frequency)
+                .then(a.dimension.cmp(&b.dimension))
+        });
+        top_dimensions.truncate(top);
+
+        SparseIndexStats {
+            indexed_vectors: self.indexed_vectors,
+            dimensions,
+            postings,
+            max_posting_list_len: self
+                .document_frequencies
+                .values()
+                .max()
+                .copied()
+                .unwrap_or_default(),
+            ram_usage_bytes: self.ram_usage_bytes,
+            disk_usage_bytes: self.disk_usage_bytes,
+            top_dimensions,
+        }
+    }
+}
+
+#[derive(Serialize, Clone, Debug, JsonSchema, Default, PartialEq)]
+pub struct SparseIndexStats {
+    /// Number of vectors in the index
+    pub indexed_vectors: usize,
+    /// Number of dimensions with non-empty posting lists
+    pub dimensions: usize,
+    /// Total number of elements in all posting lists
+    pub postings: usize,
+    /// Number of elements in the longest posting list
+    pub max_posting_list_len: usize,
+    /// Approximate size of the index data kept in RAM
+    pub ram_usage_bytes: usize,
+    /// Size of the index files
+    pub disk_usage_bytes: usize,
+    /// Dimensions with the longest posting lists, most frequent first
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub top_dimensions: Vec<DimensionStats>,
+}
+
+#[derive(Serialize, Clone, Debug, JsonSchema, PartialEq, Eq)]
+pub struct DimensionStats {
+    /// Dimension id, as used in sparse vectors
+    pub dimension: DimId,
+    /// Number of indexed vectors containing this dimension
+    pub document_frequency: usize,
+}
+
+impl Anonymize for SparseIndexStats {
+    fn anonymize(&self) -> Self {
+        SparseIndexStats {
+            indexed_vectors: self.indexed_vectors.anonymize(),
+            dimensions: self.dimensions.anonymize(),
+            postings: self.postings.anonymize(),
+            max_posting_list_len: self.max_posting_list_len.anonymize(),
+            ram_usage_bytes: self.ram_usage_bytes.anonymize(),
+            disk_usage_bytes: self.disk_usage_bytes.anonymize(),
+            top_dimensions: vec![],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sparse_index_statistics_merge() {
+        let mut segment_a = SparseIndexStatistics {
+            indexed_vectors: 3,
+            ram_usage_bytes: 100,
+            disk_usage_bytes: 0,
+            document_frequencies: HashMap::from([(1, 3), (2, 1), (5, 0)]),
+        };
+        let segment_b = SparseIndexStatistics {
+            indexed_vectors: 2,
+            ram_usage_bytes: 0,
+            disk_usage_bytes: 200,
+            document_frequencies: HashMap::from([(2, 2), (7, 1)]),
+        };
+        segment_a.merge(segment_b);
+
+        let stats = segment_a.summarize(2);
+        assert_eq!(stats.indexed_vectors, 5);
+        assert_eq!(stats.dimensions, 3);
+        assert_eq!(stats.postings, 7);
+        assert_eq!(stats.max_posting_list_len, 3);
+        assert_eq!(stats.ram_usage_bytes, 100);
+        assert_eq!(stats.disk_usage_bytes, 200);
+        assert_eq!(
+            stats.top_dimensions,
+            vec![
+                DimensionStats {
+                    dimension: 1,
+                    document_frequency: 3,
+                },
+                DimensionStats {
+                    dimension: 2,
+                    document_frequency: 3,
+                },
+            ],
+        );
+    }
+}
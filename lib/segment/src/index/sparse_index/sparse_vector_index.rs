@@ -1,5 +1,5 @@
 use std::collections::{HashMap, HashSet};
-use std::fs::create_dir_all;
+use std::fs::{create_dir_all, remove_file};
 use std::path::{Path, PathBuf};
 use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
@@ -10,12 +10,15 @@ use common::types::{PointOffsetType, ScoredPointOffset, TelemetryDetail};
 use itertools::Itertools;
 use sparse::common::scores_memory_pool::ScoresMemoryPool;
 use sparse::common::sparse_vector::SparseVector;
-use sparse::common::types::DimId;
+use sparse::common::types::{DimId, DimOffset};
+use sparse::index::inverted_index::inverted_index_mmap::InvertedIndexMmap;
 use sparse::index::inverted_index::inverted_index_ram::InvertedIndexRam;
 use sparse::index::inverted_index::inverted_index_ram_builder::InvertedIndexBuilder;
 use sparse::index::inverted_index::InvertedIndex;
+use sparse::index::posting_list::PostingListIter;
 use sparse::index::search_context::SearchContext;
 
+use super::document_frequencies::DocumentFrequencies;
 use super::indices_tracker::IndicesTracker;
 use super::sparse_index_config::SparseIndexType;
 use crate::common::operation_error::{check_process_stopped, OperationError, OperationResult};
@@ -26,9 +29,12 @@ use crate::id_tracker::IdTrackerSS;
 use crate::index::field_index::CardinalityEstimation;
 use crate::index::query_estimator::adjust_to_available_vectors;
 use crate::index::sparse_index::sparse_index_config::SparseIndexConfig;
-use crate::index::sparse_index::sparse_search_telemetry::SparseSearchesTelemetry;
+use crate::index::sparse_index::sparse_search_telemetry::{
+    SparseIndexStatistics, SparseSearchesTelemetry, TELEMETRY_TOP_DIMENSIONS,
+};
 use crate::index::struct_payload_index::StructPayloadIndex;
 use crate::index::{PayloadIndex, VectorIndex};
+use crate::spaces::tools::peek_top_largest_iterable;
 use crate::telemetry::VectorIndexSearchesTelemetry;
 use crate::types::{Filter, SearchParams, DEFAULT_SPARSE_FULL_SCAN_THRESHOLD};
 use crate::vector_storage::query::TransformInto;
@@ -46,6 +52,8 @@ pub struct SparseVectorIndex<TInvertedIndex: InvertedIndex> {
     searches_telemetry: SparseSearchesTelemetry,
     is_appendable: bool,
     pub indices_tracker: IndicesTracker,
+    /// Document frequencies of a pruned index, counted before pruning
+    document_frequencies: Option<DocumentFrequencies>,
     scores_memory_pool: ScoresMemoryPool,
 }
 
@@ -64,28 +72,82 @@ impl<TInvertedIndex: InvertedIndex> SparseVectorIndex<TInvertedIndex> {
         let is_appendable = config.index_type == SparseIndexType::MutableRam;
 
         let config_path = SparseIndexConfig::get_config_path(path);
-        let (config, inverted_index, indices_tracker) = if is_appendable {
+        let (config, inverted_index, indices_tracker, document_frequencies) = if is_appendable {
             // RAM mutable case - build inverted index from scratch and use provided config
-            let (inverted_index, indices_tracker) = Self::build_inverted_index(
-                id_tracker.clone(),
-                vector_storage.clone(),
-                path,
-                stopped,
-                || (),
-            )?;
-            (config, inverted_index, indices_tracker)
+            let (inverted_index, indices_tracker, document_frequencies) =
+                Self::build_inverted_index(
+                    id_tracker.clone(),
+                    vector_storage.clone(),
+                    path,
+                    &config,
+                    stopped,
+                    || (),
+                )?;
+            (
+                config,
+                inverted_index,
+                indices_tracker,
+                document_frequencies,
+            )
         } else if config_path.exists() {
-            // Load inverted index and config
             let loaded_config = SparseIndexConfig::load(&config_path)?;
-            let inverted_index = TInvertedIndex::open(path)?;
-            let indices_tracker =
-                IndicesTracker::open(path, || inverted_index.max_index().unwrap_or_default())?;
-            (loaded_config, inverted_index, indices_tracker)
+            let index_files = TInvertedIndex::files(path);
+            let document_frequencies = DocumentFrequencies::open(path)?;
+            let is_index_complete = !index_files.is_empty()
+                && index_files.iter().all(|file| file.exists())
+                && (document_frequencies.is_some() || !loaded_config.pruning().is_enabled());
+            if is_index_complete {
+                // Load inverted index and config
+                let inverted_index = TInvertedIndex::open(path)?;
+                let indices_tracker =
+                    IndicesTracker::open(path, || inverted_index.max_index().unwrap_or_default())?;
+                (
+                    loaded_config,
+                    inverted_index,
+                    indices_tracker,
+                    document_frequencies,
+                )
+            } else {
+                // Index was built in an older format without compressed posting lists
+                // or without document frequencies of a pruned index - rebuild it
+                log::info!(
+                    "Rebuilding sparse index in {} in the current format",
+                    path.display(),
+                );
+                let (inverted_index, indices_tracker, document_frequencies) =
+                    Self::build_inverted_index(
+                        id_tracker.clone(),
+                        vector_storage.clone(),
+                        path,
+                        &loaded_config,
+                        stopped,
+                        || (),
+                    )?;
+                indices_tracker.save(path)?;
+                if let Some(document_frequencies) = &document_frequencies {
+                    document_frequencies.save(path)?;
+                }
+                inverted_index.save(path)?;
+                // Index is converted, files of the older format are not needed anymore
+                for file in InvertedIndexMmap::legacy_files(path) {
+                    remove_file(file)?;
+                }
+                (
+                    loaded_config,
+                    inverted_index,
+                    indices_tracker,
+                    document_frequencies,
+                )
+            }
         } else {
             // Inverted index and config are not presented - initialize empty inverted index
-            let inverted_index = TInvertedIndex::from_ram_index(InvertedIndexRam::empty(), path)?;
+            let inverted_index = TInvertedIndex::from_ram_index(
+                InvertedIndexRam::empty(),
+                path,
+                config.weight_type(),
+            )?;
             let indices_tracker = Default::default();
-            (config, inverted_index, indices_tracker)
+            (config, inverted_index, indices_tracker, None)
         };
 
         let searches_telemetry = SparseSearchesTelemetry::new();
@@ -101,6 +163,7 @@ impl<TInvertedIndex: InvertedIndex> SparseVectorIndex<TInvertedIndex> {
             searches_telemetry,
             is_appendable,
             indices_tracker,
+            document_frequencies,
             scores_memory_pool,
         })
     }
@@ -114,15 +177,19 @@ impl<TInvertedIndex: InvertedIndex> SparseVectorIndex<TInvertedIndex> {
         id_tracker: Arc<AtomicRefCell<IdTrackerSS>>,
         vector_storage: Arc<AtomicRefCell<VectorStorageEnum>>,
         path: &Path,
+        config: &SparseIndexConfig,
         stopped: &AtomicBool,
         mut tick_progress: impl FnMut(),
-    ) -> OperationResult<(TInvertedIndex, IndicesTracker)> {
+    ) -> OperationResult<(TInvertedIndex, IndicesTracker, Option<DocumentFrequencies>)> {
         let borrowed_vector_storage = vector_storage.borrow();
         let borrowed_id_tracker = id_tracker.borrow();
         let deleted_bitslice = borrowed_vector_storage.deleted_vector_bitslice();
 
-        let mut ram_index_builder = InvertedIndexBuilder::new();
+        let pruning = config.pruning();
+        let mut ram_index_builder = InvertedIndexBuilder::new().with_pruning(pruning);
         let mut indices_tracker = IndicesTracker::default();
+        // Posting lists of a pruned index don't contain every vector, so count dimensions upfront
+        let mut document_frequencies = pruning.is_enabled().then(DocumentFrequencies::default);
         for id in borrowed_id_tracker.iter_ids_excluding(deleted_bitslice) {
             check_process_stopped(stopped)?;
             // It is possible that the vector is not present in the storage in case of crash.
@@ -142,14 +209,18 @@ impl<TInvertedIndex: InvertedIndex> SparseVectorIndex<TInvertedIndex> {
                     }
                     indices_tracker.register_indices(vector);
                     let vector = indices_tracker.remap_vector(vector.to_owned());
+                    if let Some(document_frequencies) = &mut document_frequencies {
+                        document_frequencies.register_vector(&vector);
+                    }
                     ram_index_builder.add(id, vector);
                 }
             }
             tick_progress();
         }
         Ok((
-            TInvertedIndex::from_ram_index(ram_index_builder.build(), path)?,
+            TInvertedIndex::from_ram_index(ram_index_builder.build(), path, config.weight_type())?,
             indices_tracker,
+            document_frequencies,
         ))
     }
 
@@ -159,10 +230,10 @@ impl<TInvertedIndex: InvertedIndex> SparseVectorIndex<TInvertedIndex> {
         let mut unique_record_ids = HashSet::new();
         for dim_id in query_vector.indices.iter() {
             if let Some(dim_id) = self.indices_tracker.remap_index(*dim_id) {
-                if let Some(posting_list) = self.inverted_index.get(&dim_id) {
-                    for element in posting_list.elements.iter() {
-                        unique_record_ids.insert(element.record_id);
-                    }
+                if let Some(mut posting_list) = self.inverted_index.get(&dim_id) {
+                    posting_list.for_each_till_id(PointOffsetType::MAX, |record_id, _| {
+                        unique_record_ids.insert(record_id);
+                    });
                 }
             }
         }
@@ -253,6 +324,11 @@ impl<TInvertedIndex: InvertedIndex> SparseVectorIndex<TInvertedIndex> {
         .filter(|&idx| check_deleted_condition(idx, deleted_vectors, deleted_point_bitslice))
         .collect_vec();
 
+        // Pruned index misses some weights, score original vectors instead
+        if self.config.pruning().is_enabled() {
+            return self.score_exact(sparse_vector, ids, top, &is_stopped);
+        }
+
         let sparse_vector = self.indices_tracker.remap_vector(sparse_vector.to_owned());
         let memory_handle = self.scores_memory_pool.get();
         let mut search_context = SearchContext::new(
@@ -286,27 +362,65 @@ impl<TInvertedIndex: InvertedIndex> SparseVectorIndex<TInvertedIndex> {
 
         let is_stopped = vector_query_context.is_stopped();
 
-        let sparse_vector = self.indices_tracker.remap_vector(sparse_vector.to_owned());
+        // Pruned index gives approximate scores, retrieve more candidates to rescore them
+        let is_pruned = self.config.pruning().is_enabled();
+        let candidates = if is_pruned {
+            self.config.prune_candidates(top)
+        } else {
+            top
+        };
+
+        let remapped_vector = self.indices_tracker.remap_vector(sparse_vector.to_owned());
         let memory_handle = self.scores_memory_pool.get();
         let mut search_context = SearchContext::new(
-            sparse_vector,
-            top,
+            remapped_vector,
+            candidates,
             &self.inverted_index,
             memory_handle,
             &is_stopped,
         );
 
-        match filter {
+        let results = match filter {
             Some(filter) => {
                 let payload_index = self.payload_index.borrow();
                 let filter_context = payload_index.filter_context(filter);
                 let matches_filter_condition = |idx: PointOffsetType| -> bool {
                     not_deleted_condition(idx) && filter_context.check(idx)
                 };
-                Ok(search_context.search(&matches_filter_condition))
+                search_context.search(&matches_filter_condition)
             }
-            None => Ok(search_context.search(&not_deleted_condition)),
+            None => search_context.search(&not_deleted_condition),
+        };
+
+        // Rescore retrieved candidates of a pruned index with original vectors
+        if is_pruned {
+            let ids = results.iter().map(|scored| scored.idx);
+            return self.score_exact(sparse_vector, ids, top, &is_stopped);
         }
+
+        Ok(results)
+    }
+
+    /// Score points against the vectors from the storage, which are never pruned
+    fn score_exact(
+        &self,
+        sparse_vector: &SparseVector,
+        ids: impl IntoIterator<Item = PointOffsetType>,
+        top: usize,
+        is_stopped: &AtomicBool,
+    ) -> OperationResult<Vec<ScoredPointOffset>> {
+        let vector_storage = self.vector_storage.borrow();
+        let mut scores = Vec::new();
+        for idx in ids {
+            check_process_stopped(is_stopped)?;
+            let vector = vector_storage.get_vector(idx);
+            let vector: &SparseVector = vector.as_vec_ref().try_into()?;
+            scores.push(ScoredPointOffset {
+                idx,
+                score: sparse_vector.score(vector).unwrap_or(0.0),
+            });
+        }
+        Ok(peek_top_largest_iterable(scores, top))
     }
 
     fn search_nearest_query(
@@ -374,7 +488,10 @@ impl<TInvertedIndex: InvertedIndex> SparseVectorIndex<TInvertedIndex> {
                 prefiltered_points,
                 vector_query_context,
             ),
-            QueryVector::Recommend(_) | QueryVector::Discovery(_) | QueryVector::Context(_) => {
+            QueryVector::Recommend(_)
+            | QueryVector::RecommendSumScores(_)
+            | QueryVector::Discovery(_)
+            | QueryVector::Context(_) => {
                 let _timer = if filter.is_some() {
                     ScopeDurationMeasurer::new(&self.searches_telemetry.filtered_plain)
                 } else {
@@ -391,18 +508,51 @@ impl<TInvertedIndex: InvertedIndex> SparseVectorIndex<TInvertedIndex> {
         }
     }
 
+    /// Number of indexed vectors which contain the dimension.
+    /// Pruned index uses frequencies counted before pruning, as its posting lists are incomplete.
+    fn document_frequency(&self, remapped_dim_id: &DimOffset) -> Option<usize> {
+        match &self.document_frequencies {
+            Some(document_frequencies) => document_frequencies.get(remapped_dim_id),
+            None => self.inverted_index.posting_list_len(remapped_dim_id),
+        }
+    }
+
     // Update statistics for idf-dot similarity
     pub fn fill_idf_statistics(&self, idf: &mut HashMap<DimId, usize>) {
         for (dim_id, count) in idf.iter_mut() {
             if let Some(remapped_dim_id) = self.indices_tracker.remap_index(*dim_id) {
-                if let Some(posting_list_len) =
-                    self.inverted_index.posting_list_len(&remapped_dim_id)
-                {
-                    *count += posting_list_len
+                if let Some(document_frequency) = self.document_frequency(&remapped_dim_id) {
+                    *count += document_frequency
                 }
             }
         }
     }
+
+    /// Statistics of the inverted index, document frequencies are reported for original dimension ids
+    pub fn index_statistics(&self) -> SparseIndexStatistics {
+        let document_frequencies = self
+            .indices_tracker
+            .map
+            .iter()
+            .filter_map(|(&dim_id, remapped_dim_id)| {
+                self.document_frequency(remapped_dim_id)
+                    .map(|document_frequency| (dim_id, document_frequency))
+            })
+            .collect();
+
+        let disk_usage_bytes = TInvertedIndex::files(&self.path)
+            .iter()
+            .filter_map(|file| file.metadata().ok())
+            .map(|metadata| metadata.len() as usize)
+            .sum();
+
+        SparseIndexStatistics {
+            indexed_vectors: self.inverted_index.vector_count(),
+            ram_usage_bytes: self.inverted_index.ram_usage_bytes(),
+            disk_usage_bytes,
+            document_frequencies,
+        }
+    }
 }
 
 impl<TInvertedIndex: InvertedIndex> VectorIndex for SparseVectorIndex<TInvertedIndex> {
@@ -449,20 +599,25 @@ impl<TInvertedIndex: InvertedIndex> VectorIndex for SparseVectorIndex<TInvertedI
         stopped: &AtomicBool,
         tick_progress: impl FnMut(),
     ) -> OperationResult<()> {
-        let (inverted_index, indices_tracker) = Self::build_inverted_index(
+        let (inverted_index, indices_tracker, document_frequencies) = Self::build_inverted_index(
             self.id_tracker.clone(),
             self.vector_storage.clone(),
             &self.path,
+            &self.config,
             stopped,
             tick_progress,
         )?;
 
         self.inverted_index = inverted_index;
         self.indices_tracker = indices_tracker;
+        self.document_frequencies = document_frequencies;
 
         // save inverted index
         if !self.is_appendable {
             self.indices_tracker.save(&self.path)?;
+            if let Some(document_frequencies) = &self.document_frequencies {
+                document_frequencies.save(&self.path)?;
+            }
             self.inverted_index.save(&self.path)?;
         }
 
@@ -472,7 +627,9 @@ impl<TInvertedIndex: InvertedIndex> VectorIndex for SparseVectorIndex<TInvertedI
     }
 
     fn get_telemetry_data(&self, detail: TelemetryDetail) -> VectorIndexSearchesTelemetry {
-        self.searches_telemetry.get_telemetry_data(detail)
+        let mut telemetry = self.searches_telemetry.get_telemetry_data(detail);
+        telemetry.sparse_index = Some(self.index_statistics().summarize(TELEMETRY_TOP_DIMENSIONS));
+        telemetry
     }
 
     fn files(&self) -> Vec<PathBuf> {
@@ -488,6 +645,11 @@ impl<TInvertedIndex: InvertedIndex> VectorIndex for SparseVectorIndex<TInvertedI
             all_files.push(indices_tracker_file);
         }
 
+        let document_frequencies_file = DocumentFrequencies::file_path(&self.path);
+        if document_frequencies_file.exists() {
+            all_files.push(document_frequencies_file);
+        }
+
         all_files.push(config_file);
         all_files.extend_from_slice(&TInvertedIndex::files(&self.path));
         all_files
@@ -31,9 +31,9 @@ use crate::payload_storage::payload_storage_enum::PayloadStorageEnum;
 use crate::payload_storage::{FilterContext, PayloadStorage};
 use crate::telemetry::PayloadIndexTelemetry;
 use crate::types::{
-    infer_collection_value_type, infer_value_type, Condition, FieldCondition, Filter,
-    IsEmptyCondition, IsNullCondition, Payload, PayloadContainer, PayloadField, PayloadFieldSchema,
-    PayloadKeyType, PayloadKeyTypeRef, PayloadSchemaType,
+    infer_collection_value_type, infer_value_type, Condition, FieldCondition, FieldsComparison,
+    Filter, IsEmptyCondition, IsNullCondition, Payload, PayloadContainer, PayloadField,
+    PayloadFieldSchema, PayloadKeyType, PayloadKeyTypeRef, PayloadSchemaType,
 };
 
 pub const PAYLOAD_FIELD_INDEX_PATH: &str = "fields";
@@ -290,6 +290,32 @@ impl StructPayloadIndex {
                     }
                 }
             }
+            Condition::Compare(compare) => {
+                // There is no index to evaluate the comparison, so the estimation is conservative
+                // and has no primary clauses, which makes the query planner fall back to full scan.
+                // The only thing we know is that both fields must have a value,
+                // assuming field type consistency.
+                let available_points = self.available_point_count();
+                let FieldsComparison { left, right, .. } = &compare.compare;
+                let left_path = JsonPath::extend_or_new(nested_path, left);
+                let right_path = JsonPath::extend_or_new(nested_path, right);
+                if self.field_indexes.contains_key(&left_path)
+                    && self.field_indexes.contains_key(&right_path)
+                {
+                    let max = self
+                        .indexed_points(&left_path)
+                        .min(self.indexed_points(&right_path))
+                        .min(available_points);
+                    CardinalityEstimation {
+                        primary_clauses: vec![],
+                        min: 0,
+                        exp: max / 2,
+                        max,
+                    }
+                } else {
+                    CardinalityEstimation::unknown(available_points)
+                }
+            }
             Condition::HasId(has_id) => {
                 let id_tracker_ref = self.id_tracker.borrow();
                 let mapped_ids: HashSet<PointOffsetType> = has_id
@@ -506,6 +532,10 @@ impl PayloadIndex for StructPayloadIndex {
         self.payload.borrow().payload(point_id)
     }
 
+    fn payloads(&self, point_ids: &[PointOffsetType]) -> OperationResult<Vec<Payload>> {
+        self.payload.borrow().payloads(point_ids)
+    }
+
     fn delete(
         &mut self,
         point_id: PointOffsetType,
@@ -4,14 +4,17 @@ use std::sync::Arc;
 
 use common::cpu::CpuPermit;
 use common::types::{PointOffsetType, ScoredPointOffset, TelemetryDetail};
+use sparse::index::inverted_index::inverted_index_immutable_ram::InvertedIndexImmutableRam;
 use sparse::index::inverted_index::inverted_index_mmap::InvertedIndexMmap;
 use sparse::index::inverted_index::inverted_index_ram::InvertedIndexRam;
 
+use super::hnsw_index::graph_layers::GraphHealth;
 use super::hnsw_index::graph_links::{GraphLinksMmap, GraphLinksRam};
-use super::hnsw_index::hnsw::HNSWIndex;
+use super::hnsw_index::hnsw::{HNSWIndex, RepairedGraph};
+use super::hnsw_index::old_index::OldIndex;
 use super::plain_payload_index::PlainIndex;
 use super::sparse_index::sparse_vector_index::SparseVectorIndex;
-use crate::common::operation_error::OperationResult;
+use crate::common::operation_error::{OperationError, OperationResult};
 use crate::data_types::query_context::VectorQueryContext;
 use crate::data_types::vectors::{QueryVector, VectorRef};
 use crate::telemetry::VectorIndexSearchesTelemetry;
@@ -58,9 +61,16 @@ pub enum VectorIndexEnum {
     HnswRam(HNSWIndex<GraphLinksRam>),
     HnswMmap(HNSWIndex<GraphLinksMmap>),
     SparseRam(SparseVectorIndex<InvertedIndexRam>),
+    SparseImmutableRam(SparseVectorIndex<InvertedIndexImmutableRam>),
     SparseMmap(SparseVectorIndex<InvertedIndexMmap>),
 }
 
+/// HNSW graph repaired by [`VectorIndexEnum::repair_graph`]
+pub enum RepairedVectorIndex {
+    HnswRam(RepairedGraph<GraphLinksRam>),
+    HnswMmap(RepairedGraph<GraphLinksMmap>),
+}
+
 impl VectorIndexEnum {
     pub fn is_index(&self) -> bool {
         match self {
@@ -68,9 +78,86 @@ impl VectorIndexEnum {
             Self::HnswRam(_) => true,
             Self::HnswMmap(_) => true,
             Self::SparseRam(_) => true,
+            Self::SparseImmutableRam(_) => true,
             Self::SparseMmap(_) => true,
         }
     }
+
+    /// Extract HNSW graph of this index to reuse it when building an index of another segment
+    pub fn old_index(&self, old_to_new: &[Option<PointOffsetType>]) -> Option<OldIndex> {
+        match self {
+            Self::HnswRam(index) => index.old_index(old_to_new),
+            Self::HnswMmap(index) => index.old_index(old_to_new),
+            Self::Plain(_)
+            | Self::SparseRam(_)
+            | Self::SparseImmutableRam(_)
+            | Self::SparseMmap(_) => None,
+        }
+    }
+
+    /// Health of the HNSW graph, `None` if there is no graph
+    pub fn graph_health(&self) -> Option<GraphHealth> {
+        match self {
+            Self::HnswRam(index) => index.graph_health(),
+            Self::HnswMmap(index) => index.graph_health(),
+            Self::Plain(_)
+            | Self::SparseRam(_)
+            | Self::SparseImmutableRam(_)
+            | Self::SparseMmap(_) => None,
+        }
+    }
+
+    /// Build a repaired copy of the HNSW graph, see [`HNSWIndex::repair_graph`]
+    pub fn repair_graph(
+        &self,
+        permit: Arc<CpuPermit>,
+        stopped: &AtomicBool,
+    ) -> OperationResult<Option<RepairedVectorIndex>> {
+        Ok(match self {
+            Self::HnswRam(index) => index
+                .repair_graph(permit, stopped)?
+                .map(RepairedVectorIndex::HnswRam),
+            Self::HnswMmap(index) => index
+                .repair_graph(permit, stopped)?
+                .map(RepairedVectorIndex::HnswMmap),
+            Self::Plain(_)
+            | Self::SparseRam(_)
+            | Self::SparseImmutableRam(_)
+            | Self::SparseMmap(_) => None,
+        })
+    }
+
+    /// Replace the HNSW graph with the repaired one
+    pub fn apply_repaired_graph(&mut self, repaired: RepairedVectorIndex) -> OperationResult<()> {
+        match (self, repaired) {
+            (Self::HnswRam(index), RepairedVectorIndex::HnswRam(repaired)) => {
+                index.apply_repaired_graph(repaired)
+            }
+            (Self::HnswMmap(index), RepairedVectorIndex::HnswMmap(repaired)) => {
+                index.apply_repaired_graph(repaired)
+            }
+            _ => Err(OperationError::service_error(
+                "Repaired graph does not match the type of vector index",
+            )),
+        }
+    }
+
+    /// Build the index, reusing `old_index` graph if possible
+    pub fn build_index_with_old_index(
+        &mut self,
+        permit: Arc<CpuPermit>,
+        stopped: &AtomicBool,
+        old_index: Option<OldIndex>,
+    ) -> OperationResult<()> {
+        match self {
+            Self::HnswRam(index) => index.build_index_with_old_index(permit, stopped, old_index),
+            Self::HnswMmap(index) => index.build_index_with_old_index(permit, stopped, old_index),
+            Self::Plain(_)
+            | Self::SparseRam(_)
+            | Self::SparseImmutableRam(_)
+            | Self::SparseMmap(_) => self.build_index(permit, stopped),
+        }
+    }
 }
 
 impl VectorIndex for VectorIndexEnum {
@@ -95,6 +182,9 @@ impl VectorIndex for VectorIndexEnum {
             VectorIndexEnum::SparseRam(index) => {
                 index.search(vectors, filter, top, params, query_context)
             }
+            VectorIndexEnum::SparseImmutableRam(index) => {
+                index.search(vectors, filter, top, params, query_context)
+            }
             VectorIndexEnum::SparseMmap(index) => {
                 index.search(vectors, filter, top, params, query_context)
             }
@@ -120,6 +210,9 @@ impl VectorIndex for VectorIndexEnum {
             VectorIndexEnum::SparseRam(index) => {
                 index.build_index_with_progress(permit, stopped, tick_progress)
             }
+            VectorIndexEnum::SparseImmutableRam(index) => {
+                index.build_index_with_progress(permit, stopped, tick_progress)
+            }
             VectorIndexEnum::SparseMmap(index) => {
                 index.build_index_with_progress(permit, stopped, tick_progress)
             }
@@ -132,6 +225,7 @@ impl VectorIndex for VectorIndexEnum {
             VectorIndexEnum::HnswRam(index) => index.get_telemetry_data(detail),
             VectorIndexEnum::HnswMmap(index) => index.get_telemetry_data(detail),
             VectorIndexEnum::SparseRam(index) => index.get_telemetry_data(detail),
+            VectorIndexEnum::SparseImmutableRam(index) => index.get_telemetry_data(detail),
             VectorIndexEnum::SparseMmap(index) => index.get_telemetry_data(detail),
         }
     }
@@ -142,6 +236,7 @@ impl VectorIndex for VectorIndexEnum {
             VectorIndexEnum::HnswRam(index) => index.files(),
             VectorIndexEnum::HnswMmap(index) => index.files(),
             VectorIndexEnum::SparseRam(index) => index.files(),
+            VectorIndexEnum::SparseImmutableRam(index) => index.files(),
             VectorIndexEnum::SparseMmap(index) => index.files(),
         }
     }
@@ -152,6 +247,7 @@ impl VectorIndex for VectorIndexEnum {
             Self::HnswRam(index) => index.indexed_vector_count(),
             Self::HnswMmap(index) => index.indexed_vector_count(),
             Self::SparseRam(index) => index.indexed_vector_count(),
+            Self::SparseImmutableRam(index) => index.indexed_vector_count(),
             Self::SparseMmap(index) => index.indexed_vector_count(),
         }
     }
@@ -162,6 +258,7 @@ impl VectorIndex for VectorIndexEnum {
             Self::HnswRam(index) => index.update_vector(id, vector),
             Self::HnswMmap(index) => index.update_vector(id, vector),
             Self::SparseRam(index) => index.update_vector(id, vector),
+            Self::SparseImmutableRam(index) => index.update_vector(id, vector),
             Self::SparseMmap(index) => index.update_vector(id, vector),
         }
     }
@@ -182,6 +182,65 @@ impl JsonPathV2 {
             })
     }
 
+    /// Same as `value_get`, but returns mutable references to the values.
+    pub fn value_get_mut<'a>(
+        &self,
+        json_map: &'a mut serde_json::Map<String, Value>,
+    ) -> Vec<&'a mut Value> {
+        let mut result = Vec::new();
+        if let Some(value) = json_map.get_mut(&self.first_key) {
+            value_get_mut(&self.rest, value, &mut result);
+        }
+        result
+    }
+
+    /// Same as `value_get_mut`, but for a `json_map` which is going to be set under `key`,
+    /// see `value_set`. The path is resolved relative to the `key`.
+    ///
+    /// Returns `None` if the path points to the `key` itself, so the whole `json_map` becomes
+    /// its value. Nothing is returned if the path points to one of the parents of the `key`.
+    pub fn value_get_mut_under_key<'a>(
+        &self,
+        key: &JsonPathV2,
+        json_map: &'a mut serde_json::Map<String, Value>,
+    ) -> Option<Vec<&'a mut Value>> {
+        let mut result = Vec::new();
+        if self.first_key != key.first_key {
+            return Some(result);
+        }
+
+        let mut path_items = self.rest.iter();
+        for key_item in &key.rest {
+            let Some(path_item) = path_items.next() else {
+                return Some(result);
+            };
+            let matches = match (path_item, key_item) {
+                (JsonPathItem::Key(a), JsonPathItem::Key(b)) => a == b,
+                (JsonPathItem::Index(a), JsonPathItem::Index(b)) => a == b,
+                (
+                    JsonPathItem::Index(_) | JsonPathItem::WildcardIndex,
+                    JsonPathItem::Index(_) | JsonPathItem::WildcardIndex,
+                ) => true,
+                (JsonPathItem::Key(_), _) | (_, JsonPathItem::Key(_)) => false,
+            };
+            if !matches {
+                return Some(result);
+            }
+        }
+
+        match path_items.as_slice().split_first() {
+            None => return None,
+            Some((JsonPathItem::Key(first_key), rest)) => {
+                if let Some(value) = json_map.get_mut(first_key) {
+                    value_get_mut(rest, value, &mut result);
+                }
+            }
+            // The value under the key is an object, it can't be indexed
+            Some((JsonPathItem::Index(_) | JsonPathItem::WildcardIndex, _)) => {}
+        }
+        Some(result)
+    }
+
     /// Check if the path will be affected by a call to `path_to_remove.value_remove(_)`.
     pub fn is_affected_by_value_remove(&self, path_to_remove: &JsonPathV2) -> bool {
         // If we have, e.g., indexed field "a.b", then it is not safe to delete any of of "a",
@@ -279,6 +338,29 @@ fn value_get<'a>(
     }
 }
 
+fn value_get_mut<'a>(path: &[JsonPathItem], value: &'a mut Value, result: &mut Vec<&'a mut Value>) {
+    if let Some((head, tail)) = path.split_first() {
+        match (head, value) {
+            (JsonPathItem::Key(key), Value::Object(map)) => {
+                if let Some(value) = map.get_mut(key) {
+                    value_get_mut(tail, value, result);
+                }
+            }
+            (JsonPathItem::Index(index), Value::Array(array)) => {
+                if let Some(value) = array.get_mut(*index) {
+                    value_get_mut(tail, value, result);
+                }
+            }
+            (JsonPathItem::WildcardIndex, Value::Array(array)) => array
+                .iter_mut()
+                .for_each(|value| value_get_mut(tail, value, result)),
+            _ => (),
+        }
+    } else {
+        result.push(value);
+    }
+}
+
 fn value_set(path: &[JsonPathItem], dest: &mut Value, src: &serde_json::Map<String, Value>) {
     if let Some((head, rest)) = path.split_first() {
         match head {
@@ -507,6 +589,49 @@ mod tests {
         assert!(path("a.b").is_affected_by_value_remove(&path("a.b.c")));
     }
 
+    #[test]
+    fn test_value_get_mut() {
+        let mut json: serde_json::Map<String, Value> =
+            serde_json::from_str(r#"{"a": [{"b": 1}, {"b": 2}, {"c": 3}], "d": {"e": 4}}"#)
+                .unwrap();
+
+        for value in path("a[].b").value_get_mut(&mut json) {
+            *value = Value::from(value.as_i64().unwrap() * 10);
+        }
+        assert_eq!(path("d.e").value_get_mut(&mut json).len(), 1);
+        assert!(path("d.x").value_get_mut(&mut json).is_empty());
+        assert!(path("a[5]").value_get_mut(&mut json).is_empty());
+
+        assert_eq!(
+            Value::Object(json),
+            serde_json::json!({"a": [{"b": 10}, {"b": 20}, {"c": 3}], "d": {"e": 4}}),
+        );
+    }
+
+    #[test]
+    fn test_value_get_mut_under_key() {
+        let values = |path_str: &str, key_str: &str| {
+            let mut json: serde_json::Map<String, Value> =
+                serde_json::from_str(r#"{"b": [{"c": 1}, {"c": 2}], "d": 3}"#).unwrap();
+            path(path_str)
+                .value_get_mut_under_key(&path(key_str), &mut json)
+                .map(|values| values.into_iter().map(Value::take).collect::<Vec<_>>())
+        };
+
+        assert_eq!(
+            values("a.b[].c", "a"),
+            Some(vec![Value::from(1), Value::from(2)]),
+        );
+        assert_eq!(values("a.d", "a"), Some(vec![Value::from(3)]));
+        assert_eq!(values("a[].d", "a[1]"), Some(vec![Value::from(3)]));
+        assert_eq!(values("a[0].d", "a[1]"), Some(vec![]));
+        assert_eq!(values("a.e", "a"), Some(vec![]));
+        assert_eq!(values("x.d", "a"), Some(vec![]));
+        assert_eq!(values("a", "a.b"), Some(vec![]));
+        assert_eq!(values("a.b", "a.b"), None);
+        assert_eq!(values("a[]", "a[0]"), None);
+    }
+
     /// This test checks that `is_affected_by_value_set` and `is_affected_by_value_remove` don't
     /// produce false negatives.
     /// The penalty for a false positive is just degraded performance, but the penalty for a false
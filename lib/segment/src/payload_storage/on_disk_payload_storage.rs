@@ -11,6 +11,7 @@ use crate::common::Flusher;
 use crate::json_path::JsonPath;
 use crate::payload_storage::PayloadStorage;
 use crate::types::Payload;
+use crate::vector_storage::common::get_async_scorer;
 
 /// On-disk implementation of `PayloadStorage`.
 /// Persists all changes to disk using `store`, does not keep payload in memory
@@ -48,6 +49,22 @@ impl OnDiskPayloadStorage {
             .map_err(OperationError::from)
     }
 
+    /// Read payloads of multiple points at once, concurrently if async IO is enabled
+    pub fn read_payloads(
+        &self,
+        point_ids: &[PointOffsetType],
+    ) -> OperationResult<Vec<Option<Payload>>> {
+        let keys: Vec<_> = point_ids
+            .iter()
+            .map(|point_id| serde_cbor::to_vec(point_id).unwrap())
+            .collect();
+        self.db_wrapper
+            .multi_get_pinned(&keys, get_async_scorer(), |raw| serde_cbor::from_slice(raw))?
+            .into_iter()
+            .map(|payload| payload.transpose().map_err(OperationError::from))
+            .collect()
+    }
+
     pub fn iter<F>(&self, mut callback: F) -> OperationResult<()>
     where
         F: FnMut(PointOffsetType, &Payload) -> OperationResult<bool>,
@@ -110,6 +127,14 @@ impl PayloadStorage for OnDiskPayloadStorage {
         }
     }
 
+    fn payloads(&self, point_ids: &[PointOffsetType]) -> OperationResult<Vec<Payload>> {
+        Ok(self
+            .read_payloads(point_ids)?
+            .into_iter()
+            .map(Option::unwrap_or_default)
+            .collect())
+    }
+
     fn delete(&mut self, point_id: PointOffsetType, key: &JsonPath) -> OperationResult<Vec<Value>> {
         let stored_payload = self.read_payload(point_id)?;
 
@@ -86,6 +86,15 @@ impl PayloadStorage for PayloadStorageEnum {
         }
     }
 
+    fn payloads(&self, point_ids: &[PointOffsetType]) -> OperationResult<Vec<Payload>> {
+        match self {
+            #[cfg(feature = "testing")]
+            PayloadStorageEnum::InMemoryPayloadStorage(s) => s.payloads(point_ids),
+            PayloadStorageEnum::SimplePayloadStorage(s) => s.payloads(point_ids),
+            PayloadStorageEnum::OnDiskPayloadStorage(s) => s.payloads(point_ids),
+        }
+    }
+
     fn delete(&mut self, point_id: PointOffsetType, key: &JsonPath) -> OperationResult<Vec<Value>> {
         match self {
             #[cfg(feature = "testing")]
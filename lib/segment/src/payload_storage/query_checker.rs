@@ -1,12 +1,15 @@
 #![cfg_attr(not(feature = "testing"), allow(unused_imports))]
 
 use std::cell::RefCell;
+use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::ops::Deref;
+use std::str::FromStr;
 use std::sync::Arc;
 
 use atomic_refcell::AtomicRefCell;
 use common::types::PointOffsetType;
+use serde_json::Value;
 
 use crate::common::utils::{check_is_empty, check_is_null, IndexesMap};
 use crate::id_tracker::IdTrackerSS;
@@ -16,8 +19,9 @@ use crate::payload_storage::condition_checker::ValueChecker;
 use crate::payload_storage::payload_storage_enum::PayloadStorageEnum;
 use crate::payload_storage::ConditionChecker;
 use crate::types::{
-    Condition, FieldCondition, Filter, IsEmptyCondition, IsNullCondition, MinShould,
-    OwnedPayloadRef, Payload, PayloadContainer, PayloadKeyType,
+    Condition, DateTimePayloadType, FieldCondition, FieldsComparison, FieldsComparisonCondition,
+    Filter, IsEmptyCondition, IsNullCondition, MinShould, OwnedPayloadRef, Payload,
+    PayloadContainer, PayloadKeyType,
 };
 
 fn check_condition<F>(checker: &F, condition: &Condition) -> bool
@@ -127,6 +131,9 @@ where
         }
         Condition::IsEmpty(is_empty) => check_is_empty_condition(is_empty, get_payload().deref()),
         Condition::IsNull(is_null) => check_is_null_condition(is_null, get_payload().deref()),
+        Condition::Compare(compare) => {
+            check_fields_comparison_condition(compare, get_payload().deref())
+        }
         Condition::HasId(has_id) => id_tracker
             .and_then(|id_tracker| id_tracker.external_id(point_id))
             .map_or(false, |id| has_id.has_id.contains(&id)),
@@ -164,6 +171,46 @@ pub fn check_is_null_condition(is_null: &IsNullCondition, payload: &impl Payload
     check_is_null(payload.get_value(&is_null.is_null.key).iter().copied())
 }
 
+pub fn check_fields_comparison_condition(
+    condition: &FieldsComparisonCondition,
+    payload: &impl PayloadContainer,
+) -> bool {
+    let FieldsComparison { left, op, right } = &condition.compare;
+    let left_values = payload.get_value(left);
+    if left_values.is_empty() {
+        return false;
+    }
+    let right_values = payload.get_value(right);
+    left_values.iter().any(|left_value| {
+        right_values.iter().any(|right_value| {
+            compare_payload_values(left_value, right_value).map_or(false, |ord| op.check(ord))
+        })
+    })
+}
+
+/// Compare two payload values of the same kind: numbers, datetimes or keywords.
+/// Returns `None` if values are not comparable.
+fn compare_payload_values(left: &Value, right: &Value) -> Option<Ordering> {
+    match (left, right) {
+        (Value::Number(left), Value::Number(right)) => {
+            if let (Some(left), Some(right)) = (left.as_i64(), right.as_i64()) {
+                return Some(left.cmp(&right));
+            }
+            left.as_f64()?.partial_cmp(&right.as_f64()?)
+        }
+        (Value::String(left), Value::String(right)) => {
+            match (
+                DateTimePayloadType::from_str(left),
+                DateTimePayloadType::from_str(right),
+            ) {
+                (Ok(left), Ok(right)) => Some(left.timestamp().cmp(&right.timestamp())),
+                _ => Some(left.cmp(right)),
+            }
+        }
+        _ => None,
+    }
+}
+
 pub fn check_field_condition<R>(
     field_condition: &FieldCondition,
     payload: &impl PayloadContainer,
@@ -295,9 +342,59 @@ mod tests {
     use crate::payload_storage::simple_payload_storage::SimplePayloadStorage;
     use crate::payload_storage::PayloadStorage;
     use crate::types::{
-        DateTimeWrapper, FieldCondition, GeoBoundingBox, GeoPoint, PayloadField, Range, ValuesCount,
+        ComparisonOperator, DateTimeWrapper, FieldCondition, GeoBoundingBox, GeoPoint,
+        PayloadField, Range, ValuesCount,
     };
 
+    #[test]
+    fn test_fields_comparison_condition() {
+        let payload: Payload = json!({
+            "price": 450,
+            "list_price": 499.90,
+            "discounts": [10, 600],
+            "updated_at": "2024-03-02T10:00:00+01:00",
+            "indexed_at": "2024-03-02T09:30:00Z",
+            "brand": "acme",
+            "vendor": "zenith",
+            "color": ["red", "blue"],
+            "favorite_color": "blue",
+            "has_delivery": true,
+        })
+        .into();
+
+        let check = |left: &str, op: ComparisonOperator, right: &str| {
+            let condition = FieldsComparisonCondition::new(path(left), op, path(right));
+            check_fields_comparison_condition(&condition, &payload)
+        };
+
+        // Numbers, including int vs float
+        assert!(check("price", ComparisonOperator::Lt, "list_price"));
+        assert!(!check("price", ComparisonOperator::Gte, "list_price"));
+        assert!(check("price", ComparisonOperator::Eq, "price"));
+        assert!(!check("price", ComparisonOperator::Ne, "price"));
+
+        // Any pair of values may match
+        assert!(check("discounts", ComparisonOperator::Gt, "price"));
+        assert!(check("discounts", ComparisonOperator::Lt, "price"));
+
+        // Datetimes are compared as points in time, not as strings
+        assert!(check("updated_at", ComparisonOperator::Lt, "indexed_at"));
+
+        // Keywords
+        assert!(check("brand", ComparisonOperator::Lt, "vendor"));
+        assert!(check("color", ComparisonOperator::Eq, "favorite_color"));
+
+        // Incompatible or missing values never match
+        assert!(!check("price", ComparisonOperator::Ne, "brand"));
+        assert!(!check(
+            "has_delivery",
+            ComparisonOperator::Eq,
+            "has_delivery"
+        ));
+        assert!(!check("missing", ComparisonOperator::Ne, "price"));
+        assert!(!check("price", ComparisonOperator::Ne, "missing"));
+    }
+
     #[test]
     fn test_condition_checker() {
         let dir = Builder::new().prefix("db_dir").tempdir().unwrap();
@@ -13,6 +13,7 @@ use io::file_operations::{atomic_save_json, read_json};
 use itertools::Either;
 use memory::mmap_ops;
 use parking_lot::{Mutex, RwLock};
+use rand::seq::IteratorRandom as _;
 use rocksdb::DB;
 use sparse::common::sparse_vector::SparseVector;
 use tar::Builder;
@@ -33,6 +34,7 @@ use crate::entry::entry_point::SegmentEntry;
 use crate::id_tracker::IdTrackerSS;
 use crate::index::field_index::numeric_index::StreamRange;
 use crate::index::field_index::CardinalityEstimation;
+use crate::index::sparse_index::sparse_search_telemetry::SparseIndexStatistics;
 use crate::index::struct_payload_index::StructPayloadIndex;
 use crate::index::{PayloadIndex, VectorIndex, VectorIndexEnum};
 use crate::json_path::JsonPath;
@@ -216,17 +218,22 @@ impl Segment {
                     let vector = match *vector_storage {
                         VectorStorageEnum::DenseSimple(_)
                         | VectorStorageEnum::DenseSimpleByte(_)
+                        | VectorStorageEnum::DenseSimpleHalf(_)
                         | VectorStorageEnum::DenseMemmap(_)
                         | VectorStorageEnum::DenseMemmapByte(_)
+                        | VectorStorageEnum::DenseMemmapHalf(_)
                         | VectorStorageEnum::DenseAppendableMemmap(_)
-                        | VectorStorageEnum::DenseAppendableMemmapByte(_) => {
+                        | VectorStorageEnum::DenseAppendableMemmapByte(_)
+                        | VectorStorageEnum::DenseAppendableMemmapHalf(_) => {
                             Vector::from(vec![1.0; dim])
                         }
                         VectorStorageEnum::SparseSimple(_) => Vector::from(SparseVector::default()),
                         VectorStorageEnum::MultiDenseSimple(_)
                         | VectorStorageEnum::MultiDenseSimpleByte(_)
+                        | VectorStorageEnum::MultiDenseSimpleHalf(_)
                         | VectorStorageEnum::MultiDenseAppendableMemmap(_)
-                        | VectorStorageEnum::MultiDenseAppendableMemmapByte(_) => {
+                        | VectorStorageEnum::MultiDenseAppendableMemmapByte(_)
+                        | VectorStorageEnum::MultiDenseAppendableMemmapHalf(_) => {
                             Vector::from(MultiDenseVector::placeholder(dim))
                         }
                     };
@@ -639,13 +646,45 @@ impl Segment {
         with_vector: &WithVector,
     ) -> OperationResult<Vec<ScoredPoint>> {
         let id_tracker = self.id_tracker.borrow();
+
+        // Load vectors and payloads of all results at once, so reads from disk can run concurrently
+        let point_offsets: Vec<_> = internal_result.iter().map(|point| point.idx).collect();
+        match with_vector {
+            WithVector::Bool(false) => {}
+            WithVector::Bool(true) => {
+                for vector_data in self.vector_data.values() {
+                    vector_data
+                        .vector_storage
+                        .borrow()
+                        .prefetch_vectors(&point_offsets)?;
+                }
+            }
+            WithVector::Selector(vectors) => {
+                for vector_name in vectors {
+                    if let Some(vector_data) = self.vector_data.get(vector_name) {
+                        vector_data
+                            .vector_storage
+                            .borrow()
+                            .prefetch_vectors(&point_offsets)?;
+                    }
+                }
+            }
+        }
+        let mut payloads = if with_payload.enable {
+            self.payload_index.borrow().payloads(&point_offsets)?
+        } else {
+            vec![]
+        }
+        .into_iter();
+
         internal_result
             .iter()
             .filter_map(|&scored_point_offset| {
+                let payload = payloads.next();
                 let point_offset = scored_point_offset.idx;
                 let external_id = id_tracker.external_id(point_offset);
                 match external_id {
-                    Some(point_id) => Some((point_id, scored_point_offset)),
+                    Some(point_id) => Some((point_id, scored_point_offset, payload)),
                     None => {
                         log::warn!(
                             "Point with internal ID {} not found in id tracker, skipping",
@@ -655,24 +694,20 @@ impl Segment {
                     }
                 }
             })
-            .map(|(point_id, scored_point_offset)| {
+            .map(|(point_id, scored_point_offset, payload)| {
                 let point_offset = scored_point_offset.idx;
                 let point_version = id_tracker.internal_version(point_offset).ok_or_else(|| {
                     OperationError::service_error(format!(
                         "Corrupter id_tracker, no version for point {point_id}"
                     ))
                 })?;
-                let payload = if with_payload.enable {
-                    let initial_payload = self.payload_by_offset(point_offset)?;
-                    let processed_payload = if let Some(i) = &with_payload.payload_selector {
+                let payload = payload.map(|initial_payload| {
+                    if let Some(i) = &with_payload.payload_selector {
                         i.process(initial_payload)
                     } else {
                         initial_payload
-                    };
-                    Some(processed_payload)
-                } else {
-                    None
-                };
+                    }
+                });
                 let vector = match with_vector {
                     WithVector::Bool(false) => None,
                     WithVector::Bool(true) => {
@@ -1312,6 +1347,42 @@ impl SegmentEntry for Segment {
         }
     }
 
+    fn sample_filtered<'a>(
+        &'a self,
+        filter: Option<&'a Filter>,
+        limit: usize,
+    ) -> (Vec<PointIdType>, usize) {
+        let mut rng = rand::thread_rng();
+        let mut count = 0;
+        let sample = match filter {
+            None => self
+                .iter_points()
+                .inspect(|_| count += 1)
+                .choose_multiple(&mut rng, limit),
+            Some(condition) => {
+                let payload_index = self.payload_index.borrow();
+                let id_tracker = self.id_tracker.borrow();
+                if self.should_pre_filter(condition, None) {
+                    payload_index
+                        .query_points(condition)
+                        .into_iter()
+                        .filter_map(|internal_id| id_tracker.external_id(internal_id))
+                        .inspect(|_| count += 1)
+                        .choose_multiple(&mut rng, limit)
+                } else {
+                    let filter_context = payload_index.filter_context(condition);
+                    id_tracker
+                        .iter_from(None)
+                        .filter(|(_, internal_id)| filter_context.check(*internal_id))
+                        .map(|(external_id, _)| external_id)
+                        .inspect(|_| count += 1)
+                        .choose_multiple(&mut rng, limit)
+                }
+            }
+        };
+        (sample, count)
+    }
+
     fn read_range(&self, from: Option<PointIdType>, to: Option<PointIdType>) -> Vec<PointIdType> {
         let id_tracker = self.id_tracker.borrow();
         let iterator = id_tracker.iter_from(from).map(|x| x.0);
@@ -1803,6 +1874,9 @@ impl SegmentEntry for Segment {
                     VectorIndexEnum::SparseRam(sparse_index) => {
                         sparse_index.fill_idf_statistics(idf);
                     }
+                    VectorIndexEnum::SparseImmutableRam(sparse_index) => {
+                        sparse_index.fill_idf_statistics(idf);
+                    }
                     VectorIndexEnum::SparseMmap(sparse_index) => {
                         sparse_index.fill_idf_statistics(idf);
                     }
@@ -1813,6 +1887,25 @@ impl SegmentEntry for Segment {
             }
         }
     }
+
+    fn sparse_index_statistics(&self) -> HashMap<String, SparseIndexStatistics> {
+        self.vector_data
+            .iter()
+            .filter_map(|(vector_name, vector_data)| {
+                let statistics = match vector_data.vector_index.borrow().deref() {
+                    VectorIndexEnum::SparseRam(sparse_index) => sparse_index.index_statistics(),
+                    VectorIndexEnum::SparseImmutableRam(sparse_index) => {
+                        sparse_index.index_statistics()
+                    }
+                    VectorIndexEnum::SparseMmap(sparse_index) => sparse_index.index_statistics(),
+                    VectorIndexEnum::Plain(_)
+                    | VectorIndexEnum::HnswRam(_)
+                    | VectorIndexEnum::HnswMmap(_) => return None,
+                };
+                Some((vector_name.clone(), statistics))
+            })
+            .collect()
+    }
 }
 
 impl Drop for Segment {
@@ -1830,9 +1923,13 @@ mod tests {
     use super::*;
     use crate::common::check_vector;
     use crate::common::operation_error::OperationError::PointIdError;
-    use crate::data_types::vectors::{only_default_vector, DEFAULT_VECTOR_NAME};
+    use crate::data_types::vectors::{only_default_vector, VectorStruct, DEFAULT_VECTOR_NAME};
+    use crate::json_path::path;
     use crate::segment_constructor::{build_segment, load_segment};
-    use crate::types::{Distance, Indexes, SegmentConfig, VectorDataConfig, VectorStorageType};
+    use crate::types::{
+        Distance, ExtendedPointId, Indexes, PayloadSelector, PayloadStorageType, SegmentConfig,
+        VectorDataConfig, VectorStorageType,
+    };
 
     // no longer valid since users are now allowed to store arbitrary json objects.
     // TODO(gvelo): add tests for invalid payload types on indexed fields.
@@ -1883,6 +1980,7 @@ mod tests {
                     quantization_config: None,
                     multivec_config: None,
                     datatype: None,
+                    prefix_size: None,
                 },
             )]),
             sparse_vector_data: Default::default(),
@@ -1932,6 +2030,84 @@ mod tests {
         assert_eq!(search_result, search_batch_result[0].clone())
     }
 
+    #[test]
+    fn test_search_result_payloads() {
+        let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+        let dim = 2;
+        let config = SegmentConfig {
+            vector_data: HashMap::from([(
+                DEFAULT_VECTOR_NAME.to_owned(),
+                VectorDataConfig {
+                    size: dim,
+                    distance: Distance::Dot,
+                    storage_type: VectorStorageType::Memory,
+                    index: Indexes::Plain {},
+                    quantization_config: None,
+                    multivec_config: None,
+                    datatype: None,
+                    prefix_size: None,
+                },
+            )]),
+            sparse_vector_data: Default::default(),
+            payload_storage_type: PayloadStorageType::OnDisk,
+        };
+        let mut segment = build_segment(dir.path(), &config, true).unwrap();
+
+        for id in 0..10u64 {
+            let vector = [id as f32, 1.0];
+            segment
+                .upsert_point(id, id.into(), only_default_vector(&vector))
+                .unwrap();
+            // Leave some points without payload
+            if id % 3 != 0 {
+                let payload: Payload =
+                    serde_json::from_value(serde_json::json!({ "id": id, "even": id % 2 == 0 }))
+                        .unwrap();
+                segment.set_full_payload(id, id.into(), &payload).unwrap();
+            }
+        }
+        segment.delete_point(10, 4.into()).unwrap();
+
+        let query_vector = [1.0, 0.0].into();
+        let with_payload = WithPayload {
+            enable: true,
+            payload_selector: Some(PayloadSelector::new_include(vec![path("id")])),
+        };
+        let search_result = segment
+            .search(
+                DEFAULT_VECTOR_NAME,
+                &query_vector,
+                &with_payload,
+                &true.into(),
+                None,
+                10,
+                None,
+            )
+            .unwrap();
+
+        assert_eq!(search_result.len(), 9);
+        for scored_point in search_result {
+            let ExtendedPointId::NumId(id) = scored_point.id else {
+                unreachable!();
+            };
+            assert_ne!(id, 4);
+            assert_eq!(scored_point.score, id as f32);
+
+            let expected_payload: Payload = if id % 3 != 0 {
+                serde_json::from_value(serde_json::json!({ "id": id })).unwrap()
+            } else {
+                Payload::default()
+            };
+            assert_eq!(scored_point.payload, Some(expected_payload));
+
+            let vector = scored_point.vector.unwrap();
+            assert_eq!(
+                vector,
+                VectorStruct::from(only_default_vector(&[id as f32, 1.0]))
+            );
+        }
+    }
+
     #[test]
     fn test_from_filter_attributes() {
         let data = r#"
@@ -1957,6 +2133,7 @@ mod tests {
                     quantization_config: None,
                     multivec_config: None,
                     datatype: None,
+                    prefix_size: None,
                 },
             )]),
             sparse_vector_data: Default::default(),
@@ -2049,6 +2226,7 @@ mod tests {
                     quantization_config: None,
                     multivec_config: None,
                     datatype: None,
+                    prefix_size: None,
                 },
             )]),
             sparse_vector_data: Default::default(),
@@ -2146,6 +2324,7 @@ mod tests {
                     quantization_config: None,
                     multivec_config: None,
                     datatype: None,
+                    prefix_size: None,
                 },
             )]),
             sparse_vector_data: Default::default(),
@@ -2180,6 +2359,7 @@ mod tests {
                     quantization_config: None,
                     multivec_config: None,
                     datatype: None,
+                    prefix_size: None,
                 },
             )]),
             sparse_vector_data: Default::default(),
@@ -2275,6 +2455,7 @@ mod tests {
                     quantization_config: None,
                     multivec_config: None,
                     datatype: None,
+                    prefix_size: None,
                 },
             )]),
             sparse_vector_data: Default::default(),
@@ -2330,6 +2511,7 @@ mod tests {
                         quantization_config: None,
                         multivec_config: None,
                         datatype: None,
+                        prefix_size: None,
                     },
                 ),
                 (
@@ -2342,6 +2524,7 @@ mod tests {
                         quantization_config: None,
                         multivec_config: None,
                         datatype: None,
+                        prefix_size: None,
                     },
                 ),
             ]),
@@ -2440,6 +2623,7 @@ mod tests {
                         quantization_config: None,
                         multivec_config: None,
                         datatype: None,
+                        prefix_size: None,
                     },
                 ),
                 (
@@ -2452,6 +2636,7 @@ mod tests {
                         quantization_config: None,
                         multivec_config: None,
                         datatype: None,
+                        prefix_size: None,
                     },
                 ),
             ]),
@@ -2615,6 +2800,7 @@ mod tests {
                     quantization_config: None,
                     multivec_config: None,
                     datatype: None,
+                    prefix_size: None,
                 },
             )]),
             sparse_vector_data: Default::default(),
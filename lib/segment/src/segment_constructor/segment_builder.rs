@@ -5,16 +5,20 @@ use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
 
 use common::cpu::CpuPermit;
+use common::types::PointOffsetType;
 
 use super::get_vector_storage_path;
 use crate::common::error_logging::LogError;
 use crate::common::operation_error::{check_process_stopped, OperationError, OperationResult};
 use crate::entry::entry_point::SegmentEntry;
 use crate::index::hnsw_index::num_rayon_threads;
-use crate::index::{PayloadIndex, VectorIndex};
+use crate::index::hnsw_index::old_index::OldIndex;
+use crate::index::PayloadIndex;
 use crate::segment::Segment;
 use crate::segment_constructor::{build_segment, load_segment};
-use crate::types::{Indexes, PayloadFieldSchema, PayloadKeyType, SegmentConfig};
+use crate::types::{
+    Indexes, Payload, PayloadContainer, PayloadFieldSchema, PayloadKeyType, SegmentConfig,
+};
 use crate::vector_storage::quantized::quantized_vectors::QuantizedVectors;
 use crate::vector_storage::VectorStorage;
 
@@ -24,6 +28,8 @@ pub struct SegmentBuilder {
     pub destination_path: PathBuf,
     pub temp_path: PathBuf,
     pub indexed_fields: HashMap<PayloadKeyType, PayloadFieldSchema>,
+    // HNSW graphs of the largest source segment, reused when building vector indexes
+    old_indices: HashMap<String, OldIndex>,
 }
 
 impl SegmentBuilder {
@@ -42,6 +48,7 @@ impl SegmentBuilder {
             destination_path,
             temp_path,
             indexed_fields: Default::default(),
+            old_indices: Default::default(),
         })
     }
 
@@ -57,6 +64,46 @@ impl SegmentBuilder {
     /// * `bool` - if `true` - data successfully added, if `false` - process was interrupted
     ///
     pub fn update_from(&mut self, other: &Segment, stopped: &AtomicBool) -> OperationResult<bool> {
+        self.update(&[other], stopped)
+    }
+
+    /// Update current segment builder with all (not deleted) vectors and payload from `segments`
+    ///
+    /// If any of the indexed payload fields is marked as a tenant key, points of all given
+    /// segments are reordered so that points sharing the same tenant value get consecutive
+    /// internal ids. Vector storages, payload storage and the HNSW graph built later on
+    /// follow the internal id order, so per-tenant data ends up in contiguous regions.
+    ///
+    /// HNSW graphs of the largest of `segments` are kept to be reused by [`Self::build`],
+    /// so that only points of the other segments have to be inserted into the new graph.
+    ///
+    /// # Arguments
+    ///
+    /// * `segments` - segments to add into construction
+    ///
+    /// # Result
+    ///
+    /// * `bool` - if `true` - data successfully added, if `false` - process was interrupted
+    ///
+    pub fn update(&mut self, segments: &[&Segment], stopped: &AtomicBool) -> OperationResult<bool> {
+        if segments.is_empty() {
+            return Ok(true);
+        }
+
+        for segment in segments {
+            for (field, payload_schema) in segment.payload_index.borrow().indexed_fields() {
+                self.indexed_fields.insert(field, payload_schema);
+            }
+        }
+
+        let mut tenant_fields: Vec<_> = self
+            .indexed_fields
+            .iter()
+            .filter(|(_, payload_schema)| payload_schema.is_tenant())
+            .map(|(field, _)| field.clone())
+            .collect();
+        tenant_fields.sort_by_cached_key(|field| field.to_string());
+
         let self_segment = match &mut self.segment {
             Some(segment) => segment,
             None => {
@@ -65,17 +112,28 @@ impl SegmentBuilder {
                 ));
             }
         };
-        self_segment.version = Some(cmp::max(self_segment.version(), other.version()));
 
-        let other_id_tracker = other.id_tracker.borrow();
-        let other_vector_storages: HashMap<_, _> = other
-            .vector_data
+        for segment in segments {
+            self_segment.version = Some(cmp::max(self_segment.version(), segment.version()));
+        }
+
+        let other_id_trackers: Vec<_> = segments.iter().map(|s| s.id_tracker.borrow()).collect();
+        let other_vector_storages: Vec<HashMap<_, _>> = segments
             .iter()
-            .map(|(vector_name, vector_data)| {
-                (vector_name.to_owned(), vector_data.vector_storage.borrow())
+            .map(|segment| {
+                segment
+                    .vector_data
+                    .iter()
+                    .map(|(vector_name, vector_data)| {
+                        (vector_name.to_owned(), vector_data.vector_storage.borrow())
+                    })
+                    .collect()
             })
             .collect();
-        let other_payload_index = other.payload_index.borrow();
+        let other_payload_indexes: Vec<_> = segments
+            .iter()
+            .map(|segment| segment.payload_index.borrow())
+            .collect();
 
         let mut id_tracker = self_segment.id_tracker.borrow_mut();
         let mut vector_storages: HashMap<_, _> = self_segment
@@ -90,39 +148,84 @@ impl SegmentBuilder {
             .collect();
         let mut payload_index = self_segment.payload_index.borrow_mut();
 
-        if vector_storages.len() != other_vector_storages.len() {
-            return Err(OperationError::service_error(
-                format!("Self and other segments have different vector names count. Self count: {}, other count: {}", vector_storages.len(), other_vector_storages.len()),
-            ));
+        for other_vector_storages in &other_vector_storages {
+            if vector_storages.len() != other_vector_storages.len() {
+                return Err(OperationError::service_error(
+                    format!("Self and other segments have different vector names count. Self count: {}, other count: {}", vector_storages.len(), other_vector_storages.len()),
+                ));
+            }
         }
 
-        let mut new_internal_range = None;
-        for (vector_name, vector_storage) in &mut vector_storages {
+        let largest_segment = segments
+            .iter()
+            .enumerate()
+            .max_by_key(|(_, segment)| segment.available_point_count())
+            .map_or(0, |(segment_index, _)| segment_index);
+        // Internal ids of the largest segment mapped to internal ids of the new segment
+        let mut old_to_new: Vec<Option<PointOffsetType>> =
+            vec![None; other_id_trackers[largest_segment].total_point_count()];
+
+        // Points to insert as (source segment index, internal id in source segment)
+        let mut points_to_insert: Vec<(usize, PointOffsetType)> = Vec::new();
+        for (segment_index, other_id_tracker) in other_id_trackers.iter().enumerate() {
+            points_to_insert.extend(
+                other_id_tracker
+                    .iter_ids()
+                    .map(|internal_id| (segment_index, internal_id)),
+            );
+        }
+
+        if !tenant_fields.is_empty() {
             check_process_stopped(stopped)?;
-            let other_vector_storage = other_vector_storages.get(vector_name).ok_or_else(|| {
-                OperationError::service_error(format!(
-                    "Cannot update from other segment because if missing vector name {vector_name}"
-                ))
-            })?;
-            let internal_range = vector_storage.update_from(
-                other_vector_storage,
-                &mut other_id_tracker.iter_ids(),
-                stopped,
-            )?;
-            match new_internal_range.clone() {
-                Some(new_internal_range) => {
-                    if new_internal_range != internal_range {
-                        return Err(OperationError::service_error(
-                            "Internal ids range mismatch between self segment vectors and other segment vectors",
-                        ));
+            let mut tenant_keys = Vec::with_capacity(points_to_insert.len());
+            for &(segment_index, internal_id) in &points_to_insert {
+                let payload = other_payload_indexes[segment_index].payload(internal_id)?;
+                tenant_keys.push(Self::tenant_key(&payload, &tenant_fields));
+            }
+            // Stable sort keeps the original order of points within the same tenant
+            let mut order: Vec<usize> = (0..points_to_insert.len()).collect();
+            order.sort_by(|&a, &b| tenant_keys[a].cmp(&tenant_keys[b]));
+            points_to_insert = order.into_iter().map(|i| points_to_insert[i]).collect();
+        }
+
+        // Insert consecutive points of the same source segment in one batch
+        for batch in points_to_insert.chunk_by(|a, b| a.0 == b.0) {
+            let segment_index = batch[0].0;
+            let other_id_tracker = &other_id_trackers[segment_index];
+            let other_payload_index = &other_payload_indexes[segment_index];
+
+            let mut new_internal_range = None;
+            for (vector_name, vector_storage) in &mut vector_storages {
+                check_process_stopped(stopped)?;
+                let other_vector_storage = other_vector_storages[segment_index]
+                    .get(vector_name)
+                    .ok_or_else(|| {
+                        OperationError::service_error(format!(
+                            "Cannot update from other segment because if missing vector name {vector_name}"
+                        ))
+                    })?;
+                let internal_range = vector_storage.update_from(
+                    other_vector_storage,
+                    &mut batch.iter().map(|&(_, internal_id)| internal_id),
+                    stopped,
+                )?;
+                match new_internal_range.clone() {
+                    Some(new_internal_range) => {
+                        if new_internal_range != internal_range {
+                            return Err(OperationError::service_error(
+                                "Internal ids range mismatch between self segment vectors and other segment vectors",
+                            ));
+                        }
                     }
+                    None => new_internal_range = Some(internal_range.clone()),
                 }
-                None => new_internal_range = Some(internal_range.clone()),
             }
-        }
 
-        if let Some(new_internal_range) = new_internal_range {
-            let internal_id_iter = new_internal_range.zip(other_id_tracker.iter_ids());
+            let Some(new_internal_range) = new_internal_range else {
+                continue;
+            };
+
+            let internal_id_iter = new_internal_range.zip(batch.iter().map(|&(_, id)| id));
 
             for (new_internal_id, old_internal_id) in internal_id_iter {
                 check_process_stopped(stopped)?;
@@ -137,6 +240,12 @@ impl SegmentBuilder {
                         continue;
                     };
 
+                if segment_index == largest_segment {
+                    if let Some(new_id) = old_to_new.get_mut(old_internal_id as usize) {
+                        *new_id = Some(new_internal_id);
+                    }
+                }
+
                 let other_version = match other_id_tracker.internal_version(old_internal_id) {
                     Some(version) => version,
                     None => {
@@ -188,13 +297,37 @@ impl SegmentBuilder {
             }
         }
 
-        for (field, payload_schema) in other.payload_index.borrow().indexed_fields() {
-            self.indexed_fields.insert(field, payload_schema);
+        // Points which are removed later on are dropped from the graph when building the index
+        for (vector_name, vector_data) in &segments[largest_segment].vector_data {
+            check_process_stopped(stopped)?;
+            let Some(old_index) = vector_data.vector_index.borrow().old_index(&old_to_new) else {
+                continue;
+            };
+            let is_larger = self.old_indices.get(vector_name).map_or(true, |existing| {
+                existing.num_points() < old_index.num_points()
+            });
+            if is_larger {
+                self.old_indices.insert(vector_name.clone(), old_index);
+            }
         }
 
         Ok(true)
     }
 
+    /// Key used to order points by tenant: first keyword value of each tenant field
+    fn tenant_key(payload: &Payload, tenant_fields: &[PayloadKeyType]) -> Vec<Option<String>> {
+        tenant_fields
+            .iter()
+            .map(|field| {
+                payload
+                    .get_value(field)
+                    .iter()
+                    .find_map(|value| value.as_str())
+                    .map(ToOwned::to_owned)
+            })
+            .collect()
+    }
+
     pub fn build(
         mut self,
         permit: CpuPermit,
@@ -215,11 +348,12 @@ impl SegmentBuilder {
 
             Self::update_quantization(&mut segment, stopped)?;
 
-            for vector_data in segment.vector_data.values_mut() {
+            for (vector_name, vector_data) in &mut segment.vector_data {
+                let old_index = self.old_indices.remove(vector_name);
                 vector_data
                     .vector_index
                     .borrow_mut()
-                    .build_index(permit.clone(), stopped)?;
+                    .build_index_with_old_index(permit.clone(), stopped, old_index)?;
             }
 
             // We're done with CPU-intensive tasks, release CPU permit
@@ -270,9 +404,15 @@ impl SegmentBuilder {
 
                 let vector_storage = vector_data.vector_storage.borrow();
 
+                let prefix_size = config
+                    .vector_data
+                    .get(vector_name)
+                    .and_then(|vector_config| vector_config.prefix_size);
+
                 let quantized_vectors = QuantizedVectors::create(
                     &vector_storage,
                     quantization,
+                    prefix_size,
                     &vector_storage_path,
                     max_threads,
                     stopped,
@@ -34,18 +34,22 @@ use crate::types::{
 };
 use crate::vector_storage::dense::appendable_mmap_dense_vector_storage::{
     open_appendable_memmap_vector_storage, open_appendable_memmap_vector_storage_byte,
+    open_appendable_memmap_vector_storage_half,
 };
 use crate::vector_storage::dense::memmap_dense_vector_storage::{
-    open_memmap_vector_storage, open_memmap_vector_storage_byte,
+    open_memmap_vector_storage, open_memmap_vector_storage_byte, open_memmap_vector_storage_half,
 };
 use crate::vector_storage::dense::simple_dense_vector_storage::{
-    open_simple_dense_byte_vector_storage, open_simple_dense_vector_storage,
+    open_simple_dense_byte_vector_storage, open_simple_dense_half_vector_storage,
+    open_simple_dense_vector_storage,
 };
 use crate::vector_storage::multi_dense::appendable_mmap_multi_dense_vector_storage::{
     open_appendable_memmap_multi_vector_storage, open_appendable_memmap_multi_vector_storage_byte,
+    open_appendable_memmap_multi_vector_storage_half,
 };
 use crate::vector_storage::multi_dense::simple_multi_dense_vector_storage::{
     open_simple_multi_dense_vector_storage, open_simple_multi_dense_vector_storage_byte,
+    open_simple_multi_dense_vector_storage_half,
 };
 use crate::vector_storage::quantized::quantized_vectors::QuantizedVectors;
 use crate::vector_storage::simple_sparse_vector_storage::open_simple_sparse_vector_storage;
@@ -156,6 +160,16 @@ fn create_segment(
                                 stopped,
                             )?
                         }
+                        VectorStorageDatatype::Float16 => {
+                            open_simple_multi_dense_vector_storage_half(
+                                database.clone(),
+                                &db_column_name,
+                                vector_config.size,
+                                vector_config.distance,
+                                *multi_vec_config,
+                                stopped,
+                            )?
+                        }
                     }
                 } else {
                     match storage_element_type {
@@ -173,6 +187,13 @@ fn create_segment(
                             vector_config.distance,
                             stopped,
                         )?,
+                        VectorStorageDatatype::Float16 => open_simple_dense_half_vector_storage(
+                            database.clone(),
+                            &db_column_name,
+                            vector_config.size,
+                            vector_config.distance,
+                            stopped,
+                        )?,
                     }
                 }
             }
@@ -197,6 +218,14 @@ fn create_segment(
                                 *multi_vec_config,
                             )?
                         }
+                        VectorStorageDatatype::Float16 => {
+                            open_appendable_memmap_multi_vector_storage_half(
+                                &vector_storage_path,
+                                vector_config.size,
+                                vector_config.distance,
+                                *multi_vec_config,
+                            )?
+                        }
                     }
                 } else {
                     match storage_element_type {
@@ -210,6 +239,11 @@ fn create_segment(
                             vector_config.size,
                             vector_config.distance,
                         )?,
+                        VectorStorageDatatype::Float16 => open_memmap_vector_storage_half(
+                            &vector_storage_path,
+                            vector_config.size,
+                            vector_config.distance,
+                        )?,
                     }
                 }
             }
@@ -233,6 +267,14 @@ fn create_segment(
                                 *multi_vec_config,
                             )?
                         }
+                        VectorStorageDatatype::Float16 => {
+                            open_appendable_memmap_multi_vector_storage_half(
+                                &vector_storage_path,
+                                vector_config.size,
+                                vector_config.distance,
+                                *multi_vec_config,
+                            )?
+                        }
                     }
                 } else {
                     match storage_element_type {
@@ -246,6 +288,13 @@ fn create_segment(
                             vector_config.size,
                             vector_config.distance,
                         )?,
+                        VectorStorageDatatype::Float16 => {
+                            open_appendable_memmap_vector_storage_half(
+                                &vector_storage_path,
+                                vector_config.size,
+                                vector_config.distance,
+                            )?
+                        }
                     }
                 }
             }
@@ -288,6 +337,7 @@ fn create_segment(
                     quantized_vectors.clone(),
                     payload_index.clone(),
                     vector_hnsw_config.clone(),
+                    vector_config.prefix_size,
                 )?)
             } else {
                 VectorIndexEnum::HnswRam(HNSWIndex::<GraphLinksRam>::open(
@@ -297,6 +347,7 @@ fn create_segment(
                     quantized_vectors.clone(),
                     payload_index.clone(),
                     vector_hnsw_config.clone(),
+                    vector_config.prefix_size,
                 )?)
             }),
         };
@@ -340,16 +391,24 @@ fn create_segment(
                 &vector_index_path,
                 stopped,
             )?)),
-            SparseIndexType::MutableRam | SparseIndexType::ImmutableRam => {
-                sp(VectorIndexEnum::SparseRam(SparseVectorIndex::open(
+            SparseIndexType::MutableRam => sp(VectorIndexEnum::SparseRam(SparseVectorIndex::open(
+                sparse_vector_config.index,
+                id_tracker.clone(),
+                vector_storage.clone(),
+                payload_index.clone(),
+                &vector_index_path,
+                stopped,
+            )?)),
+            SparseIndexType::ImmutableRam => sp(VectorIndexEnum::SparseImmutableRam(
+                SparseVectorIndex::open(
                     sparse_vector_config.index,
                     id_tracker.clone(),
                     vector_storage.clone(),
                     payload_index.clone(),
                     &vector_index_path,
                     stopped,
-                )?))
-            }
+                )?,
+            )),
         };
 
         check_process_stopped(stopped)?;
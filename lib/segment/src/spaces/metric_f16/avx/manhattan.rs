@@ -0,0 +1,69 @@
+use std::arch::x86_64::*;
+
+use half::f16;
+
+use crate::spaces::simple_avx::hsum256_ps_avx;
+
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "f16c")]
+#[target_feature(enable = "fma")]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe fn avx_manhattan_similarity_half(v1: &[f16], v2: &[f16]) -> f32 {
+    debug_assert!(v1.len() == v2.len());
+    debug_assert!(is_x86_feature_detected!("avx"));
+    debug_assert!(is_x86_feature_detected!("f16c"));
+    debug_assert!(is_x86_feature_detected!("fma"));
+
+    let mask: __m256 = _mm256_set1_ps(-0.0f32); // 1 << 31 used to clear sign bit to mimic abs
+
+    let n = v1.len();
+    let m = n - (n % 16);
+    let mut ptr1: *const f16 = v1.as_ptr();
+    let mut ptr2: *const f16 = v2.as_ptr();
+    let mut sum256_1: __m256 = _mm256_setzero_ps();
+    let mut sum256_2: __m256 = _mm256_setzero_ps();
+    let mut i: usize = 0;
+    while i < m {
+        // convert 8x16 bit floats into 8x32 bit floats
+        let a1 = _mm256_cvtph_ps(_mm_loadu_si128(ptr1 as *const __m128i));
+        let b1 = _mm256_cvtph_ps(_mm_loadu_si128(ptr2 as *const __m128i));
+        sum256_1 = _mm256_add_ps(_mm256_andnot_ps(mask, _mm256_sub_ps(a1, b1)), sum256_1);
+
+        let a2 = _mm256_cvtph_ps(_mm_loadu_si128(ptr1.add(8) as *const __m128i));
+        let b2 = _mm256_cvtph_ps(_mm_loadu_si128(ptr2.add(8) as *const __m128i));
+        sum256_2 = _mm256_add_ps(_mm256_andnot_ps(mask, _mm256_sub_ps(a2, b2)), sum256_2);
+
+        ptr1 = ptr1.add(16);
+        ptr2 = ptr2.add(16);
+        i += 16;
+    }
+
+    let mut result = hsum256_ps_avx(sum256_1) + hsum256_ps_avx(sum256_2);
+    for (a, b) in v1[m..].iter().zip(&v2[m..]) {
+        result += (a.to_f32() - b.to_f32()).abs();
+    }
+    -result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spaces::metric_f16::simple_manhattan::manhattan_similarity_half;
+
+    #[test]
+    fn test_spaces_avx() {
+        if is_x86_feature_detected!("avx")
+            && is_x86_feature_detected!("f16c")
+            && is_x86_feature_detected!("fma")
+        {
+            let v1: Vec<f16> = (0..101).map(|i| f16::from_f32(i as f32 / 10.0)).collect();
+            let v2: Vec<f16> = (0..101).map(|i| f16::from_f32(-(i as f32) / 7.0)).collect();
+
+            let manhattan_simd = unsafe { avx_manhattan_similarity_half(&v1, &v2) };
+            let manhattan = manhattan_similarity_half(&v1, &v2);
+            assert!((manhattan_simd - manhattan).abs() / manhattan.abs() < 1e-5);
+        } else {
+            println!("avx test skipped");
+        }
+    }
+}
@@ -0,0 +1,13 @@
+pub mod simple_cosine;
+pub mod simple_dot;
+pub mod simple_euclid;
+pub mod simple_hamming;
+pub mod simple_jaccard;
+pub mod simple_manhattan;
+pub mod simple_minkowski;
+
+#[cfg(target_arch = "x86_64")]
+pub mod avx;
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+pub mod neon;
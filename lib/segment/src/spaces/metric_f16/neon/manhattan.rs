@@ -0,0 +1,65 @@
+use std::arch::aarch64::*;
+
+use half::f16;
+
+use super::convert_chunk;
+
+#[target_feature(enable = "neon")]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe fn neon_manhattan_similarity_half(v1: &[f16], v2: &[f16]) -> f32 {
+    debug_assert!(v1.len() == v2.len());
+
+    let n = v1.len();
+    let m = n - (n % 16);
+    let mut sum1 = vdupq_n_f32(0.);
+    let mut sum2 = vdupq_n_f32(0.);
+    let mut sum3 = vdupq_n_f32(0.);
+    let mut sum4 = vdupq_n_f32(0.);
+    for (chunk1, chunk2) in v1[..m].chunks_exact(16).zip(v2[..m].chunks_exact(16)) {
+        let a = convert_chunk(chunk1);
+        let b = convert_chunk(chunk2);
+        let (ptr1, ptr2) = (a.as_ptr(), b.as_ptr());
+
+        sum1 = vaddq_f32(sum1, vabdq_f32(vld1q_f32(ptr1), vld1q_f32(ptr2)));
+        sum2 = vaddq_f32(
+            sum2,
+            vabdq_f32(vld1q_f32(ptr1.add(4)), vld1q_f32(ptr2.add(4))),
+        );
+        sum3 = vaddq_f32(
+            sum3,
+            vabdq_f32(vld1q_f32(ptr1.add(8)), vld1q_f32(ptr2.add(8))),
+        );
+        sum4 = vaddq_f32(
+            sum4,
+            vabdq_f32(vld1q_f32(ptr1.add(12)), vld1q_f32(ptr2.add(12))),
+        );
+    }
+
+    let mut result = vaddvq_f32(sum1) + vaddvq_f32(sum2) + vaddvq_f32(sum3) + vaddvq_f32(sum4);
+    for (a, b) in v1[m..].iter().zip(&v2[m..]) {
+        result += (a.to_f32() - b.to_f32()).abs();
+    }
+    -result
+}
+
+#[cfg(test)]
+mod tests {
+    use std::arch::is_aarch64_feature_detected;
+
+    use super::*;
+    use crate::spaces::metric_f16::simple_manhattan::manhattan_similarity_half;
+
+    #[test]
+    fn test_spaces_neon() {
+        if is_aarch64_feature_detected!("neon") {
+            let v1: Vec<f16> = (0..101).map(|i| f16::from_f32(i as f32 / 10.0)).collect();
+            let v2: Vec<f16> = (0..101).map(|i| f16::from_f32(-(i as f32) / 7.0)).collect();
+
+            let manhattan_simd = unsafe { neon_manhattan_similarity_half(&v1, &v2) };
+            let manhattan = manhattan_similarity_half(&v1, &v2);
+            assert!((manhattan_simd - manhattan).abs() / manhattan.abs() < 1e-5);
+        } else {
+            println!("neon test skipped");
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use half::f16;
+use half::slice::HalfFloatSliceExt;
+
+pub mod dot;
+pub mod euclid;
+pub mod manhattan;
+
+/// Convert a chunk of 16 half-precision values into full precision.
+///
+/// Half-precision NEON intrinsics are not stable yet, so conversion is done by `half`,
+/// which uses hardware conversion instructions where available.
+#[inline(always)]
+fn convert_chunk(chunk: &[f16]) -> [f32; 16] {
+    let mut buffer = [0.0f32; 16];
+    chunk.convert_to_f32_slice(&mut buffer);
+    buffer
+}
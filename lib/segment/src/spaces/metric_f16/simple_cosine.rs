@@ -0,0 +1,22 @@
+use common::types::ScoreType;
+
+use crate::data_types::vectors::{DenseVector, VectorElementType, VectorElementTypeHalf};
+use crate::spaces::metric::Metric;
+use crate::spaces::simple::{CosineMetric, DotProductMetric};
+use crate::types::Distance;
+
+/// Vectors are normalized in full precision before being converted to half-precision,
+/// so cosine similarity is equivalent to the dot product.
+impl Metric<VectorElementTypeHalf> for CosineMetric {
+    fn distance() -> Distance {
+        Distance::Cosine
+    }
+
+    fn similarity(v1: &[VectorElementTypeHalf], v2: &[VectorElementTypeHalf]) -> ScoreType {
+        <DotProductMetric as Metric<VectorElementTypeHalf>>::similarity(v1, v2)
+    }
+
+    fn preprocess(vector: DenseVector) -> DenseVector {
+        <CosineMetric as Metric<VectorElementType>>::preprocess(vector)
+    }
+}
@@ -0,0 +1,34 @@
+use common::types::ScoreType;
+
+use crate::data_types::vectors::{DenseVector, VectorElementTypeHalf};
+use crate::spaces::metric::Metric;
+use crate::spaces::simple::{jaccard_ratio, JaccardMetric};
+use crate::types::Distance;
+
+impl Metric<VectorElementTypeHalf> for JaccardMetric {
+    fn distance() -> Distance {
+        Distance::Jaccard
+    }
+
+    fn similarity(v1: &[VectorElementTypeHalf], v2: &[VectorElementTypeHalf]) -> ScoreType {
+        jaccard_similarity_half(v1, v2)
+    }
+
+    fn preprocess(vector: DenseVector) -> DenseVector {
+        vector
+    }
+}
+
+pub fn jaccard_similarity_half(
+    v1: &[VectorElementTypeHalf],
+    v2: &[VectorElementTypeHalf],
+) -> ScoreType {
+    let (intersection, union) =
+        v1.iter()
+            .zip(v2)
+            .fold((0.0, 0.0), |(intersection, union), (a, b)| {
+                let (a, b) = (a.to_f32(), b.to_f32());
+                (intersection + a.min(b), union + a.max(b))
+            });
+    jaccard_ratio(intersection, union)
+}
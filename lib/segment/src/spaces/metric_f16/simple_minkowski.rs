@@ -0,0 +1,39 @@
+use common::types::ScoreType;
+
+use crate::data_types::vectors::{DenseVector, VectorElementTypeHalf};
+use crate::spaces::metric::Metric;
+use crate::spaces::simple::{minkowski_power, MinkowskiMetric, MinkowskiSimilarity};
+use crate::types::Distance;
+
+impl<const P: u32> Metric<VectorElementTypeHalf> for MinkowskiMetric<P> {
+    fn distance() -> Distance {
+        Distance::Minkowski(P)
+    }
+
+    fn similarity(v1: &[VectorElementTypeHalf], v2: &[VectorElementTypeHalf]) -> ScoreType {
+        minkowski_similarity_half::<P>(v1, v2)
+    }
+
+    fn preprocess(vector: DenseVector) -> DenseVector {
+        vector
+    }
+}
+
+pub fn minkowski_similarity_half<const P: u32>(
+    v1: &[VectorElementTypeHalf],
+    v2: &[VectorElementTypeHalf],
+) -> ScoreType {
+    -v1.iter()
+        .zip(v2)
+        .map(|(a, b)| (a.to_f32() - b.to_f32()).abs().powi(P as i32))
+        .sum::<ScoreType>()
+}
+
+impl MinkowskiSimilarity for VectorElementTypeHalf {
+    fn minkowski_similarity(v1: &[Self], v2: &[Self], p: u32) -> ScoreType {
+        -v1.iter()
+            .zip(v2)
+            .map(|(a, b)| minkowski_power((a.to_f32() - b.to_f32()).abs(), p))
+            .sum::<ScoreType>()
+    }
+}
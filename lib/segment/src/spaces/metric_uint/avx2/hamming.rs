@@ -0,0 +1,90 @@
+use std::arch::x86_64::*;
+
+/// Number of set bits in each byte, looked up by nibbles
+#[target_feature(enable = "avx2")]
+pub(super) unsafe fn popcount_bytes_avx2(v: __m256i) -> __m256i {
+    let lookup = _mm256_setr_epi8(
+        0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4, 0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3,
+        3, 4,
+    );
+    let low_mask = _mm256_set1_epi8(0x0f);
+    let low = _mm256_and_si256(v, low_mask);
+    let high = _mm256_and_si256(_mm256_srli_epi16(v, 4), low_mask);
+    _mm256_add_epi8(
+        _mm256_shuffle_epi8(lookup, low),
+        _mm256_shuffle_epi8(lookup, high),
+    )
+}
+
+/// Adds bit counts of each byte of `v` to 4x64 bit accumulator
+#[target_feature(enable = "avx2")]
+pub(super) unsafe fn accumulate_popcount_avx2(acc: __m256i, v: __m256i) -> __m256i {
+    let counts = popcount_bytes_avx2(v);
+    _mm256_add_epi64(acc, _mm256_sad_epu8(counts, _mm256_setzero_si256()))
+}
+
+#[target_feature(enable = "avx2")]
+pub(super) unsafe fn hsum_epi64_avx2(acc: __m256i) -> u64 {
+    (_mm256_extract_epi64(acc, 0)
+        + _mm256_extract_epi64(acc, 1)
+        + _mm256_extract_epi64(acc, 2)
+        + _mm256_extract_epi64(acc, 3)) as u64
+}
+
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+#[target_feature(enable = "fma")]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe fn avx_hamming_similarity_bytes(v1: &[u8], v2: &[u8]) -> f32 {
+    debug_assert!(v1.len() == v2.len());
+    debug_assert!(is_x86_feature_detected!("avx"));
+    debug_assert!(is_x86_feature_detected!("avx2"));
+    debug_assert!(is_x86_feature_detected!("fma"));
+
+    let mut ptr1: *const u8 = v1.as_ptr();
+    let mut ptr2: *const u8 = v2.as_ptr();
+
+    // number of different bits, accumulated into 4x64 bit integers
+    let mut acc = _mm256_setzero_si256();
+    let len = v1.len();
+    for _ in 0..len / 32 {
+        let p1 = _mm256_loadu_si256(ptr1 as *const __m256i);
+        let p2 = _mm256_loadu_si256(ptr2 as *const __m256i);
+        ptr1 = ptr1.add(32);
+        ptr2 = ptr2.add(32);
+
+        acc = accumulate_popcount_avx2(acc, _mm256_xor_si256(p1, p2));
+    }
+
+    let mut count = hsum_epi64_avx2(acc);
+    for _ in 0..len % 32 {
+        count += (*ptr1 ^ *ptr2).count_ones() as u64;
+        ptr1 = ptr1.add(1);
+        ptr2 = ptr2.add(1);
+    }
+
+    -(count as f32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spaces::metric_uint::simple_hamming::hamming_similarity_bytes;
+
+    #[test]
+    fn test_spaces_avx() {
+        if is_x86_feature_detected!("avx")
+            && is_x86_feature_detected!("avx2")
+            && is_x86_feature_detected!("fma")
+        {
+            let v1: Vec<u8> = (0..100).map(|i| (i * 7) as u8).collect();
+            let v2: Vec<u8> = (0..100).map(|i| (255 - i * 3) as u8).collect();
+
+            let hamming_simd = unsafe { avx_hamming_similarity_bytes(&v1, &v2) };
+            let hamming = hamming_similarity_bytes(&v1, &v2);
+            assert_eq!(hamming_simd, hamming);
+        } else {
+            println!("avx test skipped");
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use std::arch::x86_64::*;
+
+use super::hamming::{accumulate_popcount_avx2, hsum_epi64_avx2};
+use crate::spaces::simple::jaccard_ratio;
+
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+#[target_feature(enable = "fma")]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe fn avx_jaccard_similarity_bytes(v1: &[u8], v2: &[u8]) -> f32 {
+    debug_assert!(v1.len() == v2.len());
+    debug_assert!(is_x86_feature_detected!("avx"));
+    debug_assert!(is_x86_feature_detected!("avx2"));
+    debug_assert!(is_x86_feature_detected!("fma"));
+
+    let mut ptr1: *const u8 = v1.as_ptr();
+    let mut ptr2: *const u8 = v2.as_ptr();
+
+    // number of bits set in both and in any of vectors, accumulated into 4x64 bit integers
+    let mut intersection_acc = _mm256_setzero_si256();
+    let mut union_acc = _mm256_setzero_si256();
+    let len = v1.len();
+    for _ in 0..len / 32 {
+        let p1 = _mm256_loadu_si256(ptr1 as *const __m256i);
+        let p2 = _mm256_loadu_si256(ptr2 as *const __m256i);
+        ptr1 = ptr1.add(32);
+        ptr2 = ptr2.add(32);
+
+        intersection_acc = accumulate_popcount_avx2(intersection_acc, _mm256_and_si256(p1, p2));
+        union_acc = accumulate_popcount_avx2(union_acc, _mm256_or_si256(p1, p2));
+    }
+
+    let mut intersection = hsum_epi64_avx2(intersection_acc);
+    let mut union = hsum_epi64_avx2(union_acc);
+    for _ in 0..len % 32 {
+        intersection += (*ptr1 & *ptr2).count_ones() as u64;
+        union += (*ptr1 | *ptr2).count_ones() as u64;
+        ptr1 = ptr1.add(1);
+        ptr2 = ptr2.add(1);
+    }
+
+    jaccard_ratio(intersection as f32, union as f32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spaces::metric_uint::simple_jaccard::jaccard_similarity_bytes;
+
+    #[test]
+    fn test_spaces_avx() {
+        if is_x86_feature_detected!("avx")
+            && is_x86_feature_detected!("avx2")
+            && is_x86_feature_detected!("fma")
+        {
+            let v1: Vec<u8> = (0..100).map(|i| (i * 7) as u8).collect();
+            let v2: Vec<u8> = (0..100).map(|i| (255 - i * 3) as u8).collect();
+
+            let jaccard_simd = unsafe { avx_jaccard_similarity_bytes(&v1, &v2) };
+            let jaccard = jaccard_similarity_bytes(&v1, &v2);
+            assert_eq!(jaccard_simd, jaccard);
+        } else {
+            println!("avx test skipped");
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use common::types::ScoreType;
+
+use crate::data_types::vectors::{DenseVector, VectorElementTypeByte};
+use crate::spaces::metric::Metric;
+#[cfg(target_arch = "x86_64")]
+use crate::spaces::metric_uint::avx2::jaccard::avx_jaccard_similarity_bytes;
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use crate::spaces::metric_uint::neon::jaccard::neon_jaccard_similarity_bytes;
+#[cfg(target_arch = "x86_64")]
+use crate::spaces::simple::MIN_DIM_SIZE_AVX;
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use crate::spaces::simple::MIN_DIM_SIZE_SIMD;
+use crate::spaces::simple::{jaccard_ratio, JaccardMetric};
+use crate::types::Distance;
+
+/// Bytes are treated as packed bits, which form a set
+impl Metric<VectorElementTypeByte> for JaccardMetric {
+    fn distance() -> Distance {
+        Distance::Jaccard
+    }
+
+    fn similarity(v1: &[VectorElementTypeByte], v2: &[VectorElementTypeByte]) -> ScoreType {
+        #[cfg(target_arch = "x86_64")]
+        {
+            if is_x86_feature_detected!("avx")
+                && is_x86_feature_detected!("avx2")
+                && is_x86_feature_detected!("fma")
+                && v1.len() >= MIN_DIM_SIZE_AVX
+            {
+                return unsafe { avx_jaccard_similarity_bytes(v1, v2) };
+            }
+        }
+
+        #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+        {
+            if std::arch::is_aarch64_feature_detected!("neon") && v1.len() >= MIN_DIM_SIZE_SIMD {
+                return unsafe { neon_jaccard_similarity_bytes(v1, v2) };
+            }
+        }
+
+        jaccard_similarity_bytes(v1, v2)
+    }
+
+    fn preprocess(vector: DenseVector) -> DenseVector {
+        vector
+    }
+}
+
+pub fn jaccard_similarity_bytes(
+    v1: &[VectorElementTypeByte],
+    v2: &[VectorElementTypeByte],
+) -> ScoreType {
+    let (intersection, union) = v1
+        .iter()
+        .zip(v2)
+        .fold((0, 0), |(intersection, union), (a, b)| {
+            (
+                intersection + (a & b).count_ones(),
+                union + (a | b).count_ones(),
+            )
+        });
+    jaccard_ratio(intersection as ScoreType, union as ScoreType)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jaccard_over_bits() {
+        let v1: Vec<u8> = vec![0b1010_1010, 0b1111_0000];
+        let v2: Vec<u8> = vec![0b1010_0000, 0b0011_1100];
+        let score = <JaccardMetric as Metric<VectorElementTypeByte>>::similarity(&v1, &v2);
+        assert_eq!(score, 4.0 / 10.0);
+        assert_eq!(jaccard_similarity_bytes(&[0, 0], &[0, 0]), 1.0);
+    }
+}
@@ -0,0 +1,40 @@
+use common::types::ScoreType;
+
+use crate::data_types::vectors::{DenseVector, VectorElementTypeByte};
+use crate::spaces::metric::Metric;
+use crate::spaces::simple::{minkowski_power, MinkowskiMetric, MinkowskiSimilarity};
+use crate::types::Distance;
+
+impl<const P: u32> Metric<VectorElementTypeByte> for MinkowskiMetric<P> {
+    fn distance() -> Distance {
+        Distance::Minkowski(P)
+    }
+
+    fn similarity(v1: &[VectorElementTypeByte], v2: &[VectorElementTypeByte]) -> ScoreType {
+        minkowski_similarity_bytes::<P>(v1, v2)
+    }
+
+    fn preprocess(vector: DenseVector) -> DenseVector {
+        vector
+    }
+}
+
+pub fn minkowski_similarity_bytes<const P: u32>(
+    v1: &[VectorElementTypeByte],
+    v2: &[VectorElementTypeByte],
+) -> ScoreType {
+    // Powers of byte differences overflow 32 bit integers already for `P = 4`
+    -v1.iter()
+        .zip(v2)
+        .map(|(a, b)| (a.abs_diff(*b) as ScoreType).powi(P as i32))
+        .sum::<ScoreType>()
+}
+
+impl MinkowskiSimilarity for VectorElementTypeByte {
+    fn minkowski_similarity(v1: &[Self], v2: &[Self], p: u32) -> ScoreType {
+        -v1.iter()
+            .zip(v2)
+            .map(|(a, b)| minkowski_power(a.abs_diff(*b) as ScoreType, p))
+            .sum::<ScoreType>()
+    }
+}
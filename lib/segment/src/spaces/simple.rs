@@ -8,6 +8,7 @@ use super::simple_neon::*;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 use super::simple_sse::*;
 use super::tools::is_length_zero_or_normalized;
+use crate::data_types::primitive::PrimitiveVectorElement;
 use crate::data_types::vectors::{DenseVector, VectorElementType};
 use crate::types::Distance;
 
@@ -33,6 +34,23 @@ pub struct EuclidMetric;
 #[derive(Clone)]
 pub struct ManhattanMetric;
 
+#[derive(Clone)]
+pub struct HammingMetric;
+
+#[derive(Clone)]
+pub struct JaccardMetric;
+
+/// Minkowski distance of order `P`
+#[derive(Clone)]
+pub struct MinkowskiMetric<const P: u32>;
+
+/// Minkowski similarity of an order, which is only known at runtime
+///
+/// Used for orders without a dedicated [`MinkowskiMetric`] implementation.
+pub trait MinkowskiSimilarity: PrimitiveVectorElement {
+    fn minkowski_similarity(v1: &[Self], v2: &[Self], p: u32) -> ScoreType;
+}
+
 impl Metric<VectorElementType> for EuclidMetric {
     fn distance() -> Distance {
         Distance::Euclid
@@ -211,6 +229,138 @@ impl MetricPostProcessing for CosineMetric {
     }
 }
 
+impl Metric<VectorElementType> for HammingMetric {
+    fn distance() -> Distance {
+        Distance::Hamming
+    }
+
+    fn similarity(v1: &[VectorElementType], v2: &[VectorElementType]) -> ScoreType {
+        #[cfg(target_arch = "x86_64")]
+        {
+            if is_x86_feature_detected!("avx")
+                && is_x86_feature_detected!("fma")
+                && v1.len() >= MIN_DIM_SIZE_AVX
+            {
+                return unsafe { hamming_similarity_avx(v1, v2) };
+            }
+        }
+
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            if is_x86_feature_detected!("sse") && v1.len() >= MIN_DIM_SIZE_SIMD {
+                return unsafe { hamming_similarity_sse(v1, v2) };
+            }
+        }
+
+        #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+        {
+            if std::arch::is_aarch64_feature_detected!("neon") && v1.len() >= MIN_DIM_SIZE_SIMD {
+                return unsafe { hamming_similarity_neon(v1, v2) };
+            }
+        }
+
+        hamming_similarity(v1, v2)
+    }
+
+    fn preprocess(vector: DenseVector) -> DenseVector {
+        vector
+    }
+}
+
+impl MetricPostProcessing for HammingMetric {
+    fn postprocess(score: ScoreType) -> ScoreType {
+        score.abs()
+    }
+}
+
+impl Metric<VectorElementType> for JaccardMetric {
+    fn distance() -> Distance {
+        Distance::Jaccard
+    }
+
+    fn similarity(v1: &[VectorElementType], v2: &[VectorElementType]) -> ScoreType {
+        #[cfg(target_arch = "x86_64")]
+        {
+            if is_x86_feature_detected!("avx")
+                && is_x86_feature_detected!("fma")
+                && v1.len() >= MIN_DIM_SIZE_AVX
+            {
+                return unsafe { jaccard_similarity_avx(v1, v2) };
+            }
+        }
+
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            if is_x86_feature_detected!("sse") && v1.len() >= MIN_DIM_SIZE_SIMD {
+                return unsafe { jaccard_similarity_sse(v1, v2) };
+            }
+        }
+
+        #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+        {
+            if std::arch::is_aarch64_feature_detected!("neon") && v1.len() >= MIN_DIM_SIZE_SIMD {
+                return unsafe { jaccard_similarity_neon(v1, v2) };
+            }
+        }
+
+        jaccard_similarity(v1, v2)
+    }
+
+    fn preprocess(vector: DenseVector) -> DenseVector {
+        vector
+    }
+}
+
+impl MetricPostProcessing for JaccardMetric {
+    fn postprocess(score: ScoreType) -> ScoreType {
+        score
+    }
+}
+
+impl<const P: u32> Metric<VectorElementType> for MinkowskiMetric<P> {
+    fn distance() -> Distance {
+        Distance::Minkowski(P)
+    }
+
+    fn similarity(v1: &[VectorElementType], v2: &[VectorElementType]) -> ScoreType {
+        #[cfg(target_arch = "x86_64")]
+        {
+            if is_x86_feature_detected!("avx")
+                && is_x86_feature_detected!("fma")
+                && v1.len() >= MIN_DIM_SIZE_AVX
+            {
+                return unsafe { minkowski_similarity_avx::<P>(v1, v2) };
+            }
+        }
+
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            if is_x86_feature_detected!("sse") && v1.len() >= MIN_DIM_SIZE_SIMD {
+                return unsafe { minkowski_similarity_sse::<P>(v1, v2) };
+            }
+        }
+
+        #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+        {
+            if std::arch::is_aarch64_feature_detected!("neon") && v1.len() >= MIN_DIM_SIZE_SIMD {
+                return unsafe { minkowski_similarity_neon::<P>(v1, v2) };
+            }
+        }
+
+        minkowski_similarity::<P>(v1, v2)
+    }
+
+    fn preprocess(vector: DenseVector) -> DenseVector {
+        vector
+    }
+}
+
+impl<const P: u32> MetricPostProcessing for MinkowskiMetric<P> {
+    fn postprocess(score: ScoreType) -> ScoreType {
+        minkowski_postprocess(score, P)
+    }
+}
+
 pub fn euclid_similarity(v1: &[VectorElementType], v2: &[VectorElementType]) -> ScoreType {
     -v1.iter()
         .zip(v2)
@@ -225,6 +375,61 @@ pub fn manhattan_similarity(v1: &[VectorElementType], v2: &[VectorElementType])
         .sum::<ScoreType>()
 }
 
+pub fn hamming_similarity(v1: &[VectorElementType], v2: &[VectorElementType]) -> ScoreType {
+    -(v1.iter().zip(v2).filter(|(a, b)| a != b).count() as ScoreType)
+}
+
+pub fn jaccard_similarity(v1: &[VectorElementType], v2: &[VectorElementType]) -> ScoreType {
+    let (intersection, union) = v1
+        .iter()
+        .zip(v2)
+        .fold((0.0, 0.0), |(intersection, union), (a, b)| {
+            (intersection + a.min(*b), union + a.max(*b))
+        });
+    jaccard_ratio(intersection, union)
+}
+
+/// Jaccard similarity of two empty sets is 1, as they are equal
+#[inline]
+pub fn jaccard_ratio(intersection: ScoreType, union: ScoreType) -> ScoreType {
+    if union == 0.0 {
+        1.0
+    } else {
+        intersection / union
+    }
+}
+
+/// Sum of `|a - b|^P`, the `P`-th root is only taken in postprocessing
+pub fn minkowski_similarity<const P: u32>(
+    v1: &[VectorElementType],
+    v2: &[VectorElementType],
+) -> ScoreType {
+    -v1.iter()
+        .zip(v2)
+        .map(|(a, b)| (a - b).abs().powi(P as i32))
+        .sum::<ScoreType>()
+}
+
+impl MinkowskiSimilarity for VectorElementType {
+    fn minkowski_similarity(v1: &[Self], v2: &[Self], p: u32) -> ScoreType {
+        -v1.iter()
+            .zip(v2)
+            .map(|(a, b)| minkowski_power((a - b).abs(), p))
+            .sum::<ScoreType>()
+    }
+}
+
+/// Power of an absolute difference of vector elements
+///
+/// Orders above `i32::MAX` are clamped, the power is `0`, `1` or infinity for them anyway.
+pub(crate) fn minkowski_power(difference: ScoreType, p: u32) -> ScoreType {
+    difference.powi(p.min(i32::MAX as u32) as i32)
+}
+
+pub fn minkowski_postprocess(score: ScoreType, p: u32) -> ScoreType {
+    score.abs().powf(1.0 / p as ScoreType)
+}
+
 pub fn cosine_preprocess(vector: DenseVector) -> DenseVector {
     let mut length: f32 = vector.iter().map(|x| x * x).sum();
     if is_length_zero_or_normalized(length) {
@@ -250,6 +455,32 @@ mod tests {
         assert_eq!(res, vec![0.0, 0.0, 0.0, 0.0]);
     }
 
+    #[test]
+    fn test_hamming_jaccard_minkowski() {
+        let v1 = vec![1.0, 0.0, 2.0, 3.0];
+        let v2 = vec![1.0, 1.0, 0.0, 3.0];
+
+        let hamming = <HammingMetric as Metric<VectorElementType>>::similarity(&v1, &v2);
+        assert_eq!(HammingMetric::postprocess(hamming), 2.0);
+
+        // min: 1 + 0 + 0 + 3, max: 1 + 1 + 2 + 3
+        let jaccard = <JaccardMetric as Metric<VectorElementType>>::similarity(&v1, &v2);
+        assert_eq!(jaccard, 4.0 / 7.0);
+        assert_eq!(jaccard_similarity(&[0.0, 0.0], &[0.0, 0.0]), 1.0);
+
+        let minkowski = <MinkowskiMetric<3> as Metric<VectorElementType>>::similarity(&v1, &v2);
+        assert_eq!(minkowski, -9.0);
+        assert!((MinkowskiMetric::<3>::postprocess(minkowski) - 9f32.cbrt()).abs() < 1e-6);
+        assert_eq!(
+            Distance::Minkowski(3).postprocess_score(minkowski),
+            MinkowskiMetric::<3>::postprocess(minkowski),
+        );
+
+        // Orders without a dedicated metric
+        assert_eq!(VectorElementType::minkowski_similarity(&v1, &v2, 3), minkowski);
+        assert_eq!(VectorElementType::minkowski_similarity(&v1, &v2, 5), -33.0);
+    }
+
     /// If we preprocess a vector multiple times, we expect the same result.
     /// Renormalization should not produce something different.
     #[test]
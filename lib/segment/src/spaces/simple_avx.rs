@@ -2,6 +2,7 @@ use std::arch::x86_64::*;
 
 use common::types::ScoreType;
 
+use super::simple::jaccard_ratio;
 use super::tools::is_length_zero_or_normalized;
 use crate::data_types::vectors::{DenseVector, VectorElementType};
 
@@ -201,6 +202,103 @@ pub(crate) unsafe fn dot_similarity_avx(
     result
 }
 
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "fma")]
+pub(crate) unsafe fn hamming_similarity_avx(
+    v1: &[VectorElementType],
+    v2: &[VectorElementType],
+) -> ScoreType {
+    let n = v1.len();
+    let m = n - (n % 8);
+    let mut ptr1: *const f32 = v1.as_ptr();
+    let mut ptr2: *const f32 = v2.as_ptr();
+    let mut count: u32 = 0;
+    let mut i: usize = 0;
+    while i < m {
+        let neq256 = _mm256_cmp_ps(_mm256_loadu_ps(ptr1), _mm256_loadu_ps(ptr2), _CMP_NEQ_UQ);
+        count += (_mm256_movemask_ps(neq256) as u32).count_ones();
+
+        ptr1 = ptr1.add(8);
+        ptr2 = ptr2.add(8);
+        i += 8;
+    }
+
+    for i in 0..n - m {
+        if *ptr1.add(i) != *ptr2.add(i) {
+            count += 1;
+        }
+    }
+    -(count as ScoreType)
+}
+
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "fma")]
+pub(crate) unsafe fn jaccard_similarity_avx(
+    v1: &[VectorElementType],
+    v2: &[VectorElementType],
+) -> ScoreType {
+    let n = v1.len();
+    let m = n - (n % 8);
+    let mut ptr1: *const f32 = v1.as_ptr();
+    let mut ptr2: *const f32 = v2.as_ptr();
+    let mut min256: __m256 = _mm256_setzero_ps();
+    let mut max256: __m256 = _mm256_setzero_ps();
+    let mut i: usize = 0;
+    while i < m {
+        let a256 = _mm256_loadu_ps(ptr1);
+        let b256 = _mm256_loadu_ps(ptr2);
+        min256 = _mm256_add_ps(_mm256_min_ps(a256, b256), min256);
+        max256 = _mm256_add_ps(_mm256_max_ps(a256, b256), max256);
+
+        ptr1 = ptr1.add(8);
+        ptr2 = ptr2.add(8);
+        i += 8;
+    }
+
+    let mut intersection = hsum256_ps_avx(min256);
+    let mut union = hsum256_ps_avx(max256);
+    for i in 0..n - m {
+        intersection += (*ptr1.add(i)).min(*ptr2.add(i));
+        union += (*ptr1.add(i)).max(*ptr2.add(i));
+    }
+    jaccard_ratio(intersection, union)
+}
+
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "fma")]
+pub(crate) unsafe fn minkowski_similarity_avx<const P: u32>(
+    v1: &[VectorElementType],
+    v2: &[VectorElementType],
+) -> ScoreType {
+    let mask: __m256 = _mm256_set1_ps(-0.0f32); // 1 << 31 used to clear sign bit to mimic abs
+
+    let n = v1.len();
+    let m = n - (n % 8);
+    let mut ptr1: *const f32 = v1.as_ptr();
+    let mut ptr2: *const f32 = v2.as_ptr();
+    let mut sum256: __m256 = _mm256_setzero_ps();
+    let mut i: usize = 0;
+    while i < m {
+        let sub256 = _mm256_sub_ps(_mm256_loadu_ps(ptr1), _mm256_loadu_ps(ptr2));
+        let abs256 = _mm256_andnot_ps(mask, sub256);
+        let mut pow256 = abs256;
+        for _ in 1..P {
+            pow256 = _mm256_mul_ps(pow256, abs256);
+        }
+        sum256 = _mm256_add_ps(pow256, sum256);
+
+        ptr1 = ptr1.add(8);
+        ptr2 = ptr2.add(8);
+        i += 8;
+    }
+
+    let mut result = hsum256_ps_avx(sum256);
+    for i in 0..n - m {
+        result += (*ptr1.add(i) - *ptr2.add(i)).abs().powi(P as i32);
+    }
+    -result
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -232,6 +330,18 @@ mod tests {
             let manhattan = manhattan_similarity(&v1, &v2);
             assert_eq!(manhattan_simd, manhattan);
 
+            let hamming_simd = unsafe { hamming_similarity_avx(&v1, &v2) };
+            let hamming = hamming_similarity(&v1, &v2);
+            assert_eq!(hamming_simd, hamming);
+
+            let jaccard_simd = unsafe { jaccard_similarity_avx(&v1, &v2) };
+            let jaccard = jaccard_similarity(&v1, &v2);
+            assert!((jaccard_simd - jaccard).abs() < 1e-6);
+
+            let minkowski_simd = unsafe { minkowski_similarity_avx::<3>(&v1, &v2) };
+            let minkowski = minkowski_similarity::<3>(&v1, &v2);
+            assert!(((minkowski_simd - minkowski) / minkowski).abs() < 1e-6);
+
             let dot_simd = unsafe { dot_similarity_avx(&v1, &v2) };
             let dot = dot_similarity(&v1, &v2);
             assert_eq!(dot_simd, dot);
@@ -4,6 +4,8 @@ use std::arch::aarch64::*;
 #[cfg(target_feature = "neon")]
 use common::types::ScoreType;
 
+#[cfg(target_feature = "neon")]
+use super::simple::jaccard_ratio;
 use super::tools::is_length_zero_or_normalized;
 use crate::data_types::vectors::DenseVector;
 #[cfg(target_feature = "neon")]
@@ -156,6 +158,99 @@ pub(crate) unsafe fn dot_similarity_neon(
     result
 }
 
+#[cfg(target_feature = "neon")]
+pub(crate) unsafe fn hamming_similarity_neon(
+    v1: &[VectorElementType],
+    v2: &[VectorElementType],
+) -> ScoreType {
+    let n = v1.len();
+    let m = n - (n % 4);
+    let mut ptr1: *const f32 = v1.as_ptr();
+    let mut ptr2: *const f32 = v2.as_ptr();
+    // Counts lanes with equal values, all bits of a lane are set on equality
+    let mut equal = vdupq_n_u32(0);
+    let mut i: usize = 0;
+    while i < m {
+        let eq = vceqq_f32(vld1q_f32(ptr1), vld1q_f32(ptr2));
+        equal = vaddq_u32(equal, vshrq_n_u32(eq, 31));
+
+        ptr1 = ptr1.add(4);
+        ptr2 = ptr2.add(4);
+        i += 4;
+    }
+
+    let mut count = m as u32 - vaddvq_u32(equal);
+    for i in 0..n - m {
+        if *ptr1.add(i) != *ptr2.add(i) {
+            count += 1;
+        }
+    }
+    -(count as ScoreType)
+}
+
+#[cfg(target_feature = "neon")]
+pub(crate) unsafe fn jaccard_similarity_neon(
+    v1: &[VectorElementType],
+    v2: &[VectorElementType],
+) -> ScoreType {
+    let n = v1.len();
+    let m = n - (n % 4);
+    let mut ptr1: *const f32 = v1.as_ptr();
+    let mut ptr2: *const f32 = v2.as_ptr();
+    let mut min_sum = vdupq_n_f32(0.);
+    let mut max_sum = vdupq_n_f32(0.);
+    let mut i: usize = 0;
+    while i < m {
+        let a = vld1q_f32(ptr1);
+        let b = vld1q_f32(ptr2);
+        min_sum = vaddq_f32(min_sum, vminq_f32(a, b));
+        max_sum = vaddq_f32(max_sum, vmaxq_f32(a, b));
+
+        ptr1 = ptr1.add(4);
+        ptr2 = ptr2.add(4);
+        i += 4;
+    }
+
+    let mut intersection = vaddvq_f32(min_sum);
+    let mut union = vaddvq_f32(max_sum);
+    for i in 0..n - m {
+        intersection += (*ptr1.add(i)).min(*ptr2.add(i));
+        union += (*ptr1.add(i)).max(*ptr2.add(i));
+    }
+    jaccard_ratio(intersection, union)
+}
+
+#[cfg(target_feature = "neon")]
+pub(crate) unsafe fn minkowski_similarity_neon<const P: u32>(
+    v1: &[VectorElementType],
+    v2: &[VectorElementType],
+) -> ScoreType {
+    let n = v1.len();
+    let m = n - (n % 4);
+    let mut ptr1: *const f32 = v1.as_ptr();
+    let mut ptr2: *const f32 = v2.as_ptr();
+    let mut sum = vdupq_n_f32(0.);
+    let mut i: usize = 0;
+    while i < m {
+        let abs = vabsq_f32(vsubq_f32(vld1q_f32(ptr1), vld1q_f32(ptr2)));
+        let mut pow = abs;
+        for _ in 1..P {
+            pow = vmulq_f32(pow, abs);
+        }
+        sum = vaddq_f32(sum, pow);
+
+        ptr1 = ptr1.add(4);
+        ptr2 = ptr2.add(4);
+        i += 4;
+    }
+
+    let mut result = vaddvq_f32(sum);
+    for i in 0..n - m {
+        result += (*ptr1.add(i) - *ptr2.add(i)).abs().powi(P as i32);
+    }
+    -result
+}
+
 #[cfg(test)]
 mod tests {
     #[cfg(target_feature = "neon")]
@@ -182,6 +277,18 @@ mod tests {
             let manhattan = manhattan_similarity(&v1, &v2);
             assert_eq!(manhattan_simd, manhattan);
 
+            let hamming_simd = unsafe { hamming_similarity_neon(&v1, &v2) };
+            let hamming = hamming_similarity(&v1, &v2);
+            assert_eq!(hamming_simd, hamming);
+
+            let jaccard_simd = unsafe { jaccard_similarity_neon(&v1, &v2) };
+            let jaccard = jaccard_similarity(&v1, &v2);
+            assert!((jaccard_simd - jaccard).abs() < 1e-6);
+
+            let minkowski_simd = unsafe { minkowski_similarity_neon::<3>(&v1, &v2) };
+            let minkowski = minkowski_similarity::<3>(&v1, &v2);
+            assert!(((minkowski_simd - minkowski) / minkowski).abs() < 1e-6);
+
             let dot_simd = unsafe { dot_similarity_neon(&v1, &v2) };
             let dot = dot_similarity(&v1, &v2);
             assert_eq!(dot_simd, dot);
@@ -5,6 +5,7 @@ use std::arch::x86_64::*;
 
 use common::types::ScoreType;
 
+use super::simple::jaccard_ratio;
 use super::tools::is_length_zero_or_normalized;
 use crate::data_types::vectors::{DenseVector, VectorElementType};
 
@@ -192,6 +193,100 @@ pub(crate) unsafe fn dot_similarity_sse(
     result
 }
 
+#[target_feature(enable = "sse")]
+pub(crate) unsafe fn hamming_similarity_sse(
+    v1: &[VectorElementType],
+    v2: &[VectorElementType],
+) -> ScoreType {
+    let n = v1.len();
+    let m = n - (n % 4);
+    let mut ptr1: *const f32 = v1.as_ptr();
+    let mut ptr2: *const f32 = v2.as_ptr();
+    let mut count: u32 = 0;
+    let mut i: usize = 0;
+    while i < m {
+        let neq128 = _mm_cmpneq_ps(_mm_loadu_ps(ptr1), _mm_loadu_ps(ptr2));
+        count += (_mm_movemask_ps(neq128) as u32).count_ones();
+
+        ptr1 = ptr1.add(4);
+        ptr2 = ptr2.add(4);
+        i += 4;
+    }
+
+    for i in 0..n - m {
+        if *ptr1.add(i) != *ptr2.add(i) {
+            count += 1;
+        }
+    }
+    -(count as ScoreType)
+}
+
+#[target_feature(enable = "sse")]
+pub(crate) unsafe fn jaccard_similarity_sse(
+    v1: &[VectorElementType],
+    v2: &[VectorElementType],
+) -> ScoreType {
+    let n = v1.len();
+    let m = n - (n % 4);
+    let mut ptr1: *const f32 = v1.as_ptr();
+    let mut ptr2: *const f32 = v2.as_ptr();
+    let mut min128: __m128 = _mm_setzero_ps();
+    let mut max128: __m128 = _mm_setzero_ps();
+    let mut i: usize = 0;
+    while i < m {
+        let a128 = _mm_loadu_ps(ptr1);
+        let b128 = _mm_loadu_ps(ptr2);
+        min128 = _mm_add_ps(_mm_min_ps(a128, b128), min128);
+        max128 = _mm_add_ps(_mm_max_ps(a128, b128), max128);
+
+        ptr1 = ptr1.add(4);
+        ptr2 = ptr2.add(4);
+        i += 4;
+    }
+
+    let mut intersection = hsum128_ps_sse(min128);
+    let mut union = hsum128_ps_sse(max128);
+    for i in 0..n - m {
+        intersection += (*ptr1.add(i)).min(*ptr2.add(i));
+        union += (*ptr1.add(i)).max(*ptr2.add(i));
+    }
+    jaccard_ratio(intersection, union)
+}
+
+#[target_feature(enable = "sse")]
+pub(crate) unsafe fn minkowski_similarity_sse<const P: u32>(
+    v1: &[VectorElementType],
+    v2: &[VectorElementType],
+) -> ScoreType {
+    let mask: __m128 = _mm_set1_ps(-0.0f32); // 1 << 31 used to clear sign bit to mimic abs
+
+    let n = v1.len();
+    let m = n - (n % 4);
+    let mut ptr1: *const f32 = v1.as_ptr();
+    let mut ptr2: *const f32 = v2.as_ptr();
+    let mut sum128: __m128 = _mm_setzero_ps();
+    let mut i: usize = 0;
+    while i < m {
+        let sub128 = _mm_sub_ps(_mm_loadu_ps(ptr1), _mm_loadu_ps(ptr2));
+        let abs128 = _mm_andnot_ps(mask, sub128);
+        let mut pow128 = abs128;
+        for _ in 1..P {
+            pow128 = _mm_mul_ps(pow128, abs128);
+        }
+        sum128 = _mm_add_ps(pow128, sum128);
+
+        ptr1 = ptr1.add(4);
+        ptr2 = ptr2.add(4);
+        i += 4;
+    }
+
+    let mut result = hsum128_ps_sse(sum128);
+    for i in 0..n - m {
+        result += (*ptr1.add(i) - *ptr2.add(i)).abs().powi(P as i32);
+    }
+    -result
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -221,6 +316,18 @@ mod tests {
             let manhattan = manhattan_similarity(&v1, &v2);
             assert_eq!(manhattan_simd, manhattan);
 
+            let hamming_simd = unsafe { hamming_similarity_sse(&v1, &v2) };
+            let hamming = hamming_similarity(&v1, &v2);
+            assert_eq!(hamming_simd, hamming);
+
+            let jaccard_simd = unsafe { jaccard_similarity_sse(&v1, &v2) };
+            let jaccard = jaccard_similarity(&v1, &v2);
+            assert!((jaccard_simd - jaccard).abs() < 1e-6);
+
+            let minkowski_simd = unsafe { minkowski_similarity_sse::<3>(&v1, &v2) };
+            let minkowski = minkowski_similarity::<3>(&v1, &v2);
+            assert!(((minkowski_simd - minkowski) / minkowski).abs() < 1e-6);
+
             let dot_simd = unsafe { dot_similarity_sse(&v1, &v2) };
             let dot = dot_similarity(&v1, &v2);
             assert_eq!(dot_simd, dot);
@@ -1,6 +1,7 @@
 use std::cmp::Reverse;
 
 use common::fixed_length_priority_queue::FixedLengthPriorityQueue;
+use rand::Rng;
 
 /// Check if the length is zero or normalized enough.
 ///
@@ -53,6 +54,39 @@ pub fn peek_top_scores<E: Ord + Clone>(scores: &[E], top: usize) -> Vec<E> {
     peek_top_largest_iterable(scores.iter().cloned(), top)
 }
 
+/// Merge uniform samples of disjoint sets into a uniform sample of their union.
+///
+/// Each sample comes with the size of the set it was taken from, and must contain
+/// `min(limit, size)` elements of it. Returns the merged sample and the size of the union.
+pub fn merge_samples<E, R: Rng + ?Sized>(
+    samples: Vec<(Vec<E>, usize)>,
+    limit: usize,
+    rng: &mut R,
+) -> (Vec<E>, usize) {
+    let total_count: usize = samples.iter().map(|(_, count)| count).sum();
+    let mut samples = samples;
+    let mut remaining_count = total_count;
+    let mut merged = Vec::with_capacity(limit.min(total_count));
+    while merged.len() < limit && remaining_count > 0 {
+        // Pick a set proportionally to the number of its elements which are not picked yet
+        let mut pick = rng.gen_range(0..remaining_count);
+        let (sample, count) = samples
+            .iter_mut()
+            .find(|(_, count)| {
+                if pick < *count {
+                    return true;
+                }
+                pick -= *count;
+                false
+            })
+            .expect("pick is less than the remaining count");
+        merged.push(sample.swap_remove(rng.gen_range(0..sample.len())));
+        *count -= 1;
+        remaining_count -= 1;
+    }
+    (merged, total_count)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -70,4 +104,25 @@ mod tests {
         let res = peek_top_smallest_iterable(data, 3);
         assert_eq!(res, vec![5, 10, 20]);
     }
+
+    #[test]
+    fn test_merge_samples() {
+        let mut rng = rand::thread_rng();
+
+        // Sets smaller than the limit are taken entirely
+        let (mut merged, count) = merge_samples(vec![(vec![1, 2], 2), (vec![3], 1)], 10, &mut rng);
+        merged.sort_unstable();
+        assert_eq!((merged, count), (vec![1, 2, 3], 3));
+
+        // Bigger sets are picked proportionally to their size
+        let mut picked_small = 0;
+        for _ in 0..1000 {
+            let (merged, count) =
+                merge_samples(vec![(vec![0, 1], 100), (vec![2, 3], 900)], 2, &mut rng);
+            assert_eq!(merged.len(), 2);
+            assert_eq!(count, 1000);
+            picked_small += merged.iter().filter(|&&e| e < 2).count();
+        }
+        assert!((100..300).contains(&picked_small), "{picked_small}");
+    }
 }
@@ -3,6 +3,7 @@ use serde::Serialize;
 
 use crate::common::anonymize::Anonymize;
 use crate::common::operation_time_statistics::OperationDurationStatistics;
+use crate::index::sparse_index::sparse_search_telemetry::SparseIndexStats;
 use crate::types::{
     PayloadIndexInfo, SegmentConfig, SegmentInfo, SparseVectorDataConfig, VectorDataConfig,
     VectorDataInfo,
@@ -69,6 +70,9 @@ pub struct VectorIndexSearchesTelemetry {
 
     #[serde(skip_serializing_if = "OperationDurationStatistics::is_empty")]
     pub unfiltered_exact: OperationDurationStatistics,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sparse_index: Option<SparseIndexStats>,
 }
 
 impl Anonymize for SegmentTelemetry {
@@ -139,6 +143,7 @@ impl Anonymize for VectorDataConfig {
             quantization_config: self.quantization_config.clone(),
             multivec_config: self.multivec_config,
             datatype: self.datatype,
+            prefix_size: self.prefix_size,
         }
     }
 }
@@ -164,6 +169,7 @@ impl Anonymize for VectorIndexSearchesTelemetry {
             filtered_exact: self.filtered_exact.anonymize(),
             filtered_sparse: self.filtered_sparse.anonymize(),
             unfiltered_exact: self.filtered_exact.anonymize(),
+            sparse_index: self.sparse_index.anonymize(),
         }
     }
 }
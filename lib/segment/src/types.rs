@@ -13,6 +13,7 @@ use geo::prelude::HaversineDistance;
 use geo::{Contains, Coord, LineString, Point, Polygon};
 use indexmap::IndexSet;
 use itertools::Itertools;
+use num_traits::FromPrimitive;
 use ordered_float::OrderedFloat;
 use schemars::JsonSchema;
 use serde::{Deserialize, Deserializer, Serialize};
@@ -23,13 +24,18 @@ use validator::{Validate, ValidationError, ValidationErrors};
 
 use crate::common::operation_error::{OperationError, OperationResult};
 use crate::common::utils::{self, MultiValue};
+use crate::data_types::date_math;
 use crate::data_types::integer_index::IntegerIndexParams;
+use crate::data_types::keyword_index::KeywordIndexParams;
 use crate::data_types::text_index::TextIndexParams;
 use crate::data_types::vectors::{VectorElementType, VectorStruct};
 use crate::index::sparse_index::sparse_index_config::{SparseIndexConfig, SparseIndexType};
 use crate::json_path::{JsonPath, JsonPathInterface};
 use crate::spaces::metric::MetricPostProcessing;
-use crate::spaces::simple::{CosineMetric, DotProductMetric, EuclidMetric, ManhattanMetric};
+use crate::spaces::simple::{
+    minkowski_postprocess, CosineMetric, DotProductMetric, EuclidMetric, HammingMetric,
+    JaccardMetric, ManhattanMetric,
+};
 use crate::vector_storage::simple_sparse_vector_storage::SPARSE_VECTOR_DISTANCE;
 
 pub type PayloadKeyType = JsonPath;
@@ -54,6 +60,14 @@ impl DateTimeWrapper {
     pub fn timestamp(&self) -> i64 {
         self.0.timestamp_micros()
     }
+
+    /// Parse either an absolute datetime or a relative date math expression, like `now-7d/d`.
+    ///
+    /// Relative expressions are resolved against the current time once, when the request is
+    /// parsed on the receiving node, so all shards see the same absolute value.
+    pub fn from_expression(s: &str) -> Result<Self, String> {
+        date_math::resolve_datetime_expression(s, chrono::Utc::now())
+    }
 }
 
 impl<'de> Deserialize<'de> for DateTimeWrapper {
@@ -62,6 +76,13 @@ impl<'de> Deserialize<'de> for DateTimeWrapper {
         D: Deserializer<'de>,
     {
         let str_datetime = <&str>::deserialize(deserializer)?;
+        if date_math::is_date_math(str_datetime) {
+            return DateTimeWrapper::from_expression(str_datetime).map_err(|err| {
+                serde::de::Error::custom(format!(
+                    "'{str_datetime}' is not a valid date math expression: {err}"
+                ))
+            });
+        }
         let parse_result = DateTimePayloadType::from_str(str_datetime).ok();
         match parse_result {
             Some(datetime) => Ok(datetime),
@@ -149,9 +170,7 @@ impl<'de> serde::Deserialize<'de> for ExtendedPointId {
 pub type PointIdType = ExtendedPointId;
 
 /// Type of internal tags, build from payload
-#[derive(
-    Debug, Deserialize, Serialize, JsonSchema, Clone, Copy, FromPrimitive, PartialEq, Eq, Hash,
-)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Eq, Hash)]
 /// Distance function types used to compare vectors
 pub enum Distance {
     // <https://en.wikipedia.org/wiki/Cosine_similarity>
@@ -162,6 +181,40 @@ pub enum Distance {
     Dot,
     // <https://simple.wikipedia.org/wiki/Manhattan_distance>
     Manhattan,
+    // <https://en.wikipedia.org/wiki/Hamming_distance>
+    // Number of different bits for `Uint8` vectors, number of different elements otherwise
+    Hamming,
+    // <https://en.wikipedia.org/wiki/Jaccard_index>
+    // Over set bits for `Uint8` vectors, weighted over non-negative elements otherwise
+    Jaccard,
+    // <https://en.wikipedia.org/wiki/Minkowski_distance>
+    // Order `p` must be within `1..=MAX_MINKOWSKI_ORDER`, orders 3 and 4 have SIMD implementations
+    Minkowski(u32),
+}
+
+/// Highest supported order of Minkowski distance
+///
+/// Powers of element differences overflow `f32` scores for higher orders,
+/// and the distance is indistinguishable from the largest element difference anyway.
+pub const MAX_MINKOWSKI_ORDER: u32 = 16;
+
+/// `Minkowski` carries its order, so only the other distances have a primitive representation
+impl FromPrimitive for Distance {
+    fn from_i64(n: i64) -> Option<Self> {
+        u64::try_from(n).ok().and_then(Self::from_u64)
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        match n {
+            0 => Some(Distance::Cosine),
+            1 => Some(Distance::Euclid),
+            2 => Some(Distance::Dot),
+            3 => Some(Distance::Manhattan),
+            4 => Some(Distance::Hamming),
+            5 => Some(Distance::Jaccard),
+            _ => None,
+        }
+    }
 }
 
 impl Distance {
@@ -171,13 +224,39 @@ impl Distance {
             Distance::Euclid => EuclidMetric::postprocess(score),
             Distance::Dot => DotProductMetric::postprocess(score),
             Distance::Manhattan => ManhattanMetric::postprocess(score),
+            Distance::Hamming => HammingMetric::postprocess(score),
+            Distance::Jaccard => JaccardMetric::postprocess(score),
+            Distance::Minkowski(p) => minkowski_postprocess(score, *p),
         }
     }
 
     pub fn distance_order(&self) -> Order {
         match self {
-            Distance::Cosine | Distance::Dot => Order::LargeBetter,
-            Distance::Euclid | Distance::Manhattan => Order::SmallBetter,
+            Distance::Cosine | Distance::Dot | Distance::Jaccard => Order::LargeBetter,
+            Distance::Euclid | Distance::Manhattan | Distance::Hamming | Distance::Minkowski(_) => {
+                Order::SmallBetter
+            }
+        }
+    }
+
+    /// Whether vectors compared with this distance can be quantized
+    ///
+    /// Jaccard and Minkowski distances have no close counterpart among the distances supported by
+    /// quantization, so their vectors are never quantized.
+    pub fn is_quantizable(&self) -> bool {
+        !matches!(self, Distance::Jaccard | Distance::Minkowski(_))
+    }
+
+    /// Checks if the distance parameters are valid
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            Distance::Minkowski(0) => {
+                Err("Minkowski distance order must be at least 1".to_string())
+            }
+            Distance::Minkowski(p) if *p > MAX_MINKOWSKI_ORDER => Err(format!(
+                "Minkowski distance order must be at most {MAX_MINKOWSKI_ORDER}, got {p}"
+            )),
+            _ => Ok(()),
         }
     }
 
@@ -274,6 +353,11 @@ impl PayloadIndexInfo {
                     params: Some(schema_params),
                     points: points_count,
                 },
+                PayloadSchemaParams::Keyword(_) => PayloadIndexInfo {
+                    data_type: PayloadSchemaType::Keyword,
+                    params: Some(schema_params),
+                    points: points_count,
+                },
             },
         }
     }
@@ -452,6 +536,7 @@ pub enum CompressionRatio {
 pub enum ScalarType {
     #[default]
     Int8,
+    Int4,
 }
 
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone, PartialEq)]
@@ -459,6 +544,7 @@ pub enum ScalarType {
 pub struct ScalarQuantizationConfig {
     /// Type of quantization to use
     /// If `int8` - 8 bit quantization will be used
+    /// If `int4` - 4 bit quantization will be used, two dimensions are packed into a byte
     pub r#type: ScalarType,
     /// Quantile for quantization. Expected value range in [0.5, 1.0]. If not set - use the whole range of values
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -526,6 +612,50 @@ impl Eq for ScalarQuantizationConfig {}
 pub struct BinaryQuantizationConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub always_ram: Option<bool>,
+    /// Number of bits used to encode each dimension of stored vectors.
+    /// Default: one_bit
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encoding: Option<BinaryQuantizationEncoding>,
+    /// Encoding of the query vector.
+    /// Scalar encodings keep the query in higher precision and score it asymmetrically against the binary codes.
+    /// Default: same as the encoding of stored vectors
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_encoding: Option<BinaryQuantizationQueryEncoding>,
+}
+
+impl BinaryQuantizationConfig {
+    /// Whether the configuration requires anything beyond plain 1-bit symmetric encoding
+    pub fn is_multi_bit(&self) -> bool {
+        self.encoding.unwrap_or_default() != BinaryQuantizationEncoding::OneBit
+            || self.query_encoding.is_some_and(|query_encoding| {
+                query_encoding != BinaryQuantizationQueryEncoding::Binary
+            })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum BinaryQuantizationEncoding {
+    /// Sign of each dimension, 1 bit per dimension
+    #[default]
+    OneBit,
+    /// Sign of each dimension plus a magnitude bit shared by each pair of dimensions
+    OneAndHalfBits,
+    /// Sign and magnitude of each dimension, 2 bits per dimension
+    TwoBits,
+}
+
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum BinaryQuantizationQueryEncoding {
+    /// Encode the query in the same way as stored vectors
+    Binary,
+    /// Quantize the query into 4-bit integers
+    #[serde(rename = "scalar4bits")]
+    Scalar4Bits,
+    /// Quantize the query into 8-bit integers
+    #[serde(rename = "scalar8bits")]
+    Scalar8Bits,
 }
 
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone, PartialEq, Eq, Hash)]
@@ -735,6 +865,8 @@ pub enum VectorStorageDatatype {
     Float32,
     // Unsigned 8-bit integer
     Uint8,
+    // Half-precision floating point
+    Float16,
 }
 
 #[derive(Debug, Default, Deserialize, Serialize, JsonSchema, Eq, PartialEq, Copy, Clone, Hash)]
@@ -742,13 +874,141 @@ pub enum VectorStorageDatatype {
 pub struct MultiVectorConfig {
     /// How to compare multivector points
     pub comparator: MultiVectorComparator,
+    /// If set, a fixed dimensional encoding of each multivector is maintained next to it.
+    /// Full scan search first selects candidates by the encodings, and only compares them with
+    /// the comparator.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fde: Option<FdeConfig>,
+}
+
+impl MultiVectorConfig {
+    /// Checks if the configuration is applicable to vectors of the given distance
+    pub fn validate(&self, distance: Distance) -> Result<(), String> {
+        if self.comparator == MultiVectorComparator::MeanPooledCosine
+            && !matches!(distance, Distance::Cosine | Distance::Dot)
+        {
+            return Err(format!(
+                "MeanPooledCosine comparator scores by cosine similarity, it is not supported for {distance:?} distance"
+            ));
+        }
+        let Some(fde) = &self.fde else {
+            return Ok(());
+        };
+        if !matches!(distance, Distance::Cosine | Distance::Dot) {
+            return Err(format!(
+                "Fixed dimensional encoding approximates dot product, it is not supported for {distance:?} distance"
+            ));
+        }
+        if !matches!(
+            self.comparator,
+            MultiVectorComparator::MaxSim | MultiVectorComparator::MaxSimNormalized
+        ) {
+            return Err(format!(
+                "Fixed dimensional encoding approximates MaxSim, it is not supported for {:?} comparator",
+                self.comparator
+            ));
+        }
+        fde.validate()
+    }
 }
 
 #[derive(Debug, Default, Deserialize, Serialize, JsonSchema, Eq, PartialEq, Copy, Clone, Hash)]
 #[serde(rename_all = "snake_case")]
 pub enum MultiVectorComparator {
+    /// Sum over query vectors of the best similarity with any of the point vectors
     #[default]
     MaxSim,
+    /// Cosine similarity of the mean query vector and the mean point vector.
+    /// Only supported for `Cosine` and `Dot` distances.
+    MeanPooledCosine,
+    /// `max_sim`, divided by the number of query vectors
+    MaxSimNormalized,
+    /// Average of `max_sim_normalized` in both directions, from query to point and from point to query
+    SymmetricMaxSim,
+}
+
+pub const FDE_MAX_SIMHASH_BITS: u32 = 10;
+
+/// MUVERA fixed dimensional encoding of multivectors, see <https://arxiv.org/abs/2405.19504>
+///
+/// Each repetition partitions the space with random hyperplanes into `2^simhash_bits` buckets and
+/// aggregates vectors per bucket. The dot product of encodings approximates MaxSim.
+/// Encoding has `repetitions * 2^simhash_bits * projection_dim` dimensions.
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Eq, PartialEq, Copy, Clone, Hash)]
+#[serde(rename_all = "snake_case")]
+pub struct FdeConfig {
+    /// Number of independent partitionings, concatenated in the encoding. Default: 10
+    #[serde(default = "default_fde_repetitions")]
+    pub repetitions: u32,
+    /// Number of random hyperplanes of each partitioning. Default: 4
+    #[serde(default = "default_fde_simhash_bits")]
+    pub simhash_bits: u32,
+    /// Vectors of each bucket are reduced to this many dimensions with a random projection.
+    /// Default: 16
+    #[serde(default = "default_fde_projection_dim")]
+    pub projection_dim: u32,
+    /// Seed of the random hyperplanes and projections. Default: 42
+    #[serde(default = "default_fde_seed")]
+    pub seed: u64,
+    /// Number of candidates selected by the encodings for each requested result. Default: 10
+    #[serde(default = "default_fde_prefetch_factor")]
+    pub prefetch_factor: u32,
+}
+
+const fn default_fde_repetitions() -> u32 {
+    10
+}
+
+const fn default_fde_simhash_bits() -> u32 {
+    4
+}
+
+const fn default_fde_projection_dim() -> u32 {
+    16
+}
+
+const fn default_fde_seed() -> u64 {
+    42
+}
+
+const fn default_fde_prefetch_factor() -> u32 {
+    10
+}
+
+impl Default for FdeConfig {
+    fn default() -> Self {
+        Self {
+            repetitions: default_fde_repetitions(),
+            simhash_bits: default_fde_simhash_bits(),
+            projection_dim: default_fde_projection_dim(),
+            seed: default_fde_seed(),
+            prefetch_factor: default_fde_prefetch_factor(),
+        }
+    }
+}
+
+impl FdeConfig {
+    fn validate(&self) -> Result<(), String> {
+        if self.repetitions == 0 {
+            return Err("Fixed dimensional encoding requires at least one repetition".to_string());
+        }
+        if self.simhash_bits > FDE_MAX_SIMHASH_BITS {
+            return Err(format!(
+                "Fixed dimensional encoding supports at most {FDE_MAX_SIMHASH_BITS} simhash bits"
+            ));
+        }
+        if self.projection_dim == 0 {
+            return Err(
+                "Projection dimension of fixed dimensional encoding must be positive".to_string(),
+            );
+        }
+        if self.prefetch_factor == 0 {
+            return Err(
+                "Prefetch factor of fixed dimensional encoding must be positive".to_string(),
+            );
+        }
+        Ok(())
+    }
 }
 
 impl VectorStorageType {
@@ -781,6 +1041,9 @@ pub struct VectorDataConfig {
     /// Vector specific configuration to set specific storage element type
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub datatype: Option<VectorStorageDatatype>,
+    /// If set, index and quantization are built over only this many first dimensions of the vectors
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prefix_size: Option<usize>,
 }
 
 impl VectorDataConfig {
@@ -1097,6 +1360,7 @@ pub enum PayloadSchemaType {
 pub enum PayloadSchemaParams {
     Text(TextIndexParams),
     Integer(IntegerIndexParams),
+    Keyword(KeywordIndexParams),
 }
 
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Hash, Eq)]
@@ -1117,7 +1381,8 @@ impl PayloadFieldSchema {
             | PayloadFieldSchema::FieldType(PayloadSchemaType::Keyword)
             | PayloadFieldSchema::FieldType(PayloadSchemaType::Text)
             | PayloadFieldSchema::FieldType(PayloadSchemaType::Geo)
-            | PayloadFieldSchema::FieldParams(PayloadSchemaParams::Text(_)) => false,
+            | PayloadFieldSchema::FieldParams(PayloadSchemaParams::Text(_))
+            | PayloadFieldSchema::FieldParams(PayloadSchemaParams::Keyword(_)) => false,
 
             PayloadFieldSchema::FieldParams(PayloadSchemaParams::Integer(IntegerIndexParams {
                 range,
@@ -1125,6 +1390,34 @@ impl PayloadFieldSchema {
             })) => *range,
         }
     }
+
+    /// Type of the values indexed with this schema
+    pub fn data_type(&self) -> PayloadSchemaType {
+        match self {
+            PayloadFieldSchema::FieldType(data_type) => *data_type,
+            PayloadFieldSchema::FieldParams(PayloadSchemaParams::Text(_)) => {
+                PayloadSchemaType::Text
+            }
+            PayloadFieldSchema::FieldParams(PayloadSchemaParams::Integer(_)) => {
+                PayloadSchemaType::Integer
+            }
+            PayloadFieldSchema::FieldParams(PayloadSchemaParams::Keyword(_)) => {
+                PayloadSchemaType::Keyword
+            }
+        }
+    }
+
+    /// Whether the field is marked as a tenant key, so points are grouped by its value
+    pub fn is_tenant(&self) -> bool {
+        match self {
+            PayloadFieldSchema::FieldParams(PayloadSchemaParams::Keyword(params)) => {
+                params.is_tenant()
+            }
+            PayloadFieldSchema::FieldType(_)
+            | PayloadFieldSchema::FieldParams(PayloadSchemaParams::Text(_))
+            | PayloadFieldSchema::FieldParams(PayloadSchemaParams::Integer(_)) => false,
+        }
+    }
 }
 
 impl From<PayloadSchemaType> for PayloadFieldSchema {
@@ -1148,9 +1441,17 @@ impl TryFrom<PayloadIndexInfo> for PayloadFieldSchema {
             (PayloadSchemaType::Integer, PayloadSchemaParams::Integer(params)) => Ok(
                 PayloadFieldSchema::FieldParams(PayloadSchemaParams::Integer(params)),
             ),
-            (data_type, PayloadSchemaParams::Integer(_) | PayloadSchemaParams::Text(_)) => Err(
-                format!("Payload field with type {data_type:?} has unexpected params"),
+            (PayloadSchemaType::Keyword, PayloadSchemaParams::Keyword(params)) => Ok(
+                PayloadFieldSchema::FieldParams(PayloadSchemaParams::Keyword(params)),
             ),
+            (
+                data_type,
+                PayloadSchemaParams::Integer(_)
+                | PayloadSchemaParams::Text(_)
+                | PayloadSchemaParams::Keyword(_),
+            ) => Err(format!(
+                "Payload field with type {data_type:?} has unexpected params"
+            )),
         }
     }
 }
@@ -1823,6 +2124,64 @@ impl From<JsonPath> for IsEmptyCondition {
     }
 }
 
+/// Operator used to compare values of two payload fields
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum ComparisonOperator {
+    /// left < right
+    Lt,
+    /// left <= right
+    Lte,
+    /// left > right
+    Gt,
+    /// left >= right
+    Gte,
+    /// left == right
+    Eq,
+    /// left != right
+    Ne,
+}
+
+impl ComparisonOperator {
+    pub fn check(&self, ordering: Ordering) -> bool {
+        match self {
+            ComparisonOperator::Lt => ordering.is_lt(),
+            ComparisonOperator::Lte => ordering.is_le(),
+            ComparisonOperator::Gt => ordering.is_gt(),
+            ComparisonOperator::Gte => ordering.is_ge(),
+            ComparisonOperator::Eq => ordering.is_eq(),
+            ComparisonOperator::Ne => ordering.is_ne(),
+        }
+    }
+}
+
+/// Compare values of two payload fields of the same point
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq)]
+pub struct FieldsComparison {
+    /// Payload key of the left-hand side value
+    pub left: PayloadKeyType,
+    /// How to compare the left value with the right one
+    pub op: ComparisonOperator,
+    /// Payload key of the right-hand side value
+    pub right: PayloadKeyType,
+}
+
+/// Select points, where values of two payload fields satisfy the comparison.
+/// Numbers, datetimes and keywords can be compared, values of other types never match.
+/// If any of the fields has multiple values, the condition is satisfied if any pair of values matches.
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq)]
+pub struct FieldsComparisonCondition {
+    pub compare: FieldsComparison,
+}
+
+impl FieldsComparisonCondition {
+    pub fn new(left: PayloadKeyType, op: ComparisonOperator, right: PayloadKeyType) -> Self {
+        Self {
+            compare: FieldsComparison { left, op, right },
+        }
+    }
+}
+
 /// ID-based filtering condition
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq)]
 pub struct HasIdCondition {
@@ -1884,6 +2243,8 @@ pub enum Condition {
     HasId(HasIdCondition),
     /// Nested filters
     Nested(NestedCondition),
+    /// Compare values of two payload fields
+    Compare(FieldsComparisonCondition),
     /// Nested filter
     Filter(Filter),
 }
@@ -1900,7 +2261,10 @@ impl Condition {
 impl Validate for Condition {
     fn validate(&self) -> Result<(), ValidationErrors> {
         match self {
-            Condition::HasId(_) | Condition::IsEmpty(_) | Condition::IsNull(_) => Ok(()),
+            Condition::HasId(_)
+            | Condition::IsEmpty(_)
+            | Condition::IsNull(_)
+            | Condition::Compare(_) => Ok(()),
             Condition::Field(field_condition) => field_condition.validate(),
             Condition::Nested(nested_condition) => nested_condition.validate(),
             Condition::Filter(filter) => filter.validate(),
@@ -2266,6 +2630,18 @@ mod tests {
         assert_eq!(record, de_record);
     }
 
+    #[test]
+    fn test_minkowski_order_validation() {
+        assert!(Distance::Minkowski(0).validate().is_err());
+        assert!(Distance::Minkowski(1).validate().is_ok());
+        assert!(Distance::Minkowski(MAX_MINKOWSKI_ORDER).validate().is_ok());
+        assert!(Distance::Minkowski(MAX_MINKOWSKI_ORDER + 1)
+            .validate()
+            .is_err());
+        assert!(Distance::Minkowski(u32::MAX).validate().is_err());
+        assert!(Distance::Cosine.validate().is_ok());
+    }
+
     #[test]
     #[ignore]
     fn test_rmp_vs_cbor_deserialize() {
@@ -2305,6 +2681,20 @@ mod tests {
         assert_eq!(datetime.timestamp(), datetime_no_z.timestamp());
     }
 
+    #[test]
+    fn test_datetime_range_date_math() {
+        let range: Range<DateTimePayloadType> =
+            serde_json::from_str(r#"{"gte": "now-7d/d", "lt": "now/d[Europe/Berlin]"}"#).unwrap();
+        let gte = range.gte.unwrap();
+        let lt = range.lt.unwrap();
+        assert!(gte < lt);
+        assert!(lt.0 <= chrono::Utc::now());
+
+        let res: Result<Range<DateTimePayloadType>, _> =
+            serde_json::from_str(r#"{"gte": "now-7x"}"#);
+        assert!(res.is_err());
+    }
+
     #[test]
     fn test_timezone_ordering() {
         let datetimes = [
@@ -3447,6 +3837,7 @@ mod tests {
         });
         assert_eq!(payload, expected.into());
     }
+
 }
 
 pub type TheMap<K, V> = BTreeMap<K, V>;
@@ -7,8 +7,7 @@ use memory::mmap_ops::transmute_from_u8_to_slice;
 
 use crate::common::operation_error::{OperationError, OperationResult};
 use crate::data_types::primitive::PrimitiveVectorElement;
-
-const DISK_PARALLELISM: usize = 16; // TODO: benchmark it better, or make it configurable
+use crate::vector_storage::common::get_async_queue_depth;
 
 struct BufferMeta {
     /// Sequential index of the processing point
@@ -53,13 +52,16 @@ pub struct UringReader<T: PrimitiveVectorElement> {
     io_uring: Option<IoUring>,
     raw_size: usize,
     header_size: usize,
+    /// Max number of reads in flight
+    queue_depth: usize,
     _phantom: std::marker::PhantomData<T>,
 }
 
 impl<T: PrimitiveVectorElement> UringReader<T> {
     pub fn new(file: File, raw_size: usize, header_size: usize) -> OperationResult<Self> {
-        let buffers = BufferStore::new(DISK_PARALLELISM, raw_size);
-        let io_uring = IoUring::new(DISK_PARALLELISM as _)?;
+        let queue_depth = get_async_queue_depth();
+        let buffers = BufferStore::new(queue_depth, raw_size);
+        let io_uring = IoUring::new(queue_depth as _)?;
 
         Ok(Self {
             file,
@@ -67,10 +69,22 @@ impl<T: PrimitiveVectorElement> UringReader<T> {
             io_uring: Some(io_uring),
             raw_size,
             header_size,
+            queue_depth,
             _phantom: std::marker::PhantomData,
         })
     }
 
+    /// Reads given points without processing them, to load them into the page cache.
+    ///
+    /// Reads are done concurrently, so that following accesses of the same data through mmap
+    /// do not block on page faults one at a time.
+    pub fn prefetch(
+        &mut self,
+        points: impl IntoIterator<Item = PointOffsetType>,
+    ) -> OperationResult<()> {
+        self.read_stream(points, |_, _, _| {})
+    }
+
     /// Takes in iterator of point offsets, reads it, and yields a callback with the read data.
     pub fn read_stream(
         &mut self,
@@ -83,7 +97,7 @@ impl<T: PrimitiveVectorElement> UringReader<T> {
             // Use existing `IoUring` if there's one...
             Some(io_uring) => io_uring,
             // ...or create a new one if not
-            None => IoUring::new(DISK_PARALLELISM as _)?,
+            None => IoUring::new(self.queue_depth as _)?,
         };
 
         let buffers_count = self.buffers.buffers.len();
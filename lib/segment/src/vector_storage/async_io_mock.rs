@@ -1,5 +1,7 @@
 use std::fs::File;
 
+use common::types::PointOffsetType;
+
 use crate::common::operation_error::OperationResult;
 use crate::data_types::primitive::PrimitiveVectorElement;
 
@@ -16,4 +18,11 @@ impl<T: PrimitiveVectorElement> UringReader<T> {
             _phantom: std::marker::PhantomData,
         })
     }
+
+    pub fn prefetch(
+        &mut self,
+        _points: impl IntoIterator<Item = PointOffsetType>,
+    ) -> OperationResult<()> {
+        Ok(())
+    }
 }
@@ -6,17 +6,23 @@ use common::types::{PointOffsetType, ScoreType, ScoredPointOffset};
 
 use super::query::context_query::ContextQuery;
 use super::query::discovery_query::DiscoveryQuery;
-use super::query::reco_query::RecoQuery;
+use super::query::reco_query::{RecoQuery, RecoSumScoresQuery};
 use super::query::TransformInto;
 use super::query_scorer::custom_query_scorer::CustomQueryScorer;
 use crate::common::operation_error::{OperationError, OperationResult};
 use crate::data_types::vectors::{DenseVector, QueryVector, Vector, VectorElementType};
 use crate::spaces::metric::Metric;
-use crate::spaces::simple::{CosineMetric, DotProductMetric, EuclidMetric, ManhattanMetric};
+use crate::spaces::simple::{
+    CosineMetric, DotProductMetric, EuclidMetric, HammingMetric, JaccardMetric, ManhattanMetric,
+    MinkowskiMetric,
+};
 use crate::types::Distance;
 use crate::vector_storage::dense::memmap_dense_vector_storage::MemmapDenseVectorStorage;
 use crate::vector_storage::dense::mmap_dense_vectors::MmapDenseVectors;
 use crate::vector_storage::query_scorer::metric_query_scorer::MetricQueryScorer;
+use crate::vector_storage::query_scorer::minkowski_query_scorer::{
+    MinkowskiCustomQueryScorer, MinkowskiQueryScorer,
+};
 use crate::vector_storage::query_scorer::QueryScorer;
 use crate::vector_storage::{RawScorer, VectorStorage as _, DEFAULT_STOPPED};
 
@@ -246,6 +252,11 @@ impl<'a> AsyncRawScorerBuilder<'a> {
             Distance::Euclid => self._build_with_metric::<EuclidMetric>(),
             Distance::Dot => self._build_with_metric::<DotProductMetric>(),
             Distance::Manhattan => self._build_with_metric::<ManhattanMetric>(),
+            Distance::Hamming => self._build_with_metric::<HammingMetric>(),
+            Distance::Jaccard => self._build_with_metric::<JaccardMetric>(),
+            Distance::Minkowski(3) => self._build_with_metric::<MinkowskiMetric<3>>(),
+            Distance::Minkowski(4) => self._build_with_metric::<MinkowskiMetric<4>>(),
+            Distance::Minkowski(p) => self._build_with_minkowski(p),
         }
     }
 
@@ -306,6 +317,20 @@ impl<'a> AsyncRawScorerBuilder<'a> {
                     is_stopped.unwrap_or(&DEFAULT_STOPPED),
                 )))
             }
+            QueryVector::RecommendSumScores(reco_query) => {
+                let reco_query: RecoSumScoresQuery<DenseVector> = reco_query.transform_into()?;
+                let query_scorer = CustomQueryScorer::<VectorElementType, TMetric, _, _, _>::new(
+                    reco_query, storage,
+                );
+                Ok(Box::new(AsyncRawScorerImpl::new(
+                    points_count,
+                    query_scorer,
+                    storage.get_mmap_vectors(),
+                    point_deleted,
+                    vec_deleted,
+                    is_stopped.unwrap_or(&DEFAULT_STOPPED),
+                )))
+            }
             QueryVector::Discovery(discovery_query) => {
                 let discovery_query: DiscoveryQuery<DenseVector> =
                     discovery_query.transform_into()?;
@@ -339,4 +364,106 @@ impl<'a> AsyncRawScorerBuilder<'a> {
             }
         }
     }
+
+    /// Builds a scorer for Minkowski distance of an order, which has no dedicated metric
+    fn _build_with_minkowski(self, p: u32) -> OperationResult<Box<dyn RawScorer + 'a>> {
+        let Self {
+            points_count,
+            query,
+            storage,
+            point_deleted,
+            vec_deleted,
+            distance: _,
+            is_stopped,
+        } = self;
+
+        match query {
+            QueryVector::Nearest(vector) => {
+                match vector {
+                    Vector::Dense(dense_vector) => {
+                        let query_scorer = MinkowskiQueryScorer::<VectorElementType, _>::new(
+                            dense_vector,
+                            p,
+                            storage,
+                        );
+                        Ok(Box::new(AsyncRawScorerImpl::new(
+                            points_count,
+                            query_scorer,
+                            storage.get_mmap_vectors(),
+                            point_deleted,
+                            vec_deleted,
+                            is_stopped.unwrap_or(&DEFAULT_STOPPED),
+                        )))
+                    }
+                    Vector::Sparse(_sparse_vector) => Err(OperationError::service_error(
+                        "sparse vectors are not supported for async scorer",
+                    )), // TODO(sparse) add support?
+                    Vector::MultiDense(_multi_dense_vector) => Err(OperationError::service_error(
+                        "multi-dense vectors are not supported for async scorer",
+                    )), // TODO(colbert) add support?
+                }
+            }
+            QueryVector::Recommend(reco_query) => {
+                let reco_query: RecoQuery<DenseVector> = reco_query.transform_into()?;
+                let query_scorer = MinkowskiCustomQueryScorer::<VectorElementType, _, _, _>::new(
+                    reco_query, p, storage,
+                );
+                Ok(Box::new(AsyncRawScorerImpl::new(
+                    points_count,
+                    query_scorer,
+                    storage.get_mmap_vectors(),
+                    point_deleted,
+                    vec_deleted,
+                    is_stopped.unwrap_or(&DEFAULT_STOPPED),
+                )))
+            }
+            QueryVector::RecommendSumScores(reco_query) => {
+                let reco_query: RecoSumScoresQuery<DenseVector> = reco_query.transform_into()?;
+                let query_scorer = MinkowskiCustomQueryScorer::<VectorElementType, _, _, _>::new(
+                    reco_query, p, storage,
+                );
+                Ok(Box::new(AsyncRawScorerImpl::new(
+                    points_count,
+                    query_scorer,
+                    storage.get_mmap_vectors(),
+                    point_deleted,
+                    vec_deleted,
+                    is_stopped.unwrap_or(&DEFAULT_STOPPED),
+                )))
+            }
+            QueryVector::Discovery(discovery_query) => {
+                let discovery_query: DiscoveryQuery<DenseVector> =
+                    discovery_query.transform_into()?;
+                let query_scorer = MinkowskiCustomQueryScorer::<VectorElementType, _, _, _>::new(
+                    discovery_query,
+                    p,
+                    storage,
+                );
+                Ok(Box::new(AsyncRawScorerImpl::new(
+                    points_count,
+                    query_scorer,
+                    storage.get_mmap_vectors(),
+                    point_deleted,
+                    vec_deleted,
+                    is_stopped.unwrap_or(&DEFAULT_STOPPED),
+                )))
+            }
+            QueryVector::Context(context_query) => {
+                let context_query: ContextQuery<DenseVector> = context_query.transform_into()?;
+                let query_scorer = MinkowskiCustomQueryScorer::<VectorElementType, _, _, _>::new(
+                    context_query,
+                    p,
+                    storage,
+                );
+                Ok(Box::new(AsyncRawScorerImpl::new(
+                    points_count,
+                    query_scorer,
+                    storage.get_mmap_vectors(),
+                    point_deleted,
+                    vec_deleted,
+                    is_stopped.unwrap_or(&DEFAULT_STOPPED),
+                )))
+            }
+        }
+    }
 }
@@ -1,9 +1,14 @@
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 
 use serde::{Deserialize, Serialize};
 
+/// Default number of reads kept in flight by a single async IO reader
+pub const DEFAULT_ASYNC_QUEUE_DEPTH: usize = 16;
+
 static ASYNC_SCORER: AtomicBool = AtomicBool::new(false);
 
+static ASYNC_QUEUE_DEPTH: AtomicUsize = AtomicUsize::new(DEFAULT_ASYNC_QUEUE_DEPTH);
+
 pub fn set_async_scorer(async_scorer: bool) {
     ASYNC_SCORER.store(async_scorer, Ordering::Relaxed);
 }
@@ -12,6 +17,15 @@ pub fn get_async_scorer() -> bool {
     ASYNC_SCORER.load(Ordering::Relaxed)
 }
 
+/// Set number of reads kept in flight by async IO readers created from now on
+pub fn set_async_queue_depth(queue_depth: usize) {
+    ASYNC_QUEUE_DEPTH.store(queue_depth.max(1), Ordering::Relaxed);
+}
+
+pub fn get_async_queue_depth() -> usize {
+    ASYNC_QUEUE_DEPTH.load(Ordering::Relaxed)
+}
+
 /// Storage type for RocksDB based storage
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct StoredRecord<T> {
@@ -15,7 +15,9 @@ use crate::common::operation_error::{check_process_stopped, OperationResult};
 use crate::common::Flusher;
 use crate::data_types::named_vectors::CowVector;
 use crate::data_types::primitive::PrimitiveVectorElement;
-use crate::data_types::vectors::{VectorElementType, VectorElementTypeByte, VectorRef};
+use crate::data_types::vectors::{
+    VectorElementType, VectorElementTypeByte, VectorElementTypeHalf, VectorRef,
+};
 use crate::types::{Distance, VectorStorageDatatype};
 use crate::vector_storage::common::get_async_scorer;
 use crate::vector_storage::dense::mmap_dense_vectors::MmapDenseVectors;
@@ -69,6 +71,22 @@ pub fn open_memmap_vector_storage_byte(
     )))
 }
 
+pub fn open_memmap_vector_storage_half(
+    path: &Path,
+    dim: usize,
+    distance: Distance,
+) -> OperationResult<Arc<AtomicRefCell<VectorStorageEnum>>> {
+    let storage = open_memmap_vector_storage_with_async_io_impl::<VectorElementTypeHalf>(
+        path,
+        dim,
+        distance,
+        get_async_scorer(),
+    )?;
+    Ok(Arc::new(AtomicRefCell::new(
+        VectorStorageEnum::DenseMemmapHalf(storage),
+    )))
+}
+
 pub fn open_memmap_vector_storage_with_async_io(
     path: &Path,
     dim: usize,
@@ -744,7 +762,7 @@ mod tests {
 
         let stopped = Arc::new(AtomicBool::new(false));
         let quantized_vectors =
-            QuantizedVectors::create(&borrowed_storage, &config, dir.path(), 1, &stopped).unwrap();
+            QuantizedVectors::create(&borrowed_storage, &config, None, dir.path(), 1, &stopped).unwrap();
 
         let query: QueryVector = [0.5, 0.5, 0.5, 0.5].into();
 
@@ -177,6 +177,16 @@ impl<T: PrimitiveVectorElement> MmapDenseVectors<T> {
         Ok(())
     }
 
+    /// Loads vectors of the given ids into the page cache using concurrent reads.
+    ///
+    /// Does nothing if async IO is not enabled for this storage.
+    pub fn prefetch(&self, points: &[PointOffsetType]) -> OperationResult<()> {
+        match self.uring_reader.lock().as_mut() {
+            Some(uring_reader) => uring_reader.prefetch(points.iter().copied()),
+            None => Ok(()),
+        }
+    }
+
     /// Reads vectors for the given ids and calls the callback for each vector.
     /// Tries to utilize asynchronous IO if possible.
     /// In particular, uses io_uring on Linux and simple synchronous IO otherwise.
@@ -15,16 +15,18 @@ use crate::data_types::named_vectors::CowVector;
 use crate::data_types::primitive::PrimitiveVectorElement;
 use crate::data_types::vectors::{
     MultiDenseVector, TypedMultiDenseVector, TypedMultiDenseVectorRef, VectorElementType,
-    VectorElementTypeByte, VectorRef,
+    VectorElementTypeByte, VectorElementTypeHalf, VectorRef,
 };
 use crate::types::{Distance, MultiVectorConfig, VectorStorageDatatype};
 use crate::vector_storage::chunked_mmap_vectors::ChunkedMmapVectors;
 use crate::vector_storage::dense::dynamic_mmap_flags::DynamicMmapFlags;
+use crate::vector_storage::multi_dense::fixed_dimensional_encoding::FixedDimensionalEncodings;
 use crate::vector_storage::{MultiVectorStorage, VectorStorage, VectorStorageEnum};
 
 const VECTORS_DIR_PATH: &str = "vectors";
 const OFFSETS_DIR_PATH: &str = "offsets";
 const DELETED_DIR_PATH: &str = "deleted";
+const FDE_DIR_PATH: &str = "fde";
 
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
 struct MultivectorMmapOffset {
@@ -36,6 +38,8 @@ struct MultivectorMmapOffset {
 pub struct AppendableMmapMultiDenseVectorStorage<T: PrimitiveVectorElement + 'static> {
     vectors: ChunkedMmapVectors<T>,
     offsets: ChunkedMmapVectors<MultivectorMmapOffset>,
+    /// Fixed dimensional encodings of the vectors, if configured
+    fixed_dimensional_encodings: Option<FixedDimensionalEncodings>,
     deleted: DynamicMmapFlags,
     distance: Distance,
     multi_vector_config: MultiVectorConfig,
@@ -78,6 +82,24 @@ pub fn open_appendable_memmap_multi_vector_storage_byte(
     )))
 }
 
+pub fn open_appendable_memmap_multi_vector_storage_half(
+    path: &Path,
+    dim: usize,
+    distance: Distance,
+    multi_vector_config: MultiVectorConfig,
+) -> OperationResult<Arc<AtomicRefCell<VectorStorageEnum>>> {
+    let storage = open_appendable_memmap_multi_vector_storage_impl::<VectorElementTypeHalf>(
+        path,
+        dim,
+        distance,
+        multi_vector_config,
+    )?;
+
+    Ok(Arc::new(AtomicRefCell::new(
+        VectorStorageEnum::MultiDenseAppendableMemmapHalf(Box::new(storage)),
+    )))
+}
+
 pub fn open_appendable_memmap_multi_vector_storage_impl<T: PrimitiveVectorElement>(
     path: &Path,
     dim: usize,
@@ -96,14 +118,22 @@ pub fn open_appendable_memmap_multi_vector_storage_impl<T: PrimitiveVectorElemen
     let deleted: DynamicMmapFlags = DynamicMmapFlags::open(&deleted_path)?;
     let deleted_count = deleted.count_flags();
 
-    Ok(AppendableMmapMultiDenseVectorStorage {
+    let fixed_dimensional_encodings = multi_vector_config
+        .fde
+        .map(|fde| FixedDimensionalEncodings::open_mmap(&path.join(FDE_DIR_PATH), &fde, dim))
+        .transpose()?;
+
+    let mut storage = AppendableMmapMultiDenseVectorStorage {
         vectors,
         offsets,
+        fixed_dimensional_encodings,
         deleted,
         distance,
         multi_vector_config,
         deleted_count,
-    })
+    };
+    storage.encode_all()?;
+    Ok(storage)
 }
 
 impl<T: PrimitiveVectorElement + 'static> AppendableMmapMultiDenseVectorStorage<T> {
@@ -125,6 +155,23 @@ impl<T: PrimitiveVectorElement + 'static> AppendableMmapMultiDenseVectorStorage<
         }
         Ok(previous)
     }
+
+    /// Compute fixed dimensional encodings of all stored vectors, if persisted encodings don't
+    /// match the stored vectors
+    fn encode_all(&mut self) -> OperationResult<()> {
+        let Some(mut fixed_dimensional_encodings) = self.fixed_dimensional_encodings.take() else {
+            return Ok(());
+        };
+        if fixed_dimensional_encodings.len() == self.offsets.len() {
+            self.fixed_dimensional_encodings = Some(fixed_dimensional_encodings);
+            return Ok(());
+        }
+        for key in 0..self.offsets.len() as PointOffsetType {
+            fixed_dimensional_encodings.update(key, self.get_multi(key))?;
+        }
+        self.fixed_dimensional_encodings = Some(fixed_dimensional_encodings);
+        Ok(())
+    }
 }
 
 impl<T: PrimitiveVectorElement> MultiVectorStorage<T> for AppendableMmapMultiDenseVectorStorage<T> {
@@ -143,6 +190,10 @@ impl<T: PrimitiveVectorElement> MultiVectorStorage<T> for AppendableMmapMultiDen
     fn multi_vector_config(&self) -> &MultiVectorConfig {
         &self.multi_vector_config
     }
+
+    fn fixed_dimensional_encodings(&self) -> Option<&FixedDimensionalEncodings> {
+        self.fixed_dimensional_encodings.as_ref()
+    }
 }
 
 impl<T: PrimitiveVectorElement> VectorStorage for AppendableMmapMultiDenseVectorStorage<T> {
@@ -212,6 +263,9 @@ impl<T: PrimitiveVectorElement> VectorStorage for AppendableMmapMultiDenseVector
             multi_vector.len(),
         )?;
         self.offsets.insert(key as usize, &[offset])?;
+        if let Some(fixed_dimensional_encodings) = &mut self.fixed_dimensional_encodings {
+            fixed_dimensional_encodings.update(key, multi_vector.into())?;
+        }
         self.set_deleted(key, false)?;
 
         Ok(())
@@ -243,10 +297,17 @@ impl<T: PrimitiveVectorElement> VectorStorage for AppendableMmapMultiDenseVector
             let vectors_flusher = self.vectors.flusher();
             let offsets_flusher = self.offsets.flusher();
             let deleted_flusher = self.deleted.flusher();
+            let fde_flusher = self
+                .fixed_dimensional_encodings
+                .as_ref()
+                .map(|fixed_dimensional_encodings| fixed_dimensional_encodings.flusher());
             move || {
                 vectors_flusher()?;
                 offsets_flusher()?;
                 deleted_flusher()?;
+                if let Some(fde_flusher) = fde_flusher {
+                    fde_flusher()?;
+                }
                 Ok(())
             }
         })
@@ -256,6 +317,9 @@ impl<T: PrimitiveVectorElement> VectorStorage for AppendableMmapMultiDenseVector
         let mut files = self.vectors.files();
         files.extend(self.offsets.files());
         files.extend(self.deleted.files());
+        if let Some(fixed_dimensional_encodings) = &self.fixed_dimensional_encodings {
+            files.extend(fixed_dimensional_encodings.files());
+        }
         files
     }
 
@@ -0,0 +1,378 @@
+use std::borrow::Cow;
+use std::path::{Path, PathBuf};
+
+use common::types::{PointOffsetType, ScoreType};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::common::operation_error::OperationResult;
+use crate::common::Flusher;
+use crate::data_types::primitive::PrimitiveVectorElement;
+use crate::data_types::vectors::{
+    DenseVector, MultiDenseVector, TypedMultiDenseVectorRef, VectorElementType,
+};
+use crate::spaces::metric::Metric;
+use crate::spaces::simple::DotProductMetric;
+use crate::types::FdeConfig;
+use crate::vector_storage::chunked_mmap_vectors::ChunkedMmapVectors;
+use crate::vector_storage::chunked_vectors::ChunkedVectors;
+
+/// MUVERA fixed dimensional encoder of multivectors, see <https://arxiv.org/abs/2405.19504>
+///
+/// Hyperplanes and projections are generated from the configured seed, so encoders with the same
+/// config always produce the same encodings.
+pub struct FixedDimensionalEncoder {
+    dim: usize,
+    repetitions: usize,
+    simhash_bits: usize,
+    projection_dim: usize,
+    /// `repetitions * simhash_bits` random hyperplanes of `dim` dimensions
+    hyperplanes: Vec<VectorElementType>,
+    /// `repetitions * projection_dim` rows of `dim` dimensions
+    projections: Vec<VectorElementType>,
+}
+
+impl FixedDimensionalEncoder {
+    pub fn new(config: &FdeConfig, dim: usize) -> Self {
+        let repetitions = config.repetitions as usize;
+        let simhash_bits = config.simhash_bits as usize;
+        let projection_dim = config.projection_dim as usize;
+
+        let mut rng = StdRng::seed_from_u64(config.seed);
+        let hyperplanes = (0..repetitions * simhash_bits * dim)
+            .map(|_| sample_standard_normal(&mut rng))
+            .collect();
+        // Random ±1/sqrt(d) projection preserves dot products in expectation
+        let scale = 1.0 / (projection_dim as VectorElementType).sqrt();
+        let projections = (0..repetitions * projection_dim * dim)
+            .map(|_| if rng.gen::<bool>() { scale } else { -scale })
+            .collect();
+
+        Self {
+            dim,
+            repetitions,
+            simhash_bits,
+            projection_dim,
+            hyperplanes,
+            projections,
+        }
+    }
+
+    /// Number of dimensions of the encodings
+    pub fn encoding_dim(&self) -> usize {
+        self.repetitions * self.num_buckets() * self.projection_dim
+    }
+
+    fn num_buckets(&self) -> usize {
+        1 << self.simhash_bits
+    }
+
+    /// Encode a stored multivector: vectors are averaged per bucket, empty buckets take the vector
+    /// with the closest bucket by hamming distance
+    pub fn encode_document<T: PrimitiveVectorElement>(
+        &self,
+        multi_vector: TypedMultiDenseVectorRef<T>,
+    ) -> DenseVector {
+        let vectors: Vec<_> = multi_vector
+            .multi_vectors()
+            .map(|vector| T::slice_to_float_cow(Cow::Borrowed(vector)))
+            .collect();
+
+        let mut encoding = vec![0.0; self.encoding_dim()];
+        let mut buckets = Vec::with_capacity(vectors.len());
+        let mut counts = vec![0usize; self.num_buckets()];
+        for (repetition, block) in encoding
+            .chunks_exact_mut(self.num_buckets() * self.projection_dim)
+            .enumerate()
+        {
+            buckets.clear();
+            counts.fill(0);
+            for vector in &vectors {
+                let bucket = self.bucket(repetition, vector);
+                buckets.push(bucket);
+                counts[bucket] += 1;
+                self.add_projected(repetition, vector, self.bucket_mut(block, bucket));
+            }
+
+            for (bucket, &count) in counts.iter().enumerate() {
+                if count > 0 {
+                    let scale = 1.0 / count as VectorElementType;
+                    self.bucket_mut(block, bucket)
+                        .iter_mut()
+                        .for_each(|x| *x *= scale);
+                    continue;
+                }
+                let nearest = buckets
+                    .iter()
+                    .enumerate()
+                    .min_by_key(|(_, &other)| (bucket ^ other).count_ones());
+                if let Some((nearest, _)) = nearest {
+                    self.add_projected(
+                        repetition,
+                        &vectors[nearest],
+                        self.bucket_mut(block, bucket),
+                    );
+                }
+            }
+        }
+        encoding
+    }
+
+    /// Encode a query multivector: vectors are summed per bucket, so the dot product with
+    /// document encodings approximates MaxSim
+    pub fn encode_query(&self, multi_vector: &MultiDenseVector) -> DenseVector {
+        let mut encoding = vec![0.0; self.encoding_dim()];
+        for (repetition, block) in encoding
+            .chunks_exact_mut(self.num_buckets() * self.projection_dim)
+            .enumerate()
+        {
+            for vector in multi_vector.multi_vectors() {
+                let bucket = self.bucket(repetition, vector);
+                self.add_projected(repetition, vector, self.bucket_mut(block, bucket));
+            }
+        }
+        encoding
+    }
+
+    /// Bucket of a vector in the given repetition, one bit per hyperplane
+    fn bucket(&self, repetition: usize, vector: &[VectorElementType]) -> usize {
+        let stride = self.simhash_bits * self.dim;
+        self.hyperplanes[repetition * stride..(repetition + 1) * stride]
+            .chunks_exact(self.dim)
+            .enumerate()
+            .fold(0, |bucket, (bit, hyperplane)| {
+                if <DotProductMetric as Metric<VectorElementType>>::similarity(hyperplane, vector)
+                    > 0.0
+                {
+                    bucket | (1 << bit)
+                } else {
+                    bucket
+                }
+            })
+    }
+
+    fn bucket_mut<'b>(
+        &self,
+        block: &'b mut [VectorElementType],
+        bucket: usize,
+    ) -> &'b mut [VectorElementType] {
+        &mut block[bucket * self.projection_dim..(bucket + 1) * self.projection_dim]
+    }
+
+    fn add_projected(
+        &self,
+        repetition: usize,
+        vector: &[VectorElementType],
+        output: &mut [VectorElementType],
+    ) {
+        let stride = self.projection_dim * self.dim;
+        let rows =
+            self.projections[repetition * stride..(repetition + 1) * stride].chunks_exact(self.dim);
+        for (o, row) in output.iter_mut().zip(rows) {
+            *o += <DotProductMetric as Metric<VectorElementType>>::similarity(row, vector);
+        }
+    }
+}
+
+/// Box-Muller transform
+fn sample_standard_normal(rng: &mut StdRng) -> VectorElementType {
+    let u1: f32 = rng.gen_range(f32::EPSILON..1.0);
+    let u2: f32 = rng.gen();
+    (-2.0 * u1.ln()).sqrt() * (2.0 * std::f32::consts::PI * u2).cos()
+}
+
+enum EncodingsStorage {
+    Ram(ChunkedVectors<VectorElementType>),
+    Mmap(ChunkedMmapVectors<VectorElementType>),
+}
+
+/// Fixed dimensional encodings of all multivectors of a storage.
+///
+/// Encodings are either kept in RAM and recomputed when the storage is opened, or persisted
+/// in memmap files next to the vectors of the storage.
+pub struct FixedDimensionalEncodings {
+    encoder: FixedDimensionalEncoder,
+    encodings: EncodingsStorage,
+    prefetch_factor: usize,
+}
+
+impl FixedDimensionalEncodings {
+    pub fn new(config: &FdeConfig, dim: usize) -> Self {
+        let encoder = FixedDimensionalEncoder::new(config, dim);
+        let encodings = EncodingsStorage::Ram(ChunkedVectors::new(encoder.encoding_dim()));
+        Self {
+            encoder,
+            encodings,
+            prefetch_factor: config.prefetch_factor as usize,
+        }
+    }
+
+    /// Open encodings persisted in the given directory
+    pub fn open_mmap(directory: &Path, config: &FdeConfig, dim: usize) -> OperationResult<Self> {
+        let encoder = FixedDimensionalEncoder::new(config, dim);
+        let encodings =
+            EncodingsStorage::Mmap(ChunkedMmapVectors::open(directory, encoder.encoding_dim())?);
+        Ok(Self {
+            encoder,
+            encodings,
+            prefetch_factor: config.prefetch_factor as usize,
+        })
+    }
+
+    /// Number of candidates to select by the encodings for each requested result
+    pub fn prefetch_factor(&self) -> usize {
+        self.prefetch_factor
+    }
+
+    pub fn len(&self) -> usize {
+        match &self.encodings {
+            EncodingsStorage::Ram(encodings) => encodings.len(),
+            EncodingsStorage::Mmap(encodings) => encodings.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn get(&self, key: PointOffsetType) -> Option<&[VectorElementType]> {
+        if key as usize >= self.len() {
+            return None;
+        }
+        match &self.encodings {
+            EncodingsStorage::Ram(encodings) => Some(encodings.get(key)),
+            EncodingsStorage::Mmap(encodings) => encodings.get(key),
+        }
+    }
+
+    /// Re-encode the multivector of the given point
+    pub fn update<T: PrimitiveVectorElement>(
+        &mut self,
+        key: PointOffsetType,
+        multi_vector: TypedMultiDenseVectorRef<T>,
+    ) -> OperationResult<()> {
+        let encoding = self.encoder.encode_document(multi_vector);
+        match &mut self.encodings {
+            EncodingsStorage::Ram(encodings) => encodings.insert(key, &encoding)?,
+            EncodingsStorage::Mmap(encodings) => encodings.insert(key, &encoding)?,
+        }
+        Ok(())
+    }
+
+    pub fn encode_query(&self, multi_vector: &MultiDenseVector) -> DenseVector {
+        self.encoder.encode_query(multi_vector)
+    }
+
+    /// Approximate MaxSim of the encoded query and the given point
+    pub fn score(&self, query_encoding: &[VectorElementType], key: PointOffsetType) -> ScoreType {
+        match self.get(key) {
+            Some(encoding) => <DotProductMetric as Metric<VectorElementType>>::similarity(
+                query_encoding,
+                encoding,
+            ),
+            None => ScoreType::NEG_INFINITY,
+        }
+    }
+
+    /// Approximate similarity of two stored points
+    pub fn score_internal(&self, point_a: PointOffsetType, point_b: PointOffsetType) -> ScoreType {
+        match (self.get(point_a), self.get(point_b)) {
+            (Some(a), Some(b)) => <DotProductMetric as Metric<VectorElementType>>::similarity(a, b),
+            _ => ScoreType::NEG_INFINITY,
+        }
+    }
+
+    pub fn flusher(&self) -> Flusher {
+        match &self.encodings {
+            EncodingsStorage::Ram(_) => Box::new(|| Ok(())),
+            EncodingsStorage::Mmap(encodings) => encodings.flusher(),
+        }
+    }
+
+    pub fn files(&self) -> Vec<PathBuf> {
+        match &self.encodings {
+            EncodingsStorage::Ram(_) => vec![],
+            EncodingsStorage::Mmap(encodings) => encodings.files(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spaces::simple::CosineMetric;
+    use crate::vector_storage::query_scorer::score_max_similarity;
+
+    #[test]
+    fn test_fde_approximates_max_sim() {
+        let dim = 32;
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut random_multi_vector = |count: usize| {
+            let flattened_vectors = (0..count)
+                .flat_map(|_| {
+                    let vector = (0..dim).map(|_| rng.gen_range(-1.0..1.0)).collect();
+                    <CosineMetric as Metric<VectorElementType>>::preprocess(vector)
+                })
+                .collect();
+            MultiDenseVector::new(flattened_vectors, dim)
+        };
+
+        let config = FdeConfig {
+            repetitions: 20,
+            simhash_bits: 3,
+            projection_dim: dim as u32,
+            ..Default::default()
+        };
+        let mut encodings = FixedDimensionalEncodings::new(&config, dim);
+        assert_eq!(encodings.encoder.encoding_dim(), 20 * 8 * dim);
+
+        let points: Vec<_> = (0..50).map(|_| random_multi_vector(4)).collect();
+        for (idx, point) in points.iter().enumerate() {
+            encodings
+                .update(
+                    idx as PointOffsetType,
+                    TypedMultiDenseVectorRef::from(point),
+                )
+                .unwrap();
+        }
+        assert_eq!(encodings.len(), points.len());
+
+        // The same seed gives the same encodings
+        let other = FixedDimensionalEncoder::new(&config, dim);
+        assert_eq!(
+            other.encode_document(TypedMultiDenseVectorRef::from(&points[0])),
+            encodings.get(0).unwrap(),
+        );
+
+        // Query close to a point is scored higher by encodings than a random query
+        let query = points[7].clone();
+        let query_encoding = encodings.encode_query(&query);
+        let exact: Vec<_> = points
+            .iter()
+            .map(|point| {
+                score_max_similarity::<VectorElementType, DotProductMetric>(
+                    TypedMultiDenseVectorRef::from(&query),
+                    TypedMultiDenseVectorRef::from(point),
+                )
+            })
+            .collect();
+        let approximate: Vec<_> = (0..points.len())
+            .map(|idx| encodings.score(&query_encoding, idx as PointOffsetType))
+            .collect();
+
+        let best_exact = (0..points.len())
+            .max_by(|&a, &b| exact[a].total_cmp(&exact[b]))
+            .unwrap();
+        let best_approximate = (0..points.len())
+            .max_by(|&a, &b| approximate[a].total_cmp(&approximate[b]))
+            .unwrap();
+        assert_eq!(best_exact, 7);
+        assert_eq!(best_approximate, 7);
+
+        // Out of range points are never selected
+        assert_eq!(
+            encodings.score(&query_encoding, points.len() as PointOffsetType),
+            ScoreType::NEG_INFINITY
+        );
+    }
+}
@@ -1,2 +1,3 @@
 pub mod appendable_mmap_multi_dense_vector_storage;
+pub mod fixed_dimensional_encoding;
 pub mod simple_multi_dense_vector_storage;
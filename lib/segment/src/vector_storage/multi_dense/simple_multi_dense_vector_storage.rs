@@ -21,6 +21,7 @@ use crate::types::{Distance, MultiVectorConfig, VectorStorageDatatype};
 use crate::vector_storage::bitvec::bitvec_set_deleted;
 use crate::vector_storage::chunked_vectors::ChunkedVectors;
 use crate::vector_storage::common::StoredRecord;
+use crate::vector_storage::multi_dense::fixed_dimensional_encoding::FixedDimensionalEncodings;
 use crate::vector_storage::{MultiVectorStorage, VectorStorage, VectorStorageEnum};
 
 type StoredMultiDenseVector<T> = StoredRecord<TypedMultiDenseVector<T>>;
@@ -41,6 +42,8 @@ pub struct SimpleMultiDenseVectorStorage<T: PrimitiveVectorElement> {
     /// Keep vectors in memory
     vectors: ChunkedVectors<T>,
     vectors_metadata: Vec<MultiVectorMetadata>,
+    /// Fixed dimensional encodings of the vectors, if configured
+    fixed_dimensional_encodings: Option<FixedDimensionalEncodings>,
     db_wrapper: DatabaseColumnWrapper,
     update_buffer: StoredMultiDenseVector<T>,
     /// BitVec for deleted flags. Grows dynamically upto last set flag.
@@ -91,6 +94,27 @@ pub fn open_simple_multi_dense_vector_storage_byte(
     )))
 }
 
+pub fn open_simple_multi_dense_vector_storage_half(
+    database: Arc<RwLock<DB>>,
+    database_column_name: &str,
+    dim: usize,
+    distance: Distance,
+    multi_vector_config: MultiVectorConfig,
+    stopped: &AtomicBool,
+) -> OperationResult<Arc<AtomicRefCell<VectorStorageEnum>>> {
+    let storage = open_simple_multi_dense_vector_storage_impl(
+        database,
+        database_column_name,
+        dim,
+        distance,
+        multi_vector_config,
+        stopped,
+    )?;
+    Ok(Arc::new(AtomicRefCell::new(
+        VectorStorageEnum::MultiDenseSimpleHalf(storage),
+    )))
+}
+
 fn open_simple_multi_dense_vector_storage_impl<T: PrimitiveVectorElement>(
     database: Arc<RwLock<DB>>,
     database_column_name: &str,
@@ -101,6 +125,9 @@ fn open_simple_multi_dense_vector_storage_impl<T: PrimitiveVectorElement>(
 ) -> OperationResult<SimpleMultiDenseVectorStorage<T>> {
     let mut vectors = ChunkedVectors::new(dim);
     let mut vectors_metadata = Vec::<MultiVectorMetadata>::new();
+    let mut fixed_dimensional_encodings = multi_vector_config
+        .fde
+        .map(|fde| FixedDimensionalEncodings::new(&fde, dim));
     let (mut deleted, mut deleted_count) = (BitVec::new(), 0);
     let db_wrapper = DatabaseColumnWrapper::new(database, database_column_name);
     db_wrapper.lock_db().iter()?;
@@ -135,6 +162,9 @@ fn open_simple_multi_dense_vector_storage_impl<T: PrimitiveVectorElement>(
             &stored_record.vector.flattened_vectors,
             stored_record.vector.len(),
         )?;
+        if let Some(fixed_dimensional_encodings) = &mut fixed_dimensional_encodings {
+            fixed_dimensional_encodings.update(point_id, (&stored_record.vector).into())?;
+        }
 
         check_process_stopped(stopped)?;
     }
@@ -145,6 +175,7 @@ fn open_simple_multi_dense_vector_storage_impl<T: PrimitiveVectorElement>(
         multi_vector_config,
         vectors,
         vectors_metadata,
+        fixed_dimensional_encodings,
         db_wrapper,
         update_buffer: StoredMultiDenseVector {
             deleted: false,
@@ -240,6 +271,10 @@ impl<T: PrimitiveVectorElement> SimpleMultiDenseVectorStorage<T> {
             )?;
         }
 
+        if let Some(fixed_dimensional_encodings) = &mut self.fixed_dimensional_encodings {
+            fixed_dimensional_encodings.update(key, multi_vector.into())?;
+        }
+
         self.set_deleted(key, is_deleted);
         self.update_stored(key, is_deleted, Some(multi_vector))?;
         Ok(())
@@ -258,6 +293,10 @@ impl<T: PrimitiveVectorElement> MultiVectorStorage<T> for SimpleMultiDenseVector
     fn multi_vector_config(&self) -> &MultiVectorConfig {
         &self.multi_vector_config
     }
+
+    fn fixed_dimensional_encodings(&self) -> Option<&FixedDimensionalEncodings> {
+        self.fixed_dimensional_encodings.as_ref()
+    }
 }
 
 impl<T: PrimitiveVectorElement> VectorStorage for SimpleMultiDenseVectorStorage<T> {
@@ -270,7 +309,7 @@ impl<T: PrimitiveVectorElement> VectorStorage for SimpleMultiDenseVectorStorage<
     }
 
     fn datatype(&self) -> VectorStorageDatatype {
-        VectorStorageDatatype::Float32
+        T::datatype()
     }
 
     fn is_on_disk(&self) -> bool {
@@ -0,0 +1,682 @@
+use std::path::Path;
+
+use common::types::PointOffsetType;
+use quantization::{DistanceType, EncodedStorage, EncodedStorageBuilder, VectorParameters};
+use serde::{Deserialize, Serialize};
+
+use crate::common::operation_error::{OperationError, OperationResult};
+use crate::types::{BinaryQuantizationEncoding, BinaryQuantizationQueryEncoding};
+
+/// Dimension is considered large (magnitude bit is set) if its absolute value exceeds
+/// this number of standard deviations. Optimal 4-level quantization threshold of the normal distribution.
+const TWO_BITS_THRESHOLD: f32 = 0.98;
+
+/// Pair of dimensions is considered large if the mean of their absolute values exceeds
+/// this number of standard deviations. Mean absolute value of the normal distribution.
+const ONE_AND_HALF_BITS_THRESHOLD: f32 = 0.8;
+
+const BITS_IN_WORD: usize = u64::BITS as usize;
+
+/// Binary quantization with optional magnitude bits and asymmetric query encoding.
+///
+/// Each stored vector is encoded as a plane of sign bits followed by a plane of magnitude bits.
+/// Encoded value of a dimension is `±1` for small and `±3` for large values,
+/// so similarity of codes can be computed with popcount operations only.
+///
+/// Magnitude plane size depends on the encoding:
+/// - `OneBit` - no magnitude plane, all values are `±1`
+/// - `OneAndHalfBits` - one magnitude bit for each pair of dimensions
+/// - `TwoBits` - one magnitude bit for each dimension
+pub struct EncodedVectorsMultiBit<TStorage: EncodedStorage> {
+    encoded_vectors: TStorage,
+    metadata: Metadata,
+    words_count: usize,
+    last_word_mask: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Metadata {
+    vector_parameters: VectorParameters,
+    encoding: BinaryQuantizationEncoding,
+    query_encoding: Option<BinaryQuantizationQueryEncoding>,
+    /// Standard deviation (around zero) of each dimension
+    scales: Vec<f32>,
+}
+
+pub enum EncodedMultiBitQuery {
+    /// Query encoded in the same way as stored vectors
+    Binary(Vec<u8>),
+    /// Query quantized into small integers, stored as bit planes of `value + offset`
+    Scalar(EncodedScalarQuery),
+}
+
+pub struct EncodedScalarQuery {
+    /// `bits` planes of `words_count` words each
+    planes: Vec<u64>,
+    bits: usize,
+    offset: i64,
+    /// Sum of quantized values
+    sum: i64,
+    /// Multiplier from quantized values to level units
+    scale: f32,
+    /// Squared norm of the query in level units
+    norm: f32,
+}
+
+/// Popcounts of a pair of codes, sufficient to compute their dot product
+#[derive(Default)]
+struct CodesCounters {
+    magnitude_a: u32,
+    magnitude_b: u32,
+    magnitude_ab: u32,
+    sign_xor: u32,
+    sign_xor_magnitude_a: u32,
+    sign_xor_magnitude_b: u32,
+    sign_xor_magnitude_ab: u32,
+}
+
+impl<TStorage: EncodedStorage> EncodedVectorsMultiBit<TStorage> {
+    pub fn encode<'a>(
+        orig_data: impl Iterator<Item = impl AsRef<[f32]> + 'a> + Clone,
+        mut storage_builder: impl EncodedStorageBuilder<TStorage>,
+        vector_parameters: &VectorParameters,
+        encoding: BinaryQuantizationEncoding,
+        query_encoding: Option<BinaryQuantizationQueryEncoding>,
+        stopped: impl Fn() -> bool,
+    ) -> OperationResult<Self> {
+        let dim = vector_parameters.dim;
+
+        let mut sum_squares = vec![0f64; dim];
+        let mut count = 0usize;
+        for vector in orig_data.clone() {
+            if stopped() {
+                return Err(OperationError::Cancelled {
+                    description: "Binary quantization encoding was cancelled".to_string(),
+                });
+            }
+            for (sum, &value) in sum_squares.iter_mut().zip(vector.as_ref()) {
+                *sum += f64::from(value) * f64::from(value);
+            }
+            count += 1;
+        }
+        let scales = sum_squares
+            .into_iter()
+            .map(|sum| {
+                let scale = (sum / count.max(1) as f64).sqrt() as f32;
+                if scale > f32::EPSILON {
+                    scale
+                } else {
+                    1.0
+                }
+            })
+            .collect();
+
+        let metadata = Metadata {
+            vector_parameters: vector_parameters.clone(),
+            encoding,
+            query_encoding,
+            scales,
+        };
+
+        let mut encoded = Vec::new();
+        for vector in orig_data {
+            if stopped() {
+                return Err(OperationError::Cancelled {
+                    description: "Binary quantization encoding was cancelled".to_string(),
+                });
+            }
+            encoded.clear();
+            Self::encode_vector(&metadata, vector.as_ref(), &mut encoded);
+            storage_builder.push_vector_data(&encoded);
+        }
+
+        Ok(Self::new(storage_builder.build(), metadata))
+    }
+
+    fn new(encoded_vectors: TStorage, metadata: Metadata) -> Self {
+        let dim = metadata.vector_parameters.dim;
+        let words_count = dim.div_ceil(BITS_IN_WORD);
+        let last_word_mask = match dim % BITS_IN_WORD {
+            0 => u64::MAX,
+            rest => (1 << rest) - 1,
+        };
+        Self {
+            encoded_vectors,
+            metadata,
+            words_count,
+            last_word_mask,
+        }
+    }
+
+    pub fn get_quantized_vector_size(
+        vector_parameters: &VectorParameters,
+        encoding: BinaryQuantizationEncoding,
+    ) -> usize {
+        let words_count = vector_parameters.dim.div_ceil(BITS_IN_WORD);
+        let sign_plane_size = words_count * std::mem::size_of::<u64>();
+        let magnitude_plane_size = match encoding {
+            BinaryQuantizationEncoding::OneBit => 0,
+            BinaryQuantizationEncoding::OneAndHalfBits => words_count * std::mem::size_of::<u32>(),
+            BinaryQuantizationEncoding::TwoBits => words_count * std::mem::size_of::<u64>(),
+        };
+        sign_plane_size + magnitude_plane_size
+    }
+
+    fn quantized_vector_size(&self) -> usize {
+        Self::get_quantized_vector_size(&self.metadata.vector_parameters, self.metadata.encoding)
+    }
+
+    fn encode_vector(metadata: &Metadata, vector: &[f32], encoded: &mut Vec<u8>) {
+        let words_count = vector.len().div_ceil(BITS_IN_WORD);
+
+        let mut signs = vec![0u64; words_count];
+        for (i, &value) in vector.iter().enumerate() {
+            if value > 0.0 {
+                signs[i / BITS_IN_WORD] |= 1 << (i % BITS_IN_WORD);
+            }
+        }
+        for word in signs {
+            encoded.extend_from_slice(&word.to_le_bytes());
+        }
+
+        let normalized = || {
+            vector
+                .iter()
+                .zip(&metadata.scales)
+                .map(|(value, scale)| (value / scale).abs())
+        };
+
+        match metadata.encoding {
+            BinaryQuantizationEncoding::OneBit => {}
+            BinaryQuantizationEncoding::OneAndHalfBits => {
+                let mut magnitudes = vec![0u32; words_count];
+                let values: Vec<_> = normalized().collect();
+                for (pair, chunk) in values.chunks(2).enumerate() {
+                    let mean = chunk.iter().sum::<f32>() / 2.0;
+                    if mean > ONE_AND_HALF_BITS_THRESHOLD {
+                        let half_word_bits = BITS_IN_WORD / 2;
+                        magnitudes[pair / half_word_bits] |= 1 << (pair % half_word_bits);
+                    }
+                }
+                for word in magnitudes {
+                    encoded.extend_from_slice(&word.to_le_bytes());
+                }
+            }
+            BinaryQuantizationEncoding::TwoBits => {
+                let mut magnitudes = vec![0u64; words_count];
+                for (i, value) in normalized().enumerate() {
+                    if value > TWO_BITS_THRESHOLD {
+                        magnitudes[i / BITS_IN_WORD] |= 1 << (i % BITS_IN_WORD);
+                    }
+                }
+                for word in magnitudes {
+                    encoded.extend_from_slice(&word.to_le_bytes());
+                }
+            }
+        }
+    }
+
+    /// Value of `|z|` which corresponds to a single level unit of the encoded values
+    fn level_unit(&self) -> f32 {
+        match self.metadata.encoding {
+            // Codes are `±1`, which approximate the mean absolute value of `0.8` standard deviations
+            BinaryQuantizationEncoding::OneBit => 0.8,
+            // Codes are `±1` and `±3`, which approximate `±0.45` and `±1.5` standard deviations
+            BinaryQuantizationEncoding::OneAndHalfBits | BinaryQuantizationEncoding::TwoBits => 0.5,
+        }
+    }
+
+    fn encode_scalar_query(&self, query: &[f32], bits: usize) -> EncodedScalarQuery {
+        let max_value = (1i64 << (bits - 1)) - 1;
+        let offset = 1i64 << (bits - 1);
+        let level_unit = self.level_unit();
+
+        let levels: Vec<f32> = query
+            .iter()
+            .zip(&self.metadata.scales)
+            .map(|(value, scale)| value / scale / level_unit)
+            .collect();
+        let max_abs = levels.iter().fold(0f32, |max, level| max.max(level.abs()));
+        let multiplier = if max_abs > f32::EPSILON {
+            max_value as f32 / max_abs
+        } else {
+            1.0
+        };
+
+        let mut planes = vec![0u64; bits * self.words_count];
+        let mut sum = 0i64;
+        let mut norm = 0f32;
+        for (i, level) in levels.into_iter().enumerate() {
+            let quantized = ((level * multiplier).round() as i64).clamp(-max_value, max_value);
+            sum += quantized;
+            norm += (quantized as f32 / multiplier).powi(2);
+
+            let shifted = (quantized + offset) as u64;
+            for bit in 0..bits {
+                if shifted & (1 << bit) != 0 {
+                    planes[bit * self.words_count + i / BITS_IN_WORD] |= 1 << (i % BITS_IN_WORD);
+                }
+            }
+        }
+
+        EncodedScalarQuery {
+            planes,
+            bits,
+            offset,
+            sum,
+            scale: 1.0 / multiplier,
+            norm,
+        }
+    }
+
+    #[inline]
+    fn read_u64(data: &[u8], index: usize) -> u64 {
+        let start = index * std::mem::size_of::<u64>();
+        u64::from_le_bytes(
+            data[start..start + std::mem::size_of::<u64>()]
+                .try_into()
+                .unwrap(),
+        )
+    }
+
+    #[inline]
+    fn read_u32(data: &[u8], index: usize) -> u32 {
+        let start = index * std::mem::size_of::<u32>();
+        u32::from_le_bytes(
+            data[start..start + std::mem::size_of::<u32>()]
+                .try_into()
+                .unwrap(),
+        )
+    }
+
+    /// Duplicate each bit of `value`: bit `i` is moved into bits `2i` and `2i + 1`
+    #[inline]
+    fn spread_bits(value: u32) -> u64 {
+        let mut x = u64::from(value);
+        x = (x | (x << 16)) & 0x0000_FFFF_0000_FFFF;
+        x = (x | (x << 8)) & 0x00FF_00FF_00FF_00FF;
+        x = (x | (x << 4)) & 0x0F0F_0F0F_0F0F_0F0F;
+        x = (x | (x << 2)) & 0x3333_3333_3333_3333;
+        x = (x | (x << 1)) & 0x5555_5555_5555_5555;
+        x | (x << 1)
+    }
+
+    /// Sign and magnitude bits of the `index`-th word of an encoded vector
+    #[inline]
+    fn code_word(&self, code: &[u8], index: usize) -> (u64, u64) {
+        let sign = Self::read_u64(code, index);
+        let magnitudes = &code[self.words_count * std::mem::size_of::<u64>()..];
+        let magnitude = match self.metadata.encoding {
+            BinaryQuantizationEncoding::OneBit => 0,
+            BinaryQuantizationEncoding::OneAndHalfBits => {
+                let mask = if index + 1 == self.words_count {
+                    self.last_word_mask
+                } else {
+                    u64::MAX
+                };
+                Self::spread_bits(Self::read_u32(magnitudes, index)) & mask
+            }
+            BinaryQuantizationEncoding::TwoBits => Self::read_u64(magnitudes, index),
+        };
+        (sign, magnitude)
+    }
+
+    /// Squared norm of an encoded vector in level units: `dim + 8 * |magnitude|`
+    #[inline]
+    fn code_norm(&self, magnitude_count: u32) -> f32 {
+        (self.metadata.vector_parameters.dim as u32 + 8 * magnitude_count) as f32
+    }
+
+    fn score_codes(&self, code_a: &[u8], code_b: &[u8]) -> f32 {
+        let mut counters = CodesCounters::default();
+        for index in 0..self.words_count {
+            let (sign_a, magnitude_a) = self.code_word(code_a, index);
+            let (sign_b, magnitude_b) = self.code_word(code_b, index);
+            let sign_xor = sign_a ^ sign_b;
+            let magnitude_ab = magnitude_a & magnitude_b;
+            counters.magnitude_a += magnitude_a.count_ones();
+            counters.magnitude_b += magnitude_b.count_ones();
+            counters.magnitude_ab += magnitude_ab.count_ones();
+            counters.sign_xor += sign_xor.count_ones();
+            counters.sign_xor_magnitude_a += (sign_xor & magnitude_a).count_ones();
+            counters.sign_xor_magnitude_b += (sign_xor & magnitude_b).count_ones();
+            counters.sign_xor_magnitude_ab += (sign_xor & magnitude_ab).count_ones();
+        }
+
+        // Product of codes is `sign * (1 + 2 m_a) * (1 + 2 m_b)` where `sign = 1 - 2 * sign_xor`,
+        // so the dot product is `sum(w) - 2 * sum(sign_xor * w)` with `w = 1 + 2 m_a + 2 m_b + 4 m_a m_b`
+        let weights = self.metadata.vector_parameters.dim as i64
+            + 2 * i64::from(counters.magnitude_a)
+            + 2 * i64::from(counters.magnitude_b)
+            + 4 * i64::from(counters.magnitude_ab);
+        let xor_weights = i64::from(counters.sign_xor)
+            + 2 * i64::from(counters.sign_xor_magnitude_a)
+            + 2 * i64::from(counters.sign_xor_magnitude_b)
+            + 4 * i64::from(counters.sign_xor_magnitude_ab);
+        let dot = (weights - 2 * xor_weights) as f32;
+
+        self.postprocess_score(
+            dot,
+            self.code_norm(counters.magnitude_a),
+            self.code_norm(counters.magnitude_b),
+        )
+    }
+
+    fn score_scalar_query(&self, query: &EncodedScalarQuery, code: &[u8]) -> f32 {
+        // Sum of quantized query values over the dimensions selected by `mask_of`
+        let masked_sum = |mask_of: &dyn Fn(usize) -> u64| -> i64 {
+            let mut sum = 0i64;
+            let mut count = 0i64;
+            for index in 0..self.words_count {
+                let mask = mask_of(index);
+                count += i64::from(mask.count_ones());
+                for bit in 0..query.bits {
+                    let plane = query.planes[bit * self.words_count + index];
+                    sum += i64::from((plane & mask).count_ones()) << bit;
+                }
+            }
+            sum - query.offset * count
+        };
+
+        let mut magnitude_count = 0;
+        let mut words = Vec::with_capacity(self.words_count);
+        for index in 0..self.words_count {
+            let (sign, magnitude) = self.code_word(code, index);
+            magnitude_count += magnitude.count_ones();
+            words.push((sign, magnitude));
+        }
+
+        // Code is `(2 s - 1) * (1 + 2 m)`, so the dot product with query `q` is
+        // `2 q(s) - q(all) + 2 * (2 q(s & m) - q(m))`, where `q(x)` is the sum of `q` over bits of `x`
+        let signs_sum = masked_sum(&|index| words[index].0);
+        let mut dot = 2 * signs_sum - query.sum;
+        if magnitude_count > 0 {
+            let signs_magnitudes_sum = masked_sum(&|index| words[index].0 & words[index].1);
+            let magnitudes_sum = masked_sum(&|index| words[index].1);
+            dot += 2 * (2 * signs_magnitudes_sum - magnitudes_sum);
+        }
+
+        self.postprocess_score(
+            dot as f32 * query.scale,
+            query.norm,
+            self.code_norm(magnitude_count),
+        )
+    }
+
+    fn postprocess_score(&self, dot: f32, norm_a: f32, norm_b: f32) -> f32 {
+        let score = match self.metadata.vector_parameters.distance_type {
+            DistanceType::Dot => dot,
+            // Squared euclidean distance between codes approximates both distances well enough for ranking
+            DistanceType::L1 | DistanceType::L2 => norm_a + norm_b - 2.0 * dot,
+        };
+        if self.metadata.vector_parameters.invert {
+            -score
+        } else {
+            score
+        }
+    }
+}
+
+impl<TStorage: EncodedStorage> quantization::EncodedVectors<EncodedMultiBitQuery>
+    for EncodedVectorsMultiBit<TStorage>
+{
+    fn save(&self, data_path: &Path, meta_path: &Path) -> std::io::Result<()> {
+        let metadata_bytes = serde_json::to_vec(&self.metadata)?;
+        meta_path.parent().map(std::fs::create_dir_all);
+        std::fs::write(meta_path, metadata_bytes)?;
+
+        data_path.parent().map(std::fs::create_dir_all);
+        self.encoded_vectors.save_to_file(data_path)?;
+        Ok(())
+    }
+
+    fn load(
+        data_path: &Path,
+        meta_path: &Path,
+        vector_parameters: &VectorParameters,
+    ) -> std::io::Result<Self> {
+        let contents = std::fs::read_to_string(meta_path)?;
+        let metadata: Metadata = serde_json::from_str(&contents)?;
+        let quantized_vector_size =
+            Self::get_quantized_vector_size(vector_parameters, metadata.encoding);
+        let encoded_vectors =
+            TStorage::from_file(data_path, quantized_vector_size, vector_parameters.count)?;
+        Ok(Self::new(encoded_vectors, metadata))
+    }
+
+    fn encode_query(&self, query: &[f32]) -> EncodedMultiBitQuery {
+        match self.metadata.query_encoding {
+            None | Some(BinaryQuantizationQueryEncoding::Binary) => {
+                let mut encoded = Vec::with_capacity(self.quantized_vector_size());
+                Self::encode_vector(&self.metadata, query, &mut encoded);
+                EncodedMultiBitQuery::Binary(encoded)
+            }
+            Some(BinaryQuantizationQueryEncoding::Scalar4Bits) => {
+                EncodedMultiBitQuery::Scalar(self.encode_scalar_query(query, 4))
+            }
+            Some(BinaryQuantizationQueryEncoding::Scalar8Bits) => {
+                EncodedMultiBitQuery::Scalar(self.encode_scalar_query(query, 8))
+            }
+        }
+    }
+
+    fn score_point(&self, query: &EncodedMultiBitQuery, i: PointOffsetType) -> f32 {
+        let code = self
+            .encoded_vectors
+            .get_vector_data(i as usize, self.quantized_vector_size());
+        match query {
+            EncodedMultiBitQuery::Binary(query_code) => self.score_codes(query_code, code),
+            EncodedMultiBitQuery::Scalar(query) => self.score_scalar_query(query, code),
+        }
+    }
+
+    fn score_internal(&self, i: PointOffsetType, j: PointOffsetType) -> f32 {
+        let vector_size = self.quantized_vector_size();
+        let code_a = self
+            .encoded_vectors
+            .get_vector_data(i as usize, vector_size);
+        let code_b = self
+            .encoded_vectors
+            .get_vector_data(j as usize, vector_size);
+        self.score_codes(code_a, code_b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quantization::EncodedVectors;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    use super::*;
+    use crate::vector_storage::chunked_vectors::ChunkedVectors;
+
+    fn dot(a: &[f32], b: &[f32]) -> f32 {
+        a.iter().zip(b).map(|(a, b)| a * b).sum()
+    }
+
+    /// Share of the true top-k found in the top-k by quantized score
+    fn recall(
+        encoding: BinaryQuantizationEncoding,
+        query_encoding: Option<BinaryQuantizationQueryEncoding>,
+    ) -> f32 {
+        let dim = 100;
+        let count = 1000;
+        let top = 10;
+        let mut rng = StdRng::seed_from_u64(42);
+        let vectors: Vec<Vec<f32>> = (0..count)
+            .map(|_| (0..dim).map(|_| rng.gen_range(-1.0..1.0)).collect())
+            .collect();
+
+        let vector_parameters = VectorParameters {
+            dim,
+            count,
+            distance_type: DistanceType::Dot,
+            invert: false,
+        };
+        let quantized_vector_size =
+            EncodedVectorsMultiBit::<ChunkedVectors<u8>>::get_quantized_vector_size(
+                &vector_parameters,
+                encoding,
+            );
+        let encoded = EncodedVectorsMultiBit::encode(
+            vectors.iter(),
+            ChunkedVectors::<u8>::new(quantized_vector_size),
+            &vector_parameters,
+            encoding,
+            query_encoding,
+            || false,
+        )
+        .unwrap();
+
+        let queries = 20;
+        let mut found = 0;
+        for _ in 0..queries {
+            let query: Vec<f32> = (0..dim).map(|_| rng.gen_range(-1.0..1.0)).collect();
+            let mut exact: Vec<_> = (0..count).map(|i| (dot(&query, &vectors[i]), i)).collect();
+            exact.sort_by(|a, b| b.0.total_cmp(&a.0));
+
+            let encoded_query = encoded.encode_query(&query);
+            let mut approx: Vec<_> = (0..count)
+                .map(|i| (encoded.score_point(&encoded_query, i as PointOffsetType), i))
+                .collect();
+            approx.sort_by(|a, b| b.0.total_cmp(&a.0));
+
+            let approx_top: Vec<_> = approx[..top].iter().map(|(_, i)| *i).collect();
+            found += exact[..top]
+                .iter()
+                .filter(|(_, i)| approx_top.contains(i))
+                .count();
+        }
+        found as f32 / (queries * top) as f32
+    }
+
+    #[test]
+    fn test_spread_bits() {
+        let spread = EncodedVectorsMultiBit::<ChunkedVectors<u8>>::spread_bits(0b1001);
+        assert_eq!(spread, 0b11000011);
+        let spread = EncodedVectorsMultiBit::<ChunkedVectors<u8>>::spread_bits(u32::MAX);
+        assert_eq!(spread, u64::MAX);
+    }
+
+    #[test]
+    fn test_multi_bit_improves_recall() {
+        let one_bit = recall(BinaryQuantizationEncoding::OneBit, None);
+        let one_and_half_bits = recall(BinaryQuantizationEncoding::OneAndHalfBits, None);
+        let two_bits = recall(BinaryQuantizationEncoding::TwoBits, None);
+        let two_bits_asymmetric = recall(
+            BinaryQuantizationEncoding::TwoBits,
+            Some(BinaryQuantizationQueryEncoding::Scalar8Bits),
+        );
+
+        assert!(
+            one_and_half_bits > one_bit,
+            "{one_and_half_bits} <= {one_bit}"
+        );
+        assert!(two_bits > one_bit, "{two_bits} <= {one_bit}");
+        assert!(
+            two_bits_asymmetric >= two_bits,
+            "{two_bits_asymmetric} <= {two_bits}"
+        );
+    }
+
+    /// Decode stored code into level values
+    fn decode(encoded: &EncodedVectorsMultiBit<ChunkedVectors<u8>>, code: &[u8]) -> Vec<f32> {
+        let dim = encoded.metadata.vector_parameters.dim;
+        (0..dim)
+            .map(|i| {
+                let (sign, magnitude) = encoded.code_word(code, i / BITS_IN_WORD);
+                let bit = 1 << (i % BITS_IN_WORD);
+                let level = if magnitude & bit != 0 { 3.0 } else { 1.0 };
+                if sign & bit != 0 {
+                    level
+                } else {
+                    -level
+                }
+            })
+            .collect()
+    }
+
+    /// Decode scalar query into level values
+    fn decode_query(
+        encoded: &EncodedVectorsMultiBit<ChunkedVectors<u8>>,
+        query: &EncodedScalarQuery,
+    ) -> Vec<f32> {
+        let dim = encoded.metadata.vector_parameters.dim;
+        (0..dim)
+            .map(|i| {
+                let shifted: i64 = (0..query.bits)
+                    .map(|bit| {
+                        let plane = query.planes[bit * encoded.words_count + i / BITS_IN_WORD];
+                        i64::from((plane >> (i % BITS_IN_WORD)) & 1 == 1) << bit
+                    })
+                    .sum();
+                (shifted - query.offset) as f32 * query.scale
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_popcount_scores_match_decoded() {
+        let dim = 131;
+        let count = 20;
+        let mut rng = StdRng::seed_from_u64(42);
+        let vectors: Vec<Vec<f32>> = (0..count)
+            .map(|_| (0..dim).map(|_| rng.gen_range(-1.0..1.0)).collect())
+            .collect();
+        let vector_parameters = VectorParameters {
+            dim,
+            count,
+            distance_type: DistanceType::Dot,
+            invert: false,
+        };
+
+        for encoding in [
+            BinaryQuantizationEncoding::OneBit,
+            BinaryQuantizationEncoding::OneAndHalfBits,
+            BinaryQuantizationEncoding::TwoBits,
+        ] {
+            let size = EncodedVectorsMultiBit::<ChunkedVectors<u8>>::get_quantized_vector_size(
+                &vector_parameters,
+                encoding,
+            );
+            let encoded = EncodedVectorsMultiBit::encode(
+                vectors.iter(),
+                ChunkedVectors::<u8>::new(size),
+                &vector_parameters,
+                encoding,
+                Some(BinaryQuantizationQueryEncoding::Scalar4Bits),
+                || false,
+            )
+            .unwrap();
+            let decoded: Vec<_> = (0..count)
+                .map(|i| decode(&encoded, encoded.encoded_vectors.get_vector_data(i, size)))
+                .collect();
+
+            for (i, decoded_a) in decoded.iter().enumerate() {
+                for (j, decoded_b) in decoded.iter().enumerate() {
+                    let expected = dot(decoded_a, decoded_b);
+                    let score = encoded.score_internal(i as PointOffsetType, j as PointOffsetType);
+                    assert_eq!(score, expected, "{encoding:?}: {i} {j}");
+                }
+            }
+
+            let query: Vec<f32> = (0..dim).map(|_| rng.gen_range(-1.0..1.0)).collect();
+            let EncodedMultiBitQuery::Scalar(encoded_query) = encoded.encode_query(&query) else {
+                panic!("scalar query expected");
+            };
+            let decoded_query = decode_query(&encoded, &encoded_query);
+            for (i, decoded_vector) in decoded.iter().enumerate() {
+                let expected = dot(&decoded_query, decoded_vector);
+                let score = encoded.score_scalar_query(
+                    &encoded_query,
+                    encoded.encoded_vectors.get_vector_data(i, size),
+                );
+                assert!(
+                    (score - expected).abs() < 1e-3 * expected.abs().max(1.0),
+                    "{encoding:?}: {score} != {expected}"
+                );
+            }
+        }
+    }
+}
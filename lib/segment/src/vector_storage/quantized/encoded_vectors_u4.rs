@@ -0,0 +1,336 @@
+use std::path::Path;
+
+use common::types::PointOffsetType;
+use quantization::{DistanceType, EncodedStorage, EncodedStorageBuilder, VectorParameters};
+use serde::{Deserialize, Serialize};
+
+use super::u4_kernels::{dot_u4, manhattan_u4};
+use crate::common::operation_error::{OperationError, OperationResult};
+
+/// Maximal value of a 4-bit code
+const MAX_CODE: u8 = 15;
+
+/// Maximal number of values used to estimate quantiles
+const QUANTILE_SAMPLE_SIZE: usize = 1_000_000;
+
+/// Size of the per-vector offset, stored before the codes
+const OFFSET_SIZE: usize = std::mem::size_of::<f32>();
+
+/// Scalar quantization into 4-bit integers, two dimensions per byte.
+///
+/// All dimensions share the same bounds, as in 8-bit scalar quantization.
+/// A value `x` is encoded as `c = round((x - min) / alpha)` in range `[0, 15]`.
+///
+/// Each encoded vector starts with a precomputed offset, so that the score of
+/// two vectors only requires the sum of products of their codes:
+/// - `Dot`: `dot(x, y) = offset(x) + offset(y) + alpha^2 * sum(c_x * c_y)`,
+///   where `offset(x) = min * alpha * sum(c_x) + dim * min^2 / 2`
+/// - `L2`: `|x - y|^2 = offset(x) + offset(y) - 2 * alpha^2 * sum(c_x * c_y)`,
+///   where `offset(x) = alpha^2 * sum(c_x^2)`
+/// - `L1`: `|x - y| = alpha * sum(|c_x - c_y|)`, offset is not used
+pub struct EncodedVectorsU4<TStorage: EncodedStorage> {
+    encoded_vectors: TStorage,
+    metadata: Metadata,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Metadata {
+    vector_parameters: VectorParameters,
+    min: f32,
+    alpha: f32,
+}
+
+impl<TStorage: EncodedStorage> EncodedVectorsU4<TStorage> {
+    pub fn encode<'a>(
+        orig_data: impl Iterator<Item = impl AsRef<[f32]> + 'a> + Clone,
+        mut storage_builder: impl EncodedStorageBuilder<TStorage>,
+        vector_parameters: &VectorParameters,
+        quantile: Option<f32>,
+        stopped: impl Fn() -> bool,
+    ) -> OperationResult<Self> {
+        let (min, max) = Self::find_bounds(orig_data.clone(), vector_parameters, quantile);
+        let alpha = if max - min > f32::EPSILON {
+            (max - min) / f32::from(MAX_CODE)
+        } else {
+            1.0
+        };
+
+        let metadata = Metadata {
+            vector_parameters: vector_parameters.clone(),
+            min,
+            alpha,
+        };
+
+        let mut encoded = Vec::with_capacity(Self::get_quantized_vector_size(vector_parameters));
+        for vector in orig_data {
+            if stopped() {
+                return Err(OperationError::Cancelled {
+                    description: "Scalar quantization encoding was cancelled".to_string(),
+                });
+            }
+            encoded.clear();
+            Self::encode_vector(&metadata, vector.as_ref(), &mut encoded);
+            storage_builder.push_vector_data(&encoded);
+        }
+
+        Ok(Self {
+            encoded_vectors: storage_builder.build(),
+            metadata,
+        })
+    }
+
+    pub fn get_quantized_vector_size(vector_parameters: &VectorParameters) -> usize {
+        OFFSET_SIZE + vector_parameters.dim.div_ceil(2)
+    }
+
+    /// Bounds of the encoded range: either full range of values,
+    /// or the central `quantile` share of values of a sample
+    fn find_bounds<'a>(
+        orig_data: impl Iterator<Item = impl AsRef<[f32]> + 'a>,
+        vector_parameters: &VectorParameters,
+        quantile: Option<f32>,
+    ) -> (f32, f32) {
+        let vectors_to_sample = (QUANTILE_SAMPLE_SIZE / vector_parameters.dim.max(1)).max(1);
+        let step = vector_parameters.count.div_ceil(vectors_to_sample).max(1);
+
+        let mut values: Vec<f32> = orig_data
+            .step_by(step)
+            .flat_map(|vector| vector.as_ref().to_vec())
+            .filter(|value| value.is_finite())
+            .collect();
+        if values.is_empty() {
+            return (0.0, 0.0);
+        }
+
+        match quantile {
+            Some(quantile) if quantile < 1.0 => {
+                let last = values.len() - 1;
+                let lower_index = ((1.0 - quantile) / 2.0 * last as f32) as usize;
+                let upper_index = ((1.0 + quantile) / 2.0 * last as f32) as usize;
+                let (_, &mut lower, _) = values.select_nth_unstable_by(lower_index, f32::total_cmp);
+                let (_, &mut upper, _) = values.select_nth_unstable_by(upper_index, f32::total_cmp);
+                (lower, upper)
+            }
+            _ => values
+                .iter()
+                .fold((f32::MAX, f32::MIN), |(min, max), &value| {
+                    (min.min(value), max.max(value))
+                }),
+        }
+    }
+
+    fn encode_vector(metadata: &Metadata, vector: &[f32], encoded: &mut Vec<u8>) {
+        let codes: Vec<u8> = vector
+            .iter()
+            .map(|&value| {
+                let code = ((value - metadata.min) / metadata.alpha).round();
+                code.clamp(0.0, f32::from(MAX_CODE)) as u8
+            })
+            .collect();
+
+        let dim = codes.len() as f32;
+        let (sum, sum_squares) = codes
+            .iter()
+            .fold((0f32, 0f32), |(sum, sum_squares), &code| {
+                let code = f32::from(code);
+                (sum + code, sum_squares + code * code)
+            });
+        let Metadata {
+            vector_parameters,
+            min,
+            alpha,
+        } = metadata;
+        let offset = match vector_parameters.distance_type {
+            DistanceType::Dot => min * alpha * sum + dim * min * min / 2.0,
+            DistanceType::L2 => alpha * alpha * sum_squares,
+            DistanceType::L1 => 0.0,
+        };
+
+        encoded.extend_from_slice(&offset.to_le_bytes());
+        encoded.extend(
+            codes
+                .chunks(2)
+                .map(|pair| pair[0] | pair.get(1).map_or(0, |high| high << 4)),
+        );
+    }
+
+    fn score_codes(&self, encoded_a: &[u8], encoded_b: &[u8]) -> f32 {
+        let (offset_a, codes_a) = encoded_a.split_at(OFFSET_SIZE);
+        let (offset_b, codes_b) = encoded_b.split_at(OFFSET_SIZE);
+        let offset_a = f32::from_le_bytes(offset_a.try_into().unwrap());
+        let offset_b = f32::from_le_bytes(offset_b.try_into().unwrap());
+
+        let alpha = self.metadata.alpha;
+        let score = match self.metadata.vector_parameters.distance_type {
+            DistanceType::Dot => {
+                offset_a + offset_b + alpha * alpha * dot_u4(codes_a, codes_b) as f32
+            }
+            DistanceType::L2 => {
+                offset_a + offset_b - 2.0 * alpha * alpha * dot_u4(codes_a, codes_b) as f32
+            }
+            DistanceType::L1 => alpha * manhattan_u4(codes_a, codes_b) as f32,
+        };
+
+        if self.metadata.vector_parameters.invert {
+            -score
+        } else {
+            score
+        }
+    }
+}
+
+impl<TStorage: EncodedStorage> quantization::EncodedVectors<Vec<u8>>
+    for EncodedVectorsU4<TStorage>
+{
+    fn save(&self, data_path: &Path, meta_path: &Path) -> std::io::Result<()> {
+        let metadata_bytes = serde_json::to_vec(&self.metadata)?;
+        meta_path.parent().map(std::fs::create_dir_all);
+        std::fs::write(meta_path, metadata_bytes)?;
+
+        data_path.parent().map(std::fs::create_dir_all);
+        self.encoded_vectors.save_to_file(data_path)?;
+        Ok(())
+    }
+
+    fn load(
+        data_path: &Path,
+        meta_path: &Path,
+        vector_parameters: &VectorParameters,
+    ) -> std::io::Result<Self> {
+        let contents = std::fs::read_to_string(meta_path)?;
+        let metadata: Metadata = serde_json::from_str(&contents)?;
+        let quantized_vector_size = Self::get_quantized_vector_size(vector_parameters);
+        let encoded_vectors =
+            TStorage::from_file(data_path, quantized_vector_size, vector_parameters.count)?;
+        Ok(Self {
+            encoded_vectors,
+            metadata,
+        })
+    }
+
+    fn encode_query(&self, query: &[f32]) -> Vec<u8> {
+        let mut encoded = Vec::with_capacity(Self::get_quantized_vector_size(
+            &self.metadata.vector_parameters,
+        ));
+        Self::encode_vector(&self.metadata, query, &mut encoded);
+        encoded
+    }
+
+    fn score_point(&self, query: &Vec<u8>, i: PointOffsetType) -> f32 {
+        let vector_size = Self::get_quantized_vector_size(&self.metadata.vector_parameters);
+        let encoded = self
+            .encoded_vectors
+            .get_vector_data(i as usize, vector_size);
+        self.score_codes(query, encoded)
+    }
+
+    fn score_internal(&self, i: PointOffsetType, j: PointOffsetType) -> f32 {
+        let vector_size = Self::get_quantized_vector_size(&self.metadata.vector_parameters);
+        let encoded_a = self
+            .encoded_vectors
+            .get_vector_data(i as usize, vector_size);
+        let encoded_b = self
+            .encoded_vectors
+            .get_vector_data(j as usize, vector_size);
+        self.score_codes(encoded_a, encoded_b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quantization::EncodedVectors;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    use super::*;
+    use crate::vector_storage::chunked_vectors::ChunkedVectors;
+
+    #[test]
+    fn test_u4_scores_approximate_original() {
+        let dim = 65;
+        let count = 100;
+        let mut rng = StdRng::seed_from_u64(42);
+        let vectors: Vec<Vec<f32>> = (0..count)
+            .map(|_| (0..dim).map(|_| rng.gen_range(-1.0..1.0)).collect())
+            .collect();
+
+        let cases: [(DistanceType, bool, fn(&[f32], &[f32]) -> f32); 3] = [
+            (DistanceType::Dot, false, |a, b| {
+                a.iter().zip(b).map(|(a, b)| a * b).sum()
+            }),
+            (DistanceType::L2, true, |a, b| {
+                -a.iter().zip(b).map(|(a, b)| (a - b).powi(2)).sum::<f32>()
+            }),
+            (DistanceType::L1, true, |a, b| {
+                -a.iter().zip(b).map(|(a, b)| (a - b).abs()).sum::<f32>()
+            }),
+        ];
+
+        for (distance_type, invert, score_fn) in cases {
+            let vector_parameters = VectorParameters {
+                dim,
+                count,
+                distance_type,
+                invert,
+            };
+            let encoded = EncodedVectorsU4::encode(
+                vectors.iter(),
+                ChunkedVectors::<u8>::new(
+                    EncodedVectorsU4::<ChunkedVectors<u8>>::get_quantized_vector_size(
+                        &vector_parameters,
+                    ),
+                ),
+                &vector_parameters,
+                None,
+                || false,
+            )
+            .unwrap();
+
+            let query: Vec<f32> = (0..dim).map(|_| rng.gen_range(-1.0..1.0)).collect();
+            let encoded_query = encoded.encode_query(&query);
+            for (i, vector) in vectors.iter().enumerate() {
+                let expected = score_fn(&query, vector);
+                let score = encoded.score_point(&encoded_query, i as PointOffsetType);
+                // Quantization step is 2/15, error of each dimension is at most a half of it
+                assert!(
+                    (score - expected).abs() < 0.1 * dim as f32 * 0.5,
+                    "{distance_type:?}: {score} != {expected}"
+                );
+
+                let internal = encoded.score_internal(i as PointOffsetType, i as PointOffsetType);
+                let expected_internal = score_fn(vector, vector);
+                assert!(
+                    (internal - expected_internal).abs() < 0.1 * dim as f32 * 0.5,
+                    "{distance_type:?}: {internal} != {expected_internal}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_u4_quantile_bounds() {
+        let vector_parameters = VectorParameters {
+            dim: 10,
+            count: 100,
+            distance_type: DistanceType::Dot,
+            invert: false,
+        };
+        let vectors: Vec<Vec<f32>> = (0..100)
+            .map(|i| (0..10).map(|j| (i * 10 + j) as f32).collect())
+            .collect();
+
+        let (min, max) = EncodedVectorsU4::<ChunkedVectors<u8>>::find_bounds(
+            vectors.iter(),
+            &vector_parameters,
+            None,
+        );
+        assert_eq!((min, max), (0.0, 999.0));
+
+        let (min, max) = EncodedVectorsU4::<ChunkedVectors<u8>>::find_bounds(
+            vectors.iter(),
+            &vector_parameters,
+            Some(0.9),
+        );
+        assert_eq!((min, max), (49.0, 949.0));
+    }
+}
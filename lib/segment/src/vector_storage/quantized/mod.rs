@@ -1,5 +1,12 @@
+mod encoded_vectors_multi_bit;
+mod encoded_vectors_u4;
+mod prefetch_raw_scorer;
 mod quantized_custom_query_scorer;
 mod quantized_mmap_storage;
+mod quantized_multi_custom_query_scorer;
+mod quantized_multi_query_scorer;
+mod quantized_multivector_storage;
 mod quantized_query_scorer;
 mod quantized_scorer_builder;
 pub mod quantized_vectors;
+mod u4_kernels;
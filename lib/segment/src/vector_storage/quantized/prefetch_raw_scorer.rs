@@ -0,0 +1,277 @@
+use common::fixed_length_priority_queue::FixedLengthPriorityQueue;
+use common::types::{PointOffsetType, ScoreType, ScoredPointOffset};
+use parking_lot::Mutex;
+
+#[cfg(target_os = "linux")]
+use crate::vector_storage::async_io::UringReader;
+#[cfg(not(target_os = "linux"))]
+use crate::vector_storage::async_io_mock::UringReader;
+use crate::vector_storage::RawScorer;
+
+/// Number of points to prefetch at once, when scoring a stream of points
+const PREFETCH_BATCH_SIZE: usize = 1024;
+
+/// Raw scorer over mmap'd quantized vectors, which loads the vectors into the page cache with
+/// concurrent async reads before scoring them.
+///
+/// Without it, each vector missing in the page cache blocks the search on a page fault, one at a
+/// time.
+///
+/// Only batches of points are prefetched. Graph search scores a handful of neighbours at a time,
+/// a synchronous prefetch round trip per expansion costs more than the page faults it saves.
+pub(super) struct PrefetchRawScorer<'a> {
+    raw_scorer: Box<dyn RawScorer + 'a>,
+    uring_reader: &'a Mutex<UringReader<u8>>,
+}
+
+impl<'a> PrefetchRawScorer<'a> {
+    pub fn new(
+        raw_scorer: Box<dyn RawScorer + 'a>,
+        uring_reader: &'a Mutex<UringReader<u8>>,
+    ) -> Self {
+        Self {
+            raw_scorer,
+            uring_reader,
+        }
+    }
+
+    fn prefetch(&self, points: impl IntoIterator<Item = PointOffsetType>) {
+        let points = points
+            .into_iter()
+            .filter(|&point_id| self.raw_scorer.check_vector(point_id));
+        // Don't wait for concurrent searches, vectors are read through mmap anyway
+        let Some(mut uring_reader) = self.uring_reader.try_lock() else {
+            return;
+        };
+        // Failed prefetch only makes the search slower
+        if let Err(err) = uring_reader.prefetch(points) {
+            log::debug!("Failed to prefetch quantized vectors: {err}");
+        }
+    }
+}
+
+impl<'a> RawScorer for PrefetchRawScorer<'a> {
+    fn score_points(&self, points: &[PointOffsetType], scores: &mut [ScoredPointOffset]) -> usize {
+        self.raw_scorer.score_points(points, scores)
+    }
+
+    fn score_points_unfiltered(
+        &self,
+        points: &mut dyn Iterator<Item = PointOffsetType>,
+    ) -> Vec<ScoredPointOffset> {
+        let points: Vec<_> = points.collect();
+        self.prefetch(points.iter().copied());
+        self.raw_scorer
+            .score_points_unfiltered(&mut points.into_iter())
+    }
+
+    fn check_vector(&self, point: PointOffsetType) -> bool {
+        self.raw_scorer.check_vector(point)
+    }
+
+    fn score_point(&self, point: PointOffsetType) -> ScoreType {
+        self.raw_scorer.score_point(point)
+    }
+
+    fn score_internal(&self, point_a: PointOffsetType, point_b: PointOffsetType) -> ScoreType {
+        self.raw_scorer.score_internal(point_a, point_b)
+    }
+
+    fn peek_top_iter(
+        &self,
+        points: &mut dyn Iterator<Item = PointOffsetType>,
+        top: usize,
+    ) -> Vec<ScoredPointOffset> {
+        if top == 0 {
+            return vec![];
+        }
+
+        let mut pq = FixedLengthPriorityQueue::new(top);
+        let mut batch = Vec::with_capacity(PREFETCH_BATCH_SIZE);
+        loop {
+            batch.clear();
+            batch.extend(points.by_ref().take(PREFETCH_BATCH_SIZE));
+            if batch.is_empty() {
+                break;
+            }
+            self.prefetch(batch.iter().copied());
+            for scored_point_offset in self
+                .raw_scorer
+                .peek_top_iter(&mut batch.iter().copied(), top)
+            {
+                pq.push(scored_point_offset);
+            }
+        }
+        pq.into_vec()
+    }
+
+    fn peek_top_all(&self, top: usize) -> Vec<ScoredPointOffset> {
+        // Full scan reads the storage sequentially, kernel readahead does the job
+        self.raw_scorer.peek_top_all(top)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+    use std::sync::atomic::AtomicBool;
+    use std::sync::Arc;
+
+    use atomic_refcell::AtomicRefCell;
+    use io::file_operations::read_json;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use rstest::rstest;
+    use tempfile::Builder;
+
+    use super::*;
+    use crate::common::rocksdb_wrapper::{open_db, DB_VECTOR_CF};
+    use crate::data_types::vectors::QueryVector;
+    use crate::fixtures::index_fixtures::random_vector;
+    use crate::fixtures::payload_context_fixture::FixtureIdTracker;
+    use crate::id_tracker::IdTracker;
+    use crate::types::{
+        BinaryQuantizationConfig, BinaryQuantizationEncoding, CompressionRatio, Distance,
+        ProductQuantizationConfig, QuantizationConfig, ScalarQuantizationConfig, ScalarType,
+    };
+    use crate::vector_storage::dense::memmap_dense_vector_storage::open_memmap_vector_storage;
+    use crate::vector_storage::dense::simple_dense_vector_storage::open_simple_dense_vector_storage;
+    use crate::vector_storage::quantized::quantized_vectors::{
+        QuantizedVectors, QuantizedVectorsConfig, QUANTIZED_CONFIG_PATH, QUANTIZED_DATA_PATH,
+    };
+    use crate::vector_storage::VectorStorage;
+
+    const DIM: usize = 16;
+    /// More than a single prefetch batch
+    const NUM_POINTS: usize = PREFETCH_BATCH_SIZE + 500;
+
+    #[rstest]
+    #[case::scalar(ScalarQuantizationConfig {
+        r#type: ScalarType::Int8,
+        quantile: None,
+        always_ram: Some(false),
+    }.into())]
+    #[case::scalar_int4(ScalarQuantizationConfig {
+        r#type: ScalarType::Int4,
+        quantile: None,
+        always_ram: Some(false),
+    }.into())]
+    #[case::product(ProductQuantizationConfig {
+        compression: CompressionRatio::X4,
+        always_ram: Some(false),
+    }.into())]
+    #[case::binary(BinaryQuantizationConfig {
+        always_ram: Some(false),
+        encoding: None,
+        query_encoding: None,
+    }.into())]
+    #[case::binary_two_bits(BinaryQuantizationConfig {
+        always_ram: Some(false),
+        encoding: Some(BinaryQuantizationEncoding::TwoBits),
+        query_encoding: None,
+    }.into())]
+    fn test_prefetch_raw_scorer(#[case] quantization_config: QuantizationConfig) {
+        let mut rng = StdRng::seed_from_u64(42);
+        let dir = Builder::new().prefix("storage_dir").tempdir().unwrap();
+        let quantized_dir = Builder::new().prefix("quantized_dir").tempdir().unwrap();
+
+        let id_tracker = Arc::new(AtomicRefCell::new(FixtureIdTracker::new(NUM_POINTS)));
+        let storage = open_memmap_vector_storage(dir.path(), DIM, Distance::Dot).unwrap();
+        let mut borrowed_storage = storage.borrow_mut();
+        {
+            let db_dir = Builder::new().prefix("db_dir").tempdir().unwrap();
+            let db = open_db(db_dir.path(), &[DB_VECTOR_CF]).unwrap();
+            let simple_storage = open_simple_dense_vector_storage(
+                db,
+                DB_VECTOR_CF,
+                DIM,
+                Distance::Dot,
+                &AtomicBool::new(false),
+            )
+            .unwrap();
+            {
+                let mut borrowed_simple_storage = simple_storage.borrow_mut();
+                for i in 0..NUM_POINTS {
+                    let vector = random_vector(&mut rng, DIM);
+                    borrowed_simple_storage
+                        .insert_vector(i as PointOffsetType, vector.as_slice().into())
+                        .unwrap();
+                }
+            }
+            borrowed_storage
+                .update_from(
+                    &simple_storage.borrow(),
+                    &mut Box::new(0..NUM_POINTS as PointOffsetType),
+                    &Default::default(),
+                )
+                .unwrap();
+        }
+
+        let stopped = AtomicBool::new(false);
+        let quantized_vectors = QuantizedVectors::create(
+            &borrowed_storage,
+            &quantization_config,
+            None,
+            quantized_dir.path(),
+            1,
+            &stopped,
+        )
+        .unwrap();
+
+        // Quantized data is a plain array of vectors of the expected size
+        let config: QuantizedVectorsConfig =
+            read_json(&quantized_dir.path().join(QUANTIZED_CONFIG_PATH)).unwrap();
+        let quantized_vector_size = QuantizedVectors::quantized_vector_size(&config);
+        let data_file = File::open(quantized_dir.path().join(QUANTIZED_DATA_PATH)).unwrap();
+        assert_eq!(
+            data_file.metadata().unwrap().len() as usize,
+            quantized_vector_size * NUM_POINTS,
+        );
+        let uring_reader =
+            Mutex::new(UringReader::new(data_file, quantized_vector_size, 0).unwrap());
+
+        let borrowed_id_tracker = id_tracker.borrow();
+        let query: QueryVector = random_vector(&mut rng, DIM).into();
+        let new_raw_scorer = || {
+            quantized_vectors
+                .raw_scorer(
+                    query.clone(),
+                    borrowed_id_tracker.deleted_point_bitslice(),
+                    borrowed_storage.deleted_vector_bitslice(),
+                    &stopped,
+                )
+                .unwrap()
+        };
+        let raw_scorer = new_raw_scorer();
+        let prefetch_scorer = PrefetchRawScorer::new(new_raw_scorer(), &uring_reader);
+
+        let points: Vec<_> = (0..NUM_POINTS as PointOffsetType).collect();
+
+        let mut expected_scores = vec![ScoredPointOffset::default(); 10];
+        let mut scores = vec![ScoredPointOffset::default(); 10];
+        let expected_count = raw_scorer.score_points(&points[..10], &mut expected_scores);
+        let count = prefetch_scorer.score_points(&points[..10], &mut scores);
+        assert_eq!(count, expected_count);
+        assert_eq!(scores, expected_scores);
+
+        assert_eq!(
+            prefetch_scorer.score_points_unfiltered(&mut points.iter().copied()),
+            raw_scorer.score_points_unfiltered(&mut points.iter().copied()),
+        );
+
+        for top in [0, 1, 10, NUM_POINTS] {
+            let mut expected_top = raw_scorer.peek_top_iter(&mut points.iter().copied(), top);
+            let mut top_points = prefetch_scorer.peek_top_iter(&mut points.iter().copied(), top);
+            assert_eq!(top_points.len(), top);
+            // Scores of batches are merged, ties may come in a different order
+            expected_top.sort_by_key(|scored_point| scored_point.idx);
+            top_points.sort_by_key(|scored_point| scored_point.idx);
+            assert_eq!(top_points, expected_top);
+        }
+
+        assert_eq!(
+            prefetch_scorer.peek_top_all(10),
+            raw_scorer.peek_top_all(10)
+        );
+    }
+}
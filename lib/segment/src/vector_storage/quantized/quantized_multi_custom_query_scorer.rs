@@ -0,0 +1,150 @@
+use std::borrow::Cow;
+use std::marker::PhantomData;
+
+use common::types::{PointOffsetType, ScoreType};
+
+use super::quantized_multivector_storage::QuantizedMultivectorStorage;
+use crate::data_types::primitive::PrimitiveVectorElement;
+use crate::data_types::vectors::{
+    DenseVector, MultiDenseVector, TypedMultiDenseVector, TypedMultiDenseVectorRef,
+};
+use crate::spaces::metric::Metric;
+use crate::types::QuantizationConfig;
+use crate::vector_storage::query::{Query, TransformInto};
+use crate::vector_storage::query_scorer::{score_multi, QueryScorer};
+
+pub struct QuantizedMultiCustomQueryScorer<
+    'a,
+    TElement,
+    TMetric,
+    TEncodedQuery,
+    TEncodedVectors,
+    TQuery,
+    TOriginalQuery,
+> where
+    TElement: PrimitiveVectorElement,
+    TMetric: Metric<TElement>,
+    TEncodedVectors: quantization::EncodedVectors<TEncodedQuery>,
+    TQuery: Query<Vec<TEncodedQuery>>,
+    TOriginalQuery: Query<TypedMultiDenseVector<TElement>>,
+{
+    original_query: TOriginalQuery,
+    query: TQuery,
+    quantized_multivector_storage: &'a QuantizedMultivectorStorage<TEncodedVectors>,
+    phantom: PhantomData<TEncodedQuery>,
+    metric: PhantomData<TMetric>,
+    element: PhantomData<TElement>,
+}
+
+impl<'a, TElement, TMetric, TEncodedQuery, TEncodedVectors, TQuery, TOriginalQuery>
+    QuantizedMultiCustomQueryScorer<
+        'a,
+        TElement,
+        TMetric,
+        TEncodedQuery,
+        TEncodedVectors,
+        TQuery,
+        TOriginalQuery,
+    >
+where
+    TElement: PrimitiveVectorElement,
+    TMetric: Metric<TElement>,
+    TEncodedVectors: quantization::EncodedVectors<TEncodedQuery>,
+    TQuery: Query<Vec<TEncodedQuery>>,
+    TOriginalQuery: Query<TypedMultiDenseVector<TElement>>
+        + TransformInto<TQuery, TypedMultiDenseVector<TElement>, Vec<TEncodedQuery>>
+        + Clone,
+{
+    pub fn new<TInputQuery>(
+        raw_query: TInputQuery,
+        quantized_multivector_storage: &'a QuantizedMultivectorStorage<TEncodedVectors>,
+        quantization_config: &QuantizationConfig,
+    ) -> Self
+    where
+        TInputQuery: Query<MultiDenseVector>
+            + TransformInto<TOriginalQuery, MultiDenseVector, TypedMultiDenseVector<TElement>>,
+    {
+        let original_query: TOriginalQuery = raw_query
+            .transform(|raw_vector| {
+                let preprocessed_vector: DenseVector = raw_vector
+                    .multi_vectors()
+                    .flat_map(|slice| TMetric::preprocess(slice.to_vec()))
+                    .collect();
+                let preprocessed_vector =
+                    MultiDenseVector::new(preprocessed_vector, raw_vector.dim);
+                let original_vector =
+                    TElement::from_float_multivector(Cow::Owned(preprocessed_vector)).into_owned();
+                Ok(original_vector)
+            })
+            .unwrap();
+
+        let query: TQuery = original_query
+            .clone()
+            .transform(|original_vector| {
+                let encoded_vector = original_vector
+                    .multi_vectors()
+                    .map(|inner_vector| {
+                        let inner_vector_prequantized = TElement::quantization_preprocess(
+                            quantization_config,
+                            TMetric::distance(),
+                            inner_vector,
+                        );
+                        quantized_multivector_storage.encode_query(&inner_vector_prequantized)
+                    })
+                    .collect();
+                Ok(encoded_vector)
+            })
+            .unwrap();
+
+        Self {
+            original_query,
+            query,
+            quantized_multivector_storage,
+            phantom: PhantomData,
+            metric: PhantomData,
+            element: PhantomData,
+        }
+    }
+}
+
+impl<TElement, TMetric, TEncodedQuery, TEncodedVectors, TQuery, TOriginalQuery>
+    QueryScorer<TypedMultiDenseVector<TElement>>
+    for QuantizedMultiCustomQueryScorer<
+        '_,
+        TElement,
+        TMetric,
+        TEncodedQuery,
+        TEncodedVectors,
+        TQuery,
+        TOriginalQuery,
+    >
+where
+    TElement: PrimitiveVectorElement,
+    TMetric: Metric<TElement>,
+    TEncodedVectors: quantization::EncodedVectors<TEncodedQuery>,
+    TQuery: Query<Vec<TEncodedQuery>>,
+    TOriginalQuery: Query<TypedMultiDenseVector<TElement>>,
+{
+    fn score_stored(&self, idx: PointOffsetType) -> ScoreType {
+        self.query
+            .score_by(|this| self.quantized_multivector_storage.score_point(this, idx))
+    }
+
+    fn score(&self, v2: &TypedMultiDenseVector<TElement>) -> ScoreType {
+        debug_assert!(
+            false,
+            "This method is not expected to be called for quantized scorer"
+        );
+        self.original_query.score_by(|this| {
+            score_multi::<TElement, TMetric>(
+                self.quantized_multivector_storage.multi_vector_config(),
+                TypedMultiDenseVectorRef::from(this),
+                TypedMultiDenseVectorRef::from(v2),
+            )
+        })
+    }
+
+    fn score_internal(&self, _point_a: PointOffsetType, _point_b: PointOffsetType) -> ScoreType {
+        unimplemented!("Custom scorer compares against multiple vectors, not just one")
+    }
+}
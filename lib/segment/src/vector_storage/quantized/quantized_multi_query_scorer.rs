@@ -0,0 +1,96 @@
+use std::borrow::Cow;
+use std::marker::PhantomData;
+
+use common::types::{PointOffsetType, ScoreType};
+
+use super::quantized_multivector_storage::QuantizedMultivectorStorage;
+use crate::data_types::primitive::PrimitiveVectorElement;
+use crate::data_types::vectors::{
+    DenseVector, MultiDenseVector, TypedMultiDenseVector, TypedMultiDenseVectorRef,
+};
+use crate::spaces::metric::Metric;
+use crate::types::QuantizationConfig;
+use crate::vector_storage::query_scorer::{score_multi, QueryScorer};
+
+pub struct QuantizedMultiQueryScorer<'a, TElement, TMetric, TEncodedQuery, TEncodedVectors>
+where
+    TElement: PrimitiveVectorElement,
+    TMetric: Metric<TElement>,
+    TEncodedVectors: quantization::EncodedVectors<TEncodedQuery>,
+{
+    original_query: TypedMultiDenseVector<TElement>,
+    query: Vec<TEncodedQuery>,
+    quantized_multivector_storage: &'a QuantizedMultivectorStorage<TEncodedVectors>,
+    metric: PhantomData<TMetric>,
+}
+
+impl<'a, TElement, TMetric, TEncodedQuery, TEncodedVectors>
+    QuantizedMultiQueryScorer<'a, TElement, TMetric, TEncodedQuery, TEncodedVectors>
+where
+    TElement: PrimitiveVectorElement,
+    TMetric: Metric<TElement>,
+    TEncodedVectors: quantization::EncodedVectors<TEncodedQuery>,
+{
+    pub fn new(
+        raw_query: MultiDenseVector,
+        quantized_multivector_storage: &'a QuantizedMultivectorStorage<TEncodedVectors>,
+        quantization_config: &QuantizationConfig,
+    ) -> Self {
+        let raw_preprocessed_query: DenseVector = raw_query
+            .multi_vectors()
+            .flat_map(|slice| TMetric::preprocess(slice.to_vec()))
+            .collect();
+        let raw_preprocessed_query = MultiDenseVector::new(raw_preprocessed_query, raw_query.dim);
+        let original_query =
+            TElement::from_float_multivector(Cow::Owned(raw_preprocessed_query)).into_owned();
+
+        let query = original_query
+            .multi_vectors()
+            .map(|inner_vector| {
+                let inner_vector_prequantized = TElement::quantization_preprocess(
+                    quantization_config,
+                    TMetric::distance(),
+                    inner_vector,
+                );
+                quantized_multivector_storage.encode_query(&inner_vector_prequantized)
+            })
+            .collect();
+
+        Self {
+            original_query,
+            query,
+            quantized_multivector_storage,
+            metric: PhantomData,
+        }
+    }
+}
+
+impl<TElement, TMetric, TEncodedQuery, TEncodedVectors> QueryScorer<TypedMultiDenseVector<TElement>>
+    for QuantizedMultiQueryScorer<'_, TElement, TMetric, TEncodedQuery, TEncodedVectors>
+where
+    TElement: PrimitiveVectorElement,
+    TMetric: Metric<TElement>,
+    TEncodedVectors: quantization::EncodedVectors<TEncodedQuery>,
+{
+    fn score_stored(&self, idx: PointOffsetType) -> ScoreType {
+        self.quantized_multivector_storage
+            .score_point(&self.query, idx)
+    }
+
+    fn score(&self, v2: &TypedMultiDenseVector<TElement>) -> ScoreType {
+        debug_assert!(
+            false,
+            "This method is not expected to be called for quantized scorer"
+        );
+        score_multi::<TElement, TMetric>(
+            self.quantized_multivector_storage.multi_vector_config(),
+            TypedMultiDenseVectorRef::from(&self.original_query),
+            TypedMultiDenseVectorRef::from(v2),
+        )
+    }
+
+    fn score_internal(&self, point_a: PointOffsetType, point_b: PointOffsetType) -> ScoreType {
+        self.quantized_multivector_storage
+            .score_internal(point_a, point_b)
+    }
+}
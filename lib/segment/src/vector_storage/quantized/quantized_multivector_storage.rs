@@ -0,0 +1,267 @@
+use std::path::Path;
+
+use common::types::{PointOffsetType, ScoreType};
+use io::file_operations::atomic_save_bin;
+use quantization::EncodedVectors;
+use serde::{Deserialize, Serialize};
+
+use crate::common::operation_error::OperationResult;
+use crate::types::{MultiVectorComparator, MultiVectorConfig};
+
+/// Position of the inner vectors of a multivector point in the quantized storage
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize)]
+pub struct MultivectorOffset {
+    pub start: PointOffsetType,
+    pub count: PointOffsetType,
+}
+
+/// Quantized multivectors.
+///
+/// Inner vectors of all points are quantized as independent dense vectors,
+/// offsets map each point to the range of its inner vectors.
+pub struct QuantizedMultivectorStorage<TEncodedVectors> {
+    quantized_storage: TEncodedVectors,
+    offsets: Vec<MultivectorOffset>,
+    multi_vector_config: MultiVectorConfig,
+}
+
+impl<TEncodedVectors> QuantizedMultivectorStorage<TEncodedVectors> {
+    pub fn new(
+        quantized_storage: TEncodedVectors,
+        offsets: Vec<MultivectorOffset>,
+        multi_vector_config: MultiVectorConfig,
+    ) -> Self {
+        Self {
+            quantized_storage,
+            offsets,
+            multi_vector_config,
+        }
+    }
+
+    pub fn save<TEncodedQuery>(
+        &self,
+        data_path: &Path,
+        meta_path: &Path,
+        offsets_path: &Path,
+    ) -> OperationResult<()>
+    where
+        TEncodedVectors: EncodedVectors<TEncodedQuery>,
+    {
+        self.quantized_storage.save(data_path, meta_path)?;
+        atomic_save_bin(offsets_path, &self.offsets)?;
+        Ok(())
+    }
+
+    pub fn multi_vector_config(&self) -> &MultiVectorConfig {
+        &self.multi_vector_config
+    }
+
+    /// Encode a single inner vector of a query
+    pub fn encode_query<TEncodedQuery>(&self, query: &[f32]) -> TEncodedQuery
+    where
+        TEncodedVectors: EncodedVectors<TEncodedQuery>,
+    {
+        self.quantized_storage.encode_query(query)
+    }
+
+    /// Whether quantized scores only approximate the comparator, because query vectors are not
+    /// stored, so found points always have to be rescored
+    pub fn is_approximate_comparator(&self) -> bool {
+        match self.multi_vector_config.comparator {
+            MultiVectorComparator::MaxSim | MultiVectorComparator::MaxSimNormalized => false,
+            MultiVectorComparator::MeanPooledCosine | MultiVectorComparator::SymmetricMaxSim => {
+                true
+            }
+        }
+    }
+
+    /// Score encoded inner vectors of a query against the stored point
+    ///
+    /// Mean pooled cosine is approximated by the mean similarity of all pairs of vectors,
+    /// symmetric MaxSim by the normalized MaxSim from query to point.
+    pub fn score_point<TEncodedQuery>(
+        &self,
+        query: &[TEncodedQuery],
+        idx: PointOffsetType,
+    ) -> ScoreType
+    where
+        TEncodedVectors: EncodedVectors<TEncodedQuery>,
+    {
+        let offset = self.offsets[idx as usize];
+        let score =
+            |inner_query, inner_idx| self.quantized_storage.score_point(inner_query, inner_idx);
+        match self.multi_vector_config.comparator {
+            MultiVectorComparator::MaxSim => {
+                Self::score_max_similarity(query.iter(), offset, score)
+            }
+            MultiVectorComparator::MaxSimNormalized | MultiVectorComparator::SymmetricMaxSim => {
+                Self::score_max_similarity(query.iter(), offset, score) / query.len() as ScoreType
+            }
+            MultiVectorComparator::MeanPooledCosine => {
+                Self::score_mean_similarity(query.iter(), offset, score)
+            }
+        }
+    }
+
+    /// Score two stored points against each other
+    ///
+    /// Mean pooled cosine is approximated by the mean similarity of all pairs of vectors.
+    pub fn score_internal<TEncodedQuery>(
+        &self,
+        point_a: PointOffsetType,
+        point_b: PointOffsetType,
+    ) -> ScoreType
+    where
+        TEncodedVectors: EncodedVectors<TEncodedQuery>,
+    {
+        let offset_a = self.offsets[point_a as usize];
+        let offset_b = self.offsets[point_b as usize];
+        let inner_a = offset_a.start..offset_a.start + offset_a.count;
+        let score = |inner_a, inner_b| self.quantized_storage.score_internal(inner_a, inner_b);
+        match self.multi_vector_config.comparator {
+            MultiVectorComparator::MaxSim => Self::score_max_similarity(inner_a, offset_b, score),
+            MultiVectorComparator::MaxSimNormalized => {
+                Self::score_max_similarity(inner_a, offset_b, score) / offset_a.count as ScoreType
+            }
+            MultiVectorComparator::SymmetricMaxSim => {
+                let inner_b = offset_b.start..offset_b.start + offset_b.count;
+                let forward = Self::score_max_similarity(inner_a, offset_b, score)
+                    / offset_a.count as ScoreType;
+                let backward = Self::score_max_similarity(inner_b, offset_a, score)
+                    / offset_b.count as ScoreType;
+                (forward + backward) / 2.0
+            }
+            MultiVectorComparator::MeanPooledCosine => {
+                Self::score_mean_similarity(inner_a, offset_b, score)
+            }
+        }
+    }
+
+    /// Colbert MaxSim over quantized inner vectors, same as
+    /// [`crate::vector_storage::query_scorer::score_max_similarity`]
+    fn score_max_similarity<T>(
+        query: impl Iterator<Item = T>,
+        offset: MultivectorOffset,
+        score: impl Fn(T, PointOffsetType) -> ScoreType,
+    ) -> ScoreType
+    where
+        T: Copy,
+    {
+        debug_assert!(offset.count > 0);
+        let mut sum = 0.0;
+        for inner_query in query {
+            let mut max_sim = ScoreType::NEG_INFINITY;
+            // manual `max_by` for performance
+            for inner_idx in offset.start..offset.start + offset.count {
+                let sim = score(inner_query, inner_idx);
+                if sim > max_sim {
+                    max_sim = sim;
+                }
+            }
+            // sum of max similarity
+            sum += max_sim;
+        }
+        sum
+    }
+
+    /// Mean similarity of all pairs of query and point vectors
+    fn score_mean_similarity<T>(
+        query: impl Iterator<Item = T>,
+        offset: MultivectorOffset,
+        score: impl Fn(T, PointOffsetType) -> ScoreType,
+    ) -> ScoreType
+    where
+        T: Copy,
+    {
+        debug_assert!(offset.count > 0);
+        let mut sum = 0.0;
+        let mut pairs = 0;
+        for inner_query in query {
+            for inner_idx in offset.start..offset.start + offset.count {
+                sum += score(inner_query, inner_idx);
+                pairs += 1;
+            }
+        }
+        sum / pairs.max(1) as ScoreType
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    use super::*;
+    use crate::vector_storage::chunked_vectors::ChunkedVectors;
+
+    #[test]
+    fn test_quantized_max_similarity() {
+        let dim = 16;
+        let mut rng = StdRng::seed_from_u64(42);
+        let points: Vec<Vec<Vec<f32>>> = (0..20)
+            .map(|_| {
+                (0..rng.gen_range(1..5))
+                    .map(|_| (0..dim).map(|_| rng.gen_range(-1.0..1.0)).collect())
+                    .collect()
+            })
+            .collect();
+
+        let mut offsets = Vec::new();
+        let mut start = 0;
+        for point in &points {
+            let count = point.len() as PointOffsetType;
+            offsets.push(MultivectorOffset { start, count });
+            start += count;
+        }
+
+        let vector_parameters = quantization::VectorParameters {
+            dim,
+            count: start as usize,
+            distance_type: quantization::DistanceType::Dot,
+            invert: false,
+        };
+        let quantized_storage = quantization::EncodedVectorsU8::encode(
+            points.iter().flatten(),
+            ChunkedVectors::<u8>::new(
+                quantization::EncodedVectorsU8::<ChunkedVectors<u8>>::get_quantized_vector_size(
+                    &vector_parameters,
+                ),
+            ),
+            &vector_parameters,
+            None,
+            || false,
+        )
+        .unwrap();
+        let storage = QuantizedMultivectorStorage::new(
+            quantized_storage,
+            offsets,
+            MultiVectorConfig::default(),
+        );
+
+        let dot = |a: &[f32], b: &[f32]| -> f32 { a.iter().zip(b).map(|(a, b)| a * b).sum() };
+        let max_sim = |a: &[Vec<f32>], b: &[Vec<f32>]| -> f32 {
+            a.iter()
+                .map(|a| {
+                    b.iter()
+                        .map(|b| dot(a, b))
+                        .fold(f32::NEG_INFINITY, f32::max)
+                })
+                .sum()
+        };
+
+        let query = &points[0];
+        let encoded_query: Vec<_> = query
+            .iter()
+            .map(|inner| storage.encode_query(inner))
+            .collect();
+        for (idx, point) in points.iter().enumerate() {
+            let expected = max_sim(query, point);
+            let score = storage.score_point(&encoded_query, idx as PointOffsetType);
+            assert!((expected - score).abs() < 0.5, "{expected} vs {score}");
+
+            let expected = max_sim(point, &points[1]);
+            let score = storage.score_internal(idx as PointOffsetType, 1);
+            assert!((expected - score).abs() < 0.5, "{expected} vs {score}");
+        }
+    }
+}
@@ -4,19 +4,25 @@ use bitvec::slice::BitSlice;
 use quantization::EncodedVectors;
 
 use super::quantized_custom_query_scorer::QuantizedCustomQueryScorer;
+use super::quantized_multi_custom_query_scorer::QuantizedMultiCustomQueryScorer;
+use super::quantized_multi_query_scorer::QuantizedMultiQueryScorer;
+use super::quantized_multivector_storage::QuantizedMultivectorStorage;
 use super::quantized_query_scorer::QuantizedQueryScorer;
 use super::quantized_vectors::QuantizedVectorStorage;
-use crate::common::operation_error::OperationResult;
+use crate::common::operation_error::{OperationError, OperationResult};
 use crate::data_types::primitive::PrimitiveVectorElement;
 use crate::data_types::vectors::{
-    DenseVector, QueryVector, VectorElementType, VectorElementTypeByte,
+    DenseVector, MultiDenseVector, QueryVector, VectorElementType, VectorElementTypeByte,
+    VectorElementTypeHalf,
 };
 use crate::spaces::metric::Metric;
-use crate::spaces::simple::{CosineMetric, DotProductMetric, EuclidMetric, ManhattanMetric};
+use crate::spaces::simple::{
+    CosineMetric, DotProductMetric, EuclidMetric, HammingMetric, ManhattanMetric,
+};
 use crate::types::{Distance, QuantizationConfig, VectorStorageDatatype};
 use crate::vector_storage::query::context_query::ContextQuery;
 use crate::vector_storage::query::discovery_query::DiscoveryQuery;
-use crate::vector_storage::query::reco_query::RecoQuery;
+use crate::vector_storage::query::reco_query::{RecoQuery, RecoSumScoresQuery};
 use crate::vector_storage::query::TransformInto;
 use crate::vector_storage::{raw_scorer_from_query_scorer, RawScorer};
 
@@ -64,6 +70,10 @@ impl<'a> QuantizedScorerBuilder<'a> {
                 Distance::Manhattan => {
                     self.build_with_metric::<VectorElementType, ManhattanMetric>()
                 }
+                Distance::Hamming => self.build_with_metric::<VectorElementType, HammingMetric>(),
+                distance @ (Distance::Jaccard | Distance::Minkowski(_)) => {
+                    Err(unsupported_quantization_error(*distance))
+                }
             },
             VectorStorageDatatype::Uint8 => match self.distance {
                 Distance::Cosine => self.build_with_metric::<VectorElementTypeByte, CosineMetric>(),
@@ -74,6 +84,28 @@ impl<'a> QuantizedScorerBuilder<'a> {
                 Distance::Manhattan => {
                     self.build_with_metric::<VectorElementTypeByte, ManhattanMetric>()
                 }
+                Distance::Hamming => {
+                    self.build_with_metric::<VectorElementTypeByte, HammingMetric>()
+                }
+                distance @ (Distance::Jaccard | Distance::Minkowski(_)) => {
+                    Err(unsupported_quantization_error(*distance))
+                }
+            },
+            VectorStorageDatatype::Float16 => match self.distance {
+                Distance::Cosine => self.build_with_metric::<VectorElementTypeHalf, CosineMetric>(),
+                Distance::Euclid => self.build_with_metric::<VectorElementTypeHalf, EuclidMetric>(),
+                Distance::Dot => {
+                    self.build_with_metric::<VectorElementTypeHalf, DotProductMetric>()
+                }
+                Distance::Manhattan => {
+                    self.build_with_metric::<VectorElementTypeHalf, ManhattanMetric>()
+                }
+                Distance::Hamming => {
+                    self.build_with_metric::<VectorElementTypeHalf, HammingMetric>()
+                }
+                distance @ (Distance::Jaccard | Distance::Minkowski(_)) => {
+                    Err(unsupported_quantization_error(*distance))
+                }
             },
         }
     }
@@ -90,6 +122,12 @@ impl<'a> QuantizedScorerBuilder<'a> {
             QuantizedVectorStorage::ScalarMmap(storage) => {
                 self.new_quantized_scorer::<TElement, TMetric, _>(storage)
             }
+            QuantizedVectorStorage::ScalarInt4Ram(storage) => {
+                self.new_quantized_scorer::<TElement, TMetric, _>(storage)
+            }
+            QuantizedVectorStorage::ScalarInt4Mmap(storage) => {
+                self.new_quantized_scorer::<TElement, TMetric, _>(storage)
+            }
             QuantizedVectorStorage::PQRam(storage) => {
                 self.new_quantized_scorer::<TElement, TMetric, _>(storage)
             }
@@ -102,6 +140,42 @@ impl<'a> QuantizedScorerBuilder<'a> {
             QuantizedVectorStorage::BinaryMmap(storage) => {
                 self.new_quantized_scorer::<TElement, TMetric, _>(storage)
             }
+            QuantizedVectorStorage::BinaryMultiBitRam(storage) => {
+                self.new_quantized_scorer::<TElement, TMetric, _>(storage)
+            }
+            QuantizedVectorStorage::BinaryMultiBitMmap(storage) => {
+                self.new_quantized_scorer::<TElement, TMetric, _>(storage)
+            }
+            QuantizedVectorStorage::ScalarRamMulti(storage) => {
+                self.new_multi_quantized_scorer::<TElement, TMetric, _, _>(storage)
+            }
+            QuantizedVectorStorage::ScalarMmapMulti(storage) => {
+                self.new_multi_quantized_scorer::<TElement, TMetric, _, _>(storage)
+            }
+            QuantizedVectorStorage::ScalarInt4RamMulti(storage) => {
+                self.new_multi_quantized_scorer::<TElement, TMetric, _, _>(storage)
+            }
+            QuantizedVectorStorage::ScalarInt4MmapMulti(storage) => {
+                self.new_multi_quantized_scorer::<TElement, TMetric, _, _>(storage)
+            }
+            QuantizedVectorStorage::PQRamMulti(storage) => {
+                self.new_multi_quantized_scorer::<TElement, TMetric, _, _>(storage)
+            }
+            QuantizedVectorStorage::PQMmapMulti(storage) => {
+                self.new_multi_quantized_scorer::<TElement, TMetric, _, _>(storage)
+            }
+            QuantizedVectorStorage::BinaryRamMulti(storage) => {
+                self.new_multi_quantized_scorer::<TElement, TMetric, _, _>(storage)
+            }
+            QuantizedVectorStorage::BinaryMmapMulti(storage) => {
+                self.new_multi_quantized_scorer::<TElement, TMetric, _, _>(storage)
+            }
+            QuantizedVectorStorage::BinaryMultiBitRamMulti(storage) => {
+                self.new_multi_quantized_scorer::<TElement, TMetric, _, _>(storage)
+            }
+            QuantizedVectorStorage::BinaryMultiBitMmapMulti(storage) => {
+                self.new_multi_quantized_scorer::<TElement, TMetric, _, _>(storage)
+            }
         }
     }
 
@@ -144,6 +218,15 @@ impl<'a> QuantizedScorerBuilder<'a> {
                 );
                 raw_scorer_from_query_scorer(query_scorer, point_deleted, vec_deleted, is_stopped)
             }
+            QueryVector::RecommendSumScores(reco_query) => {
+                let reco_query: RecoSumScoresQuery<DenseVector> = reco_query.transform_into()?;
+                let query_scorer = QuantizedCustomQueryScorer::<TElement, TMetric, _, _, _, _>::new(
+                    reco_query,
+                    quantized_storage,
+                    quantization_config,
+                );
+                raw_scorer_from_query_scorer(query_scorer, point_deleted, vec_deleted, is_stopped)
+            }
             QueryVector::Discovery(discovery_query) => {
                 let discovery_query: DiscoveryQuery<DenseVector> =
                     discovery_query.transform_into()?;
@@ -165,4 +248,88 @@ impl<'a> QuantizedScorerBuilder<'a> {
             }
         }
     }
+
+    #[inline]
+    fn new_multi_quantized_scorer<TElement, TMetric, TEncodedQuery, TEncodedVectors>(
+        self,
+        quantized_multivector_storage: &'a QuantizedMultivectorStorage<TEncodedVectors>,
+    ) -> OperationResult<Box<dyn RawScorer + 'a>>
+    where
+        TElement: PrimitiveVectorElement + 'a,
+        TMetric: Metric<TElement> + 'a,
+        TEncodedQuery: 'a,
+        TEncodedVectors: EncodedVectors<TEncodedQuery>,
+    {
+        let Self {
+            quantized_storage: _same_as_quantized_storage_in_args,
+            quantization_config,
+            query,
+            point_deleted,
+            vec_deleted,
+            is_stopped,
+            distance: _,
+            datatype: _,
+        } = self;
+
+        match query {
+            QueryVector::Nearest(vector) => {
+                let query_scorer = QuantizedMultiQueryScorer::<TElement, TMetric, _, _>::new(
+                    vector.try_into()?,
+                    quantized_multivector_storage,
+                    quantization_config,
+                );
+                raw_scorer_from_query_scorer(query_scorer, point_deleted, vec_deleted, is_stopped)
+            }
+            QueryVector::Recommend(reco_query) => {
+                let reco_query: RecoQuery<MultiDenseVector> = reco_query.transform_into()?;
+                let query_scorer =
+                    QuantizedMultiCustomQueryScorer::<TElement, TMetric, _, _, _, _>::new(
+                        reco_query,
+                        quantized_multivector_storage,
+                        quantization_config,
+                    );
+                raw_scorer_from_query_scorer(query_scorer, point_deleted, vec_deleted, is_stopped)
+            }
+            QueryVector::RecommendSumScores(reco_query) => {
+                let reco_query: RecoSumScoresQuery<MultiDenseVector> =
+                    reco_query.transform_into()?;
+                let query_scorer =
+                    QuantizedMultiCustomQueryScorer::<TElement, TMetric, _, _, _, _>::new(
+                        reco_query,
+                        quantized_multivector_storage,
+                        quantization_config,
+                    );
+                raw_scorer_from_query_scorer(query_scorer, point_deleted, vec_deleted, is_stopped)
+            }
+            QueryVector::Discovery(discovery_query) => {
+                let discovery_query: DiscoveryQuery<MultiDenseVector> =
+                    discovery_query.transform_into()?;
+                let query_scorer =
+                    QuantizedMultiCustomQueryScorer::<TElement, TMetric, _, _, _, _>::new(
+                        discovery_query,
+                        quantized_multivector_storage,
+                        quantization_config,
+                    );
+                raw_scorer_from_query_scorer(query_scorer, point_deleted, vec_deleted, is_stopped)
+            }
+            QueryVector::Context(context_query) => {
+                let context_query: ContextQuery<MultiDenseVector> =
+                    context_query.transform_into()?;
+                let query_scorer =
+                    QuantizedMultiCustomQueryScorer::<TElement, TMetric, _, _, _, _>::new(
+                        context_query,
+                        quantized_multivector_storage,
+                        quantization_config,
+                    );
+                raw_scorer_from_query_scorer(query_scorer, point_deleted, vec_deleted, is_stopped)
+            }
+        }
+    }
+}
+
+/// Error for a distance, which vectors are never quantized with, see [`Distance::is_quantizable`]
+pub(super) fn unsupported_quantization_error(distance: Distance) -> OperationError {
+    OperationError::service_error(format!(
+        "Quantization is not supported for {distance:?} distance"
+    ))
 }
@@ -1,46 +1,229 @@
+use std::borrow::Cow;
+use std::fs::File;
 use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, Ordering};
 
 use bitvec::slice::BitSlice;
 use common::types::PointOffsetType;
-use io::file_operations::{atomic_save_json, read_json};
+use io::file_operations::{atomic_save_json, read_bin, read_json};
+use parking_lot::Mutex;
 use quantization::encoded_vectors_binary::EncodedVectorsBin;
 use quantization::{EncodedVectors, EncodedVectorsPQ, EncodedVectorsU8};
 use serde::{Deserialize, Serialize};
 
-use super::quantized_scorer_builder::QuantizedScorerBuilder;
+use super::encoded_vectors_multi_bit::EncodedVectorsMultiBit;
+use super::encoded_vectors_u4::EncodedVectorsU4;
+use super::prefetch_raw_scorer::PrefetchRawScorer;
+use super::quantized_multivector_storage::{MultivectorOffset, QuantizedMultivectorStorage};
+use super::quantized_scorer_builder::{unsupported_quantization_error, QuantizedScorerBuilder};
 use crate::common::operation_error::{OperationError, OperationResult};
 use crate::common::vector_utils::TrySetCapacityExact;
 use crate::data_types::primitive::PrimitiveVectorElement;
 use crate::data_types::vectors::{QueryVector, VectorElementType};
+use crate::spaces::metric::Metric;
+use crate::spaces::simple::CosineMetric;
 use crate::types::{
-    BinaryQuantization, BinaryQuantizationConfig, CompressionRatio, Distance, ProductQuantization,
-    ProductQuantizationConfig, QuantizationConfig, ScalarQuantization, ScalarQuantizationConfig,
-    VectorStorageDatatype,
+    BinaryQuantization, BinaryQuantizationConfig, CompressionRatio, Distance, MultiVectorConfig,
+    ProductQuantization, ProductQuantizationConfig, QuantizationConfig, ScalarQuantization,
+    ScalarQuantizationConfig, ScalarType, VectorStorageDatatype,
 };
+#[cfg(target_os = "linux")]
+use crate::vector_storage::async_io::UringReader;
+#[cfg(not(target_os = "linux"))]
+use crate::vector_storage::async_io_mock::UringReader;
 use crate::vector_storage::chunked_vectors::ChunkedVectors;
+use crate::vector_storage::common::get_async_scorer;
 use crate::vector_storage::quantized::quantized_mmap_storage::{
     QuantizedMmapStorage, QuantizedMmapStorageBuilder,
 };
-use crate::vector_storage::{DenseVectorStorage, RawScorer, VectorStorage, VectorStorageEnum};
+use crate::vector_storage::{
+    DenseVectorStorage, MultiVectorStorage, RawScorer, VectorStorage, VectorStorageEnum,
+};
 
 pub const QUANTIZED_CONFIG_PATH: &str = "quantized.config.json";
 pub const QUANTIZED_DATA_PATH: &str = "quantized.data";
 pub const QUANTIZED_META_PATH: &str = "quantized.meta.json";
+pub const QUANTIZED_OFFSETS_PATH: &str = "quantized.offsets.data";
 
 #[derive(Deserialize, Serialize, Clone)]
 pub struct QuantizedVectorsConfig {
     pub quantization_config: QuantizationConfig,
     pub vector_parameters: quantization::VectorParameters,
+    /// Only this many first dimensions of the vectors are quantized
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prefix_size: Option<usize>,
 }
 
 pub enum QuantizedVectorStorage {
     ScalarRam(EncodedVectorsU8<ChunkedVectors<u8>>),
     ScalarMmap(EncodedVectorsU8<QuantizedMmapStorage>),
+    ScalarInt4Ram(EncodedVectorsU4<ChunkedVectors<u8>>),
+    ScalarInt4Mmap(EncodedVectorsU4<QuantizedMmapStorage>),
     PQRam(EncodedVectorsPQ<ChunkedVectors<u8>>),
     PQMmap(EncodedVectorsPQ<QuantizedMmapStorage>),
     BinaryRam(EncodedVectorsBin<ChunkedVectors<u8>>),
     BinaryMmap(EncodedVectorsBin<QuantizedMmapStorage>),
+    BinaryMultiBitRam(EncodedVectorsMultiBit<ChunkedVectors<u8>>),
+    BinaryMultiBitMmap(EncodedVectorsMultiBit<QuantizedMmapStorage>),
+    ScalarRamMulti(QuantizedMultivectorStorage<EncodedVectorsU8<ChunkedVectors<u8>>>),
+    ScalarMmapMulti(QuantizedMultivectorStorage<EncodedVectorsU8<QuantizedMmapStorage>>),
+    ScalarInt4RamMulti(QuantizedMultivectorStorage<EncodedVectorsU4<ChunkedVectors<u8>>>),
+    ScalarInt4MmapMulti(QuantizedMultivectorStorage<EncodedVectorsU4<QuantizedMmapStorage>>),
+    PQRamMulti(QuantizedMultivectorStorage<EncodedVectorsPQ<ChunkedVectors<u8>>>),
+    PQMmapMulti(QuantizedMultivectorStorage<EncodedVectorsPQ<QuantizedMmapStorage>>),
+    BinaryRamMulti(QuantizedMultivectorStorage<EncodedVectorsBin<ChunkedVectors<u8>>>),
+    BinaryMmapMulti(QuantizedMultivectorStorage<EncodedVectorsBin<QuantizedMmapStorage>>),
+    BinaryMultiBitRamMulti(QuantizedMultivectorStorage<EncodedVectorsMultiBit<ChunkedVectors<u8>>>),
+    BinaryMultiBitMmapMulti(
+        QuantizedMultivectorStorage<EncodedVectorsMultiBit<QuantizedMmapStorage>>,
+    ),
+}
+
+impl QuantizedVectorStorage {
+    pub fn is_on_disk(&self) -> bool {
+        match self {
+            QuantizedVectorStorage::ScalarRam(_)
+            | QuantizedVectorStorage::ScalarInt4Ram(_)
+            | QuantizedVectorStorage::PQRam(_)
+            | QuantizedVectorStorage::BinaryRam(_)
+            | QuantizedVectorStorage::BinaryMultiBitRam(_)
+            | QuantizedVectorStorage::ScalarRamMulti(_)
+            | QuantizedVectorStorage::ScalarInt4RamMulti(_)
+            | QuantizedVectorStorage::PQRamMulti(_)
+            | QuantizedVectorStorage::BinaryRamMulti(_)
+            | QuantizedVectorStorage::BinaryMultiBitRamMulti(_) => false,
+            QuantizedVectorStorage::ScalarMmap(_)
+            | QuantizedVectorStorage::ScalarInt4Mmap(_)
+            | QuantizedVectorStorage::PQMmap(_)
+            | QuantizedVectorStorage::BinaryMmap(_)
+            | QuantizedVectorStorage::BinaryMultiBitMmap(_)
+            | QuantizedVectorStorage::ScalarMmapMulti(_)
+            | QuantizedVectorStorage::ScalarInt4MmapMulti(_)
+            | QuantizedVectorStorage::PQMmapMulti(_)
+            | QuantizedVectorStorage::BinaryMmapMulti(_)
+            | QuantizedVectorStorage::BinaryMultiBitMmapMulti(_) => true,
+        }
+    }
+
+    /// Whether quantized multivectors only approximate the comparator of the vectors
+    fn is_approximate_comparator(&self) -> bool {
+        match self {
+            QuantizedVectorStorage::ScalarRam(_)
+            | QuantizedVectorStorage::ScalarMmap(_)
+            | QuantizedVectorStorage::ScalarInt4Ram(_)
+            | QuantizedVectorStorage::ScalarInt4Mmap(_)
+            | QuantizedVectorStorage::PQRam(_)
+            | QuantizedVectorStorage::PQMmap(_)
+            | QuantizedVectorStorage::BinaryRam(_)
+            | QuantizedVectorStorage::BinaryMmap(_)
+            | QuantizedVectorStorage::BinaryMultiBitRam(_)
+            | QuantizedVectorStorage::BinaryMultiBitMmap(_) => false,
+            QuantizedVectorStorage::ScalarRamMulti(q) => q.is_approximate_comparator(),
+            QuantizedVectorStorage::ScalarMmapMulti(q) => q.is_approximate_comparator(),
+            QuantizedVectorStorage::ScalarInt4RamMulti(q) => q.is_approximate_comparator(),
+            QuantizedVectorStorage::ScalarInt4MmapMulti(q) => q.is_approximate_comparator(),
+            QuantizedVectorStorage::PQRamMulti(q) => q.is_approximate_comparator(),
+            QuantizedVectorStorage::PQMmapMulti(q) => q.is_approximate_comparator(),
+            QuantizedVectorStorage::BinaryRamMulti(q) => q.is_approximate_comparator(),
+            QuantizedVectorStorage::BinaryMmapMulti(q) => q.is_approximate_comparator(),
+            QuantizedVectorStorage::BinaryMultiBitRamMulti(q) => q.is_approximate_comparator(),
+            QuantizedVectorStorage::BinaryMultiBitMmapMulti(q) => q.is_approximate_comparator(),
+        }
+    }
+
+    pub fn is_multivector(&self) -> bool {
+        match self {
+            QuantizedVectorStorage::ScalarRam(_)
+            | QuantizedVectorStorage::ScalarMmap(_)
+            | QuantizedVectorStorage::ScalarInt4Ram(_)
+            | QuantizedVectorStorage::ScalarInt4Mmap(_)
+            | QuantizedVectorStorage::PQRam(_)
+            | QuantizedVectorStorage::PQMmap(_)
+            | QuantizedVectorStorage::BinaryRam(_)
+            | QuantizedVectorStorage::BinaryMmap(_)
+            | QuantizedVectorStorage::BinaryMultiBitRam(_)
+            | QuantizedVectorStorage::BinaryMultiBitMmap(_) => false,
+            QuantizedVectorStorage::ScalarRamMulti(_)
+            | QuantizedVectorStorage::ScalarMmapMulti(_)
+            | QuantizedVectorStorage::ScalarInt4RamMulti(_)
+            | QuantizedVectorStorage::ScalarInt4MmapMulti(_)
+            | QuantizedVectorStorage::PQRamMulti(_)
+            | QuantizedVectorStorage::PQMmapMulti(_)
+            | QuantizedVectorStorage::BinaryRamMulti(_)
+            | QuantizedVectorStorage::BinaryMmapMulti(_)
+            | QuantizedVectorStorage::BinaryMultiBitRamMulti(_)
+            | QuantizedVectorStorage::BinaryMultiBitMmapMulti(_) => true,
+        }
+    }
+
+    /// Wrap quantized inner vectors into a multivector storage
+    fn into_multivector(
+        self,
+        offsets: Vec<MultivectorOffset>,
+        multi_vector_config: MultiVectorConfig,
+    ) -> Self {
+        match self {
+            QuantizedVectorStorage::ScalarRam(storage) => QuantizedVectorStorage::ScalarRamMulti(
+                QuantizedMultivectorStorage::new(storage, offsets, multi_vector_config),
+            ),
+            QuantizedVectorStorage::ScalarMmap(storage) => QuantizedVectorStorage::ScalarMmapMulti(
+                QuantizedMultivectorStorage::new(storage, offsets, multi_vector_config),
+            ),
+            QuantizedVectorStorage::ScalarInt4Ram(storage) => {
+                QuantizedVectorStorage::ScalarInt4RamMulti(QuantizedMultivectorStorage::new(
+                    storage,
+                    offsets,
+                    multi_vector_config,
+                ))
+            }
+            QuantizedVectorStorage::ScalarInt4Mmap(storage) => {
+                QuantizedVectorStorage::ScalarInt4MmapMulti(QuantizedMultivectorStorage::new(
+                    storage,
+                    offsets,
+                    multi_vector_config,
+                ))
+            }
+            QuantizedVectorStorage::PQRam(storage) => QuantizedVectorStorage::PQRamMulti(
+                QuantizedMultivectorStorage::new(storage, offsets, multi_vector_config),
+            ),
+            QuantizedVectorStorage::PQMmap(storage) => QuantizedVectorStorage::PQMmapMulti(
+                QuantizedMultivectorStorage::new(storage, offsets, multi_vector_config),
+            ),
+            QuantizedVectorStorage::BinaryRam(storage) => QuantizedVectorStorage::BinaryRamMulti(
+                QuantizedMultivectorStorage::new(storage, offsets, multi_vector_config),
+            ),
+            QuantizedVectorStorage::BinaryMmap(storage) => QuantizedVectorStorage::BinaryMmapMulti(
+                QuantizedMultivectorStorage::new(storage, offsets, multi_vector_config),
+            ),
+            QuantizedVectorStorage::BinaryMultiBitRam(storage) => {
+                QuantizedVectorStorage::BinaryMultiBitRamMulti(QuantizedMultivectorStorage::new(
+                    storage,
+                    offsets,
+                    multi_vector_config,
+                ))
+            }
+            QuantizedVectorStorage::BinaryMultiBitMmap(storage) => {
+                QuantizedVectorStorage::BinaryMultiBitMmapMulti(QuantizedMultivectorStorage::new(
+                    storage,
+                    offsets,
+                    multi_vector_config,
+                ))
+            }
+            QuantizedVectorStorage::ScalarRamMulti(_)
+            | QuantizedVectorStorage::ScalarMmapMulti(_)
+            | QuantizedVectorStorage::ScalarInt4RamMulti(_)
+            | QuantizedVectorStorage::ScalarInt4MmapMulti(_)
+            | QuantizedVectorStorage::PQRamMulti(_)
+            | QuantizedVectorStorage::PQMmapMulti(_)
+            | QuantizedVectorStorage::BinaryRamMulti(_)
+            | QuantizedVectorStorage::BinaryMmapMulti(_)
+            | QuantizedVectorStorage::BinaryMultiBitRamMulti(_)
+            | QuantizedVectorStorage::BinaryMultiBitMmapMulti(_) => {
+                debug_assert!(false, "Quantized storage is already a multivector storage");
+                self
+            }
+        }
+    }
 }
 
 pub struct QuantizedVectors {
@@ -49,16 +232,44 @@ pub struct QuantizedVectors {
     path: PathBuf,
     distance: Distance,
     datatype: VectorStorageDatatype,
+    /// Context for io_uring-based async reads of on-disk quantized vectors
+    uring_reader: Option<Mutex<UringReader<u8>>>,
 }
 
 impl QuantizedVectors {
     pub fn default_rescoring(&self) -> bool {
         matches!(
             self.storage_impl,
-            QuantizedVectorStorage::BinaryRam(_) | QuantizedVectorStorage::BinaryMmap(_)
+            QuantizedVectorStorage::ScalarInt4Ram(_)
+                | QuantizedVectorStorage::ScalarInt4Mmap(_)
+                | QuantizedVectorStorage::BinaryRam(_)
+                | QuantizedVectorStorage::BinaryMmap(_)
+                | QuantizedVectorStorage::BinaryMultiBitRam(_)
+                | QuantizedVectorStorage::BinaryMultiBitMmap(_)
+                | QuantizedVectorStorage::ScalarInt4RamMulti(_)
+                | QuantizedVectorStorage::ScalarInt4MmapMulti(_)
+                | QuantizedVectorStorage::BinaryRamMulti(_)
+                | QuantizedVectorStorage::BinaryMmapMulti(_)
+                | QuantizedVectorStorage::BinaryMultiBitRamMulti(_)
+                | QuantizedVectorStorage::BinaryMultiBitMmapMulti(_)
         )
     }
 
+    /// Whether quantized scores use a different distance or multivector comparator than the
+    /// vectors, so they are only good for candidate selection and always have to be rescored
+    pub fn is_approximate_distance(&self) -> bool {
+        self.distance == Distance::Hamming || self.storage_impl.is_approximate_comparator()
+    }
+
+    pub fn quantization_config(&self) -> &QuantizationConfig {
+        &self.config.quantization_config
+    }
+
+    /// Number of first dimensions of the vectors, which are quantized, if not all of them
+    pub fn prefix_size(&self) -> Option<usize> {
+        self.config.prefix_size
+    }
+
     pub fn raw_scorer<'a>(
         &'a self,
         query: QueryVector,
@@ -66,6 +277,10 @@ impl QuantizedVectors {
         vec_deleted: &'a BitSlice,
         is_stopped: &'a AtomicBool,
     ) -> OperationResult<Box<dyn RawScorer + 'a>> {
+        let query = match self.config.prefix_size {
+            Some(prefix_size) => query.truncate_dense(prefix_size)?,
+            None => query,
+        };
         QuantizedScorerBuilder::new(
             &self.storage_impl,
             &self.config.quantization_config,
@@ -79,62 +294,255 @@ impl QuantizedVectors {
         .build()
     }
 
+    /// Same as [`Self::raw_scorer`], but on-disk vectors are loaded with concurrent async reads
+    /// ahead of scoring, if async IO is enabled.
+    pub fn search_raw_scorer<'a>(
+        &'a self,
+        query: QueryVector,
+        point_deleted: &'a BitSlice,
+        vec_deleted: &'a BitSlice,
+        is_stopped: &'a AtomicBool,
+    ) -> OperationResult<Box<dyn RawScorer + 'a>> {
+        let raw_scorer = self.raw_scorer(query, point_deleted, vec_deleted, is_stopped)?;
+        Ok(match &self.uring_reader {
+            Some(uring_reader) => Box::new(PrefetchRawScorer::new(raw_scorer, uring_reader)),
+            None => raw_scorer,
+        })
+    }
+
+    /// Open async reader of the quantized data, if it is kept on disk and async IO is enabled
+    ///
+    /// Quantized multivectors are not prefetched, as their records are inner vectors, not points.
+    fn open_uring_reader(
+        storage_impl: &QuantizedVectorStorage,
+        path: &Path,
+        config: &QuantizedVectorsConfig,
+    ) -> OperationResult<Option<Mutex<UringReader<u8>>>> {
+        if !storage_impl.is_on_disk()
+            || storage_impl.is_multivector()
+            || !get_async_scorer()
+            || config.vector_parameters.count == 0
+        {
+            return Ok(None);
+        }
+        let data_file = File::open(path.join(QUANTIZED_DATA_PATH))?;
+        let uring_reader = UringReader::new(data_file, Self::quantized_vector_size(config), 0)?;
+        Ok(Some(Mutex::new(uring_reader)))
+    }
+
+    /// Size of a single quantized vector in the data file
+    pub(super) fn quantized_vector_size(config: &QuantizedVectorsConfig) -> usize {
+        let vector_parameters = &config.vector_parameters;
+        match &config.quantization_config {
+            QuantizationConfig::Scalar(ScalarQuantization { scalar })
+                if scalar.r#type == ScalarType::Int4 =>
+            {
+                EncodedVectorsU4::<QuantizedMmapStorage>::get_quantized_vector_size(
+                    vector_parameters,
+                )
+            }
+            QuantizationConfig::Scalar(_) => {
+                EncodedVectorsU8::<QuantizedMmapStorage>::get_quantized_vector_size(
+                    vector_parameters,
+                )
+            }
+            QuantizationConfig::Product(ProductQuantization { product }) => {
+                EncodedVectorsPQ::<QuantizedMmapStorage>::get_quantized_vector_size(
+                    vector_parameters,
+                    Self::get_bucket_size(product.compression),
+                )
+            }
+            QuantizationConfig::Binary(BinaryQuantization { binary }) if binary.is_multi_bit() => {
+                EncodedVectorsMultiBit::<QuantizedMmapStorage>::get_quantized_vector_size(
+                    vector_parameters,
+                    binary.encoding.unwrap_or_default(),
+                )
+            }
+            QuantizationConfig::Binary(_) => {
+                EncodedVectorsBin::<QuantizedMmapStorage>::get_quantized_vector_size_from_params(
+                    vector_parameters,
+                )
+            }
+        }
+    }
+
     pub fn save_to(&self, path: &Path) -> OperationResult<()> {
         let data_path = path.join(QUANTIZED_DATA_PATH);
         let meta_path = path.join(QUANTIZED_META_PATH);
+        let offsets_path = path.join(QUANTIZED_OFFSETS_PATH);
         match &self.storage_impl {
             QuantizedVectorStorage::ScalarRam(storage) => storage.save(&data_path, &meta_path)?,
             QuantizedVectorStorage::ScalarMmap(storage) => storage.save(&data_path, &meta_path)?,
+            QuantizedVectorStorage::ScalarInt4Ram(storage) => {
+                storage.save(&data_path, &meta_path)?
+            }
+            QuantizedVectorStorage::ScalarInt4Mmap(storage) => {
+                storage.save(&data_path, &meta_path)?
+            }
             QuantizedVectorStorage::PQRam(storage) => storage.save(&data_path, &meta_path)?,
             QuantizedVectorStorage::PQMmap(storage) => storage.save(&data_path, &meta_path)?,
             QuantizedVectorStorage::BinaryRam(storage) => storage.save(&data_path, &meta_path)?,
             QuantizedVectorStorage::BinaryMmap(storage) => storage.save(&data_path, &meta_path)?,
+            QuantizedVectorStorage::BinaryMultiBitRam(storage) => {
+                storage.save(&data_path, &meta_path)?
+            }
+            QuantizedVectorStorage::BinaryMultiBitMmap(storage) => {
+                storage.save(&data_path, &meta_path)?
+            }
+            QuantizedVectorStorage::ScalarRamMulti(storage) => {
+                storage.save(&data_path, &meta_path, &offsets_path)?
+            }
+            QuantizedVectorStorage::ScalarMmapMulti(storage) => {
+                storage.save(&data_path, &meta_path, &offsets_path)?
+            }
+            QuantizedVectorStorage::ScalarInt4RamMulti(storage) => {
+                storage.save(&data_path, &meta_path, &offsets_path)?
+            }
+            QuantizedVectorStorage::ScalarInt4MmapMulti(storage) => {
+                storage.save(&data_path, &meta_path, &offsets_path)?
+            }
+            QuantizedVectorStorage::PQRamMulti(storage) => {
+                storage.save(&data_path, &meta_path, &offsets_path)?
+            }
+            QuantizedVectorStorage::PQMmapMulti(storage) => {
+                storage.save(&data_path, &meta_path, &offsets_path)?
+            }
+            QuantizedVectorStorage::BinaryRamMulti(storage) => {
+                storage.save(&data_path, &meta_path, &offsets_path)?
+            }
+            QuantizedVectorStorage::BinaryMmapMulti(storage) => {
+                storage.save(&data_path, &meta_path, &offsets_path)?
+            }
+            QuantizedVectorStorage::BinaryMultiBitRamMulti(storage) => {
+                storage.save(&data_path, &meta_path, &offsets_path)?
+            }
+            QuantizedVectorStorage::BinaryMultiBitMmapMulti(storage) => {
+                storage.save(&data_path, &meta_path, &offsets_path)?
+            }
         };
         Ok(())
     }
 
     pub fn files(&self) -> Vec<PathBuf> {
-        vec![
+        let mut files = vec![
             // Config files
             self.path.join(QUANTIZED_CONFIG_PATH),
             // Storage file
             self.path.join(QUANTIZED_DATA_PATH),
             // Meta file
             self.path.join(QUANTIZED_META_PATH),
-        ]
+        ];
+        if self.storage_impl.is_multivector() {
+            // Multivector offsets file
+            files.push(self.path.join(QUANTIZED_OFFSETS_PATH));
+        }
+        files
     }
 
+    /// Quantize vectors of the storage.
+    ///
+    /// If `prefix_size` is set, only that many first dimensions of each vector are quantized.
+    /// Prefix is not applicable to multivectors, their inner vectors are quantized as a whole.
     pub fn create(
         vector_storage: &VectorStorageEnum,
         quantization_config: &QuantizationConfig,
+        prefix_size: Option<usize>,
         path: &Path,
         max_threads: usize,
         stopped: &AtomicBool,
     ) -> OperationResult<Self> {
         match vector_storage {
-            VectorStorageEnum::DenseSimple(v) => {
-                Self::create_impl(v, quantization_config, path, max_threads, stopped)
+            VectorStorageEnum::DenseSimple(v) => Self::create_impl(
+                v,
+                quantization_config,
+                prefix_size,
+                path,
+                max_threads,
+                stopped,
+            ),
+            VectorStorageEnum::DenseSimpleByte(v) => Self::create_impl(
+                v,
+                quantization_config,
+                prefix_size,
+                path,
+                max_threads,
+                stopped,
+            ),
+            VectorStorageEnum::DenseSimpleHalf(v) => Self::create_impl(
+                v,
+                quantization_config,
+                prefix_size,
+                path,
+                max_threads,
+                stopped,
+            ),
+            VectorStorageEnum::DenseMemmap(v) => Self::create_impl(
+                v.as_ref(),
+                quantization_config,
+                prefix_size,
+                path,
+                max_threads,
+                stopped,
+            ),
+            VectorStorageEnum::DenseMemmapByte(v) => Self::create_impl(
+                v.as_ref(),
+                quantization_config,
+                prefix_size,
+                path,
+                max_threads,
+                stopped,
+            ),
+            VectorStorageEnum::DenseMemmapHalf(v) => Self::create_impl(
+                v.as_ref(),
+                quantization_config,
+                prefix_size,
+                path,
+                max_threads,
+                stopped,
+            ),
+            VectorStorageEnum::DenseAppendableMemmap(v) => Self::create_impl(
+                v.as_ref(),
+                quantization_config,
+                prefix_size,
+                path,
+                max_threads,
+                stopped,
+            ),
+            VectorStorageEnum::DenseAppendableMemmapByte(v) => Self::create_impl(
+                v.as_ref(),
+                quantization_config,
+                prefix_size,
+                path,
+                max_threads,
+                stopped,
+            ),
+            VectorStorageEnum::DenseAppendableMemmapHalf(v) => Self::create_impl(
+                v.as_ref(),
+                quantization_config,
+                prefix_size,
+                path,
+                max_threads,
+                stopped,
+            ),
+            VectorStorageEnum::SparseSimple(_) => Err(OperationError::WrongSparse),
+            VectorStorageEnum::MultiDenseSimple(v) => {
+                Self::create_multi_impl(v, quantization_config, path, max_threads, stopped)
             }
-            VectorStorageEnum::DenseSimpleByte(v) => {
-                Self::create_impl(v, quantization_config, path, max_threads, stopped)
+            VectorStorageEnum::MultiDenseSimpleByte(v) => {
+                Self::create_multi_impl(v, quantization_config, path, max_threads, stopped)
             }
-            VectorStorageEnum::DenseMemmap(v) => {
-                Self::create_impl(v.as_ref(), quantization_config, path, max_threads, stopped)
+            VectorStorageEnum::MultiDenseSimpleHalf(v) => {
+                Self::create_multi_impl(v, quantization_config, path, max_threads, stopped)
             }
-            VectorStorageEnum::DenseMemmapByte(v) => {
-                Self::create_impl(v.as_ref(), quantization_config, path, max_threads, stopped)
+            VectorStorageEnum::MultiDenseAppendableMemmap(v) => {
+                Self::create_multi_impl(v.as_ref(), quantization_config, path, max_threads, stopped)
             }
-            VectorStorageEnum::DenseAppendableMemmap(v) => {
-                Self::create_impl(v.as_ref(), quantization_config, path, max_threads, stopped)
+            VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => {
+                Self::create_multi_impl(v.as_ref(), quantization_config, path, max_threads, stopped)
             }
-            VectorStorageEnum::DenseAppendableMemmapByte(v) => {
-                Self::create_impl(v.as_ref(), quantization_config, path, max_threads, stopped)
+            VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => {
+                Self::create_multi_impl(v.as_ref(), quantization_config, path, max_threads, stopped)
             }
-            VectorStorageEnum::SparseSimple(_) => Err(OperationError::WrongSparse),
-            VectorStorageEnum::MultiDenseSimple(_) => Err(OperationError::WrongMulti),
-            VectorStorageEnum::MultiDenseSimpleByte(_) => Err(OperationError::WrongMulti),
-            VectorStorageEnum::MultiDenseAppendableMemmap(_) => Err(OperationError::WrongMulti),
-            VectorStorageEnum::MultiDenseAppendableMemmapByte(_) => Err(OperationError::WrongMulti),
         }
     }
 
@@ -144,75 +552,194 @@ impl QuantizedVectors {
     >(
         vector_storage: &TVectorStorage,
         quantization_config: &QuantizationConfig,
+        prefix_size: Option<usize>,
         path: &Path,
         max_threads: usize,
         stopped: &AtomicBool,
     ) -> OperationResult<Self> {
-        let dim = vector_storage.vector_dim();
+        let dim = prefix_size.map_or(vector_storage.vector_dim(), |prefix_size| {
+            prefix_size.min(vector_storage.vector_dim())
+        });
         let count = vector_storage.total_vector_count();
         let distance = vector_storage.distance();
         let datatype = vector_storage.datatype();
         let vectors = (0..count as PointOffsetType).map(|i| {
-            PrimitiveVectorElement::quantization_preprocess(
+            let vector = PrimitiveVectorElement::quantization_preprocess(
                 quantization_config,
                 distance,
-                vector_storage.get_dense(i),
-            )
+                &vector_storage.get_dense(i)[..dim],
+            );
+            // Stored vectors are normalized over all dimensions, prefix has to be normalized again
+            if prefix_size.is_some() && distance == Distance::Cosine {
+                Cow::Owned(CosineMetric::preprocess(vector.into_owned()))
+            } else {
+                vector
+            }
         });
         let on_disk_vector_storage = vector_storage.is_on_disk();
 
-        let vector_parameters = Self::construct_vector_parameters(distance, dim, count);
+        let quantized_dim = TElement::quantization_dim(distance, dim);
+        let vector_parameters = Self::construct_vector_parameters(distance, quantized_dim, count)?;
+
+        let quantized_storage = Self::create_storage(
+            vectors,
+            &vector_parameters,
+            quantization_config,
+            path,
+            on_disk_vector_storage,
+            max_threads,
+            stopped,
+        )?;
+
+        let quantized_vectors_config = QuantizedVectorsConfig {
+            quantization_config: quantization_config.clone(),
+            vector_parameters,
+            prefix_size,
+        };
+
+        let mut quantized_vectors = QuantizedVectors {
+            storage_impl: quantized_storage,
+            config: quantized_vectors_config,
+            path: path.to_path_buf(),
+            distance,
+            datatype,
+            uring_reader: None,
+        };
+
+        quantized_vectors.save_to(path)?;
+        atomic_save_json(&path.join(QUANTIZED_CONFIG_PATH), &quantized_vectors.config)?;
+        quantized_vectors.uring_reader = Self::open_uring_reader(
+            &quantized_vectors.storage_impl,
+            path,
+            &quantized_vectors.config,
+        )?;
+        Ok(quantized_vectors)
+    }
+
+    fn create_multi_impl<
+        TElement: PrimitiveVectorElement,
+        TVectorStorage: MultiVectorStorage<TElement> + Send + Sync,
+    >(
+        vector_storage: &TVectorStorage,
+        quantization_config: &QuantizationConfig,
+        path: &Path,
+        max_threads: usize,
+        stopped: &AtomicBool,
+    ) -> OperationResult<Self> {
+        let dim = vector_storage.vector_dim();
+        let count = vector_storage.total_vector_count();
+        let distance = vector_storage.distance();
+        let datatype = vector_storage.datatype();
+        let multi_vector_config = *vector_storage.multi_vector_config();
+
+        let mut offsets = Vec::with_capacity(count);
+        let mut inner_vectors_count = 0;
+        for i in 0..count as PointOffsetType {
+            let multi_vector = vector_storage.get_multi(i);
+            let inner_count = (multi_vector.flattened_vectors.len() / dim) as PointOffsetType;
+            offsets.push(MultivectorOffset {
+                start: inner_vectors_count,
+                count: inner_count,
+            });
+            inner_vectors_count += inner_count;
+        }
+
+        // Inner vectors of all points, in the order of points
+        let inner_vectors = (0..count as PointOffsetType).flat_map(|i| {
+            let multi_vector = vector_storage.get_multi(i);
+            multi_vector
+                .flattened_vectors
+                .chunks_exact(dim)
+                .map(move |inner_vector| {
+                    PrimitiveVectorElement::quantization_preprocess(
+                        quantization_config,
+                        distance,
+                        inner_vector,
+                    )
+                })
+        });
+        let on_disk_vector_storage = vector_storage.is_on_disk();
+
+        let quantized_dim = TElement::quantization_dim(distance, dim);
+        let vector_parameters = Self::construct_vector_parameters(
+            distance,
+            quantized_dim,
+            inner_vectors_count as usize,
+        )?;
+
+        let quantized_storage = Self::create_storage(
+            inner_vectors,
+            &vector_parameters,
+            quantization_config,
+            path,
+            on_disk_vector_storage,
+            max_threads,
+            stopped,
+        )?
+        .into_multivector(offsets, multi_vector_config);
+
+        let quantized_vectors_config = QuantizedVectorsConfig {
+            quantization_config: quantization_config.clone(),
+            vector_parameters,
+            prefix_size: None,
+        };
+
+        let quantized_vectors = QuantizedVectors {
+            storage_impl: quantized_storage,
+            config: quantized_vectors_config,
+            path: path.to_path_buf(),
+            distance,
+            datatype,
+            uring_reader: None,
+        };
+
+        quantized_vectors.save_to(path)?;
+        atomic_save_json(&path.join(QUANTIZED_CONFIG_PATH), &quantized_vectors.config)?;
+        Ok(quantized_vectors)
+    }
 
-        let quantized_storage = match quantization_config {
+    fn create_storage<'a>(
+        vectors: impl Iterator<Item = impl AsRef<[VectorElementType]> + 'a> + Clone + Send,
+        vector_parameters: &quantization::VectorParameters,
+        quantization_config: &QuantizationConfig,
+        path: &Path,
+        on_disk_vector_storage: bool,
+        max_threads: usize,
+        stopped: &AtomicBool,
+    ) -> OperationResult<QuantizedVectorStorage> {
+        match quantization_config {
             QuantizationConfig::Scalar(ScalarQuantization {
                 scalar: scalar_config,
             }) => Self::create_scalar(
                 vectors,
-                &vector_parameters,
+                vector_parameters,
                 scalar_config,
                 path,
                 on_disk_vector_storage,
                 stopped,
-            )?,
+            ),
             QuantizationConfig::Product(ProductQuantization { product: pq_config }) => {
                 Self::create_pq(
                     vectors,
-                    &vector_parameters,
+                    vector_parameters,
                     pq_config,
                     path,
                     on_disk_vector_storage,
                     max_threads,
                     stopped,
-                )?
+                )
             }
             QuantizationConfig::Binary(BinaryQuantization {
                 binary: binary_config,
             }) => Self::create_binary(
                 vectors,
-                &vector_parameters,
+                vector_parameters,
                 binary_config,
                 path,
                 on_disk_vector_storage,
                 stopped,
-            )?,
-        };
-
-        let quantized_vectors_config = QuantizedVectorsConfig {
-            quantization_config: quantization_config.clone(),
-            vector_parameters,
-        };
-
-        let quantized_vectors = QuantizedVectors {
-            storage_impl: quantized_storage,
-            config: quantized_vectors_config,
-            path: path.to_path_buf(),
-            distance,
-            datatype,
-        };
-
-        quantized_vectors.save_to(path)?;
-        atomic_save_json(&path.join(QUANTIZED_CONFIG_PATH), &quantized_vectors.config)?;
-        Ok(quantized_vectors)
+            ),
+        }
     }
 
     pub fn config_exists(path: &Path) -> bool {
@@ -229,6 +756,23 @@ impl QuantizedVectors {
         let config_path = path.join(QUANTIZED_CONFIG_PATH);
         let config: QuantizedVectorsConfig = read_json(&config_path)?;
         let quantized_store = match &config.quantization_config {
+            QuantizationConfig::Scalar(ScalarQuantization { scalar })
+                if scalar.r#type == ScalarType::Int4 =>
+            {
+                if Self::is_ram(scalar.always_ram, on_disk_vector_storage) {
+                    QuantizedVectorStorage::ScalarInt4Ram(EncodedVectorsU4::load(
+                        &data_path,
+                        &meta_path,
+                        &config.vector_parameters,
+                    )?)
+                } else {
+                    QuantizedVectorStorage::ScalarInt4Mmap(EncodedVectorsU4::load(
+                        &data_path,
+                        &meta_path,
+                        &config.vector_parameters,
+                    )?)
+                }
+            }
             QuantizationConfig::Scalar(ScalarQuantization { scalar }) => {
                 if Self::is_ram(scalar.always_ram, on_disk_vector_storage) {
                     QuantizedVectorStorage::ScalarRam(EncodedVectorsU8::<ChunkedVectors<u8>>::load(
@@ -261,6 +805,21 @@ impl QuantizedVectors {
                     )?)
                 }
             }
+            QuantizationConfig::Binary(BinaryQuantization { binary }) if binary.is_multi_bit() => {
+                if Self::is_ram(binary.always_ram, on_disk_vector_storage) {
+                    QuantizedVectorStorage::BinaryMultiBitRam(EncodedVectorsMultiBit::load(
+                        &data_path,
+                        &meta_path,
+                        &config.vector_parameters,
+                    )?)
+                } else {
+                    QuantizedVectorStorage::BinaryMultiBitMmap(EncodedVectorsMultiBit::load(
+                        &data_path,
+                        &meta_path,
+                        &config.vector_parameters,
+                    )?)
+                }
+            }
             QuantizationConfig::Binary(BinaryQuantization { binary }) => {
                 if Self::is_ram(binary.always_ram, on_disk_vector_storage) {
                     QuantizedVectorStorage::BinaryRam(
@@ -282,12 +841,23 @@ impl QuantizedVectors {
             }
         };
 
+        // Inner vectors of multivectors are loaded as dense vectors and mapped to points by offsets
+        let quantized_store = match vector_storage.try_multi_vector_config() {
+            Some(multi_vector_config) => {
+                let offsets = read_bin(&path.join(QUANTIZED_OFFSETS_PATH))?;
+                quantized_store.into_multivector(offsets, *multi_vector_config)
+            }
+            None => quantized_store,
+        };
+
+        let uring_reader = Self::open_uring_reader(&quantized_store, path, &config)?;
         Ok(QuantizedVectors {
             storage_impl: quantized_store,
             config,
             path: path.to_path_buf(),
             distance,
             datatype,
+            uring_reader,
         })
     }
 
@@ -299,6 +869,17 @@ impl QuantizedVectors {
         on_disk_vector_storage: bool,
         stopped: &AtomicBool,
     ) -> OperationResult<QuantizedVectorStorage> {
+        if scalar_config.r#type == ScalarType::Int4 {
+            return Self::create_scalar_int4(
+                vectors,
+                vector_parameters,
+                scalar_config,
+                path,
+                on_disk_vector_storage,
+                stopped,
+            );
+        }
+
         let quantized_vector_size =
             EncodedVectorsU8::<QuantizedMmapStorage>::get_quantized_vector_size(vector_parameters);
         let in_ram = Self::is_ram(scalar_config.always_ram, on_disk_vector_storage);
@@ -331,6 +912,48 @@ impl QuantizedVectors {
         }
     }
 
+    fn create_scalar_int4<'a>(
+        vectors: impl Iterator<Item = impl AsRef<[VectorElementType]> + 'a> + Clone,
+        vector_parameters: &quantization::VectorParameters,
+        scalar_config: &ScalarQuantizationConfig,
+        path: &Path,
+        on_disk_vector_storage: bool,
+        stopped: &AtomicBool,
+    ) -> OperationResult<QuantizedVectorStorage> {
+        let quantized_vector_size =
+            EncodedVectorsU4::<QuantizedMmapStorage>::get_quantized_vector_size(vector_parameters);
+        let in_ram = Self::is_ram(scalar_config.always_ram, on_disk_vector_storage);
+        if in_ram {
+            let mut storage_builder = ChunkedVectors::<u8>::new(quantized_vector_size);
+            storage_builder.try_set_capacity_exact(vector_parameters.count)?;
+            Ok(QuantizedVectorStorage::ScalarInt4Ram(
+                EncodedVectorsU4::encode(
+                    vectors,
+                    storage_builder,
+                    vector_parameters,
+                    scalar_config.quantile,
+                    || stopped.load(Ordering::Relaxed),
+                )?,
+            ))
+        } else {
+            let mmap_data_path = path.join(QUANTIZED_DATA_PATH);
+            let storage_builder = QuantizedMmapStorageBuilder::new(
+                mmap_data_path.as_path(),
+                vector_parameters.count,
+                quantized_vector_size,
+            )?;
+            Ok(QuantizedVectorStorage::ScalarInt4Mmap(
+                EncodedVectorsU4::encode(
+                    vectors,
+                    storage_builder,
+                    vector_parameters,
+                    scalar_config.quantile,
+                    || stopped.load(Ordering::Relaxed),
+                )?,
+            ))
+        }
+    }
+
     fn create_pq<'a>(
         vectors: impl Iterator<Item = impl AsRef<[VectorElementType]> + 'a> + Clone + Send,
         vector_parameters: &quantization::VectorParameters,
@@ -384,6 +1007,17 @@ impl QuantizedVectors {
         on_disk_vector_storage: bool,
         stopped: &AtomicBool,
     ) -> OperationResult<QuantizedVectorStorage> {
+        if binary_config.is_multi_bit() {
+            return Self::create_binary_multi_bit(
+                vectors,
+                vector_parameters,
+                binary_config,
+                path,
+                on_disk_vector_storage,
+                stopped,
+            );
+        }
+
         let quantized_vector_size =
             EncodedVectorsBin::<QuantizedMmapStorage>::get_quantized_vector_size_from_params(
                 vector_parameters,
@@ -412,6 +1046,54 @@ impl QuantizedVectors {
         }
     }
 
+    fn create_binary_multi_bit<'a>(
+        vectors: impl Iterator<Item = impl AsRef<[VectorElementType]> + 'a> + Clone,
+        vector_parameters: &quantization::VectorParameters,
+        binary_config: &BinaryQuantizationConfig,
+        path: &Path,
+        on_disk_vector_storage: bool,
+        stopped: &AtomicBool,
+    ) -> OperationResult<QuantizedVectorStorage> {
+        let encoding = binary_config.encoding.unwrap_or_default();
+        let quantized_vector_size =
+            EncodedVectorsMultiBit::<QuantizedMmapStorage>::get_quantized_vector_size(
+                vector_parameters,
+                encoding,
+            );
+        let in_ram = Self::is_ram(binary_config.always_ram, on_disk_vector_storage);
+        if in_ram {
+            let mut storage_builder = ChunkedVectors::<u8>::new(quantized_vector_size);
+            storage_builder.try_set_capacity_exact(vector_parameters.count)?;
+            Ok(QuantizedVectorStorage::BinaryMultiBitRam(
+                EncodedVectorsMultiBit::encode(
+                    vectors,
+                    storage_builder,
+                    vector_parameters,
+                    encoding,
+                    binary_config.query_encoding,
+                    || stopped.load(Ordering::Relaxed),
+                )?,
+            ))
+        } else {
+            let mmap_data_path = path.join(QUANTIZED_DATA_PATH);
+            let storage_builder = QuantizedMmapStorageBuilder::new(
+                mmap_data_path.as_path(),
+                vector_parameters.count,
+                quantized_vector_size,
+            )?;
+            Ok(QuantizedVectorStorage::BinaryMultiBitMmap(
+                EncodedVectorsMultiBit::encode(
+                    vectors,
+                    storage_builder,
+                    vector_parameters,
+                    encoding,
+                    binary_config.query_encoding,
+                    || stopped.load(Ordering::Relaxed),
+                )?,
+            ))
+        }
+    }
+
     fn is_ram(always_ram: Option<bool>, on_disk_vector_storage: bool) -> bool {
         !on_disk_vector_storage || always_ram == Some(true)
     }
@@ -420,18 +1102,28 @@ impl QuantizedVectors {
         distance: Distance,
         dim: usize,
         count: usize,
-    ) -> quantization::VectorParameters {
-        quantization::VectorParameters {
+    ) -> OperationResult<quantization::VectorParameters> {
+        let distance_type = match distance {
+            Distance::Cosine => quantization::DistanceType::Dot,
+            Distance::Euclid => quantization::DistanceType::L2,
+            Distance::Dot => quantization::DistanceType::Dot,
+            Distance::Manhattan => quantization::DistanceType::L1,
+            // Quantized scores are only used to select candidates, which are always rescored
+            // with the original vectors, see `is_approximate_distance`
+            Distance::Hamming => quantization::DistanceType::L1,
+            Distance::Jaccard | Distance::Minkowski(_) => {
+                return Err(unsupported_quantization_error(distance));
+            }
+        };
+        Ok(quantization::VectorParameters {
             dim,
             count,
-            distance_type: match distance {
-                Distance::Cosine => quantization::DistanceType::Dot,
-                Distance::Euclid => quantization::DistanceType::L2,
-                Distance::Dot => quantization::DistanceType::Dot,
-                Distance::Manhattan => quantization::DistanceType::L1,
-            },
-            invert: distance == Distance::Euclid || distance == Distance::Manhattan,
-        }
+            invert: matches!(
+                distance_type,
+                quantization::DistanceType::L1 | quantization::DistanceType::L2
+            ),
+            distance_type,
+        })
     }
 
     fn get_bucket_size(compression: CompressionRatio) -> usize {
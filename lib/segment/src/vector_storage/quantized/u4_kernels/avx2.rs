@@ -0,0 +1,116 @@
+use std::arch::x86_64::*;
+
+use super::{dot_u4_plain, manhattan_u4_plain};
+
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe fn avx_dot_u4(v1: &[u8], v2: &[u8]) -> u32 {
+    debug_assert!(v1.len() == v2.len());
+    debug_assert!(is_x86_feature_detected!("avx"));
+    debug_assert!(is_x86_feature_detected!("avx2"));
+
+    let mut ptr1: *const u8 = v1.as_ptr();
+    let mut ptr2: *const u8 = v2.as_ptr();
+
+    // sum accumulator for 8x32 bit integers
+    let mut dot_acc = _mm256_setzero_si256();
+    let mask_nibble = _mm256_set1_epi8(0x0F);
+    let ones_epi16 = _mm256_set1_epi16(1);
+    let len = v1.len();
+    for _ in 0..len / 32 {
+        // load 32 bytes, which is 64 dimensions
+        let p1 = _mm256_loadu_si256(ptr1 as *const __m256i);
+        let p2 = _mm256_loadu_si256(ptr2 as *const __m256i);
+        ptr1 = ptr1.add(32);
+        ptr2 = ptr2.add(32);
+
+        // unpack nibbles into separate bytes
+        let p1_low = _mm256_and_si256(p1, mask_nibble);
+        let p1_high = _mm256_and_si256(_mm256_srli_epi16(p1, 4), mask_nibble);
+        let p2_low = _mm256_and_si256(p2, mask_nibble);
+        let p2_high = _mm256_and_si256(_mm256_srli_epi16(p2, 4), mask_nibble);
+
+        // multiply bytes and add adjacent pairs into 16 bit integers, max value is 2 * 15 * 15
+        let dot_low = _mm256_maddubs_epi16(p1_low, p2_low);
+        let dot_high = _mm256_maddubs_epi16(p1_high, p2_high);
+
+        // widen into 32 bit integers and add to accumulator
+        let dot = _mm256_add_epi16(dot_low, dot_high);
+        dot_acc = _mm256_add_epi32(dot_acc, _mm256_madd_epi16(dot, ones_epi16));
+    }
+
+    let mut lanes = [0u32; 8];
+    _mm256_storeu_si256(lanes.as_mut_ptr() as *mut __m256i, dot_acc);
+    let score: u32 = lanes.iter().sum();
+
+    let processed = len - len % 32;
+    score + dot_u4_plain(&v1[processed..], &v2[processed..])
+}
+
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe fn avx_manhattan_u4(v1: &[u8], v2: &[u8]) -> u32 {
+    debug_assert!(v1.len() == v2.len());
+    debug_assert!(is_x86_feature_detected!("avx"));
+    debug_assert!(is_x86_feature_detected!("avx2"));
+
+    let mut ptr1: *const u8 = v1.as_ptr();
+    let mut ptr2: *const u8 = v2.as_ptr();
+
+    // sum accumulator for 4x64 bit integers
+    let mut sum_acc = _mm256_setzero_si256();
+    let mask_nibble = _mm256_set1_epi8(0x0F);
+    let len = v1.len();
+    for _ in 0..len / 32 {
+        // load 32 bytes, which is 64 dimensions
+        let p1 = _mm256_loadu_si256(ptr1 as *const __m256i);
+        let p2 = _mm256_loadu_si256(ptr2 as *const __m256i);
+        ptr1 = ptr1.add(32);
+        ptr2 = ptr2.add(32);
+
+        // unpack nibbles into separate bytes
+        let p1_low = _mm256_and_si256(p1, mask_nibble);
+        let p1_high = _mm256_and_si256(_mm256_srli_epi16(p1, 4), mask_nibble);
+        let p2_low = _mm256_and_si256(p2, mask_nibble);
+        let p2_high = _mm256_and_si256(_mm256_srli_epi16(p2, 4), mask_nibble);
+
+        // sum of absolute differences of each 8 bytes into 64 bit integers
+        sum_acc = _mm256_add_epi64(sum_acc, _mm256_sad_epu8(p1_low, p2_low));
+        sum_acc = _mm256_add_epi64(sum_acc, _mm256_sad_epu8(p1_high, p2_high));
+    }
+
+    let mut lanes = [0u64; 4];
+    _mm256_storeu_si256(lanes.as_mut_ptr() as *mut __m256i, sum_acc);
+    let score = lanes.iter().sum::<u64>() as u32;
+
+    let processed = len - len % 32;
+    score + manhattan_u4_plain(&v1[processed..], &v2[processed..])
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{Rng, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn test_u4_kernels_avx() {
+        if is_x86_feature_detected!("avx") && is_x86_feature_detected!("avx2") {
+            let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+            for len in [32, 33, 64, 100, 777] {
+                let v1: Vec<u8> = (0..len).map(|_| rng.gen()).collect();
+                let v2: Vec<u8> = (0..len).map(|_| rng.gen()).collect();
+
+                assert_eq!(unsafe { avx_dot_u4(&v1, &v2) }, dot_u4_plain(&v1, &v2));
+                assert_eq!(
+                    unsafe { avx_manhattan_u4(&v1, &v2) },
+                    manhattan_u4_plain(&v1, &v2)
+                );
+            }
+        } else {
+            println!("avx test skipped");
+        }
+    }
+}
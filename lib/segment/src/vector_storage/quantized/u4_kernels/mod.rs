@@ -0,0 +1,76 @@
+//! Scoring kernels for vectors of 4-bit codes, packed two dimensions per byte.
+//!
+//! Low nibble of a byte holds the even dimension, high nibble holds the odd one.
+
+#[cfg(target_arch = "x86_64")]
+mod avx2;
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+mod neon;
+
+#[cfg(target_arch = "x86_64")]
+use crate::spaces::simple::MIN_DIM_SIZE_AVX;
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use crate::spaces::simple::MIN_DIM_SIZE_SIMD;
+
+/// Sum of products of 4-bit codes
+pub fn dot_u4(v1: &[u8], v2: &[u8]) -> u32 {
+    #[cfg(target_arch = "x86_64")]
+    {
+        if is_x86_feature_detected!("avx")
+            && is_x86_feature_detected!("avx2")
+            && v1.len() >= MIN_DIM_SIZE_AVX
+        {
+            return unsafe { avx2::avx_dot_u4(v1, v2) };
+        }
+    }
+
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    {
+        if std::arch::is_aarch64_feature_detected!("neon") && v1.len() >= MIN_DIM_SIZE_SIMD {
+            return unsafe { neon::neon_dot_u4(v1, v2) };
+        }
+    }
+
+    dot_u4_plain(v1, v2)
+}
+
+/// Sum of absolute differences of 4-bit codes
+pub fn manhattan_u4(v1: &[u8], v2: &[u8]) -> u32 {
+    #[cfg(target_arch = "x86_64")]
+    {
+        if is_x86_feature_detected!("avx")
+            && is_x86_feature_detected!("avx2")
+            && v1.len() >= MIN_DIM_SIZE_AVX
+        {
+            return unsafe { avx2::avx_manhattan_u4(v1, v2) };
+        }
+    }
+
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    {
+        if std::arch::is_aarch64_feature_detected!("neon") && v1.len() >= MIN_DIM_SIZE_SIMD {
+            return unsafe { neon::neon_manhattan_u4(v1, v2) };
+        }
+    }
+
+    manhattan_u4_plain(v1, v2)
+}
+
+pub fn dot_u4_plain(v1: &[u8], v2: &[u8]) -> u32 {
+    v1.iter()
+        .zip(v2)
+        .map(|(a, b)| {
+            u32::from(a & 0x0F) * u32::from(b & 0x0F) + u32::from(a >> 4) * u32::from(b >> 4)
+        })
+        .sum()
+}
+
+pub fn manhattan_u4_plain(v1: &[u8], v2: &[u8]) -> u32 {
+    v1.iter()
+        .zip(v2)
+        .map(|(a, b)| {
+            u32::from((a & 0x0F).abs_diff(b & 0x0F)) + u32::from((a >> 4).abs_diff(b >> 4))
+        })
+        .sum()
+}
@@ -0,0 +1,96 @@
+use std::arch::aarch64::*;
+
+use super::{dot_u4_plain, manhattan_u4_plain};
+
+#[target_feature(enable = "neon")]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe fn neon_dot_u4(v1: &[u8], v2: &[u8]) -> u32 {
+    debug_assert!(v1.len() == v2.len());
+    let mut ptr1: *const u8 = v1.as_ptr();
+    let mut ptr2: *const u8 = v2.as_ptr();
+
+    let mask_nibble = vdupq_n_u8(0x0F);
+    let mut mul1 = vdupq_n_u32(0);
+    let mut mul2 = vdupq_n_u32(0);
+    let len = v1.len();
+    for _ in 0..len / 16 {
+        let p1 = vld1q_u8(ptr1);
+        let p2 = vld1q_u8(ptr2);
+        ptr1 = ptr1.add(16);
+        ptr2 = ptr2.add(16);
+
+        // unpack nibbles into separate bytes
+        let p1_low = vandq_u8(p1, mask_nibble);
+        let p1_high = vshrq_n_u8(p1, 4);
+        let p2_low = vandq_u8(p2, mask_nibble);
+        let p2_high = vshrq_n_u8(p2, 4);
+
+        // products of nibbles fit into 8 bits, sum of two of them fits into 16 bits
+        let mul_low = vmull_u8(vget_low_u8(p1_low), vget_low_u8(p2_low));
+        let mul_low = vmlal_u8(mul_low, vget_low_u8(p1_high), vget_low_u8(p2_high));
+        let mul_high = vmull_u8(vget_high_u8(p1_low), vget_high_u8(p2_low));
+        let mul_high = vmlal_u8(mul_high, vget_high_u8(p1_high), vget_high_u8(p2_high));
+        mul1 = vpadalq_u16(mul1, mul_low);
+        mul2 = vpadalq_u16(mul2, mul_high);
+    }
+    let score = vaddvq_u32(vaddq_u32(mul1, mul2));
+
+    let processed = len - len % 16;
+    score + dot_u4_plain(&v1[processed..], &v2[processed..])
+}
+
+#[target_feature(enable = "neon")]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe fn neon_manhattan_u4(v1: &[u8], v2: &[u8]) -> u32 {
+    debug_assert!(v1.len() == v2.len());
+    let mut ptr1: *const u8 = v1.as_ptr();
+    let mut ptr2: *const u8 = v2.as_ptr();
+
+    let mask_nibble = vdupq_n_u8(0x0F);
+    let mut sum = vdupq_n_u32(0);
+    let len = v1.len();
+    for _ in 0..len / 16 {
+        let p1 = vld1q_u8(ptr1);
+        let p2 = vld1q_u8(ptr2);
+        ptr1 = ptr1.add(16);
+        ptr2 = ptr2.add(16);
+
+        // absolute differences of nibbles, sum of two of them fits into 8 bits
+        let diff_low = vabdq_u8(vandq_u8(p1, mask_nibble), vandq_u8(p2, mask_nibble));
+        let diff_high = vabdq_u8(vshrq_n_u8(p1, 4), vshrq_n_u8(p2, 4));
+        let diff = vaddq_u8(diff_low, diff_high);
+        sum = vpadalq_u16(sum, vpaddlq_u8(diff));
+    }
+    let score = vaddvq_u32(sum);
+
+    let processed = len - len % 16;
+    score + manhattan_u4_plain(&v1[processed..], &v2[processed..])
+}
+
+#[cfg(test)]
+mod tests {
+    use std::arch::is_aarch64_feature_detected;
+
+    use rand::{Rng, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn test_u4_kernels_neon() {
+        if is_aarch64_feature_detected!("neon") {
+            let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+            for len in [16, 17, 64, 100, 777] {
+                let v1: Vec<u8> = (0..len).map(|_| rng.gen()).collect();
+                let v2: Vec<u8> = (0..len).map(|_| rng.gen()).collect();
+
+                assert_eq!(unsafe { neon_dot_u4(&v1, &v2) }, dot_u4_plain(&v1, &v2));
+                assert_eq!(
+                    unsafe { neon_manhattan_u4(&v1, &v2) },
+                    manhattan_u4_plain(&v1, &v2)
+                );
+            }
+        } else {
+            println!("neon test skipped");
+        }
+    }
+}
@@ -76,6 +76,45 @@ impl From<RecoQuery<Vector>> for QueryVector {
     }
 }
 
+/// Recommendation query, which scores each candidate by the sum of its similarities to all
+/// positives minus the sum of its similarities to all negatives.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecoSumScoresQuery<T>(pub RecoQuery<T>);
+
+impl<T> RecoSumScoresQuery<T> {
+    pub fn new(positives: Vec<T>, negatives: Vec<T>) -> Self {
+        Self(RecoQuery::new(positives, negatives))
+    }
+
+    pub fn flat_iter(&self) -> impl Iterator<Item = &T> {
+        self.0.flat_iter()
+    }
+}
+
+impl<T, U> TransformInto<RecoSumScoresQuery<U>, T, U> for RecoSumScoresQuery<T> {
+    fn transform<F>(self, f: F) -> OperationResult<RecoSumScoresQuery<U>>
+    where
+        F: FnMut(T) -> OperationResult<U>,
+    {
+        self.0.transform(f).map(RecoSumScoresQuery)
+    }
+}
+
+impl<T> Query<T> for RecoSumScoresQuery<T> {
+    fn score_by(&self, similarity: impl Fn(&T) -> ScoreType) -> ScoreType {
+        let positive_sum: ScoreType = self.0.positives.iter().map(&similarity).sum();
+        let negative_sum: ScoreType = self.0.negatives.iter().map(&similarity).sum();
+
+        positive_sum - negative_sum
+    }
+}
+
+impl From<RecoSumScoresQuery<Vector>> for QueryVector {
+    fn from(query: RecoSumScoresQuery<Vector>) -> Self {
+        QueryVector::RecommendSumScores(query)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use common::math::scaled_fast_sigmoid;
@@ -83,7 +122,7 @@ mod test {
     use proptest::prelude::*;
     use rstest::rstest;
 
-    use super::RecoQuery;
+    use super::{RecoQuery, RecoSumScoresQuery};
     use crate::vector_storage::query::Query;
 
     enum Chosen {
@@ -125,6 +164,24 @@ mod test {
         }
     }
 
+    #[rstest]
+    #[case::only_positives(vec![1, 2, 3], vec![], 6.0)]
+    #[case::only_negatives(vec![], vec![1, 2, 3], -6.0)]
+    #[case::positives_and_negatives(vec![4, 5], vec![1, 2], 6.0)]
+    #[case::negatives_outweigh_positives(vec![1], vec![2, 3], -4.0)]
+    #[case::negative_similarities(vec![-1, -2], vec![-4], 1.0)]
+    fn score_sum_scores_query(
+        #[case] positives: Vec<isize>,
+        #[case] negatives: Vec<isize>,
+        #[case] expected: ScoreType,
+    ) {
+        let query = RecoSumScoresQuery::new(positives, negatives);
+
+        let dummy_similarity = |x: &isize| *x as ScoreType;
+
+        assert_eq!(query.score_by(dummy_similarity), expected);
+    }
+
     proptest! {
         /// Checks that the negative-chosen scores invert the order of the candidates
         #[test]
@@ -0,0 +1,78 @@
+use common::types::{PointOffsetType, ScoreType};
+
+use crate::data_types::vectors::{DenseVector, VectorElementType};
+use crate::spaces::metric::Metric;
+use crate::spaces::simple::DotProductMetric;
+use crate::vector_storage::multi_dense::fixed_dimensional_encoding::FixedDimensionalEncodings;
+use crate::vector_storage::query_scorer::QueryScorer;
+
+/// Scores multivectors by the dot product of their fixed dimensional encodings.
+///
+/// Scores only approximate MaxSim, so found points have to be rescored with the original
+/// multivectors.
+pub struct FdeQueryScorer<'a> {
+    fixed_dimensional_encodings: &'a FixedDimensionalEncodings,
+    query_encoding: DenseVector,
+}
+
+impl<'a> FdeQueryScorer<'a> {
+    pub fn new(
+        fixed_dimensional_encodings: &'a FixedDimensionalEncodings,
+        query_encoding: DenseVector,
+    ) -> Self {
+        Self {
+            fixed_dimensional_encodings,
+            query_encoding,
+        }
+    }
+}
+
+impl<'a> QueryScorer<[VectorElementType]> for FdeQueryScorer<'a> {
+    #[inline]
+    fn score_stored(&self, idx: PointOffsetType) -> ScoreType {
+        self.fixed_dimensional_encodings
+            .score(&self.query_encoding, idx)
+    }
+
+    #[inline]
+    fn score(&self, v2: &[VectorElementType]) -> ScoreType {
+        DotProductMetric::similarity(&self.query_encoding, v2)
+    }
+
+    fn score_internal(&self, point_a: PointOffsetType, point_b: PointOffsetType) -> ScoreType {
+        self.fixed_dimensional_encodings
+            .score_internal(point_a, point_b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_types::vectors::{MultiDenseVector, TypedMultiDenseVectorRef};
+    use crate::types::FdeConfig;
+
+    #[test]
+    fn test_fde_query_scorer_prefers_similar_points() {
+        let dim = 4;
+        let mut encodings = FixedDimensionalEncodings::new(&FdeConfig::default(), dim);
+        let points = [
+            MultiDenseVector::new(vec![1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0], dim),
+            MultiDenseVector::new(vec![0.0, 0.0, -1.0, 0.0, 0.0, 0.0, 0.0, -1.0], dim),
+        ];
+        for (idx, point) in points.iter().enumerate() {
+            encodings
+                .update(
+                    idx as PointOffsetType,
+                    TypedMultiDenseVectorRef::from(point),
+                )
+                .unwrap();
+        }
+
+        let query_encoding = encodings.encode_query(&points[0]);
+        let scorer = FdeQueryScorer::new(&encodings, query_encoding);
+        assert!(scorer.score_stored(0) > scorer.score_stored(1));
+        assert!(scorer.score_internal(0, 0) > scorer.score_internal(0, 1));
+        // Points without encodings are never selected
+        assert_eq!(scorer.score_stored(2), ScoreType::NEG_INFINITY);
+    }
+}
@@ -0,0 +1,190 @@
+use std::borrow::Cow;
+use std::marker::PhantomData;
+
+use common::types::{PointOffsetType, ScoreType};
+
+use crate::data_types::vectors::{DenseVector, TypedDenseVector};
+use crate::spaces::simple::MinkowskiSimilarity;
+use crate::vector_storage::query::{Query, TransformInto};
+use crate::vector_storage::query_scorer::QueryScorer;
+use crate::vector_storage::DenseVectorStorage;
+
+/// Scorer for Minkowski distance of an order, which has no dedicated metric implementation
+///
+/// Stored vectors are compared over the length of the query, so a query truncated to a prefix
+/// scores only the prefixes of stored vectors.
+pub struct MinkowskiQueryScorer<
+    'a,
+    TElement: MinkowskiSimilarity,
+    TVectorStorage: DenseVectorStorage<TElement>,
+> {
+    vector_storage: &'a TVectorStorage,
+    query: TypedDenseVector<TElement>,
+    p: u32,
+}
+
+impl<'a, TElement: MinkowskiSimilarity, TVectorStorage: DenseVectorStorage<TElement>>
+    MinkowskiQueryScorer<'a, TElement, TVectorStorage>
+{
+    pub fn new(query: DenseVector, p: u32, vector_storage: &'a TVectorStorage) -> Self {
+        Self {
+            query: TElement::slice_from_float_cow(Cow::from(query)).into_owned(),
+            vector_storage,
+            p,
+        }
+    }
+
+    fn truncate<'b>(&self, vector: &'b [TElement]) -> &'b [TElement] {
+        &vector[..self.query.len().min(vector.len())]
+    }
+}
+
+impl<TElement: MinkowskiSimilarity, TVectorStorage: DenseVectorStorage<TElement>>
+    QueryScorer<[TElement]> for MinkowskiQueryScorer<'_, TElement, TVectorStorage>
+{
+    #[inline]
+    fn score_stored(&self, idx: PointOffsetType) -> ScoreType {
+        self.score(self.vector_storage.get_dense(idx))
+    }
+
+    #[inline]
+    fn score(&self, v2: &[TElement]) -> ScoreType {
+        TElement::minkowski_similarity(&self.query, self.truncate(v2), self.p)
+    }
+
+    fn score_internal(&self, point_a: PointOffsetType, point_b: PointOffsetType) -> ScoreType {
+        let v1 = self.truncate(self.vector_storage.get_dense(point_a));
+        let v2 = self.truncate(self.vector_storage.get_dense(point_b));
+        TElement::minkowski_similarity(v1, v2, self.p)
+    }
+}
+
+/// Custom query scorer for Minkowski distance of an order, which has no dedicated metric
+/// implementation
+pub struct MinkowskiCustomQueryScorer<
+    'a,
+    TElement: MinkowskiSimilarity,
+    TVectorStorage: DenseVectorStorage<TElement>,
+    TInputQuery: Query<DenseVector>,
+    TStoredQuery: Query<TypedDenseVector<TElement>>,
+> {
+    vector_storage: &'a TVectorStorage,
+    query: TStoredQuery,
+    p: u32,
+    _input_query: PhantomData<TInputQuery>,
+    _element: PhantomData<TElement>,
+}
+
+impl<
+        'a,
+        TElement: MinkowskiSimilarity,
+        TVectorStorage: DenseVectorStorage<TElement>,
+        TInputQuery: Query<DenseVector> + TransformInto<TStoredQuery, DenseVector, TypedDenseVector<TElement>>,
+        TStoredQuery: Query<TypedDenseVector<TElement>>,
+    > MinkowskiCustomQueryScorer<'a, TElement, TVectorStorage, TInputQuery, TStoredQuery>
+{
+    pub fn new(query: TInputQuery, p: u32, vector_storage: &'a TVectorStorage) -> Self {
+        let query = query
+            .transform(|vector| Ok(TElement::slice_from_float_cow(Cow::from(vector)).into_owned()))
+            .unwrap();
+
+        Self {
+            query,
+            vector_storage,
+            p,
+            _input_query: PhantomData,
+            _element: PhantomData,
+        }
+    }
+}
+
+impl<
+        TElement: MinkowskiSimilarity,
+        TVectorStorage: DenseVectorStorage<TElement>,
+        TInputQuery: Query<DenseVector>,
+        TStoredQuery: Query<TypedDenseVector<TElement>>,
+    > QueryScorer<[TElement]>
+    for MinkowskiCustomQueryScorer<'_, TElement, TVectorStorage, TInputQuery, TStoredQuery>
+{
+    #[inline]
+    fn score_stored(&self, idx: PointOffsetType) -> ScoreType {
+        let stored = self.vector_storage.get_dense(idx);
+        self.score(stored)
+    }
+
+    #[inline]
+    fn score(&self, against: &[TElement]) -> ScoreType {
+        self.query
+            .score_by(|example| TElement::minkowski_similarity(example, against, self.p))
+    }
+
+    fn score_internal(&self, _point_a: PointOffsetType, _point_b: PointOffsetType) -> ScoreType {
+        unimplemented!("Custom scorer can compare against multiple vectors, not just one")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicBool;
+
+    use super::*;
+    use crate::common::rocksdb_wrapper::{open_db, DB_VECTOR_CF};
+    use crate::data_types::vectors::{VectorElementType, VectorRef};
+    use crate::spaces::simple::MinkowskiMetric;
+    use crate::types::Distance;
+    use crate::vector_storage::dense::simple_dense_vector_storage::open_simple_dense_vector_storage;
+    use crate::vector_storage::query_scorer::metric_query_scorer::MetricQueryScorer;
+    use crate::vector_storage::{VectorStorage, VectorStorageEnum};
+
+    #[test]
+    fn test_minkowski_runtime_order() {
+        let dir = tempfile::Builder::new()
+            .prefix("storage")
+            .tempdir()
+            .unwrap();
+        let db = open_db(dir.path(), &[DB_VECTOR_CF]).unwrap();
+        let storage = open_simple_dense_vector_storage(
+            db,
+            DB_VECTOR_CF,
+            4,
+            Distance::Minkowski(5),
+            &AtomicBool::new(false),
+        )
+        .unwrap();
+        let mut storage = storage.borrow_mut();
+
+        let vectors = [vec![1.0, 1.0, 0.0, 3.0], vec![0.0, 2.0, 2.0, 0.0]];
+        for (idx, vector) in vectors.iter().enumerate() {
+            storage
+                .insert_vector(idx as PointOffsetType, VectorRef::from(vector))
+                .unwrap();
+        }
+
+        let VectorStorageEnum::DenseSimple(storage) = &*storage else {
+            unreachable!();
+        };
+        let query = vec![1.0, 0.0, 2.0, 3.0];
+
+        // Runtime order agrees with the dedicated metric
+        let scorer = MinkowskiQueryScorer::<VectorElementType, _>::new(query.clone(), 3, storage);
+        let metric_scorer = MetricQueryScorer::<VectorElementType, MinkowskiMetric<3>, _>::new(
+            query.clone(),
+            storage,
+        );
+        assert_eq!(scorer.score_stored(0), metric_scorer.score_stored(0));
+        assert_eq!(
+            scorer.score_internal(0, 1),
+            metric_scorer.score_internal(0, 1)
+        );
+
+        let scorer = MinkowskiQueryScorer::<VectorElementType, _>::new(query.clone(), 5, storage);
+        assert_eq!(scorer.score_stored(0), -33.0);
+        assert_eq!(scorer.score_stored(1), -(1.0 + 32.0 + 243.0));
+
+        // Truncated query only scores prefixes of stored vectors
+        let scorer =
+            MinkowskiQueryScorer::<VectorElementType, _>::new(query[..2].to_vec(), 5, storage);
+        assert_eq!(scorer.score_stored(0), -1.0);
+        assert_eq!(scorer.score_internal(0, 1), -2.0);
+    }
+}
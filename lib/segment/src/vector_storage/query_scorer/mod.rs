@@ -1,15 +1,21 @@
+use std::borrow::Cow;
+
 use common::types::{PointOffsetType, ScoreType};
 use ordered_float::OrderedFloat;
 
 use crate::data_types::primitive::PrimitiveVectorElement;
-use crate::data_types::vectors::TypedMultiDenseVectorRef;
+use crate::data_types::vectors::{DenseVector, TypedMultiDenseVectorRef};
 use crate::spaces::metric::Metric;
 use crate::types::{MultiVectorComparator, MultiVectorConfig};
 
 pub mod custom_query_scorer;
+pub mod fde_query_scorer;
 pub mod metric_query_scorer;
+pub mod minkowski_query_scorer;
 pub mod multi_custom_query_scorer;
 pub mod multi_metric_query_scorer;
+pub mod multi_minkowski_query_scorer;
+pub mod prefix_query_scorer;
 pub mod sparse_custom_query_scorer;
 
 pub trait QueryScorer<TVector: ?Sized> {
@@ -25,6 +31,15 @@ pub trait QueryScorer<TVector: ?Sized> {
 pub fn score_max_similarity<T: PrimitiveVectorElement, TMetric: Metric<T>>(
     multi_dense_a: TypedMultiDenseVectorRef<T>,
     multi_dense_b: TypedMultiDenseVectorRef<T>,
+) -> ScoreType {
+    score_max_similarity_by(multi_dense_a, multi_dense_b, TMetric::similarity)
+}
+
+/// MaxSim metric over the given similarity of dense vectors
+fn score_max_similarity_by<T: PrimitiveVectorElement>(
+    multi_dense_a: TypedMultiDenseVectorRef<T>,
+    multi_dense_b: TypedMultiDenseVectorRef<T>,
+    similarity: impl Fn(&[T], &[T]) -> ScoreType,
 ) -> ScoreType {
     // TODO(colbert) add user input validation
     debug_assert!(!multi_dense_a.is_empty());
@@ -34,7 +49,7 @@ pub fn score_max_similarity<T: PrimitiveVectorElement, TMetric: Metric<T>>(
         let mut max_sim = OrderedFloat(ScoreType::NEG_INFINITY);
         // manual `max_by` for performance
         for dense_b in multi_dense_b.multi_vectors() {
-            let sim = OrderedFloat(TMetric::similarity(dense_a, dense_b));
+            let sim = OrderedFloat(similarity(dense_a, dense_b));
             if sim > max_sim {
                 max_sim = sim;
             }
@@ -45,14 +60,126 @@ pub fn score_max_similarity<T: PrimitiveVectorElement, TMetric: Metric<T>>(
     sum
 }
 
-fn score_multi<T: PrimitiveVectorElement, TMetric: Metric<T>>(
+/// Cosine similarity of mean vectors of multi-dense vectors
+pub fn score_mean_pooled_cosine<T: PrimitiveVectorElement>(
+    multi_dense_a: TypedMultiDenseVectorRef<T>,
+    multi_dense_b: TypedMultiDenseVectorRef<T>,
+) -> ScoreType {
+    debug_assert_eq!(multi_dense_a.dim, multi_dense_b.dim);
+    let mean_a = mean_vector(&multi_dense_a);
+    let mean_b = mean_vector(&multi_dense_b);
+    let dot: ScoreType = mean_a.iter().zip(&mean_b).map(|(a, b)| a * b).sum();
+    let norm_a: ScoreType = mean_a.iter().map(|a| a * a).sum::<ScoreType>().sqrt();
+    let norm_b: ScoreType = mean_b.iter().map(|b| b * b).sum::<ScoreType>().sqrt();
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return 0.0;
+    }
+    dot / (norm_a * norm_b)
+}
+
+fn mean_vector<T: PrimitiveVectorElement>(
+    multi_dense: &TypedMultiDenseVectorRef<T>,
+) -> DenseVector {
+    let mut mean = vec![0.0; multi_dense.dim];
+    let mut count = 0;
+    for dense in multi_dense.multi_vectors() {
+        let dense = T::slice_to_float_cow(Cow::Borrowed(dense));
+        for (mean, value) in mean.iter_mut().zip(dense.iter()) {
+            *mean += value;
+        }
+        count += 1;
+    }
+    if count > 0 {
+        mean.iter_mut().for_each(|mean| *mean /= count as ScoreType);
+    }
+    mean
+}
+
+/// MaxSim metric, divided by the number of vectors of `multi_dense_a`
+fn score_max_similarity_normalized<T: PrimitiveVectorElement>(
+    multi_dense_a: TypedMultiDenseVectorRef<T>,
+    multi_dense_b: TypedMultiDenseVectorRef<T>,
+    similarity: impl Fn(&[T], &[T]) -> ScoreType,
+) -> ScoreType {
+    let count = multi_dense_a.multi_vectors().count();
+    score_max_similarity_by(multi_dense_a, multi_dense_b, similarity) / count as ScoreType
+}
+
+pub(crate) fn score_multi<T: PrimitiveVectorElement, TMetric: Metric<T>>(
+    multi_vector_config: &MultiVectorConfig,
+    multi_dense_a: TypedMultiDenseVectorRef<T>,
+    multi_dense_b: TypedMultiDenseVectorRef<T>,
+) -> ScoreType {
+    score_multi_by(
+        multi_vector_config,
+        multi_dense_a,
+        multi_dense_b,
+        TMetric::similarity,
+    )
+}
+
+/// Compares multi-dense vectors with the configured comparator over the given similarity of
+/// dense vectors
+pub(crate) fn score_multi_by<T: PrimitiveVectorElement>(
     multi_vector_config: &MultiVectorConfig,
     multi_dense_a: TypedMultiDenseVectorRef<T>,
     multi_dense_b: TypedMultiDenseVectorRef<T>,
+    similarity: impl Fn(&[T], &[T]) -> ScoreType + Copy,
 ) -> ScoreType {
     match multi_vector_config.comparator {
         MultiVectorComparator::MaxSim => {
-            score_max_similarity::<T, TMetric>(multi_dense_a, multi_dense_b)
+            score_max_similarity_by(multi_dense_a, multi_dense_b, similarity)
+        }
+        MultiVectorComparator::MeanPooledCosine => {
+            score_mean_pooled_cosine(multi_dense_a, multi_dense_b)
+        }
+        MultiVectorComparator::MaxSimNormalized => {
+            score_max_similarity_normalized(multi_dense_a, multi_dense_b, similarity)
         }
+        MultiVectorComparator::SymmetricMaxSim => {
+            let forward = score_max_similarity_normalized(
+                multi_dense_a.clone(),
+                multi_dense_b.clone(),
+                similarity,
+            );
+            let backward = score_max_similarity_normalized(multi_dense_b, multi_dense_a, similarity);
+            (forward + backward) / 2.0
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_types::vectors::MultiDenseVector;
+    use crate::spaces::simple::DotProductMetric;
+
+    #[test]
+    fn test_multi_vector_comparators() {
+        let a = MultiDenseVector::new(vec![1.0, 0.0, 0.0, 1.0], 2);
+        let b = MultiDenseVector::new(vec![1.0, 0.0, 0.5, 0.5, 0.0, 2.0], 2);
+        let score = |comparator| {
+            let config = MultiVectorConfig {
+                comparator,
+                fde: None,
+            };
+            score_multi::<f32, DotProductMetric>(
+                &config,
+                TypedMultiDenseVectorRef::from(&a),
+                TypedMultiDenseVectorRef::from(&b),
+            )
+        };
+
+        // a -> b: max(1.0, 0.5, 0.0) + max(0.0, 0.5, 2.0)
+        assert_eq!(score(MultiVectorComparator::MaxSim), 3.0);
+        assert_eq!(score(MultiVectorComparator::MaxSimNormalized), 1.5);
+        // b -> a: max(1.0, 0.0) + max(0.5, 0.5) + max(0.0, 2.0) = 3.5, normalized 3.5 / 3
+        let symmetric = score(MultiVectorComparator::SymmetricMaxSim);
+        assert!((symmetric - (1.5 + 3.5 / 3.0) / 2.0).abs() < 1e-6);
+        // mean a = [0.5, 0.5], mean b = [0.5, 0.833]
+        let mean_pooled = score(MultiVectorComparator::MeanPooledCosine);
+        let expected = (0.25 + 0.5 * 2.5 / 3.0)
+            / ((0.5_f32.powi(2) * 2.0).sqrt() * (0.25_f32 + (2.5_f32 / 3.0).powi(2)).sqrt());
+        assert!((mean_pooled - expected).abs() < 1e-6);
     }
 }
@@ -0,0 +1,171 @@
+use std::borrow::Cow;
+use std::marker::PhantomData;
+
+use common::types::{PointOffsetType, ScoreType};
+
+use super::score_multi_by;
+use crate::data_types::vectors::{
+    MultiDenseVector, TypedMultiDenseVector, TypedMultiDenseVectorRef,
+};
+use crate::spaces::simple::MinkowskiSimilarity;
+use crate::vector_storage::query::{Query, TransformInto};
+use crate::vector_storage::query_scorer::QueryScorer;
+use crate::vector_storage::MultiVectorStorage;
+
+/// Multi-vector scorer for Minkowski distance of an order, which has no dedicated metric
+/// implementation
+pub struct MultiMinkowskiQueryScorer<
+    'a,
+    TElement: MinkowskiSimilarity,
+    TVectorStorage: MultiVectorStorage<TElement>,
+> {
+    vector_storage: &'a TVectorStorage,
+    query: TypedMultiDenseVector<TElement>,
+    p: u32,
+}
+
+impl<'a, TElement: MinkowskiSimilarity, TVectorStorage: MultiVectorStorage<TElement>>
+    MultiMinkowskiQueryScorer<'a, TElement, TVectorStorage>
+{
+    pub fn new(query: MultiDenseVector, p: u32, vector_storage: &'a TVectorStorage) -> Self {
+        Self {
+            query: TElement::from_float_multivector(Cow::Owned(query)).into_owned(),
+            vector_storage,
+            p,
+        }
+    }
+
+    fn score_multi(
+        &self,
+        multi_dense_a: TypedMultiDenseVectorRef<TElement>,
+        multi_dense_b: TypedMultiDenseVectorRef<TElement>,
+    ) -> ScoreType {
+        score_multi_by(
+            self.vector_storage.multi_vector_config(),
+            multi_dense_a,
+            multi_dense_b,
+            |v1, v2| TElement::minkowski_similarity(v1, v2, self.p),
+        )
+    }
+}
+
+impl<TElement: MinkowskiSimilarity, TVectorStorage: MultiVectorStorage<TElement>>
+    QueryScorer<TypedMultiDenseVector<TElement>>
+    for MultiMinkowskiQueryScorer<'_, TElement, TVectorStorage>
+{
+    #[inline]
+    fn score_stored(&self, idx: PointOffsetType) -> ScoreType {
+        self.score_multi(
+            TypedMultiDenseVectorRef::from(&self.query),
+            self.vector_storage.get_multi(idx),
+        )
+    }
+
+    #[inline]
+    fn score(&self, v2: &TypedMultiDenseVector<TElement>) -> ScoreType {
+        self.score_multi(
+            TypedMultiDenseVectorRef::from(&self.query),
+            TypedMultiDenseVectorRef::from(v2),
+        )
+    }
+
+    fn score_internal(&self, point_a: PointOffsetType, point_b: PointOffsetType) -> ScoreType {
+        let v1 = self.vector_storage.get_multi(point_a);
+        let v2 = self.vector_storage.get_multi(point_b);
+        self.score_multi(v1, v2)
+    }
+}
+
+/// Multi-vector custom query scorer for Minkowski distance of an order, which has no dedicated
+/// metric implementation
+pub struct MultiMinkowskiCustomQueryScorer<
+    'a,
+    TElement: MinkowskiSimilarity,
+    TVectorStorage: MultiVectorStorage<TElement>,
+    TQuery: Query<TypedMultiDenseVector<TElement>>,
+    TInputQuery: Query<MultiDenseVector>,
+> {
+    vector_storage: &'a TVectorStorage,
+    query: TQuery,
+    p: u32,
+    input_query: PhantomData<TInputQuery>,
+    element: PhantomData<TElement>,
+}
+
+impl<
+        'a,
+        TElement: MinkowskiSimilarity,
+        TVectorStorage: MultiVectorStorage<TElement>,
+        TQuery: Query<TypedMultiDenseVector<TElement>>,
+        TInputQuery: Query<MultiDenseVector>
+            + TransformInto<TQuery, MultiDenseVector, TypedMultiDenseVector<TElement>>,
+    > MultiMinkowskiCustomQueryScorer<'a, TElement, TVectorStorage, TQuery, TInputQuery>
+{
+    pub fn new(query: TInputQuery, p: u32, vector_storage: &'a TVectorStorage) -> Self {
+        let query = query
+            .transform(|vector| {
+                Ok(TElement::from_float_multivector(Cow::Owned(vector)).into_owned())
+            })
+            .unwrap();
+
+        Self {
+            query,
+            vector_storage,
+            p,
+            input_query: PhantomData,
+            element: PhantomData,
+        }
+    }
+}
+
+impl<
+        TElement: MinkowskiSimilarity,
+        TVectorStorage: MultiVectorStorage<TElement>,
+        TQuery: Query<TypedMultiDenseVector<TElement>>,
+        TInputQuery: Query<MultiDenseVector>,
+    > MultiMinkowskiCustomQueryScorer<'_, TElement, TVectorStorage, TQuery, TInputQuery>
+{
+    fn score_multi(
+        &self,
+        multi_dense_a: TypedMultiDenseVectorRef<TElement>,
+        multi_dense_b: TypedMultiDenseVectorRef<TElement>,
+    ) -> ScoreType {
+        score_multi_by(
+            self.vector_storage.multi_vector_config(),
+            multi_dense_a,
+            multi_dense_b,
+            |v1, v2| TElement::minkowski_similarity(v1, v2, self.p),
+        )
+    }
+}
+
+impl<
+        TElement: MinkowskiSimilarity,
+        TVectorStorage: MultiVectorStorage<TElement>,
+        TQuery: Query<TypedMultiDenseVector<TElement>>,
+        TInputQuery: Query<MultiDenseVector>,
+    > QueryScorer<TypedMultiDenseVector<TElement>>
+    for MultiMinkowskiCustomQueryScorer<'_, TElement, TVectorStorage, TQuery, TInputQuery>
+{
+    #[inline]
+    fn score_stored(&self, idx: PointOffsetType) -> ScoreType {
+        let stored = self.vector_storage.get_multi(idx);
+        self.query.score_by(|example| {
+            self.score_multi(TypedMultiDenseVectorRef::from(example), stored.clone())
+        })
+    }
+
+    #[inline]
+    fn score(&self, against: &TypedMultiDenseVector<TElement>) -> ScoreType {
+        self.query.score_by(|example| {
+            self.score_multi(
+                TypedMultiDenseVectorRef::from(example),
+                TypedMultiDenseVectorRef::from(against),
+            )
+        })
+    }
+
+    fn score_internal(&self, _point_a: PointOffsetType, _point_b: PointOffsetType) -> ScoreType {
+        unimplemented!("Custom scorer can compare against multiple vectors, not just one")
+    }
+}
@@ -0,0 +1,149 @@
+use std::marker::PhantomData;
+
+use common::types::{PointOffsetType, ScoreType};
+
+use crate::data_types::vectors::{DenseVector, VectorElementType};
+use crate::spaces::metric::Metric;
+use crate::spaces::simple::DotProductMetric;
+use crate::types::Distance;
+use crate::vector_storage::query_scorer::QueryScorer;
+use crate::vector_storage::DenseVectorStorage;
+
+/// Scores only the first `prefix_size` dimensions of stored vectors.
+///
+/// Meant for Matryoshka embeddings, where a prefix of the vector is a good approximation of the
+/// full one.
+pub struct PrefixQueryScorer<
+    'a,
+    TMetric: Metric<VectorElementType>,
+    TVectorStorage: DenseVectorStorage<VectorElementType>,
+> {
+    vector_storage: &'a TVectorStorage,
+    /// Preprocessed prefix of the query
+    query: DenseVector,
+    prefix_size: usize,
+    metric: PhantomData<TMetric>,
+}
+
+impl<
+        'a,
+        TMetric: Metric<VectorElementType>,
+        TVectorStorage: DenseVectorStorage<VectorElementType>,
+    > PrefixQueryScorer<'a, TMetric, TVectorStorage>
+{
+    pub fn new(
+        mut query: DenseVector,
+        prefix_size: usize,
+        vector_storage: &'a TVectorStorage,
+    ) -> Self {
+        query.truncate(prefix_size);
+        Self {
+            query: TMetric::preprocess(query),
+            vector_storage,
+            prefix_size,
+            metric: PhantomData,
+        }
+    }
+
+    fn stored_prefix(&self, idx: PointOffsetType) -> &[VectorElementType] {
+        let vector = self.vector_storage.get_dense(idx);
+        &vector[..self.prefix_size.min(vector.len())]
+    }
+
+    /// Stored vectors are normalized over all dimensions, so their prefixes have to be
+    /// normalized again for cosine similarity
+    fn prefix_norm(prefix: &[VectorElementType]) -> ScoreType {
+        if TMetric::distance() == Distance::Cosine {
+            DotProductMetric::similarity(prefix, prefix).sqrt()
+        } else {
+            1.0
+        }
+    }
+
+    fn similarity(
+        v1: &[VectorElementType],
+        v2: &[VectorElementType],
+        norm: ScoreType,
+    ) -> ScoreType {
+        let similarity = TMetric::similarity(v1, v2);
+        if norm > 0.0 {
+            similarity / norm
+        } else {
+            similarity
+        }
+    }
+}
+
+impl<
+        'a,
+        TMetric: Metric<VectorElementType>,
+        TVectorStorage: DenseVectorStorage<VectorElementType>,
+    > QueryScorer<[VectorElementType]> for PrefixQueryScorer<'a, TMetric, TVectorStorage>
+{
+    #[inline]
+    fn score_stored(&self, idx: PointOffsetType) -> ScoreType {
+        let stored = self.stored_prefix(idx);
+        Self::similarity(&self.query, stored, Self::prefix_norm(stored))
+    }
+
+    #[inline]
+    fn score(&self, v2: &[VectorElementType]) -> ScoreType {
+        let v2 = &v2[..self.prefix_size.min(v2.len())];
+        Self::similarity(&self.query, v2, Self::prefix_norm(v2))
+    }
+
+    fn score_internal(&self, point_a: PointOffsetType, point_b: PointOffsetType) -> ScoreType {
+        let v1 = self.stored_prefix(point_a);
+        let v2 = self.stored_prefix(point_b);
+        Self::similarity(v1, v2, Self::prefix_norm(v1) * Self::prefix_norm(v2))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicBool;
+
+    use super::*;
+    use crate::common::rocksdb_wrapper::{open_db, DB_VECTOR_CF};
+    use crate::data_types::vectors::VectorRef;
+    use crate::spaces::simple::CosineMetric;
+    use crate::vector_storage::dense::simple_dense_vector_storage::open_simple_dense_vector_storage;
+    use crate::vector_storage::{VectorStorage, VectorStorageEnum};
+
+    #[test]
+    fn test_prefix_cosine_is_renormalized() {
+        let dir = tempfile::Builder::new()
+            .prefix("storage")
+            .tempdir()
+            .unwrap();
+        let db = open_db(dir.path(), &[DB_VECTOR_CF]).unwrap();
+        let storage = open_simple_dense_vector_storage(
+            db,
+            DB_VECTOR_CF,
+            4,
+            Distance::Cosine,
+            &AtomicBool::new(false),
+        )
+        .unwrap();
+        let mut storage = storage.borrow_mut();
+
+        // Prefixes of both vectors point the same way, while the full vectors don't
+        let vectors = [vec![1.0, 1.0, 0.0, 0.0], vec![2.0, 2.0, 5.0, 0.0]];
+        for (idx, vector) in vectors.iter().enumerate() {
+            let vector = CosineMetric::preprocess(vector.clone());
+            storage
+                .insert_vector(idx as PointOffsetType, VectorRef::from(&vector))
+                .unwrap();
+        }
+
+        let VectorStorageEnum::DenseSimple(storage) = &*storage else {
+            unreachable!();
+        };
+        let scorer =
+            PrefixQueryScorer::<CosineMetric, _>::new(vec![3.0, 3.0, 0.0, 1.0], 2, storage);
+
+        assert!((scorer.score_stored(0) - 1.0).abs() < 1e-5);
+        assert!((scorer.score_stored(1) - 1.0).abs() < 1e-5);
+        assert!((scorer.score_internal(0, 1) - 1.0).abs() < 1e-5);
+    }
+}
@@ -6,22 +6,36 @@ use sparse::common::sparse_vector::SparseVector;
 
 use super::query::context_query::ContextQuery;
 use super::query::discovery_query::DiscoveryQuery;
-use super::query::reco_query::RecoQuery;
+use super::query::reco_query::{RecoQuery, RecoSumScoresQuery};
 use super::query::TransformInto;
 use super::query_scorer::custom_query_scorer::CustomQueryScorer;
+use super::query_scorer::fde_query_scorer::FdeQueryScorer;
 use super::query_scorer::multi_custom_query_scorer::MultiCustomQueryScorer;
 use super::query_scorer::sparse_custom_query_scorer::SparseCustomQueryScorer;
-use super::{DenseVectorStorage, MultiVectorStorage, SparseVectorStorage, VectorStorageEnum};
+use super::{
+    DenseVectorStorage, MultiVectorStorage, SparseVectorStorage, VectorStorage, VectorStorageEnum,
+};
 use crate::common::operation_error::{OperationError, OperationResult};
 use crate::data_types::vectors::{
-    DenseVector, MultiDenseVector, QueryVector, VectorElementType, VectorElementTypeByte,
+    DenseVector, MultiDenseVector, QueryVector, Vector, VectorElementType, VectorElementTypeByte,
+    VectorElementTypeHalf,
 };
 use crate::spaces::metric::Metric;
-use crate::spaces::simple::{CosineMetric, DotProductMetric, EuclidMetric, ManhattanMetric};
+use crate::spaces::simple::{
+    CosineMetric, DotProductMetric, EuclidMetric, HammingMetric, JaccardMetric, ManhattanMetric,
+    MinkowskiMetric, MinkowskiSimilarity,
+};
 use crate::spaces::tools::peek_top_largest_iterable;
 use crate::types::Distance;
 use crate::vector_storage::query_scorer::metric_query_scorer::MetricQueryScorer;
+use crate::vector_storage::query_scorer::minkowski_query_scorer::{
+    MinkowskiCustomQueryScorer, MinkowskiQueryScorer,
+};
 use crate::vector_storage::query_scorer::multi_metric_query_scorer::MultiMetricQueryScorer;
+use crate::vector_storage::query_scorer::multi_minkowski_query_scorer::{
+    MultiMinkowskiCustomQueryScorer, MultiMinkowskiQueryScorer,
+};
+use crate::vector_storage::query_scorer::prefix_query_scorer::PrefixQueryScorer;
 use crate::vector_storage::query_scorer::QueryScorer;
 
 /// RawScorer composition:
@@ -116,6 +130,9 @@ pub fn new_stoppable_raw_scorer<'a>(
         VectorStorageEnum::DenseSimpleByte(vs) => {
             raw_scorer_byte_impl(query, vs, point_deleted, is_stopped)
         }
+        VectorStorageEnum::DenseSimpleHalf(vs) => {
+            raw_scorer_half_impl(query, vs, point_deleted, is_stopped)
+        }
 
         VectorStorageEnum::DenseMemmap(vs) => {
             if vs.has_async_reader() {
@@ -140,6 +157,9 @@ pub fn new_stoppable_raw_scorer<'a>(
         VectorStorageEnum::DenseMemmapByte(vs) => {
             raw_scorer_byte_impl(query, vs.as_ref(), point_deleted, is_stopped)
         }
+        VectorStorageEnum::DenseMemmapHalf(vs) => {
+            raw_scorer_half_impl(query, vs.as_ref(), point_deleted, is_stopped)
+        }
 
         VectorStorageEnum::DenseAppendableMemmap(vs) => {
             raw_scorer_impl(query, vs.as_ref(), point_deleted, is_stopped)
@@ -147,6 +167,9 @@ pub fn new_stoppable_raw_scorer<'a>(
         VectorStorageEnum::DenseAppendableMemmapByte(vs) => {
             raw_scorer_byte_impl(query, vs.as_ref(), point_deleted, is_stopped)
         }
+        VectorStorageEnum::DenseAppendableMemmapHalf(vs) => {
+            raw_scorer_half_impl(query, vs.as_ref(), point_deleted, is_stopped)
+        }
         VectorStorageEnum::SparseSimple(vs) => {
             raw_sparse_scorer_impl(query, vs, point_deleted, is_stopped)
         }
@@ -156,12 +179,170 @@ pub fn new_stoppable_raw_scorer<'a>(
         VectorStorageEnum::MultiDenseSimpleByte(vs) => {
             raw_multi_scorer_byte_impl(query, vs, point_deleted, is_stopped)
         }
+        VectorStorageEnum::MultiDenseSimpleHalf(vs) => {
+            raw_multi_scorer_half_impl(query, vs, point_deleted, is_stopped)
+        }
         VectorStorageEnum::MultiDenseAppendableMemmap(vs) => {
             raw_multi_scorer_impl(query, vs.as_ref(), point_deleted, is_stopped)
         }
         VectorStorageEnum::MultiDenseAppendableMemmapByte(vs) => {
             raw_multi_scorer_byte_impl(query, vs.as_ref(), point_deleted, is_stopped)
         }
+        VectorStorageEnum::MultiDenseAppendableMemmapHalf(vs) => {
+            raw_multi_scorer_half_impl(query, vs.as_ref(), point_deleted, is_stopped)
+        }
+    }
+}
+
+/// Raw scorer, which compares only the first `prefix_size` dimensions of nearest queries
+/// with stored vectors.
+///
+/// Other queries are scored with full vectors.
+pub fn new_prefix_raw_scorer<'a>(
+    query: QueryVector,
+    vector_storage: &'a VectorStorageEnum,
+    prefix_size: usize,
+    point_deleted: &'a BitSlice,
+    is_stopped: &'a AtomicBool,
+) -> OperationResult<Box<dyn RawScorer + 'a>> {
+    let QueryVector::Nearest(vector) = query else {
+        return new_stoppable_raw_scorer(query, vector_storage, point_deleted, is_stopped);
+    };
+    let vector: DenseVector = vector.try_into()?;
+    match vector_storage {
+        VectorStorageEnum::DenseSimple(vs) => {
+            prefix_raw_scorer_impl(vector, vs, prefix_size, point_deleted, is_stopped)
+        }
+        VectorStorageEnum::DenseMemmap(vs) => {
+            prefix_raw_scorer_impl(vector, vs.as_ref(), prefix_size, point_deleted, is_stopped)
+        }
+        VectorStorageEnum::DenseAppendableMemmap(vs) => {
+            prefix_raw_scorer_impl(vector, vs.as_ref(), prefix_size, point_deleted, is_stopped)
+        }
+        VectorStorageEnum::DenseSimpleByte(_)
+        | VectorStorageEnum::DenseSimpleHalf(_)
+        | VectorStorageEnum::DenseMemmapByte(_)
+        | VectorStorageEnum::DenseMemmapHalf(_)
+        | VectorStorageEnum::DenseAppendableMemmapByte(_)
+        | VectorStorageEnum::DenseAppendableMemmapHalf(_) => Err(OperationError::service_error(
+            "Prefix search is only supported for float32 vectors",
+        )),
+        VectorStorageEnum::SparseSimple(_) => Err(OperationError::WrongSparse),
+        VectorStorageEnum::MultiDenseSimple(_)
+        | VectorStorageEnum::MultiDenseSimpleByte(_)
+        | VectorStorageEnum::MultiDenseSimpleHalf(_)
+        | VectorStorageEnum::MultiDenseAppendableMemmap(_)
+        | VectorStorageEnum::MultiDenseAppendableMemmapByte(_)
+        | VectorStorageEnum::MultiDenseAppendableMemmapHalf(_) => Err(OperationError::WrongMulti),
+    }
+}
+
+/// Raw scorer, which compares nearest multivector queries with stored multivectors by the dot
+/// product of their fixed dimensional encodings.
+///
+/// Other queries, and storages without encodings, are scored exactly.
+pub fn new_fde_raw_scorer<'a>(
+    query: QueryVector,
+    vector_storage: &'a VectorStorageEnum,
+    point_deleted: &'a BitSlice,
+    is_stopped: &'a AtomicBool,
+) -> OperationResult<Box<dyn RawScorer + 'a>> {
+    let (QueryVector::Nearest(Vector::MultiDense(multi_vector)), Some(fixed_dimensional_encodings)) =
+        (&query, vector_storage.fixed_dimensional_encodings())
+    else {
+        return new_stoppable_raw_scorer(query, vector_storage, point_deleted, is_stopped);
+    };
+
+    // Encodings are only configured for cosine and dot distances
+    let preprocessed_query = match vector_storage.distance() {
+        Distance::Cosine => MultiDenseVector::new(
+            multi_vector
+                .multi_vectors()
+                .flat_map(|vector| {
+                    <CosineMetric as Metric<VectorElementType>>::preprocess(vector.to_vec())
+                })
+                .collect(),
+            multi_vector.dim,
+        ),
+        _ => multi_vector.clone(),
+    };
+    let query_encoding = fixed_dimensional_encodings.encode_query(&preprocessed_query);
+
+    raw_scorer_from_query_scorer(
+        FdeQueryScorer::new(fixed_dimensional_encodings, query_encoding),
+        point_deleted,
+        vector_storage.deleted_vector_bitslice(),
+        is_stopped,
+    )
+}
+
+fn prefix_raw_scorer_impl<'a, TVectorStorage: DenseVectorStorage<VectorElementType>>(
+    vector: DenseVector,
+    vector_storage: &'a TVectorStorage,
+    prefix_size: usize,
+    point_deleted: &'a BitSlice,
+    is_stopped: &'a AtomicBool,
+) -> OperationResult<Box<dyn RawScorer + 'a>> {
+    let vec_deleted = vector_storage.deleted_vector_bitslice();
+    match vector_storage.distance() {
+        Distance::Cosine => raw_scorer_from_query_scorer(
+            PrefixQueryScorer::<CosineMetric, _>::new(vector, prefix_size, vector_storage),
+            point_deleted,
+            vec_deleted,
+            is_stopped,
+        ),
+        Distance::Euclid => raw_scorer_from_query_scorer(
+            PrefixQueryScorer::<EuclidMetric, _>::new(vector, prefix_size, vector_storage),
+            point_deleted,
+            vec_deleted,
+            is_stopped,
+        ),
+        Distance::Dot => raw_scorer_from_query_scorer(
+            PrefixQueryScorer::<DotProductMetric, _>::new(vector, prefix_size, vector_storage),
+            point_deleted,
+            vec_deleted,
+            is_stopped,
+        ),
+        Distance::Manhattan => raw_scorer_from_query_scorer(
+            PrefixQueryScorer::<ManhattanMetric, _>::new(vector, prefix_size, vector_storage),
+            point_deleted,
+            vec_deleted,
+            is_stopped,
+        ),
+        Distance::Hamming => raw_scorer_from_query_scorer(
+            PrefixQueryScorer::<HammingMetric, _>::new(vector, prefix_size, vector_storage),
+            point_deleted,
+            vec_deleted,
+            is_stopped,
+        ),
+        Distance::Jaccard => raw_scorer_from_query_scorer(
+            PrefixQueryScorer::<JaccardMetric, _>::new(vector, prefix_size, vector_storage),
+            point_deleted,
+            vec_deleted,
+            is_stopped,
+        ),
+        Distance::Minkowski(3) => raw_scorer_from_query_scorer(
+            PrefixQueryScorer::<MinkowskiMetric<3>, _>::new(vector, prefix_size, vector_storage),
+            point_deleted,
+            vec_deleted,
+            is_stopped,
+        ),
+        Distance::Minkowski(4) => raw_scorer_from_query_scorer(
+            PrefixQueryScorer::<MinkowskiMetric<4>, _>::new(vector, prefix_size, vector_storage),
+            point_deleted,
+            vec_deleted,
+            is_stopped,
+        ),
+        Distance::Minkowski(p) => {
+            let mut prefix = vector;
+            prefix.truncate(prefix_size);
+            raw_scorer_from_query_scorer(
+                MinkowskiQueryScorer::<VectorElementType, _>::new(prefix, p, vector_storage),
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )
+        }
     }
 }
 
@@ -187,6 +368,15 @@ pub fn raw_sparse_scorer_impl<'a, TVectorStorage: SparseVectorStorage>(
                 is_stopped,
             )
         }
+        QueryVector::RecommendSumScores(reco_query) => {
+            let reco_query: RecoSumScoresQuery<SparseVector> = reco_query.transform_into()?;
+            raw_scorer_from_query_scorer(
+                SparseCustomQueryScorer::<_, _>::new(reco_query, vector_storage),
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )
+        }
         QueryVector::Discovery(discovery_query) => {
             let discovery_query: DiscoveryQuery<SparseVector> = discovery_query.transform_into()?;
             raw_scorer_from_query_scorer(
@@ -247,6 +437,33 @@ pub fn raw_scorer_impl<'a, TVectorStorage: DenseVectorStorage<VectorElementType>
             point_deleted,
             is_stopped,
         ),
+        Distance::Hamming => new_scorer_with_metric::<HammingMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Jaccard => new_scorer_with_metric::<JaccardMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Minkowski(3) => new_scorer_with_metric::<MinkowskiMetric<3>, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Minkowski(4) => new_scorer_with_metric::<MinkowskiMetric<4>, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Minkowski(p) => {
+            new_minkowski_scorer(p, query, vector_storage, point_deleted, is_stopped)
+        }
     }
 }
 
@@ -283,6 +500,18 @@ fn new_scorer_with_metric<
                 is_stopped,
             )
         }
+        QueryVector::RecommendSumScores(reco_query) => {
+            let reco_query: RecoSumScoresQuery<DenseVector> = reco_query.transform_into()?;
+            raw_scorer_from_query_scorer(
+                CustomQueryScorer::<VectorElementType, TMetric, _, _, _>::new(
+                    reco_query,
+                    vector_storage,
+                ),
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )
+        }
         QueryVector::Discovery(discovery_query) => {
             let discovery_query: DiscoveryQuery<DenseVector> = discovery_query.transform_into()?;
             raw_scorer_from_query_scorer(
@@ -341,6 +570,94 @@ pub fn raw_scorer_byte_impl<'a, TVectorStorage: DenseVectorStorage<VectorElement
             point_deleted,
             is_stopped,
         ),
+        Distance::Hamming => new_scorer_byte_with_metric::<HammingMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Jaccard => new_scorer_byte_with_metric::<JaccardMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Minkowski(3) => new_scorer_byte_with_metric::<MinkowskiMetric<3>, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Minkowski(4) => new_scorer_byte_with_metric::<MinkowskiMetric<4>, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Minkowski(p) => {
+            new_minkowski_scorer(p, query, vector_storage, point_deleted, is_stopped)
+        }
+    }
+}
+
+pub fn raw_scorer_half_impl<'a, TVectorStorage: DenseVectorStorage<VectorElementTypeHalf>>(
+    query: QueryVector,
+    vector_storage: &'a TVectorStorage,
+    point_deleted: &'a BitSlice,
+    is_stopped: &'a AtomicBool,
+) -> OperationResult<Box<dyn RawScorer + 'a>> {
+    match vector_storage.distance() {
+        Distance::Cosine => new_scorer_half_with_metric::<CosineMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Euclid => new_scorer_half_with_metric::<EuclidMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Dot => new_scorer_half_with_metric::<DotProductMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Manhattan => new_scorer_half_with_metric::<ManhattanMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Hamming => new_scorer_half_with_metric::<HammingMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Jaccard => new_scorer_half_with_metric::<JaccardMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Minkowski(3) => new_scorer_half_with_metric::<MinkowskiMetric<3>, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Minkowski(4) => new_scorer_half_with_metric::<MinkowskiMetric<4>, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Minkowski(p) => {
+            new_minkowski_scorer(p, query, vector_storage, point_deleted, is_stopped)
+        }
     }
 }
 
@@ -377,6 +694,18 @@ fn new_scorer_byte_with_metric<
                 is_stopped,
             )
         }
+        QueryVector::RecommendSumScores(reco_query) => {
+            let reco_query: RecoSumScoresQuery<DenseVector> = reco_query.transform_into()?;
+            raw_scorer_from_query_scorer(
+                CustomQueryScorer::<VectorElementTypeByte, TMetric, _, _, _>::new(
+                    reco_query,
+                    vector_storage,
+                ),
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )
+        }
         QueryVector::Discovery(discovery_query) => {
             let discovery_query: DiscoveryQuery<DenseVector> = discovery_query.transform_into()?;
             raw_scorer_from_query_scorer(
@@ -404,57 +733,223 @@ fn new_scorer_byte_with_metric<
     }
 }
 
-pub fn raw_scorer_from_query_scorer<'a, TVector, TQueryScorer>(
-    query_scorer: TQueryScorer,
-    point_deleted: &'a BitSlice,
-    vec_deleted: &'a BitSlice,
-    is_stopped: &'a AtomicBool,
-) -> OperationResult<Box<dyn RawScorer + 'a>>
-where
-    TVector: ?Sized + 'a,
-    TQueryScorer: QueryScorer<TVector> + 'a,
-{
-    Ok(Box::new(RawScorerImpl::<TVector, TQueryScorer> {
-        query_scorer,
-        point_deleted,
-        vec_deleted,
-        is_stopped,
-        vector: std::marker::PhantomData,
-    }))
-}
-
-pub fn raw_multi_scorer_impl<'a, TVectorStorage: MultiVectorStorage<VectorElementType>>(
+fn new_scorer_half_with_metric<
+    'a,
+    TMetric: Metric<VectorElementTypeHalf> + 'a,
+    TVectorStorage: DenseVectorStorage<VectorElementTypeHalf>,
+>(
     query: QueryVector,
     vector_storage: &'a TVectorStorage,
     point_deleted: &'a BitSlice,
     is_stopped: &'a AtomicBool,
 ) -> OperationResult<Box<dyn RawScorer + 'a>> {
-    match vector_storage.distance() {
-        Distance::Cosine => new_multi_scorer_with_metric::<CosineMetric, _>(
-            query,
-            vector_storage,
-            point_deleted,
-            is_stopped,
-        ),
-        Distance::Euclid => new_multi_scorer_with_metric::<EuclidMetric, _>(
-            query,
-            vector_storage,
-            point_deleted,
-            is_stopped,
-        ),
-        Distance::Dot => new_multi_scorer_with_metric::<DotProductMetric, _>(
-            query,
-            vector_storage,
-            point_deleted,
-            is_stopped,
-        ),
-        Distance::Manhattan => new_multi_scorer_with_metric::<ManhattanMetric, _>(
-            query,
-            vector_storage,
+    let vec_deleted = vector_storage.deleted_vector_bitslice();
+    match query {
+        QueryVector::Nearest(vector) => raw_scorer_from_query_scorer(
+            MetricQueryScorer::<VectorElementTypeHalf, TMetric, _>::new(
+                vector.try_into()?,
+                vector_storage,
+            ),
             point_deleted,
+            vec_deleted,
             is_stopped,
         ),
-    }
+        QueryVector::Recommend(reco_query) => {
+            let reco_query: RecoQuery<DenseVector> = reco_query.transform_into()?;
+            raw_scorer_from_query_scorer(
+                CustomQueryScorer::<VectorElementTypeHalf, TMetric, _, _, _>::new(
+                    reco_query,
+                    vector_storage,
+                ),
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )
+        }
+        QueryVector::RecommendSumScores(reco_query) => {
+            let reco_query: RecoSumScoresQuery<DenseVector> = reco_query.transform_into()?;
+            raw_scorer_from_query_scorer(
+                CustomQueryScorer::<VectorElementTypeHalf, TMetric, _, _, _>::new(
+                    reco_query,
+                    vector_storage,
+                ),
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )
+        }
+        QueryVector::Discovery(discovery_query) => {
+            let discovery_query: DiscoveryQuery<DenseVector> = discovery_query.transform_into()?;
+            raw_scorer_from_query_scorer(
+                CustomQueryScorer::<VectorElementTypeHalf, TMetric, _, _, _>::new(
+                    discovery_query,
+                    vector_storage,
+                ),
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )
+        }
+        QueryVector::Context(context_query) => {
+            let context_query: ContextQuery<DenseVector> = context_query.transform_into()?;
+            raw_scorer_from_query_scorer(
+                CustomQueryScorer::<VectorElementTypeHalf, TMetric, _, _, _>::new(
+                    context_query,
+                    vector_storage,
+                ),
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )
+        }
+    }
+}
+
+/// Scorer for Minkowski distance of an order, which has no dedicated metric implementation
+fn new_minkowski_scorer<
+    'a,
+    TElement: MinkowskiSimilarity + 'a,
+    TVectorStorage: DenseVectorStorage<TElement>,
+>(
+    p: u32,
+    query: QueryVector,
+    vector_storage: &'a TVectorStorage,
+    point_deleted: &'a BitSlice,
+    is_stopped: &'a AtomicBool,
+) -> OperationResult<Box<dyn RawScorer + 'a>> {
+    let vec_deleted = vector_storage.deleted_vector_bitslice();
+    match query {
+        QueryVector::Nearest(vector) => raw_scorer_from_query_scorer(
+            MinkowskiQueryScorer::<TElement, _>::new(vector.try_into()?, p, vector_storage),
+            point_deleted,
+            vec_deleted,
+            is_stopped,
+        ),
+        QueryVector::Recommend(reco_query) => {
+            let reco_query: RecoQuery<DenseVector> = reco_query.transform_into()?;
+            raw_scorer_from_query_scorer(
+                MinkowskiCustomQueryScorer::<TElement, _, _, _>::new(reco_query, p, vector_storage),
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )
+        }
+        QueryVector::RecommendSumScores(reco_query) => {
+            let reco_query: RecoSumScoresQuery<DenseVector> = reco_query.transform_into()?;
+            raw_scorer_from_query_scorer(
+                MinkowskiCustomQueryScorer::<TElement, _, _, _>::new(reco_query, p, vector_storage),
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )
+        }
+        QueryVector::Discovery(discovery_query) => {
+            let discovery_query: DiscoveryQuery<DenseVector> = discovery_query.transform_into()?;
+            raw_scorer_from_query_scorer(
+                MinkowskiCustomQueryScorer::<TElement, _, _, _>::new(
+                    discovery_query,
+                    p,
+                    vector_storage,
+                ),
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )
+        }
+        QueryVector::Context(context_query) => {
+            let context_query: ContextQuery<DenseVector> = context_query.transform_into()?;
+            raw_scorer_from_query_scorer(
+                MinkowskiCustomQueryScorer::<TElement, _, _, _>::new(
+                    context_query,
+                    p,
+                    vector_storage,
+                ),
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )
+        }
+    }
+}
+
+pub fn raw_scorer_from_query_scorer<'a, TVector, TQueryScorer>(
+    query_scorer: TQueryScorer,
+    point_deleted: &'a BitSlice,
+    vec_deleted: &'a BitSlice,
+    is_stopped: &'a AtomicBool,
+) -> OperationResult<Box<dyn RawScorer + 'a>>
+where
+    TVector: ?Sized + 'a,
+    TQueryScorer: QueryScorer<TVector> + 'a,
+{
+    Ok(Box::new(RawScorerImpl::<TVector, TQueryScorer> {
+        query_scorer,
+        point_deleted,
+        vec_deleted,
+        is_stopped,
+        vector: std::marker::PhantomData,
+    }))
+}
+
+pub fn raw_multi_scorer_impl<'a, TVectorStorage: MultiVectorStorage<VectorElementType>>(
+    query: QueryVector,
+    vector_storage: &'a TVectorStorage,
+    point_deleted: &'a BitSlice,
+    is_stopped: &'a AtomicBool,
+) -> OperationResult<Box<dyn RawScorer + 'a>> {
+    match vector_storage.distance() {
+        Distance::Cosine => new_multi_scorer_with_metric::<CosineMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Euclid => new_multi_scorer_with_metric::<EuclidMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Dot => new_multi_scorer_with_metric::<DotProductMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Manhattan => new_multi_scorer_with_metric::<ManhattanMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Hamming => new_multi_scorer_with_metric::<HammingMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Jaccard => new_multi_scorer_with_metric::<JaccardMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Minkowski(3) => new_multi_scorer_with_metric::<MinkowskiMetric<3>, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Minkowski(4) => new_multi_scorer_with_metric::<MinkowskiMetric<4>, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Minkowski(p) => {
+            new_multi_minkowski_scorer(p, query, vector_storage, point_deleted, is_stopped)
+        }
+    }
 }
 
 fn new_multi_scorer_with_metric<
@@ -490,6 +985,18 @@ fn new_multi_scorer_with_metric<
                 is_stopped,
             )
         }
+        QueryVector::RecommendSumScores(reco_query) => {
+            let reco_query: RecoSumScoresQuery<MultiDenseVector> = reco_query.transform_into()?;
+            raw_scorer_from_query_scorer(
+                MultiCustomQueryScorer::<VectorElementType, TMetric, _, _, _>::new(
+                    reco_query,
+                    vector_storage,
+                ),
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )
+        }
         QueryVector::Discovery(discovery_query) => {
             let discovery_query: DiscoveryQuery<MultiDenseVector> =
                 discovery_query.transform_into()?;
@@ -549,6 +1056,94 @@ pub fn raw_multi_scorer_byte_impl<'a, TVectorStorage: MultiVectorStorage<VectorE
             point_deleted,
             is_stopped,
         ),
+        Distance::Hamming => new_multi_scorer_byte_with_metric::<HammingMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Jaccard => new_multi_scorer_byte_with_metric::<JaccardMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Minkowski(3) => new_multi_scorer_byte_with_metric::<MinkowskiMetric<3>, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Minkowski(4) => new_multi_scorer_byte_with_metric::<MinkowskiMetric<4>, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Minkowski(p) => {
+            new_multi_minkowski_scorer(p, query, vector_storage, point_deleted, is_stopped)
+        }
+    }
+}
+
+pub fn raw_multi_scorer_half_impl<'a, TVectorStorage: MultiVectorStorage<VectorElementTypeHalf>>(
+    query: QueryVector,
+    vector_storage: &'a TVectorStorage,
+    point_deleted: &'a BitSlice,
+    is_stopped: &'a AtomicBool,
+) -> OperationResult<Box<dyn RawScorer + 'a>> {
+    match vector_storage.distance() {
+        Distance::Cosine => new_multi_scorer_half_with_metric::<CosineMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Euclid => new_multi_scorer_half_with_metric::<EuclidMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Dot => new_multi_scorer_half_with_metric::<DotProductMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Manhattan => new_multi_scorer_half_with_metric::<ManhattanMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Hamming => new_multi_scorer_half_with_metric::<HammingMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Jaccard => new_multi_scorer_half_with_metric::<JaccardMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Minkowski(3) => new_multi_scorer_half_with_metric::<MinkowskiMetric<3>, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Minkowski(4) => new_multi_scorer_half_with_metric::<MinkowskiMetric<4>, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Minkowski(p) => {
+            new_multi_minkowski_scorer(p, query, vector_storage, point_deleted, is_stopped)
+        }
     }
 }
 
@@ -585,6 +1180,18 @@ fn new_multi_scorer_byte_with_metric<
                 is_stopped,
             )
         }
+        QueryVector::RecommendSumScores(reco_query) => {
+            let reco_query: RecoSumScoresQuery<MultiDenseVector> = reco_query.transform_into()?;
+            raw_scorer_from_query_scorer(
+                MultiCustomQueryScorer::<VectorElementTypeByte, TMetric, _, _, _>::new(
+                    reco_query,
+                    vector_storage,
+                ),
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )
+        }
         QueryVector::Discovery(discovery_query) => {
             let discovery_query: DiscoveryQuery<MultiDenseVector> =
                 discovery_query.transform_into()?;
@@ -613,6 +1220,155 @@ fn new_multi_scorer_byte_with_metric<
     }
 }
 
+fn new_multi_scorer_half_with_metric<
+    'a,
+    TMetric: Metric<VectorElementTypeHalf> + 'a,
+    TVectorStorage: MultiVectorStorage<VectorElementTypeHalf>,
+>(
+    query: QueryVector,
+    vector_storage: &'a TVectorStorage,
+    point_deleted: &'a BitSlice,
+    is_stopped: &'a AtomicBool,
+) -> OperationResult<Box<dyn RawScorer + 'a>> {
+    let vec_deleted = vector_storage.deleted_vector_bitslice();
+    match query {
+        QueryVector::Nearest(vector) => raw_scorer_from_query_scorer(
+            MultiMetricQueryScorer::<VectorElementTypeHalf, TMetric, _>::new(
+                vector.try_into()?,
+                vector_storage,
+            ),
+            point_deleted,
+            vec_deleted,
+            is_stopped,
+        ),
+        QueryVector::Recommend(reco_query) => {
+            let reco_query: RecoQuery<MultiDenseVector> = reco_query.transform_into()?;
+            raw_scorer_from_query_scorer(
+                MultiCustomQueryScorer::<VectorElementTypeHalf, TMetric, _, _, _>::new(
+                    reco_query,
+                    vector_storage,
+                ),
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )
+        }
+        QueryVector::RecommendSumScores(reco_query) => {
+            let reco_query: RecoSumScoresQuery<MultiDenseVector> = reco_query.transform_into()?;
+            raw_scorer_from_query_scorer(
+                MultiCustomQueryScorer::<VectorElementTypeHalf, TMetric, _, _, _>::new(
+                    reco_query,
+                    vector_storage,
+                ),
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )
+        }
+        QueryVector::Discovery(discovery_query) => {
+            let discovery_query: DiscoveryQuery<MultiDenseVector> =
+                discovery_query.transform_into()?;
+            raw_scorer_from_query_scorer(
+                MultiCustomQueryScorer::<VectorElementTypeHalf, TMetric, _, _, _>::new(
+                    discovery_query,
+                    vector_storage,
+                ),
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )
+        }
+        QueryVector::Context(context_query) => {
+            let context_query: ContextQuery<MultiDenseVector> = context_query.transform_into()?;
+            raw_scorer_from_query_scorer(
+                MultiCustomQueryScorer::<VectorElementTypeHalf, TMetric, _, _, _>::new(
+                    context_query,
+                    vector_storage,
+                ),
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )
+        }
+    }
+}
+
+/// Multi-vector scorer for Minkowski distance of an order, which has no dedicated metric implementation
+fn new_multi_minkowski_scorer<
+    'a,
+    TElement: MinkowskiSimilarity + 'a,
+    TVectorStorage: MultiVectorStorage<TElement>,
+>(
+    p: u32,
+    query: QueryVector,
+    vector_storage: &'a TVectorStorage,
+    point_deleted: &'a BitSlice,
+    is_stopped: &'a AtomicBool,
+) -> OperationResult<Box<dyn RawScorer + 'a>> {
+    let vec_deleted = vector_storage.deleted_vector_bitslice();
+    match query {
+        QueryVector::Nearest(vector) => raw_scorer_from_query_scorer(
+            MultiMinkowskiQueryScorer::<TElement, _>::new(vector.try_into()?, p, vector_storage),
+            point_deleted,
+            vec_deleted,
+            is_stopped,
+        ),
+        QueryVector::Recommend(reco_query) => {
+            let reco_query: RecoQuery<MultiDenseVector> = reco_query.transform_into()?;
+            raw_scorer_from_query_scorer(
+                MultiMinkowskiCustomQueryScorer::<TElement, _, _, _>::new(
+                    reco_query,
+                    p,
+                    vector_storage,
+                ),
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )
+        }
+        QueryVector::RecommendSumScores(reco_query) => {
+            let reco_query: RecoSumScoresQuery<MultiDenseVector> = reco_query.transform_into()?;
+            raw_scorer_from_query_scorer(
+                MultiMinkowskiCustomQueryScorer::<TElement, _, _, _>::new(
+                    reco_query,
+                    p,
+                    vector_storage,
+                ),
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )
+        }
+        QueryVector::Discovery(discovery_query) => {
+            let discovery_query: DiscoveryQuery<MultiDenseVector> =
+                discovery_query.transform_into()?;
+            raw_scorer_from_query_scorer(
+                MultiMinkowskiCustomQueryScorer::<TElement, _, _, _>::new(
+                    discovery_query,
+                    p,
+                    vector_storage,
+                ),
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )
+        }
+        QueryVector::Context(context_query) => {
+            let context_query: ContextQuery<MultiDenseVector> = context_query.transform_into()?;
+            raw_scorer_from_query_scorer(
+                MultiMinkowskiCustomQueryScorer::<TElement, _, _, _>::new(
+                    context_query,
+                    p,
+                    vector_storage,
+                ),
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )
+        }
+    }
+}
+
 impl<'a, TVector, TQueryScorer> RawScorer for RawScorerImpl<'a, TVector, TQueryScorer>
 where
     TVector: ?Sized,
@@ -124,5 +124,11 @@ fn test_random_score(
 
     assert_eq!(res, async_res);
 
+    // Prefetched vectors are read the same way
+    storage.prefetch_vectors(&points)?;
+    let prefetched_res = score(&*raw_scorer, &points);
+
+    assert_eq!(res, prefetched_res);
+
     Ok(())
 }
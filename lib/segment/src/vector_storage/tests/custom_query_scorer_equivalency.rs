@@ -164,6 +164,8 @@ fn product_x4() -> Option<WithQuantization> {
 fn binary() -> Option<WithQuantization> {
     let config = BinaryQuantizationConfig {
         always_ram: Some(true),
+        encoding: None,
+        query_encoding: None,
     }
     .into();
 
@@ -232,6 +234,7 @@ fn scoring_equivalency(
         Some(QuantizedVectors::create(
             &other_storage,
             config,
+            None,
             quant_dir.path(),
             4,
             &AtomicBool::new(false),
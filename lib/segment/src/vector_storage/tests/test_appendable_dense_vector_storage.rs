@@ -311,7 +311,7 @@ fn test_score_quantized_points(storage: Arc<AtomicRefCell<VectorStorageEnum>>) {
 
     let stopped = AtomicBool::new(false);
     let quantized_vectors =
-        QuantizedVectors::create(&borrowed_storage, &config, dir.path(), 1, &stopped).unwrap();
+        QuantizedVectors::create(&borrowed_storage, &config, None, dir.path(), 1, &stopped).unwrap();
 
     let query: QueryVector = vec![0.5, 0.5, 0.5, 0.5].into();
 
@@ -10,7 +10,7 @@ use crate::common::rocksdb_wrapper::{open_db, DB_VECTOR_CF};
 use crate::data_types::vectors::QueryVector;
 use crate::fixtures::payload_context_fixture::FixtureIdTracker;
 use crate::id_tracker::IdTrackerSS;
-use crate::vector_storage::query::reco_query::RecoQuery;
+use crate::vector_storage::query::reco_query::{RecoQuery, RecoSumScoresQuery};
 use crate::vector_storage::simple_sparse_vector_storage::open_simple_sparse_vector_storage;
 use crate::vector_storage::{new_raw_scorer, VectorStorage, VectorStorageEnum};
 
@@ -87,6 +87,28 @@ fn do_test_delete_points(storage: Arc<AtomicRefCell<VectorStorageEnum>>) {
     assert_eq!(closest[1].idx, 1);
     assert_eq!(closest[2].idx, 4);
 
+    // Check sum of scores query against the remaining points
+    let positives: Vec<SparseVector> = vec![vec![(0, 1.0), (2, 1.0)], vec![(3, 1.0)]]
+        .into_iter()
+        .map(|v| v.try_into().unwrap())
+        .collect();
+    let negative: SparseVector = vec![(0, 1.0)].try_into().unwrap();
+    let query_vector = QueryVector::RecommendSumScores(RecoSumScoresQuery::new(
+        positives.into_iter().map(Into::into).collect(),
+        vec![negative.into()],
+    ));
+    let closest = new_raw_scorer(
+        query_vector,
+        &borrowed_storage,
+        borrowed_id_tracker.deleted_point_bitslice(),
+    )
+    .unwrap()
+    .peek_top_iter(&mut [0, 1, 2, 3, 4].iter().cloned(), 5);
+    assert_eq!(closest.len(), 3, "must have 3 vectors, 2 are deleted");
+    assert_eq!((closest[0].idx, closest[0].score), (0, 2.0));
+    assert_eq!((closest[1].idx, closest[1].score), (1, 1.0));
+    assert_eq!((closest[2].idx, closest[2].score), (4, 0.0));
+
     // Delete 1, re-delete 2
     borrowed_storage
         .delete_vector(1 as PointOffsetType)
@@ -44,6 +44,7 @@ fn test_batch_and_single_request_equivalency() {
                 quantization_config: None,
                 multivec_config: None,
                 datatype: None,
+                prefix_size: None,
             },
         )]),
         sparse_vector_data: Default::default(),
@@ -156,6 +157,7 @@ fn test_batch_and_single_request_equivalency() {
         quantized_vectors.clone(),
         payload_index_ptr,
         hnsw_config,
+        None,
     )
     .unwrap();
 
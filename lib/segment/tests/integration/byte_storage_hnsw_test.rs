@@ -125,6 +125,7 @@ fn test_byte_storage_hnsw(
                 quantization_config: None,
                 multivec_config: None,
                 datatype: None,
+                prefix_size: None,
             },
         )]),
         sparse_vector_data: Default::default(),
@@ -141,6 +142,7 @@ fn test_byte_storage_hnsw(
                 quantization_config: None,
                 multivec_config: None,
                 datatype: Some(VectorStorageDatatype::Uint8),
+                prefix_size: None,
             },
         )]),
         sparse_vector_data: Default::default(),
@@ -217,6 +219,7 @@ fn test_byte_storage_hnsw(
             .clone(),
         segment_byte.payload_index.clone(),
         hnsw_config,
+        None,
     )
     .unwrap();
 
@@ -19,15 +19,16 @@ use segment::index::{PayloadIndex, VectorIndex};
 use segment::json_path::path;
 use segment::segment_constructor::build_segment;
 use segment::types::{
-    BinaryQuantizationConfig, CompressionRatio, Condition, Distance, FieldCondition, Filter,
-    HnswConfig, Indexes, Payload, PayloadSchemaType, ProductQuantizationConfig,
-    QuantizationSearchParams, Range, ScalarQuantizationConfig, SearchParams, SegmentConfig,
-    SeqNumberType, VectorDataConfig, VectorStorageDatatype, VectorStorageType,
+    BinaryQuantizationConfig, BinaryQuantizationEncoding, BinaryQuantizationQueryEncoding,
+    CompressionRatio, Condition, Distance, FieldCondition, Filter, HnswConfig, Indexes, Payload,
+    PayloadSchemaType, ProductQuantizationConfig, QuantizationSearchParams, Range,
+    ScalarQuantizationConfig, SearchParams, SegmentConfig, SeqNumberType, VectorDataConfig,
+    VectorStorageDatatype, VectorStorageType,
 };
 use segment::vector_storage::quantized::quantized_vectors::QuantizedVectors;
 use segment::vector_storage::query::context_query::ContextPair;
 use segment::vector_storage::query::discovery_query::DiscoveryQuery;
-use segment::vector_storage::query::reco_query::RecoQuery;
+use segment::vector_storage::query::reco_query::{RecoQuery, RecoSumScoresQuery};
 use segment::vector_storage::VectorStorageEnum;
 use serde_json::json;
 use tempfile::Builder;
@@ -37,6 +38,7 @@ const MAX_EXAMPLE_PAIRS: usize = 4;
 enum QueryVariant {
     Nearest,
     RecommendBestScore,
+    RecommendSumScores,
     Discovery,
 }
 
@@ -44,6 +46,8 @@ enum QuantizationVariant {
     Scalar,
     PQ,
     Binary,
+    BinaryTwoBits,
+    BinaryOneAndHalfBitsScalarQuery,
 }
 
 fn random_discovery_query<R: Rng + ?Sized>(rnd: &mut R, dim: usize) -> QueryVector {
@@ -75,11 +79,25 @@ fn random_reco_query<R: Rng + ?Sized>(rnd: &mut R, dim: usize) -> QueryVector {
     RecoQuery::new(positive, negative).into()
 }
 
+fn random_reco_sum_scores_query<R: Rng + ?Sized>(rnd: &mut R, dim: usize) -> QueryVector {
+    let num_examples: usize = rnd.gen_range(1..MAX_EXAMPLE_PAIRS);
+
+    let positive = (0..num_examples)
+        .map(|_| random_dense_byte_vector(rnd, dim).into())
+        .collect_vec();
+    let negative = (0..num_examples)
+        .map(|_| random_dense_byte_vector(rnd, dim).into())
+        .collect_vec();
+
+    RecoSumScoresQuery::new(positive, negative).into()
+}
+
 fn random_query<R: Rng + ?Sized>(variant: &QueryVariant, rnd: &mut R, dim: usize) -> QueryVector {
     match variant {
         QueryVariant::Nearest => random_dense_byte_vector(rnd, dim).into(),
         QueryVariant::Discovery => random_discovery_query(rnd, dim),
         QueryVariant::RecommendBestScore => random_reco_query(rnd, dim),
+        QueryVariant::RecommendSumScores => random_reco_sum_scores_query(rnd, dim),
     }
 }
 
@@ -140,6 +158,30 @@ fn sames_count(a: &[Vec<ScoredPointOffset>], b: &[Vec<ScoredPointOffset>]) -> us
     64, // ef
     15., // min_acc out of 100
 )]
+#[case::recommend_sum_scores_binary_cosine(
+    QueryVariant::RecommendSumScores,
+    QuantizationVariant::Binary,
+    Distance::Cosine,
+    128, // dim
+    64, // ef
+    15., // min_acc out of 100
+)]
+#[case::nearest_binary_two_bits_cosine(
+    QueryVariant::Nearest,
+    QuantizationVariant::BinaryTwoBits,
+    Distance::Cosine,
+    128, // dim
+    32, // ef
+    25., // min_acc out of 100
+)]
+#[case::nearest_binary_one_and_half_bits_scalar_query_euclid(
+    QueryVariant::Nearest,
+    QuantizationVariant::BinaryOneAndHalfBitsScalarQuery,
+    Distance::Euclid,
+    128, // dim
+    32, // ef
+    25., // min_acc out of 100
+)]
 #[case::nearest_scalar_dot(
     QueryVariant::Nearest,
     QuantizationVariant::Scalar,
@@ -164,6 +206,14 @@ fn sames_count(a: &[Vec<ScoredPointOffset>], b: &[Vec<ScoredPointOffset>]) -> us
     32, // ef
     70., // min_acc out of 100
 )]
+#[case::nearest_binary_hamming(
+    QueryVariant::Nearest,
+    QuantizationVariant::Binary,
+    Distance::Hamming,
+    16, // dim
+    32, // ef
+    50., // min_acc out of 100
+)]
 fn test_byte_storage_binary_quantization_hnsw(
     #[case] query_variant: QueryVariant,
     #[case] quantization_variant: QuantizationVariant,
@@ -197,6 +247,7 @@ fn test_byte_storage_binary_quantization_hnsw(
                 quantization_config: None,
                 multivec_config: None,
                 datatype: Some(VectorStorageDatatype::Uint8),
+                prefix_size: None,
             },
         )]),
         sparse_vector_data: Default::default(),
@@ -251,7 +302,24 @@ fn test_byte_storage_binary_quantization_hnsw(
             always_ram: None,
         }
         .into(),
-        QuantizationVariant::Binary => BinaryQuantizationConfig { always_ram: None }.into(),
+        QuantizationVariant::Binary => BinaryQuantizationConfig {
+            always_ram: None,
+            encoding: None,
+            query_encoding: None,
+        }
+        .into(),
+        QuantizationVariant::BinaryTwoBits => BinaryQuantizationConfig {
+            always_ram: None,
+            encoding: Some(BinaryQuantizationEncoding::TwoBits),
+            query_encoding: None,
+        }
+        .into(),
+        QuantizationVariant::BinaryOneAndHalfBitsScalarQuery => BinaryQuantizationConfig {
+            always_ram: None,
+            encoding: Some(BinaryQuantizationEncoding::OneAndHalfBits),
+            query_encoding: Some(BinaryQuantizationQueryEncoding::Scalar8Bits),
+        }
+        .into(),
     };
 
     segment_byte
@@ -261,6 +329,7 @@ fn test_byte_storage_binary_quantization_hnsw(
             let quantized_vectors = QuantizedVectors::create(
                 &vector_storage.vector_storage.borrow(),
                 &quantization_config,
+                None,
                 quantized_data_path,
                 4,
                 &stopped,
@@ -292,6 +361,7 @@ fn test_byte_storage_binary_quantization_hnsw(
             .clone(),
         segment_byte.payload_index.clone(),
         hnsw_config,
+        None,
     )
     .unwrap();
     hnsw_index_byte.build_index(permit, &stopped).unwrap();
@@ -359,3 +429,58 @@ fn test_byte_storage_binary_quantization_hnsw(
     println!("sames = {sames}, attempts = {attempts}, top = {top}, acc = {acc}");
     assert!(acc > min_acc);
 }
+
+#[rstest]
+#[case::jaccard(Distance::Jaccard)]
+#[case::minkowski(Distance::Minkowski(3))]
+fn test_byte_storage_quantization_unsupported_distance(#[case] distance: Distance) {
+    let stopped = AtomicBool::new(false);
+    let dim = 16;
+    let mut rnd = StdRng::seed_from_u64(42);
+
+    let dir_byte = Builder::new().prefix("segment_dir_byte").tempdir().unwrap();
+    let config_byte = SegmentConfig {
+        vector_data: HashMap::from([(
+            DEFAULT_VECTOR_NAME.to_owned(),
+            VectorDataConfig {
+                size: dim,
+                distance,
+                storage_type: VectorStorageType::Memory,
+                index: Indexes::Plain {},
+                quantization_config: None,
+                multivec_config: None,
+                datatype: Some(VectorStorageDatatype::Uint8),
+                prefix_size: None,
+            },
+        )]),
+        sparse_vector_data: Default::default(),
+        payload_storage_type: Default::default(),
+    };
+
+    let mut segment_byte = build_segment(dir_byte.path(), &config_byte, true).unwrap();
+    for n in 0..10u64 {
+        let vector = random_dense_byte_vector(&mut rnd, dim);
+        segment_byte
+            .upsert_point(n as SeqNumberType, n.into(), only_default_vector(&vector))
+            .unwrap();
+    }
+
+    // Distance can't be approximated by quantization, so vectors are never quantized
+    let quantization_config = ScalarQuantizationConfig {
+        r#type: Default::default(),
+        quantile: None,
+        always_ram: None,
+    }
+    .into();
+    let result = QuantizedVectors::create(
+        &segment_byte.vector_data[DEFAULT_VECTOR_NAME]
+            .vector_storage
+            .borrow(),
+        &quantization_config,
+        None,
+        dir_byte.path(),
+        4,
+        &stopped,
+    );
+    assert!(result.is_err());
+}
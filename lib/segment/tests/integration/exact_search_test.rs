@@ -52,6 +52,7 @@ fn exact_search_test() {
                 quantization_config: None,
                 multivec_config: None,
                 datatype: None,
+                prefix_size: None,
             },
         )]),
         sparse_vector_data: Default::default(),
@@ -102,6 +103,7 @@ fn exact_search_test() {
             .clone(),
         payload_index_ptr.clone(),
         hnsw_config,
+        None,
     )
     .unwrap();
 
@@ -23,7 +23,7 @@ use segment::types::{
 };
 use segment::vector_storage::query::context_query::ContextPair;
 use segment::vector_storage::query::discovery_query::DiscoveryQuery;
-use segment::vector_storage::query::reco_query::RecoQuery;
+use segment::vector_storage::query::reco_query::{RecoQuery, RecoSumScoresQuery};
 use serde_json::json;
 use tempfile::Builder;
 
@@ -32,6 +32,7 @@ const MAX_EXAMPLE_PAIRS: usize = 4;
 enum QueryVariant {
     Nearest,
     RecommendBestScore,
+    RecommendSumScores,
     Discovery,
 }
 
@@ -64,11 +65,25 @@ fn random_reco_query<R: Rng + ?Sized>(rnd: &mut R, dim: usize) -> QueryVector {
     RecoQuery::new(positive, negative).into()
 }
 
+fn random_reco_sum_scores_query<R: Rng + ?Sized>(rnd: &mut R, dim: usize) -> QueryVector {
+    let num_examples: usize = rnd.gen_range(1..MAX_EXAMPLE_PAIRS);
+
+    let positive = (0..num_examples)
+        .map(|_| random_vector(rnd, dim).into())
+        .collect_vec();
+    let negative = (0..num_examples)
+        .map(|_| random_vector(rnd, dim).into())
+        .collect_vec();
+
+    RecoSumScoresQuery::new(positive, negative).into()
+}
+
 fn random_query<R: Rng + ?Sized>(variant: &QueryVariant, rnd: &mut R, dim: usize) -> QueryVector {
     match variant {
         QueryVariant::Nearest => random_vector(rnd, dim).into(),
         QueryVariant::Discovery => random_discovery_query(rnd, dim),
         QueryVariant::RecommendBestScore => random_reco_query(rnd, dim),
+        QueryVariant::RecommendSumScores => random_reco_sum_scores_query(rnd, dim),
     }
 }
 
@@ -76,6 +91,7 @@ fn random_query<R: Rng + ?Sized>(variant: &QueryVariant, rnd: &mut R, dim: usize
 #[case::nearest(QueryVariant::Nearest, 32, 5)]
 #[case::discovery(QueryVariant::Discovery, 128, 10)] // tests that check better precision are in `hnsw_discover_test.rs`
 #[case::recommend(QueryVariant::RecommendBestScore, 64, 10)]
+#[case::recommend_sum_scores(QueryVariant::RecommendSumScores, 64, 10)]
 fn test_filterable_hnsw(
     #[case] query_variant: QueryVariant,
     #[case] ef: usize,
@@ -116,6 +132,7 @@ fn _test_filterable_hnsw(
                 quantization_config: None,
                 multivec_config: None,
                 datatype: None,
+                prefix_size: None,
             },
         )]),
         sparse_vector_data: Default::default(),
@@ -163,6 +180,7 @@ fn _test_filterable_hnsw(
         quantized_vectors.clone(),
         payload_index_ptr.clone(),
         hnsw_config,
+        None,
     )
     .unwrap();
 
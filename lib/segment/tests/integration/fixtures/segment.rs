@@ -132,6 +132,7 @@ pub fn build_segment_3(path: &Path) -> Segment {
                         quantization_config: None,
                         multivec_config: None,
                         datatype: None,
+                        prefix_size: None,
                     },
                 ),
                 (
@@ -144,6 +145,7 @@ pub fn build_segment_3(path: &Path) -> Segment {
                         quantization_config: None,
                         multivec_config: None,
                         datatype: None,
+                        prefix_size: None,
                     },
                 ),
                 (
@@ -156,6 +158,7 @@ pub fn build_segment_3(path: &Path) -> Segment {
                         quantization_config: None,
                         multivec_config: None,
                         datatype: None,
+                        prefix_size: None,
                     },
                 ),
             ]),
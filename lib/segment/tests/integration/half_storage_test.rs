@@ -0,0 +1,161 @@
+use std::collections::HashMap;
+
+use rand::prelude::StdRng;
+use rand::SeedableRng;
+use rstest::rstest;
+use segment::common::operation_error::OperationError;
+use segment::data_types::vectors::{only_default_vector, QueryVector, DEFAULT_VECTOR_NAME};
+use segment::entry::entry_point::SegmentEntry;
+use segment::fixtures::payload_fixtures::random_vector;
+use segment::segment_constructor::build_segment;
+use segment::types::{
+    Distance, Indexes, SegmentConfig, SeqNumberType, VectorDataConfig, VectorStorageDatatype,
+    VectorStorageType,
+};
+use segment::vector_storage::VectorStorageEnum;
+use tempfile::Builder;
+
+fn segment_config(
+    dim: usize,
+    distance: Distance,
+    storage_type: VectorStorageType,
+    datatype: Option<VectorStorageDatatype>,
+) -> SegmentConfig {
+    SegmentConfig {
+        vector_data: HashMap::from([(
+            DEFAULT_VECTOR_NAME.to_owned(),
+            VectorDataConfig {
+                size: dim,
+                distance,
+                storage_type,
+                index: Indexes::Plain {},
+                quantization_config: None,
+                multivec_config: None,
+                datatype,
+                prefix_size: None,
+            },
+        )]),
+        sparse_vector_data: Default::default(),
+        payload_storage_type: Default::default(),
+    }
+}
+
+#[rstest]
+fn test_half_storage_search(
+    #[values(Distance::Cosine, Distance::Dot, Distance::Euclid, Distance::Manhattan)]
+    distance: Distance,
+    #[values(VectorStorageType::Memory, VectorStorageType::ChunkedMmap)]
+    storage_type: VectorStorageType,
+) {
+    let dim = 64;
+    let num_vectors: u64 = 500;
+    let top = 10;
+
+    let mut rnd = StdRng::seed_from_u64(42);
+
+    let dir_float = Builder::new()
+        .prefix("segment_dir_float")
+        .tempdir()
+        .unwrap();
+    let dir_half = Builder::new().prefix("segment_dir_half").tempdir().unwrap();
+
+    let config_float = segment_config(dim, distance, storage_type, None);
+    let config_half = segment_config(
+        dim,
+        distance,
+        storage_type,
+        Some(VectorStorageDatatype::Float16),
+    );
+
+    let mut segment_float = build_segment(dir_float.path(), &config_float, true).unwrap();
+    let mut segment_half = build_segment(dir_half.path(), &config_half, true).unwrap();
+    // check that `segment_half` uses half-precision storage
+    {
+        let borrowed_storage = segment_half.vector_data[DEFAULT_VECTOR_NAME]
+            .vector_storage
+            .borrow();
+        let raw_storage: &VectorStorageEnum = &borrowed_storage;
+        assert!(matches!(
+            raw_storage,
+            &VectorStorageEnum::DenseSimpleHalf(_)
+                | &VectorStorageEnum::DenseAppendableMemmapHalf(_)
+        ));
+    }
+
+    for n in 0..num_vectors {
+        let idx = n.into();
+        let vector = random_vector(&mut rnd, dim);
+
+        segment_float
+            .upsert_point(n as SeqNumberType, idx, only_default_vector(&vector))
+            .unwrap();
+        segment_half
+            .upsert_point(n as SeqNumberType, idx, only_default_vector(&vector))
+            .unwrap();
+    }
+
+    let attempts = 20;
+    let mut matched = 0;
+    for _ in 0..attempts {
+        let query: QueryVector = random_vector(&mut rnd, dim).into();
+
+        let result_float = segment_float.vector_data[DEFAULT_VECTOR_NAME]
+            .vector_index
+            .borrow()
+            .search(&[&query], None, top, None, &Default::default())
+            .unwrap();
+        let result_half = segment_half.vector_data[DEFAULT_VECTOR_NAME]
+            .vector_index
+            .borrow()
+            .search(&[&query], None, top, None, &Default::default())
+            .unwrap();
+
+        for (float, half) in result_float[0].iter().zip(&result_half[0]) {
+            // half-precision keeps ~3 significant digits
+            assert!(
+                (float.score - half.score).abs() <= float.score.abs() * 1e-2 + 1e-2,
+                "float: {float:?}, half: {half:?}"
+            );
+            if float.idx == half.idx {
+                matched += 1;
+            }
+        }
+    }
+
+    // Precision loss may only swap points with almost equal scores
+    assert!(
+        matched >= attempts * top * 9 / 10,
+        "matched: {matched} of {}",
+        attempts * top
+    );
+}
+
+#[rstest]
+fn test_half_storage_rejects_out_of_range(
+    #[values(Distance::Cosine, Distance::Dot, Distance::Euclid)] distance: Distance,
+) {
+    let dim = 4;
+    let dir = Builder::new().prefix("segment_dir_half").tempdir().unwrap();
+    let config = segment_config(
+        dim,
+        distance,
+        VectorStorageType::Memory,
+        Some(VectorStorageDatatype::Float16),
+    );
+    let mut segment = build_segment(dir.path(), &config, true).unwrap();
+
+    segment
+        .upsert_point(1, 1.into(), only_default_vector(&[1.0, -65504.0, 0.0, 1.0]))
+        .unwrap();
+
+    let result = segment.upsert_point(2, 2.into(), only_default_vector(&[1.0, 70000.0, 0.0, 1.0]));
+    if distance == Distance::Cosine {
+        // Cosine vectors are normalized before being converted
+        result.unwrap();
+    } else {
+        assert!(matches!(
+            result,
+            Err(OperationError::ValidationError { .. })
+        ));
+    }
+}
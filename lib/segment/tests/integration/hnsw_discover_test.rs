@@ -78,6 +78,7 @@ fn hnsw_discover_precision() {
                 quantization_config: None,
                 multivec_config: None,
                 datatype: None,
+                prefix_size: None,
             },
         )]),
         payload_storage_type: Default::default(),
@@ -118,6 +119,7 @@ fn hnsw_discover_precision() {
         quantized_vectors.clone(),
         payload_index_ptr.clone(),
         hnsw_config,
+        None,
     )
     .unwrap();
 
@@ -190,6 +192,7 @@ fn filtered_hnsw_discover_precision() {
                 quantization_config: None,
                 multivec_config: None,
                 datatype: None,
+                prefix_size: None,
             },
         )]),
         payload_storage_type: Default::default(),
@@ -237,6 +240,7 @@ fn filtered_hnsw_discover_precision() {
         quantized_vectors.clone(),
         payload_index_ptr.clone(),
         hnsw_config,
+        None,
     )
     .unwrap();
 
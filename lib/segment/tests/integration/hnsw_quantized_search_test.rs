@@ -23,7 +23,8 @@ use segment::types::PayloadSchemaType::Keyword;
 use segment::types::{
     CompressionRatio, Condition, Distance, FieldCondition, Filter, HnswConfig, Indexes, Payload,
     ProductQuantizationConfig, QuantizationConfig, QuantizationSearchParams,
-    ScalarQuantizationConfig, SearchParams, SegmentConfig, VectorDataConfig, VectorStorageType,
+    ScalarQuantizationConfig, ScalarType, SearchParams, SegmentConfig, VectorDataConfig,
+    VectorStorageType,
 };
 use segment::vector_storage::quantized::quantized_vectors::QuantizedVectors;
 use serde_json::json;
@@ -73,6 +74,7 @@ fn hnsw_quantized_search_test(
                 quantization_config: None,
                 multivec_config: None,
                 datatype: None,
+                prefix_size: None,
             },
         )]),
         sparse_vector_data: Default::default(),
@@ -109,6 +111,7 @@ fn hnsw_quantized_search_test(
         let quantized_vectors = QuantizedVectors::create(
             &vector_storage.vector_storage.borrow(),
             &quantization_config,
+            None,
             quantized_data_path,
             4,
             &stopped,
@@ -140,6 +143,7 @@ fn hnsw_quantized_search_test(
             .clone(),
         segment.payload_index.clone(),
         hnsw_config,
+        None,
     )
     .unwrap();
 
@@ -356,6 +360,48 @@ fn hnsw_quantized_search_manhattan_test() {
     );
 }
 
+#[test]
+fn hnsw_int4_quantized_search_cosine_test() {
+    hnsw_quantized_search_test(
+        Distance::Cosine,
+        5003,
+        ScalarQuantizationConfig {
+            r#type: ScalarType::Int4,
+            quantile: Some(0.99),
+            always_ram: None,
+        }
+        .into(),
+    );
+}
+
+#[test]
+fn hnsw_int4_quantized_search_euclid_test() {
+    hnsw_quantized_search_test(
+        Distance::Euclid,
+        5003,
+        ScalarQuantizationConfig {
+            r#type: ScalarType::Int4,
+            quantile: Some(0.99),
+            always_ram: None,
+        }
+        .into(),
+    );
+}
+
+#[test]
+fn hnsw_int4_quantized_search_manhattan_test() {
+    hnsw_quantized_search_test(
+        Distance::Manhattan,
+        5003,
+        ScalarQuantizationConfig {
+            r#type: ScalarType::Int4,
+            quantile: Some(0.99),
+            always_ram: None,
+        }
+        .into(),
+    );
+}
+
 #[test]
 fn hnsw_product_quantization_cosine_test() {
     hnsw_quantized_search_test(
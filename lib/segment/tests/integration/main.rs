@@ -9,10 +9,13 @@ pub mod fail_recovery_test;
 pub mod filtering_context_check;
 pub mod filtrable_hnsw_test;
 pub mod fixtures;
+mod half_storage_test;
 pub mod hnsw_discover_test;
 pub mod hnsw_quantized_search_test;
+mod multivector_fde_test;
 mod multivector_filtrable_hnsw_test;
 mod multivector_hnsw_test;
+mod multivector_quantization_test;
 pub mod nested_filtering_test;
 pub mod payload_index_test;
 pub mod scroll_filtering_test;
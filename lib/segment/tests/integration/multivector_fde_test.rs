@@ -0,0 +1,270 @@
+use std::collections::{BTreeSet, HashMap};
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+
+use common::cpu::CpuPermit;
+use common::types::PointOffsetType;
+use rand::prelude::StdRng;
+use rand::{Rng, SeedableRng};
+use rstest::rstest;
+use segment::data_types::vectors::{
+    only_default_multi_vector, QueryVector, VectorRef, DEFAULT_VECTOR_NAME,
+};
+use segment::entry::entry_point::SegmentEntry;
+use segment::fixtures::payload_fixtures::random_multi_vector;
+use segment::index::hnsw_index::graph_links::GraphLinksRam;
+use segment::index::hnsw_index::hnsw::HNSWIndex;
+use segment::index::VectorIndex;
+use segment::segment_constructor::build_segment;
+use segment::types::{
+    Distance, FdeConfig, HnswConfig, Indexes, MultiVectorComparator, MultiVectorConfig,
+    SearchParams, SegmentConfig, SeqNumberType, VectorDataConfig, VectorStorageType,
+};
+use segment::vector_storage::multi_dense::appendable_mmap_multi_dense_vector_storage::open_appendable_memmap_multi_vector_storage;
+use segment::vector_storage::VectorStorage;
+use tempfile::Builder;
+
+#[rstest]
+#[case::cosine_memory(Distance::Cosine, VectorStorageType::Memory, 32)]
+#[case::dot_mmap(Distance::Dot, VectorStorageType::ChunkedMmap, 32)]
+#[case::cosine_default_projection(Distance::Cosine, VectorStorageType::Memory, 16)]
+fn test_multivector_fde_prefetch(
+    #[case] distance: Distance,
+    #[case] storage_type: VectorStorageType,
+    #[case] projection_dim: u32,
+) {
+    let vector_dim = 32;
+    let max_num_vector_per_points = 4;
+    let num_points: u64 = 1_000;
+    let top = 10;
+    let attempts = 10;
+    let min_acc = 6; // out of 10
+
+    let mut rnd = StdRng::seed_from_u64(42);
+
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+
+    let config = SegmentConfig {
+        vector_data: HashMap::from([(
+            DEFAULT_VECTOR_NAME.to_owned(),
+            VectorDataConfig {
+                size: vector_dim,
+                distance,
+                storage_type,
+                index: Indexes::Plain {},
+                quantization_config: None,
+                multivec_config: Some(MultiVectorConfig {
+                    comparator: MultiVectorComparator::MaxSim,
+                    fde: Some(FdeConfig {
+                        projection_dim,
+                        prefetch_factor: 20,
+                        ..Default::default()
+                    }),
+                }),
+                datatype: None,
+                prefix_size: None,
+            },
+        )]),
+        sparse_vector_data: Default::default(),
+        payload_storage_type: Default::default(),
+    };
+
+    let mut segment = build_segment(dir.path(), &config, true).unwrap();
+    for n in 0..num_points {
+        let num_vector_for_point = rnd.gen_range(1..=max_num_vector_per_points);
+        let multi_vec = random_multi_vector(&mut rnd, vector_dim, num_vector_for_point);
+        segment
+            .upsert_point(
+                n as SeqNumberType,
+                n.into(),
+                only_default_multi_vector(&multi_vec),
+            )
+            .unwrap();
+    }
+
+    let hnsw_dir = Builder::new().prefix("hnsw_dir").tempdir().unwrap();
+    let stopped = AtomicBool::new(false);
+    let hnsw_config = HnswConfig {
+        m: 16,
+        ef_construct: 100,
+        full_scan_threshold: 1, // KB, so that unfiltered searches use the graph
+        max_indexing_threads: 2,
+        on_disk: Some(false),
+        payload_m: None,
+    };
+    let vector_data = &segment.vector_data[DEFAULT_VECTOR_NAME];
+    let mut vector_index = HNSWIndex::<GraphLinksRam>::open(
+        hnsw_dir.path(),
+        segment.id_tracker.clone(),
+        vector_data.vector_storage.clone(),
+        vector_data.quantized_vectors.clone(),
+        segment.payload_index.clone(),
+        hnsw_config,
+        None,
+    )
+    .unwrap();
+    vector_index
+        .build_index(Arc::new(CpuPermit::dummy(2)), &stopped)
+        .unwrap();
+
+    let mut sames = 0;
+    for _ in 0..attempts {
+        let num_vector_for_query = rnd.gen_range(1..=max_num_vector_per_points);
+        let query: QueryVector =
+            random_multi_vector(&mut rnd, vector_dim, num_vector_for_query).into();
+
+        let prefetched_result = vector_index
+            .search(&[&query], None, top, None, &Default::default())
+            .unwrap();
+
+        let exact_result = vector_index
+            .search(
+                &[&query],
+                None,
+                top,
+                Some(&SearchParams {
+                    exact: true,
+                    ..Default::default()
+                }),
+                &Default::default(),
+            )
+            .unwrap();
+
+        assert_eq!(prefetched_result[0].len(), top);
+
+        // candidates found by the encodings are rescored with the exact comparator
+        for scored_point in &prefetched_result[0] {
+            let exact_point = exact_result[0]
+                .iter()
+                .find(|exact_point| exact_point.idx == scored_point.idx);
+            if let Some(exact_point) = exact_point {
+                assert!((exact_point.score - scored_point.score).abs() < 1e-4);
+            }
+        }
+
+        sames += prefetched_result[0]
+            .iter()
+            .map(|x| x.idx)
+            .collect::<BTreeSet<_>>()
+            .intersection(&exact_result[0].iter().map(|x| x.idx).collect())
+            .count();
+    }
+
+    let min_sames = min_acc * top * attempts / 10;
+    assert!(
+        sames >= min_sames,
+        "sames: {sames} out of {} (expected at least {min_sames})",
+        top * attempts,
+    );
+}
+
+#[test]
+fn test_multivector_fde_persisted() {
+    let vector_dim = 8;
+    let num_points = 50;
+    let mut rnd = StdRng::seed_from_u64(42);
+    let dir = Builder::new().prefix("storage_dir").tempdir().unwrap();
+    let multi_vector_config = MultiVectorConfig {
+        comparator: MultiVectorComparator::MaxSim,
+        fde: Some(FdeConfig::default()),
+    };
+    let query = random_multi_vector(&mut rnd, vector_dim, 2);
+
+    let scores: Vec<_> = {
+        let storage = open_appendable_memmap_multi_vector_storage(
+            dir.path(),
+            vector_dim,
+            Distance::Dot,
+            multi_vector_config,
+        )
+        .unwrap();
+        let mut storage = storage.borrow_mut();
+        for idx in 0..num_points {
+            let multi_vector = random_multi_vector(&mut rnd, vector_dim, 3);
+            storage
+                .insert_vector(idx, VectorRef::MultiDense(&multi_vector))
+                .unwrap();
+        }
+        storage.flusher()().unwrap();
+        assert!(storage
+            .files()
+            .iter()
+            .any(|file| file.starts_with(dir.path().join("fde"))));
+
+        let encodings = storage.fixed_dimensional_encodings().unwrap();
+        let query_encoding = encodings.encode_query(&query);
+        (0..num_points)
+            .map(|idx| encodings.score(&query_encoding, idx))
+            .collect()
+    };
+
+    // Encodings are loaded from disk as they were persisted
+    let storage = open_appendable_memmap_multi_vector_storage(
+        dir.path(),
+        vector_dim,
+        Distance::Dot,
+        multi_vector_config,
+    )
+    .unwrap();
+    let storage = storage.borrow();
+    let encodings = storage.fixed_dimensional_encodings().unwrap();
+    assert_eq!(encodings.len(), num_points as usize);
+    let query_encoding = encodings.encode_query(&query);
+    for (idx, score) in scores.into_iter().enumerate() {
+        assert_eq!(
+            encodings.score(&query_encoding, idx as PointOffsetType),
+            score
+        );
+    }
+}
+
+#[test]
+fn test_multivector_fde_validation() {
+    let fde_config = MultiVectorConfig {
+        comparator: MultiVectorComparator::MaxSim,
+        fde: Some(FdeConfig::default()),
+    };
+    assert!(fde_config.validate(Distance::Cosine).is_ok());
+    assert!(fde_config.validate(Distance::Dot).is_ok());
+    assert!(fde_config.validate(Distance::Euclid).is_err());
+
+    let symmetric = MultiVectorConfig {
+        comparator: MultiVectorComparator::SymmetricMaxSim,
+        ..fde_config
+    };
+    assert!(symmetric.validate(Distance::Cosine).is_err());
+
+    let too_many_buckets = MultiVectorConfig {
+        fde: Some(FdeConfig {
+            simhash_bits: 16,
+            ..Default::default()
+        }),
+        ..fde_config
+    };
+    assert!(too_many_buckets.validate(Distance::Dot).is_err());
+
+    // Comparators without encodings are allowed for any distance
+    let max_sim = MultiVectorConfig {
+        comparator: MultiVectorComparator::MaxSim,
+        fde: None,
+    };
+    assert!(max_sim.validate(Distance::Euclid).is_ok());
+    assert!(max_sim.validate(Distance::Minkowski(3)).is_ok());
+
+    // Except for mean pooling, which always scores by cosine similarity
+    let mean_pooled = MultiVectorConfig {
+        comparator: MultiVectorComparator::MeanPooledCosine,
+        fde: None,
+    };
+    assert!(mean_pooled.validate(Distance::Cosine).is_ok());
+    assert!(mean_pooled.validate(Distance::Dot).is_ok());
+    for distance in [
+        Distance::Euclid,
+        Distance::Manhattan,
+        Distance::Hamming,
+        Distance::Jaccard,
+        Distance::Minkowski(3),
+    ] {
+        assert!(mean_pooled.validate(distance).is_err(), "{distance:?}");
+    }
+}
@@ -130,6 +130,7 @@ fn test_multi_filterable_hnsw(
                 quantization_config: None,
                 multivec_config: Some(MultiVectorConfig::default()), // uses multivec config
                 datatype: None,
+                prefix_size: None,
             },
         )]),
         sparse_vector_data: Default::default(),
@@ -180,6 +181,7 @@ fn test_multi_filterable_hnsw(
         quantized_vectors.clone(),
         payload_index_ptr.clone(),
         hnsw_config,
+        None,
     )
     .unwrap();
 
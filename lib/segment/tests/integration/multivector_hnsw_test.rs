@@ -51,6 +51,7 @@ fn test_single_multi_and_dense_hnsw_equivalency() {
                 quantization_config: None,
                 multivec_config: None,
                 datatype: None,
+                prefix_size: None,
             },
         )]),
         sparse_vector_data: Default::default(),
@@ -93,6 +94,7 @@ fn test_single_multi_and_dense_hnsw_equivalency() {
             Distance::Manhattan => {
                 <ManhattanMetric as Metric<VectorElementType>>::preprocess(vector.clone())
             }
+            Distance::Hamming | Distance::Jaccard | Distance::Minkowski(_) => vector.clone(),
         };
         let vector_multi = MultiDenseVector::new(preprocessed_vector, vector.len());
 
@@ -142,6 +144,7 @@ fn test_single_multi_and_dense_hnsw_equivalency() {
         quantized_vectors.clone(),
         segment.payload_index.clone(),
         hnsw_config.clone(),
+        None,
     )
     .unwrap();
     hnsw_index_dense
@@ -155,6 +158,7 @@ fn test_single_multi_and_dense_hnsw_equivalency() {
         quantized_vectors.clone(),
         segment.payload_index.clone(),
         hnsw_config,
+        None,
     )
     .unwrap();
     hnsw_index_multi.build_index(permit, &stopped).unwrap();
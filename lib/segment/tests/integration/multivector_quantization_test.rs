@@ -0,0 +1,255 @@
+use std::collections::{BTreeSet, HashMap};
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+
+use atomic_refcell::AtomicRefCell;
+use common::cpu::CpuPermit;
+use rand::prelude::StdRng;
+use rand::{Rng, SeedableRng};
+use rstest::rstest;
+use segment::data_types::vectors::{only_default_multi_vector, QueryVector, DEFAULT_VECTOR_NAME};
+use segment::entry::entry_point::SegmentEntry;
+use segment::fixtures::payload_fixtures::random_multi_vector;
+use segment::id_tracker::IdTracker;
+use segment::index::hnsw_index::graph_links::GraphLinksRam;
+use segment::index::hnsw_index::hnsw::HNSWIndex;
+use segment::index::hnsw_index::num_rayon_threads;
+use segment::index::VectorIndex;
+use segment::segment_constructor::build_segment;
+use segment::types::{
+    BinaryQuantizationConfig, CompressionRatio, Distance, HnswConfig, Indexes, MultiVectorConfig,
+    ProductQuantizationConfig, QuantizationConfig, QuantizationSearchParams,
+    ScalarQuantizationConfig, ScalarType, SearchParams, SegmentConfig, SeqNumberType,
+    VectorDataConfig, VectorStorageType,
+};
+use segment::vector_storage::quantized::quantized_vectors::QuantizedVectors;
+use segment::vector_storage::VectorStorage;
+use tempfile::Builder;
+
+fn scalar_u8() -> QuantizationConfig {
+    ScalarQuantizationConfig {
+        r#type: ScalarType::Int8,
+        quantile: Some(0.99),
+        always_ram: None,
+    }
+    .into()
+}
+
+fn product_x4() -> QuantizationConfig {
+    ProductQuantizationConfig {
+        compression: CompressionRatio::X4,
+        always_ram: None,
+    }
+    .into()
+}
+
+fn binary() -> QuantizationConfig {
+    BinaryQuantizationConfig {
+        always_ram: None,
+        encoding: None,
+        query_encoding: None,
+    }
+    .into()
+}
+
+#[rstest]
+#[case::cosine_scalar(Distance::Cosine, scalar_u8(), 8)]
+#[case::dot_scalar(Distance::Dot, scalar_u8(), 8)]
+#[case::euclid_scalar(Distance::Euclid, scalar_u8(), 8)]
+#[case::cosine_product(Distance::Cosine, product_x4(), 6)]
+#[case::cosine_binary(Distance::Cosine, binary(), 5)]
+fn test_multivector_quantized_hnsw(
+    #[case] distance: Distance,
+    #[case] quantization_config: QuantizationConfig,
+    #[case] min_acc: usize, // out of 10
+) {
+    let stopped = AtomicBool::new(false);
+
+    let vector_dim = 64;
+    let max_num_vector_per_points = 3;
+    let num_points: u64 = 1_000;
+    let m = 16;
+    let ef = 64;
+    let ef_construct = 64;
+    let top = 10;
+    let attempts = 10;
+
+    let mut rnd = StdRng::seed_from_u64(42);
+
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let quantized_dir = Builder::new().prefix("quantized_dir").tempdir().unwrap();
+    let hnsw_dir = Builder::new().prefix("hnsw_dir").tempdir().unwrap();
+
+    let config = SegmentConfig {
+        vector_data: HashMap::from([(
+            DEFAULT_VECTOR_NAME.to_owned(),
+            VectorDataConfig {
+                size: vector_dim,
+                distance,
+                storage_type: VectorStorageType::Memory,
+                index: Indexes::Plain {}, // uses plain index for comparison
+                quantization_config: None,
+                multivec_config: Some(MultiVectorConfig::default()),
+                datatype: None,
+                prefix_size: None,
+            },
+        )]),
+        sparse_vector_data: Default::default(),
+        payload_storage_type: Default::default(),
+    };
+
+    let mut segment = build_segment(dir.path(), &config, true).unwrap();
+    for n in 0..num_points {
+        let idx = n.into();
+        // Random number of vectors per multivec point
+        let num_vector_for_point = rnd.gen_range(1..=max_num_vector_per_points);
+        let multi_vec = random_multi_vector(&mut rnd, vector_dim, num_vector_for_point);
+        segment
+            .upsert_point(
+                n as SeqNumberType,
+                idx,
+                only_default_multi_vector(&multi_vec),
+            )
+            .unwrap();
+    }
+
+    segment.vector_data.values_mut().for_each(|vector_data| {
+        let quantized_vectors = QuantizedVectors::create(
+            &vector_data.vector_storage.borrow(),
+            &quantization_config,
+            None,
+            quantized_dir.path(),
+            4,
+            &stopped,
+        )
+        .unwrap();
+        vector_data.quantized_vectors = Arc::new(AtomicRefCell::new(Some(quantized_vectors)));
+    });
+
+    let hnsw_config = HnswConfig {
+        m,
+        ef_construct,
+        full_scan_threshold: 1,
+        max_indexing_threads: 2,
+        on_disk: Some(false),
+        payload_m: None,
+    };
+
+    let permit_cpu_count = num_rayon_threads(hnsw_config.max_indexing_threads);
+    let permit = Arc::new(CpuPermit::dummy(permit_cpu_count as u32));
+
+    let vector_data = &segment.vector_data[DEFAULT_VECTOR_NAME];
+    let mut hnsw_index = HNSWIndex::<GraphLinksRam>::open(
+        hnsw_dir.path(),
+        segment.id_tracker.clone(),
+        vector_data.vector_storage.clone(),
+        vector_data.quantized_vectors.clone(),
+        segment.payload_index.clone(),
+        hnsw_config,
+        None,
+    )
+    .unwrap();
+    hnsw_index.build_index(permit, &stopped).unwrap();
+
+    // Quantized multivectors are persisted together with their offsets
+    let loaded_quantized_vectors =
+        QuantizedVectors::load(&vector_data.vector_storage.borrow(), quantized_dir.path()).unwrap();
+
+    let search_params = SearchParams {
+        hnsw_ef: Some(ef),
+        quantization: Some(QuantizationSearchParams {
+            rescore: Some(true),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let mut sames = 0;
+    for _ in 0..attempts {
+        let num_vector_for_query = rnd.gen_range(1..=max_num_vector_per_points);
+        let query: QueryVector =
+            random_multi_vector(&mut rnd, vector_dim, num_vector_for_query).into();
+
+        let index_result = hnsw_index
+            .search(
+                &[&query],
+                None,
+                top,
+                Some(&search_params),
+                &Default::default(),
+            )
+            .unwrap();
+
+        // segment uses a plain index by configuration
+        let plain_result = vector_data
+            .vector_index
+            .borrow()
+            .search(
+                &[&query],
+                None,
+                top,
+                Some(&SearchParams {
+                    exact: true,
+                    ..Default::default()
+                }),
+                &Default::default(),
+            )
+            .unwrap();
+
+        // rescored results must have exact scores
+        for scored_point in &index_result[0] {
+            let exact_score = plain_result[0]
+                .iter()
+                .find(|plain_point| plain_point.idx == scored_point.idx);
+            if let Some(exact_score) = exact_score {
+                assert!((exact_score.score - scored_point.score).abs() < 1e-4);
+            }
+        }
+
+        sames += index_result[0]
+            .iter()
+            .map(|x| x.idx)
+            .collect::<BTreeSet<_>>()
+            .intersection(&plain_result[0].iter().map(|x| x.idx).collect())
+            .count();
+
+        // loaded quantized vectors score the same as the created ones
+        let quantized_vectors = vector_data.quantized_vectors.borrow();
+        let quantized_vectors = quantized_vectors.as_ref().unwrap();
+        let id_tracker = segment.id_tracker.borrow();
+        let vector_storage = vector_data.vector_storage.borrow();
+        let created_scorer = quantized_vectors
+            .raw_scorer(
+                query.clone(),
+                id_tracker.deleted_point_bitslice(),
+                vector_storage.deleted_vector_bitslice(),
+                &stopped,
+            )
+            .unwrap();
+        let loaded_scorer = loaded_quantized_vectors
+            .raw_scorer(
+                query,
+                id_tracker.deleted_point_bitslice(),
+                vector_storage.deleted_vector_bitslice(),
+                &stopped,
+            )
+            .unwrap();
+        for idx in 0..num_points as u32 {
+            assert_eq!(
+                created_scorer.score_point(idx),
+                loaded_scorer.score_point(idx)
+            );
+        }
+        assert_eq!(
+            created_scorer.score_internal(0, 1),
+            loaded_scorer.score_internal(0, 1)
+        );
+    }
+
+    let min_sames = min_acc * top * attempts / 10;
+    assert!(
+        sames >= min_sames,
+        "sames: {sames} out of {} (expected at least {min_sames})",
+        top * attempts,
+    );
+}
@@ -6,12 +6,19 @@ use std::time::{Duration, Instant};
 use common::cpu::CpuPermit;
 use itertools::Itertools;
 use segment::common::operation_error::OperationError;
+use segment::data_types::keyword_index::KeywordIndexParams;
 use segment::data_types::vectors::{only_default_vector, DEFAULT_VECTOR_NAME};
 use segment::entry::entry_point::SegmentEntry;
 use segment::index::hnsw_index::num_rayon_threads;
+use segment::index::PayloadIndex;
+use segment::json_path::path;
 use segment::segment::Segment;
 use segment::segment_constructor::segment_builder::SegmentBuilder;
-use segment::types::{Indexes, SegmentConfig, VectorDataConfig, VectorStorageType};
+use segment::types::{
+    Indexes, Payload, PayloadContainer, PayloadFieldSchema, PayloadSchemaParams, SegmentConfig,
+    VectorDataConfig, VectorStorageType,
+};
+use serde_json::json;
 use tempfile::Builder;
 
 use crate::fixtures::segment::{build_segment_1, build_segment_2, empty_segment};
@@ -75,6 +82,73 @@ fn test_building_new_segment() {
     assert_eq!(merged_segment.point_version(3.into()), Some(100));
 }
 
+#[test]
+fn test_building_new_segment_groups_tenants() {
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let temp_dir = Builder::new().prefix("segment_temp_dir").tempdir().unwrap();
+
+    let stopped = AtomicBool::new(false);
+
+    let tenant_key = path("group_id");
+    let tenant_schema =
+        PayloadFieldSchema::FieldParams(PayloadSchemaParams::Keyword(KeywordIndexParams {
+            is_tenant: Some(true),
+            ..Default::default()
+        }));
+
+    let mut segment1 = empty_segment(dir.path());
+    let mut segment2 = empty_segment(dir.path());
+
+    for segment in [&mut segment1, &mut segment2] {
+        segment
+            .create_field_index(0, &tenant_key, Some(&tenant_schema))
+            .unwrap();
+    }
+
+    for idx in 0..40u64 {
+        let (segment, group) = if idx < 20 {
+            (&mut segment1, ["a", "b"][idx as usize % 2])
+        } else {
+            (&mut segment2, ["c", "a", "b"][idx as usize % 3])
+        };
+        let payload: Payload = json!({ "group_id": group }).into();
+        segment
+            .upsert_point(1, idx.into(), only_default_vector(&[0., 0., 0., 0.]))
+            .unwrap();
+        segment.set_full_payload(1, idx.into(), &payload).unwrap();
+    }
+
+    let mut builder =
+        SegmentBuilder::new(dir.path(), temp_dir.path(), &segment1.segment_config).unwrap();
+    builder.update(&[&segment1, &segment2], &stopped).unwrap();
+
+    let permit_cpu_count = num_rayon_threads(0);
+    let permit = CpuPermit::dummy(permit_cpu_count as u32);
+
+    let merged_segment: Segment = builder.build(permit, &stopped).unwrap();
+
+    assert_eq!(merged_segment.available_point_count(), 40);
+    assert!(merged_segment.get_indexed_fields()[&tenant_key].is_tenant());
+
+    let mut internal_ids: Vec<_> = merged_segment.id_tracker.borrow().iter_ids().collect();
+    internal_ids.sort_unstable();
+
+    let payload_index = merged_segment.payload_index.borrow();
+    let groups: Vec<String> = internal_ids
+        .into_iter()
+        .map(|internal_id| {
+            let payload = payload_index.payload(internal_id).unwrap();
+            payload.get_value(&tenant_key)[0]
+                .as_str()
+                .unwrap()
+                .to_owned()
+        })
+        .collect();
+
+    // Points of the same tenant must occupy consecutive internal ids
+    assert!(groups.windows(2).all(|w| w[0] <= w[1]), "{groups:?}");
+}
+
 fn estimate_build_time(segment: &Segment, stop_delay_millis: u64) -> (u64, bool) {
     let stopped = Arc::new(AtomicBool::new(false));
 
@@ -92,6 +166,7 @@ fn estimate_build_time(segment: &Segment, stop_delay_millis: u64) -> (u64, bool)
                 quantization_config: None,
                 multivec_config: None,
                 datatype: None,
+                prefix_size: None,
             },
         )]),
         sparse_vector_data: Default::default(),
@@ -124,6 +124,10 @@ fn sparse_index_discover_test() {
                 index: SparseIndexConfig {
                     full_scan_threshold: Some(DEFAULT_SPARSE_FULL_SCAN_THRESHOLD),
                     index_type: SparseIndexType::MutableRam,
+                    datatype: None,
+                    prune_top_k: None,
+                    prune_threshold: None,
+                    prune_oversampling: None,
                 },
             },
         )]),
@@ -140,6 +144,7 @@ fn sparse_index_discover_test() {
                 quantization_config: None,
                 multivec_config: None,
                 datatype: None,
+                prefix_size: None,
             },
         )]),
         payload_storage_type: Default::default(),
@@ -171,6 +176,10 @@ fn sparse_index_discover_test() {
         SparseIndexConfig {
             full_scan_threshold: Some(DEFAULT_SPARSE_FULL_SCAN_THRESHOLD),
             index_type: SparseIndexType::ImmutableRam,
+            datatype: None,
+            prune_top_k: None,
+            prune_threshold: None,
+            prune_oversampling: None,
         },
         sparse_segment.id_tracker.clone(),
         vector_storage.clone(),
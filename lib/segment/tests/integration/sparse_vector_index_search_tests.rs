@@ -30,9 +30,11 @@ use serde_json::json;
 use sparse::common::sparse_vector::SparseVector;
 use sparse::common::sparse_vector_fixture::{random_full_sparse_vector, random_sparse_vector};
 use sparse::common::types::DimId;
+use sparse::index::inverted_index::inverted_index_immutable_ram::InvertedIndexImmutableRam;
 use sparse::index::inverted_index::inverted_index_mmap::InvertedIndexMmap;
 use sparse::index::inverted_index::inverted_index_ram::InvertedIndexRam;
 use sparse::index::inverted_index::InvertedIndex;
+use sparse::index::posting_list::PostingListIter;
 use tempfile::Builder;
 
 /// Max dimension of sparse vectors used in tests
@@ -161,17 +163,17 @@ fn check_index_storage_consistency<T: InvertedIndex>(sparse_vector_index: &Spars
             .iter()
             .zip(remapped_vector.values.iter())
         {
-            let posting_list = sparse_vector_index.inverted_index.get(dim_id).unwrap();
+            let mut posting_list = sparse_vector_index.inverted_index.get(dim_id).unwrap();
+            let mut elements = vec![];
+            posting_list.for_each_till_id(PointOffsetType::MAX, |record_id, weight| {
+                elements.push((record_id, weight));
+            });
             // assert posting list sorted by record id
-            assert!(posting_list
-                .elements
-                .windows(2)
-                .all(|w| w[0].record_id < w[1].record_id));
+            assert!(elements.windows(2).all(|w| w[0].0 < w[1].0));
             // assert posted list contains record id
-            assert!(posting_list
-                .elements
+            assert!(elements
                 .iter()
-                .any(|e| e.record_id == id && e.weight == *dim_value));
+                .any(|&(record_id, weight)| record_id == id && weight == *dim_value));
         }
         // check the vector can be found via search using large top
         let top = sparse_vector_index.max_result_count(vector);
@@ -258,6 +260,96 @@ fn sparse_vector_index_consistent_with_storage() {
     check_index_storage_consistency(&sparse_vector_mmap_index);
 }
 
+fn total_postings<T: InvertedIndex>(sparse_vector_index: &SparseVectorIndex<T>) -> usize {
+    let inverted_index = &sparse_vector_index.inverted_index;
+    (0..=inverted_index.max_index().unwrap_or_default())
+        .filter_map(|dim_id| inverted_index.posting_list_len(&dim_id))
+        .sum()
+}
+
+#[test]
+fn sparse_vector_index_pruned_exact_scores() {
+    let stopped = AtomicBool::new(false);
+    let mut rnd = StdRng::seed_from_u64(42);
+
+    let data_dir = Builder::new().prefix("data_dir").tempdir().unwrap();
+    let sparse_vector_ram_index = fixture_sparse_index_ram(
+        &mut rnd,
+        NUM_VECTORS,
+        MAX_SPARSE_DIM,
+        LOW_FULL_SCAN_THRESHOLD,
+        data_dir.path(),
+        &stopped,
+    );
+
+    let permit_cpu_count = num_rayon_threads(0);
+    let permit = Arc::new(CpuPermit::dummy(permit_cpu_count as u32));
+
+    // create immutable index keeping only a few dimensions of each vector
+    let pruned_index_dir = Builder::new().prefix("pruned_index_dir").tempdir().unwrap();
+    let mut sparse_index_config = sparse_vector_ram_index.config;
+    sparse_index_config.index_type = SparseIndexType::ImmutableRam;
+    sparse_index_config.prune_top_k = Some(3);
+    let mut sparse_vector_pruned_index: SparseVectorIndex<InvertedIndexImmutableRam> =
+        SparseVectorIndex::open(
+            sparse_index_config,
+            sparse_vector_ram_index.id_tracker.clone(),
+            sparse_vector_ram_index.vector_storage.clone(),
+            sparse_vector_ram_index.payload_index.clone(),
+            pruned_index_dir.path(),
+            &stopped,
+        )
+        .unwrap();
+    sparse_vector_pruned_index
+        .build_index(permit, &stopped)
+        .unwrap();
+
+    assert!(total_postings(&sparse_vector_pruned_index) < total_postings(&sparse_vector_ram_index));
+    assert!(total_postings(&sparse_vector_pruned_index) <= 3 * NUM_VECTORS);
+
+    // document frequencies are counted before pruning
+    let idf_statistics = |sparse_vector_index: &SparseVectorIndex<_>| {
+        let mut idf: HashMap<DimId, usize> = (0..MAX_SPARSE_DIM as DimId).map(|i| (i, 0)).collect();
+        sparse_vector_index.fill_idf_statistics(&mut idf);
+        idf
+    };
+    let mut expected_idf: HashMap<DimId, usize> =
+        (0..MAX_SPARSE_DIM as DimId).map(|i| (i, 0)).collect();
+    sparse_vector_ram_index.fill_idf_statistics(&mut expected_idf);
+    assert_eq!(idf_statistics(&sparse_vector_pruned_index), expected_idf);
+
+    // document frequencies are persisted with the index
+    drop(sparse_vector_pruned_index);
+    let sparse_vector_pruned_index: SparseVectorIndex<InvertedIndexImmutableRam> =
+        SparseVectorIndex::open(
+            sparse_index_config,
+            sparse_vector_ram_index.id_tracker.clone(),
+            sparse_vector_ram_index.vector_storage.clone(),
+            sparse_vector_ram_index.payload_index.clone(),
+            pruned_index_dir.path(),
+            &stopped,
+        )
+        .unwrap();
+    assert_eq!(idf_statistics(&sparse_vector_pruned_index), expected_idf);
+
+    // scores of the retrieved points are computed with the original vectors
+    let borrowed_vector_storage = sparse_vector_ram_index.vector_storage.borrow();
+    for _ in 0..100 {
+        let mut query = random_sparse_vector(&mut rnd, MAX_SPARSE_DIM);
+        query.sort_by_indices();
+        let query_vector: QueryVector = query.clone().into();
+        let results = sparse_vector_pruned_index
+            .search(&[&query_vector], None, 10, None, &Default::default())
+            .unwrap();
+        for scored in &results[0] {
+            let vector = borrowed_vector_storage.get_vector(scored.idx);
+            let vector: &SparseVector = vector.as_vec_ref().try_into().unwrap();
+            assert_eq!(scored.score, query.score(vector).unwrap_or(0.0));
+        }
+        assert!(results[0].windows(2).all(|w| w[0].score >= w[1].score));
+    }
+}
+
 #[test]
 fn sparse_vector_index_load_missing_mmap() {
     let data_dir = Builder::new().prefix("data_dir").tempdir().unwrap();
@@ -614,6 +706,10 @@ fn sparse_vector_index_persistence_test() {
                 index: SparseIndexConfig {
                     full_scan_threshold: Some(DEFAULT_SPARSE_FULL_SCAN_THRESHOLD),
                     index_type: SparseIndexType::MutableRam,
+                    datatype: None,
+                    prune_top_k: None,
+                    prune_threshold: None,
+                    prune_oversampling: None,
                 },
             },
         )]),
@@ -679,20 +775,25 @@ fn sparse_vector_index_persistence_test() {
         .prefix("inverted_index_ram")
         .tempdir()
         .unwrap();
-    let mut sparse_vector_index_ram: SparseVectorIndex<InvertedIndexRam> = SparseVectorIndex::open(
-        SparseIndexConfig {
-            full_scan_threshold: Some(DEFAULT_SPARSE_FULL_SCAN_THRESHOLD),
-            index_type: SparseIndexType::ImmutableRam,
-        },
-        segment.id_tracker.clone(),
-        segment.vector_data[SPARSE_VECTOR_NAME]
-            .vector_storage
-            .clone(),
-        segment.payload_index.clone(),
-        inverted_index_dir.path(),
-        &stopped,
-    )
-    .unwrap();
+    let mut sparse_vector_index_ram: SparseVectorIndex<InvertedIndexImmutableRam> =
+        SparseVectorIndex::open(
+            SparseIndexConfig {
+                full_scan_threshold: Some(DEFAULT_SPARSE_FULL_SCAN_THRESHOLD),
+                index_type: SparseIndexType::ImmutableRam,
+                datatype: None,
+                prune_top_k: None,
+                prune_threshold: None,
+                prune_oversampling: None,
+            },
+            segment.id_tracker.clone(),
+            segment.vector_data[SPARSE_VECTOR_NAME]
+                .vector_storage
+                .clone(),
+            segment.payload_index.clone(),
+            inverted_index_dir.path(),
+            &stopped,
+        )
+        .unwrap();
     // call build index to create inverted index files
     sparse_vector_index_ram
         .build_index(permit, &stopped)
@@ -700,20 +801,25 @@ fn sparse_vector_index_persistence_test() {
 
     // reload sparse index from file
     drop(sparse_vector_index_ram);
-    let sparse_vector_index_ram: SparseVectorIndex<InvertedIndexRam> = SparseVectorIndex::open(
-        SparseIndexConfig {
-            full_scan_threshold: Some(DEFAULT_SPARSE_FULL_SCAN_THRESHOLD),
-            index_type: SparseIndexType::ImmutableRam,
-        },
-        segment.id_tracker.clone(),
-        segment.vector_data[SPARSE_VECTOR_NAME]
-            .vector_storage
-            .clone(),
-        segment.payload_index.clone(),
-        inverted_index_dir.path(),
-        &stopped,
-    )
-    .unwrap();
+    let sparse_vector_index_ram: SparseVectorIndex<InvertedIndexImmutableRam> =
+        SparseVectorIndex::open(
+            SparseIndexConfig {
+                full_scan_threshold: Some(DEFAULT_SPARSE_FULL_SCAN_THRESHOLD),
+                index_type: SparseIndexType::ImmutableRam,
+                datatype: None,
+                prune_top_k: None,
+                prune_threshold: None,
+                prune_oversampling: None,
+            },
+            segment.id_tracker.clone(),
+            segment.vector_data[SPARSE_VECTOR_NAME]
+                .vector_storage
+                .clone(),
+            segment.payload_index.clone(),
+            inverted_index_dir.path(),
+            &stopped,
+        )
+        .unwrap();
 
     // check that the loaded index performs the same search
     let search_after_reload_result = sparse_vector_index_ram
@@ -743,6 +849,10 @@ fn sparse_vector_index_persistence_test() {
             SparseIndexConfig {
                 full_scan_threshold: Some(DEFAULT_SPARSE_FULL_SCAN_THRESHOLD),
                 index_type: SparseIndexType::Mmap,
+                datatype: None,
+                prune_top_k: None,
+                prune_threshold: None,
+                prune_oversampling: None,
             },
             segment.id_tracker.clone(),
             segment.vector_data[SPARSE_VECTOR_NAME]
@@ -765,6 +875,10 @@ fn sparse_vector_index_persistence_test() {
         SparseIndexConfig {
             full_scan_threshold: Some(DEFAULT_SPARSE_FULL_SCAN_THRESHOLD),
             index_type: SparseIndexType::Mmap,
+            datatype: None,
+            prune_top_k: None,
+            prune_threshold: None,
+            prune_oversampling: None,
         },
         segment.id_tracker.clone(),
         segment.vector_data[SPARSE_VECTOR_NAME]
@@ -872,6 +986,68 @@ fn sparse_vector_index_files() {
     assert_eq!(mutable_index_files.len(), 1); // only the sparse index config file
 }
 
+#[test]
+fn sparse_vector_index_convert_legacy_files() {
+    let stopped = AtomicBool::new(false);
+    let mut rnd = StdRng::seed_from_u64(42);
+
+    let data_dir = Builder::new().prefix("data_dir").tempdir().unwrap();
+    let sparse_vector_ram_index = fixture_sparse_index_ram(
+        &mut rnd,
+        NUM_VECTORS,
+        MAX_SPARSE_DIM,
+        LOW_FULL_SCAN_THRESHOLD,
+        data_dir.path(),
+        &stopped,
+    );
+
+    let mmap_index_dir = Builder::new().prefix("mmap_index_dir").tempdir().unwrap();
+    let mut sparse_index_config = sparse_vector_ram_index.config;
+    sparse_index_config.index_type = SparseIndexType::Mmap;
+    let open_mmap_index = || -> SparseVectorIndex<InvertedIndexMmap> {
+        SparseVectorIndex::open(
+            sparse_index_config,
+            sparse_vector_ram_index.id_tracker.clone(),
+            sparse_vector_ram_index.vector_storage.clone(),
+            sparse_vector_ram_index.payload_index.clone(),
+            mmap_index_dir.path(),
+            &stopped,
+        )
+        .unwrap()
+    };
+
+    let mut sparse_vector_mmap_index = open_mmap_index();
+    let permit = Arc::new(CpuPermit::dummy(num_rayon_threads(0) as u32));
+    sparse_vector_mmap_index
+        .build_index(permit, &stopped)
+        .unwrap();
+    drop(sparse_vector_mmap_index);
+
+    // emulate an index in the older format: only files with legacy names are present
+    let legacy_files = [
+        mmap_index_dir.path().join("inverted_index.data"),
+        mmap_index_dir.path().join("inverted_index_config.json"),
+    ];
+    for (file, legacy_file) in InvertedIndexMmap::files(mmap_index_dir.path())
+        .into_iter()
+        .zip(&legacy_files)
+    {
+        std::fs::rename(file, legacy_file).unwrap();
+    }
+
+    // index is rebuilt on load, and legacy files are removed
+    let sparse_vector_mmap_index = open_mmap_index();
+    assert_eq!(
+        sparse_vector_mmap_index.indexed_vector_count(),
+        sparse_vector_ram_index.indexed_vector_count()
+    );
+    check_index_storage_consistency(&sparse_vector_mmap_index);
+    assert!(InvertedIndexMmap::files(mmap_index_dir.path())
+        .iter()
+        .all(|file| file.exists()));
+    assert!(legacy_files.iter().all(|file| !file.exists()));
+}
+
 #[test]
 fn sparse_vector_test_large_index() {
     let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
@@ -883,6 +1059,10 @@ fn sparse_vector_test_large_index() {
                 index: SparseIndexConfig {
                     full_scan_threshold: Some(DEFAULT_SPARSE_FULL_SCAN_THRESHOLD),
                     index_type: SparseIndexType::MutableRam,
+                    datatype: None,
+                    prune_top_k: None,
+                    prune_threshold: None,
+                    prune_oversampling: None,
                 },
             },
         )]),
@@ -1,3 +1,29 @@
+use serde::{Deserialize, Serialize};
+
 pub type DimOffset = u32;
 pub type DimId = u32;
 pub type DimWeight = f32;
+
+/// Type used to store weights in compressed posting lists
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WeightType {
+    /// Single-precision floating point, lossless
+    #[default]
+    Float32,
+    /// Half-precision floating point, scaled per chunk
+    Float16,
+    /// Unsigned 8-bit integer, linearly quantized per chunk
+    Uint8,
+}
+
+impl WeightType {
+    /// Number of bytes used to store a single weight
+    pub fn size(self) -> usize {
+        match self {
+            WeightType::Float32 => 4,
+            WeightType::Float16 => 2,
+            WeightType::Uint8 => 1,
+        }
+    }
+}
@@ -0,0 +1,629 @@
+use bitpacking::BitPacker;
+use common::types::PointOffsetType;
+use half::f16;
+
+use crate::common::types::{DimWeight, WeightType};
+use crate::index::posting_list::{PostingElement, PostingListIter};
+
+type BitPackerImpl = bitpacking::BitPacker4x;
+
+/// Number of elements in a chunk of a compressed posting list
+pub const CHUNK_LEN: usize = BitPackerImpl::BLOCK_LEN;
+
+/// Header of a chunk of `CHUNK_LEN` elements of a compressed posting list
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[repr(C)]
+pub struct CompressedPostingChunk {
+    /// First record id of the chunk, other ids are delta-encoded from it
+    pub initial: PointOffsetType,
+    /// Offset of the bit-packed ids of the chunk in the id data
+    pub offset: u32,
+    /// Weights of the chunk are decoded as `weight_offset + value * weight_scale`
+    pub weight_offset: DimWeight,
+    pub weight_scale: DimWeight,
+    /// Bounds of the weights of the elements of this chunk, used for block-max pruning
+    pub min_weight: DimWeight,
+    pub max_weight: DimWeight,
+    /// Max weight of the elements of this chunk and all following chunks
+    pub max_next_weight: DimWeight,
+}
+
+/// Immutable posting list with bit-packed ids and quantized weights.
+///
+/// Elements are split into chunks of `CHUNK_LEN` elements, the last chunk is padded by repeating
+/// its last id, so every chunk is bit-packed.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct CompressedPostingList {
+    len: usize,
+    last_id: Option<PointOffsetType>,
+    /// Bit-packed ids of all chunks
+    id_data: Vec<u8>,
+    chunks: Vec<CompressedPostingChunk>,
+    /// Encoded weights of all elements, `weight_type.size()` bytes each
+    weights: Vec<u8>,
+    weight_type: WeightType,
+}
+
+impl CompressedPostingList {
+    /// Compress posting elements sorted by record id
+    pub fn new(elements: &[PostingElement], weight_type: WeightType) -> Self {
+        let bitpacker = BitPackerImpl::new();
+        let mut id_data = Vec::new();
+        let mut chunks = Vec::with_capacity(elements.len().div_ceil(CHUNK_LEN));
+        let mut weights = Vec::with_capacity(elements.len() * weight_type.size());
+
+        let mut ids = [0; CHUNK_LEN];
+        for chunk_elements in elements.chunks(CHUNK_LEN) {
+            let last_id = chunk_elements[chunk_elements.len() - 1].record_id;
+            for (i, id) in ids.iter_mut().enumerate() {
+                *id = chunk_elements.get(i).map_or(last_id, |e| e.record_id);
+            }
+            let initial = ids[0];
+            let num_bits = bitpacker.num_bits_sorted(initial, &ids);
+            let offset = id_data.len();
+            id_data.resize(offset + BitPackerImpl::compressed_block_size(num_bits), 0);
+            bitpacker.compress_sorted(initial, &ids, &mut id_data[offset..], num_bits);
+
+            let (weight_offset, weight_scale) =
+                chunk_weight_params(weight_type, chunk_elements.iter().map(|e| e.weight));
+            for element in chunk_elements {
+                encode_weight(
+                    weight_type,
+                    element.weight,
+                    weight_offset,
+                    weight_scale,
+                    &mut weights,
+                );
+            }
+
+            chunks.push(CompressedPostingChunk {
+                initial,
+                offset: offset as u32,
+                weight_offset,
+                weight_scale,
+                min_weight: DimWeight::INFINITY,
+                max_weight: DimWeight::NEG_INFINITY,
+                max_next_weight: DimWeight::NEG_INFINITY,
+            });
+        }
+
+        // Weight bounds are computed from the decoded weights, so they bound the scores exactly
+        let mut max_next_weight = DimWeight::NEG_INFINITY;
+        let mut decoded = [0.0; CHUNK_LEN];
+        let weight_size = weight_type.size();
+        for (chunk_index, chunk) in chunks.iter_mut().enumerate().rev() {
+            let start = chunk_index * CHUNK_LEN;
+            let end = (start + CHUNK_LEN).min(elements.len());
+            let decoded = &mut decoded[..end - start];
+            decode_weights(
+                weight_type,
+                &weights[start * weight_size..end * weight_size],
+                chunk.weight_offset,
+                chunk.weight_scale,
+                decoded,
+            );
+            chunk.min_weight = decoded.iter().copied().fold(DimWeight::INFINITY, f32::min);
+            chunk.max_weight = decoded
+                .iter()
+                .copied()
+                .fold(DimWeight::NEG_INFINITY, f32::max);
+            max_next_weight = max_next_weight.max(chunk.max_weight);
+            chunk.max_next_weight = max_next_weight;
+        }
+
+        Self {
+            len: elements.len(),
+            last_id: elements.last().map(|e| e.record_id),
+            id_data,
+            chunks,
+            weights,
+            weight_type,
+        }
+    }
+
+    pub fn view(&self) -> CompressedPostingListView {
+        CompressedPostingListView {
+            len: self.len,
+            last_id: self.last_id,
+            id_data: &self.id_data,
+            chunks: &self.chunks,
+            weights: &self.weights,
+            weight_type: self.weight_type,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Size of the compressed ids, chunk headers and weights
+    pub fn data_size_bytes(&self) -> usize {
+        self.id_data.len()
+            + self.chunks.len() * std::mem::size_of::<CompressedPostingChunk>()
+            + self.weights.len()
+    }
+
+    pub fn iter(&self) -> CompressedPostingListIterator {
+        self.view().iter()
+    }
+}
+
+/// Compressed posting list borrowed from owned or memory-mapped data
+#[derive(Debug, Clone, Copy)]
+pub struct CompressedPostingListView<'a> {
+    len: usize,
+    last_id: Option<PointOffsetType>,
+    id_data: &'a [u8],
+    chunks: &'a [CompressedPostingChunk],
+    weights: &'a [u8],
+    weight_type: WeightType,
+}
+
+impl<'a> CompressedPostingListView<'a> {
+    pub fn new(
+        len: usize,
+        last_id: Option<PointOffsetType>,
+        id_data: &'a [u8],
+        chunks: &'a [CompressedPostingChunk],
+        weights: &'a [u8],
+        weight_type: WeightType,
+    ) -> Self {
+        debug_assert_eq!(chunks.len(), len.div_ceil(CHUNK_LEN));
+        debug_assert_eq!(weights.len(), len * weight_type.size());
+        Self {
+            len,
+            last_id,
+            id_data,
+            chunks,
+            weights,
+            weight_type,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn last_id(&self) -> Option<PointOffsetType> {
+        self.last_id
+    }
+
+    pub fn id_data(&self) -> &'a [u8] {
+        self.id_data
+    }
+
+    pub fn chunks(&self) -> &'a [CompressedPostingChunk] {
+        self.chunks
+    }
+
+    pub fn weights(&self) -> &'a [u8] {
+        self.weights
+    }
+
+    pub fn to_owned(&self) -> CompressedPostingList {
+        CompressedPostingList {
+            len: self.len,
+            last_id: self.last_id,
+            id_data: self.id_data.to_vec(),
+            chunks: self.chunks.to_vec(),
+            weights: self.weights.to_vec(),
+            weight_type: self.weight_type,
+        }
+    }
+
+    pub fn iter(&self) -> CompressedPostingListIterator<'a> {
+        CompressedPostingListIterator::new(*self)
+    }
+
+    /// Number of elements in the given chunk, only the last chunk can be incomplete
+    fn chunk_len(&self, chunk_index: usize) -> usize {
+        (self.len - chunk_index * CHUNK_LEN).min(CHUNK_LEN)
+    }
+
+    fn chunk_size(&self, chunk_index: usize) -> usize {
+        let next_offset = match self.chunks.get(chunk_index + 1) {
+            Some(next_chunk) => next_chunk.offset as usize,
+            None => self.id_data.len(),
+        };
+        next_offset - self.chunks[chunk_index].offset as usize
+    }
+
+    fn decompress_chunk(
+        &self,
+        bitpacker: &BitPackerImpl,
+        chunk_index: usize,
+        ids: &mut [PointOffsetType; CHUNK_LEN],
+        weights: &mut [DimWeight; CHUNK_LEN],
+    ) {
+        let chunk = &self.chunks[chunk_index];
+        let chunk_size = self.chunk_size(chunk_index);
+        let num_bits = (chunk_size * 8) / CHUNK_LEN;
+        let offset = chunk.offset as usize;
+        bitpacker.decompress_sorted(
+            chunk.initial,
+            &self.id_data[offset..offset + chunk_size],
+            ids,
+            num_bits as u8,
+        );
+
+        let weight_size = self.weight_type.size();
+        let start = chunk_index * CHUNK_LEN;
+        let chunk_len = self.chunk_len(chunk_index);
+        decode_weights(
+            self.weight_type,
+            &self.weights[start * weight_size..(start + chunk_len) * weight_size],
+            chunk.weight_offset,
+            chunk.weight_scale,
+            &mut weights[..chunk_len],
+        );
+    }
+}
+
+/// Offset and scale of the weights of a chunk
+fn chunk_weight_params(
+    weight_type: WeightType,
+    weights: impl Iterator<Item = DimWeight>,
+) -> (DimWeight, DimWeight) {
+    match weight_type {
+        WeightType::Float32 => (0.0, 1.0),
+        WeightType::Float16 => {
+            // Keep values within [-1, 1] to avoid overflowing the half-precision range
+            let max_abs = weights.map(f32::abs).fold(0.0, f32::max);
+            (0.0, if max_abs > 0.0 { max_abs } else { 1.0 })
+        }
+        WeightType::Uint8 => {
+            let (min, max) = weights.fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), w| {
+                (min.min(w), max.max(w))
+            });
+            (min, (max - min) / u8::MAX as DimWeight)
+        }
+    }
+}
+
+fn encode_weight(
+    weight_type: WeightType,
+    weight: DimWeight,
+    weight_offset: DimWeight,
+    weight_scale: DimWeight,
+    output: &mut Vec<u8>,
+) {
+    match weight_type {
+        WeightType::Float32 => output.extend_from_slice(&weight.to_le_bytes()),
+        WeightType::Float16 => {
+            let value = f16::from_f32((weight - weight_offset) / weight_scale);
+            output.extend_from_slice(&value.to_le_bytes())
+        }
+        WeightType::Uint8 => {
+            let value = if weight_scale > 0.0 {
+                ((weight - weight_offset) / weight_scale)
+                    .round()
+                    .clamp(0.0, u8::MAX as DimWeight) as u8
+            } else {
+                0
+            };
+            output.push(value)
+        }
+    }
+}
+
+fn decode_weights(
+    weight_type: WeightType,
+    data: &[u8],
+    weight_offset: DimWeight,
+    weight_scale: DimWeight,
+    output: &mut [DimWeight],
+) {
+    match weight_type {
+        WeightType::Float32 => {
+            for (weight, bytes) in output.iter_mut().zip(data.chunks_exact(4)) {
+                *weight = f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+            }
+        }
+        WeightType::Float16 => {
+            for (weight, bytes) in output.iter_mut().zip(data.chunks_exact(2)) {
+                let value = f16::from_le_bytes([bytes[0], bytes[1]]).to_f32();
+                *weight = weight_offset + value * weight_scale;
+            }
+        }
+        WeightType::Uint8 => {
+            for (weight, &value) in output.iter_mut().zip(data) {
+                *weight = weight_offset + value as DimWeight * weight_scale;
+            }
+        }
+    }
+}
+
+/// Iterator over a compressed posting list.
+///
+/// The chunk of the current element is always kept decompressed.
+pub struct CompressedPostingListIterator<'a> {
+    list: CompressedPostingListView<'a>,
+    bitpacker: BitPackerImpl,
+    /// Index of the next element
+    current_index: usize,
+    /// Chunk currently decompressed into `decompressed_ids` and `decompressed_weights`
+    decompressed_chunk: Option<usize>,
+    decompressed_ids: [PointOffsetType; CHUNK_LEN],
+    decompressed_weights: [DimWeight; CHUNK_LEN],
+}
+
+impl<'a> CompressedPostingListIterator<'a> {
+    pub fn new(list: CompressedPostingListView<'a>) -> Self {
+        let mut iterator = Self {
+            list,
+            bitpacker: BitPackerImpl::new(),
+            current_index: 0,
+            decompressed_chunk: None,
+            decompressed_ids: [0; CHUNK_LEN],
+            decompressed_weights: [0.0; CHUNK_LEN],
+        };
+        iterator.set_current_index(0);
+        iterator
+    }
+
+    /// Moves to the given element and decompresses its chunk if needed
+    fn set_current_index(&mut self, index: usize) {
+        self.current_index = index;
+        if index >= self.list.len {
+            return;
+        }
+        let chunk_index = index / CHUNK_LEN;
+        if self.decompressed_chunk != Some(chunk_index) {
+            self.list.decompress_chunk(
+                &self.bitpacker,
+                chunk_index,
+                &mut self.decompressed_ids,
+                &mut self.decompressed_weights,
+            );
+            self.decompressed_chunk = Some(chunk_index);
+        }
+    }
+}
+
+impl<'a> PostingListIter for CompressedPostingListIterator<'a> {
+    fn peek(&self) -> Option<PostingElement> {
+        if self.current_index >= self.list.len {
+            return None;
+        }
+        let chunk_index = self.current_index / CHUNK_LEN;
+        debug_assert_eq!(self.decompressed_chunk, Some(chunk_index));
+        let in_chunk = self.current_index % CHUNK_LEN;
+        Some(PostingElement {
+            record_id: self.decompressed_ids[in_chunk],
+            weight: self.decompressed_weights[in_chunk],
+            max_next_weight: self.list.chunks[chunk_index].max_next_weight,
+        })
+    }
+
+    fn last_id(&self) -> Option<PointOffsetType> {
+        self.list.last_id
+    }
+
+    fn skip_to(&mut self, id: PointOffsetType) -> Option<PostingElement> {
+        if self.current_index >= self.list.len {
+            return None;
+        }
+        if self.list.last_id.map_or(true, |last_id| last_id < id) {
+            self.skip_to_end();
+            return None;
+        }
+
+        // find the last chunk starting at or before `id`
+        let current_chunk = self.current_index / CHUNK_LEN;
+        let chunk_index = match self.list.chunks[current_chunk..]
+            .binary_search_by(|chunk| chunk.initial.cmp(&id))
+        {
+            Ok(found) => current_chunk + found,
+            Err(0) => current_chunk,
+            Err(next) => current_chunk + next - 1,
+        };
+        if chunk_index != current_chunk {
+            self.set_current_index(chunk_index * CHUNK_LEN);
+        }
+
+        let chunk_start = chunk_index * CHUNK_LEN;
+        let from = self.current_index - chunk_start;
+        let to = self.list.chunk_len(chunk_index);
+        match self.decompressed_ids[from..to].binary_search(&id) {
+            Ok(found) => {
+                self.set_current_index(chunk_start + from + found);
+                self.peek()
+            }
+            Err(next) => {
+                self.set_current_index(chunk_start + from + next);
+                None
+            }
+        }
+    }
+
+    fn skip_to_end(&mut self) {
+        self.current_index = self.list.len;
+    }
+
+    fn len_to_end(&self) -> usize {
+        self.list.len - self.current_index
+    }
+
+    fn current_index(&self) -> usize {
+        self.current_index
+    }
+
+    fn weight_bounds_till_id(&self, id: PointOffsetType) -> (DimWeight, DimWeight) {
+        if self.current_index >= self.list.len {
+            return (DimWeight::INFINITY, DimWeight::NEG_INFINITY);
+        }
+        // bounds of the current chunk also cover its already visited elements
+        let current_chunk = self.current_index / CHUNK_LEN;
+        self.list.chunks[current_chunk..]
+            .iter()
+            .take_while(|chunk| chunk.initial <= id)
+            .fold(
+                (DimWeight::INFINITY, DimWeight::NEG_INFINITY),
+                |(min_weight, max_weight), chunk| {
+                    (
+                        min_weight.min(chunk.min_weight),
+                        max_weight.max(chunk.max_weight),
+                    )
+                },
+            )
+    }
+
+    fn for_each_till_id(
+        &mut self,
+        id: PointOffsetType,
+        mut f: impl FnMut(PointOffsetType, DimWeight),
+    ) {
+        while self.current_index < self.list.len {
+            let chunk_index = self.current_index / CHUNK_LEN;
+            let chunk_start = chunk_index * CHUNK_LEN;
+            let chunk_len = self.list.chunk_len(chunk_index);
+            for in_chunk in self.current_index - chunk_start..chunk_len {
+                let record_id = self.decompressed_ids[in_chunk];
+                if record_id > id {
+                    self.current_index = chunk_start + in_chunk;
+                    return;
+                }
+                f(record_id, self.decompressed_weights[in_chunk]);
+            }
+            self.set_current_index(chunk_start + chunk_len);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    use super::*;
+    use crate::index::posting_list::{PostingBuilder, PostingListIterator};
+
+    fn random_elements(num_elements: usize) -> Vec<PostingElement> {
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut builder = PostingBuilder::new();
+        let mut id = 0;
+        for _ in 0..num_elements {
+            id += rng.gen_range(1..50);
+            builder.add(id, rng.gen_range(0.0..10.0));
+        }
+        builder.build().elements
+    }
+
+    #[test]
+    fn test_compressed_posting_list_roundtrip() {
+        for num_elements in [0, 1, 127, 128, 129, 1000] {
+            let elements = random_elements(num_elements);
+            for (weight_type, tolerance) in [
+                (WeightType::Float32, 0.0),
+                (WeightType::Float16, 0.01),
+                (WeightType::Uint8, 0.03),
+            ] {
+                let compressed = CompressedPostingList::new(&elements, weight_type);
+                assert_eq!(compressed.len(), elements.len());
+
+                let mut decompressed = Vec::new();
+                let mut iter = compressed.iter();
+                iter.for_each_till_id(PointOffsetType::MAX, |id, weight| {
+                    decompressed.push((id, weight))
+                });
+                assert_eq!(iter.len_to_end(), 0);
+                assert_eq!(decompressed.len(), elements.len());
+                for (element, (id, weight)) in elements.iter().zip(decompressed) {
+                    assert_eq!(element.record_id, id);
+                    assert!((element.weight - weight).abs() <= tolerance);
+                }
+
+                // the owned copy of a view is the same list
+                assert_eq!(compressed.view().to_owned(), compressed);
+            }
+        }
+    }
+
+    #[test]
+    fn test_compressed_posting_list_skip_to() {
+        let elements = random_elements(1000);
+        let compressed = CompressedPostingList::new(&elements, WeightType::Uint8);
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for _ in 0..100 {
+            let mut expected = PostingListIterator::new(&elements);
+            let mut iter = compressed.iter();
+            let mut id = 0;
+            while iter.len_to_end() > 0 {
+                id += rng.gen_range(0..300);
+                let found = iter.skip_to(id).map(|e| e.record_id);
+                let expected_found = expected.skip_to(id).map(|e| e.record_id);
+                assert_eq!(found, expected_found);
+                assert_eq!(iter.current_index(), expected.current_index);
+                assert_eq!(
+                    iter.peek().map(|e| e.record_id),
+                    expected.peek().map(|e| e.record_id)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_compressed_posting_list_max_next_weight() {
+        let elements = random_elements(1000);
+        for weight_type in [WeightType::Float32, WeightType::Float16, WeightType::Uint8] {
+            let compressed = CompressedPostingList::new(&elements, weight_type);
+            let mut weights = Vec::new();
+            compressed
+                .iter()
+                .for_each_till_id(PointOffsetType::MAX, |_, weight| weights.push(weight));
+
+            // the max weight of the chunk bounds all remaining weights
+            let mut iter = compressed.iter();
+            while let Some(element) = iter.peek() {
+                let max_remaining = weights[iter.current_index()..]
+                    .iter()
+                    .copied()
+                    .fold(f32::NEG_INFINITY, f32::max);
+                assert!(element.max_next_weight >= max_remaining);
+                iter.skip_to(element.record_id + 1);
+            }
+        }
+    }
+
+    #[test]
+    fn test_compressed_posting_list_weight_bounds() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let elements = random_elements(1000);
+        for weight_type in [WeightType::Float32, WeightType::Float16, WeightType::Uint8] {
+            let compressed = CompressedPostingList::new(&elements, weight_type);
+            let mut decompressed = Vec::new();
+            compressed
+                .iter()
+                .for_each_till_id(PointOffsetType::MAX, |id, weight| {
+                    decompressed.push((id, weight))
+                });
+
+            let mut iter = compressed.iter();
+            while let Some(element) = iter.peek() {
+                let till_id = element.record_id + rng.gen_range(0..2_000);
+                let (min_weight, max_weight) = iter.weight_bounds_till_id(till_id);
+                // the bounds cover all remaining weights up to the given id
+                for &(_, weight) in decompressed[iter.current_index()..]
+                    .iter()
+                    .take_while(|(id, _)| *id <= till_id)
+                {
+                    assert!(min_weight <= weight && weight <= max_weight);
+                }
+                iter.skip_to(element.record_id + rng.gen_range(1..300));
+            }
+
+            // exhausted iterators have empty bounds
+            let (min_weight, max_weight) = iter.weight_bounds_till_id(PointOffsetType::MAX);
+            assert!(min_weight > max_weight);
+        }
+    }
+}
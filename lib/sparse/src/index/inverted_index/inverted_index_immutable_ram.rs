@@ -0,0 +1,162 @@
+use std::path::{Path, PathBuf};
+
+use common::types::PointOffsetType;
+
+use super::inverted_index_mmap::InvertedIndexMmap;
+use super::inverted_index_ram::InvertedIndexRam;
+use crate::common::sparse_vector::RemappedSparseVector;
+use crate::common::types::{DimId, DimOffset, WeightType};
+use crate::index::compressed_posting_list::{CompressedPostingList, CompressedPostingListIterator};
+use crate::index::inverted_index::InvertedIndex;
+
+/// Immutable inverted flatten index from dimension id to compressed posting list, kept in RAM
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvertedIndexImmutableRam {
+    /// Compressed posting lists for each dimension flattened (dimension id -> posting list)
+    /// Gaps are filled with empty posting lists
+    pub postings: Vec<CompressedPostingList>,
+    /// Number of unique indexed vectors
+    pub vector_count: usize,
+    /// Type used to store weights in all posting lists
+    pub weight_type: WeightType,
+}
+
+impl InvertedIndex for InvertedIndexImmutableRam {
+    type Iter<'a> = CompressedPostingListIterator<'a>;
+
+    fn open(path: &Path) -> std::io::Result<Self> {
+        let mmap_inverted_index = InvertedIndexMmap::load(path)?;
+        let mut postings = Vec::with_capacity(mmap_inverted_index.file_header.posting_count);
+        for i in 0..mmap_inverted_index.file_header.posting_count as DimId {
+            let posting_list = mmap_inverted_index.get(&i).ok_or_else(|| {
+                std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    format!("Posting list {} not found", i),
+                )
+            })?;
+            postings.push(posting_list.to_owned());
+        }
+
+        Ok(InvertedIndexImmutableRam {
+            postings,
+            vector_count: mmap_inverted_index.file_header.vector_count,
+            weight_type: mmap_inverted_index.file_header.weight_type,
+        })
+    }
+
+    fn save(&self, path: &Path) -> std::io::Result<()> {
+        let posting_views: Vec<_> = self.postings.iter().map(|p| p.view()).collect();
+        InvertedIndexMmap::save_compressed(
+            &posting_views,
+            self.vector_count,
+            self.weight_type,
+            path,
+        )?;
+        Ok(())
+    }
+
+    fn get<'a>(&'a self, id: &DimOffset) -> Option<CompressedPostingListIterator<'a>> {
+        self.postings
+            .get(*id as usize)
+            .map(|posting_list| posting_list.iter())
+    }
+
+    fn len(&self) -> usize {
+        self.postings.len()
+    }
+
+    fn posting_list_len(&self, id: &DimOffset) -> Option<usize> {
+        self.postings
+            .get(*id as usize)
+            .map(|posting_list| posting_list.len())
+    }
+
+    fn files(path: &Path) -> Vec<PathBuf> {
+        InvertedIndexMmap::files(path)
+            .into_iter()
+            .filter(|p| p.exists())
+            .collect()
+    }
+
+    fn upsert(&mut self, _id: PointOffsetType, _vector: RemappedSparseVector) {
+        panic!("Cannot upsert into a read-only RAM inverted index")
+    }
+
+    fn from_ram_index<P: AsRef<Path>>(
+        ram_index: InvertedIndexRam,
+        _path: P,
+        weight_type: WeightType,
+    ) -> std::io::Result<Self> {
+        let postings = ram_index
+            .postings
+            .iter()
+            .map(|posting| CompressedPostingList::new(&posting.elements, weight_type))
+            .collect();
+        Ok(InvertedIndexImmutableRam {
+            postings,
+            vector_count: ram_index.vector_count,
+            weight_type,
+        })
+    }
+
+    fn vector_count(&self) -> usize {
+        self.vector_count
+    }
+
+    fn max_index(&self) -> Option<DimId> {
+        match self.postings.len() {
+            0 => None,
+            len => Some(len as DimId - 1),
+        }
+    }
+
+    fn ram_usage_bytes(&self) -> usize {
+        self.postings.len() * std::mem::size_of::<CompressedPostingList>()
+            + self
+                .postings
+                .iter()
+                .map(CompressedPostingList::data_size_bytes)
+                .sum::<usize>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::Builder;
+
+    use super::*;
+    use crate::index::inverted_index::inverted_index_ram_builder::InvertedIndexBuilder;
+    use crate::index::posting_list::PostingListIter;
+
+    #[test]
+    fn inverted_index_immutable_ram_save_load() {
+        let mut builder = InvertedIndexBuilder::new();
+        builder.add(1, [(1, 10.0), (2, 10.0), (3, 10.0)].into());
+        builder.add(2, [(1, 20.0), (2, 20.0), (3, 20.0)].into());
+        builder.add(3, [(1, 30.0), (3, 30.0)].into());
+        let inverted_index_ram = builder.build();
+
+        for weight_type in [WeightType::Float32, WeightType::Float16, WeightType::Uint8] {
+            let tmp_dir_path = Builder::new().prefix("test_index_dir").tempdir().unwrap();
+            let inverted_index = InvertedIndexImmutableRam::from_ram_index(
+                inverted_index_ram.clone(),
+                tmp_dir_path.path(),
+                weight_type,
+            )
+            .unwrap();
+            assert_eq!(inverted_index.vector_count(), 3);
+            assert_eq!(inverted_index.posting_list_len(&2), Some(2));
+
+            let mut posting = inverted_index.get(&3).unwrap();
+            assert_eq!(posting.last_id(), Some(3));
+            let element = posting.skip_to(2).unwrap();
+            assert_eq!(element.record_id, 2);
+            assert!((element.weight - 20.0).abs() < 0.1);
+
+            inverted_index.save(tmp_dir_path.path()).unwrap();
+            let loaded_inverted_index =
+                InvertedIndexImmutableRam::open(tmp_dir_path.path()).unwrap();
+            assert_eq!(inverted_index, loaded_inverted_index);
+        }
+    }
+}
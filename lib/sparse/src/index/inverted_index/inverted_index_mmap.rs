@@ -1,4 +1,4 @@
-use std::mem::size_of;
+use std::mem::{align_of, size_of, size_of_val};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
@@ -13,35 +13,55 @@ use memory::mmap_ops::{
 use serde::{Deserialize, Serialize};
 
 use crate::common::sparse_vector::RemappedSparseVector;
-use crate::common::types::{DimId, DimOffset};
+use crate::common::types::{DimId, DimOffset, WeightType};
+use crate::index::compressed_posting_list::{
+    CompressedPostingChunk, CompressedPostingList, CompressedPostingListIterator,
+    CompressedPostingListView,
+};
 use crate::index::inverted_index::inverted_index_ram::InvertedIndexRam;
 use crate::index::inverted_index::InvertedIndex;
-use crate::index::posting_list::{PostingElement, PostingListIterator};
 
 const POSTING_HEADER_SIZE: usize = size_of::<PostingListFileHeader>();
-const INDEX_FILE_NAME: &str = "inverted_index.data";
-const INDEX_CONFIG_FILE_NAME: &str = "inverted_index_config.json";
+const INDEX_FILE_NAME: &str = "inverted_index_compressed.data";
+const INDEX_CONFIG_FILE_NAME: &str = "inverted_index_compressed_config.json";
+/// Files of the index format with uncompressed posting lists
+const LEGACY_INDEX_FILE_NAME: &str = "inverted_index.data";
+const LEGACY_INDEX_CONFIG_FILE_NAME: &str = "inverted_index_config.json";
 
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct InvertedIndexFileHeader {
     pub posting_count: usize, // number oof posting lists
     pub vector_count: usize,  // number of unique vectors indexed
+    pub weight_type: WeightType,
 }
 
-/// Inverted flatten index from dimension id to posting list
+/// Inverted flatten index from dimension id to compressed posting list
 pub struct InvertedIndexMmap {
     path: PathBuf,
     mmap: Arc<Mmap>,
     pub file_header: InvertedIndexFileHeader,
 }
 
+/// Location of a compressed posting list in the index file.
+///
+/// A posting list is stored as its chunk headers, followed by its weights and its bit-packed ids.
 #[derive(Debug, Default, Clone)]
+#[repr(C)]
 struct PostingListFileHeader {
     pub start_offset: u64,
-    pub end_offset: u64,
+    /// Number of elements
+    pub len: u32,
+    /// Number of chunks
+    pub chunks_count: u32,
+    /// Size of the bit-packed ids in bytes
+    pub ids_size: u32,
+    /// Record id of the last element, not used for empty posting lists
+    pub last_id: PointOffsetType,
 }
 
 impl InvertedIndex for InvertedIndexMmap {
+    type Iter<'a> = CompressedPostingListIterator<'a>;
+
     fn open(path: &Path) -> std::io::Result<Self> {
         Self::load(path)
     }
@@ -51,8 +71,8 @@ impl InvertedIndex for InvertedIndexMmap {
         Ok(())
     }
 
-    fn get(&self, id: &DimId) -> Option<PostingListIterator> {
-        self.get(id).map(PostingListIterator::new)
+    fn get<'a>(&'a self, id: &DimId) -> Option<CompressedPostingListIterator<'a>> {
+        self.get(id).map(|posting_list| posting_list.iter())
     }
 
     fn len(&self) -> usize {
@@ -77,8 +97,9 @@ impl InvertedIndex for InvertedIndexMmap {
     fn from_ram_index<P: AsRef<Path>>(
         ram_index: InvertedIndexRam,
         path: P,
+        weight_type: WeightType,
     ) -> std::io::Result<Self> {
-        Self::convert_and_save(&ram_index, path)
+        Self::convert_and_save(&ram_index, path, weight_type)
     }
 
     fn vector_count(&self) -> usize {
@@ -91,6 +112,10 @@ impl InvertedIndex for InvertedIndexMmap {
             len => Some(len as DimId - 1),
         }
     }
+
+    fn ram_usage_bytes(&self) -> usize {
+        0
+    }
 }
 
 impl InvertedIndexMmap {
@@ -102,7 +127,18 @@ impl InvertedIndexMmap {
         path.join(INDEX_CONFIG_FILE_NAME)
     }
 
-    pub fn get(&self, id: &DimId) -> Option<&[PostingElement]> {
+    /// Files of an index in the older format, which may be left in `path`
+    pub fn legacy_files(path: &Path) -> Vec<PathBuf> {
+        [
+            path.join(LEGACY_INDEX_FILE_NAME),
+            path.join(LEGACY_INDEX_CONFIG_FILE_NAME),
+        ]
+        .into_iter()
+        .filter(|p| p.exists())
+        .collect()
+    }
+
+    pub fn get(&self, id: &DimId) -> Option<CompressedPostingListView> {
         // check that the id is not out of bounds (posting_count includes the empty zeroth entry)
         if *id >= self.file_header.posting_count as DimId {
             return None;
@@ -112,18 +148,59 @@ impl InvertedIndexMmap {
             &self.mmap[header_start..header_start + POSTING_HEADER_SIZE],
         )
         .clone();
-        let elements_bytes = &self.mmap[header.start_offset as usize..header.end_offset as usize];
-        Some(transmute_from_u8_to_slice(elements_bytes))
+
+        let chunks_start = header.start_offset as usize;
+        let weights_start =
+            chunks_start + header.chunks_count as usize * size_of::<CompressedPostingChunk>();
+        let ids_start = weights_start + header.len as usize * self.file_header.weight_type.size();
+        let ids_end = ids_start + header.ids_size as usize;
+
+        Some(CompressedPostingListView::new(
+            header.len as usize,
+            (header.len > 0).then_some(header.last_id),
+            &self.mmap[ids_start..ids_end],
+            transmute_from_u8_to_slice(&self.mmap[chunks_start..weights_start]),
+            &self.mmap[weights_start..ids_start],
+            self.file_header.weight_type,
+        ))
     }
 
+    /// Compress posting lists of the RAM index and save them
     pub fn convert_and_save<P: AsRef<Path>>(
         inverted_index_ram: &InvertedIndexRam,
         path: P,
+        weight_type: WeightType,
     ) -> std::io::Result<Self> {
-        let total_posting_headers_size = Self::total_posting_headers_size(inverted_index_ram);
-        let total_posting_elements_size = Self::total_posting_elements_size(inverted_index_ram);
+        let postings: Vec<_> = inverted_index_ram
+            .postings
+            .iter()
+            .map(|posting| CompressedPostingList::new(&posting.elements, weight_type))
+            .collect();
+        let posting_views: Vec<_> = postings.iter().map(|posting| posting.view()).collect();
+        Self::save_compressed(
+            &posting_views,
+            inverted_index_ram.vector_count(),
+            weight_type,
+            path,
+        )
+    }
+
+    /// Save already compressed posting lists, all of them have to use `weight_type`
+    pub fn save_compressed<P: AsRef<Path>>(
+        postings: &[CompressedPostingListView],
+        vector_count: usize,
+        weight_type: WeightType,
+        path: P,
+    ) -> std::io::Result<Self> {
+        let total_posting_headers_size = postings.len() * POSTING_HEADER_SIZE;
+        let posting_headers = Self::posting_headers(postings, total_posting_headers_size);
+        let file_length = posting_headers
+            .last()
+            .zip(postings.last())
+            .map_or(total_posting_headers_size, |(header, posting)| {
+                header.start_offset as usize + Self::posting_size(posting)
+            });
 
-        let file_length = total_posting_headers_size + total_posting_elements_size;
         let file_path = Self::index_file_path(path.as_ref());
         create_and_ensure_length(file_path.as_ref(), file_length)?;
 
@@ -131,20 +208,16 @@ impl InvertedIndexMmap {
         madvise::madvise(&mmap, madvise::Advice::Normal)?;
 
         // file index data
-        Self::save_posting_headers(&mut mmap, inverted_index_ram, total_posting_headers_size);
-        Self::save_posting_elements(&mut mmap, inverted_index_ram, total_posting_headers_size);
+        Self::save_postings(&mut mmap, postings, &posting_headers);
         if file_length > 0 {
             mmap.flush()?;
         }
 
-        // save header properties
-        let posting_count = inverted_index_ram.postings.len();
-        let vector_count = inverted_index_ram.vector_count();
-
         // finalize data with index file.
         let file_header = InvertedIndexFileHeader {
-            posting_count,
+            posting_count: postings.len(),
             vector_count,
+            weight_type,
         };
         let config_file_path = Self::index_config_file_path(path.as_ref());
         atomic_save_json(&config_file_path, &file_header)?;
@@ -172,53 +245,55 @@ impl InvertedIndexMmap {
         })
     }
 
-    fn total_posting_headers_size(inverted_index_ram: &InvertedIndexRam) -> usize {
-        inverted_index_ram.postings.len() * POSTING_HEADER_SIZE
+    fn posting_size(posting: &CompressedPostingListView) -> usize {
+        size_of_val(posting.chunks()) + posting.weights().len() + posting.id_data().len()
     }
 
-    fn total_posting_elements_size(inverted_index_ram: &InvertedIndexRam) -> usize {
-        let mut total_posting_elements_size = 0;
-        for posting in &inverted_index_ram.postings {
-            total_posting_elements_size += posting.elements.len() * size_of::<PostingElement>();
-        }
-
-        total_posting_elements_size
+    fn posting_headers(
+        postings: &[CompressedPostingListView],
+        total_posting_headers_size: usize,
+    ) -> Vec<PostingListFileHeader> {
+        let mut offset = total_posting_headers_size;
+        postings
+            .iter()
+            .map(|posting| {
+                // chunk headers are transmuted from the file, keep them aligned
+                offset = offset.next_multiple_of(align_of::<CompressedPostingChunk>());
+                let header = PostingListFileHeader {
+                    start_offset: offset as u64,
+                    len: posting.len() as u32,
+                    chunks_count: posting.chunks().len() as u32,
+                    ids_size: posting.id_data().len() as u32,
+                    last_id: posting.last_id().unwrap_or_default(),
+                };
+                offset += Self::posting_size(posting);
+                header
+            })
+            .collect()
     }
 
-    fn save_posting_headers(
+    fn save_postings(
         mmap: &mut MmapMut,
-        inverted_index_ram: &InvertedIndexRam,
-        total_posting_headers_size: usize,
+        postings: &[CompressedPostingListView],
+        posting_headers: &[PostingListFileHeader],
     ) {
-        let mut elements_offset: usize = total_posting_headers_size;
-        for (id, posting) in inverted_index_ram.postings.iter().enumerate() {
-            let posting_elements_size = posting.elements.len() * size_of::<PostingElement>();
-            let posting_header = PostingListFileHeader {
-                start_offset: elements_offset as u64,
-                end_offset: (elements_offset + posting_elements_size) as u64,
-            };
-            elements_offset = posting_header.end_offset as usize;
-
+        for (id, (posting, posting_header)) in postings.iter().zip(posting_headers).enumerate() {
             // save posting header
-            let posting_header_bytes = transmute_to_u8(&posting_header);
+            let posting_header_bytes = transmute_to_u8(posting_header);
             let start_posting_offset = id * POSTING_HEADER_SIZE;
             let end_posting_offset = (id + 1) * POSTING_HEADER_SIZE;
             mmap[start_posting_offset..end_posting_offset].copy_from_slice(posting_header_bytes);
-        }
-    }
 
-    fn save_posting_elements(
-        mmap: &mut MmapMut,
-        inverted_index_ram: &InvertedIndexRam,
-        total_posting_headers_size: usize,
-    ) {
-        let mut offset = total_posting_headers_size;
-        for posting in &inverted_index_ram.postings {
-            // save posting element
-            let posting_elements_bytes = transmute_to_u8_slice(&posting.elements);
-            mmap[offset..offset + posting_elements_bytes.len()]
-                .copy_from_slice(posting_elements_bytes);
-            offset += posting_elements_bytes.len();
+            // save chunks, weights and ids
+            let mut offset = posting_header.start_offset as usize;
+            for data in [
+                transmute_to_u8_slice(posting.chunks()),
+                posting.weights(),
+                posting.id_data(),
+            ] {
+                mmap[offset..offset + data.len()].copy_from_slice(data);
+                offset += data.len();
+            }
         }
     }
 }
@@ -229,6 +304,7 @@ mod tests {
 
     use super::*;
     use crate::index::inverted_index::inverted_index_ram_builder::InvertedIndexBuilder;
+    use crate::index::posting_list::PostingListIter;
 
     fn compare_indexes(
         inverted_index_ram: &InvertedIndexRam,
@@ -236,10 +312,18 @@ mod tests {
     ) {
         for id in 0..inverted_index_ram.postings.len() as DimId {
             let posting_list_ram = inverted_index_ram.get(&id).unwrap().elements.as_slice();
-            let posting_list_mmap = inverted_index_mmap.get(&id).unwrap();
+            let mut posting_list_mmap = Vec::new();
+            inverted_index_mmap
+                .get(&id)
+                .unwrap()
+                .iter()
+                .for_each_till_id(PointOffsetType::MAX, |record_id, weight| {
+                    posting_list_mmap.push((record_id, weight))
+                });
             assert_eq!(posting_list_ram.len(), posting_list_mmap.len());
-            for i in 0..posting_list_ram.len() {
-                assert_eq!(posting_list_ram[i], posting_list_mmap[i]);
+            for (element, &(record_id, weight)) in posting_list_ram.iter().zip(&posting_list_mmap) {
+                assert_eq!(element.record_id, record_id);
+                assert_eq!(element.weight, weight);
             }
         }
     }
@@ -262,8 +346,12 @@ mod tests {
         let tmp_dir_path = Builder::new().prefix("test_index_dir").tempdir().unwrap();
 
         {
-            let inverted_index_mmap =
-                InvertedIndexMmap::convert_and_save(&inverted_index_ram, &tmp_dir_path).unwrap();
+            let inverted_index_mmap = InvertedIndexMmap::convert_and_save(
+                &inverted_index_ram,
+                &tmp_dir_path,
+                WeightType::Float32,
+            )
+            .unwrap();
 
             compare_indexes(&inverted_index_ram, &inverted_index_mmap);
         }
@@ -5,9 +5,11 @@ use common::types::PointOffsetType;
 
 use super::inverted_index_mmap::InvertedIndexMmap;
 use crate::common::sparse_vector::RemappedSparseVector;
-use crate::common::types::DimId;
+use crate::common::types::{DimId, WeightType};
 use crate::index::inverted_index::InvertedIndex;
-use crate::index::posting_list::{PostingElement, PostingList, PostingListIterator};
+use crate::index::posting_list::{
+    PostingBuilder, PostingElement, PostingList, PostingListIter, PostingListIterator,
+};
 
 /// Inverted flatten index from dimension id to posting list
 #[derive(Debug, Clone, PartialEq)]
@@ -21,6 +23,8 @@ pub struct InvertedIndexRam {
 }
 
 impl InvertedIndex for InvertedIndexRam {
+    type Iter<'a> = PostingListIterator<'a>;
+
     fn open(path: &Path) -> std::io::Result<Self> {
         let mmap_inverted_index = InvertedIndexMmap::load(path)?;
         let mut inverted_index = InvertedIndexRam {
@@ -35,20 +39,24 @@ impl InvertedIndex for InvertedIndexRam {
                     format!("Posting list {} not found", i),
                 )
             })?;
-            inverted_index.postings.push(PostingList {
-                elements: posting_list.to_owned(),
-            });
+            let mut posting_builder = PostingBuilder::new();
+            posting_list
+                .iter()
+                .for_each_till_id(PointOffsetType::MAX, |record_id, weight| {
+                    posting_builder.add(record_id, weight)
+                });
+            inverted_index.postings.push(posting_builder.build());
         }
 
         Ok(inverted_index)
     }
 
     fn save(&self, path: &Path) -> std::io::Result<()> {
-        InvertedIndexMmap::convert_and_save(self, path)?;
+        InvertedIndexMmap::convert_and_save(self, path, WeightType::Float32)?;
         Ok(())
     }
 
-    fn get(&self, id: &DimId) -> Option<PostingListIterator> {
+    fn get<'a>(&'a self, id: &DimId) -> Option<PostingListIterator<'a>> {
         self.get(id)
             .map(|posting_list| PostingListIterator::new(&posting_list.elements))
     }
@@ -78,6 +86,7 @@ impl InvertedIndex for InvertedIndexRam {
     fn from_ram_index<P: AsRef<Path>>(
         ram_index: InvertedIndexRam,
         _path: P,
+        _weight_type: WeightType,
     ) -> std::io::Result<Self> {
         Ok(ram_index)
     }
@@ -92,6 +101,12 @@ impl InvertedIndex for InvertedIndexRam {
             len => Some(len as DimId - 1),
         }
     }
+
+    fn ram_usage_bytes(&self) -> usize {
+        let elements_count: usize = self.postings.iter().map(|p| p.elements.len()).sum();
+        self.postings.len() * std::mem::size_of::<PostingList>()
+            + elements_count * std::mem::size_of::<PostingElement>()
+    }
 }
 
 impl InvertedIndexRam {
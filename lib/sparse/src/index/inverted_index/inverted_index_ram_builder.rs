@@ -3,13 +3,61 @@ use std::cmp::max;
 use common::types::PointOffsetType;
 
 use crate::common::sparse_vector::RemappedSparseVector;
+use crate::common::types::DimWeight;
 use crate::index::inverted_index::inverted_index_ram::InvertedIndexRam;
 use crate::index::posting_list::PostingBuilder;
 
+/// Index-time pruning of sparse vectors.
+///
+/// Dimensions which are unlikely to contribute to the score are not added to the posting lists.
+/// Original vectors are not affected, so they can still be used for exact rescoring.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct IndexPruning {
+    /// Keep only this number of dimensions with the largest absolute weights
+    pub top_k: Option<usize>,
+    /// Drop dimensions with absolute weight below this threshold
+    pub threshold: Option<DimWeight>,
+}
+
+impl IndexPruning {
+    pub fn is_enabled(&self) -> bool {
+        self.top_k.is_some() || self.threshold.is_some()
+    }
+
+    /// Remove pruned dimensions from the vector, order of the remaining dimensions is preserved
+    pub fn prune(&self, vector: RemappedSparseVector) -> RemappedSparseVector {
+        if !self.is_enabled() {
+            return vector;
+        }
+
+        let mut weights: Vec<_> = vector.indices.into_iter().zip(vector.values).collect();
+
+        if let Some(threshold) = self.threshold {
+            weights.retain(|(_, weight)| weight.abs() >= threshold);
+        }
+
+        if let Some(top_k) = self.top_k {
+            if weights.len() > top_k {
+                let mut order: Vec<usize> = (0..weights.len()).collect();
+                order.select_nth_unstable_by(top_k, |&a, &b| {
+                    weights[b].1.abs().total_cmp(&weights[a].1.abs())
+                });
+                order.truncate(top_k);
+                order.sort_unstable();
+                weights = order.into_iter().map(|i| weights[i]).collect();
+            }
+        }
+
+        let (indices, values) = weights.into_iter().unzip();
+        RemappedSparseVector { indices, values }
+    }
+}
+
 /// Builder for InvertedIndexRam
 pub struct InvertedIndexBuilder {
     pub posting_builders: Vec<PostingBuilder>,
     pub vector_count: usize,
+    pub pruning: IndexPruning,
 }
 
 impl Default for InvertedIndexBuilder {
@@ -23,11 +71,19 @@ impl InvertedIndexBuilder {
         InvertedIndexBuilder {
             posting_builders: Vec::new(),
             vector_count: 0,
+            pruning: IndexPruning::default(),
         }
     }
 
+    /// Prune vectors before adding them to the posting lists
+    pub fn with_pruning(mut self, pruning: IndexPruning) -> InvertedIndexBuilder {
+        self.pruning = pruning;
+        self
+    }
+
     /// Add a vector to the inverted index builder
     pub fn add(&mut self, id: PointOffsetType, vector: RemappedSparseVector) {
+        let vector = self.pruning.prune(vector);
         for (dim_id, weight) in vector.indices.into_iter().zip(vector.values.into_iter()) {
             let dim_id = dim_id as usize;
             self.posting_builders.resize_with(
@@ -64,3 +120,58 @@ impl InvertedIndexBuilder {
         builder.build()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vector(indices: Vec<u32>, values: Vec<f32>) -> RemappedSparseVector {
+        RemappedSparseVector { indices, values }
+    }
+
+    #[test]
+    fn test_pruning_top_k() {
+        let pruning = IndexPruning {
+            top_k: Some(2),
+            threshold: None,
+        };
+        let pruned = pruning.prune(vector(vec![1, 2, 3, 4], vec![0.1, -0.9, 0.3, 0.5]));
+        assert_eq!(pruned, vector(vec![2, 4], vec![-0.9, 0.5]));
+
+        let short = vector(vec![1], vec![0.1]);
+        assert_eq!(pruning.prune(short.clone()), short);
+    }
+
+    #[test]
+    fn test_pruning_threshold() {
+        let pruning = IndexPruning {
+            top_k: Some(2),
+            threshold: Some(0.4),
+        };
+        let pruned = pruning.prune(vector(vec![1, 2, 3, 4], vec![0.1, -0.9, 0.3, 0.5]));
+        assert_eq!(pruned, vector(vec![2, 4], vec![-0.9, 0.5]));
+
+        let pruning = IndexPruning {
+            top_k: None,
+            threshold: Some(0.6),
+        };
+        let pruned = pruning.prune(vector(vec![1, 2, 3, 4], vec![0.1, -0.9, 0.3, 0.5]));
+        assert_eq!(pruned, vector(vec![2], vec![-0.9]));
+    }
+
+    #[test]
+    fn test_builder_prunes_postings() {
+        let pruning = IndexPruning {
+            top_k: Some(1),
+            threshold: None,
+        };
+        let mut builder = InvertedIndexBuilder::new().with_pruning(pruning);
+        builder.add(0, vector(vec![0, 1], vec![1.0, 2.0]));
+        builder.add(1, vector(vec![0, 1], vec![3.0, 2.0]));
+        let index = builder.build();
+
+        assert_eq!(index.vector_count, 2);
+        assert_eq!(index.postings[0].elements.len(), 1);
+        assert_eq!(index.postings[1].elements.len(), 1);
+    }
+}
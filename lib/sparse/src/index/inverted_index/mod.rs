@@ -3,15 +3,20 @@ use std::path::{Path, PathBuf};
 use common::types::PointOffsetType;
 
 use crate::common::sparse_vector::RemappedSparseVector;
-use crate::common::types::DimOffset;
+use crate::common::types::{DimOffset, WeightType};
 use crate::index::inverted_index::inverted_index_ram::InvertedIndexRam;
-use crate::index::posting_list::PostingListIterator;
+use crate::index::posting_list::PostingListIter;
 
+pub mod inverted_index_immutable_ram;
 pub mod inverted_index_mmap;
 pub mod inverted_index_ram;
 pub mod inverted_index_ram_builder;
 
 pub trait InvertedIndex: Sized {
+    type Iter<'a>: PostingListIter
+    where
+        Self: 'a;
+
     /// Open existing index based on path
     fn open(path: &Path) -> std::io::Result<Self>;
 
@@ -19,7 +24,7 @@ pub trait InvertedIndex: Sized {
     fn save(&self, path: &Path) -> std::io::Result<()>;
 
     /// Get posting list for dimension id
-    fn get(&self, id: &DimOffset) -> Option<PostingListIterator>;
+    fn get<'a>(&'a self, id: &DimOffset) -> Option<Self::Iter<'a>>;
 
     /// Get number of posting lists
     fn len(&self) -> usize;
@@ -39,9 +44,12 @@ pub trait InvertedIndex: Sized {
     fn upsert(&mut self, id: PointOffsetType, vector: RemappedSparseVector);
 
     /// Create inverted index from ram index
+    ///
+    /// Immutable indexes store weights with the given type, mutable indexes keep them as is.
     fn from_ram_index<P: AsRef<Path>>(
         ram_index: InvertedIndexRam,
         path: P,
+        weight_type: WeightType,
     ) -> std::io::Result<Self>;
 
     /// Number of indexed vectors
@@ -49,4 +57,7 @@ pub trait InvertedIndex: Sized {
 
     // Get max existed index
     fn max_index(&self) -> Option<DimOffset>;
+
+    /// Approximate size of the index data kept in RAM, memory-mapped data is not included
+    fn ram_usage_bytes(&self) -> usize;
 }
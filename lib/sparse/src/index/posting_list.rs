@@ -5,6 +5,38 @@ use ordered_float::OrderedFloat;
 
 use crate::common::types::DimWeight;
 
+/// Iterator over posting list elements, independent of the posting list format.
+pub trait PostingListIter {
+    /// Returns the next element without advancing the iterator.
+    fn peek(&self) -> Option<PostingElement>;
+
+    /// Returns the record id of the last element of the posting list.
+    fn last_id(&self) -> Option<PointOffsetType>;
+
+    /// Tries to find the element with ID == id and returns it.
+    /// If the element is not found, the iterator is advanced to the next element with ID > id
+    /// and None is returned.
+    fn skip_to(&mut self, id: PointOffsetType) -> Option<PostingElement>;
+
+    /// Skips to the end of the posting list.
+    fn skip_to_end(&mut self);
+
+    /// Returns the number of elements from the current position to the end of the list.
+    fn len_to_end(&self) -> usize;
+
+    /// Returns the position of the iterator in the posting list.
+    fn current_index(&self) -> usize;
+
+    /// Calls `f` with the record id and weight of each next element with ID <= id
+    /// and advances the iterator past them.
+    fn for_each_till_id(&mut self, id: PointOffsetType, f: impl FnMut(PointOffsetType, DimWeight));
+
+    /// Returns lower and upper bounds of the weights of the next elements with ID <= id.
+    /// Bounds may be loose, unknown bounds are infinite.
+    /// If there are no such elements, the lower bound is greater than the upper bound.
+    fn weight_bounds_till_id(&self, id: PointOffsetType) -> (DimWeight, DimWeight);
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct PostingElement {
     /// Record ID
@@ -248,6 +280,59 @@ impl<'a> PostingListIterator<'a> {
     }
 }
 
+impl<'a> PostingListIter for PostingListIterator<'a> {
+    fn peek(&self) -> Option<PostingElement> {
+        PostingListIterator::peek(self).cloned()
+    }
+
+    fn last_id(&self) -> Option<PointOffsetType> {
+        self.elements.last().map(|element| element.record_id)
+    }
+
+    fn skip_to(&mut self, id: PointOffsetType) -> Option<PostingElement> {
+        PostingListIterator::skip_to(self, id).cloned()
+    }
+
+    fn skip_to_end(&mut self) {
+        PostingListIterator::skip_to_end(self);
+    }
+
+    fn len_to_end(&self) -> usize {
+        PostingListIterator::len_to_end(self)
+    }
+
+    fn current_index(&self) -> usize {
+        self.current_index
+    }
+
+    fn for_each_till_id(
+        &mut self,
+        id: PointOffsetType,
+        mut f: impl FnMut(PointOffsetType, DimWeight),
+    ) {
+        let mut current_index = self.current_index;
+        for element in self.remaining_elements() {
+            if element.record_id > id {
+                break;
+            }
+            f(element.record_id, element.weight);
+            current_index += 1;
+        }
+        self.current_index = current_index;
+    }
+
+    fn weight_bounds_till_id(&self, id: PointOffsetType) -> (DimWeight, DimWeight) {
+        match PostingListIterator::peek(self) {
+            // only the max weight of the remaining elements is tracked
+            Some(element) if element.record_id <= id => (
+                DimWeight::NEG_INFINITY,
+                element.weight.max(element.max_next_weight),
+            ),
+            _ => (DimWeight::INFINITY, DimWeight::NEG_INFINITY),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
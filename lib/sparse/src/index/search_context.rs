@@ -1,4 +1,4 @@
-use std::cmp::{max, min, Ordering};
+use std::cmp::{max, min};
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering::Relaxed;
 
@@ -9,20 +9,42 @@ use crate::common::scores_memory_pool::PooledScoresHandle;
 use crate::common::sparse_vector::RemappedSparseVector;
 use crate::common::types::{DimId, DimWeight};
 use crate::index::inverted_index::InvertedIndex;
-use crate::index::posting_list::PostingListIterator;
+use crate::index::posting_list::PostingListIter;
 
 /// Iterator over posting lists with a reference to the corresponding query index and weight
-pub struct IndexedPostingListIterator<'a> {
-    posting_list_iterator: PostingListIterator<'a>,
+pub struct IndexedPostingListIterator<T: PostingListIter> {
+    posting_list_iterator: T,
     query_index: DimId,
     query_weight: DimWeight,
+    /// Upper bound of the score contribution within the current batch
+    max_contribution: DimWeight,
+}
+
+impl<T: PostingListIter> IndexedPostingListIterator<T> {
+    /// Upper bound of the score contribution of the next elements with ID <= id.
+    ///
+    /// Negative query weights are bounded by the min weight of the posting list, so lists without
+    /// a known lower bound are never pruned for them.
+    /// Never negative, as ids missing from the posting list do not contribute to the score.
+    fn max_contribution_till_id(&self, id: PointOffsetType) -> DimWeight {
+        let (min_weight, max_weight) = self.posting_list_iterator.weight_bounds_till_id(id);
+        if min_weight > max_weight || self.query_weight == 0.0 {
+            return 0.0;
+        }
+        let max_contribution = if self.query_weight > 0.0 {
+            max_weight * self.query_weight
+        } else {
+            min_weight * self.query_weight
+        };
+        max_contribution.max(0.0)
+    }
 }
 
 /// Making this larger makes the search faster but uses more (pooled) memory
 const ADVANCE_BATCH_SIZE: usize = 10_000;
 
-pub struct SearchContext<'a, 'b> {
-    postings_iterators: Vec<IndexedPostingListIterator<'a>>,
+pub struct SearchContext<'a, 'b, T: PostingListIter> {
+    postings_iterators: Vec<IndexedPostingListIterator<T>>,
     query: RemappedSparseVector,
     top: usize,
     is_stopped: &'a AtomicBool,
@@ -30,17 +52,16 @@ pub struct SearchContext<'a, 'b> {
     min_record_id: Option<PointOffsetType>, // min_record_id ids across all posting lists
     max_record_id: PointOffsetType,         // max_record_id ids across all posting lists
     pooled: PooledScoresHandle<'b>,         // handle to pooled scores
-    use_pruning: bool,
 }
 
-impl<'a, 'b> SearchContext<'a, 'b> {
+impl<'a, 'b, T: PostingListIter> SearchContext<'a, 'b, T> {
     pub fn new(
         query: RemappedSparseVector,
         top: usize,
-        inverted_index: &'a impl InvertedIndex,
+        inverted_index: &'a impl InvertedIndex<Iter<'a> = T>,
         pooled: PooledScoresHandle<'b>,
         is_stopped: &'a AtomicBool,
-    ) -> SearchContext<'a, 'b> {
+    ) -> SearchContext<'a, 'b, T> {
         let mut postings_iterators = Vec::new();
         // track min and max record ids across all posting lists
         let mut max_record_id = 0;
@@ -48,15 +69,15 @@ impl<'a, 'b> SearchContext<'a, 'b> {
         // iterate over query indices
         for (query_weight_offset, id) in query.indices.iter().enumerate() {
             if let Some(posting_list_iterator) = inverted_index.get(id) {
-                let posting_elements = posting_list_iterator.elements;
-                if !posting_elements.is_empty() {
+                if let (Some(first_element), Some(last_record_id)) = (
+                    posting_list_iterator.peek(),
+                    posting_list_iterator.last_id(),
+                ) {
                     // check if new min
-                    let min_record_id_posting = posting_elements[0].record_id;
-                    min_record_id = min(min_record_id, min_record_id_posting);
+                    min_record_id = min(min_record_id, first_element.record_id);
 
                     // check if new max
-                    let max_record_id_posting = posting_elements.last().unwrap().record_id;
-                    max_record_id = max(max_record_id, max_record_id_posting);
+                    max_record_id = max(max_record_id, last_record_id);
 
                     // capture query info
                     let query_index = *id;
@@ -66,15 +87,12 @@ impl<'a, 'b> SearchContext<'a, 'b> {
                         posting_list_iterator,
                         query_index,
                         query_weight,
+                        max_contribution: DimWeight::INFINITY,
                     });
                 }
             }
         }
         let top_results = TopK::new(top);
-        // Query vectors with negative values can NOT use the pruning mechanism which relies on the pre-computed `max_next_weight`.
-        // The max contribution per posting list that we calculate is not made to compute the max value of two negative numbers.
-        // This is a limitation of the current pruning implementation.
-        let use_pruning = query.values.iter().all(|v| *v >= 0.0);
         let min_record_id = Some(min_record_id);
         SearchContext {
             postings_iterators,
@@ -85,7 +103,6 @@ impl<'a, 'b> SearchContext<'a, 'b> {
             min_record_id,
             max_record_id,
             pooled,
-            use_pruning,
         }
     }
 
@@ -126,6 +143,32 @@ impl<'a, 'b> SearchContext<'a, 'b> {
         top.into_vec()
     }
 
+    /// Reorders posting lists by their max contribution to the scores of the batch and returns the
+    /// number of leading non-essential posting lists.
+    ///
+    /// Ids present only in non-essential posting lists can not score above `min_score` (MaxScore),
+    /// so these lists are only probed for the ids found in the essential ones.
+    fn split_non_essential_postings(
+        &mut self,
+        batch_last_id: PointOffsetType,
+        min_score: f32,
+    ) -> usize {
+        for posting in self.postings_iterators.iter_mut() {
+            posting.max_contribution = posting.max_contribution_till_id(batch_last_id);
+        }
+        self.postings_iterators
+            .sort_unstable_by(|a, b| a.max_contribution.total_cmp(&b.max_contribution));
+
+        let mut non_essential_max_score = 0.0;
+        self.postings_iterators
+            .iter()
+            .take_while(|posting| {
+                non_essential_max_score += posting.max_contribution;
+                non_essential_max_score <= min_score
+            })
+            .count()
+    }
+
     /// Advance posting lists iterators in a batch fashion.
     fn advance_batch<F: Fn(PointOffsetType) -> bool>(
         &mut self,
@@ -138,37 +181,58 @@ impl<'a, 'b> SearchContext<'a, 'b> {
         self.pooled.scores.clear(); // keep underlying allocated memory
         self.pooled.scores.resize(batch_len as usize, 0.0);
 
-        for posting in self.postings_iterators.iter_mut() {
-            // offset at which the posting list stops contributing to the batch (relative to the batch start)
-            let mut posting_stopped_at = None;
-            for (offset, element) in posting
+        // the threshold is a lower bound of the score to beat until enough results are gathered
+        let min_score = self.top_results.threshold();
+        let non_essential_count = self.split_non_essential_postings(batch_last_id, min_score);
+        let (non_essential, essential) = self.postings_iterators.split_at_mut(non_essential_count);
+        let scores = &mut self.pooled.scores;
+
+        for posting in essential.iter_mut() {
+            let query_weight = posting.query_weight;
+            // score elements up to the end of the batch and advance posting list iterator past them
+            posting
                 .posting_list_iterator
-                .remaining_elements()
+                .for_each_till_id(batch_last_id, |element_id, weight| {
+                    let element_score = weight * query_weight;
+                    // update score for id
+                    let local_id = (element_id - batch_start_id) as usize;
+                    scores[local_id] += element_score;
+                });
+        }
+
+        if !non_essential.is_empty() {
+            let non_essential_max_score: DimWeight = non_essential
                 .iter()
-                .enumerate()
-            {
-                let element_id = element.record_id;
-                if element_id > batch_last_id {
-                    // reaching end of the batch
-                    posting_stopped_at = Some(offset);
-                    break;
+                .map(|posting| posting.max_contribution)
+                .sum();
+            for (local_index, score) in scores.iter_mut().enumerate() {
+                // ids missing from essential posting lists can not beat the min score
+                if *score == 0.0 {
+                    continue;
                 }
-                let element_score = element.weight * posting.query_weight;
-                // update score for id
-                let local_id = (element_id - batch_start_id) as usize;
-                self.pooled.scores[local_id] += element_score;
-            }
-            // advance posting list iterator
-            match posting_stopped_at {
-                None => {
-                    // posting list is exhausted before reaching the end of the batch
-                    posting.posting_list_iterator.skip_to_end();
+                let id = batch_start_id + local_index as PointOffsetType;
+                // probe the posting lists with the largest contribution first
+                let mut max_remaining_score = non_essential_max_score;
+                for posting in non_essential.iter_mut().rev() {
+                    if *score + max_remaining_score <= min_score {
+                        break;
+                    }
+                    if let Some(element) = posting.posting_list_iterator.skip_to(id) {
+                        *score += element.weight * posting.query_weight;
+                    }
+                    max_remaining_score -= posting.max_contribution;
                 }
-                Some(stopped_at) => {
-                    // posting list is not exhausted - advance to last id
-                    posting.posting_list_iterator.advance_by(stopped_at)
+            }
+
+            // skip the remaining elements of the batch in non-essential posting lists
+            for posting in non_essential.iter_mut() {
+                match batch_last_id.checked_add(1) {
+                    Some(next_id) => {
+                        posting.posting_list_iterator.skip_to(next_id);
+                    }
+                    None => posting.posting_list_iterator.skip_to_end(),
                 }
-            };
+            }
         }
 
         for (local_index, &score) in self.pooled.scores.iter().enumerate() {
@@ -191,24 +255,29 @@ impl<'a, 'b> SearchContext<'a, 'b> {
     /// Compute scores for the last posting list quickly
     fn process_last_posting_list<F: Fn(PointOffsetType) -> bool>(&mut self, filter_condition: &F) {
         debug_assert_eq!(self.postings_iterators.len(), 1);
-        let posting = &self.postings_iterators[0];
-        for element in posting.posting_list_iterator.remaining_elements() {
-            // do not score if filter condition is not satisfied
-            if !filter_condition(element.record_id) {
-                continue;
-            }
-            let score = element.weight * posting.query_weight;
-            self.top_results.push(ScoredPointOffset {
-                score,
-                idx: element.record_id,
-            });
-        }
+        let posting = &mut self.postings_iterators[0];
+        let query_weight = posting.query_weight;
+        let top_results = &mut self.top_results;
+        posting.posting_list_iterator.for_each_till_id(
+            PointOffsetType::MAX,
+            |record_id, weight| {
+                // do not score if filter condition is not satisfied
+                if !filter_condition(record_id) {
+                    return;
+                }
+                let score = weight * query_weight;
+                top_results.push(ScoredPointOffset {
+                    score,
+                    idx: record_id,
+                });
+            },
+        );
     }
 
     /// Returns the next min record id from all posting list iterators
     ///
     /// returns None if all posting list iterators are exhausted
-    fn next_min_id(to_inspect: &[IndexedPostingListIterator<'_>]) -> Option<PointOffsetType> {
+    fn next_min_id(to_inspect: &[IndexedPostingListIterator<T>]) -> Option<PointOffsetType> {
         let mut min_record_id = None;
 
         // Iterate to find min record id at the head of the posting lists
@@ -229,29 +298,6 @@ impl<'a, 'b> SearchContext<'a, 'b> {
         min_record_id
     }
 
-    /// Make sure the longest posting list is at the head of the posting list iterators
-    fn promote_longest_posting_lists_to_the_front(&mut self) {
-        // find index of longest posting list
-        let posting_index = self
-            .postings_iterators
-            .iter()
-            .enumerate()
-            .max_by(|(_, a), (_, b)| {
-                a.posting_list_iterator
-                    .len_to_end()
-                    .cmp(&b.posting_list_iterator.len_to_end())
-            })
-            .map(|(index, _)| index);
-
-        if let Some(posting_index) = posting_index {
-            // make sure it is not already at the head
-            if posting_index != 0 {
-                // swap longest posting list to the head
-                self.postings_iterators.swap(0, posting_index);
-            }
-        }
-    }
-
     /// Search for the top k results that satisfy the filter condition
     pub fn search<F: Fn(PointOffsetType) -> bool>(
         &mut self,
@@ -260,7 +306,6 @@ impl<'a, 'b> SearchContext<'a, 'b> {
         if self.postings_iterators.is_empty() {
             return Vec::new();
         }
-        let mut best_min_score = f32::MIN;
         loop {
             // check for cancellation (atomic amortized by batch)
             if self.is_stopped.load(Relaxed) {
@@ -295,113 +340,38 @@ impl<'a, 'b> SearchContext<'a, 'b> {
                 break;
             }
 
-            // if only one posting list left, we can score it quickly
+            // if only one posting list left, we can score it quickly or skip it entirely
             if self.postings_iterators.len() == 1 {
-                self.process_last_posting_list(filter_condition);
-                break;
-            }
-
-            // we potentially have enough results to prune low performing posting lists
-            if self.use_pruning && self.top_results.len() >= self.top {
-                // current min score
-                let new_min_score = self.top_results.threshold();
-                if new_min_score == best_min_score {
-                    // no improvement in lowest best score since last pruning - skip pruning
-                    continue;
-                } else {
-                    best_min_score = new_min_score;
-                }
-                // make sure the first posting list is the longest for pruning
-                self.promote_longest_posting_lists_to_the_front();
-
-                // prune posting list that cannot possibly contribute to the top results
-                let pruned = self.prune_longest_posting_list(new_min_score);
-                if pruned {
-                    // update min_record_id
-                    self.min_record_id = Self::next_min_id(&self.postings_iterators);
+                let max_contribution =
+                    self.postings_iterators[0].max_contribution_till_id(PointOffsetType::MAX);
+                if max_contribution > self.top_results.threshold() {
+                    self.process_last_posting_list(filter_condition);
                 }
+                break;
             }
         }
         // posting iterators exhausted, return result queue
         let queue = std::mem::take(&mut self.top_results);
         queue.into_vec()
     }
-
-    /// Prune posting lists that cannot possibly contribute to the top results
-    /// Assumes longest posting list is at the head of the posting list iterators
-    /// Returns true if the longest posting list was pruned
-    pub fn prune_longest_posting_list(&mut self, min_score: f32) -> bool {
-        if self.postings_iterators.is_empty() {
-            return false;
-        }
-        // peek first element of longest posting list
-        let longest_posting_iterator = &self.postings_iterators[0];
-        if let Some(element) = longest_posting_iterator.posting_list_iterator.peek() {
-            let next_min_id_in_others = Self::next_min_id(&self.postings_iterators[1..]);
-            match next_min_id_in_others {
-                Some(next_min_id) => {
-                    match next_min_id.cmp(&element.record_id) {
-                        Ordering::Equal => {
-                            // if the next min id in the other posting lists is the same as the current one,
-                            // we can't prune the current element as it needs to be scored properly across posting lists
-                            return false;
-                        }
-                        Ordering::Less => {
-                            // we can't prune as there the other posting lists contains smaller smaller ids that need to scored first
-                            return false;
-                        }
-                        Ordering::Greater => {
-                            // next_min_id is > element.record_id there is a chance to prune up to `next_min_id`
-                            // check against the max possible score using the `max_next_weight`
-                            // we can under prune as we should actually check the best score up to `next_min_id` - 1 only
-                            // instead of the max possible score but it is not possible to know the best score up to `next_min_id` - 1
-                            let max_weight_from_list = element.weight.max(element.max_next_weight);
-                            let max_score_contribution =
-                                max_weight_from_list * longest_posting_iterator.query_weight;
-                            if max_score_contribution <= min_score {
-                                // prune to next_min_id
-                                let longest_posting_iterator =
-                                    &mut self.postings_iterators[0].posting_list_iterator;
-                                let position_before_pruning =
-                                    longest_posting_iterator.current_index;
-                                longest_posting_iterator.skip_to(next_min_id);
-                                let position_after_pruning = longest_posting_iterator.current_index;
-                                // check if pruning took place
-                                return position_before_pruning != position_after_pruning;
-                            }
-                        }
-                    }
-                }
-                None => {
-                    // the current posting list is the only one left, we can potentially skip it to the end
-                    // check against the max possible score using the `max_next_weight`
-                    let max_weight_from_list = element.weight.max(element.max_next_weight);
-                    let max_score_contribution =
-                        max_weight_from_list * longest_posting_iterator.query_weight;
-                    if max_score_contribution <= min_score {
-                        // prune to the end!
-                        let longest_posting_iterator = &mut self.postings_iterators[0];
-                        longest_posting_iterator.posting_list_iterator.skip_to_end();
-                        return true;
-                    }
-                }
-            }
-        }
-        // no pruning took place
-        false
-    }
 }
 
 #[cfg(test)]
 mod tests {
     use std::sync::OnceLock;
 
-    use rand::Rng;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
 
     use super::*;
     use crate::common::scores_memory_pool::ScoresMemoryPool;
     use crate::common::sparse_vector::SparseVector;
-    use crate::common::sparse_vector_fixture::random_sparse_vector;
+    use crate::common::sparse_vector_fixture::{
+        random_full_sparse_vector, random_positive_sparse_vector, random_sparse_vector,
+    };
+    use crate::common::types::WeightType;
+    use crate::index::compressed_posting_list::CHUNK_LEN;
+    use crate::index::inverted_index::inverted_index_immutable_ram::InvertedIndexImmutableRam;
     use crate::index::inverted_index::inverted_index_mmap::InvertedIndexMmap;
     use crate::index::inverted_index::inverted_index_ram::InvertedIndexRam;
     use crate::index::inverted_index::inverted_index_ram_builder::InvertedIndexBuilder;
@@ -481,9 +451,22 @@ mod tests {
             .prefix("test_index_dir")
             .tempdir()
             .unwrap();
-        let inverted_index_mmap =
-            InvertedIndexMmap::convert_and_save(&inverted_index_ram, &tmp_dir_path).unwrap();
+        let inverted_index_mmap = InvertedIndexMmap::convert_and_save(
+            &inverted_index_ram,
+            &tmp_dir_path,
+            WeightType::Float32,
+        )
+        .unwrap();
         _search_test(&inverted_index_mmap);
+
+        // test with compressed immutable ram index
+        let inverted_index_immutable_ram = InvertedIndexImmutableRam::from_ram_index(
+            inverted_index_ram,
+            &tmp_dir_path,
+            WeightType::Float32,
+        )
+        .unwrap();
+        _search_test(&inverted_index_immutable_ram);
     }
 
     #[test]
@@ -650,13 +633,26 @@ mod tests {
             .prefix("test_index_dir")
             .tempdir()
             .unwrap();
-        let inverted_index_mmap =
-            InvertedIndexMmap::convert_and_save(&inverted_index_ram, &tmp_dir_path).unwrap();
+        let inverted_index_mmap = InvertedIndexMmap::convert_and_save(
+            &inverted_index_ram,
+            &tmp_dir_path,
+            WeightType::Float32,
+        )
+        .unwrap();
         _search_with_hot_key_test(&inverted_index_mmap);
+
+        // test with compressed immutable ram index
+        let inverted_index_immutable_ram = InvertedIndexImmutableRam::from_ram_index(
+            inverted_index_ram,
+            &tmp_dir_path,
+            WeightType::Float32,
+        )
+        .unwrap();
+        _search_with_hot_key_test(&inverted_index_immutable_ram);
     }
 
     #[test]
-    fn pruning_single_to_end_test() {
+    fn pruning_single_non_essential_test() {
         let mut builder = InvertedIndexBuilder::new();
         builder.add(1, [(1, 10.0)].into());
         builder.add(2, [(1, 20.0)].into());
@@ -675,19 +671,14 @@ mod tests {
             &is_stopped,
         );
 
-        // assuming we have gathered enough results and want to prune the longest posting list
-        assert!(search_context.prune_longest_posting_list(30.0));
-        // the longest posting list was pruned to the end
-        assert_eq!(
-            search_context.postings_iterators[0]
-                .posting_list_iterator
-                .len_to_end(),
-            0
-        );
+        // assuming we have gathered enough results, the posting list can not beat the min score
+        assert_eq!(search_context.split_non_essential_postings(3, 30.0), 1);
+        // but it can beat a lower one
+        assert_eq!(search_context.split_non_essential_postings(3, 29.0), 0);
     }
 
     #[test]
-    fn pruning_multi_to_end_test() {
+    fn pruning_multi_non_essential_test() {
         let mut builder = InvertedIndexBuilder::new();
         builder.add(1, [(1, 10.0)].into());
         builder.add(2, [(1, 20.0)].into());
@@ -709,52 +700,108 @@ mod tests {
             &is_stopped,
         );
 
-        // assuming we have gathered enough results and want to prune the longest posting list
-        assert!(search_context.prune_longest_posting_list(30.0));
-        // the longest posting list was pruned to the end
-        assert_eq!(
-            search_context.postings_iterators[0]
-                .posting_list_iterator
-                .len_to_end(),
-            0
-        );
+        // max contributions are 30.0 for all posting lists, only one fits under the min score
+        assert_eq!(search_context.split_non_essential_postings(7, 30.0), 1);
+        assert_eq!(search_context.split_non_essential_postings(7, 60.0), 2);
+        assert_eq!(search_context.split_non_essential_postings(7, 90.0), 3);
+
+        // posting lists are sorted by max contribution within the batch
+        // only the first posting list has elements up to id 3
+        assert_eq!(search_context.split_non_essential_postings(3, 0.0), 2);
+        assert_eq!(search_context.postings_iterators[2].query_index, 1);
     }
 
     #[test]
-    fn pruning_multi_under_prune_test() {
+    fn pruning_block_max_test() {
         let mut builder = InvertedIndexBuilder::new();
-        builder.add(1, [(1, 10.0)].into());
-        builder.add(2, [(1, 20.0)].into());
-        builder.add(3, [(1, 20.0)].into());
-        builder.add(4, [(1, 10.0)].into());
-        builder.add(5, [(3, 10.0)].into());
-        builder.add(6, [(1, 20.0), (2, 20.0), (3, 20.0)].into());
-        builder.add(7, [(1, 40.0), (2, 30.0), (3, 30.0)].into());
+        // the first block has low weights, the second one has a single high weight
+        for id in 0..CHUNK_LEN as PointOffsetType {
+            builder.add(id, [(1, 1.0)].into());
+        }
+        builder.add(CHUNK_LEN as PointOffsetType, [(1, 50.0)].into());
+        builder.add(CHUNK_LEN as PointOffsetType + 1, [(2, 10.0)].into());
         let inverted_index_ram = builder.build();
+        let tmp_dir_path = tempfile::Builder::new()
+            .prefix("test_index_dir")
+            .tempdir()
+            .unwrap();
+        let inverted_index = InvertedIndexImmutableRam::from_ram_index(
+            inverted_index_ram,
+            &tmp_dir_path,
+            WeightType::Float32,
+        )
+        .unwrap();
 
         let is_stopped = AtomicBool::new(false);
         let mut search_context = SearchContext::new(
             RemappedSparseVector {
-                indices: vec![1, 2, 3],
-                values: vec![1.0, 1.0, 1.0],
+                indices: vec![1, 2],
+                values: vec![1.0, 1.0],
             },
             1,
-            &inverted_index_ram,
+            &inverted_index,
             get_pooled_scores(),
             &is_stopped,
         );
 
-        // one would expect this to prune up to `6` but it does not happen it practice because we are under pruning by design
-        // we should actually check the best score up to `6` - 1 only instead of the max possible score (40.0)
-        assert!(!search_context.prune_longest_posting_list(30.0));
+        // within the first block, the first posting list can not beat the min score
+        assert_eq!(search_context.split_non_essential_postings(10, 5.0), 2);
+        // the block of the high weight is essential
+        assert_eq!(
+            search_context.split_non_essential_postings(CHUNK_LEN as PointOffsetType, 5.0),
+            1
+        );
+    }
 
-        assert!(search_context.prune_longest_posting_list(40.0));
-        // the longest posting list was pruned to the end
+    #[test]
+    fn pruning_negative_query_weight_test() {
+        let mut builder = InvertedIndexBuilder::new();
+        builder.add(1, [(1, 10.0)].into());
+        builder.add(2, [(1, -20.0)].into());
+        builder.add(3, [(1, 30.0)].into());
+        let inverted_index_ram = builder.build();
+        let tmp_dir_path = tempfile::Builder::new()
+            .prefix("test_index_dir")
+            .tempdir()
+            .unwrap();
+        let inverted_index_compressed = InvertedIndexImmutableRam::from_ram_index(
+            inverted_index_ram.clone(),
+            &tmp_dir_path,
+            WeightType::Float32,
+        )
+        .unwrap();
+        let query = RemappedSparseVector {
+            indices: vec![1],
+            values: vec![-1.0],
+        };
+
+        // the min weight of mutable posting lists is unknown, so they are never pruned
+        let is_stopped = AtomicBool::new(false);
+        let mut search_context = SearchContext::new(
+            query.clone(),
+            1,
+            &inverted_index_ram,
+            get_pooled_scores(),
+            &is_stopped,
+        );
+        assert_eq!(search_context.split_non_essential_postings(3, 1000.0), 0);
+
+        // compressed posting lists are bounded by their min weight
+        let mut search_context = SearchContext::new(
+            query,
+            1,
+            &inverted_index_compressed,
+            get_pooled_scores(),
+            &is_stopped,
+        );
+        assert_eq!(search_context.split_non_essential_postings(3, 20.0), 1);
+        assert_eq!(search_context.split_non_essential_postings(3, 19.0), 0);
         assert_eq!(
-            search_context.postings_iterators[0]
-                .posting_list_iterator
-                .len_to_end(),
-            2 // 6, 7
+            search_context.search(&match_all),
+            vec![ScoredPointOffset {
+                score: 20.0,
+                idx: 2
+            }]
         );
     }
 
@@ -776,40 +823,116 @@ mod tests {
     }
 
     #[test]
-    fn promote_longest_test() {
+    fn search_compressed_quantized_test() {
         let is_stopped = AtomicBool::new(false);
-        let mut builder = InvertedIndexBuilder::new();
-        builder.add(1, [(1, 10.0), (2, 10.0), (3, 10.0)].into());
-        builder.add(2, [(1, 20.0), (3, 20.0)].into());
-        builder.add(3, [(2, 30.0), (3, 30.0)].into());
-        let inverted_index_ram = builder.build();
-
-        let mut search_context = SearchContext::new(
-            RemappedSparseVector {
-                indices: vec![1, 2, 3],
-                values: vec![1.0, 1.0, 1.0],
-            },
-            3,
-            &inverted_index_ram,
-            get_pooled_scores(),
-            &is_stopped,
-        );
+        let mut rnd = StdRng::seed_from_u64(42);
+        let max_sparse_dimension = 200;
+        let inverted_index_ram = random_inverted_index(&mut rnd, 1_000, max_sparse_dimension);
+        let tmp_dir_path = tempfile::Builder::new()
+            .prefix("test_index_dir")
+            .tempdir()
+            .unwrap();
+        let inverted_index_f16 = InvertedIndexImmutableRam::from_ram_index(
+            inverted_index_ram.clone(),
+            &tmp_dir_path,
+            WeightType::Float16,
+        )
+        .unwrap();
+
+        for _ in 0..10 {
+            let SparseVector { indices, values } =
+                random_positive_sparse_vector(&mut rnd, max_sparse_dimension);
+            let query = RemappedSparseVector::new(indices, values).unwrap();
+            // weights within [-100, 100] are stored with at most 2^-11 of the chunk max error
+            let max_error = query.values.iter().sum::<f32>() * 100.0 / 1024.0;
+
+            let exact = SearchContext::new(
+                query.clone(),
+                10,
+                &inverted_index_ram,
+                get_pooled_scores(),
+                &is_stopped,
+            )
+            .search(&match_all);
+            let quantized = SearchContext::new(
+                query,
+                10,
+                &inverted_index_f16,
+                get_pooled_scores(),
+                &is_stopped,
+            )
+            .search(&match_all);
+
+            // the n-th best score moves no further than the error of a single score
+            assert_eq!(exact.len(), quantized.len());
+            for (exact, quantized) in exact.iter().zip(&quantized) {
+                assert!(
+                    (exact.score - quantized.score).abs() <= max_error,
+                    "{exact:?} vs {quantized:?}"
+                );
+            }
+        }
+    }
 
-        assert_eq!(
-            search_context.postings_iterators[0]
-                .posting_list_iterator
-                .len_to_end(),
-            2
-        );
+    fn _search_pruning_matches_plain_search_test(inverted_index: &impl InvertedIndex) {
+        let is_stopped = AtomicBool::new(false);
+        let mut rnd = StdRng::seed_from_u64(42);
+        let all_ids: Vec<_> = (1..=inverted_index.vector_count() as PointOffsetType).collect();
+
+        for _ in 0..10 {
+            // long queries with mixed signs
+            let SparseVector { indices, values } = random_full_sparse_vector(&mut rnd, 150);
+            let query = RemappedSparseVector::new(indices, values).unwrap();
+
+            let pruned = SearchContext::new(
+                query.clone(),
+                10,
+                inverted_index,
+                get_pooled_scores(),
+                &is_stopped,
+            )
+            .search(&match_all);
+            let plain =
+                SearchContext::new(query, 10, inverted_index, get_pooled_scores(), &is_stopped)
+                    .plain_search(&all_ids);
+
+            assert_eq!(pruned.len(), plain.len());
+            for (pruned, plain) in pruned.iter().zip(&plain) {
+                assert!(
+                    (pruned.score - plain.score).abs() <= plain.score.abs() * 1e-4,
+                    "{pruned:?} vs {plain:?}"
+                );
+            }
+        }
+    }
 
-        search_context.promote_longest_posting_lists_to_the_front();
+    #[test]
+    fn search_pruning_matches_plain_search_test() {
+        let mut rnd = StdRng::seed_from_u64(42);
+        let inverted_index_ram = random_inverted_index(&mut rnd, 5_000, 200);
+        _search_pruning_matches_plain_search_test(&inverted_index_ram);
 
-        assert_eq!(
-            search_context.postings_iterators[0]
-                .posting_list_iterator
-                .len_to_end(),
-            3
-        );
+        let tmp_dir_path = tempfile::Builder::new()
+            .prefix("test_index_dir")
+            .tempdir()
+            .unwrap();
+        let inverted_index_mmap = InvertedIndexMmap::convert_and_save(
+            &inverted_index_ram,
+            &tmp_dir_path,
+            WeightType::Float32,
+        )
+        .unwrap();
+        _search_pruning_matches_plain_search_test(&inverted_index_mmap);
+
+        for weight_type in [WeightType::Float32, WeightType::Uint8] {
+            let inverted_index_immutable_ram = InvertedIndexImmutableRam::from_ram_index(
+                inverted_index_ram.clone(),
+                &tmp_dir_path,
+                weight_type,
+            )
+            .unwrap();
+            _search_pruning_matches_plain_search_test(&inverted_index_immutable_ram);
+        }
     }
 
     #[test]
@@ -1,4 +1,6 @@
-use collection::operations::conversions::sharding_method_from_proto;
+use collection::operations::conversions::{
+    payload_schema_enforcement_from_proto, sharding_method_from_proto,
+};
 use collection::operations::types::SparseVectorsConfig;
 use tonic::Status;
 
@@ -40,7 +42,14 @@ impl TryFrom<api::grpc::qdrant::CreateCollection> for CollectionMetaOperations {
                 },
                 sparse_vectors: value
                     .sparse_vectors_config
-                    .map(|config| config.map.into_iter().map(|(k, v)| (k, v.into())).collect()),
+                    .map(|config| {
+                        config
+                            .map
+                            .into_iter()
+                            .map(|(k, v)| Ok((k, v.try_into()?)))
+                            .collect::<Result<_, Status>>()
+                    })
+                    .transpose()?,
                 hnsw_config: value.hnsw_config.map(|v| v.into()),
                 wal_config: value.wal_config.map(|v| v.into()),
                 optimizers_config: value.optimizers_config.map(|v| v.into()),
@@ -59,6 +68,10 @@ impl TryFrom<api::grpc::qdrant::CreateCollection> for CollectionMetaOperations {
                     .sharding_method
                     .map(sharding_method_from_proto)
                     .transpose()?,
+                payload_schema_enforcement: value
+                    .payload_schema_enforcement
+                    .map(payload_schema_enforcement_from_proto)
+                    .transpose()?,
             },
         )))
     }
@@ -83,11 +96,18 @@ impl TryFrom<api::grpc::qdrant::UpdateCollection> for CollectionMetaOperations {
                     .quantization_config
                     .map(TryInto::try_into)
                     .transpose()?,
-                sparse_vectors: value.sparse_vectors_config.map(|config| {
-                    SparseVectorsConfig(
-                        config.map.into_iter().map(|(k, v)| (k, v.into())).collect(),
-                    )
-                }),
+                sparse_vectors: value
+                    .sparse_vectors_config
+                    .map(|config| -> Result<_, Status> {
+                        Ok(SparseVectorsConfig(
+                            config
+                                .map
+                                .into_iter()
+                                .map(|(k, v)| Ok((k, v.try_into()?)))
+                                .collect::<Result<_, Status>>()?,
+                        ))
+                    })
+                    .transpose()?,
             },
         )))
     }
@@ -38,6 +38,7 @@ impl TableOfContent {
             shard_number,
             sharding_method,
             on_disk_payload,
+            payload_schema_enforcement,
             hnsw_config: hnsw_config_diff,
             wal_config: wal_config_diff,
             optimizers_config: optimizers_config_diff,
@@ -125,6 +126,7 @@ impl TableOfContent {
                 },
             )?,
             read_fan_out_factor: None,
+            payload_schema_enforcement,
         };
         let wal_config = match wal_config_diff {
             None => self.storage_config.wal.clone(),
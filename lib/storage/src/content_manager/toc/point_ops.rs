@@ -1,21 +1,25 @@
+use std::collections::HashMap;
 use std::time::Duration;
 
 use collection::collection::Collection;
 use collection::grouping::group_by::GroupRequest;
 use collection::grouping::GroupBy;
 use collection::operations::consistency_params::ReadConsistency;
+use collection::operations::payload_stats::{PayloadStatsAccumulator, PayloadStatsRequest};
 use collection::operations::point_ops::WriteOrdering;
 use collection::operations::shard_selector_internal::ShardSelectorInternal;
+use collection::operations::sparse_stats::SparseStatsRequest;
 use collection::operations::types::*;
 use collection::operations::{CollectionUpdateOperations, OperationWithClockTag};
-use collection::{discovery, recommendations};
+use collection::{arithmetic, discovery, recommendations};
 use futures::stream::FuturesUnordered;
 use futures::TryStreamExt as _;
+use segment::index::sparse_index::sparse_search_telemetry::SparseIndexStatistics;
 use segment::types::{ScoredPoint, ShardKey};
 
 use super::TableOfContent;
 use crate::content_manager::errors::StorageError;
-use crate::rbac::Access;
+use crate::rbac::{Access, AccessRequirements};
 
 impl TableOfContent {
     /// Recommend points using positive and negative example from the request
@@ -128,6 +132,37 @@ impl TableOfContent {
             .map_err(|err| err.into())
     }
 
+    /// Search for the points with text most relevant to the query, using BM25 vectorizer of the sparse vector
+    ///
+    /// # Arguments
+    ///
+    /// * `collection_name` - in what collection do we search
+    /// * `request` - [`TextSearchRequestInternal`]
+    /// * `shard_selection` - which local shard to use
+    /// * `timeout` - how long to wait for the response
+    /// * `read_consistency` - consistency level
+    ///
+    /// # Result
+    ///
+    /// Points with search score
+    pub async fn search_text(
+        &self,
+        collection_name: &str,
+        mut request: TextSearchRequestInternal,
+        read_consistency: Option<ReadConsistency>,
+        shard_selection: ShardSelectorInternal,
+        access: Access,
+        timeout: Option<Duration>,
+    ) -> Result<Vec<ScoredPoint>, StorageError> {
+        let collection_pass = access.check_point_op(collection_name, &mut request)?;
+
+        let collection = self.get_collection(&collection_pass).await?;
+        collection
+            .search_text(request, read_consistency, &shard_selection, timeout)
+            .await
+            .map_err(|err| err.into())
+    }
+
     /// Count points in the collection.
     ///
     /// # Arguments
@@ -157,6 +192,91 @@ impl TableOfContent {
             .map_err(|err| err.into())
     }
 
+    /// Collect IDF statistics of sparse vector dimensions
+    ///
+    /// # Arguments
+    ///
+    /// * `collection_name` - in what collection do we collect statistics
+    /// * `request` - [`IdfStatisticsRequest`]
+    /// * `shard_selection` - which shards to use
+    ///
+    /// # Result
+    ///
+    /// Number of points and document frequency of each requested dimension
+    pub async fn idf_statistics(
+        &self,
+        collection_name: &str,
+        request: IdfStatisticsRequest,
+        shard_selection: ShardSelectorInternal,
+        access: Access,
+    ) -> Result<IdfStatistics, StorageError> {
+        // Statistics are aggregated over all points, so access to the whole collection is required
+        let collection_pass =
+            access.check_collection_access(collection_name, AccessRequirements::new().whole())?;
+
+        let collection = self.get_collection(&collection_pass).await?;
+        collection
+            .idf_statistics(request, &shard_selection)
+            .await
+            .map_err(|err| err.into())
+    }
+
+    /// Collect mergeable payload statistics of the selected shards
+    ///
+    /// # Arguments
+    ///
+    /// * `collection_name` - in what collection do we collect statistics
+    /// * `request` - [`PayloadStatsRequest`]
+    /// * `shard_selection` - which shards to use
+    ///
+    /// # Result
+    ///
+    /// Payload statistics which are not finalized yet and can be merged with other shards
+    pub async fn payload_stats_partial(
+        &self,
+        collection_name: &str,
+        request: PayloadStatsRequest,
+        shard_selection: ShardSelectorInternal,
+        access: Access,
+    ) -> Result<PayloadStatsAccumulator, StorageError> {
+        let collection_pass =
+            access.check_collection_access(collection_name, AccessRequirements::new().whole())?;
+
+        let collection = self.get_collection(&collection_pass).await?;
+        collection
+            .payload_stats_partial(request, &shard_selection)
+            .await
+            .map_err(|err| err.into())
+    }
+
+    /// Collect mergeable statistics of sparse vector indexes of the selected shards
+    ///
+    /// # Arguments
+    ///
+    /// * `collection_name` - in what collection do we collect statistics
+    /// * `request` - [`SparseStatsRequest`]
+    /// * `shard_selection` - which shards to use
+    ///
+    /// # Result
+    ///
+    /// Statistics of each sparse vector which are not summarized yet and can be merged with other shards
+    pub async fn sparse_stats_partial(
+        &self,
+        collection_name: &str,
+        request: SparseStatsRequest,
+        shard_selection: ShardSelectorInternal,
+        access: Access,
+    ) -> Result<HashMap<String, SparseIndexStatistics>, StorageError> {
+        let collection_pass =
+            access.check_collection_access(collection_name, AccessRequirements::new().whole())?;
+
+        let collection = self.get_collection(&collection_pass).await?;
+        collection
+            .sparse_stats_partial(request, &shard_selection)
+            .await
+            .map_err(|err| err.into())
+    }
+
     /// Return specific points by IDs
     ///
     /// # Arguments
@@ -265,6 +385,43 @@ impl TableOfContent {
         .map_err(|err| err.into())
     }
 
+    /// Search for the points closest to a weighted combination of vectors
+    ///
+    /// # Arguments
+    ///
+    /// * `collection_name` - in what collection do we search
+    /// * `request` - [`ArithmeticRequestInternal`]
+    /// * `shard_selector` - which shards to search in
+    /// * `timeout` - how long to wait for the response
+    /// * `read_consistency` - consistency level
+    ///
+    /// # Result
+    ///
+    /// Points with search score
+    pub async fn search_arithmetic(
+        &self,
+        collection_name: &str,
+        mut request: ArithmeticRequestInternal,
+        read_consistency: Option<ReadConsistency>,
+        shard_selector: ShardSelectorInternal,
+        access: Access,
+        timeout: Option<Duration>,
+    ) -> Result<Vec<ScoredPoint>, StorageError> {
+        let collection_pass = access.check_point_op(collection_name, &mut request)?;
+
+        let collection = self.get_collection(&collection_pass).await?;
+        arithmetic::search_arithmetic(
+            request,
+            &collection,
+            |name| self.get_collection_opt(name),
+            read_consistency,
+            shard_selector,
+            timeout,
+        )
+        .await
+        .map_err(|err| err.into())
+    }
+
     /// Paginate over all stored points with given filtering conditions
     ///
     /// # Arguments
@@ -7,9 +7,9 @@ use collection::lookup::WithLookup;
 use collection::operations::payload_ops::{DeletePayloadOp, PayloadOps, SetPayloadOp};
 use collection::operations::point_ops::{PointIdsList, PointOperations};
 use collection::operations::types::{
-    ContextExamplePair, CoreSearchRequest, CountRequestInternal, DiscoverRequestInternal,
-    LookupLocation, PointRequestInternal, RecommendExample, RecommendRequestInternal,
-    ScrollRequestInternal,
+    ArithmeticRequestInternal, ContextExamplePair, CoreSearchRequest, CountRequestInternal,
+    DiscoverRequestInternal, LookupLocation, PointRequestInternal, RecommendExample,
+    RecommendRequestInternal, ScrollRequestInternal, TextSearchRequestInternal, WeightedExample,
 };
 use collection::operations::vector_ops::VectorOperations;
 use collection::operations::CollectionUpdateOperations;
@@ -185,6 +185,25 @@ impl CheckableCollectionOperation for CoreSearchRequest {
     }
 }
 
+impl CheckableCollectionOperation for TextSearchRequestInternal {
+    fn access_requirements(&self) -> AccessRequirements {
+        AccessRequirements {
+            write: false,
+            manage: false,
+            whole: false,
+        }
+    }
+
+    fn check_access(
+        &mut self,
+        view: CollectionAccessView<'_>,
+        _access: &CollectionAccessList,
+    ) -> Result<(), StorageError> {
+        view.apply_filter(&mut self.filter);
+        Ok(())
+    }
+}
+
 impl CheckableCollectionOperation for CountRequestInternal {
     fn access_requirements(&self) -> AccessRequirements {
         AccessRequirements {
@@ -257,6 +276,29 @@ impl CheckableCollectionOperation for DiscoverRequestInternal {
     }
 }
 
+impl CheckableCollectionOperation for ArithmeticRequestInternal {
+    fn access_requirements(&self) -> AccessRequirements {
+        AccessRequirements {
+            write: false,
+            manage: false,
+            whole: false,
+        }
+    }
+
+    fn check_access(
+        &mut self,
+        view: CollectionAccessView<'_>,
+        access: &CollectionAccessList,
+    ) -> Result<(), StorageError> {
+        for WeightedExample { example, weight: _ } in &self.terms {
+            view.check_recommend_example(example)?;
+        }
+        view.apply_filter(&mut self.filter);
+        access.check_lookup_from(&self.lookup_from)?;
+        Ok(())
+    }
+}
+
 impl CheckableCollectionOperation for ScrollRequestInternal {
     fn access_requirements(&self) -> AccessRequirements {
         AccessRequirements {
@@ -746,6 +788,41 @@ mod tests_ops {
         );
     }
 
+    #[test]
+    fn test_text_search_request_internal() {
+        let op = TextSearchRequestInternal {
+            using: "bm25".to_string(),
+            text: "hello world".to_string(),
+            filter: None,
+            params: Some(SearchParams::default()),
+            limit: 100,
+            offset: Some(100),
+            with_payload: Some(WithPayloadInterface::Bool(true)),
+            with_vector: Some(WithVector::Bool(true)),
+            score_threshold: Some(42.0),
+        };
+
+        assert_allowed(&op, &Access::Global(GlobalAccessMode::Manage));
+        assert_allowed(&op, &Access::Global(GlobalAccessMode::Read));
+
+        assert_allowed(
+            &op,
+            &AccessCollectionBuilder::new()
+                .add("col", false, true)
+                .into(),
+        );
+
+        assert_allowed_rewrite(
+            &op,
+            &AccessCollectionBuilder::new()
+                .add("col", false, false)
+                .into(),
+            |op| {
+                op.filter = Some(PayloadConstraint::new_test("col").to_filter());
+            },
+        );
+    }
+
     #[test]
     fn test_count_request_internal() {
         let op = CountRequestInternal {
@@ -949,6 +1026,90 @@ mod tests_ops {
         );
     }
 
+    #[test]
+    fn test_arithmetic_request_internal() {
+        let op = ArithmeticRequestInternal {
+            terms: vec![
+                WeightedExample {
+                    example: RecommendExample::Dense(vec![0.0, 1.0, 2.0]),
+                    weight: 1.0,
+                },
+                WeightedExample {
+                    example: RecommendExample::Dense(vec![2.0, 1.0, 0.0]),
+                    weight: -0.5,
+                },
+            ],
+            average: Some(true),
+            filter: None,
+            params: Some(SearchParams::default()),
+            limit: 100,
+            offset: Some(100),
+            with_payload: Some(WithPayloadInterface::Bool(true)),
+            with_vector: Some(WithVector::Bool(true)),
+            score_threshold: Some(42.0),
+            using: Some(UsingVector::Name("vector".to_string())),
+            lookup_from: Some(LookupLocation {
+                collection: "col2".to_string(),
+                vector: Some("vector".to_string()),
+                shard_key: None,
+            }),
+        };
+
+        assert_allowed(&op, &Access::Global(GlobalAccessMode::Manage));
+        assert_allowed(&op, &Access::Global(GlobalAccessMode::Read));
+
+        assert_allowed(
+            &op,
+            &AccessCollectionBuilder::new()
+                .add("col", false, true)
+                .add("col2", false, true)
+                .into(),
+        );
+
+        assert_allowed_rewrite(
+            &op,
+            &AccessCollectionBuilder::new()
+                .add("col", false, false)
+                .add("col2", false, true)
+                .into(),
+            |op| {
+                op.filter = Some(PayloadConstraint::new_test("col").to_filter());
+            },
+        );
+
+        // Point ID is used
+        assert_forbidden(
+            &ArithmeticRequestInternal {
+                terms: vec![WeightedExample {
+                    example: RecommendExample::PointId(ExtendedPointId::NumId(12345)),
+                    weight: 1.0,
+                }],
+                ..op.clone()
+            },
+            &AccessCollectionBuilder::new()
+                .add("col", false, false)
+                .add("col2", false, true)
+                .into(),
+        );
+
+        // lookup_from requires read access
+        assert_forbidden(
+            &op,
+            &AccessCollectionBuilder::new()
+                .add("col", false, true)
+                .into(),
+        );
+        assert_allowed(
+            &ArithmeticRequestInternal {
+                lookup_from: None,
+                ..op.clone()
+            },
+            &AccessCollectionBuilder::new()
+                .add("col", false, true)
+                .into(),
+        );
+    }
+
     #[test]
     fn test_scroll_request_internal() {
         let op = ScrollRequestInternal {
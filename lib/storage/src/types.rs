@@ -82,6 +82,10 @@ pub struct StorageConfig {
     pub handle_collection_load_errors: bool,
     #[serde(default)]
     pub async_scorer: bool,
+    /// Max number of concurrent disk reads of a single async scorer.
+    /// If not set - `DEFAULT_ASYNC_QUEUE_DEPTH` is used.
+    #[serde(default)]
+    pub async_scorer_queue_depth: Option<usize>,
     /// If provided - qdrant will start in recovery mode, which means that it will not accept any new data.
     /// Only collection metadata will be available, and it will only process collection delete requests.
     /// Provided value will be used error message for unavailable requests.
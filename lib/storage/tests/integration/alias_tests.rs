@@ -64,6 +64,7 @@ fn test_alias_operation() {
         handle_collection_load_errors: false,
         recovery_mode: None,
         async_scorer: false,
+        async_scorer_queue_depth: None,
         update_concurrency: Some(NonZeroUsize::new(2).unwrap()),
         // update_concurrency: None,
         shard_transfer_method: None,
@@ -106,6 +107,7 @@ fn test_alias_operation() {
                         optimizers_config: None,
                         shard_number: Some(1),
                         on_disk_payload: None,
+                        payload_schema_enforcement: None,
                         replication_factor: None,
                         write_consistency_factor: None,
                         init_from: None,
@@ -5,6 +5,8 @@ use actix_web::rt::time::Instant;
 use actix_web::{delete, get, patch, post, put, web, HttpResponse, Responder};
 use actix_web_validator::{Json, Path, Query};
 use collection::operations::cluster_ops::ClusterOperations;
+use collection::operations::payload_stats::PayloadStatsRequest;
+use collection::operations::sparse_stats::SparseStatsRequest;
 use serde::Deserialize;
 use storage::content_manager::collection_meta_ops::{
     ChangeAliasesOperation, CollectionMetaOperations, CreateCollection, CreateCollectionOperation,
@@ -83,6 +85,44 @@ async fn get_collection_aliases(
     process_response(response, timing)
 }
 
+#[post("/collections/{name}/payload/stats")]
+async fn get_payload_stats(
+    dispatcher: web::Data<Dispatcher>,
+    collection: Path<CollectionPath>,
+    request: Json<PayloadStatsRequest>,
+    ActixAccess(access): ActixAccess,
+) -> impl Responder {
+    let timing = Instant::now();
+    let response = do_get_payload_stats(
+        dispatcher.toc(&access),
+        access,
+        &collection.name,
+        request.into_inner(),
+        None,
+    )
+    .await;
+    process_response(response, timing)
+}
+
+#[post("/collections/{name}/sparse/stats")]
+async fn get_sparse_stats(
+    dispatcher: web::Data<Dispatcher>,
+    collection: Path<CollectionPath>,
+    request: Json<SparseStatsRequest>,
+    ActixAccess(access): ActixAccess,
+) -> impl Responder {
+    let timing = Instant::now();
+    let response = do_get_sparse_stats(
+        dispatcher.toc(&access),
+        access,
+        &collection.name,
+        request.into_inner(),
+        None,
+    )
+    .await;
+    process_response(response, timing)
+}
+
 #[put("/collections/{name}")]
 async fn create_collection(
     dispatcher: web::Data<Dispatcher>,
@@ -212,6 +252,8 @@ pub fn config_collections_api(cfg: &mut web::ServiceConfig) {
         .service(delete_collection)
         .service(get_aliases)
         .service(get_collection_aliases)
+        .service(get_payload_stats)
+        .service(get_sparse_stats)
         .service(get_cluster_info)
         .service(update_collection_cluster);
 }
@@ -3,7 +3,8 @@ use actix_web::{post, web, Responder};
 use actix_web_validator::{Json, Path, Query};
 use collection::operations::shard_selector_internal::ShardSelectorInternal;
 use collection::operations::types::{
-    CoreSearchRequest, SearchGroupsRequest, SearchRequest, SearchRequestBatch,
+    ArithmeticRequest, CoreSearchRequest, SearchGroupsRequest, SearchRequest, SearchRequestBatch,
+    TextSearchRequest,
 };
 use itertools::Itertools;
 use storage::dispatcher::Dispatcher;
@@ -143,9 +144,93 @@ async fn search_point_groups(
     process_response(response, timing)
 }
 
+#[post("/collections/{name}/points/search/text")]
+async fn search_text_points(
+    dispatcher: web::Data<Dispatcher>,
+    collection: Path<CollectionPath>,
+    request: Json<TextSearchRequest>,
+    params: Query<ReadParams>,
+    ActixAccess(access): ActixAccess,
+) -> impl Responder {
+    let timing = Instant::now();
+
+    let TextSearchRequest {
+        text_search_request,
+        shard_key,
+    } = request.into_inner();
+
+    let shard_selection = match shard_key {
+        None => ShardSelectorInternal::All,
+        Some(shard_keys) => shard_keys.into(),
+    };
+
+    let response = dispatcher
+        .toc(&access)
+        .search_text(
+            &collection.name,
+            text_search_request,
+            params.consistency,
+            shard_selection,
+            access,
+            params.timeout(),
+        )
+        .await
+        .map(|scored_points| {
+            scored_points
+                .into_iter()
+                .map(api::rest::ScoredPoint::from)
+                .collect_vec()
+        });
+
+    process_response(response, timing)
+}
+
+#[post("/collections/{name}/points/search/arithmetic")]
+async fn search_arithmetic_points(
+    dispatcher: web::Data<Dispatcher>,
+    collection: Path<CollectionPath>,
+    request: Json<ArithmeticRequest>,
+    params: Query<ReadParams>,
+    ActixAccess(access): ActixAccess,
+) -> impl Responder {
+    let timing = Instant::now();
+
+    let ArithmeticRequest {
+        arithmetic_request,
+        shard_key,
+    } = request.into_inner();
+
+    let shard_selection = match shard_key {
+        None => ShardSelectorInternal::All,
+        Some(shard_keys) => shard_keys.into(),
+    };
+
+    let response = dispatcher
+        .toc(&access)
+        .search_arithmetic(
+            &collection.name,
+            arithmetic_request,
+            params.consistency,
+            shard_selection,
+            access,
+            params.timeout(),
+        )
+        .await
+        .map(|scored_points| {
+            scored_points
+                .into_iter()
+                .map(api::rest::ScoredPoint::from)
+                .collect_vec()
+        });
+
+    process_response(response, timing)
+}
+
 // Configure services
 pub fn config_search_api(cfg: &mut web::ServiceConfig) {
     cfg.service(search_points)
         .service(batch_search_points)
-        .service(search_point_groups);
+        .service(search_point_groups)
+        .service(search_text_points)
+        .service(search_arithmetic_points);
 }
@@ -8,8 +8,10 @@ use collection::operations::cluster_ops::{
     AbortTransferOperation, ClusterOperations, DropReplicaOperation, MoveShardOperation,
     ReplicateShardOperation, RestartTransfer, RestartTransferOperation,
 };
+use collection::operations::payload_stats::{PayloadStatsRequest, PayloadStatsResult};
 use collection::operations::shard_selector_internal::ShardSelectorInternal;
 use collection::operations::snapshot_ops::SnapshotDescription;
+use collection::operations::sparse_stats::{SparseStatsRequest, SparseStatsResult};
 use collection::operations::types::{
     AliasDescription, CollectionClusterInfo, CollectionInfo, CollectionsAliasesResponse,
 };
@@ -66,6 +68,46 @@ pub async fn do_get_collection(
     Ok(collection.info(&shard_selection).await?)
 }
 
+pub async fn do_get_payload_stats(
+    toc: &TableOfContent,
+    access: Access,
+    name: &str,
+    request: PayloadStatsRequest,
+    shard_selection: Option<ShardId>,
+) -> Result<PayloadStatsResult, StorageError> {
+    let collection_pass =
+        access.check_collection_access(name, AccessRequirements::new().whole())?;
+
+    let collection = toc.get_collection(&collection_pass).await?;
+
+    let shard_selection = match shard_selection {
+        None => ShardSelectorInternal::All,
+        Some(shard_id) => ShardSelectorInternal::ShardId(shard_id),
+    };
+
+    Ok(collection.payload_stats(request, &shard_selection).await?)
+}
+
+pub async fn do_get_sparse_stats(
+    toc: &TableOfContent,
+    access: Access,
+    name: &str,
+    request: SparseStatsRequest,
+    shard_selection: Option<ShardId>,
+) -> Result<SparseStatsResult, StorageError> {
+    let collection_pass =
+        access.check_collection_access(name, AccessRequirements::new().whole())?;
+
+    let collection = toc.get_collection(&collection_pass).await?;
+
+    let shard_selection = match shard_selection {
+        None => ShardSelectorInternal::All,
+        Some(shard_id) => ShardSelectorInternal::ShardId(shard_id),
+    };
+
+    Ok(collection.sparse_stats(request, &shard_selection).await?)
+}
+
 pub async fn do_list_collections(
     toc: &TableOfContent,
     access: Access,
@@ -762,6 +762,7 @@ pub async fn do_core_search_points(
         collection_name,
         CoreSearchRequestBatch {
             searches: vec![request],
+            idf_statistics: None,
         },
         read_consistency,
         shard_selection,
@@ -802,6 +803,7 @@ pub async fn do_search_batch_points(
 
             let core_batch = CoreSearchRequestBatch {
                 searches: core_requests,
+                idf_statistics: None,
             };
 
             let req = toc.core_search_batch(
@@ -1339,6 +1339,7 @@ mod tests {
                             optimizers_config: None,
                             shard_number: Some(2),
                             on_disk_payload: None,
+                            payload_schema_enforcement: None,
                             replication_factor: None,
                             write_consistency_factor: None,
                             init_from: None,
@@ -148,6 +148,9 @@ fn main() -> anyhow::Result<()> {
 
     memory::madvise::set_global(settings.storage.mmap_advice);
     segment::vector_storage::common::set_async_scorer(settings.storage.async_scorer);
+    if let Some(queue_depth) = settings.storage.async_scorer_queue_depth {
+        segment::vector_storage::common::set_async_queue_depth(queue_depth);
+    }
 
     welcome(&settings);
 
@@ -3,16 +3,18 @@ use api::rest::{Record, ScoredPoint};
 use collection::operations::cluster_ops::ClusterOperations;
 use collection::operations::consistency_params::ReadConsistency;
 use collection::operations::payload_ops::{DeletePayload, SetPayload};
+use collection::operations::payload_stats::{PayloadStatsRequest, PayloadStatsResult};
 use collection::operations::point_ops::{PointInsertOperations, PointsSelector, WriteOrdering};
 use collection::operations::snapshot_ops::{
     ShardSnapshotRecover, SnapshotDescription, SnapshotRecover,
 };
+use collection::operations::sparse_stats::{SparseStatsRequest, SparseStatsResult};
 use collection::operations::types::{
-    AliasDescription, CollectionClusterInfo, CollectionExistence, CollectionInfo,
-    CollectionsAliasesResponse, CountRequest, CountResult, DiscoverRequest, DiscoverRequestBatch,
-    GroupsResult, PointGroup, PointRequest, RecommendGroupsRequest, RecommendRequest,
-    RecommendRequestBatch, ScrollRequest, ScrollResult, SearchGroupsRequest, SearchRequest,
-    SearchRequestBatch, UpdateResult,
+    AliasDescription, ArithmeticRequest, CollectionClusterInfo, CollectionExistence,
+    CollectionInfo, CollectionsAliasesResponse, CountRequest, CountResult, DiscoverRequest,
+    DiscoverRequestBatch, GroupsResult, PointGroup, PointRequest, RecommendGroupsRequest,
+    RecommendRequest, RecommendRequestBatch, ScrollRequest, ScrollResult, SearchGroupsRequest,
+    SearchRequest, SearchRequestBatch, TextSearchRequest, UpdateResult,
 };
 use collection::operations::vector_ops::{DeleteVectors, UpdateVectors};
 use schemars::gen::SchemaSettings;
@@ -80,6 +82,12 @@ struct AllDefinitions {
     bb: DiscoverRequestBatch,
     bc: VersionInfo,
     bd: CollectionExistence,
+    be: PayloadStatsRequest,
+    bf: PayloadStatsResult,
+    bg: TextSearchRequest,
+    bh: SparseStatsRequest,
+    bi: SparseStatsResult,
+    bj: ArithmeticRequest,
 }
 
 fn save_schema<T: JsonSchema>() {
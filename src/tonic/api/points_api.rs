@@ -6,25 +6,26 @@ use api::grpc::qdrant::{
     ClearPayloadPoints, CountPoints, CountResponse, CreateFieldIndexCollection,
     DeleteFieldIndexCollection, DeletePayloadPoints, DeletePointVectors, DeletePoints,
     DiscoverBatchPoints, DiscoverBatchResponse, DiscoverPoints, DiscoverResponse, GetPoints,
-    GetResponse, PointsOperationResponse, RecommendBatchPoints, RecommendBatchResponse,
-    RecommendGroupsResponse, RecommendPointGroups, RecommendPoints, RecommendResponse,
-    ScrollPoints, ScrollResponse, SearchBatchPoints, SearchBatchResponse, SearchGroupsResponse,
-    SearchPointGroups, SearchPoints, SearchResponse, SetPayloadPoints, UpdateBatchPoints,
-    UpdateBatchResponse, UpdatePointVectors, UpsertPoints,
+    GetResponse, PayloadStatsPoints, PayloadStatsResponse, PointsOperationResponse,
+    RecommendBatchPoints, RecommendBatchResponse, RecommendGroupsResponse, RecommendPointGroups,
+    RecommendPoints, RecommendResponse, ScrollPoints, ScrollResponse, SearchArithmeticPoints,
+    SearchBatchPoints, SearchBatchResponse, SearchGroupsResponse, SearchPointGroups, SearchPoints,
+    SearchResponse, SearchTextPoints, SetPayloadPoints, UpdateBatchPoints, UpdateBatchResponse,
+    UpdatePointVectors, UpsertPoints,
 };
 use collection::operations::types::CoreSearchRequest;
 use storage::dispatcher::Dispatcher;
 use tonic::{Request, Response, Status};
 
 use super::points_common::{
-    delete_vectors, discover, discover_batch, recommend_groups, search_groups, update_batch,
-    update_vectors,
+    delete_vectors, discover, discover_batch, recommend_groups, search_arithmetic, search_groups,
+    search_text, update_batch, update_vectors,
 };
 use super::validate;
 use crate::tonic::api::points_common::{
     clear_payload, convert_shard_selector_for_read, core_search_batch, count, create_field_index,
-    delete, delete_field_index, delete_payload, get, overwrite_payload, recommend, recommend_batch,
-    scroll, search, set_payload, upsert,
+    delete, delete_field_index, delete_payload, get, overwrite_payload, payload_stats, recommend,
+    recommend_batch, scroll, search, set_payload, upsert,
 };
 use crate::tonic::auth::extract_access;
 
@@ -331,6 +332,30 @@ impl Points for PointsService {
         .await
     }
 
+    async fn search_text(
+        &self,
+        mut request: Request<SearchTextPoints>,
+    ) -> Result<Response<SearchResponse>, Status> {
+        validate(request.get_ref())?;
+        let access = extract_access(&mut request);
+        search_text(
+            self.dispatcher.toc(&access),
+            request.into_inner(),
+            None,
+            access,
+        )
+        .await
+    }
+
+    async fn search_arithmetic(
+        &self,
+        mut request: Request<SearchArithmeticPoints>,
+    ) -> Result<Response<SearchResponse>, Status> {
+        validate(request.get_ref())?;
+        let access = extract_access(&mut request);
+        search_arithmetic(self.dispatcher.toc(&access), request.into_inner(), access).await
+    }
+
     async fn scroll(
         &self,
         mut request: Request<ScrollPoints>,
@@ -444,4 +469,15 @@ impl Points for PointsService {
         )
         .await
     }
+
+    async fn payload_stats(
+        &self,
+        mut request: Request<PayloadStatsPoints>,
+    ) -> Result<Response<PayloadStatsResponse>, Status> {
+        validate(request.get_ref())?;
+
+        let access = extract_access(&mut request);
+
+        payload_stats(self.dispatcher.toc(&access), request.into_inner(), access).await
+    }
 }
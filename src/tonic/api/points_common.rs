@@ -8,11 +8,14 @@ use api::grpc::qdrant::{
     points_update_operation, BatchResult, ClearPayloadPoints, CoreSearchPoints, CountPoints,
     CountResponse, CreateFieldIndexCollection, DeleteFieldIndexCollection, DeletePayloadPoints,
     DeletePointVectors, DeletePoints, DiscoverBatchResponse, DiscoverPoints, DiscoverResponse,
-    FieldType, GetPoints, GetResponse, PayloadIndexParams, PointsOperationResponseInternal,
-    PointsSelector, ReadConsistency as ReadConsistencyGrpc, RecommendBatchResponse,
-    RecommendGroupsResponse, RecommendPointGroups, RecommendPoints, RecommendResponse,
-    ScrollPoints, ScrollResponse, SearchBatchResponse, SearchGroupsResponse, SearchPointGroups,
-    SearchPoints, SearchResponse, SetPayloadPoints, SyncPoints, UpdateBatchPoints,
+    FieldType, GetPoints, GetResponse, IdfStatistics as IdfStatisticsGrpc, IdfStatisticsResponse,
+    IdfVectorIndices, PayloadIndexParams, PayloadStatsInternalResponse, PayloadStatsPoints,
+    PayloadStatsResponse, PointsOperationResponseInternal, PointsSelector,
+    ReadConsistency as ReadConsistencyGrpc, RecommendBatchResponse, RecommendGroupsResponse,
+    RecommendPointGroups, RecommendPoints, RecommendResponse, ScrollPoints, ScrollResponse,
+    SearchArithmeticPoints, SearchBatchResponse, SearchGroupsResponse, SearchPointGroups,
+    SearchPoints, SearchResponse, SearchTextPoints, SetPayloadPoints, SparseIndexStatistics,
+    SparseStatsInternal, SparseStatsInternalResponse, SyncPoints, UpdateBatchPoints,
     UpdateBatchResponse, UpdatePointVectors, UpsertPoints,
 };
 use collection::operations::consistency_params::ReadConsistency;
@@ -20,15 +23,18 @@ use collection::operations::conversions::{
     try_discover_request_from_grpc, try_points_selector_from_grpc, write_ordering_from_proto,
 };
 use collection::operations::payload_ops::DeletePayload;
+use collection::operations::payload_stats::PayloadStatsRequest;
 use collection::operations::point_ops::{
     self, PointInsertOperations, PointOperations, PointSyncOperation, PointsList,
 };
 use collection::operations::query_enum::QueryEnum;
 use collection::operations::shard_key_selector::ShardKeySelector;
 use collection::operations::shard_selector_internal::ShardSelectorInternal;
+use collection::operations::sparse_stats::SparseStatsRequest;
 use collection::operations::types::{
-    default_exact_count, CoreSearchRequest, CoreSearchRequestBatch, OrderByInterface,
-    PointRequestInternal, RecommendExample, Record, ScrollRequestInternal,
+    default_exact_count, ArithmeticRequestInternal, CoreSearchRequest, CoreSearchRequestBatch,
+    IdfStatistics, IdfStatisticsRequest, OrderByInterface, PointRequestInternal, RecommendExample,
+    Record, ScrollRequestInternal, TextSearchRequestInternal,
 };
 use collection::operations::vector_ops::{DeleteVectors, PointVectors, UpdateVectors};
 use collection::operations::{ClockTag, CollectionUpdateOperations, OperationWithClockTag};
@@ -44,6 +50,7 @@ use storage::dispatcher::Dispatcher;
 use storage::rbac::Access;
 use tonic::{Response, Status};
 
+use crate::common::collections::do_get_payload_stats;
 use crate::common::points::{
     do_clear_payload, do_core_search_points, do_count_points, do_create_index,
     do_create_index_internal, do_delete_index, do_delete_index_internal, do_delete_payload,
@@ -768,6 +775,15 @@ fn convert_field_type(
         ) => Some(PayloadFieldSchema::FieldParams(
             PayloadSchemaParams::Integer(integer_params.try_into()?),
         )),
+        // Parameterized keyword type
+        (
+            Some(FieldType::Keyword),
+            Some(PayloadIndexParams {
+                index_params: Some(IndexParams::KeywordIndexParams(keyword_params)),
+            }),
+        ) => Some(PayloadFieldSchema::FieldParams(
+            PayloadSchemaParams::Keyword(keyword_params.try_into()?),
+        )),
         // Regular field types
         (Some(v), None | Some(PayloadIndexParams { index_params: None })) => match v {
             FieldType::Keyword => Some(PayloadSchemaType::Keyword.into()),
@@ -1009,6 +1025,144 @@ pub async fn search(
     Ok(Response::new(response))
 }
 
+pub async fn search_text(
+    toc: &TableOfContent,
+    search_text_points: SearchTextPoints,
+    shard_selection: Option<ShardId>,
+    access: Access,
+) -> Result<Response<SearchResponse>, Status> {
+    let SearchTextPoints {
+        collection_name,
+        using,
+        text,
+        filter,
+        limit,
+        with_payload,
+        params,
+        score_threshold,
+        offset,
+        with_vectors,
+        read_consistency,
+        timeout,
+        shard_key_selector,
+    } = search_text_points;
+
+    let shard_selector = convert_shard_selector_for_read(shard_selection, shard_key_selector);
+
+    let request = TextSearchRequestInternal {
+        using,
+        text,
+        filter: filter.map(|f| f.try_into()).transpose()?,
+        params: params.map(|p| p.into()),
+        limit: limit as usize,
+        offset: offset.map(|offset| offset as usize),
+        with_payload: with_payload.map(|wp| wp.try_into()).transpose()?,
+        with_vector: Some(
+            with_vectors
+                .map(|selector| selector.into())
+                .unwrap_or_default(),
+        ),
+        score_threshold,
+    };
+
+    let read_consistency = ReadConsistency::try_from_optional(read_consistency)?;
+
+    let timing = Instant::now();
+    let scored_points = toc
+        .search_text(
+            &collection_name,
+            request,
+            read_consistency,
+            shard_selector,
+            access,
+            timeout.map(Duration::from_secs),
+        )
+        .await
+        .map_err(error_to_status)?;
+
+    let response = SearchResponse {
+        result: scored_points
+            .into_iter()
+            .map(|point| point.into())
+            .collect(),
+        time: timing.elapsed().as_secs_f64(),
+    };
+
+    Ok(Response::new(response))
+}
+
+pub async fn search_arithmetic(
+    toc: &TableOfContent,
+    search_arithmetic_points: SearchArithmeticPoints,
+    access: Access,
+) -> Result<Response<SearchResponse>, Status> {
+    let SearchArithmeticPoints {
+        collection_name,
+        terms,
+        average,
+        filter,
+        limit,
+        with_payload,
+        params,
+        score_threshold,
+        offset,
+        using,
+        with_vectors,
+        lookup_from,
+        read_consistency,
+        timeout,
+        shard_key_selector,
+    } = search_arithmetic_points;
+
+    let shard_selector = convert_shard_selector_for_read(None, shard_key_selector);
+
+    let request = ArithmeticRequestInternal {
+        terms: terms
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<_, _>>()?,
+        average,
+        filter: filter.map(|f| f.try_into()).transpose()?,
+        params: params.map(|p| p.into()),
+        limit: limit as usize,
+        offset: offset.map(|offset| offset as usize),
+        with_payload: with_payload.map(|wp| wp.try_into()).transpose()?,
+        with_vector: Some(
+            with_vectors
+                .map(|selector| selector.into())
+                .unwrap_or_default(),
+        ),
+        score_threshold,
+        using: using.map(|u| u.into()),
+        lookup_from: lookup_from.map(|l| l.into()),
+    };
+
+    let read_consistency = ReadConsistency::try_from_optional(read_consistency)?;
+
+    let timing = Instant::now();
+    let scored_points = toc
+        .search_arithmetic(
+            &collection_name,
+            request,
+            read_consistency,
+            shard_selector,
+            access,
+            timeout.map(Duration::from_secs),
+        )
+        .await
+        .map_err(error_to_status)?;
+
+    let response = SearchResponse {
+        result: scored_points
+            .into_iter()
+            .map(|point| point.into())
+            .collect(),
+        time: timing.elapsed().as_secs_f64(),
+    };
+
+    Ok(Response::new(response))
+}
+
 pub async fn core_search_batch(
     toc: &TableOfContent,
     collection_name: String,
@@ -1045,10 +1199,12 @@ pub async fn core_search_batch(
     Ok(Response::new(response))
 }
 
+#[allow(clippy::too_many_arguments)]
 pub async fn core_search_list(
     toc: &TableOfContent,
     collection_name: String,
     search_points: Vec<CoreSearchPoints>,
+    idf_statistics: Option<IdfStatisticsGrpc>,
     read_consistency: Option<ReadConsistencyGrpc>,
     shard_selection: Option<ShardId>,
     access: Access,
@@ -1059,6 +1215,7 @@ pub async fn core_search_list(
 
     let request = CoreSearchRequestBatch {
         searches: searches?,
+        idf_statistics: idf_statistics.map(IdfStatistics::try_from).transpose()?,
     };
 
     let timing = Instant::now();
@@ -1502,6 +1659,137 @@ pub async fn count(
     Ok(Response::new(response))
 }
 
+pub async fn idf_statistics(
+    toc: &TableOfContent,
+    collection_name: String,
+    vectors: Vec<IdfVectorIndices>,
+    shard_selection: Option<ShardId>,
+    access: Access,
+) -> Result<Response<IdfStatisticsResponse>, Status> {
+    let request = IdfStatisticsRequest {
+        vectors: vectors
+            .into_iter()
+            .map(|vector| (vector.vector_name, vector.indices))
+            .collect(),
+    };
+
+    // As this function is handling an internal request,
+    // we can assume that shard_key is already resolved
+    let shard_selection = match shard_selection {
+        None => {
+            debug_assert!(false, "Shard selection is expected for internal request");
+            ShardSelectorInternal::All
+        }
+        Some(shard_id) => ShardSelectorInternal::ShardId(shard_id),
+    };
+
+    let timing = Instant::now();
+    let statistics = toc
+        .idf_statistics(&collection_name, request, shard_selection, access)
+        .await
+        .map_err(error_to_status)?;
+
+    let response = IdfStatisticsResponse {
+        result: Some(statistics.into()),
+        time: timing.elapsed().as_secs_f64(),
+    };
+
+    Ok(Response::new(response))
+}
+
+pub async fn payload_stats(
+    toc: &TableOfContent,
+    payload_stats_points: PayloadStatsPoints,
+    access: Access,
+) -> Result<Response<PayloadStatsResponse>, Status> {
+    let collection_name = payload_stats_points.collection_name.clone();
+    let request = PayloadStatsRequest::try_from(payload_stats_points)?;
+
+    let timing = Instant::now();
+    let payload_stats = do_get_payload_stats(toc, access, &collection_name, request, None)
+        .await
+        .map_err(error_to_status)?;
+
+    let response = PayloadStatsResponse {
+        result: Some(payload_stats.into()),
+        time: timing.elapsed().as_secs_f64(),
+    };
+
+    Ok(Response::new(response))
+}
+
+pub async fn payload_stats_internal(
+    toc: &TableOfContent,
+    payload_stats_points: PayloadStatsPoints,
+    shard_selection: Option<ShardId>,
+    access: Access,
+) -> Result<Response<PayloadStatsInternalResponse>, Status> {
+    let collection_name = payload_stats_points.collection_name.clone();
+    let request = PayloadStatsRequest::try_from(payload_stats_points)?;
+
+    // As this function is handling an internal request,
+    // we can assume that shard_key is already resolved
+    let shard_selection = match shard_selection {
+        None => {
+            debug_assert!(false, "Shard selection is expected for internal request");
+            ShardSelectorInternal::All
+        }
+        Some(shard_id) => ShardSelectorInternal::ShardId(shard_id),
+    };
+
+    let timing = Instant::now();
+    let payload_stats = toc
+        .payload_stats_partial(&collection_name, request, shard_selection, access)
+        .await
+        .map_err(error_to_status)?;
+
+    let response = PayloadStatsInternalResponse {
+        result: Some(payload_stats.into()),
+        time: timing.elapsed().as_secs_f64(),
+    };
+
+    Ok(Response::new(response))
+}
+
+pub async fn sparse_stats_internal(
+    toc: &TableOfContent,
+    sparse_stats_internal: SparseStatsInternal,
+    access: Access,
+) -> Result<Response<SparseStatsInternalResponse>, Status> {
+    let SparseStatsInternal {
+        collection_name,
+        using,
+        shard_id,
+    } = sparse_stats_internal;
+
+    // As this function is handling an internal request,
+    // we can assume that shard_key is already resolved
+    let shard_selection = match shard_id {
+        None => {
+            debug_assert!(false, "Shard selection is expected for internal request");
+            ShardSelectorInternal::All
+        }
+        Some(shard_id) => ShardSelectorInternal::ShardId(shard_id),
+    };
+    let request = SparseStatsRequest { using, limit: None };
+
+    let timing = Instant::now();
+    let sparse_stats = toc
+        .sparse_stats_partial(&collection_name, request, shard_selection, access)
+        .await
+        .map_err(error_to_status)?;
+
+    let response = SparseStatsInternalResponse {
+        result: sparse_stats
+            .into_iter()
+            .map(SparseIndexStatistics::from)
+            .collect(),
+        time: timing.elapsed().as_secs_f64(),
+    };
+
+    Ok(Response::new(response))
+}
+
 pub async fn get(
     toc: &TableOfContent,
     get_points: GetPoints,
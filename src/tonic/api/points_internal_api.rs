@@ -6,9 +6,11 @@ use api::grpc::qdrant::{
     ClearPayloadPointsInternal, CoreSearchBatchPointsInternal, CountPointsInternal, CountResponse,
     CreateFieldIndexCollectionInternal, DeleteFieldIndexCollectionInternal,
     DeletePayloadPointsInternal, DeletePointsInternal, DeleteVectorsInternal, GetPointsInternal,
-    GetResponse, PointsOperationResponseInternal, RecommendPointsInternal, RecommendResponse,
-    ScrollPointsInternal, ScrollResponse, SearchBatchResponse, SetPayloadPointsInternal,
-    SyncPointsInternal, UpdateVectorsInternal, UpsertPointsInternal,
+    GetResponse, IdfStatisticsInternal, IdfStatisticsResponse, PayloadStatsInternal,
+    PayloadStatsInternalResponse, PointsOperationResponseInternal, RecommendPointsInternal,
+    RecommendResponse, ScrollPointsInternal, ScrollResponse, SearchBatchResponse,
+    SetPayloadPointsInternal, SparseStatsInternal, SparseStatsInternalResponse, SyncPointsInternal,
+    UpdateVectorsInternal, UpsertPointsInternal,
 };
 use storage::content_manager::toc::TableOfContent;
 use storage::rbac::Access;
@@ -18,8 +20,8 @@ use super::points_common::core_search_list;
 use super::validate_and_log;
 use crate::tonic::api::points_common::{
     clear_payload, count, create_field_index_internal, delete, delete_field_index_internal,
-    delete_payload, delete_vectors, get, overwrite_payload, recommend, scroll, set_payload, sync,
-    update_vectors, upsert,
+    delete_payload, delete_vectors, get, idf_statistics, overwrite_payload, payload_stats_internal,
+    recommend, scroll, set_payload, sparse_stats_internal, sync, update_vectors, upsert,
 };
 
 const FULL_ACCESS: Access = Access::full("Internal API");
@@ -296,6 +298,7 @@ impl PointsInternal for PointsInternalService {
             search_points,
             shard_id,
             timeout,
+            idf_statistics,
         } = request.into_inner();
 
         let timeout = timeout.map(Duration::from_secs);
@@ -310,6 +313,7 @@ impl PointsInternal for PointsInternalService {
             self.toc.as_ref(),
             collection_name,
             search_points,
+            idf_statistics,
             None, // *Has* to be `None`!
             shard_id,
             FULL_ACCESS.clone(),
@@ -404,6 +408,59 @@ impl PointsInternal for PointsInternalService {
         .await
     }
 
+    async fn idf_statistics(
+        &self,
+        request: Request<IdfStatisticsInternal>,
+    ) -> Result<Response<IdfStatisticsResponse>, Status> {
+        validate_and_log(request.get_ref());
+
+        let IdfStatisticsInternal {
+            collection_name,
+            vectors,
+            shard_id,
+        } = request.into_inner();
+
+        idf_statistics(
+            self.toc.as_ref(),
+            collection_name,
+            vectors,
+            shard_id,
+            FULL_ACCESS.clone(),
+        )
+        .await
+    }
+
+    async fn payload_stats(
+        &self,
+        request: Request<PayloadStatsInternal>,
+    ) -> Result<Response<PayloadStatsInternalResponse>, Status> {
+        validate_and_log(request.get_ref());
+
+        let PayloadStatsInternal {
+            payload_stats_points,
+            shard_id,
+        } = request.into_inner();
+
+        let payload_stats_points = payload_stats_points
+            .ok_or_else(|| Status::invalid_argument("PayloadStatsPoints is missing"))?;
+        payload_stats_internal(
+            self.toc.as_ref(),
+            payload_stats_points,
+            shard_id,
+            FULL_ACCESS.clone(),
+        )
+        .await
+    }
+
+    async fn sparse_stats(
+        &self,
+        request: Request<SparseStatsInternal>,
+    ) -> Result<Response<SparseStatsInternalResponse>, Status> {
+        validate_and_log(request.get_ref());
+
+        sparse_stats_internal(self.toc.as_ref(), request.into_inner(), FULL_ACCESS.clone()).await
+    }
+
     async fn sync(
         &self,
         request: Request<SyncPointsInternal>,
@@ -136,6 +136,22 @@ ACTION_ACCESS = {
         "GET /collections/{collection_name}/exists",
         "qdrant.Collections/CollectionExists",
     ),
+    "get_payload_stats": EndpointAccess(
+        True,
+        True,
+        True,
+        "POST /collections/{collection_name}/payload/stats",
+        "qdrant.Points/PayloadStats",
+        coll_rw_payload=False,
+    ),
+    "get_sparse_stats": EndpointAccess(
+        True,
+        True,
+        True,
+        "POST /collections/{collection_name}/sparse/stats",
+        None,
+        coll_rw_payload=False,
+    ),
     "replicate_shard_operation": EndpointAccess(
         False,
         False,
@@ -481,6 +497,21 @@ ACTION_ACCESS = {
         "POST /collections/{collection_name}/points/search/groups",
         "qdrant.Points/SearchGroups",
     ),
+    "search_text_points": EndpointAccess(
+        True,
+        True,
+        True,
+        "POST /collections/{collection_name}/points/search/text",
+        "qdrant.Points/SearchText",
+    ),
+    "search_arithmetic_points": EndpointAccess(
+        True,
+        True,
+        True,
+        "POST /collections/{collection_name}/points/search/arithmetic",
+        "qdrant.Points/SearchArithmetic",
+        coll_rw_payload=False,
+    ),
     "recommend_points": EndpointAccess(
         True,
         True,
@@ -939,6 +970,23 @@ def test_collection_exists():
     )
 
 
+def test_get_payload_stats():
+    check_access(
+        "get_payload_stats",
+        rest_request={"limit": 5},
+        path_params={"collection_name": COLL_NAME},
+        grpc_request={"collection_name": COLL_NAME, "limit": 5},
+    )
+
+
+def test_get_sparse_stats():
+    check_access(
+        "get_sparse_stats",
+        rest_request={"limit": 5},
+        path_params={"collection_name": COLL_NAME},
+    )
+
+
 def test_replicate_shard_operation():
     peer_ids = [PEER_ID + 5, PEER_ID + 3]
     replicate_shard = {
@@ -1624,6 +1672,35 @@ def test_search_point_groups():
     )
 
 
+def test_search_text_points():
+    query = {"using": "bm25", "text": "hello world", "limit": 10}
+    check_access(
+        "search_text_points",
+        rest_request=query,
+        path_params={"collection_name": COLL_NAME},
+        grpc_request={"collection_name": COLL_NAME, **query},
+    )
+
+
+def test_search_arithmetic_points():
+    check_access(
+        "search_arithmetic_points",
+        rest_request={
+            "terms": [{"example": 1, "weight": 1.0}, {"example": 2, "weight": -1.0}],
+            "limit": 10,
+        },
+        path_params={"collection_name": COLL_NAME},
+        grpc_request={
+            "collection_name": COLL_NAME,
+            "terms": [
+                {"example": {"id": {"num": 1}}, "weight": 1.0},
+                {"example": {"id": {"num": 2}}, "weight": -1.0},
+            ],
+            "limit": 10,
+        },
+    )
+
+
 def test_recommend_points():
     check_access(
         "recommend_points",
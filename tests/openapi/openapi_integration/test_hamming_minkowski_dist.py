@@ -0,0 +1,131 @@
+import pytest
+
+from .helpers.collection_setup import drop_collection
+from .helpers.helpers import request_with_validation
+
+hamming_collection_name = 'test_collection_hamming'
+minkowski_collection_name = 'test_collection_minkowski'
+minkowski_5_collection_name = 'test_collection_minkowski_5'
+
+
+def collection_setup(collection_name, vectors_config, points):
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="DELETE",
+        path_params={'collection_name': collection_name},
+    )
+    assert response.ok
+
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        body={"vectors": vectors_config}
+    )
+    assert response.ok
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "points": [
+                {"id": idx + 1, "vector": vector}
+                for idx, vector in enumerate(points)
+            ]
+        }
+    )
+    assert response.ok
+
+
+@pytest.fixture(autouse=True, scope="module")
+def setup(on_disk_vectors):
+    # Bytes of the vectors are packed bits
+    collection_setup(
+        collection_name=hamming_collection_name,
+        vectors_config={
+            "size": 2,
+            "distance": "Hamming",
+            "datatype": "uint8",
+            "on_disk": on_disk_vectors,
+        },
+        points=[[240, 0], [255, 255], [240, 1]],
+    )
+    collection_setup(
+        collection_name=minkowski_collection_name,
+        vectors_config={
+            "size": 2,
+            "distance": {"Minkowski": 3},
+            "on_disk": on_disk_vectors,
+        },
+        points=[[0.0, 0.0], [0.0, 1.0], [-1.0, -1.0]],
+    )
+    # Order without a SIMD implementation
+    collection_setup(
+        collection_name=minkowski_5_collection_name,
+        vectors_config={
+            "size": 2,
+            "distance": {"Minkowski": 5},
+            "on_disk": on_disk_vectors,
+        },
+        points=[[0.0, 0.0], [0.0, 1.0], [-1.0, -1.0]],
+    )
+    yield
+    drop_collection(collection_name=hamming_collection_name)
+    drop_collection(collection_name=minkowski_collection_name)
+    drop_collection(collection_name=minkowski_5_collection_name)
+
+
+def search(collection_name, vector):
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/search',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={
+            "vector": vector,
+            "limit": 3
+        }
+    )
+    assert response.ok
+    return response.json()['result']
+
+
+def test_hamming_search():
+    result = search(hamming_collection_name, [240, 0])
+
+    assert [point['id'] for point in result] == [1, 3, 2]
+    assert [point['score'] for point in result] == [0.0, 1.0, 12.0]
+
+
+def test_minkowski_search():
+    result = search(minkowski_collection_name, [1.0, 1.0])
+
+    assert [point['id'] for point in result] == [2, 1, 3]
+    assert abs(result[0]['score'] - 1.0) < 0.0001
+    assert abs(result[1]['score'] - 2 ** (1 / 3)) < 0.0001
+    assert abs(result[2]['score'] - 16 ** (1 / 3)) < 0.0001
+
+
+def test_minkowski_any_order_search():
+    result = search(minkowski_5_collection_name, [1.0, 1.0])
+
+    assert [point['id'] for point in result] == [2, 1, 3]
+    assert abs(result[0]['score'] - 1.0) < 0.0001
+    assert abs(result[1]['score'] - 2 ** (1 / 5)) < 0.0001
+    assert abs(result[2]['score'] - 64 ** (1 / 5)) < 0.0001
+
+
+def test_zero_minkowski_order():
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="PUT",
+        path_params={'collection_name': 'test_collection_minkowski_zero'},
+        body={
+            "vectors": {
+                "size": 2,
+                "distance": {"Minkowski": 0},
+            }
+        }
+    )
+    assert response.status_code == 422
@@ -89,6 +89,13 @@ def test_single_vs_batch():
             "strategy": "average_vector",
             "limit": 1,
         },
+        {
+            "positive": [2, 3],
+            "negative": [4, 5],
+            "exact": True,
+            "strategy": "sum_scores",
+            "limit": 1,
+        },
     ]
 
     batch_response = request_with_validation(
@@ -146,6 +153,10 @@ def test_without_positives():
     response = req_with_positives([], "best_score")
     assert response.status_code == 400
 
+    # And with sum_scores
+    response = req_with_positives([], "sum_scores")
+    assert response.status_code == 400
+
 
 def test_best_score_works_with_only_negatives():
     response = request_with_validation(
@@ -210,6 +221,52 @@ def test_only_1_positive_in_best_score_is_equivalent_to_normal_search():
     assert reco_ids == search_ids
 
 
+def test_sum_scores_is_equivalent_to_search_by_difference_with_dot():
+    limit = 4
+
+    # recommendation response
+    reco_response = request_with_validation(
+        api="/collections/{collection_name}/points/recommend",
+        method="POST",
+        path_params={"collection_name": collection_name},
+        body={
+            "positive": [1],
+            "negative": [2],
+            "strategy": "sum_scores",
+            "limit": limit,
+            "exact": True,
+        },
+    )
+    assert reco_response.ok
+    assert len(reco_response.json()["result"]) == limit
+
+    # With dot product, the sum of scores is the score of the sum of vectors
+    vectors = {point["id"]: point["vector"] for point in get_points([1, 2])}
+    vector_1, vector_2 = vectors[1], vectors[2]
+    vector = [a - b for a, b in zip(vector_1, vector_2)]
+
+    search_response = request_with_validation(
+        api="/collections/{collection_name}/points/search",
+        method="POST",
+        path_params={"collection_name": collection_name},
+        body={
+            "vector": vector,
+            "filter": {"must_not": [{"has_id": [1, 2]}]},
+            "limit": limit,
+            "exact": True,
+        },
+    )
+    assert search_response.ok
+    assert len(search_response.json()["result"]) == limit
+
+    reco_result = reco_response.json()["result"]
+    search_result = search_response.json()["result"]
+
+    assert [result["id"] for result in reco_result] == [result["id"] for result in search_result]
+    for reco, search in zip(reco_result, search_result):
+        assert reco["score"] == pytest.approx(search["score"], abs=1e-4)
+
+
 def get_points(ids: list):
     response = request_with_validation(
         api="/collections/{collection_name}/points",